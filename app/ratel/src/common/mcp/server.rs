@@ -193,6 +193,7 @@ impl RatelMcpServer {
             // syndication is opt-in via the human compose UI only.
             enabled_platforms: None,
             platform_overrides: None,
+            schedule: None,
         };
         update_post_handler_mcp_impl(self.mcp_secret.clone(), req.post_id, update_req)
             .await
@@ -281,6 +282,59 @@ impl RatelMcpServer {
         .await
     }
 
+    // ── Space template tools ────────────────────────────────────────
+
+    #[rmcp::tool(
+        name = "save_space_template",
+        description = "Save a space as a reusable template: its settings, every action with its poll/quiz/discussion/meet content and dependencies, installed apps, panel quotas, AI moderator and reward settings. Pass team_id to share the template with a team. Requires creator role."
+    )]
+    async fn save_space_template(
+        &self,
+        Parameters(req): Parameters<
+            crate::features::spaces::templates::controllers::SaveSpaceTemplateMcpRequest,
+        >,
+    ) -> McpResult {
+        crate::features::spaces::templates::controllers::save_space_template_mcp_handler(
+            &self.mcp_secret,
+            req,
+        )
+        .await
+    }
+
+    #[rmcp::tool(
+        name = "list_space_templates",
+        description = "List space templates, newest first. Without team_id lists the user's own templates; with team_id lists the templates shared with that team."
+    )]
+    async fn list_space_templates(
+        &self,
+        Parameters(req): Parameters<
+            crate::features::spaces::templates::controllers::ListSpaceTemplatesMcpRequest,
+        >,
+    ) -> McpResult {
+        crate::features::spaces::templates::controllers::list_space_templates_mcp_handler(
+            &self.mcp_secret,
+            req,
+        )
+        .await
+    }
+
+    #[rmcp::tool(
+        name = "create_space_from_template",
+        description = "Create a space on an existing post from a space template. Copies the template's settings, actions (with dependencies remapped to the new action ids), apps, panel quotas, AI moderator and reward settings. Rewards are funded from your credits again; actions that cannot be funded are listed in unfunded_actions."
+    )]
+    async fn create_space_from_template(
+        &self,
+        Parameters(req): Parameters<
+            crate::features::spaces::templates::controllers::CreateSpaceFromTemplateMcpRequest,
+        >,
+    ) -> McpResult {
+        crate::features::spaces::templates::controllers::create_space_from_template_mcp_handler(
+            &self.mcp_secret,
+            req,
+        )
+        .await
+    }
+
     // ── Poll tools ──────────────────────────────────────────────────
 
     #[rmcp::tool(
@@ -0,0 +1,195 @@
+//! Migration 003 — backfill UserBadgeProgress + UserBadge from the rows the
+//! badge evaluator would have counted had it been live: space joins, poll
+//! answers, perfect quiz attempts, discussion comments/replies, follows and
+//! spaces created. Awards are silent (no inbox rows) and counters are
+//! max-merged, so a re-run — or a run racing the live stream — converges
+//! without over-counting or revoking anything. Streaks are not backfilled;
+//! history has no per-day ledger.
+
+use crate::common::*;
+use crate::features::badge::models::UserBadgeProgress;
+use crate::features::badge::services::backfill_user_badges;
+use aws_sdk_dynamodb::types::AttributeValue;
+use std::collections::HashMap;
+
+/// Required version this migration advances `LastBackfillVersion` to.
+pub const REQUIRED_VERSION: i64 = 3;
+
+pub async fn run(cli: &aws_sdk_dynamodb::Client) -> crate::common::Result<()> {
+    use crate::common::models::auth::UserFollow;
+    use crate::common::models::space::{SpaceCommon, SpaceParticipant};
+    use crate::features::spaces::pages::actions::actions::discussion::SpacePostComment;
+    use crate::features::spaces::pages::actions::actions::poll::SpacePollUserAnswer;
+    use crate::features::spaces::pages::actions::actions::quiz::SpaceQuizAttempt;
+
+    // Keyed by user_pk's String form to avoid requiring Hash on Partition.
+    let mut snapshots: HashMap<String, UserBadgeProgress> = HashMap::new();
+    fn entry<'a>(
+        snapshots: &'a mut HashMap<String, UserBadgeProgress>,
+        user_pk: &Partition,
+    ) -> Option<&'a mut UserBadgeProgress> {
+        match user_pk {
+            Partition::User(id) => Some(snapshots.entry(format!("USER#{id}")).or_default()),
+            _ => None,
+        }
+    }
+
+    tracing::info!("m003: scanning SpaceParticipant rows");
+    scan_each::<SpaceParticipant>(cli, SkFilter::Eq("SPACE_PARTICIPANT"), |row| {
+        if let Some(p) = entry(&mut snapshots, &row.user_pk) {
+            p.spaces_joined += 1;
+        }
+    })
+    .await?;
+
+    tracing::info!("m003: scanning SpacePollUserAnswer rows");
+    scan_each::<SpacePollUserAnswer>(cli, SkFilter::Prefix("SPACE_POLL_USER_ANSWER#"), |row| {
+        if let Some(p) = row.user_pk.as_ref().and_then(|pk| entry(&mut snapshots, pk)) {
+            p.poll_votes_cast += 1;
+        }
+    })
+    .await?;
+
+    tracing::info!("m003: scanning SpaceQuizAttempt rows");
+    scan_each::<SpaceQuizAttempt>(cli, SkFilter::Prefix("SPACE_QUIZ_ATTEMPT#"), |row| {
+        // Same rule as `handle_quiz_badge`.
+        let perfect = row.is_perfect();
+        if !perfect {
+            return;
+        }
+        if let Some(p) = row.user_pk.as_ref().and_then(|pk| entry(&mut snapshots, pk)) {
+            p.quizzes_perfect += 1;
+        }
+    })
+    .await?;
+
+    tracing::info!("m003: scanning SpacePostComment rows");
+    // Replies count the same as top-level comments, as on the stream.
+    for prefix in ["SPACE_POST_COMMENT#", "SPACE_POST_COMMENT_REPLY#"] {
+        scan_each::<SpacePostComment>(cli, SkFilter::Prefix(prefix), |row| {
+            if let Some(p) = entry(&mut snapshots, &row.author_pk) {
+                p.discussion_comments += 1;
+            }
+        })
+        .await?;
+    }
+
+    tracing::info!("m003: scanning UserFollow rows");
+    scan_each::<UserFollow>(cli, SkFilter::Prefix("FOLLOWER#"), |row| {
+        let quest = row
+            .action_id
+            .as_deref()
+            .map(|id| !id.is_empty())
+            .unwrap_or(false);
+        if let Some(p) = entry(&mut snapshots, &row.user_pk) {
+            p.followings += 1;
+            if quest {
+                p.follow_quests_completed += 1;
+            }
+        }
+        if let Some(p) = entry(&mut snapshots, &row.target_user_pk) {
+            p.followers += 1;
+        }
+    })
+    .await?;
+
+    tracing::info!("m003: scanning SpaceCommon rows");
+    scan_each::<SpaceCommon>(cli, SkFilter::Eq("SPACE_COMMON"), |row| {
+        // A space counts once it has gone Ongoing; Processing and Finished
+        // spaces passed through Ongoing on the way.
+        let status = row.status.unwrap_or_default();
+        if !matches!(
+            status,
+            SpaceStatus::Ongoing | SpaceStatus::Processing | SpaceStatus::Finished
+        ) {
+            return;
+        }
+        if let Some(p) = entry(&mut snapshots, &row.user_pk) {
+            p.spaces_created_ongoing += 1;
+            if status == SpaceStatus::Finished {
+                p.peak_space_participants = p.peak_space_participants.max(row.participants);
+            }
+        }
+    })
+    .await?;
+
+    tracing::info!(users = snapshots.len(), "m003: aggregation done; writing");
+
+    use futures::stream::{self, StreamExt};
+    let writes = stream::iter(snapshots.into_iter().map(|(user_key, snapshot)| {
+        let user_pk = match user_key.strip_prefix("USER#") {
+            Some(id) => Partition::User(id.to_string()),
+            None => Partition::None,
+        };
+        async move { backfill_user_badges(cli, &user_pk, &snapshot).await }
+    }))
+    .buffer_unordered(16);
+    let awarded: usize = writes
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<crate::common::Result<Vec<_>>>()?
+        .iter()
+        .map(Vec::len)
+        .sum();
+
+    tracing::info!(awarded, "m003: complete");
+    Ok(())
+}
+
+enum SkFilter {
+    Eq(&'static str),
+    Prefix(&'static str),
+}
+
+/// Scan every row matching `filter` and hand each to `f`. Paginates via
+/// ExclusiveStartKey with the same page cap as m001.
+async fn scan_each<T: serde::de::DeserializeOwned>(
+    cli: &aws_sdk_dynamodb::Client,
+    filter: SkFilter,
+    mut f: impl FnMut(T),
+) -> crate::common::Result<()> {
+    let (expr, value) = match filter {
+        SkFilter::Eq(v) => ("sk = :sk", v),
+        SkFilter::Prefix(v) => ("begins_with(sk, :sk)", v),
+    };
+
+    let mut last_evaluated_key: Option<HashMap<String, AttributeValue>> = None;
+    let mut pages = 0;
+    loop {
+        pages += 1;
+        if pages > 10_000 {
+            tracing::error!(sk = value, "m003 exceeded 10000 scan pages; aborting");
+            return Err(Error::Internal);
+        }
+
+        let mut req = cli
+            .scan()
+            .table_name(UserBadgeProgress::table_name())
+            .filter_expression(expr)
+            .expression_attribute_values(":sk", AttributeValue::S(value.into()))
+            .limit(500);
+        if let Some(esk) = last_evaluated_key.clone() {
+            req = req.set_exclusive_start_key(Some(esk));
+        }
+
+        let resp = req
+            .send()
+            .await
+            .map_err(Into::<aws_sdk_dynamodb::Error>::into)?;
+
+        for item in resp.items.unwrap_or_default() {
+            match serde_dynamo::from_item::<_, T>(item) {
+                Ok(row) => f(row),
+                // One malformed legacy row shouldn't abort the whole backfill.
+                Err(e) => tracing::warn!(sk = value, error = %e, "m003: skipping row"),
+            }
+        }
+
+        match resp.last_evaluated_key {
+            Some(k) if !k.is_empty() => last_evaluated_key = Some(k),
+            _ => break,
+        }
+    }
+    Ok(())
+}
@@ -10,6 +10,8 @@
 #[cfg(feature = "server")]
 mod m001_backfill_character_xp;
 #[cfg(feature = "server")]
+mod m003_backfill_badges;
+#[cfg(feature = "server")]
 mod runner;
 
 #[cfg(feature = "server")]
@@ -22,22 +22,34 @@ pub async fn run_migrations(cli: &aws_sdk_dynamodb::Client) -> crate::common::Re
 
     tracing::info!(stored_version = stored, "migration runner starting");
 
-    if stored < 1 {
+    // Track the version actually stored as each gate advances it, so a
+    // fresh environment can run m001 and m003 back to back.
+    let mut current = stored;
+
+    if current < 1 {
         tracing::info!("running migration 001: backfill_character_xp");
         super::m001_backfill_character_xp::run(cli).await?;
-        LastBackfillVersion::advance_to(cli, stored, 1).await?;
+        LastBackfillVersion::advance_to(cli, current, 1).await?;
+        current = 1;
         tracing::info!("migration 001 complete; version advanced to 1");
     }
 
     // m002 (Biyard PendingReward backfill) was deleted alongside the
     // BiyardService removal. Prod already ran it (version >= 2 stored),
     // and there's nothing for new environments to back-fill anymore.
-    // Skip the gate; the version monotonically advances from whatever
-    // prod has, and future migrations stack from `if stored < 3`.
+    // Fresh environments jump straight from 1 to 3.
+
+    if current < 3 {
+        tracing::info!("running migration 003: backfill_badges");
+        super::m003_backfill_badges::run(cli).await?;
+        LastBackfillVersion::advance_to(cli, current, 3).await?;
+        current = 3;
+        tracing::info!("migration 003 complete; version advanced to 3");
+    }
 
     // Future migrations stack additively here:
-    //   if stored < 3 { ... advance_to(cli, stored.max(2), 3) ... }
+    //   if current < 4 { ... advance_to(cli, current, 4) ... }
 
-    tracing::info!("migration runner finished");
+    tracing::info!(version = current, "migration runner finished");
     Ok(())
 }
@@ -8,6 +8,7 @@ pub mod dev_tools;
 pub mod hooks;
 mod provider;
 pub mod providers;
+pub mod realtime;
 mod run;
 mod traits;
 pub mod types;
@@ -15,6 +15,15 @@ pub struct Reward {
     pub point: i64,
     pub period: RewardPeriod,
     pub condition: RewardCondition,
+
+    /// Streak XP ladder for this behavior. Empty = no streak bonus.
+    #[serde(default)]
+    pub streak_multipliers: Vec<StreakMultiplier>,
+
+    /// Template rules copied into each `SpaceReward` when credits are
+    /// first set on an action.
+    #[serde(default)]
+    pub rules: RewardRules,
 }
 
 #[cfg(feature = "server")]
@@ -36,6 +45,8 @@ impl Reward {
             point,
             period,
             condition,
+            streak_multipliers: vec![],
+            rules: RewardRules::default(),
         }
     }
 }
@@ -0,0 +1,349 @@
+//! Cross-process fan-out backplane behind the realtime hub.
+//!
+//! The hub (`InProcessChannel`) only reaches subscribers held by its
+//! own process. A `Backplane` is the piece every publish goes
+//! through first: it assigns the event id and keeps a per-channel
+//! log so a client that reconnects with `Last-Event-ID` — possibly
+//! to a different instance — can replay what it missed.
+//!
+//! Two implementations:
+//! - [`InMemoryBackplane`] — single process (local dev, tests). Ids
+//!   come from a per-channel counter; the log is a bounded ring
+//!   buffer, dropped once the channel has been idle for
+//!   [`CHANNEL_LOG_TTL_SECS`].
+//! - [`DynamoStreamBackplane`] — multi-instance. Ids come from an
+//!   atomic counter row; each event is written as an
+//!   `RealtimeChannelEvent` row. Those rows are the shared log: every
+//!   instance tails it (see [`Backplane::tail_interval`]) for the
+//!   channels it holds subscribers on, and replays from it on
+//!   reconnect.
+//!
+//! Selection happens once in [`crate::common::realtime::hub`].
+
+use crate::common::*;
+use crate::common::realtime::RealtimeError;
+use crate::common::realtime::models::{
+    CHANNEL_CURSOR_TTL_SECS, CHANNEL_LOG_TTL_SECS, RealtimeChannelCursor, RealtimeChannelEvent,
+};
+use crate::common::realtime::channel::{ChannelId, ServerEvent};
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Upper bound on events returned by one replay. A client further
+/// behind than this re-renders from the `subscribed` snapshot
+/// instead.
+pub const REPLAY_LIMIT: usize = 200;
+
+/// How often a hub reads the Dynamo log for a channel it holds
+/// subscribers on.
+pub const TAIL_INTERVAL: Duration = Duration::from_secs(1);
+
+#[async_trait]
+pub trait Backplane: Send + Sync + 'static {
+    /// Assign the next id for `channel`, persist the event to the
+    /// channel log, and return it. Local delivery is the hub's job.
+    async fn append(
+        &self,
+        channel: &ChannelId,
+        name: &str,
+        payload: serde_json::Value,
+    ) -> crate::common::Result<ServerEvent>;
+
+    /// Events on `channel` with `id > last_id`, oldest first, capped
+    /// at [`REPLAY_LIMIT`] (the most recent ones win). A `last_id`
+    /// past the newest event means the log expired and its ids
+    /// restarted, so the whole log comes back.
+    async fn since(
+        &self,
+        channel: &ChannelId,
+        last_id: u64,
+    ) -> crate::common::Result<Vec<ServerEvent>>;
+
+    /// Id of the newest event on `channel`; `0` for an empty log.
+    async fn head(&self, channel: &ChannelId) -> crate::common::Result<u64>;
+
+    /// How often a hub should poll [`Self::since`] for events other
+    /// processes appended. `None` when every publisher shares this
+    /// process's hub.
+    fn tail_interval(&self) -> Option<Duration> {
+        None
+    }
+}
+
+/// `events` are oldest first. Applies the `last_id` rule of
+/// [`Backplane::since`].
+fn missed_since(events: Vec<ServerEvent>, last_id: u64) -> Vec<ServerEvent> {
+    let newest = events.last().map(|e| e.id).unwrap_or(0);
+    let last_id = if last_id > newest { 0 } else { last_id };
+    let missed: Vec<ServerEvent> = events.into_iter().filter(|e| e.id > last_id).collect();
+    let skip = missed.len().saturating_sub(REPLAY_LIMIT);
+    missed.into_iter().skip(skip).collect()
+}
+
+// ── In-memory ───────────────────────────────────────────────────────
+
+struct ChannelLog {
+    next_id: u64,
+    events: VecDeque<ServerEvent>,
+    appended_at: Instant,
+}
+
+impl Default for ChannelLog {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            events: VecDeque::new(),
+            appended_at: Instant::now(),
+        }
+    }
+}
+
+/// Single-process backplane. Log retention is `log_capacity` events
+/// per channel; older events are dropped from the front, and a
+/// channel with no append for `idle_ttl` is dropped on the next
+/// append to any channel.
+#[derive(Clone)]
+pub struct InMemoryBackplane {
+    logs: Arc<Mutex<HashMap<ChannelId, ChannelLog>>>,
+    log_capacity: usize,
+    idle_ttl: Duration,
+}
+
+impl InMemoryBackplane {
+    pub fn new() -> Self {
+        Self::with_log_capacity(REPLAY_LIMIT)
+    }
+
+    pub fn with_log_capacity(log_capacity: usize) -> Self {
+        Self {
+            logs: Arc::new(Mutex::new(HashMap::new())),
+            log_capacity: log_capacity.max(1),
+            idle_ttl: Duration::from_secs(CHANNEL_LOG_TTL_SECS as u64),
+        }
+    }
+
+    pub fn with_idle_ttl(mut self, idle_ttl: Duration) -> Self {
+        self.idle_ttl = idle_ttl;
+        self
+    }
+}
+
+impl Default for InMemoryBackplane {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl Backplane for InMemoryBackplane {
+    async fn append(
+        &self,
+        channel: &ChannelId,
+        name: &str,
+        payload: serde_json::Value,
+    ) -> crate::common::Result<ServerEvent> {
+        let mut logs = self.logs.lock().await;
+        logs.retain(|_, log| log.appended_at.elapsed() < self.idle_ttl);
+        let log = logs.entry(channel.clone()).or_default();
+        log.appended_at = Instant::now();
+        log.next_id += 1;
+        let evt = ServerEvent {
+            id: log.next_id,
+            name: name.to_string(),
+            channel: channel.clone(),
+            payload,
+        };
+        if log.events.len() == self.log_capacity {
+            log.events.pop_front();
+        }
+        log.events.push_back(evt.clone());
+        Ok(evt)
+    }
+
+    async fn since(
+        &self,
+        channel: &ChannelId,
+        last_id: u64,
+    ) -> crate::common::Result<Vec<ServerEvent>> {
+        let logs = self.logs.lock().await;
+        let Some(log) = logs.get(channel) else {
+            return Ok(Vec::new());
+        };
+        Ok(missed_since(log.events.iter().cloned().collect(), last_id))
+    }
+
+    async fn head(&self, channel: &ChannelId) -> crate::common::Result<u64> {
+        let logs = self.logs.lock().await;
+        Ok(logs.get(channel).map(|log| log.next_id).unwrap_or(0))
+    }
+}
+
+// ── DynamoDB + Streams ──────────────────────────────────────────────
+
+/// Multi-instance backplane. `append` bumps the channel cursor and
+/// writes one `RealtimeChannelEvent` row; other instances pick the
+/// row up on their next tail of the channel.
+#[derive(Clone)]
+pub struct DynamoStreamBackplane {
+    cli: aws_sdk_dynamodb::Client,
+}
+
+impl DynamoStreamBackplane {
+    pub fn new(cli: aws_sdk_dynamodb::Client) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl Backplane for DynamoStreamBackplane {
+    async fn append(
+        &self,
+        channel: &ChannelId,
+        name: &str,
+        payload: serde_json::Value,
+    ) -> crate::common::Result<ServerEvent> {
+        let (pk, sk) = RealtimeChannelCursor::keys(channel);
+        let now_secs = crate::common::utils::time::get_now_timestamp_millis() / 1000;
+        let cursor = RealtimeChannelCursor::updater(pk, sk)
+            .increase_last_seq(1)
+            .with_expires_at(now_secs + CHANNEL_CURSOR_TTL_SECS)
+            .execute(&self.cli)
+            .await
+            .map_err(|e| {
+                crate::error!("realtime backplane cursor bump failed: {e}");
+                RealtimeError::StorageFailure
+            })?;
+        let seq = cursor.last_seq.max(1) as u64;
+
+        let row = RealtimeChannelEvent::new(channel, seq, name, &payload);
+        row.create(&self.cli).await.map_err(|e| {
+            crate::error!("realtime backplane event write failed: {e}");
+            RealtimeError::StorageFailure
+        })?;
+
+        Ok(ServerEvent {
+            id: seq,
+            name: name.to_string(),
+            channel: channel.clone(),
+            payload,
+        })
+    }
+
+    async fn since(
+        &self,
+        channel: &ChannelId,
+        last_id: u64,
+    ) -> crate::common::Result<Vec<ServerEvent>> {
+        // sk-descending (the query default) so the page holds the
+        // newest events; it is flipped back to oldest-first and
+        // anything at or below `last_id` is cut off client-side.
+        let pk = Partition::RealtimeChannel(channel.0.clone());
+        let opts = RealtimeChannelEvent::opt()
+            .sk("REALTIME_CHANNEL_EVENT".to_string())
+            .limit(REPLAY_LIMIT as i32);
+        let (rows, _) = RealtimeChannelEvent::query(&self.cli, pk, opts)
+            .await
+            .map_err(|e| {
+                crate::error!("realtime backplane replay query failed: {e}");
+                RealtimeError::StorageFailure
+            })?;
+
+        let mut events: Vec<ServerEvent> = rows
+            .into_iter()
+            .map(RealtimeChannelEvent::into_server_event)
+            .collect();
+        events.sort_by_key(|e| e.id);
+        Ok(missed_since(events, last_id))
+    }
+
+    async fn head(&self, channel: &ChannelId) -> crate::common::Result<u64> {
+        let (pk, sk) = RealtimeChannelCursor::keys(channel);
+        let cursor = RealtimeChannelCursor::get(&self.cli, pk, Some(sk))
+            .await
+            .map_err(|e| {
+                crate::error!("realtime backplane cursor read failed: {e}");
+                RealtimeError::StorageFailure
+            })?;
+        Ok(cursor.map(|c| c.last_seq.max(0) as u64).unwrap_or(0))
+    }
+
+    fn tail_interval(&self) -> Option<Duration> {
+        Some(TAIL_INTERVAL)
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn in_memory_ids_are_per_channel() {
+        let bp = InMemoryBackplane::new();
+        let a = ChannelId("echo:a".into());
+        let b = ChannelId("echo:b".into());
+
+        assert_eq!(bp.append(&a, "t", serde_json::json!(1)).await.unwrap().id, 1);
+        assert_eq!(bp.append(&a, "t", serde_json::json!(2)).await.unwrap().id, 2);
+        assert_eq!(bp.append(&b, "t", serde_json::json!(3)).await.unwrap().id, 1);
+    }
+
+    #[tokio::test]
+    async fn in_memory_since_returns_only_missed_events() {
+        let bp = InMemoryBackplane::new();
+        let ch = ChannelId("echo:room".into());
+        for n in 0..5 {
+            bp.append(&ch, "tick", serde_json::json!({ "n": n })).await.unwrap();
+        }
+
+        let missed = bp.since(&ch, 3).await.unwrap();
+        let ids: Vec<u64> = missed.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![4, 5]);
+
+        assert!(bp.since(&ch, 5).await.unwrap().is_empty());
+        assert!(bp.since(&ChannelId("echo:none".into()), 0).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn in_memory_log_is_bounded() {
+        let bp = InMemoryBackplane::with_log_capacity(3);
+        let ch = ChannelId("echo:room".into());
+        for n in 0..10 {
+            bp.append(&ch, "tick", serde_json::json!(n)).await.unwrap();
+        }
+
+        let ids: Vec<u64> = bp.since(&ch, 0).await.unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![8, 9, 10]);
+    }
+
+    #[tokio::test]
+    async fn in_memory_since_past_head_returns_whole_log() {
+        let bp = InMemoryBackplane::new();
+        let ch = ChannelId("echo:room".into());
+        for n in 0..3 {
+            bp.append(&ch, "tick", serde_json::json!(n)).await.unwrap();
+        }
+
+        // A client still holding an id from before the log restarted.
+        let ids: Vec<u64> = bp.since(&ch, 40).await.unwrap().iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(bp.head(&ch).await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn in_memory_idle_channels_are_dropped() {
+        let bp = InMemoryBackplane::new().with_idle_ttl(Duration::ZERO);
+        let idle = ChannelId("echo:idle".into());
+        let busy = ChannelId("echo:busy".into());
+        bp.append(&idle, "tick", serde_json::json!(1)).await.unwrap();
+        bp.append(&busy, "tick", serde_json::json!(2)).await.unwrap();
+
+        assert!(bp.since(&idle, 0).await.unwrap().is_empty());
+        assert_eq!(bp.head(&idle).await.unwrap(), 0);
+        assert_eq!(bp.logs.lock().await.len(), 1);
+    }
+}
@@ -0,0 +1,477 @@
+//! Realtime channel — Ratel-wide pub/sub boundary (이음매 2).
+//!
+//! Transport-agnostic. Publishes go through a pluggable
+//! [`Backplane`] (id assignment + durable per-channel log) and then
+//! to an in-process `tokio::sync::broadcast` hub keyed by
+//! `ChannelId`. The SSE endpoint (`/api/realtime/events`) drains
+//! subscriber receivers into a `text/event-stream` response, and
+//! the polling endpoint (`/api/realtime/poll`) is the fallback when
+//! SSE is unavailable.
+//!
+//! Started life as `arcade/realtime/` (design doc 2026-05-15 §
+//! A2/A2'). It never imported feature code, so it moved here as-is
+//! once live comments and inbox counts needed the same push path.
+//! Channel kinds are registered by their owning feature.
+
+use crate::common::*;
+use crate::common::realtime::RealtimeError;
+use crate::common::realtime::backplane::{Backplane, InMemoryBackplane};
+use async_trait::async_trait;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+use tokio::sync::{Mutex, broadcast};
+
+// ── Identifiers ─────────────────────────────────────────────────────
+
+/// Channel id in the form `<kind>:<inner>` (e.g. `"fof.round:abc"`,
+/// `"user:alice"`). `kind` selects the registered `RoomChannel`
+/// implementation; `inner` is opaque to the framework.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChannelId(pub String);
+
+impl ChannelId {
+    pub fn kind(&self) -> &str {
+        self.0.splitn(2, ':').next().unwrap_or("")
+    }
+    pub fn inner(&self) -> &str {
+        self.0.splitn(2, ':').nth(1).unwrap_or("")
+    }
+    pub fn from_parts(kind: &str, inner: &str) -> Self {
+        ChannelId(format!("{kind}:{inner}"))
+    }
+}
+
+/// Caller identity passed to every `RoomChannel` callback. Decoupled
+/// from `common::models::auth::User` so the trait stays trivially
+/// testable.
+#[derive(Debug, Clone)]
+pub struct ChannelContext {
+    pub user_id: String,
+}
+
+impl ChannelContext {
+    /// `USER#{id}` → `{id}`; the form every `authorize` hook compares
+    /// against.
+    pub fn from_user_pk(pk: &Partition) -> Self {
+        let pk = pk.to_string();
+        Self {
+            user_id: pk.strip_prefix("USER#").unwrap_or(&pk).to_string(),
+        }
+    }
+}
+
+// ── Server → client event ──────────────────────────────────────────
+
+/// One server-to-client event. Carried inside an SSE `data:` field
+/// (PR4e) or a polling response (`GET /api/realtime/poll`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ServerEvent {
+    /// Monotonic per-channel sequence. SSE clients send the last seen
+    /// `id` via `Last-Event-ID` on reconnect; polling clients pass
+    /// `?since={id}`.
+    pub id: u64,
+    /// Event name within the channel (e.g. "stage_changed").
+    pub name: String,
+    /// Channel id this event belongs to. Echoed so a multi-channel
+    /// subscriber can demux client-side.
+    pub channel: ChannelId,
+    /// JSON payload. Game-specific event types deserialize from this.
+    pub payload: serde_json::Value,
+}
+
+// ── Trait ───────────────────────────────────────────────────────────
+
+/// One implementation per channel kind. The framework looks up the
+/// registered handler by `kind()` and routes subscribe / publish
+/// calls through it.
+#[async_trait]
+pub trait RoomChannel: Send + Sync + 'static {
+    /// Channel kind. Must match the prefix in `ChannelId` (e.g.
+    /// `"fof.round"` for `fof.round:abc`).
+    fn kind(&self) -> &'static str;
+
+    /// Subscribe request. Caller has already authenticated; this is
+    /// the per-channel authorization + initial-state hook.
+    /// Returns the snapshot the client should render before any
+    /// streamed event arrives.
+    async fn authorize(
+        &self,
+        ctx: &ChannelContext,
+        channel: &ChannelId,
+        params: serde_json::Value,
+    ) -> crate::common::Result<serde_json::Value>;
+
+    /// Optional per-subscriber redaction hook. Called once per
+    /// (subscriber, event) before delivery. Return `None` to drop
+    /// the event for that subscriber (e.g. insider info on FOF).
+    /// Default: deliver as-is.
+    async fn before_publish(
+        &self,
+        _channel: &ChannelId,
+        _subscriber: &ChannelContext,
+        event: ServerEvent,
+    ) -> Option<ServerEvent> {
+        Some(event)
+    }
+}
+
+// ── In-process hub ──────────────────────────────────────────────────
+
+/// Per-channel state: `tokio::sync::broadcast` sender plus a short
+/// window of recently delivered ids. The window makes `deliver`
+/// idempotent — with the DynamoDB backplane the publishing instance
+/// delivers locally *and* later reads its own event back from the
+/// log. Only channels with a live receiver keep a state; the rest are
+/// evicted (see [`InProcessChannel::subscribe_stream`]).
+struct ChannelState {
+    tx: broadcast::Sender<ServerEvent>,
+    recent_ids: VecDeque<u64>,
+    /// Tells a log tail whether it still owns this state or the
+    /// channel was evicted and re-created under it.
+    generation: u64,
+}
+
+impl ChannelState {
+    fn new(capacity: usize, generation: u64) -> Self {
+        let (tx, _) = broadcast::channel(capacity);
+        Self {
+            tx,
+            recent_ids: VecDeque::with_capacity(capacity),
+            generation,
+        }
+    }
+}
+
+/// In-memory pub/sub hub. One process, one hub. Ids and the replay
+/// log come from the [`Backplane`]; events appended by other
+/// instances arrive through a per-channel tail of that log, which
+/// runs while this process holds subscribers on the channel.
+#[derive(Clone)]
+pub struct InProcessChannel {
+    handlers: Arc<Mutex<HashMap<&'static str, Arc<dyn RoomChannel>>>>,
+    channels: Arc<Mutex<HashMap<ChannelId, ChannelState>>>,
+    generations: Arc<AtomicU64>,
+    backplane: Arc<dyn Backplane>,
+    /// Broadcast channel capacity per (ChannelId). Slow subscribers
+    /// that lag past this lose events; the SSE wrapper will drop the
+    /// connection so the client reconnects + replays via
+    /// `Last-Event-ID`.
+    capacity: usize,
+}
+
+impl InProcessChannel {
+    pub fn new() -> Self {
+        Self::with_capacity(64)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_backplane(Arc::new(InMemoryBackplane::new()), capacity)
+    }
+
+    pub fn with_backplane(backplane: Arc<dyn Backplane>, capacity: usize) -> Self {
+        Self {
+            handlers: Arc::new(Mutex::new(HashMap::new())),
+            channels: Arc::new(Mutex::new(HashMap::new())),
+            generations: Arc::new(AtomicU64::new(0)),
+            backplane,
+            capacity,
+        }
+    }
+
+    /// Register a handler. Last registration wins so tests can swap
+    /// in mocks.
+    pub async fn register<H: RoomChannel>(&self, handler: H) {
+        let mut h = self.handlers.lock().await;
+        h.insert(handler.kind(), Arc::new(handler));
+    }
+
+    /// Resolve a handler by channel kind, or return
+    /// `RealtimeError::ChannelUnknown`.
+    pub async fn handler_for(
+        &self,
+        channel: &ChannelId,
+    ) -> crate::common::Result<Arc<dyn RoomChannel>> {
+        let h = self.handlers.lock().await;
+        h.get(channel.kind())
+            .cloned()
+            .ok_or_else(|| RealtimeError::ChannelUnknown.into())
+    }
+
+    /// Authorize a subscribe and return the initial snapshot.
+    /// Caller is responsible for subsequently attaching to
+    /// [`Self::subscribe_stream`].
+    pub async fn authorize_subscribe(
+        &self,
+        ctx: &ChannelContext,
+        channel: &ChannelId,
+        params: serde_json::Value,
+    ) -> crate::common::Result<serde_json::Value> {
+        let handler = self.handler_for(channel).await?;
+        handler.authorize(ctx, channel, params).await
+    }
+
+    /// Attach a fresh receiver to the channel's broadcast bus. The
+    /// SSE response layer (PR4e) will drain this into the response.
+    ///
+    /// Channels whose receivers have all been dropped are evicted
+    /// here. The first subscriber of a channel starts tailing its
+    /// backplane log when the backplane asks for it.
+    pub async fn subscribe_stream(
+        &self,
+        channel: &ChannelId,
+    ) -> broadcast::Receiver<ServerEvent> {
+        {
+            let mut chans = self.channels.lock().await;
+            chans.retain(|_, state| state.tx.receiver_count() > 0);
+            if let Some(state) = chans.get(channel) {
+                return state.tx.subscribe();
+            }
+        }
+
+        // Read the log head before the channel exists here, so the
+        // tail covers everything appended after this subscribe.
+        let tail = match self.backplane.tail_interval() {
+            Some(every) => Some((every, self.backplane.head(channel).await.ok())),
+            None => None,
+        };
+
+        let mut chans = self.channels.lock().await;
+        if let Some(state) = chans.get(channel) {
+            return state.tx.subscribe();
+        }
+        let generation = self.generations.fetch_add(1, Ordering::Relaxed);
+        let state = ChannelState::new(self.capacity, generation);
+        let rx = state.tx.subscribe();
+        chans.insert(channel.clone(), state);
+        drop(chans);
+
+        if let Some((every, head)) = tail {
+            tokio::spawn(self.clone().tail(channel.clone(), generation, every, head));
+        }
+        rx
+    }
+
+    /// Poll the backplane log of `channel` every `every` and deliver
+    /// what other instances appended after `last_id` (the head at
+    /// subscribe time; `None` until it could be read). Anything older
+    /// is the `subscribed` snapshot's or `Last-Event-ID` replay's
+    /// job. Stops, evicting the channel, once its last receiver is
+    /// gone.
+    async fn tail(
+        self,
+        channel: ChannelId,
+        generation: u64,
+        every: Duration,
+        mut last_id: Option<u64>,
+    ) {
+        loop {
+            tokio::time::sleep(every).await;
+            {
+                let mut chans = self.channels.lock().await;
+                match chans.get(&channel) {
+                    Some(state) if state.generation == generation => {
+                        if state.tx.receiver_count() == 0 {
+                            chans.remove(&channel);
+                            return;
+                        }
+                    }
+                    _ => return,
+                }
+            }
+
+            let Some(from) = last_id else {
+                last_id = self.backplane.head(&channel).await.ok();
+                continue;
+            };
+            let events = match self.backplane.since(&channel, from).await {
+                Ok(events) => events,
+                Err(e) => {
+                    crate::warn!("realtime tail of {} failed: {e}", channel.0);
+                    continue;
+                }
+            };
+            if let Some(newest) = events.last() {
+                last_id = Some(newest.id);
+            }
+            for evt in events {
+                self.deliver(evt).await;
+            }
+        }
+    }
+
+    /// Publish an event to every subscriber of `channel`. The
+    /// backplane assigns the id and records the event in the channel
+    /// log; this process's subscribers get it immediately, other
+    /// instances on their next tail of the log.
+    ///
+    /// `name` is the event name, `payload` is the JSON payload.
+    pub async fn publish(
+        &self,
+        channel: &ChannelId,
+        name: &str,
+        payload: serde_json::Value,
+    ) -> crate::common::Result<u64> {
+        let evt = self.backplane.append(channel, name, payload).await?;
+        let id = evt.id;
+        self.deliver(evt).await;
+        Ok(id)
+    }
+
+    /// Hand an already-appended event to this process's subscribers.
+    /// Called by `publish` and by the log tail for events appended on
+    /// other instances. Ids seen within the last `capacity`
+    /// deliveries are dropped, so a redelivery is a no-op. A channel
+    /// nobody here subscribes to has no state and is skipped.
+    pub async fn deliver(&self, evt: ServerEvent) {
+        let mut chans = self.channels.lock().await;
+        let Some(state) = chans.get_mut(&evt.channel) else {
+            return;
+        };
+        if state.recent_ids.contains(&evt.id) {
+            return;
+        }
+        if state.recent_ids.len() == self.capacity {
+            state.recent_ids.pop_front();
+        }
+        state.recent_ids.push_back(evt.id);
+        // `send` errs only when there are zero receivers — that's
+        // expected for channels that no one is watching, so ignore.
+        let _ = state.tx.send(evt);
+    }
+
+    /// Events on `channel` newer than `last_id`, oldest first. Backs
+    /// `Last-Event-ID` replay on SSE reconnect.
+    pub async fn replay_since(
+        &self,
+        channel: &ChannelId,
+        last_id: u64,
+    ) -> crate::common::Result<Vec<ServerEvent>> {
+        self.backplane.since(channel, last_id).await
+    }
+}
+
+impl Default for InProcessChannel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct EchoChannel;
+
+    #[async_trait]
+    impl RoomChannel for EchoChannel {
+        fn kind(&self) -> &'static str {
+            "echo"
+        }
+        async fn authorize(
+            &self,
+            _ctx: &ChannelContext,
+            channel: &ChannelId,
+            _params: serde_json::Value,
+        ) -> crate::common::Result<serde_json::Value> {
+            Ok(serde_json::json!({ "subscribed": channel.inner() }))
+        }
+    }
+
+    #[test]
+    fn channel_id_split() {
+        let c = ChannelId("fof.round:abc".into());
+        assert_eq!(c.kind(), "fof.round");
+        assert_eq!(c.inner(), "abc");
+
+        let c2 = ChannelId::from_parts("user", "alice");
+        assert_eq!(c2.0, "user:alice");
+    }
+
+    #[tokio::test]
+    async fn handler_resolution_by_kind() {
+        let hub = InProcessChannel::new();
+        hub.register(EchoChannel).await;
+
+        let ok = hub
+            .handler_for(&ChannelId("echo:foo".into()))
+            .await
+            .is_ok();
+        assert!(ok);
+
+        let err = hub
+            .handler_for(&ChannelId("unknown:foo".into()))
+            .await
+            .is_err();
+        assert!(err);
+    }
+
+    #[tokio::test]
+    async fn publish_assigns_monotonic_ids_and_fans_out() {
+        let hub = InProcessChannel::new();
+        let ch = ChannelId("echo:room1".into());
+        let mut rx = hub.subscribe_stream(&ch).await;
+
+        let id_a = hub.publish(&ch, "tick", serde_json::json!({"n": 1})).await.unwrap();
+        let id_b = hub.publish(&ch, "tick", serde_json::json!({"n": 2})).await.unwrap();
+        assert_eq!(id_a, 1);
+        assert_eq!(id_b, 2);
+
+        let e1 = rx.recv().await.unwrap();
+        let e2 = rx.recv().await.unwrap();
+        assert_eq!(e1.id, 1);
+        assert_eq!(e2.id, 2);
+        assert_eq!(e1.name, "tick");
+    }
+
+    #[tokio::test]
+    async fn deliver_drops_redelivered_ids() {
+        let hub = InProcessChannel::new();
+        let ch = ChannelId("echo:room1".into());
+        let mut rx = hub.subscribe_stream(&ch).await;
+
+        let id = hub.publish(&ch, "tick", serde_json::json!({})).await.unwrap();
+        // Same event read back by the log tail.
+        hub.deliver(ServerEvent {
+            id,
+            name: "tick".into(),
+            channel: ch.clone(),
+            payload: serde_json::json!({}),
+        })
+        .await;
+        hub.publish(&ch, "tock", serde_json::json!({})).await.unwrap();
+
+        assert_eq!(rx.recv().await.unwrap().name, "tick");
+        assert_eq!(rx.recv().await.unwrap().name, "tock");
+    }
+
+    #[tokio::test]
+    async fn replay_since_returns_missed_events() {
+        let hub = InProcessChannel::new();
+        let ch = ChannelId("echo:room1".into());
+        for n in 0..3 {
+            hub.publish(&ch, "tick", serde_json::json!({ "n": n })).await.unwrap();
+        }
+
+        let missed = hub.replay_since(&ch, 1).await.unwrap();
+        let ids: Vec<u64> = missed.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+    }
+
+    #[tokio::test]
+    async fn channels_without_receivers_are_evicted() {
+        let hub = InProcessChannel::new();
+        let gone = ChannelId("echo:gone".into());
+        let live = ChannelId("echo:live".into());
+
+        drop(hub.subscribe_stream(&gone).await);
+        let _rx = hub.subscribe_stream(&live).await;
+
+        let chans = hub.channels.lock().await;
+        assert!(!chans.contains_key(&gone));
+        assert!(chans.contains_key(&live));
+    }
+}
@@ -0,0 +1,66 @@
+//! `GET /api/realtime/poll?channel=&since=` — polling fallback for
+//! clients that can't hold an SSE connection. Same authorize hook
+//! as the SSE endpoint; events come from the backplane's channel
+//! log, so a poll served by any instance sees every event.
+
+use crate::common::realtime::types::*;
+use crate::common::*;
+
+#[cfg(feature = "server")]
+use crate::common::models::auth::User;
+#[cfg(feature = "server")]
+use crate::common::realtime::channel::{ChannelContext, ChannelId};
+#[cfg(feature = "server")]
+use crate::common::realtime::hub::global_hub;
+#[cfg(feature = "server")]
+use crate::common::realtime::RealtimeError;
+
+#[get("/api/realtime/poll?channel&since", user: User)]
+pub async fn poll_channel_handler(
+    channel: String,
+    since: Option<u64>,
+) -> Result<RealtimePollResponse> {
+    let hub = global_hub();
+    let channel = ChannelId(channel);
+    let handler = hub.handler_for(&channel).await?;
+
+    // Authorize on every poll — membership can change between polls
+    // and there is no connection to pin the first check to.
+    let ctx = ChannelContext::from_user_pk(&user.pk);
+    let snapshot = handler
+        .authorize(&ctx, &channel, serde_json::Value::Null)
+        .await
+        .map_err(|_| RealtimeError::ChannelForbidden)?;
+
+    match since {
+        None => {
+            // First poll: snapshot plus the current head of the log so
+            // the next poll only returns what happens from here on.
+            let last_id = hub
+                .replay_since(&channel, 0)
+                .await?
+                .last()
+                .map(|e| e.id)
+                .unwrap_or(0);
+            Ok(RealtimePollResponse {
+                initial_state: Some(snapshot),
+                events: Vec::new(),
+                last_id,
+            })
+        }
+        Some(since) => {
+            let events: Vec<RealtimeEvent> = hub
+                .replay_since(&channel, since)
+                .await?
+                .into_iter()
+                .map(RealtimeEvent::from)
+                .collect();
+            let last_id = events.last().map(|e| e.id).unwrap_or(since);
+            Ok(RealtimePollResponse {
+                initial_state: None,
+                events,
+                last_id,
+            })
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::common::*;
+pub use thiserror::Error;
+
+#[derive(Debug, Error, Serialize, Deserialize, Translate, Clone)]
+pub enum RealtimeError {
+    #[error("channel not registered")]
+    #[translate(
+        en = "Unknown channel",
+        ko = "알 수 없는 채널입니다."
+    )]
+    ChannelUnknown,
+
+    #[error("not allowed to subscribe to this channel")]
+    #[translate(
+        en = "You are not allowed to access this channel",
+        ko = "이 채널을 구독할 권한이 없습니다."
+    )]
+    ChannelForbidden,
+
+    #[error("channel payload invalid")]
+    #[translate(
+        en = "Channel payload is invalid",
+        ko = "채널 페이로드가 올바르지 않습니다."
+    )]
+    ChannelPayloadInvalid,
+
+    #[error("realtime storage failure")]
+    #[translate(
+        en = "Storage operation failed",
+        ko = "저장 작업에 실패했습니다."
+    )]
+    StorageFailure,
+}
+
+#[cfg(feature = "server")]
+impl RealtimeError {
+    pub fn status_code(&self) -> crate::axum::http::StatusCode {
+        use crate::axum::http::StatusCode;
+        match self {
+            RealtimeError::ChannelUnknown => StatusCode::NOT_FOUND,
+            RealtimeError::ChannelForbidden => StatusCode::FORBIDDEN,
+            RealtimeError::ChannelPayloadInvalid => StatusCode::BAD_REQUEST,
+            RealtimeError::StorageFailure => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::axum::response::IntoResponse for RealtimeError {
+    fn into_response(self) -> crate::axum::response::Response {
+        use crate::axum::response::IntoResponse;
+        (self.status_code(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::AsStatusCode for RealtimeError {
+    fn as_status_code(&self) -> crate::axum::http::StatusCode {
+        self.status_code()
+    }
+}
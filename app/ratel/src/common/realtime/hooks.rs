@@ -0,0 +1,135 @@
+//! Client-side subscription to a realtime channel.
+//!
+//! Opens an EventSource on `/api/realtime/events` through a
+//! `document::eval` channel (`web/subscribe.js`). When the browser
+//! can't hold the stream — no EventSource, an HTTP error, or a
+//! WebView that refuses it — the hook falls back to
+//! `GET /api/realtime/poll` every `POLL_FALLBACK_SECS`, resuming from
+//! the last id it saw.
+//!
+//! ```ignore
+//! use_realtime_channel(format!("user.inbox:{user_id}"), &["inbox_changed"], move |_| {
+//!     count.restart();
+//! });
+//! ```
+//!
+//! The channel is fixed for the lifetime of the calling scope; key
+//! the component on the channel id if it can change. Server builds
+//! compile the hook to a no-op.
+
+use crate::common::realtime::types::RealtimeEvent;
+use crate::common::*;
+
+/// Poll cadence when SSE is unavailable. Matches the interval the
+/// notification badge used to poll at, so the fallback never costs more
+/// than the polling it replaced.
+pub const POLL_FALLBACK_SECS: u64 = 60;
+
+/// Marker the JS side sends when the EventSource is gone for good.
+const CLOSED_EVENT: &str = "__closed";
+
+pub fn use_realtime_channel<F>(channel: String, events: &'static [&'static str], on_event: F)
+where
+    F: FnMut(RealtimeEvent) + 'static,
+{
+    let on_event = std::rc::Rc::new(std::cell::RefCell::new(on_event));
+
+    use_future(move || {
+        let channel = channel.clone();
+        let on_event = on_event.clone();
+        async move {
+            subscribe_realtime_channel(channel, events, move |evt| {
+                (on_event.borrow_mut())(evt)
+            })
+            .await;
+        }
+    });
+}
+
+/// Non-hook form of [`use_realtime_channel`] for callers that already
+/// own a spawned task (e.g. a `use_hook`-installed context). Runs
+/// until the task is dropped.
+pub async fn subscribe_realtime_channel(
+    channel: String,
+    events: &'static [&'static str],
+    mut on_event: impl FnMut(RealtimeEvent),
+) {
+    #[cfg(not(feature = "server"))]
+    {
+        let last_id = stream_sse(&channel, events, &mut on_event).await;
+        poll_loop(&channel, last_id, &mut on_event).await;
+    }
+    #[cfg(feature = "server")]
+    let _ = (channel, events, &mut on_event);
+}
+
+#[cfg(not(feature = "server"))]
+fn events_url(channel: &str) -> String {
+    // Tauri WebView origin is `tauri.localhost`; the stream has to go
+    // to the real backend (same base the tauri-web transport uses).
+    #[cfg(feature = "tauri-web")]
+    let base = option_env!("MOBILE_API_URL").unwrap_or("https://dev.ratel.foundation");
+    #[cfg(not(feature = "tauri-web"))]
+    let base = "";
+
+    format!(
+        "{base}/api/realtime/events?channel={}",
+        percent_encoding::utf8_percent_encode(channel, percent_encoding::NON_ALPHANUMERIC)
+    )
+}
+
+/// Pump SSE events into `emit` until the stream closes for good.
+/// Returns the last event id seen so polling can resume from it.
+#[cfg(not(feature = "server"))]
+async fn stream_sse(
+    channel: &str,
+    events: &'static [&'static str],
+    emit: &mut impl FnMut(RealtimeEvent),
+) -> Option<u64> {
+    let mut runner = document::eval(include_str!("web/subscribe.js"));
+    if runner
+        .send(serde_json::json!({ "url": events_url(channel), "events": events }))
+        .is_err()
+    {
+        return None;
+    }
+
+    let mut last_id = None;
+    while let Ok(evt) = runner.recv::<RealtimeEvent>().await {
+        if evt.name == CLOSED_EVENT {
+            break;
+        }
+        if evt.id > 0 {
+            last_id = Some(evt.id);
+        }
+        emit(evt);
+    }
+    last_id
+}
+
+#[cfg(not(feature = "server"))]
+async fn poll_loop(channel: &str, mut since: Option<u64>, emit: &mut impl FnMut(RealtimeEvent)) {
+    use crate::common::realtime::controllers::poll_channel_handler;
+
+    loop {
+        match poll_channel_handler(channel.to_string(), since).await {
+            Ok(resp) => {
+                if let Some(state) = resp.initial_state {
+                    emit(RealtimeEvent {
+                        id: 0,
+                        name: "subscribed".to_string(),
+                        channel: channel.to_string(),
+                        payload: state,
+                    });
+                }
+                for evt in resp.events {
+                    emit(evt);
+                }
+                since = Some(resp.last_id);
+            }
+            Err(e) => debug!("realtime poll {channel} failed: {e}"),
+        }
+        crate::common::utils::time::sleep(std::time::Duration::from_secs(POLL_FALLBACK_SECS))
+            .await;
+    }
+}
@@ -0,0 +1,48 @@
+//! Global `InProcessChannel` singleton.
+//!
+//! The hub itself is per-process: it only reaches the SSE
+//! subscribers this process holds. Cross-instance fan-out is the
+//! backplane's job (see [`crate::common::realtime::backplane`]):
+//!
+//! - `REALTIME_BACKPLANE=dynamo` — every publish is appended
+//!   as a `RealtimeChannelEvent` row. Each instance tails the rows of
+//!   the channels it holds subscribers on and delivers them through
+//!   `InProcessChannel::deliver`; the same rows serve `Last-Event-ID`
+//!   replay on any instance.
+//! - anything else (default) — in-memory backplane. Right for local
+//!   dev (`cargo run`) and tests, where there is exactly one process.
+//!
+//! The value is baked in at build time: the Makefile passes
+//! `REALTIME_BACKPLANE` through `BUILD_ENV` (default `memory`) and the
+//! dev/prod workflows set it to `dynamo`.
+
+use crate::common::realtime::backplane::{
+    Backplane, DynamoStreamBackplane, InMemoryBackplane,
+};
+use crate::common::realtime::channel::InProcessChannel;
+use std::sync::{Arc, LazyLock};
+
+/// Broadcast capacity per channel (see `InProcessChannel::capacity`).
+const HUB_CAPACITY: usize = 64;
+
+/// One hub per process. Cloning is cheap (Arc-wrapped state inside
+/// `InProcessChannel`), so callers should clone freely rather than
+/// holding a long-lived borrow on the static.
+static HUB: LazyLock<InProcessChannel> =
+    LazyLock::new(|| InProcessChannel::with_backplane(select_backplane(), HUB_CAPACITY));
+
+fn select_backplane() -> Arc<dyn Backplane> {
+    match option_env!("REALTIME_BACKPLANE") {
+        Some("dynamo") => {
+            let cfg = crate::common::CommonConfig::default();
+            Arc::new(DynamoStreamBackplane::new(cfg.dynamodb().clone()))
+        }
+        _ => Arc::new(InMemoryBackplane::new()),
+    }
+}
+
+/// Clone of the process-wide hub. Use this everywhere — from HTTP
+/// handlers that publish and from SSE handlers that subscribe.
+pub fn global_hub() -> InProcessChannel {
+    HUB.clone()
+}
@@ -0,0 +1,48 @@
+//! Ratel-wide realtime channels.
+//!
+//! One pub/sub boundary for every live surface: arcade rooms, space
+//! comments, space status, the notification inbox. Clients subscribe
+//! through [`use_realtime_channel`] — SSE on `/api/realtime/events`
+//! with a `/api/realtime/poll` fallback — and each channel kind
+//! registers a `RoomChannel` whose `authorize` hook decides who may
+//! listen (see `register.rs` for the full list).
+//!
+//! - `channel` / `hub` / `backplane` / `models` — server-side hub and
+//!   its cross-instance log
+//! - `sse` — raw axum SSE route
+//! - `controllers` — polling fallback
+//! - `hooks` — client subscription hook
+
+mod error;
+pub use error::*;
+
+pub mod controllers;
+pub mod hooks;
+pub mod types;
+
+pub use hooks::*;
+pub use types::*;
+
+#[cfg(feature = "server")]
+pub mod backplane;
+#[cfg(feature = "server")]
+pub mod channel;
+#[cfg(feature = "server")]
+pub mod hub;
+#[cfg(feature = "server")]
+pub mod models;
+#[cfg(feature = "server")]
+pub mod register;
+#[cfg(feature = "server")]
+pub mod sse;
+
+#[cfg(feature = "server")]
+pub use backplane::*;
+#[cfg(feature = "server")]
+pub use channel::*;
+#[cfg(feature = "server")]
+pub use hub::*;
+#[cfg(feature = "server")]
+pub use register::*;
+#[cfg(feature = "server")]
+pub use sse::*;
@@ -0,0 +1,125 @@
+//! Durable realtime log rows written by `DynamoStreamBackplane`.
+
+use crate::common::*;
+use crate::common::realtime::channel::{ChannelId, ServerEvent};
+
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// How long an appended event stays replayable. Covers any realistic
+/// reconnect gap for a live round; DynamoDB TTL sweeps older rows.
+pub const CHANNEL_LOG_TTL_SECS: i64 = 6 * 60 * 60;
+
+/// How long a channel's cursor outlives its last append. Well past
+/// [`CHANNEL_LOG_TTL_SECS`] plus DynamoDB's sweep lag, so ids only
+/// restart at 1 once the old event rows are gone.
+pub const CHANNEL_CURSOR_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+/// Per-channel sequence counter. Lives at
+/// `Partition::RealtimeChannel(channel_id) + EntityType::RealtimeChannelCursor`.
+/// Every append bumps `last_seq` with an atomic `ADD` and uses the
+/// returned value as the event id, so two instances publishing to
+/// the same channel never hand out the same id. Each append also
+/// pushes `expires_at` out, so only idle channels are swept.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(
+    feature = "server",
+    derive(DynamoEntity, rmcp::schemars::JsonSchema)
+)]
+pub struct RealtimeChannelCursor {
+    pub pk: Partition,  // Partition::RealtimeChannel(channel_id)
+    pub sk: EntityType, // EntityType::RealtimeChannelCursor
+
+    /// Last id handed out. Absent on the first `ADD`, hence the
+    /// default.
+    #[serde(default)]
+    pub last_seq: i64,
+
+    /// DynamoDB TTL field (epoch seconds).
+    #[serde(default)]
+    pub expires_at: i64,
+}
+
+/// Durable copy of one published `ServerEvent`. Written by
+/// `DynamoStreamBackplane::append`; every instance tails these rows
+/// for the channels it holds subscribers on, and they back
+/// `Last-Event-ID` replay.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(
+    feature = "server",
+    derive(DynamoEntity, rmcp::schemars::JsonSchema)
+)]
+pub struct RealtimeChannelEvent {
+    pub pk: Partition,  // Partition::RealtimeChannel(channel_id)
+    pub sk: EntityType, // EntityType::RealtimeChannelEvent({seq:020})
+
+    pub created_at: i64,
+
+    pub seq: i64,
+    pub name: String,
+
+    /// JSON-encoded payload. Stored as text so arbitrary payload
+    /// shapes (nulls, empty maps) round-trip through DynamoDB as-is.
+    pub payload: String,
+
+    /// DynamoDB TTL field (epoch seconds).
+    pub expires_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl RealtimeChannelCursor {
+    pub fn keys(channel: &ChannelId) -> (Partition, EntityType) {
+        (
+            Partition::RealtimeChannel(channel.0.clone()),
+            EntityType::RealtimeChannelCursor,
+        )
+    }
+}
+
+#[cfg(feature = "server")]
+impl RealtimeChannelEvent {
+    pub fn keys(channel: &ChannelId, seq: u64) -> (Partition, EntityType) {
+        (
+            Partition::RealtimeChannel(channel.0.clone()),
+            EntityType::RealtimeChannelEvent(format!("{seq:020}")),
+        )
+    }
+
+    pub fn new(channel: &ChannelId, seq: u64, name: &str, payload: &serde_json::Value) -> Self {
+        let (pk, sk) = Self::keys(channel, seq);
+        let now = crate::common::utils::time::get_now_timestamp_millis();
+        Self {
+            pk,
+            sk,
+            created_at: now,
+            seq: seq as i64,
+            name: name.to_string(),
+            payload: payload.to_string(),
+            expires_at: now / 1000 + CHANNEL_LOG_TTL_SECS,
+        }
+    }
+
+    /// Channel id recovered from the pk (`REALTIME_CHANNEL#<kind>:<inner>`).
+    pub fn channel(&self) -> ChannelId {
+        match &self.pk {
+            Partition::RealtimeChannel(id) => ChannelId(id.clone()),
+            other => ChannelId(
+                other
+                    .to_string()
+                    .strip_prefix("REALTIME_CHANNEL#")
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+        }
+    }
+
+    pub fn into_server_event(self) -> ServerEvent {
+        let channel = self.channel();
+        ServerEvent {
+            id: self.seq.max(0) as u64,
+            name: self.name,
+            channel,
+            payload: serde_json::from_str(&self.payload).unwrap_or(serde_json::Value::Null),
+        }
+    }
+}
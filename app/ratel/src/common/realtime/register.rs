@@ -0,0 +1,19 @@
+//! Register every `RoomChannel` with the global hub at process start.
+//! Idempotent — last registration wins so tests that swap in a mock
+//! channel still work.
+
+use crate::common::realtime::hub::global_hub;
+use crate::features::notifications::realtime::UserInboxChannel;
+use crate::features::spaces::pages::actions::actions::discussion::realtime::SpaceCommentsChannel;
+use crate::features::spaces::space_common::realtime::SpaceStatusChannel;
+
+pub async fn register_channels() {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb().clone();
+    let hub = global_hub();
+    hub.register(UserInboxChannel).await;
+    hub.register(SpaceCommentsChannel::new(cli.clone())).await;
+    hub.register(SpaceStatusChannel::new(cli)).await;
+
+    crate::features::arcade::games::fact_or_fold::realtime::register_channels().await;
+}
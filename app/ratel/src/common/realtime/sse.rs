@@ -0,0 +1,183 @@
+//! SSE endpoint for realtime channels (PR4e).
+//!
+//! `GET /api/realtime/events?channel=<kind:inner>` — opens a
+//! long-lived `text/event-stream` response, validates the caller's
+//! session, looks up the registered `RoomChannel` handler by kind,
+//! runs its `authorize` hook for the initial snapshot, and then
+//! pumps `ServerEvent`s from the broadcast hub into the SSE stream
+//! as they arrive.
+//!
+//! Designed to run on Lambda Function URL with
+//! `InvokeMode::RESPONSE_STREAM` (PR4e infra commit). On `cargo run`
+//! / tests it behaves like a normal long-poll response so unit tests
+//! can drive it without Lambda-specific plumbing.
+//!
+//! Multi-instance fan-out comes from the hub's backplane — each
+//! instance's hub receives events published elsewhere through
+//! `InProcessChannel::deliver`. On reconnect the browser sends
+//! `Last-Event-ID`; the handler replays everything newer from the
+//! backplane's channel log before switching to live events, so a
+//! client that lands on a different instance still sees the events
+//! it missed. Clients that reconnect by hand (EventSource can't set
+//! headers) pass `?last_event_id=` instead.
+//!
+//! `/api/arcade/events` stays mounted by the arcade router as an
+//! alias for clients built before the move to `common::realtime`.
+
+use crate::common::axum::{
+    Router,
+    extract::Query,
+    http::HeaderMap,
+    response::sse::{Event as SseEvent, KeepAlive, Sse},
+    routing::get,
+};
+use crate::common::models::auth::User;
+use crate::common::realtime::RealtimeError;
+use crate::common::realtime::channel::{ChannelContext, ChannelId, ServerEvent};
+use crate::common::realtime::hub::global_hub;
+use futures::stream::{Stream, StreamExt};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::convert::Infallible;
+use tokio::sync::broadcast;
+
+// ── Router ──────────────────────────────────────────────────────────
+
+pub fn router() -> Router {
+    Router::new().route("/api/realtime/events", get(events_handler))
+}
+
+// ── Query params ────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct EventsQuery {
+    /// `<kind>:<inner>` channel id, e.g. `fof.chat:abc123`.
+    pub channel: String,
+    /// Manual-reconnect equivalent of the `Last-Event-ID` header.
+    /// The header wins when both are present.
+    #[serde(default)]
+    pub last_event_id: Option<u64>,
+}
+
+// ── Handler ─────────────────────────────────────────────────────────
+
+/// SSE handler. Authenticates via the standard `User` extractor (so
+/// session cookies / bypass work the same as the rest of the app),
+/// then defers per-channel authorization to the registered
+/// `RoomChannel` impl.
+pub async fn events_handler(
+    user: User,
+    headers: HeaderMap,
+    Query(query): Query<EventsQuery>,
+) -> Result<Sse<impl Stream<Item = Result<SseEvent, Infallible>>>, RealtimeError> {
+    let hub = global_hub();
+    let channel = ChannelId(query.channel.clone());
+
+    let handler = hub
+        .handler_for(&channel)
+        .await
+        .map_err(|_| RealtimeError::ChannelUnknown)?;
+
+    let ctx = ChannelContext::from_user_pk(&user.pk);
+
+    let initial_state = handler
+        .authorize(&ctx, &channel, serde_json::Value::Null)
+        .await
+        .map_err(|_| RealtimeError::ChannelForbidden)?;
+
+    // Subscribe before reading the backlog so nothing published in
+    // between is lost; overlap is filtered out in the stream.
+    let rx = hub.subscribe_stream(&channel).await;
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .or(query.last_event_id);
+    let backlog = match last_event_id {
+        Some(last_id) => hub.replay_since(&channel, last_id).await.unwrap_or_else(|e| {
+            tracing::warn!(error = %e, channel = %channel.0, "realtime sse replay failed");
+            Vec::new()
+        }),
+        None => Vec::new(),
+    };
+    let stream = make_event_stream(
+        channel.clone(),
+        initial_state,
+        last_event_id.unwrap_or(0),
+        backlog,
+        rx,
+    );
+
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+// ── Stream wiring ───────────────────────────────────────────────────
+
+/// Build the SSE event stream: one synthetic `subscribed` event with
+/// the handler's initial snapshot, then the replayed backlog (events
+/// after `Last-Event-ID`), then every `ServerEvent` the broadcast
+/// receiver yields. Live events already covered by the backlog or
+/// at/below `last_event_id` are skipped.
+fn make_event_stream(
+    channel: ChannelId,
+    initial_state: serde_json::Value,
+    last_event_id: u64,
+    backlog: Vec<ServerEvent>,
+    rx: broadcast::Receiver<ServerEvent>,
+) -> impl Stream<Item = Result<SseEvent, Infallible>> {
+    let initial = SseEvent::default()
+        .event("subscribed")
+        .json_data(serde_json::json!({
+            "channel": channel.0,
+            "initial_state": initial_state,
+        }))
+        .expect("subscribed event serialization");
+
+    let initial_stream = futures::stream::iter(std::iter::once(Ok(initial)));
+
+    let replayed: HashSet<u64> = backlog.iter().map(|e| e.id).collect();
+    let backlog_stream =
+        futures::stream::iter(backlog.into_iter().filter_map(|evt| to_sse(&evt).map(Ok)));
+
+    let live_stream = futures::stream::unfold(rx, move |mut rx| {
+        let replayed = replayed.clone();
+        async move {
+            loop {
+                match rx.recv().await {
+                    Ok(evt) => {
+                        if evt.id <= last_event_id || replayed.contains(&evt.id) {
+                            continue;
+                        }
+                        if let Some(sse) = to_sse(&evt) {
+                            return Some((Ok(sse), rx));
+                        }
+                        // Serialization failure (should not happen for
+                        // our controlled payloads). Drop the event and
+                        // keep pumping.
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => {
+                        // Slow subscriber missed events. SSE clients
+                        // reconnect with Last-Event-ID and replay from
+                        // the backplane log; for now drop and continue.
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }
+    });
+
+    initial_stream.chain(backlog_stream).chain(live_stream)
+}
+
+fn to_sse(evt: &ServerEvent) -> Option<SseEvent> {
+    SseEvent::default()
+        .event(&evt.name)
+        .id(evt.id.to_string())
+        .json_data(serde_json::json!({
+            "channel": evt.channel.0,
+            "payload": evt.payload,
+        }))
+        .ok()
+}
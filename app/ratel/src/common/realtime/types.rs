@@ -0,0 +1,42 @@
+use crate::common::*;
+
+/// Wire form of one realtime event, shared by the polling endpoint
+/// and the client hook. Mirrors the server-side `ServerEvent` with
+/// the channel id flattened to a string so it compiles client-side.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RealtimeEvent {
+    /// Per-channel sequence. `0` for the synthetic `subscribed`
+    /// event carrying the channel's initial state.
+    pub id: u64,
+    pub name: String,
+    pub channel: String,
+    #[serde(default)]
+    pub payload: serde_json::Value,
+}
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RealtimePollResponse {
+    /// Authorize snapshot. Only set on the first poll (no `since`),
+    /// same as the SSE `subscribed` event.
+    #[serde(default)]
+    pub initial_state: Option<serde_json::Value>,
+    /// Events newer than `since`, oldest first.
+    #[serde(default)]
+    pub events: Vec<RealtimeEvent>,
+    /// Id to pass as `since` on the next poll.
+    pub last_id: u64,
+}
+
+#[cfg(feature = "server")]
+impl From<crate::common::realtime::channel::ServerEvent> for RealtimeEvent {
+    fn from(evt: crate::common::realtime::channel::ServerEvent) -> Self {
+        Self {
+            id: evt.id,
+            name: evt.name,
+            channel: evt.channel.0,
+            payload: evt.payload,
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// Realtime channel subscriber. Receives `{ url, events }`, opens an
+// EventSource and forwards every listed event (plus `subscribed`) as
+// a RealtimeEvent. Sends a `__closed` marker when the browser gives
+// up on the connection (HTTP error / unsupported) so the Rust side
+// can fall back to polling.
+const { url, events } = await dioxus.recv();
+
+const closed = () =>
+  dioxus.send({ id: 0, name: "__closed", channel: "", payload: null });
+
+if (typeof EventSource === "undefined") {
+  closed();
+} else {
+  const es = new EventSource(url, { withCredentials: true });
+  const forward = (name) => (e) => {
+    let data = {};
+    try {
+      data = JSON.parse(e.data);
+    } catch (_) {}
+    dioxus.send({
+      id: Number(e.lastEventId || 0),
+      name,
+      channel: data.channel || "",
+      payload: name === "subscribed" ? data.initial_state : data.payload,
+    });
+  };
+  es.addEventListener("subscribed", forward("subscribed"));
+  for (const name of events || []) {
+    es.addEventListener(name, forward(name));
+  }
+  // Transient drops reconnect on their own (with Last-Event-ID);
+  // only a terminal CLOSED state hands over to polling.
+  es.onerror = () => {
+    if (es.readyState === EventSource.CLOSED) {
+      closed();
+    }
+  };
+}
@@ -124,15 +124,21 @@ fn serve(app: fn() -> Element) {
 
     let mcp_router = crate::common::mcp::mcp_router();
     let membership_router = crate::features::membership::server::router();
+    let realtime_router = crate::common::realtime::sse::router();
     let arcade_router = crate::features::arcade::server::router();
     let cross_posting_router = crate::features::cross_posting::server::router();
     let launchpad_partner_router = crate::features::launchpad_partner::server::router();
+    let calendar_router = crate::features::calendar::server::router();
+    let federation_router = crate::features::federation::server::router();
     let dioxus_router = dioxus::server::router(app)
         .merge(mcp_router)
         .merge(membership_router)
+        .merge(realtime_router)
         .merge(arcade_router)
         .merge(cross_posting_router)
-        .merge(launchpad_partner_router);
+        .merge(launchpad_partner_router)
+        .merge(calendar_router)
+        .merge(federation_router);
     // CatchPanicLayer turns any panic in the request future into a 500 response
     // instead of letting it propagate up the spawn_pinned worker thread, which
     // would terminate the worker (and drop the connection). Pairs with the
@@ -149,23 +155,20 @@ fn serve(app: fn() -> Element) {
 
     crate::common::mcp::set_app_router(app.clone());
 
-    // Register arcade realtime channel handlers with the per-process
-    // global hub. Each game registers its own channels here so the
-    // SSE endpoint can resolve them by kind. Idempotent — re-registers
+    // Register realtime channel handlers with the per-process global
+    // hub so the SSE / poll endpoints can resolve them by kind. Idempotent — re-registers
     // overwrite, which is what tests rely on for mock channels.
     // `serve` is synchronous (called before tokio main spawns the
     // server), so we block on the registration through whichever
     // runtime is available.
     if let Ok(handle) = tokio::runtime::Handle::try_current() {
-        handle.block_on(
-            crate::features::arcade::games::fact_or_fold::realtime::register_channels(),
-        );
+        handle.block_on(crate::common::realtime::register_channels());
     } else {
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
             .build()
             .expect("tokio runtime build for channel registration");
-        rt.block_on(crate::features::arcade::games::fact_or_fold::realtime::register_channels());
+        rt.block_on(crate::common::realtime::register_channels());
     }
 
     #[cfg(not(feature = "lambda"))]
@@ -174,6 +177,9 @@ fn serve(app: fn() -> Element) {
         {
             tracing::info!("Starting local-dev DynamoDB Stream poller");
             crate::common::stream_poller::spawn_stream_poller();
+            crate::features::spaces::pages::actions::actions::meet::spawn_meet_scheduler();
+            crate::features::cross_posting::services::engagement::spawn_engagement_refresher();
+            crate::features::cross_posting::services::scheduler::spawn_syndication_scheduler();
         }
 
         #[cfg(feature = "local-dev")]
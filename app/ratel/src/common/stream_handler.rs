@@ -82,20 +82,44 @@ pub async fn handle_stream_record(
                 {
                     tracing::error!(error = %e, "stream: AiModeratorReplyIndex failed");
                 }
+                // CommentLive: push to open discussion pages
+                crate::features::spaces::pages::actions::actions::discussion::realtime::publish_comment_created(
+                    comment.clone(),
+                )
+                .await;
                 // DiscussionXpRecord: record XP for discussion comment
                 if let Err(e) =
-                    crate::features::activity::services::handle_discussion_xp(comment).await
+                    crate::features::activity::services::handle_discussion_xp(comment.clone())
+                        .await
                 {
                     tracing::error!(error = %e, "stream: DiscussionXpRecord failed");
                 }
+                // BadgeProgress: count the comment toward discussion badges
+                if let Err(e) =
+                    crate::features::badge::services::handle_discussion_badge(comment).await
+                {
+                    tracing::error!(error = %e, "stream: BadgeProgress (discussion) failed");
+                }
             } else if sk.starts_with("SPACE_POST_COMMENT_REPLY#") {
+                let comment: crate::features::spaces::pages::actions::actions::discussion::SpacePostComment = deserialize(image)?;
+                // CommentLive: push to open discussion pages
+                crate::features::spaces::pages::actions::actions::discussion::realtime::publish_comment_created(
+                    comment.clone(),
+                )
+                .await;
                 // DiscussionXpRecord: record XP for discussion reply
-                let comment = deserialize(image)?;
                 if let Err(e) =
-                    crate::features::activity::services::handle_discussion_xp(comment).await
+                    crate::features::activity::services::handle_discussion_xp(comment.clone())
+                        .await
                 {
                     tracing::error!(error = %e, "stream: DiscussionXpRecord (reply) failed");
                 }
+                // BadgeProgress: replies count the same as top-level comments
+                if let Err(e) =
+                    crate::features::badge::services::handle_discussion_badge(comment).await
+                {
+                    tracing::error!(error = %e, "stream: BadgeProgress (reply) failed");
+                }
             } else if sk == "POST" || sk.starts_with("POST") {
                 // PostVectorIndex for newly inserted published posts
                 if get_string_field(image, "status").as_deref() == Some("PUBLISHED") {
@@ -118,7 +142,10 @@ pub async fn handle_stream_record(
                 // service (errors logged, not propagated).
                 let notification: crate::common::models::notification::UserInboxNotification =
                     deserialize(image)?;
+                let user_pk = notification.pk.clone();
                 crate::features::notifications::services::fan_out_push(notification).await;
+                // InboxLive: nudge the recipient's open sessions to refetch.
+                crate::features::notifications::realtime::publish_inbox_changed(&user_pk).await;
             } else if sk.starts_with("SPACE_ACTIVITY#") {
                 {
                     let activity = deserialize(image)?;
@@ -143,33 +170,106 @@ pub async fn handle_stream_record(
             } else if sk.starts_with("SPACE_STATUS_CHANGE_EVENT#") {
                 let event: crate::common::models::space::SpaceStatusChangeEvent =
                     deserialize(image)?;
+                // SpaceStatusLive: push to open space pages
+                crate::features::spaces::space_common::realtime::publish_space_status_changed(
+                    &event,
+                )
+                .await;
                 if let Err(e) =
                     crate::features::spaces::space_common::services::handle_space_status_change(
-                        event,
+                        event.clone(),
                     )
                     .await
                 {
                     tracing::error!(error = %e, "stream: SpaceStatusChangeEvent failed");
                 }
+                // Cross-posting: live / results cards for the owner's connections
+                if let Err(e) = crate::features::cross_posting::services::space_cards::handle_space_status_change_for_syndication(event.clone()).await
+                {
+                    tracing::error!(error = %e, "stream: SpaceCardsForSyndication failed");
+                }
+                // BadgeProgress: creator badges (Curator, Architect, Foundation)
+                if let Err(e) =
+                    crate::features::badge::services::handle_space_status_badge(event).await
+                {
+                    tracing::error!(error = %e, "stream: BadgeProgress (space status) failed");
+                }
             } else if sk.starts_with("SPACE_POLL_USER_ANSWER#") {
                 // PollXpRecord: record XP for poll answer
-                let answer = deserialize(image)?;
-                if let Err(e) = crate::features::activity::services::handle_poll_xp(answer).await {
+                let answer: crate::features::spaces::pages::actions::actions::poll::SpacePollUserAnswer =
+                    deserialize(image)?;
+                if let Err(e) =
+                    crate::features::activity::services::handle_poll_xp(answer.clone()).await
+                {
                     tracing::error!(error = %e, "stream: PollXpRecord failed");
                 }
+                // BadgeProgress: count the vote toward poll badges
+                if let Err(e) = crate::features::badge::services::handle_poll_badge(answer).await {
+                    tracing::error!(error = %e, "stream: BadgeProgress (poll) failed");
+                }
             } else if sk.starts_with("SPACE_QUIZ_ATTEMPT#") {
                 // QuizXpRecord: record XP for quiz attempt
-                let attempt = deserialize(image)?;
-                if let Err(e) = crate::features::activity::services::handle_quiz_xp(attempt).await {
+                let attempt: crate::features::spaces::pages::actions::actions::quiz::SpaceQuizAttempt =
+                    deserialize(image)?;
+                if let Err(e) =
+                    crate::features::activity::services::handle_quiz_xp(attempt.clone()).await
+                {
                     tracing::error!(error = %e, "stream: QuizXpRecord failed");
                 }
+                // BadgeProgress: perfect attempts count toward quiz badges
+                if let Err(e) = crate::features::badge::services::handle_quiz_badge(attempt).await {
+                    tracing::error!(error = %e, "stream: BadgeProgress (quiz) failed");
+                }
+            } else if sk.starts_with("SPACE_MEET_ATTENDANCE#") {
+                // MeetXpRecord: record XP and pay AttendMeet
+                let attendance: crate::features::spaces::pages::actions::actions::meet::SpaceMeetAttendance =
+                    deserialize(image)?;
+                if let Err(e) = crate::features::activity::services::handle_meet_xp(attendance).await
+                {
+                    tracing::error!(error = %e, "stream: MeetXpRecord failed");
+                }
+            } else if sk.starts_with("SPACE_MEET_ARCHIVE#") {
+                // MeetTranscriptVectorIndex: embed transcript windows into Qdrant
+                let archive: crate::features::spaces::pages::actions::actions::meet::SpaceMeetArchive =
+                    deserialize(image)?;
+                if let Err(e) =
+                    crate::features::rag::qdrant::indexers::meet_transcript_indexer::index_meet_transcript(
+                        archive,
+                    )
+                    .await
+                {
+                    tracing::error!(error = %e, "stream: MeetTranscriptVectorIndex failed");
+                }
             } else if sk.starts_with("FOLLOWER#") {
                 // FollowXpRecord: record XP for follow action
-                let follow = deserialize(image)?;
-                if let Err(e) = crate::features::activity::services::handle_follow_xp(follow).await
+                let follow: crate::common::models::auth::UserFollow = deserialize(image)?;
+                if let Err(e) =
+                    crate::features::activity::services::handle_follow_xp(follow.clone()).await
                 {
                     tracing::error!(error = %e, "stream: FollowXpRecord failed");
                 }
+                // BadgeProgress: credits both the follower and the followed
+                if let Err(e) = crate::features::badge::services::handle_follow_badge(follow).await
+                {
+                    tracing::error!(error = %e, "stream: BadgeProgress (follow) failed");
+                }
+            } else if sk == "SPACE_PARTICIPANT" {
+                // BadgeProgress: one participant row per (space, user) join
+                let participant: crate::common::models::space::SpaceParticipant =
+                    deserialize(image)?;
+                if let Err(e) =
+                    crate::features::badge::services::handle_space_join_badge(participant).await
+                {
+                    tracing::error!(error = %e, "stream: BadgeProgress (join) failed");
+                }
+            } else if sk == "USER" {
+                // BadgeProgress: sign-up date for windowed badges (Founding Voice)
+                let user: crate::common::models::auth::User = deserialize(image)?;
+                if let Err(e) =
+                    crate::features::badge::services::handle_user_created_badge(user).await
+                {
+                    tracing::error!(error = %e, "stream: BadgeProgress (sign-up) failed");
+                }
             } else if sk.starts_with("SPACE_ANALYZE_REPORT#") {
                 // AnalyzeReportInProgress: kick off auto poll/quiz/follow
                 // analysis. Filter on INSERT only — Lambda's own status
@@ -198,18 +298,6 @@ pub async fn handle_stream_record(
                 }
             } else if sk.starts_with("SYNDICATION_JOB#") {
                 try_dispatch_pending_syndication_job(image, "INSERT").await?;
-            } else if sk.starts_with("FACT_FOLD_CHAT#") {
-                // PR4f — chat fan-out: every active SSE invocation
-                // sees this Stream record and publishes locally for
-                // its own subscribers. The hub is per-process, so
-                // each invocation's `global_hub` is a different one
-                // — the publish here only reaches the subscribers
-                // that *this* invocation happens to hold.
-                let row: crate::features::arcade::games::fact_or_fold::models::FactFoldChatMessage =
-                    deserialize(image)?;
-                if let Err(e) = handle_fact_fold_chat_inserted(row).await {
-                    tracing::error!(error = %e, "stream: FactFoldChat fan-out failed");
-                }
             } else if sk.starts_with("SUB_TEAM_ANNOUNCEMENT#") {
                 // Direct ("이 하위팀에만 공지") announcements skip the Draft
                 // step and write `status: Published` straight on INSERT,
@@ -409,42 +497,6 @@ pub async fn handle_stream_record(
 /// `cdk/lib/dynamo-stream-event.ts`) so local-dev behaviour matches Lambda;
 /// both INSERT (Stage 1 factory enqueue) and MODIFY (user-initiated retry
 /// flipping state back to pending) flow through this single entry point.
-/// FactFoldChat INSERT fan-out helper. Pulls the room id out of the
-/// row's pk (`FACT_FOLD#{round_id}`) and publishes a `chat_message`
-/// event to the matching `fof.chat:{round_id}` channel on the
-/// per-invocation hub. Each Lambda invocation receives this same
-/// Stream record and runs its own publish, so all subscribers
-/// across the cluster get the message regardless of which
-/// invocation holds their SSE connection.
-#[cfg(feature = "server")]
-async fn handle_fact_fold_chat_inserted(
-    row: crate::features::arcade::games::fact_or_fold::models::FactFoldChatMessage,
-) -> crate::common::Result<()> {
-    use crate::features::arcade::games::fact_or_fold::realtime::chat_payload_from;
-    use crate::features::arcade::games::fact_or_fold::types::ChatMessagePayload;
-    use crate::features::arcade::realtime::channel::ChannelId;
-    use crate::features::arcade::realtime::hub::global_hub;
-
-    let round_id = row
-        .pk
-        .to_string()
-        .strip_prefix("FACT_FOLD#")
-        .unwrap_or(&row.pk.to_string())
-        .to_string();
-    let channel = ChannelId(format!("fof.chat:{round_id}"));
-
-    let payload: ChatMessagePayload = chat_payload_from(row);
-    let payload = serde_json::to_value(payload).map_err(|e| {
-        crate::error!("FactFoldChat fan-out serialize failed: {e}");
-        crate::features::arcade::ArcadeError::ChannelPayloadInvalid
-    })?;
-
-    let _id = global_hub()
-        .publish(&channel, "chat_message", payload)
-        .await?;
-    Ok(())
-}
-
 #[cfg(feature = "server")]
 async fn try_dispatch_pending_syndication_job(
     image: &std::collections::HashMap<String, serde_dynamo::AttributeValue>,
@@ -542,6 +594,10 @@ async fn essence_index_dispatch(
         let quiz: crate::features::spaces::pages::actions::actions::quiz::SpaceQuiz =
             deserialize(image)?;
         crate::features::essence::services::index_quiz(cli, &quiz).await?;
+    } else if sk.starts_with("SPACE_MEET_ARCHIVE#") {
+        let archive: crate::features::spaces::pages::actions::actions::meet::SpaceMeetArchive =
+            deserialize(image)?;
+        crate::features::essence::services::index_meet_transcript(cli, &archive).await?;
     }
     Ok(())
 }
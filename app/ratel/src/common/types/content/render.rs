@@ -41,7 +41,22 @@ impl ContentDocument {
 }
 
 impl Block {
+    /// Plain text of this block without its children, normalized like
+    /// [`ContentDocument::to_plain_text`].
+    pub fn own_plain_text(&self) -> String {
+        let mut out = String::new();
+        self.append_own_plain_text(&mut out);
+        normalize_whitespace(&out)
+    }
+
     fn append_plain_text(&self, out: &mut String) {
+        self.append_own_plain_text(out);
+        for child in &self.children {
+            child.append_plain_text(out);
+        }
+    }
+
+    fn append_own_plain_text(&self, out: &mut String) {
         match &self.kind {
             BlockKind::Paragraph(t)
             | BlockKind::Quote(t)
@@ -84,9 +99,6 @@ impl Block {
             }
             BlockKind::Divider | BlockKind::Custom(_) | BlockKind::Unknown => {}
         }
-        for child in &self.children {
-            child.append_plain_text(out);
-        }
     }
 
     fn append_html(&self, out: &mut String) {
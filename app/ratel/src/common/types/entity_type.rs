@@ -62,6 +62,15 @@ pub enum EntityType {
     CharacterXpSource(String), // space_id (unprefixed; SubPartition wraps SpacePartition)
     CharacterSkill(String),    // skill_id ("money_tree", "ranker", ...)
 
+    // Badges (account-level recognition)
+    UserBadge(String), // badge_id ("first_steps", "kingmaker", ...)
+    UserBadgeProgress,
+    BadgeEventMarker(String), // hashed source event id
+
+    // Activity continuity (per-user daily ledger + streak)
+    UserDailyActivity(String), // local date "YYYY-MM-DD" in the user's UTC offset
+    UserActivityStreak,
+
     // Feed entity types
     Post,
     PostAuthor, // from User
@@ -93,9 +102,11 @@ pub enum EntityType {
     SpaceParticipant,
     SpaceAdmin(String), // SPACE_ADMIN#{user_pk}
     SpaceInvitation,
-    SpaceTemplate(String),          // template name
+    SpaceTemplate(String),          // template id
     SpaceEmailVerification(String), //email
     SpaceRequirement(String),       // use SpaceRequirementType
+    SpaceTemplateAction(String),    // "{position:04}#{source action id}"
+    SpaceTemplateBody,
 
     // Poll Feature entity types
     SpacePoll(String), // SpacePoll#{uuid or space_id}
@@ -111,9 +122,14 @@ pub enum EntityType {
     SpaceQuiz(String),        // SpaceQuiz#{uuid}
     SpaceQuizAnswer(String),  // SpaceQuizAnswer#{quiz_id}
     SpaceQuizAttempt(String), // SpaceQuizAttempt#{quiz_id}#{attempt_id}
+    SpaceQuizSession(String), // SpaceQuizSession#{quiz_id}
+    SpaceQuizQuestionStat(String), // SpaceQuizQuestionStat#{quiz_id}:{question_index}
 
     // Meet action entity types
     SpaceMeet(String), // SpaceMeet#{uuid}
+    SpaceMeetArchive(String), // SpaceMeetArchive#{meet_uuid}
+    SpaceMeetTranscriptSegment(String), // SpaceMeetTranscriptSegment#{meet_uuid}#{seq}
+    SpaceMeetAttendance(String), // SpaceMeetAttendance#{meet_uuid}#{user_pk}
 
     // Survery space entity types
     SurveySpace,
@@ -240,9 +256,13 @@ pub enum EntityType {
     // MCP
     McpClientSecret,
 
+    // Calendar
+    CalendarFeedSecret,
+
     // Activity
     SpaceActivity(String), // SPACE_ACTIVITY#action_id#timestamp
     SpaceScore,
+    SpaceLeaderboardSnapshot(String), // "day-YYYY-MM-DD" | "week-YYYY-Www"
 
     // Hot space ranking snapshot. PK: SPACE#{space_id}, SK: HotSpace.
     // Row carries denormalized counts + WindowedRankKey on gsi1 for global
@@ -287,13 +307,28 @@ pub enum EntityType {
     /// pk = TEAM#{child_team_id}, this sk encodes the announcement id.
     SubTeamAnnouncementFanout(String), // SUB_TEAM_ANNOUNCEMENT_FANOUT#{announcement_id}
 
-    // Cross-posting feature (Phase 1: Bluesky / LinkedIn / Threads). All entities
-    // share an existing Partition variant (User or Feed) — no new Partition.
-    SocialConnection(String),       // pk=User(user_id), inner=platform.to_string()
+    // Cross-posting feature (Bluesky / LinkedIn / Threads / Mastodon). Per-owner
+    // and per-post entities share an existing Partition variant (User / Team or Feed);
+    // only the per-instance Mastodon app registration has its own pk.
+    SocialConnection(String),       // pk=User(id) or Team(id), inner=platform.to_string()
     SyndicationDirective,           // pk=Feed(post_id), singleton per published post
-    SyndicationJob(String),         // pk=Feed(post_id), inner=platform.to_string()
-    EngagementSnapshot(String),     // pk=Feed(post_id), inner=platform.to_string()
+    SyndicationJob(String),         // pk=Feed(post_id), inner=platform.to_string();
+                                    // space cards: pk=Space(id), inner="{platform}#{source}"
+    EngagementSnapshot(String),     // same pk + inner as the SyndicationJob it tracks
+    ExternalComment(String),        // pk=Feed(post_id), inner="{platform}#{hashed reply id}"
+    SyndicationAudit(String),       // pk=Feed(post_id), inner="{created_at}#{platform}#{action}"
+    EngagementSample(String),       // same pk as the job, inner="{job inner}#{fetched_at}"
+    SyndicationPostStats(String),   // pk=User(author_id), inner="{post_id}#{platform}"
+    SyndicationDailyStats(String),  // pk=User(author_id), inner="{day}#{platform}"
+    SyndicationVisitMark(String),   // pk=Feed(post_id), inner="{platform}#{day}#{visitor hash}"
     UserOnboardingFlags,            // pk=User(user_id), singleton per user
+    MastodonApp,                    // pk=MastodonInstance(host), singleton per instance
+
+    // ActivityPub federation. Local actors (User / Team) keep their own pk;
+    // remote actors and the remote objects they send get dedicated pks.
+    ActorKeyPair,                   // pk=User(id) | Team(id), singleton per actor
+    RemoteActor,                    // pk=RemoteActor(hash), cached actor document
+    FederatedObject,                // pk=FederatedObject(hash), singleton per object
 
     // Ratel Arcade — *Fact or Fold*. v1 PR1 only registers subject + settings;
     // round/participant/bet/rationale/chat/settlement entries are added in PR3+.
@@ -315,8 +350,8 @@ pub enum EntityType {
 
     /// One row per chat message in a round. inner = uuid_v7 (time-
     /// sortable) so an sk-prefix query returns the chat log in
-    /// chronological order. A DDB Stream filter on this sk prefix
-    /// drives the SSE fan-out (PR4f).
+    /// chronological order. The chat controller publishes each new
+    /// row to the realtime hub once; see `RealtimeChannelEvent`.
     FactFoldChat(String),           // pk=FactFold(round_id), inner=msg_id
 
     // PR6 — settlement.
@@ -344,6 +379,13 @@ pub enum EntityType {
     /// settlement.
     FactFoldLeaderboardEntry(String),
 
+    /// Collusion review row for a round whose settlement is held.
+    /// inner = round_id (uuid_v7, so an sk-descending query at
+    /// `Partition::FactFoldReviews` lists the newest flags first).
+    /// Written by settlement when the detector fires; resolved by an
+    /// admin approve / void.
+    FactFoldCollusionReview(String),
+
     // Ratel Arcade — chip wallet (PR4b).
     /// Singleton balance row under `Partition::ArcadeWallet(user_id)`.
     /// One row per user; carries `chip_balance` + `last_updated`.
@@ -356,6 +398,15 @@ pub enum EntityType {
     /// Singleton arcade-wide settings (chip↔RP ratio, default buy-in,
     /// ...). Pairs with `Partition::ArcadeSettings`.
     ArcadeSettings,                 // pk=ArcadeSettings (singleton)
+
+    // Shared realtime backplane log (`common::realtime`).
+    /// Singleton sequence counter under `Partition::RealtimeChannel(channel_id)`.
+    /// Bumped with an atomic `ADD` per append so ids stay monotonic
+    /// across instances.
+    RealtimeChannelCursor,          // pk=RealtimeChannel(channel_id) (singleton sk)
+    /// One published event. inner = zero-padded sequence (`{seq:020}`)
+    /// so an sk-descending query returns the newest events first.
+    RealtimeChannelEvent(String),   // pk=RealtimeChannel(channel_id), inner=seq
 }
 
 impl TryInto<Partition> for EntityType {
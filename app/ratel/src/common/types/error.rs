@@ -341,6 +341,22 @@ pub enum Error {
     #[translate(from)]
     Arcade(#[from] crate::features::arcade::ArcadeError),
 
+    #[error("{0}")]
+    #[translate(from)]
+    Realtime(#[from] crate::common::realtime::RealtimeError),
+
+    #[error("{0}")]
+    #[translate(from)]
+    Calendar(#[from] crate::features::calendar::types::CalendarError),
+
+    #[error("{0}")]
+    #[translate(from)]
+    SpaceTemplate(#[from] crate::features::spaces::templates::types::SpaceTemplateError),
+
+    #[error("{0}")]
+    #[translate(from)]
+    Federation(#[from] crate::features::federation::types::FederationError),
+
     // Unit variants for common errors
     #[error("Internal error")]
     #[translate(
@@ -530,6 +546,10 @@ impl dioxus::fullstack::axum::response::IntoResponse for Error {
             Error::Character(e) => e.status_code(),
             Error::FactOrFold(e) => e.status_code(),
             Error::Arcade(e) => e.status_code(),
+            Error::Realtime(e) => e.status_code(),
+            Error::Calendar(e) => e.status_code(),
+            Error::SpaceTemplate(e) => e.status_code(),
+            Error::Federation(e) => e.status_code(),
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         };
 
@@ -655,6 +675,10 @@ impl dioxus::fullstack::AsStatusCode for Error {
             Error::Character(e) => e.status_code(),
             Error::FactOrFold(e) => e.status_code(),
             Error::Arcade(e) => e.status_code(),
+            Error::Realtime(e) => e.status_code(),
+            Error::Calendar(e) => e.status_code(),
+            Error::SpaceTemplate(e) => e.status_code(),
+            Error::Federation(e) => e.status_code(),
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -107,6 +107,37 @@ pub enum DetailType {
     /// same `fan_out_push` directly via `stream_handler`; this is the prod
     /// (EventBridge) path. See conventions/implementing-event-bridge.md.
     InboxPushFanout,
+    /// Fires on `SPACE_PARTICIPANT` INSERT. Counts the join toward the
+    /// participant's badge progress (First Steps, Explorer, ...). The other
+    /// badge sources ride on the existing XP / status detail types.
+    BadgeSpaceJoin,
+    /// Fires on `USER` INSERT. Feeds the sign-up timestamp to windowed
+    /// badges (Founding Voice).
+    BadgeAccountCreated,
+    /// Fired every minute by an EventBridge schedule (not a stream pipe).
+    /// Runs the meet deadline scheduler: 10-minute reminders, Scheduled →
+    /// Expired after 24h, and the Live ceiling. The local-dev equivalent
+    /// is `spawn_meet_scheduler`.
+    MeetScheduleTick,
+    /// Fired every 5 minutes by an EventBridge schedule. Runs the Stage 4
+    /// cross-posting engagement refresh: engagement counts and imported
+    /// replies for due `SyndicationJob`s. The local-dev equivalent is
+    /// `spawn_engagement_refresher`.
+    EngagementRefreshTick,
+    /// Fired every minute by an EventBridge schedule. Runs the
+    /// cross-posting syndication scheduler: due `Scheduled` jobs are
+    /// released to `Pending` for Stage 2. The local-dev equivalent is
+    /// `spawn_syndication_scheduler`.
+    SyndicationScheduleTick,
+    /// Fires on `SpaceMeetArchive` INSERT. Embeds the transcript windows
+    /// into Qdrant.
+    MeetTranscriptVectorIndex,
+    /// Fires on `SpaceMeetArchive` INSERT. Mirrors the transcript into the
+    /// space creator's Essence list.
+    EssenceIndexMeetTranscript,
+    /// Fires on `SpaceMeetAttendance` INSERT. Records meet XP and pays the
+    /// `AttendMeet` reward.
+    MeetXpRecord,
     #[serde(other)]
     Unknown,
 }
@@ -198,11 +229,23 @@ impl EventBridgeEnvelope {
                 let event: crate::common::models::space::SpaceStatusChangeEvent =
                     DetailType::parse_detail(&self.detail)?;
                 let space_pk = event.space_pk.clone();
+                // SpaceStatusLive: push to open space pages
+                crate::features::spaces::space_common::realtime::publish_space_status_changed(
+                    &event,
+                )
+                .await;
                 let r =
                     crate::features::spaces::space_common::services::handle_space_status_change(
-                        event,
+                        event.clone(),
                     )
                     .await;
+                if let Err(e) = crate::features::cross_posting::services::space_cards::handle_space_status_change_for_syndication(event.clone()).await
+                {
+                    tracing::error!(error = %e, "space card syndication failed");
+                }
+                log_badge_err(
+                    crate::features::badge::services::handle_space_status_badge(event).await,
+                );
                 fanout_hot_space(&space_pk).await;
                 r
             }
@@ -221,7 +264,10 @@ impl EventBridgeEnvelope {
                 let answer: crate::features::spaces::pages::actions::actions::poll::SpacePollUserAnswer =
                     DetailType::parse_detail(&self.detail)?;
                 let space_pk = space_pk_from_id_str(answer.space_id.as_deref());
-                let r = crate::features::activity::services::handle_poll_xp(answer).await;
+                let r = crate::features::activity::services::handle_poll_xp(answer.clone()).await;
+                log_badge_err(
+                    crate::features::badge::services::handle_poll_badge(answer).await,
+                );
                 fanout_if_some(space_pk.as_ref()).await;
                 r
             }
@@ -229,7 +275,10 @@ impl EventBridgeEnvelope {
                 let attempt: crate::features::spaces::pages::actions::actions::quiz::SpaceQuizAttempt =
                     DetailType::parse_detail(&self.detail)?;
                 let space_pk = space_pk_from_id_str(attempt.space_id.as_deref());
-                let r = crate::features::activity::services::handle_quiz_xp(attempt).await;
+                let r = crate::features::activity::services::handle_quiz_xp(attempt.clone()).await;
+                log_badge_err(
+                    crate::features::badge::services::handle_quiz_badge(attempt).await,
+                );
                 fanout_if_some(space_pk.as_ref()).await;
                 r
             }
@@ -237,7 +286,19 @@ impl EventBridgeEnvelope {
                 let comment: crate::features::spaces::pages::actions::actions::discussion::SpacePostComment =
                     DetailType::parse_detail(&self.detail)?;
                 let space_pk = comment.space_pk.clone();
-                let r = crate::features::activity::services::handle_discussion_xp(comment).await;
+                // CommentLive: the comment and reply pipes both route here
+                // on INSERT, so this is the one place to push to open
+                // discussion pages.
+                crate::features::spaces::pages::actions::actions::discussion::realtime::publish_comment_created(
+                    comment.clone(),
+                )
+                .await;
+                let r =
+                    crate::features::activity::services::handle_discussion_xp(comment.clone())
+                        .await;
+                log_badge_err(
+                    crate::features::badge::services::handle_discussion_badge(comment).await,
+                );
                 fanout_if_some(space_pk.as_ref()).await;
                 r
             }
@@ -245,7 +306,10 @@ impl EventBridgeEnvelope {
                 let follow: crate::common::models::auth::UserFollow =
                     DetailType::parse_detail(&self.detail)?;
                 let space_pk = space_pk_from_id_str(follow.space_id.as_deref());
-                let r = crate::features::activity::services::handle_follow_xp(follow).await;
+                let r = crate::features::activity::services::handle_follow_xp(follow.clone()).await;
+                log_badge_err(
+                    crate::features::badge::services::handle_follow_badge(follow).await,
+                );
                 fanout_if_some(space_pk.as_ref()).await;
                 r
             }
@@ -413,11 +477,81 @@ impl EventBridgeEnvelope {
             DetailType::InboxPushFanout => {
                 let notification: crate::common::models::notification::UserInboxNotification =
                     DetailType::parse_detail(&self.detail)?;
+                let user_pk = notification.pk.clone();
                 // Best-effort: `fan_out_push` logs and swallows its own errors
                 // (the inbox row is already written), so it returns `()`.
                 crate::features::notifications::services::fan_out_push(notification).await;
+                // InboxLive: nudge the recipient's open sessions to refetch.
+                crate::features::notifications::realtime::publish_inbox_changed(&user_pk).await;
                 Ok(())
             }
+            DetailType::BadgeSpaceJoin => {
+                let participant: crate::common::models::space::SpaceParticipant =
+                    DetailType::parse_detail(&self.detail)?;
+                crate::features::badge::services::handle_space_join_badge(participant).await
+            }
+            DetailType::BadgeAccountCreated => {
+                let user: crate::common::models::auth::User =
+                    DetailType::parse_detail(&self.detail)?;
+                crate::features::badge::services::handle_user_created_badge(user).await
+            }
+            DetailType::MeetScheduleTick => {
+                let cfg = crate::common::CommonConfig::default();
+                let now = crate::common::utils::time::get_now_timestamp_millis();
+                crate::features::spaces::pages::actions::actions::meet::run_meet_scheduler(
+                    cfg.dynamodb(),
+                    now,
+                )
+                .await
+                .map(|_| ())
+            }
+            DetailType::EngagementRefreshTick => {
+                let cfg = crate::common::CommonConfig::default();
+                let now = crate::common::utils::time::get_now_timestamp_millis();
+                crate::features::cross_posting::services::engagement::run_engagement_refresh(
+                    cfg.dynamodb(),
+                    now,
+                )
+                .await
+                .map(|_| ())
+            }
+            DetailType::SyndicationScheduleTick => {
+                let cfg = crate::common::CommonConfig::default();
+                let now = crate::common::utils::time::get_now_timestamp_millis();
+                crate::features::cross_posting::services::scheduler::run_syndication_scheduler(
+                    cfg.dynamodb(),
+                    now,
+                )
+                .await
+                .map(|_| ())
+            }
+            DetailType::MeetTranscriptVectorIndex => {
+                let archive: crate::features::spaces::pages::actions::actions::meet::SpaceMeetArchive =
+                    DetailType::parse_detail(&self.detail)?;
+                crate::features::rag::qdrant::indexers::meet_transcript_indexer::index_meet_transcript(
+                    archive,
+                )
+                .await
+            }
+            DetailType::EssenceIndexMeetTranscript => {
+                let archive: crate::features::spaces::pages::actions::actions::meet::SpaceMeetArchive =
+                    DetailType::parse_detail(&self.detail)?;
+                let space_pk = archive.pk.clone();
+                let cfg = crate::common::CommonConfig::default();
+                let cli = cfg.dynamodb();
+                let r =
+                    crate::features::essence::services::index_meet_transcript(cli, &archive).await;
+                fanout_hot_space(&space_pk).await;
+                r
+            }
+            DetailType::MeetXpRecord => {
+                let attendance: crate::features::spaces::pages::actions::actions::meet::SpaceMeetAttendance =
+                    DetailType::parse_detail(&self.detail)?;
+                let space_pk = space_pk_from_id_str(Some(attendance.space_id.as_str()));
+                let r = crate::features::activity::services::handle_meet_xp(attendance).await;
+                fanout_if_some(space_pk.as_ref()).await;
+                r
+            }
             DetailType::Unknown => {
                 tracing::warn!(
                     "Unhandled EventBridge event: source={}",
@@ -452,6 +586,16 @@ async fn fanout_hot_space(space_pk: &crate::common::types::Partition) {
     crate::features::spaces::space_common::services::upsert_hot_space(cli, space_pk).await;
 }
 
+/// Badge progress piggybacks on the XP detail types; a badge failure is
+/// logged but never fails the envelope, so EventBridge doesn't retry the
+/// XP write alongside it.
+#[cfg(feature = "lambda")]
+fn log_badge_err(result: crate::common::Result<()>) {
+    if let Err(e) = result {
+        tracing::error!(error = %e, "badge progress failed");
+    }
+}
+
 #[cfg(feature = "lambda")]
 async fn fanout_if_some(space_pk: Option<&crate::common::types::Partition>) {
     if let Some(pk) = space_pk {
@@ -1,6 +1,8 @@
 use crate::common::*;
+use crate::features::badge::types::{BadgeCategory, BadgeId, BadgeRarity};
 use crate::features::cross_posting::models::ErrorCategory;
 use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::spaces::pages::actions::actions::meet::MeetNotice;
 use crate::features::spaces::pages::actions::types::SpaceActionType;
 #[cfg(feature = "server")]
 #[allow(unused_imports)]
@@ -31,6 +33,11 @@ pub enum InboxKind {
     /// A new comment/reply was posted on a discussion the recipient subscribes
     /// to (and they are not the author, a mentionee, or a direct reply target).
     DiscussionCommentPosted,
+    /// The recipient earned a badge. Sent once per badge, to the earner only.
+    BadgeAwarded,
+    /// A meet in one of the recipient's spaces was scheduled, is about to
+    /// start, went live or was cancelled. One row per (meet, notice).
+    MeetLifecycle,
 }
 
 impl Default for InboxKind {
@@ -58,6 +65,8 @@ impl InboxKind {
             InboxKind::SubTeamParentDeleted => "STTERM_PDEL",
             InboxKind::CrossPostingFailed => "XPOST_FAIL",
             InboxKind::DiscussionCommentPosted => "DISC_CMT",
+            InboxKind::BadgeAwarded => "BADGE",
+            InboxKind::MeetLifecycle => "MEET",
         }
     }
 }
@@ -183,6 +192,23 @@ pub enum InboxPayload {
         comment_preview: String,
         cta_url: String,
     },
+    BadgeAwarded {
+        badge_id: BadgeId,
+        badge_name: String,
+        badge_rarity: BadgeRarity,
+        badge_category: BadgeCategory,
+        criterion_text: String,
+        cta_url: String,
+    },
+    MeetLifecycle {
+        space_id: SpacePartition,
+        space_title: String,
+        meet_id: String,
+        meet_title: String,
+        notice: MeetNotice,
+        start_time: i64,
+        cta_url: String,
+    },
 }
 
 impl InboxPayload {
@@ -195,7 +221,8 @@ impl InboxPayload {
             InboxPayload::SpaceStatusChanged { space_id, .. }
             | InboxPayload::SpaceInvitation { space_id, .. }
             | InboxPayload::SpaceActionOngoing { space_id, .. }
-            | InboxPayload::DiscussionCommentPosted { space_id, .. } => Some(space_id.clone()),
+            | InboxPayload::DiscussionCommentPosted { space_id, .. }
+            | InboxPayload::MeetLifecycle { space_id, .. } => Some(space_id.clone()),
             InboxPayload::ReplyOnComment { space_id, .. } => space_id.clone(),
             _ => None,
         }
@@ -219,6 +246,8 @@ impl InboxPayload {
             InboxPayload::SubTeamParentDeleted { cta_url, .. } => cta_url,
             InboxPayload::CrossPostingFailed { cta_url, .. } => cta_url,
             InboxPayload::DiscussionCommentPosted { cta_url, .. } => cta_url,
+            InboxPayload::BadgeAwarded { cta_url, .. } => cta_url,
+            InboxPayload::MeetLifecycle { cta_url, .. } => cta_url,
         }
     }
 }
@@ -267,6 +296,8 @@ impl InboxPayload {
             InboxPayload::SubTeamParentDeleted { .. } => InboxKind::SubTeamParentDeleted,
             InboxPayload::CrossPostingFailed { .. } => InboxKind::CrossPostingFailed,
             InboxPayload::DiscussionCommentPosted { .. } => InboxKind::DiscussionCommentPosted,
+            InboxPayload::BadgeAwarded { .. } => InboxKind::BadgeAwarded,
+            InboxPayload::MeetLifecycle { .. } => InboxKind::MeetLifecycle,
         }
     }
 }
@@ -70,6 +70,14 @@ pub enum NotificationData {
         comment_content: String,
         cta_url: String,
     },
+    MeetLifecycle {
+        emails: Vec<String>,
+        space_title: String,
+        meet_title: String,
+        notice: crate::features::spaces::pages::actions::actions::meet::MeetNotice,
+        start_time: i64,
+        cta_url: String,
+    },
 }
 
 #[cfg(feature = "server")]
@@ -226,6 +234,28 @@ impl NotificationData {
                 )
                 .await?;
             }
+            NotificationData::MeetLifecycle {
+                emails,
+                space_title,
+                meet_title,
+                notice,
+                start_time,
+                cta_url,
+            } => {
+                let operation = EmailOperation::MeetNotification {
+                    headline: notice.email_headline(meet_title),
+                    body: notice.email_body(space_title, *start_time),
+                    space_title: space_title.clone(),
+                    meet_title: meet_title.clone(),
+                    cta_url: cta_url.clone(),
+                };
+
+                let template = EmailTemplate {
+                    targets: emails.clone(),
+                    operation,
+                };
+                template.send_email(ses).await?;
+            }
             NotificationData::None => {
                 tracing::warn!("Received notification with no data, skipping");
             }
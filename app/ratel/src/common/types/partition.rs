@@ -37,7 +37,7 @@ pub enum Partition {
     Space(String),
     SurveySpace(String),
     Requirement,
-    SpaceTemplate,
+    SpaceTemplate(String), // template id
 
     SpacePost(String),
     SpacePostLike(String),
@@ -146,6 +146,35 @@ pub enum Partition {
     /// accuracy + user id so an sk-descending query returns
     /// top-accuracy users first (PR7).
     FactFoldLeaderboard,
+
+    /// Anchor pk for the *Fact or Fold* collusion review queue. One
+    /// row per flagged round (`EntityType::FactFoldCollusionReview`);
+    /// the admin Reviews page lists this pk.
+    FactFoldReviews,
+
+    /// Durable per-channel realtime log (`common::realtime`). inner =
+    /// the full `ChannelId` (`<kind>:<inner>`). Holds the sequence
+    /// cursor (`EntityType::RealtimeChannelCursor`) and every appended
+    /// event (`EntityType::RealtimeChannelEvent(seq)`) so a
+    /// reconnecting SSE client can replay from `Last-Event-ID` on any
+    /// instance.
+    RealtimeChannel(String),
+
+    /// Cross-posting — one pk per Mastodon instance holding Ratel's
+    /// OAuth client registration on that instance
+    /// (`EntityType::MastodonApp`). inner = instance host.
+    MastodonInstance(String),
+
+    /// ActivityPub federation — a remote fediverse actor that follows,
+    /// likes or replies to Ratel content (`EntityType::RemoteActor`).
+    /// Also stands in as `user_pk` / `author_pk` on the `UserFollow`,
+    /// `PostLike` and `PostComment` rows it produces. inner = hashed
+    /// actor URL.
+    RemoteActor(String),
+    /// ActivityPub federation — dedupe / mapping marker for one remote
+    /// object or activity (`EntityType::FederatedObject`). inner = hashed
+    /// object id.
+    FederatedObject(String),
 }
 
 impl Partition {
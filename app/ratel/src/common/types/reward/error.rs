@@ -75,6 +75,27 @@ pub enum SpaceRewardError {
         ko = "이 액션에는 리워드 크레딧이 설정되어 있지 않습니다."
     )]
     NoCreditsConfigured,
+
+    #[error("Invalid streak multipliers")]
+    #[translate(
+        en = "Streak multipliers need distinct day counts of at least 1 and a bonus between 0% and 200%",
+        ko = "연속 활동 배율은 서로 다른 1일 이상의 일수와 0%~200% 사이의 보너스가 필요합니다."
+    )]
+    InvalidStreakMultipliers,
+
+    #[error("Invalid reward rules")]
+    #[translate(
+        en = "Reward rules need positive limits, non-empty time windows, distinct claim tiers and multipliers between 0% and 1000%",
+        ko = "리워드 규칙에는 양수 한도, 비어 있지 않은 기간, 서로 다른 수령 구간과 0%~1000% 사이의 배율이 필요합니다."
+    )]
+    InvalidRewardRules,
+
+    #[error("Invalid reward simulation")]
+    #[translate(
+        en = "Simulate between 1 and 100,000 participants with non-negative credits",
+        ko = "참여자 1명~100,000명, 0 이상의 크레딧으로 시뮬레이션하세요."
+    )]
+    InvalidSimulation,
 }
 
 #[cfg(feature = "server")]
@@ -3,7 +3,9 @@ mod reward_action;
 mod reward_condition;
 mod reward_key;
 mod reward_period;
+mod reward_rules;
 mod reward_user_behavior;
+mod streak_multiplier;
 mod user_reward_history_key;
 
 pub use error::*;
@@ -11,5 +13,7 @@ pub use reward_action::*;
 pub use reward_condition::*;
 pub use reward_key::*;
 pub use reward_period::*;
+pub use reward_rules::*;
 pub use reward_user_behavior::*;
+pub use streak_multiplier::*;
 pub use user_reward_history_key::*;
@@ -9,3 +9,53 @@ pub enum RewardCondition {
     MaxUserClaims(i64),
     MaxUserPoints(i64),
 }
+
+/// Counters a claim is checked against, read before the claim is written.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct RewardUsage {
+    pub total_claims: i64,
+    pub total_points: i64,
+    pub user_claims: i64,
+    pub user_points: i64,
+}
+
+impl RewardCondition {
+    /// `Err` once the counter this condition caps has reached its limit.
+    pub fn check(&self, usage: &RewardUsage) -> std::result::Result<(), SpaceRewardError> {
+        let (used, max, err) = match *self {
+            RewardCondition::None => return Ok(()),
+            RewardCondition::MaxClaims(max) => {
+                (usage.total_claims, max, SpaceRewardError::MaxClaimsReached)
+            }
+            RewardCondition::MaxPoints(max) => {
+                (usage.total_points, max, SpaceRewardError::MaxPointsReached)
+            }
+            RewardCondition::MaxUserClaims(max) => {
+                (usage.user_claims, max, SpaceRewardError::MaxUserClaimsReached)
+            }
+            RewardCondition::MaxUserPoints(max) => {
+                (usage.user_points, max, SpaceRewardError::MaxUserPointsReached)
+            }
+        };
+        if used >= max {
+            Err(err)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn limit(&self) -> Option<i64> {
+        match *self {
+            RewardCondition::None => None,
+            RewardCondition::MaxClaims(max)
+            | RewardCondition::MaxPoints(max)
+            | RewardCondition::MaxUserClaims(max)
+            | RewardCondition::MaxUserPoints(max) => Some(max),
+        }
+    }
+
+    /// A rule condition must actually cap something.
+    pub(super) fn is_valid_rule(&self) -> bool {
+        self.limit().is_some_and(|max| max > 0)
+    }
+}
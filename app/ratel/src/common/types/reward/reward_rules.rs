@@ -0,0 +1,295 @@
+use crate::common::*;
+use crate::features::membership::models::MembershipTier;
+
+/// Payouts inside `[starts_at, ends_at)` are scaled by
+/// `multiplier_percent` — e.g. `200` for a double-points launch week.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RewardTimeWindow {
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub multiplier_percent: i64,
+}
+
+impl RewardTimeWindow {
+    pub fn contains(&self, at: i64) -> bool {
+        (self.starts_at..self.ends_at).contains(&at)
+    }
+}
+
+/// `point` per credit for the first `max_claims` claims of a reward, so
+/// early responders can earn more. Claims past every tier fall back to
+/// the reward's own `point`. A tier pays at most
+/// [`RewardRules::MAX_MULTIPLIER_PERCENT`] of that `point`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RewardPointTier {
+    pub max_claims: i64,
+    pub point: i64,
+}
+
+/// Payout scale for claimants on a membership tier. Any `Enterprise`
+/// plan matches an `Enterprise` row regardless of its name.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MembershipMultiplier {
+    pub tier: MembershipTier,
+    pub multiplier_percent: i64,
+}
+
+/// Everything a claim is evaluated against besides the rules themselves.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RewardClaimContext {
+    pub at: i64,
+    pub usage: RewardUsage,
+    pub membership_tier: Option<MembershipTier>,
+}
+
+/// Composable payout rules on top of a reward's flat `point`, `period`
+/// and `condition`. An empty set pays exactly what the flat fields say.
+///
+/// Evaluation order for one claim:
+/// 1. the reward's `condition` and every entry in `conditions` must pass
+///    (AND);
+/// 2. the point tier the claim falls into replaces `point`;
+/// 3. the best active time window and the claimant's membership
+///    multiplier scale `point × credits`; the combined scale, and the
+///    payout itself, never exceed `MAX_MULTIPLIER_PERCENT` of the flat
+///    `point × credits`. The space is charged membership credits for
+///    that worst case up front ([`RewardRules::billed_credits`]);
+/// 4. character skills (Money Tree) boost the result unless
+///    `skill_multipliers` is off. The owner bonus is cut from the amount
+///    before this step, as before.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RewardRules {
+    #[serde(default)]
+    pub conditions: Vec<RewardCondition>,
+    #[serde(default)]
+    pub time_windows: Vec<RewardTimeWindow>,
+    #[serde(default)]
+    pub point_tiers: Vec<RewardPointTier>,
+    #[serde(default)]
+    pub membership_multipliers: Vec<MembershipMultiplier>,
+    #[serde(default = "skill_multipliers_on")]
+    pub skill_multipliers: bool,
+}
+
+fn skill_multipliers_on() -> bool {
+    true
+}
+
+impl Default for RewardRules {
+    fn default() -> Self {
+        Self {
+            conditions: vec![],
+            time_windows: vec![],
+            point_tiers: vec![],
+            membership_multipliers: vec![],
+            skill_multipliers: true,
+        }
+    }
+}
+
+impl RewardRules {
+    /// Ceiling for every scale a rule can apply: each multiplier, their
+    /// product, a tier's point relative to the base point, and so the
+    /// whole payout relative to `base_point × credits`.
+    pub const MAX_MULTIPLIER_PERCENT: i64 = 1000;
+
+    /// AND of `condition` and every rule condition.
+    pub fn check(
+        &self,
+        condition: &RewardCondition,
+        usage: &RewardUsage,
+    ) -> std::result::Result<(), SpaceRewardError> {
+        std::iter::once(condition)
+            .chain(self.conditions.iter())
+            .try_for_each(|c| c.check(usage))
+    }
+
+    /// Points per credit for the claim after `claims_so_far`: the
+    /// smallest tier that still has room, else `base_point`.
+    pub fn point_for(&self, base_point: i64, claims_so_far: i64) -> i64 {
+        self.point_tiers
+            .iter()
+            .filter(|t| claims_so_far < t.max_claims)
+            .min_by_key(|t| t.max_claims)
+            .map_or(base_point, |t| {
+                t.point.min(Self::max_tier_point(base_point))
+            })
+    }
+
+    fn max_tier_point(base_point: i64) -> i64 {
+        base_point.saturating_mul(Self::MAX_MULTIPLIER_PERCENT) / 100
+    }
+
+    /// Combined scale in percent; `100` leaves the payout unchanged.
+    /// Overlapping windows do not stack — the best one wins — and the
+    /// window × membership product is capped at `MAX_MULTIPLIER_PERCENT`.
+    pub fn multiplier_percent(&self, at: i64, tier: Option<&MembershipTier>) -> i64 {
+        let window = self
+            .time_windows
+            .iter()
+            .filter(|w| w.contains(at))
+            .map(|w| w.multiplier_percent)
+            .max()
+            .unwrap_or(100);
+        let membership = tier
+            .and_then(|tier| {
+                self.membership_multipliers
+                    .iter()
+                    .find(|m| same_tier(&m.tier, tier))
+            })
+            .map_or(100, |m| m.multiplier_percent);
+        (window * membership / 100).min(Self::MAX_MULTIPLIER_PERCENT)
+    }
+
+    /// Payout for the next claim before character skills, clamped to
+    /// [`max_amount`](Self::max_amount) so a tier and both multipliers
+    /// together can't exceed what [`billed_credits`](Self::billed_credits)
+    /// charged the space for.
+    pub fn amount(&self, base_point: i64, credits: i64, ctx: &RewardClaimContext) -> i64 {
+        let point = self.point_for(base_point, ctx.usage.total_claims);
+        let percent = self.multiplier_percent(ctx.at, ctx.membership_tier.as_ref());
+        let amount = point.saturating_mul(credits).saturating_mul(percent) / 100;
+        amount.min(Self::max_amount(base_point, credits))
+    }
+
+    /// Most a single claim can pay before character skills:
+    /// `MAX_MULTIPLIER_PERCENT` of the flat `base_point × credits`.
+    pub fn max_amount(base_point: i64, credits: i64) -> i64 {
+        base_point
+            .saturating_mul(credits)
+            .saturating_mul(Self::MAX_MULTIPLIER_PERCENT)
+            / 100
+    }
+
+    /// Largest scale one claim can reach under these rules, in percent of
+    /// `base_point × credits`: the best tier, window and membership
+    /// multiplier together, capped as in [`amount`](Self::amount). Never
+    /// below `100`, since a claim outside every rule pays the flat amount.
+    pub fn worst_case_percent(&self, base_point: i64) -> i64 {
+        let tier = if base_point > 0 {
+            let max_tier_point = Self::max_tier_point(base_point);
+            self.point_tiers
+                .iter()
+                .map(|t| (t.point.min(max_tier_point) * 100 + base_point - 1) / base_point)
+                .fold(100, i64::max)
+        } else {
+            100
+        };
+        let window = self
+            .time_windows
+            .iter()
+            .map(|w| w.multiplier_percent)
+            .fold(100, i64::max);
+        let membership = self
+            .membership_multipliers
+            .iter()
+            .map(|m| m.multiplier_percent)
+            .fold(100, i64::max);
+        let multiplier = (window * membership / 100).min(Self::MAX_MULTIPLIER_PERCENT);
+        (tier * multiplier / 100).min(Self::MAX_MULTIPLIER_PERCENT)
+    }
+
+    /// Membership credits a reward of `credits` costs under these rules:
+    /// enough for every claim to pay the
+    /// [`worst_case_percent`](Self::worst_case_percent), so rules never
+    /// pay out more than the space was charged for.
+    pub fn billed_credits(&self, base_point: i64, credits: i64) -> i64 {
+        let percent = self.worst_case_percent(base_point);
+        (credits.saturating_mul(percent) + 99) / 100
+    }
+
+    /// Whether evaluating a claim needs the claimant's membership tier.
+    pub fn needs_membership_tier(&self) -> bool {
+        !self.membership_multipliers.is_empty()
+    }
+
+    /// Conditions must cap something, windows must be non-empty, tiers
+    /// need distinct positive claim counts and a point within
+    /// `MAX_MULTIPLIER_PERCENT` of `base_point`, and every multiplier
+    /// stays within `0..=MAX_MULTIPLIER_PERCENT`.
+    pub fn validate(&self, base_point: i64) -> std::result::Result<(), SpaceRewardError> {
+        let percent_ok = |p: i64| (0..=Self::MAX_MULTIPLIER_PERCENT).contains(&p);
+        let max_tier_point = Self::max_tier_point(base_point);
+
+        let mut tier_claims: Vec<i64> = self.point_tiers.iter().map(|t| t.max_claims).collect();
+        tier_claims.sort_unstable();
+        tier_claims.dedup();
+
+        let membership_unique = self.membership_multipliers.iter().enumerate().all(|(i, m)| {
+            !self.membership_multipliers[..i]
+                .iter()
+                .any(|other| same_tier(&other.tier, &m.tier))
+        });
+
+        let valid = self.conditions.iter().all(RewardCondition::is_valid_rule)
+            && self
+                .time_windows
+                .iter()
+                .all(|w| w.starts_at < w.ends_at && percent_ok(w.multiplier_percent))
+            && tier_claims.len() == self.point_tiers.len()
+            && self
+                .point_tiers
+                .iter()
+                .all(|t| t.max_claims >= 1 && (0..=max_tier_point).contains(&t.point))
+            && membership_unique
+            && self
+                .membership_multipliers
+                .iter()
+                .all(|m| percent_ok(m.multiplier_percent));
+        if valid {
+            Ok(())
+        } else {
+            Err(SpaceRewardError::InvalidRewardRules)
+        }
+    }
+}
+
+/// Outcome of a dry run over a number of claims; see
+/// [`RewardRules::project`].
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RewardProjection {
+    pub paid_claims: i64,
+    pub first_payout: i64,
+    pub last_payout: i64,
+    pub total_points: i64,
+}
+
+impl RewardRules {
+    /// Replay `participants` first-time claims in order, starting from
+    /// `ctx`, without touching storage. `boost` stands in for character
+    /// skills and is skipped when `skill_multipliers` is off. Stops at
+    /// the first claim a condition would reject.
+    pub fn project(
+        &self,
+        base_point: i64,
+        credits: i64,
+        condition: &RewardCondition,
+        participants: i64,
+        mut ctx: RewardClaimContext,
+        boost: impl Fn(i64) -> i64,
+    ) -> RewardProjection {
+        let mut projection = RewardProjection::default();
+        for _ in 0..participants {
+            ctx.usage.user_claims = 0;
+            ctx.usage.user_points = 0;
+            if self.check(condition, &ctx.usage).is_err() {
+                break;
+            }
+            let raw = self.amount(base_point, credits, &ctx);
+            let paid = if self.skill_multipliers { boost(raw) } else { raw };
+            if projection.paid_claims == 0 {
+                projection.first_payout = paid;
+            }
+            projection.last_payout = paid;
+            projection.paid_claims += 1;
+            projection.total_points += paid;
+            ctx.usage.total_claims += 1;
+            ctx.usage.total_points += raw;
+        }
+        projection
+    }
+}
+
+fn same_tier(a: &MembershipTier, b: &MembershipTier) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
+}
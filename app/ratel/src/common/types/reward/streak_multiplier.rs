@@ -0,0 +1,71 @@
+use crate::common::*;
+
+/// One rung of a streak XP ladder on a global `Reward`. Once a user's
+/// activity streak reaches `min_streak_days`, XP from that behavior gains
+/// `bonus_percent` on top — written to `SpaceActivity.additional_score`,
+/// so `base_score` stays unboosted.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct StreakMultiplier {
+    pub min_streak_days: i64,
+    pub bonus_percent: i64,
+}
+
+impl StreakMultiplier {
+    pub const MAX_BONUS_PERCENT: i64 = 200;
+
+    /// Bonus of the highest rung `streak_days` has reached, `0` below the
+    /// first rung.
+    pub fn bonus_percent_for(tiers: &[Self], streak_days: i64) -> i64 {
+        tiers
+            .iter()
+            .filter(|t| streak_days >= t.min_streak_days)
+            .map(|t| t.bonus_percent)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Rungs must start at day 1 or later, have distinct day counts and
+    /// a bonus within `0..=MAX_BONUS_PERCENT`.
+    pub fn validate(tiers: &[Self]) -> std::result::Result<(), SpaceRewardError> {
+        let mut days: Vec<i64> = tiers.iter().map(|t| t.min_streak_days).collect();
+        days.sort_unstable();
+        days.dedup();
+        let valid = days.len() == tiers.len()
+            && tiers.iter().all(|t| {
+                t.min_streak_days >= 1 && (0..=Self::MAX_BONUS_PERCENT).contains(&t.bonus_percent)
+            });
+        if valid {
+            Ok(())
+        } else {
+            Err(SpaceRewardError::InvalidStreakMultipliers)
+        }
+    }
+
+    /// `"7:10, 30:25"` — the admin form's text representation.
+    pub fn format_list(tiers: &[Self]) -> String {
+        tiers
+            .iter()
+            .map(|t| format!("{}:{}", t.min_streak_days, t.bonus_percent))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Inverse of [`format_list`](Self::format_list). Empty input is an
+    /// empty ladder; any malformed rung rejects the whole list.
+    pub fn parse_list(s: &str) -> Option<Vec<Self>> {
+        let mut tiers = s
+            .split(',')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let (days, bonus) = part.split_once(':')?;
+                Some(Self {
+                    min_streak_days: days.trim().parse().ok()?,
+                    bonus_percent: bonus.trim().trim_end_matches('%').parse().ok()?,
+                })
+            })
+            .collect::<Option<Vec<_>>>()?;
+        tiers.sort_by_key(|t| t.min_streak_days);
+        Some(tiers)
+    }
+}
@@ -95,7 +95,7 @@ async fn has_completed_prerequisite_actions(
 
 /// Checks whether `user_pk` has personally completed `action`.
 /// Dispatches on action type (Poll → UserAnswer, Quiz → Attempt,
-/// Discussion → Comment, Follow → UserFollow edges).
+/// Discussion → Comment, Follow → UserFollow edges, Meet → Attendance).
 #[cfg(feature = "server")]
 pub async fn has_completed_prerequisite_action(
     cli: &aws_sdk_dynamodb::Client,
@@ -114,11 +114,30 @@ pub async fn has_completed_prerequisite_action(
             has_completed_discussion_action(cli, &action.pk.1, user_pk).await
         }
         SpaceActionType::Follow => has_completed_follow_action(cli, space, user_pk).await,
-        // Meet prerequisites are not tracked per-user yet; treat as not-completed.
-        SpaceActionType::Meet => Ok(false),
+        SpaceActionType::Meet => {
+            has_attended_meet_action(cli, &space.pk, &action.pk.1, user_pk).await
+        }
     }
 }
 
+#[cfg(feature = "server")]
+async fn has_attended_meet_action(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    action_id: &str,
+    user_pk: &Partition,
+) -> Result<bool> {
+    use crate::features::spaces::pages::actions::actions::meet::SpaceMeetAttendance;
+
+    let meet_id: SpaceMeetEntityType = action_id.to_string().into();
+    let (pk, sk) = SpaceMeetAttendance::keys(space_pk, &meet_id, user_pk);
+
+    SpaceMeetAttendance::get(cli, pk, Some(sk))
+        .await
+        .map(|attendance| attendance.is_some())
+        .map_err(|err| prerequisite_check_error("Failed to verify meet attendance", err))
+}
+
 #[cfg(feature = "server")]
 async fn has_completed_poll_action(
     cli: &aws_sdk_dynamodb::Client,
@@ -288,34 +307,22 @@ async fn has_completed_follow_action(
 }
 
 #[cfg(feature = "server")]
-impl<S> FromRequestParts<S> for SpaceUserRole
-where
-    S: Send + Sync,
-{
-    type Rejection = Error;
-
-    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
-        use crate::common::models::auth::User;
-        use crate::common::models::space::{SpaceCommon, SpaceParticipant};
+impl SpaceUserRole {
+    /// Resolve the caller's role in `space`. Shared by the request
+    /// extractor below and by callers that have no request to extract
+    /// from (e.g. realtime channel authorize hooks). `None` = signed out.
+    pub async fn resolve(
+        space: &crate::common::models::space::SpaceCommon,
+        user: Option<&crate::common::models::auth::User>,
+    ) -> Result<Self> {
+        use crate::common::models::space::SpaceParticipant;
         use crate::common::types::{CompositePartition, EntityType};
         use crate::features::spaces::{InvitationStatus, SpaceInvitationMember};
-        tracing::debug!("extracting space from request parts. Path: {:?}", parts.uri);
-
-        if let Some(space_role) = parts.extensions.get::<SpaceUserRole>() {
-            return Ok(space_role.clone());
-        }
-
-        let space = SpaceCommon::from_request_parts(parts, state)
-            .await
-            .map_err(|err| serializable_role_error("Failed to load space for role", err))?;
-
-        let user = User::from_request_parts(parts, state).await.ok();
 
         let public_space = space.is_public();
 
         if user.is_none() {
             if public_space {
-                parts.extensions.insert(SpaceUserRole::Viewer);
                 return Ok(SpaceUserRole::Viewer);
             } else {
                 return Err(Error::UnauthorizedAccess);
@@ -326,7 +333,6 @@ where
 
         // Individual creator check
         if user.pk == space.user_pk {
-            parts.extensions.insert(SpaceUserRole::Creator);
             return Ok(SpaceUserRole::Creator);
         }
 
@@ -347,7 +353,6 @@ where
                 .flatten();
             if let Some(role) = team_role {
                 if role.is_admin_or_owner() {
-                    parts.extensions.insert(SpaceUserRole::Creator);
                     return Ok(SpaceUserRole::Creator);
                 }
             }
@@ -362,7 +367,6 @@ where
                 .ok()
                 .flatten();
             if space_admin.is_some() {
-                parts.extensions.insert(SpaceUserRole::Creator);
                 return Ok(SpaceUserRole::Creator);
             }
         }
@@ -384,7 +388,7 @@ where
                 space.status,
                 Some(crate::common::SpaceStatus::Ongoing | crate::common::SpaceStatus::Finished)
             ) {
-                if has_completed_prerequisite_actions(cli, &space, &user)
+                if has_completed_prerequisite_actions(cli, space, user)
                     .await
                     .map_err(|err| {
                         serializable_role_error(
@@ -400,7 +404,6 @@ where
             } else {
                 SpaceUserRole::Candidate
             };
-            parts.extensions.insert(role);
             return Ok(role);
         }
 
@@ -415,7 +418,6 @@ where
                 invitation.as_ref().map(|member| member.status),
                 Some(InvitationStatus::Invited) | Some(InvitationStatus::Accepted)
             ) {
-                parts.extensions.insert(SpaceUserRole::Viewer);
                 return Ok(SpaceUserRole::Viewer);
             }
 
@@ -437,7 +439,6 @@ where
                     )
                     .await
                     {
-                        parts.extensions.insert(SpaceUserRole::Viewer);
                         return Ok(SpaceUserRole::Viewer);
                     }
                 }
@@ -447,10 +448,37 @@ where
         // For public spaces, unauthenticated users are Viewers (handled above),
         // but authenticated non-participants are also Viewers.
         if public_space {
-            parts.extensions.insert(SpaceUserRole::Viewer);
             return Ok(SpaceUserRole::Viewer);
         }
 
         Err(Error::UnauthorizedAccess)
     }
 }
+
+#[cfg(feature = "server")]
+impl<S> FromRequestParts<S> for SpaceUserRole
+where
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        use crate::common::models::auth::User;
+        use crate::common::models::space::SpaceCommon;
+        tracing::debug!("extracting space from request parts. Path: {:?}", parts.uri);
+
+        if let Some(space_role) = parts.extensions.get::<SpaceUserRole>() {
+            return Ok(space_role.clone());
+        }
+
+        let space = SpaceCommon::from_request_parts(parts, state)
+            .await
+            .map_err(|err| serializable_role_error("Failed to load space for role", err))?;
+
+        let user = User::from_request_parts(parts, state).await.ok();
+
+        let role = SpaceUserRole::resolve(&space, user.as_ref()).await?;
+        parts.extensions.insert(role);
+        Ok(role)
+    }
+}
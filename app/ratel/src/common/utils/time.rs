@@ -85,6 +85,21 @@ pub fn datetime_local_to_epoch_ms(s: &str) -> Option<i64> {
     }
 }
 
+/// The browser's current UTC offset in minutes **east** of UTC (540 for
+/// KST). The server-side fallback is UTC.
+pub fn local_utc_offset_minutes() -> i16 {
+    #[cfg(all(feature = "web", not(feature = "server")))]
+    {
+        // `getTimezoneOffset()` is minutes WEST of UTC, hence the negation.
+        return -(js_sys::Date::new_0().get_timezone_offset() as i16);
+    }
+
+    #[cfg(not(all(feature = "web", not(feature = "server"))))]
+    {
+        0
+    }
+}
+
 pub fn time_ago(timestamp_millis: i64) -> String {
     let now = chrono::Utc::now().timestamp_millis();
     let diff = now - timestamp_millis;
@@ -0,0 +1,58 @@
+use crate::features::activity::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct LeaderboardExportResponse {
+    pub file_name: String,
+    pub content: String,
+}
+
+/// CSV of the live standings, or of the closed period `period_key`
+/// (e.g. `week-2026-W42`). Creators only — it lists every participant.
+#[get(
+    "/api/spaces/:space_id/leaderboard/export?category&period_key",
+    role: SpaceUserRole,
+    _space: crate::common::models::space::SpaceCommon
+)]
+pub async fn export_leaderboard_handler(
+    space_id: SpacePartition,
+    category: Option<LeaderboardCategory>,
+    period_key: Option<String>,
+) -> Result<LeaderboardExportResponse> {
+    use crate::features::activity::models::SpaceLeaderboardSnapshot;
+    use crate::features::activity::services::load_leaderboard;
+
+    if role != SpaceUserRole::Creator {
+        return Err(Error::NoPermission);
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let space_pk: Partition = space_id.into();
+    let category = category.unwrap_or_default();
+
+    let (label, entries) = match period_key {
+        Some(period_key) => {
+            let (pk, sk) = SpaceLeaderboardSnapshot::keys(&space_pk, &period_key);
+            let snapshot = SpaceLeaderboardSnapshot::get(cli, &pk, Some(sk))
+                .await
+                .map_err(|e| {
+                    crate::error!("leaderboard snapshot: {e:?}");
+                    ActivityError::RankingLoadFailed
+                })?
+                .ok_or(ActivityError::SnapshotNotFound)?;
+            (period_key, snapshot.entries)
+        }
+        None => {
+            let entries = load_leaderboard(cli, &space_pk).await.map_err(|e| {
+                crate::error!("leaderboard: {e:?}");
+                ActivityError::RankingLoadFailed
+            })?;
+            ("current".to_string(), entries)
+        }
+    };
+
+    Ok(LeaderboardExportResponse {
+        file_name: format!("leaderboard-{label}-{}.csv", category.as_str()),
+        content: render_leaderboard_csv(&rank_entries(&entries, category)),
+    })
+}
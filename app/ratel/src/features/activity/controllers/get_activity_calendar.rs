@@ -0,0 +1,122 @@
+use crate::features::activity::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActivityCalendarDay {
+    pub date: String,
+    pub activity_count: i64,
+    pub xp: i64,
+    /// 0 (none) … 4 (busiest) — the grid's cell shade.
+    pub level: u8,
+}
+
+/// GitHub-style contribution calendar: one cell per local day, oldest
+/// first, ending today in the user's offset, plus the streak summary.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActivityCalendarResponse {
+    pub start_date: String,
+    pub end_date: String,
+    /// The owner's own offset; omitted from public calendars so a profile
+    /// doesn't reveal where its user lives.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub utc_offset_minutes: Option<i32>,
+    pub days: Vec<ActivityCalendarDay>,
+    pub total_activities: i64,
+    /// 0 once the streak is broken, even before the next activity resets it.
+    pub current_streak_days: i64,
+    pub longest_streak_days: i64,
+    pub grace_days_available: i64,
+}
+
+/// `days` defaults to 365 and is capped at 371 (53 full weeks).
+#[get("/api/me/activity/calendar?days", user: crate::features::auth::User)]
+pub async fn get_my_activity_calendar_handler(
+    days: Option<i64>,
+) -> Result<ActivityCalendarResponse> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    build_activity_calendar(cli, &user.pk, days).await
+}
+
+/// Public calendar for a profile. Same payload as the owner's minus the
+/// UTC offset; counts and streaks are already visible through spaces and
+/// rankings.
+#[get("/api/users/{username}/activity/calendar?days")]
+pub async fn get_user_activity_calendar_handler(
+    username: String,
+    days: Option<i64>,
+) -> Result<ActivityCalendarResponse> {
+    use crate::common::models::auth::User;
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let opt = User::opt().limit(1);
+    let (users, _) = User::find_by_username(cli, &username, opt).await?;
+    let target = users
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::NotFound(format!("no user with username {username}")))?;
+    let mut calendar = build_activity_calendar(cli, &target.pk, days).await?;
+    calendar.utc_offset_minutes = None;
+    Ok(calendar)
+}
+
+#[cfg(feature = "server")]
+async fn build_activity_calendar(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    days: Option<i64>,
+) -> Result<ActivityCalendarResponse> {
+    use crate::features::activity::models::{UserActivityStreak, UserDailyActivity};
+    use std::collections::HashMap;
+
+    let window = days
+        .unwrap_or(DEFAULT_CALENDAR_DAYS)
+        .clamp(1, MAX_CALENDAR_DAYS);
+
+    let streak = UserActivityStreak::get_or_default(cli, user_pk).await?;
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let today = local_day(now, streak.utc_offset_minutes);
+    let first = today - window + 1;
+
+    // Rows exist only for active days, so the newest `window` rows always
+    // cover the window.
+    let rows = UserDailyActivity::list_recent(cli, user_pk, window as i32).await?;
+    let by_day: HashMap<i64, UserDailyActivity> = rows
+        .into_iter()
+        .filter(|r| r.day >= first && r.day <= today)
+        .map(|r| (r.day, r))
+        .collect();
+
+    let days: Vec<ActivityCalendarDay> = (first..=today)
+        .map(|day| {
+            let (activity_count, xp) = by_day
+                .get(&day)
+                .map(|r| (r.activity_count, r.xp))
+                .unwrap_or_default();
+            ActivityCalendarDay {
+                date: day_to_date(day),
+                activity_count,
+                xp,
+                level: calendar_level(activity_count),
+            }
+        })
+        .collect();
+
+    let state = streak.state();
+    Ok(ActivityCalendarResponse {
+        start_date: day_to_date(first),
+        end_date: day_to_date(today),
+        utc_offset_minutes: Some(streak.utc_offset_minutes),
+        total_activities: days.iter().map(|d| d.activity_count).sum(),
+        days,
+        current_streak_days: state.current_as_of(today),
+        longest_streak_days: state.longest_streak_days,
+        grace_days_available: state.grace_days_available,
+    })
+}
@@ -0,0 +1,85 @@
+use crate::features::activity::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct LeaderboardEntryResponse {
+    pub rank: u32,
+    pub user_pk: String,
+    pub name: String,
+    pub avatar: String,
+    pub score: i64,
+    /// Places moved since the compared snapshot; positive is up. `None`
+    /// for a participant who was not ranked then.
+    #[serde(default)]
+    pub rank_change: Option<i32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct LeaderboardResponse {
+    pub category: LeaderboardCategory,
+    pub period: LeaderboardPeriod,
+    /// Snapshot `rank_change` is measured against, e.g. `day-2026-10-18`.
+    #[serde(default)]
+    pub compared_to: Option<String>,
+    pub entries: Vec<LeaderboardEntryResponse>,
+}
+
+/// Rank `current` by `category`, with deltas against `previous`.
+pub fn leaderboard_entries(
+    current: &[LeaderboardSnapshotEntry],
+    previous: Option<&[LeaderboardSnapshotEntry]>,
+    category: LeaderboardCategory,
+) -> Vec<LeaderboardEntryResponse> {
+    let previous = previous.map(|p| rank_entries(p, category));
+    rank_entries(current, category)
+        .into_iter()
+        .map(|(rank, e)| LeaderboardEntryResponse {
+            rank_change: previous
+                .as_deref()
+                .and_then(|p| rank_change(p, &e.user_pk, rank)),
+            rank,
+            score: e.score(category),
+            user_pk: e.user_pk,
+            name: e.name,
+            avatar: e.avatar,
+        })
+        .collect()
+}
+
+/// Live standings for one category, compared with the most recent closed
+/// day (or week).
+#[get(
+    "/api/spaces/:space_id/leaderboard?category&period",
+    _space: crate::common::models::space::SpaceCommon
+)]
+pub async fn get_leaderboard_handler(
+    space_id: SpacePartition,
+    category: Option<LeaderboardCategory>,
+    period: Option<LeaderboardPeriod>,
+) -> Result<LeaderboardResponse> {
+    use crate::features::activity::services::load_ranked_leaderboard;
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let space_pk: Partition = space_id.into();
+    let category = category.unwrap_or_default();
+    let period = period.unwrap_or_default();
+
+    let (entries, compared_to) = load_ranked_leaderboard(cli, &space_pk, category, period)
+        .await
+        .map_err(|e| {
+            crate::error!("leaderboard: {e:?}");
+            ActivityError::RankingLoadFailed
+        })?;
+
+    Ok(LeaderboardResponse {
+        category,
+        period,
+        compared_to,
+        entries,
+    })
+}
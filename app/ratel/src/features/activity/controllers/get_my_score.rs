@@ -11,6 +11,8 @@ pub struct MyScoreResponse {
     pub quiz_score: i64,
     pub follow_score: i64,
     pub discussion_score: i64,
+    #[serde(default)]
+    pub meet_score: i64,
     pub rank: u32,
 }
 
@@ -60,6 +62,7 @@ pub async fn get_my_score_handler(space_id: SpacePartition) -> Result<MyScoreRes
         quiz_score: score.quiz_score,
         follow_score: score.follow_score,
         discussion_score: score.discussion_score,
+        meet_score: score.meet_score,
         rank,
     })
 }
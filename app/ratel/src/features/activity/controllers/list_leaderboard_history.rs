@@ -0,0 +1,73 @@
+use crate::features::activity::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+const HISTORY_PAGE_SIZE: i32 = 7;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct LeaderboardSnapshotResponse {
+    pub period: LeaderboardPeriod,
+    pub period_key: String,
+    pub created_at: i64,
+    /// `rank_change` is measured against the snapshot before this one.
+    pub entries: Vec<LeaderboardEntryResponse>,
+}
+
+/// Closed-period standings for one category, newest first.
+#[get(
+    "/api/spaces/:space_id/leaderboard/history?category&period&bookmark",
+    _space: crate::common::models::space::SpaceCommon
+)]
+pub async fn list_leaderboard_history_handler(
+    space_id: SpacePartition,
+    category: Option<LeaderboardCategory>,
+    period: Option<LeaderboardPeriod>,
+    bookmark: Option<String>,
+) -> Result<ListResponse<LeaderboardSnapshotResponse>> {
+    use crate::features::activity::models::SpaceLeaderboardSnapshot;
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let space_pk: Partition = space_id.into();
+    let category = category.unwrap_or_default();
+    let period = period.unwrap_or_default();
+
+    let load_failed = |e: crate::common::Error| {
+        crate::error!("leaderboard history: {e:?}");
+        ActivityError::RankingLoadFailed
+    };
+    let (snapshots, next_bookmark) =
+        SpaceLeaderboardSnapshot::list_recent(cli, &space_pk, period, bookmark, HISTORY_PAGE_SIZE)
+            .await
+            .map_err(load_failed)?;
+    // The oldest snapshot on the page compares against the next page's
+    // first one.
+    let older = match next_bookmark.clone() {
+        Some(bk) => SpaceLeaderboardSnapshot::list_recent(cli, &space_pk, period, Some(bk), 1)
+            .await
+            .map_err(load_failed)?
+            .0
+            .into_iter()
+            .next(),
+        None => None,
+    };
+
+    let mut items = Vec::with_capacity(snapshots.len());
+    for (i, snapshot) in snapshots.iter().enumerate() {
+        let previous = snapshots.get(i + 1).or(older.as_ref());
+        items.push(LeaderboardSnapshotResponse {
+            period: snapshot.period,
+            period_key: snapshot.period_key.clone(),
+            created_at: snapshot.created_at,
+            entries: leaderboard_entries(
+                &snapshot.entries,
+                previous.map(|p| p.entries.as_slice()),
+                category,
+            ),
+        });
+    }
+
+    Ok((items, next_bookmark).into())
+}
@@ -1,9 +1,19 @@
+mod export_leaderboard;
+mod get_activity_calendar;
+mod get_leaderboard;
 mod get_ranking;
 mod get_my_score;
+mod list_leaderboard_history;
 #[cfg(feature = "server")]
 mod record_activity;
+mod update_activity_timezone;
 
+pub use export_leaderboard::*;
+pub use get_activity_calendar::*;
+pub use get_leaderboard::*;
 pub use get_ranking::*;
 pub use get_my_score::*;
+pub use list_leaderboard_history::*;
 #[cfg(feature = "server")]
 pub use record_activity::*;
+pub use update_activity_timezone::*;
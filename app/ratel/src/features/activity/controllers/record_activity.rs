@@ -12,6 +12,8 @@ pub const XP_QUIZ_FAILED: i64 = 1_000;
 pub const XP_DISCUSSION_REPLY: i64 = 5_000;
 #[cfg(feature = "server")]
 pub const XP_POLL: i64 = 50_000;
+#[cfg(feature = "server")]
+pub const XP_MEET_ATTEND: i64 = 10_000;
 
 #[cfg(feature = "server")]
 fn calculate_xp(data: &SpaceActivityData) -> i64 {
@@ -26,6 +28,7 @@ fn calculate_xp(data: &SpaceActivityData) -> i64 {
             }
         }
         SpaceActivityData::Discussion { .. } => XP_DISCUSSION_REPLY,
+        SpaceActivityData::Meet { .. } => XP_MEET_ATTEND,
         SpaceActivityData::Unknown => 0,
     }
 }
@@ -37,7 +40,7 @@ fn dedup_key(action_id: &str, data: &SpaceActivityData) -> String {
         SpaceActivityData::Discussion { comment_id, .. } => {
             format!("{}#comment#{}", action_id, comment_id)
         }
-        // Poll, Quiz, Follow: one XP per user per action
+        // Poll, Quiz, Follow, Meet: one XP per user per action
         _ => action_id.to_string(),
     }
 }
@@ -73,6 +76,39 @@ pub async fn record_activity(
         return Ok(());
     }
 
+    // Streak bonus is priced from the streak *including* today, so the
+    // first activity that extends a streak onto a new rung already earns
+    // it. Team authors have no streak.
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let user_pk = match &author {
+        AuthorPartition::User(id) => Some(Partition::User(id.clone())),
+        _ => None,
+    };
+    let plan = match &user_pk {
+        Some(pk) => {
+            match crate::features::activity::services::plan_activity_streak(cli, pk, now).await {
+                Ok(plan) => Some(plan),
+                Err(e) => {
+                    tracing::warn!(error = %e, "streak plan failed; recording without bonus");
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    let streak_bonus = match plan {
+        Some(plan) => {
+            crate::features::activity::services::streak_bonus_xp(
+                cli,
+                &action_type,
+                plan.state.current_streak_days,
+                xp,
+            )
+            .await
+        }
+        None => 0,
+    };
+
     let activity = SpaceActivity::new_with_dedup(
         cli,
         space_id,
@@ -81,7 +117,7 @@ pub async fn record_activity(
         action_type.clone(),
         data,
         xp,
-        0,
+        streak_bonus,
         user_name,
         user_avatar,
         dedup.clone(),
@@ -92,7 +128,40 @@ pub async fn record_activity(
         action_id = %action_id,
         action_type = ?action_type,
         xp = xp,
+        streak_bonus = streak_bonus,
         "recorded new activity"
     );
+
+    // Ledger write is best-effort: the activity (and its XP) already
+    // landed, and the calendar tolerates a missed bump.
+    if let (Some(pk), Some(plan)) = (user_pk, plan) {
+        if let Err(e) = crate::features::activity::services::commit_daily_activity(
+            cli,
+            &pk,
+            plan,
+            activity.total_score,
+            now,
+        )
+        .await
+        {
+            tracing::error!(user_pk = %pk, error = %e, "daily activity ledger update failed");
+            return Ok(());
+        }
+
+        // The ledger owns streaks; the Marathoner badge reads them from here.
+        if let Err(e) = crate::features::badge::services::record_badge_event(
+            cli,
+            &pk,
+            crate::features::badge::services::BadgeEvent::StreakAdvanced {
+                longest: plan.state.longest_streak_days,
+            },
+            &format!("{}#{}", activity.pk, activity.sk),
+            now,
+        )
+        .await
+        {
+            tracing::error!(user_pk = %pk, error = %e, "streak badge evaluation failed");
+        }
+    }
     Ok(())
 }
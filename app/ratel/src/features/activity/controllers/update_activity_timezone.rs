@@ -0,0 +1,39 @@
+use crate::features::activity::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct UpdateActivityTimezoneRequest {
+    /// Minutes east of UTC (`-new Date().getTimezoneOffset()` in JS).
+    pub utc_offset_minutes: i32,
+}
+
+/// Set the offset future activity is bucketed into local days with.
+/// Existing ledger rows keep their dates; a streak spanning the change
+/// may gain or lose at most the one boundary day.
+#[put("/api/me/activity/timezone", user: crate::features::auth::User)]
+pub async fn update_activity_timezone_handler(
+    req: UpdateActivityTimezoneRequest,
+) -> Result<UpdateActivityTimezoneRequest> {
+    use crate::features::activity::models::UserActivityStreak;
+
+    if !(MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&req.utc_offset_minutes) {
+        return Err(ActivityError::InvalidTimezoneOffset.into());
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let (pk, sk) = UserActivityStreak::keys(&user.pk);
+    let row = UserActivityStreak::updater(&pk, &sk)
+        .with_utc_offset_minutes(req.utc_offset_minutes)
+        .with_updated_at(crate::common::utils::time::get_now_timestamp_millis())
+        .execute(cli)
+        .await?;
+
+    Ok(UpdateActivityTimezoneRequest {
+        utc_offset_minutes: row.utc_offset_minutes,
+    })
+}
@@ -0,0 +1,22 @@
+//! JS interop for leaderboard export. `dx_eval` is a no-op outside web,
+//! so no per-target cfg gates are needed at the call site.
+
+use dioxus::document::eval as dx_eval;
+
+use crate::features::activity::controllers::LeaderboardExportResponse;
+use crate::features::activity::*;
+
+pub async fn download_leaderboard_csv(file: &LeaderboardExportResponse) -> Result<()> {
+    let mut runner = dx_eval(include_str!("web/download_csv.js"));
+    runner
+        .send(serde_json::to_value(file).map_err(|_| ActivityError::DownloadFailed)?)
+        .map_err(|_| ActivityError::DownloadFailed)?;
+    let ok = runner
+        .recv::<Option<bool>>()
+        .await
+        .map_err(|_| ActivityError::DownloadFailed)?;
+    match ok {
+        Some(true) => Ok(()),
+        _ => Err(ActivityError::DownloadFailed.into()),
+    }
+}
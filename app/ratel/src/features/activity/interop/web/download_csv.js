@@ -0,0 +1,22 @@
+// Saves a rendered leaderboard CSV as a file. Receives
+// `{ file_name, content }` (a `LeaderboardExportResponse`) from Rust.
+//
+// Returns `true` on success; sends `null` on a thrown error so Rust can
+// surface a typed `ActivityError::DownloadFailed`.
+const req = await dioxus.recv();
+try {
+  // BOM so spreadsheet apps read non-ASCII names as UTF-8.
+  const blob = new Blob(["\uFEFF", req.content], { type: "text/csv;charset=utf-8" });
+  const url = URL.createObjectURL(blob);
+  const a = document.createElement("a");
+  a.href = url;
+  a.download = req.file_name;
+  document.body.appendChild(a);
+  a.click();
+  a.remove();
+  setTimeout(() => URL.revokeObjectURL(url), 0);
+  dioxus.send(true);
+} catch (e) {
+  console.error("downloadLeaderboardCsv failed", e);
+  dioxus.send(null);
+}
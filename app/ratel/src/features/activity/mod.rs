@@ -6,6 +6,7 @@ pub mod controllers;
 pub mod services;
 
 pub mod components;
+pub mod interop;
 
 pub mod i18n;
 
@@ -1,5 +1,11 @@
 mod space_activity;
+mod space_leaderboard_snapshot;
 mod space_score;
+mod user_activity_streak;
+mod user_daily_activity;
 
 pub use space_activity::*;
+pub use space_leaderboard_snapshot::*;
 pub use space_score::*;
+pub use user_activity_streak::*;
+pub use user_daily_activity::*;
@@ -71,27 +71,21 @@ impl SpaceActivity {
         let space_pk: Partition = space_id.clone().into();
         let sk = EntityType::SpaceActivity(format!("{}#{}", dedup_key, now));
 
-        // Apply the user's Ranker skill multiplier to `additional_score` only;
-        // `base_score` is always unboosted. If the skill row read fails for any
-        // reason, fall back to permille=1000 (no boost) — never fail the insert.
-        let mult_permille = {
-            use crate::features::character::leveling::multiplier_permille;
-            use crate::features::character::models::CharacterSkill;
-            use crate::features::character::types::SkillId;
-            let user_pk: Partition = match author.clone() {
-                AuthorPartition::User(id) => Partition::User(id),
-                AuthorPartition::Team(id) => Partition::Team(id),
-                AuthorPartition::Unknown => Partition::None,
-            };
-            CharacterSkill::level_or_zero(cli, &user_pk, SkillId::Ranker)
-                .await
-                .map(multiplier_permille)
-                .unwrap_or(1000)
+        // Skill registry adjusts `additional_score` only (Ranker);
+        // `base_score` is always unboosted. A failed skill read counts as
+        // level 0 — never fail the insert.
+        let user_pk: Partition = match author.clone() {
+            AuthorPartition::User(id) => Partition::User(id),
+            AuthorPartition::Team(id) => Partition::Team(id),
+            AuthorPartition::Unknown => Partition::None,
         };
-        let boosted_additional = crate::features::character::leveling::apply_permille(
+        let boosted_additional = crate::features::character::services::apply_skill_effects(
+            cli,
+            &user_pk,
+            crate::features::character::skill_effects::SkillPipeline::ActivityXp,
             additional_score,
-            mult_permille,
-        );
+        )
+        .await;
         let total_score = base_score + boosted_additional;
 
         Self {
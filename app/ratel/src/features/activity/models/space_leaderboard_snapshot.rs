@@ -0,0 +1,60 @@
+use crate::common::macros::DynamoEntity;
+use crate::features::activity::*;
+
+/// A space's standings as they stood when a day or ISO week closed.
+/// Written once, by the first score write or leaderboard read after the
+/// boundary — nothing changes the standings in between, so that is the
+/// closing state. Periods a quiet space skipped are back-filled in the
+/// same pass.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+pub struct SpaceLeaderboardSnapshot {
+    pub pk: Partition,  // Partition::Space(space_id)
+    pub sk: EntityType, // EntityType::SpaceLeaderboardSnapshot(period_key)
+
+    pub period: LeaderboardPeriod,
+    pub period_key: String,
+    pub created_at: i64,
+    /// Ordered by total score; at most `MAX_LEADERBOARD_ENTRIES`.
+    #[serde(default)]
+    pub entries: Vec<LeaderboardSnapshotEntry>,
+}
+
+impl SpaceLeaderboardSnapshot {
+    pub fn keys(space_pk: &Partition, period_key: &str) -> (Partition, EntityType) {
+        (
+            space_pk.clone(),
+            EntityType::SpaceLeaderboardSnapshot(period_key.to_string()),
+        )
+    }
+
+    fn sk_prefix(period: LeaderboardPeriod) -> String {
+        format!("SPACE_LEADERBOARD_SNAPSHOT#{}", period.key_prefix())
+    }
+}
+
+#[cfg(feature = "server")]
+impl SpaceLeaderboardSnapshot {
+    /// Snapshots of `period`, newest first.
+    pub async fn list_recent(
+        cli: &aws_sdk_dynamodb::Client,
+        space_pk: &Partition,
+        period: LeaderboardPeriod,
+        bookmark: Option<String>,
+        limit: i32,
+    ) -> crate::common::Result<(Vec<Self>, Option<String>)> {
+        let opt = Self::opt_with_bookmark(bookmark)
+            .sk(Self::sk_prefix(period))
+            .scan_index_forward(false)
+            .limit(limit);
+        Self::query(cli, space_pk.clone(), opt).await
+    }
+
+    pub async fn latest(
+        cli: &aws_sdk_dynamodb::Client,
+        space_pk: &Partition,
+        period: LeaderboardPeriod,
+    ) -> crate::common::Result<Option<Self>> {
+        let (rows, _) = Self::list_recent(cli, space_pk, period, None, 1).await?;
+        Ok(rows.into_iter().next())
+    }
+}
@@ -25,6 +25,8 @@ pub struct SpaceScore {
     pub quiz_score: i64,
     pub follow_score: i64,
     pub discussion_score: i64,
+    #[serde(default)]
+    pub meet_score: i64,
 
     #[dynamo(index = "gsi2", order = 2, sk)]
     pub updated_at: i64,
@@ -54,6 +56,7 @@ impl SpaceScore {
             quiz_score: 0,
             follow_score: 0,
             discussion_score: 0,
+            meet_score: 0,
             updated_at: now,
         }
     }
@@ -0,0 +1,50 @@
+use crate::common::macros::DynamoEntity;
+use crate::features::activity::*;
+
+/// Singleton per user: the UTC offset days are bucketed in, plus the
+/// running streak. Read-modify-write from `record_activity`; the offset is
+/// set by the user (`PUT /api/me/activity/timezone`) and defaults to UTC.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[serde(default)]
+pub struct UserActivityStreak {
+    pub pk: Partition,  // Partition::User(user_id)
+    pub sk: EntityType, // EntityType::UserActivityStreak
+
+    pub utc_offset_minutes: i32,
+    pub current_streak_days: i64,
+    pub longest_streak_days: i64,
+    /// Local day of the latest activity; 0 = never active.
+    pub last_active_day: i64,
+    pub grace_days_available: i64,
+    pub updated_at: i64,
+}
+
+impl UserActivityStreak {
+    pub fn keys(user_pk: &Partition) -> (Partition, EntityType) {
+        (user_pk.clone(), EntityType::UserActivityStreak)
+    }
+
+    pub fn state(&self) -> StreakState {
+        StreakState {
+            current_streak_days: self.current_streak_days,
+            longest_streak_days: self.longest_streak_days,
+            last_active_day: self.last_active_day,
+            grace_days_available: self.grace_days_available,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl UserActivityStreak {
+    pub async fn get_or_default(
+        cli: &aws_sdk_dynamodb::Client,
+        user_pk: &Partition,
+    ) -> crate::common::Result<Self> {
+        let (pk, sk) = Self::keys(user_pk);
+        Ok(Self::get(cli, &pk, Some(&sk)).await?.unwrap_or(Self {
+            pk,
+            sk,
+            ..Default::default()
+        }))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::common::macros::DynamoEntity;
+use crate::features::activity::*;
+
+/// One row per (user, local date) with any recorded activity — the
+/// contribution calendar's ledger. Written only when `record_activity`
+/// creates a new `SpaceActivity`, so stream replays never double-count.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+pub struct UserDailyActivity {
+    pub pk: Partition,  // Partition::User(user_id)
+    pub sk: EntityType, // EntityType::UserDailyActivity("YYYY-MM-DD")
+
+    /// Local day number matching `sk`'s date.
+    #[serde(default)]
+    pub day: i64,
+    #[serde(default)]
+    pub activity_count: i64,
+    /// Sum of `SpaceActivity.total_score` recorded that day.
+    #[serde(default)]
+    pub xp: i64,
+    #[serde(default)]
+    pub updated_at: i64,
+}
+
+impl UserDailyActivity {
+    pub fn keys(user_pk: &Partition, day: i64) -> (Partition, EntityType) {
+        (user_pk.clone(), EntityType::UserDailyActivity(day_to_date(day)))
+    }
+}
+
+#[cfg(feature = "server")]
+impl UserDailyActivity {
+    /// The user's most recent `limit` active days, newest first.
+    pub async fn list_recent(
+        cli: &aws_sdk_dynamodb::Client,
+        user_pk: &Partition,
+        limit: i32,
+    ) -> crate::common::Result<Vec<Self>> {
+        let opt = Self::opt()
+            .sk("USER_DAILY_ACTIVITY#".to_string())
+            .scan_index_forward(false)
+            .limit(limit);
+        let (rows, _) = Self::query(cli, user_pk.clone(), opt).await?;
+        Ok(rows)
+    }
+}
@@ -0,0 +1,92 @@
+//! Daily ledger + streak bookkeeping for `record_activity`. Split in two so
+//! the streak bonus can be priced into the `SpaceActivity` before it is
+//! written: [`plan_activity_streak`] reads and advances the streak in
+//! memory, [`commit_daily_activity`] persists it once the activity row
+//! exists.
+
+use crate::common::models::reward::Reward;
+use crate::features::activity::models::{UserActivityStreak, UserDailyActivity};
+use crate::features::activity::*;
+use crate::features::spaces::pages::actions::types::SpaceActionType;
+
+/// Where a new activity lands: the user's local day and the streak it
+/// produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreakPlan {
+    pub day: i64,
+    pub state: StreakState,
+}
+
+pub async fn plan_activity_streak(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    at: i64,
+) -> crate::common::Result<StreakPlan> {
+    let streak = UserActivityStreak::get_or_default(cli, user_pk).await?;
+    let day = local_day(at, streak.utc_offset_minutes);
+    Ok(StreakPlan {
+        day,
+        state: streak.state().advance(day),
+    })
+}
+
+/// Global `Reward` behavior whose streak ladder prices this action type.
+pub fn streak_behavior(action_type: &SpaceActionType) -> RewardUserBehavior {
+    match action_type {
+        SpaceActionType::Poll => RewardUserBehavior::RespondPoll,
+        SpaceActionType::TopicDiscussion => RewardUserBehavior::DiscussionComment,
+        SpaceActionType::Follow => RewardUserBehavior::Follow,
+        SpaceActionType::Quiz => RewardUserBehavior::QuizAnswer,
+        SpaceActionType::Meet => RewardUserBehavior::AttendMeet,
+    }
+}
+
+/// Extra XP the streak earns on `base_xp`, per the behavior's
+/// `Reward.streak_multipliers`. A missing reward or a failed read is no
+/// bonus — never fail the activity.
+pub async fn streak_bonus_xp(
+    cli: &aws_sdk_dynamodb::Client,
+    action_type: &SpaceActionType,
+    streak_days: i64,
+    base_xp: i64,
+) -> i64 {
+    let behavior = streak_behavior(action_type);
+    let tiers = match Reward::get(cli, Partition::Reward, Some(behavior.clone())).await {
+        Ok(Some(reward)) => reward.streak_multipliers,
+        Ok(None) => return 0,
+        Err(e) => {
+            tracing::warn!(behavior = ?behavior, error = %e, "streak bonus: reward load failed");
+            return 0;
+        }
+    };
+    base_xp * StreakMultiplier::bonus_percent_for(&tiers, streak_days) / 100
+}
+
+/// Bump the day's ledger row and persist the planned streak.
+pub async fn commit_daily_activity(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    plan: StreakPlan,
+    xp: i64,
+    at: i64,
+) -> crate::common::Result<()> {
+    let (pk, sk) = UserDailyActivity::keys(user_pk, plan.day);
+    UserDailyActivity::updater(&pk, &sk)
+        .with_day(plan.day)
+        .increase_activity_count(1)
+        .increase_xp(xp)
+        .with_updated_at(at)
+        .execute(cli)
+        .await?;
+
+    let (pk, sk) = UserActivityStreak::keys(user_pk);
+    UserActivityStreak::updater(&pk, &sk)
+        .with_current_streak_days(plan.state.current_streak_days)
+        .with_longest_streak_days(plan.state.longest_streak_days)
+        .with_last_active_day(plan.state.last_active_day)
+        .with_grace_days_available(plan.state.grace_days_available)
+        .with_updated_at(at)
+        .execute(cli)
+        .await?;
+    Ok(())
+}
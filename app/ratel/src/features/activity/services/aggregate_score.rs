@@ -18,42 +18,29 @@ pub async fn aggregate_score(
         .await?
         .unwrap_or_default();
 
+    let space_pk: Partition = space_id.clone().into();
+    if let Err(e) =
+        crate::features::activity::services::ensure_leaderboard_snapshots(cli, &space_pk, now)
+            .await
+    {
+        tracing::warn!(space_pk = %space_pk, error = %e, "leaderboard snapshot failed");
+    }
+
     let new_total = existing.total_score + activity.total_score;
 
     use crate::features::spaces::pages::actions::types::SpaceActionType;
-    let (poll, quiz, follow, discussion) = match activity.action_type {
-        SpaceActionType::Poll => (
-            existing.poll_score + activity.total_score,
-            existing.quiz_score,
-            existing.follow_score,
-            existing.discussion_score,
-        ),
-        SpaceActionType::Quiz => (
-            existing.poll_score,
-            existing.quiz_score + activity.total_score,
-            existing.follow_score,
-            existing.discussion_score,
-        ),
-        SpaceActionType::Follow => (
-            existing.poll_score,
-            existing.quiz_score,
-            existing.follow_score + activity.total_score,
-            existing.discussion_score,
-        ),
-        SpaceActionType::TopicDiscussion => (
-            existing.poll_score,
-            existing.quiz_score,
-            existing.follow_score,
-            existing.discussion_score + activity.total_score,
-        ),
-        // Meet actions are not yet tracked in per-category score breakdowns.
-        SpaceActionType::Meet => (
-            existing.poll_score,
-            existing.quiz_score,
-            existing.follow_score,
-            existing.discussion_score,
-        ),
-    };
+    let mut poll = existing.poll_score;
+    let mut quiz = existing.quiz_score;
+    let mut follow = existing.follow_score;
+    let mut discussion = existing.discussion_score;
+    let mut meet = existing.meet_score;
+    match activity.action_type {
+        SpaceActionType::Poll => poll += activity.total_score,
+        SpaceActionType::Quiz => quiz += activity.total_score,
+        SpaceActionType::Follow => follow += activity.total_score,
+        SpaceActionType::TopicDiscussion => discussion += activity.total_score,
+        SpaceActionType::Meet => meet += activity.total_score,
+    }
 
     SpaceScore::updater(&score_pk, &score_sk)
         .with_total_score(new_total)
@@ -62,6 +49,7 @@ pub async fn aggregate_score(
         .with_quiz_score(quiz)
         .with_follow_score(follow)
         .with_discussion_score(discussion)
+        .with_meet_score(meet)
         .with_user_pk(activity.user_pk)
         .with_user_name(activity.user_name)
         .with_user_avatar(activity.user_avatar)
@@ -374,3 +374,50 @@ pub async fn handle_follow_xp(
     .await;
     Ok(())
 }
+
+/// Attendance is derived from the transcript speakers when the archive is
+/// uploaded (one `SpaceMeetAttendance` row per matched participant). The
+/// reward is keyed by the bare meet uuid, the same id `SpaceAction` uses.
+#[cfg(feature = "server")]
+pub async fn handle_meet_xp(
+    attendance: crate::features::spaces::pages::actions::actions::meet::SpaceMeetAttendance,
+) -> crate::common::Result<()> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    if attendance.space_id.is_empty() || attendance.meet_id.is_empty() {
+        tracing::warn!("MeetXpRecord: missing space_id or meet_id, skipping");
+        return Ok(());
+    }
+
+    let space_partition = crate::common::types::SpacePartition(attendance.space_id.clone());
+    let author =
+        crate::features::activity::types::AuthorPartition::from(attendance.user_pk.clone());
+    let meet_action_id = attendance.meet_id.clone();
+
+    crate::features::activity::controllers::record_activity(
+        cli,
+        space_partition.clone(),
+        author,
+        meet_action_id.clone(),
+        crate::features::spaces::pages::actions::types::SpaceActionType::Meet,
+        crate::features::activity::types::SpaceActivityData::Meet {
+            meet_id: meet_action_id.clone(),
+        },
+        attendance.display_name,
+        attendance.profile_url,
+    )
+    .await?;
+
+    let owner_pk = fetch_owner_pk(cli, &space_partition).await;
+    try_award(
+        cli,
+        space_partition,
+        meet_action_id,
+        RewardUserBehavior::AttendMeet,
+        attendance.user_pk,
+        owner_pk,
+    )
+    .await;
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use crate::features::activity::controllers::{leaderboard_entries, LeaderboardEntryResponse};
+use crate::features::activity::models::{SpaceLeaderboardSnapshot, SpaceScore};
+use crate::features::activity::*;
+
+const LEADERBOARD_PAGE_SIZE: i32 = 100;
+
+impl From<&SpaceScore> for LeaderboardSnapshotEntry {
+    fn from(score: &SpaceScore) -> Self {
+        Self {
+            user_pk: score.user_pk.to_string(),
+            name: score.user_name.clone(),
+            avatar: score.user_avatar.clone(),
+            total_score: score.total_score,
+            poll_score: score.poll_score,
+            quiz_score: score.quiz_score,
+            discussion_score: score.discussion_score,
+            follow_score: score.follow_score,
+            meet_score: score.meet_score,
+        }
+    }
+}
+
+/// Current standings of a space, highest total first, capped at
+/// `MAX_LEADERBOARD_ENTRIES`.
+pub async fn load_leaderboard(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+) -> crate::common::Result<Vec<LeaderboardSnapshotEntry>> {
+    let mut entries = Vec::new();
+    let mut bookmark: Option<String> = None;
+    loop {
+        let opt = SpaceScore::opt_with_bookmark(bookmark.take())
+            .limit(LEADERBOARD_PAGE_SIZE)
+            .scan_index_forward(false);
+        let (scores, next) = SpaceScore::find_by_space_rank(cli, space_pk, opt).await?;
+        entries.extend(scores.iter().map(LeaderboardSnapshotEntry::from));
+        match next {
+            Some(bk) if entries.len() < MAX_LEADERBOARD_ENTRIES => bookmark = Some(bk),
+            _ => break,
+        }
+    }
+    entries.truncate(MAX_LEADERBOARD_ENTRIES);
+    Ok(entries)
+}
+
+/// Write the closing snapshot of every period that ended since the space
+/// last changed. Must run *before* a score write so the snapshot holds the
+/// standings as of the boundary. Every write runs this first, so no score
+/// moved since the newest snapshot and each period closed after it —
+/// however many a quiet stretch skipped — shares the current standings.
+/// Losing a race to a concurrent writer is fine — both would store the
+/// same standings.
+pub async fn ensure_leaderboard_snapshots(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    now: i64,
+) -> crate::common::Result<()> {
+    let mut missing: Vec<(LeaderboardPeriod, String)> = Vec::new();
+    for period in LeaderboardPeriod::ALL {
+        let latest = SpaceLeaderboardSnapshot::latest(cli, space_pk, period).await?;
+        let after = latest.as_ref().map(|s| s.period_key.as_str());
+        missing.extend(
+            period
+                .closed_keys_since(after, now, MAX_SNAPSHOT_BACKFILL)
+                .into_iter()
+                .map(|key| (period, key)),
+        );
+    }
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    let entries = load_leaderboard(cli, space_pk).await?;
+    if entries.is_empty() {
+        return Ok(());
+    }
+    for (period, period_key) in missing {
+        let (pk, sk) = SpaceLeaderboardSnapshot::keys(space_pk, &period_key);
+        let snapshot = SpaceLeaderboardSnapshot {
+            pk,
+            sk,
+            period,
+            period_key,
+            created_at: now,
+            entries: entries.clone(),
+        };
+        if let Err(e) = snapshot.create(cli).await {
+            tracing::debug!(space_pk = %space_pk, error = %e, "leaderboard snapshot exists");
+        }
+    }
+    Ok(())
+}
+
+/// Live standings for `category` with `rank_change` against the most
+/// recent closed `period`, plus that period's key.
+pub async fn load_ranked_leaderboard(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    category: LeaderboardCategory,
+    period: LeaderboardPeriod,
+) -> crate::common::Result<(Vec<LeaderboardEntryResponse>, Option<String>)> {
+    // A quiet space has no score write to trigger the closing snapshot.
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    if let Err(e) = ensure_leaderboard_snapshots(cli, space_pk, now).await {
+        tracing::warn!(space_pk = %space_pk, error = %e, "leaderboard snapshot failed");
+    }
+
+    let current = load_leaderboard(cli, space_pk).await?;
+    let previous = SpaceLeaderboardSnapshot::latest(cli, space_pk, period).await?;
+    let entries = leaderboard_entries(
+        &current,
+        previous.as_ref().map(|s| s.entries.as_slice()),
+        category,
+    );
+    Ok((entries, previous.map(|s| s.period_key)))
+}
@@ -1,5 +1,9 @@
+mod activity_streak;
 mod aggregate_score;
 mod handle_xp_event;
+mod leaderboard;
 
+pub use activity_streak::*;
 pub use aggregate_score::*;
 pub use handle_xp_event::*;
+pub use leaderboard::*;
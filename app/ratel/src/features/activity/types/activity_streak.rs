@@ -0,0 +1,105 @@
+//! Pure streak + calendar arithmetic. Days are counted in the user's local
+//! calendar: `local_day` shifts the UTC instant by the stored offset before
+//! flooring to a day number, so an 11pm Seoul vote lands on the Seoul date.
+
+pub const DAY_MS: i64 = 86_400_000;
+
+/// Offsets real timezones use: UTC−12:00 … UTC+14:00.
+pub const MIN_UTC_OFFSET_MINUTES: i32 = -12 * 60;
+pub const MAX_UTC_OFFSET_MINUTES: i32 = 14 * 60;
+
+/// One grace day is earned per this many consecutive streak days.
+pub const GRACE_REFILL_STREAK_DAYS: i64 = 7;
+/// Grace days bank up to this many; extra refills are dropped.
+pub const MAX_GRACE_DAYS: i64 = 2;
+
+/// Calendar window bounds for the contribution grid (53 weeks max).
+pub const DEFAULT_CALENDAR_DAYS: i64 = 365;
+pub const MAX_CALENDAR_DAYS: i64 = 371;
+
+/// Local day number (days since 1970-01-01 in the user's offset).
+pub fn local_day(at_ms: i64, utc_offset_minutes: i32) -> i64 {
+    (at_ms + utc_offset_minutes as i64 * 60_000).div_euclid(DAY_MS)
+}
+
+/// `"YYYY-MM-DD"` for a local day number.
+pub fn day_to_date(day: i64) -> String {
+    chrono::DateTime::from_timestamp(day * 86_400, 0)
+        .map(|dt| dt.date_naive().format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+/// Continuity state carried on `UserActivityStreak`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StreakState {
+    pub current_streak_days: i64,
+    pub longest_streak_days: i64,
+    /// Local day of the latest activity; 0 = never active.
+    pub last_active_day: i64,
+    pub grace_days_available: i64,
+}
+
+impl StreakState {
+    /// State after activity on `day`.
+    ///
+    /// - same day (or a late event for an earlier day): unchanged
+    /// - next day: streak +1
+    /// - a gap the banked grace days cover: streak +1, grace spent
+    /// - any longer gap: streak restarts at 1 (banked grace is kept)
+    ///
+    /// Every `GRACE_REFILL_STREAK_DAYS`-th streak day banks one grace day.
+    pub fn advance(self, day: i64) -> Self {
+        if self.last_active_day > 0 && day <= self.last_active_day {
+            return self;
+        }
+
+        let missed = if self.last_active_day > 0 {
+            day - self.last_active_day - 1
+        } else {
+            -1
+        };
+        let (current, mut grace) = match missed {
+            0 => (self.current_streak_days + 1, self.grace_days_available),
+            m if m > 0 && m <= self.grace_days_available => {
+                (self.current_streak_days + 1, self.grace_days_available - m)
+            }
+            _ => (1, self.grace_days_available),
+        };
+        if current % GRACE_REFILL_STREAK_DAYS == 0 {
+            grace = (grace + 1).min(MAX_GRACE_DAYS);
+        }
+
+        Self {
+            current_streak_days: current,
+            longest_streak_days: self.longest_streak_days.max(current),
+            last_active_day: day,
+            grace_days_available: grace,
+        }
+    }
+
+    /// Streak as of `today` without new activity: still alive while the
+    /// missed days (excluding today, which can still be saved) fit in
+    /// the banked grace; 0 once broken.
+    pub fn current_as_of(&self, today: i64) -> i64 {
+        if self.last_active_day == 0 {
+            return 0;
+        }
+        let missed = today - self.last_active_day - 1;
+        if missed <= self.grace_days_available {
+            self.current_streak_days
+        } else {
+            0
+        }
+    }
+}
+
+/// GitHub-style intensity bucket (0 = no activity … 4 = busiest).
+pub fn calendar_level(activity_count: i64) -> u8 {
+    match activity_count {
+        i64::MIN..=0 => 0,
+        1 => 1,
+        2..=3 => 2,
+        4..=6 => 3,
+        _ => 4,
+    }
+}
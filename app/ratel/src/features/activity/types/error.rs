@@ -22,6 +22,27 @@ pub enum ActivityError {
     #[error("ranking load failed")]
     #[translate(en = "Failed to load ranking", ko = "랭킹 로드에 실패했습니다.")]
     RankingLoadFailed,
+
+    #[error("invalid timezone offset")]
+    #[translate(
+        en = "Timezone offset must be between UTC-12:00 and UTC+14:00",
+        ko = "시간대 오프셋은 UTC-12:00에서 UTC+14:00 사이여야 합니다"
+    )]
+    InvalidTimezoneOffset,
+
+    #[error("leaderboard snapshot not found")]
+    #[translate(
+        en = "No leaderboard was saved for that period",
+        ko = "해당 기간의 리더보드 기록이 없습니다"
+    )]
+    SnapshotNotFound,
+
+    #[error("leaderboard download failed")]
+    #[translate(
+        en = "Failed to download the leaderboard",
+        ko = "리더보드를 내려받지 못했습니다"
+    )]
+    DownloadFailed,
 }
 
 #[cfg(feature = "server")]
@@ -30,10 +51,14 @@ impl ActivityError {
         use crate::axum::http::StatusCode;
         match self {
             ActivityError::AlreadyRecorded => StatusCode::CONFLICT,
+            ActivityError::SnapshotNotFound => StatusCode::NOT_FOUND,
             ActivityError::AggregationFailed
             | ActivityError::ScoreLoadFailed
-            | ActivityError::RankingLoadFailed => StatusCode::INTERNAL_SERVER_ERROR,
-            ActivityError::InvalidData => StatusCode::BAD_REQUEST,
+            | ActivityError::RankingLoadFailed
+            | ActivityError::DownloadFailed => StatusCode::INTERNAL_SERVER_ERROR,
+            ActivityError::InvalidData | ActivityError::InvalidTimezoneOffset => {
+                StatusCode::BAD_REQUEST
+            }
         }
     }
 }
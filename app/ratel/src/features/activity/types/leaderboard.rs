@@ -0,0 +1,225 @@
+//! Pure leaderboard arithmetic: period keys, per-category ranking and the
+//! CSV export. Periods are UTC calendar days and ISO weeks so every
+//! participant of a space sees the same boundaries.
+
+use crate::features::activity::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Upper bound on scores ranked (and stored per snapshot) for one space.
+/// Keeps a snapshot well under DynamoDB's 400KB item limit.
+pub const MAX_LEADERBOARD_ENTRIES: usize = 500;
+
+/// Most closed periods of one kind a single pass back-fills. A space
+/// dormant for longer keeps a gap before the backfilled run.
+pub const MAX_SNAPSHOT_BACKFILL: usize = 31;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum LeaderboardCategory {
+    #[default]
+    Total,
+    Poll,
+    Quiz,
+    Discussion,
+    Follow,
+    Meet,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum LeaderboardPeriod {
+    #[default]
+    Day,
+    Week,
+}
+
+impl LeaderboardCategory {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LeaderboardCategory::Total => "total",
+            LeaderboardCategory::Poll => "poll",
+            LeaderboardCategory::Quiz => "quiz",
+            LeaderboardCategory::Discussion => "discussion",
+            LeaderboardCategory::Follow => "follow",
+            LeaderboardCategory::Meet => "meet",
+        }
+    }
+}
+
+impl LeaderboardPeriod {
+    pub const ALL: [LeaderboardPeriod; 2] = [LeaderboardPeriod::Day, LeaderboardPeriod::Week];
+
+    /// Key of the period containing `at_ms`: `day-2026-10-19` or
+    /// `week-2026-W42`. Keys of one period kind sort chronologically.
+    pub fn key_at(self, at_ms: i64) -> String {
+        use chrono::Datelike;
+
+        let date = chrono::DateTime::from_timestamp_millis(at_ms)
+            .unwrap_or_default()
+            .date_naive();
+        match self {
+            LeaderboardPeriod::Day => format!("day-{}", date.format("%Y-%m-%d")),
+            LeaderboardPeriod::Week => {
+                let week = date.iso_week();
+                format!("week-{}-W{:02}", week.year(), week.week())
+            }
+        }
+    }
+
+    /// Key of the period that closed most recently before `at_ms`.
+    pub fn previous_key(self, at_ms: i64) -> String {
+        self.key_at(self.start_of(at_ms) - 1)
+    }
+
+    /// Keys of the periods that closed after `after_key` and before
+    /// `at_ms`, oldest first, keeping the newest `max`. With no
+    /// `after_key` only the last closed period is returned.
+    pub fn closed_keys_since(self, after_key: Option<&str>, at_ms: i64, max: usize) -> Vec<String> {
+        let mut keys = Vec::new();
+        let mut cursor = self.start_of(at_ms);
+        while keys.len() < max {
+            let key = self.key_at(cursor - 1);
+            let wanted = match after_key {
+                Some(after) => key.as_str() > after,
+                None => keys.is_empty(),
+            };
+            if !wanted {
+                break;
+            }
+            keys.push(key);
+            cursor = self.start_of(cursor - 1);
+        }
+        keys.reverse();
+        keys
+    }
+
+    /// First millisecond of the period containing `at_ms`.
+    pub fn start_of(self, at_ms: i64) -> i64 {
+        let day_start = at_ms - at_ms.rem_euclid(DAY_MS);
+        match self {
+            LeaderboardPeriod::Day => day_start,
+            LeaderboardPeriod::Week => {
+                // 1970-01-01 was a Thursday; shift so weeks start Monday.
+                let days_since_monday = (day_start / DAY_MS + 3).rem_euclid(7);
+                day_start - days_since_monday * DAY_MS
+            }
+        }
+    }
+
+    /// Prefix shared by every snapshot key of this period kind.
+    pub fn key_prefix(self) -> &'static str {
+        match self {
+            LeaderboardPeriod::Day => "day-",
+            LeaderboardPeriod::Week => "week-",
+        }
+    }
+}
+
+/// One participant's standing, as stored in a snapshot and returned by
+/// the live leaderboard.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct LeaderboardSnapshotEntry {
+    pub user_pk: String,
+    pub name: String,
+    pub avatar: String,
+    pub total_score: i64,
+    pub poll_score: i64,
+    pub quiz_score: i64,
+    pub discussion_score: i64,
+    pub follow_score: i64,
+    pub meet_score: i64,
+}
+
+impl LeaderboardSnapshotEntry {
+    pub fn score(&self, category: LeaderboardCategory) -> i64 {
+        match category {
+            LeaderboardCategory::Total => self.total_score,
+            LeaderboardCategory::Poll => self.poll_score,
+            LeaderboardCategory::Quiz => self.quiz_score,
+            LeaderboardCategory::Discussion => self.discussion_score,
+            LeaderboardCategory::Follow => self.follow_score,
+            LeaderboardCategory::Meet => self.meet_score,
+        }
+    }
+}
+
+/// Rank `entries` by `category`, highest first, skipping zero scores.
+/// Ties share a rank and the next rank skips ahead (1, 2, 2, 4), so a
+/// prize draw never has to break a tie the leaderboard hid.
+pub fn rank_entries(
+    entries: &[LeaderboardSnapshotEntry],
+    category: LeaderboardCategory,
+) -> Vec<(u32, LeaderboardSnapshotEntry)> {
+    let mut sorted: Vec<&LeaderboardSnapshotEntry> =
+        entries.iter().filter(|e| e.score(category) > 0).collect();
+    sorted.sort_by(|a, b| {
+        b.score(category)
+            .cmp(&a.score(category))
+            .then_with(|| a.name.cmp(&b.name))
+            .then_with(|| a.user_pk.cmp(&b.user_pk))
+    });
+
+    let mut ranked = Vec::with_capacity(sorted.len());
+    let mut rank = 0u32;
+    let mut previous: Option<i64> = None;
+    for (i, entry) in sorted.into_iter().enumerate() {
+        let score = entry.score(category);
+        if previous != Some(score) {
+            rank = i as u32 + 1;
+            previous = Some(score);
+        }
+        ranked.push((rank, entry.clone()));
+    }
+    ranked
+}
+
+/// Places moved since `previous`; positive means up. `None` for a
+/// participant who was not ranked then.
+pub fn rank_change(
+    previous: &[(u32, LeaderboardSnapshotEntry)],
+    user_pk: &str,
+    rank: u32,
+) -> Option<i32> {
+    previous
+        .iter()
+        .find(|(_, e)| e.user_pk == user_pk)
+        .map(|(prev, _)| *prev as i32 - rank as i32)
+}
+
+/// RFC 4180 CSV of a ranked leaderboard, with every category column so a
+/// prize draw can be re-run on any of them.
+pub fn render_leaderboard_csv(ranked: &[(u32, LeaderboardSnapshotEntry)]) -> String {
+    let mut out = String::from("rank,name,user,total,poll,quiz,discussion,follow,meet\r\n");
+    for (rank, e) in ranked {
+        out.push_str(&format!(
+            "{rank},{},{},{},{},{},{},{},{}\r\n",
+            csv_field(&e.name),
+            csv_field(&e.user_pk),
+            e.total_score,
+            e.poll_score,
+            e.quiz_score,
+            e.discussion_score,
+            e.follow_score,
+            e.meet_score,
+        ));
+    }
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    // A leading formula character would be evaluated by spreadsheet apps.
+    let value = match value.chars().next() {
+        Some('=' | '+' | '-' | '@') => format!("'{value}"),
+        _ => value.to_string(),
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
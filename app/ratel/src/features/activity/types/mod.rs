@@ -1,7 +1,11 @@
+mod activity_streak;
 mod author_partition;
 mod error;
+mod leaderboard;
 mod space_activity_data;
 
+pub use activity_streak::*;
 pub use author_partition::*;
 pub use error::*;
+pub use leaderboard::*;
 pub use space_activity_data::*;
@@ -24,4 +24,7 @@ pub enum SpaceActivityData {
         comment_id: SpacePostCommentEntityType,
         is_first_contribution: bool,
     },
+    Meet {
+        meet_id: String,
+    },
 }
@@ -0,0 +1,125 @@
+use crate::common::models::auth::AdminUser;
+use crate::features::admin::*;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BackfillMeetScheduleResponse {
+    pub meets_scanned: usize,
+    pub skipped_terminal: usize,
+    pub skipped_already_set: usize,
+    pub skipped_unpublished: usize,
+    pub updated: usize,
+}
+
+/// Put every published, non-terminal `SpaceMeet` written before the
+/// deadline scheduler into its sparse `find_due_meets` index.
+///
+/// Rows created before the scheduler have no `schedule_bucket`, so
+/// `run_meet_scheduler` never sees them: no reminder, no 24h expiry, no
+/// Live ceiling. Drafts are skipped — they enter the index on publish.
+///
+/// Idempotency:
+/// Rows that already carry a bucket are skipped, and each write is
+/// conditional on the status the row was read with, so a meet that moved
+/// meanwhile keeps whatever its own transition stored.
+#[post("/api/admin/migrations/backfill-meet-schedule", _user: AdminUser)]
+pub async fn backfill_meet_schedule() -> Result<BackfillMeetScheduleResponse> {
+    use crate::features::spaces::pages::actions::actions::meet::{
+        MeetGuard, SpaceMeet, execute_meet_update_if,
+    };
+    use crate::features::spaces::pages::actions::models::SpaceAction;
+    use crate::features::spaces::pages::actions::types::SpaceActionStatus;
+    use aws_sdk_dynamodb::types::AttributeValue;
+    use std::collections::HashMap;
+
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+
+    let table_name = format!(
+        "{}-main",
+        std::env::var("DYNAMO_TABLE_PREFIX").unwrap_or_else(|_| "ratel-dev".to_string())
+    );
+
+    let mut response = BackfillMeetScheduleResponse::default();
+    let mut last_key: Option<HashMap<String, AttributeValue>> = None;
+
+    loop {
+        // `SPACE_MEET#` only: archive, attendance and transcript rows use
+        // their own `SPACE_MEET_…#` prefixes.
+        let mut scan = cli
+            .scan()
+            .table_name(&table_name)
+            .filter_expression("begins_with(sk, :prefix)")
+            .expression_attribute_values(":prefix", AttributeValue::S("SPACE_MEET#".to_string()))
+            .limit(100);
+        if let Some(key) = last_key.take() {
+            scan = scan.set_exclusive_start_key(Some(key));
+        }
+
+        let page = scan.send().await.map_err(|e| {
+            crate::error!("scan failed: {e}");
+            crate::common::Error::NotFound(format!("scan failed: {e}"))
+        })?;
+
+        for item in page.items.unwrap_or_default() {
+            response.meets_scanned += 1;
+
+            let meet: SpaceMeet = match serde_dynamo::from_item(item) {
+                Ok(m) => m,
+                Err(e) => {
+                    crate::error!("failed to deserialize SpaceMeet: {e}");
+                    continue;
+                }
+            };
+            if meet.status.is_terminal() {
+                response.skipped_terminal += 1;
+                continue;
+            }
+            if meet.schedule_bucket.is_some() {
+                response.skipped_already_set += 1;
+                continue;
+            }
+
+            let space_id: SpacePartition = meet.pk.clone().into();
+            let meet_id: SpaceMeetEntityType = meet.sk.clone().into();
+            let action = SpaceAction::get(
+                cli,
+                &CompositePartition(space_id, meet_id.to_string()),
+                Some(EntityType::SpaceAction),
+            )
+            .await?;
+            if action.and_then(|a| a.status) != Some(SpaceActionStatus::Ongoing) {
+                response.skipped_unpublished += 1;
+                continue;
+            }
+
+            let status = meet.status;
+            let meet = meet.with_schedule_keys();
+            let Some(bucket) = meet.schedule_bucket.clone() else {
+                response.skipped_terminal += 1;
+                continue;
+            };
+            let updater = SpaceMeet::updater(&meet.pk, &meet.sk)
+                .with_schedule_bucket(bucket)
+                .with_next_deadline_at(meet.next_deadline_at);
+            if execute_meet_update_if(cli, updater, MeetGuard::status(status)).await? {
+                response.updated += 1;
+            }
+        }
+
+        match page.last_evaluated_key {
+            Some(key) if !key.is_empty() => last_key = Some(key),
+            _ => break,
+        }
+    }
+
+    tracing::info!(
+        meets_scanned = response.meets_scanned,
+        updated = response.updated,
+        skipped_terminal = response.skipped_terminal,
+        skipped_already_set = response.skipped_already_set,
+        skipped_unpublished = response.skipped_unpublished,
+        "meet schedule backfill complete"
+    );
+
+    Ok(response)
+}
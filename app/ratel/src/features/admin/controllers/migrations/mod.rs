@@ -1,8 +1,10 @@
+mod backfill_meet_schedule;
 mod backfill_space_score_rank;
 mod backfill_team_owner_role;
 mod backfill_user_points_from_history;
 mod backfill_user_team_role;
 
+pub use backfill_meet_schedule::*;
 pub use backfill_space_score_rank::*;
 pub use backfill_team_owner_role::*;
 pub use backfill_user_points_from_history::*;
@@ -7,10 +7,17 @@ pub struct CreateGlobalRewardRequest {
     pub point: i64,
     pub period: RewardPeriod,
     pub condition: RewardCondition,
+    #[serde(default)]
+    pub streak_multipliers: Vec<StreakMultiplier>,
+    #[serde(default)]
+    pub rules: RewardRules,
 }
 
 #[post("/api/admin/rewards", _user: AdminUser)]
 pub async fn create_reward(req: CreateGlobalRewardRequest) -> Result<Reward> {
+    StreakMultiplier::validate(&req.streak_multipliers)?;
+    req.rules.validate(req.point)?;
+
     let common_config = crate::common::CommonConfig::default();
     let cli = common_config.dynamodb();
 
@@ -21,7 +28,9 @@ pub async fn create_reward(req: CreateGlobalRewardRequest) -> Result<Reward> {
         return Err(SpaceRewardError::RewardAlreadyExists.into());
     }
 
-    let reward = Reward::new(req.behavior, req.point, req.period, req.condition);
+    let mut reward = Reward::new(req.behavior, req.point, req.period, req.condition);
+    reward.streak_multipliers = req.streak_multipliers;
+    reward.rules = req.rules;
     reward.create(cli).await?;
 
     Ok(reward)
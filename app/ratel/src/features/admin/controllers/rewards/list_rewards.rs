@@ -7,6 +7,10 @@ pub struct RewardResponse {
     pub point: i64,
     pub period: RewardPeriod,
     pub condition: RewardCondition,
+    #[serde(default)]
+    pub streak_multipliers: Vec<StreakMultiplier>,
+    #[serde(default)]
+    pub rules: RewardRules,
 }
 
 impl From<Reward> for RewardResponse {
@@ -16,6 +20,8 @@ impl From<Reward> for RewardResponse {
             point: value.point,
             period: value.period,
             condition: value.condition,
+            streak_multipliers: value.streak_multipliers,
+            rules: value.rules,
         }
     }
 }
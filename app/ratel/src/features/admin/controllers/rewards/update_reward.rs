@@ -7,10 +7,17 @@ pub struct UpdateGlobalRewardRequest {
     pub point: i64,
     pub period: RewardPeriod,
     pub condition: RewardCondition,
+    #[serde(default)]
+    pub streak_multipliers: Vec<StreakMultiplier>,
+    #[serde(default)]
+    pub rules: RewardRules,
 }
 
 #[put("/api/admin/rewards", _user: AdminUser)]
 pub async fn update_reward(req: UpdateGlobalRewardRequest) -> Result<Reward> {
+    StreakMultiplier::validate(&req.streak_multipliers)?;
+    req.rules.validate(req.point)?;
+
     let common_config = crate::common::CommonConfig::default();
     let cli = common_config.dynamodb();
 
@@ -25,6 +32,8 @@ pub async fn update_reward(req: UpdateGlobalRewardRequest) -> Result<Reward> {
         .with_point(req.point)
         .with_period(req.period)
         .with_condition(req.condition)
+        .with_streak_multipliers(req.streak_multipliers)
+        .with_rules(req.rules)
         .execute(cli)
         .await?;
 
@@ -9,6 +9,7 @@
 //   aws ses create-template --template file://<exported>.json
 
 pub mod email_verification;
+pub mod meet_notification;
 pub mod mention_notification;
 pub mod reply_on_comment;
 pub mod signup_code;
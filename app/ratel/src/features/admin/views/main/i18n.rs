@@ -55,6 +55,30 @@ translate! {
         en: "Edit",
         ko: "수정",
     },
+    streak_multipliers: {
+        en: "Streak XP bonus",
+        ko: "연속 활동 XP 보너스",
+    },
+    streak_multipliers_placeholder: {
+        en: "days:bonus% — e.g. 7:10, 30:25",
+        ko: "일수:보너스% — 예: 7:10, 30:25",
+    },
+    streak_multipliers_invalid: {
+        en: "Use days:bonus% pairs separated by commas, e.g. 7:10, 30:25",
+        ko: "쉼표로 구분된 일수:보너스% 형식을 사용하세요. 예: 7:10, 30:25",
+    },
+    rules: {
+        en: "Payout rules (JSON)",
+        ko: "지급 규칙 (JSON)",
+    },
+    rules_placeholder: {
+        en: "JSON with conditions, time_windows, point_tiers, membership_multipliers",
+        ko: "conditions, time_windows, point_tiers, membership_multipliers를 담은 JSON",
+    },
+    rules_invalid: {
+        en: "Payout rules must be valid JSON, or left empty",
+        ko: "지급 규칙은 올바른 JSON이거나 비어 있어야 합니다",
+    },
     no_rewards: {
         en: "No rewards configured",
         ko: "설정된 리워드가 없습니다",
@@ -5,7 +5,9 @@ use crate::common::utils::format::format_with_commas;
 use crate::common::ListResponse;
 use crate::common::RewardCondition;
 use crate::common::RewardPeriod;
+use crate::common::RewardRules;
 use crate::common::RewardUserBehavior;
+use crate::common::StreakMultiplier;
 use crate::features::admin::controllers::{
     create_reward, create_test_account, delete_test_account, get_analyze_quota,
     grant_enterprise_membership, list_enterprise_memberships, list_rewards, list_test_accounts,
@@ -42,6 +44,9 @@ pub fn AdminMainPage() -> Element {
     let mut form_period = use_signal(|| RewardPeriod::default());
     let mut form_condition_type = use_signal(|| ConditionType::default());
     let mut form_condition_value = use_signal(|| 0i64);
+    let mut form_streak_multipliers = use_signal(String::new);
+    let mut form_rules = use_signal(String::new);
+    let mut form_error = use_signal(|| Option::<String>::None);
     let mut grant_target_type = use_signal(MembershipGrantTargetType::default);
     let mut grant_username = use_signal(String::new);
     let mut grant_submitting = use_signal(|| false);
@@ -54,6 +59,9 @@ pub fn AdminMainPage() -> Element {
         form_period.set(RewardPeriod::default());
         form_condition_type.set(ConditionType::default());
         form_condition_value.set(0);
+        form_streak_multipliers.set(String::new());
+        form_rules.set(String::new());
+        form_error.set(None);
         show_form.set(true);
     };
 
@@ -65,6 +73,9 @@ pub fn AdminMainPage() -> Element {
         form_period.set(reward.period.clone());
         form_condition_type.set(ct);
         form_condition_value.set(cv);
+        form_streak_multipliers.set(StreakMultiplier::format_list(&reward.streak_multipliers));
+        form_rules.set(format_rules(&reward.rules));
+        form_error.set(None);
         editing.set(Some(reward));
         show_form.set(true);
     };
@@ -82,6 +93,16 @@ pub fn AdminMainPage() -> Element {
         let condition_type = form_condition_type.read().clone();
         let condition_value = *form_condition_value.read();
         let condition = RewardCondition::from_type_and_value(&condition_type, condition_value);
+        let Some(streak_multipliers) = StreakMultiplier::parse_list(&form_streak_multipliers.read())
+        else {
+            form_error.set(Some(tr.streak_multipliers_invalid.to_string()));
+            return;
+        };
+        let Some(rules) = parse_rules(&form_rules.read()) else {
+            form_error.set(Some(tr.rules_invalid.to_string()));
+            return;
+        };
+        form_error.set(None);
         is_submitting.set(true);
         spawn(async move {
             let result = if is_edit {
@@ -90,6 +111,8 @@ pub fn AdminMainPage() -> Element {
                     point,
                     period,
                     condition,
+                    streak_multipliers,
+                    rules: rules.clone(),
                 })
                 .await
             } else {
@@ -98,6 +121,8 @@ pub fn AdminMainPage() -> Element {
                     point,
                     period,
                     condition,
+                    streak_multipliers,
+                    rules: rules.clone(),
                 })
                 .await
             };
@@ -111,6 +136,7 @@ pub fn AdminMainPage() -> Element {
                 }
                 Err(e) => {
                     error!("Failed to save reward: {:?}", e);
+                    form_error.set(Some(e.to_string()));
                 }
             }
         });
@@ -317,6 +343,34 @@ pub fn AdminMainPage() -> Element {
                                 }
                             }
 
+                            // Streak XP ladder
+                            div { class: "mb-4 admin-arena__form admin-arena__form--stretch",
+                                label { class: "admin-arena__form-label", "{tr.streak_multipliers}" }
+                                input {
+                                    r#type: "text",
+                                    class: "admin-arena__form-input",
+                                    placeholder: "{tr.streak_multipliers_placeholder}",
+                                    value: "{form_streak_multipliers}",
+                                    oninput: move |e| form_streak_multipliers.set(e.value()),
+                                }
+                            }
+
+                            // Composable payout rules
+                            div { class: "mb-4 admin-arena__form admin-arena__form--stretch",
+                                label { class: "admin-arena__form-label", "{tr.rules}" }
+                                textarea {
+                                    class: "admin-arena__form-input admin-arena__form-textarea",
+                                    rows: 6,
+                                    placeholder: "{tr.rules_placeholder}",
+                                    value: "{form_rules}",
+                                    oninput: move |e| form_rules.set(e.value()),
+                                }
+                            }
+
+                            if let Some(message) = form_error() {
+                                p { class: "mb-4 admin-arena__form-error", "{message}" }
+                            }
+
                             // Buttons
                             div { class: "admin-arena__modal-actions",
                                 button {
@@ -349,6 +403,7 @@ pub fn AdminMainPage() -> Element {
                                     th { "{tr.point}" }
                                     th { "{tr.period}" }
                                     th { "{tr.condition}" }
+                                    th { "{tr.streak_multipliers}" }
                                     th { "{tr.actions}" }
                                 }
                             }
@@ -362,6 +417,7 @@ pub fn AdminMainPage() -> Element {
                                                 td { "{format_with_commas(reward.point)}" }
                                                 td { "{reward.period.label()}" }
                                                 td { "{reward.condition.label()}" }
+                                                td { "{StreakMultiplier::format_list(&reward.streak_multipliers)}" }
                                                 td {
                                                     button {
                                                         class: "admin-arena__table-link",
@@ -714,3 +770,19 @@ fn TestAccountsSection() -> Element {
         }
     }
 }
+
+/// Rules are edited as JSON; the default set shows as an empty field.
+fn format_rules(rules: &RewardRules) -> String {
+    if *rules == RewardRules::default() {
+        return String::new();
+    }
+    serde_json::to_string_pretty(rules).unwrap_or_default()
+}
+
+/// Inverse of [`format_rules`]. Blank input is the default rule set.
+fn parse_rules(s: &str) -> Option<RewardRules> {
+    if s.trim().is_empty() {
+        return Some(RewardRules::default());
+    }
+    serde_json::from_str(s).ok()
+}
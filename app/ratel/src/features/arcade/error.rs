@@ -1,6 +1,7 @@
-//! arcade-level umbrella error. Covers wallet, realtime channel, and
-//! stage scheduler concerns. Game-specific errors live next to each
-//! game (e.g. `games::fact_or_fold::types::FactOrFoldError`).
+//! arcade-level umbrella error. Covers wallet and stage scheduler
+//! concerns (realtime channel errors moved to
+//! `common::realtime::RealtimeError`). Game-specific errors live next
+//! to each game (e.g. `games::fact_or_fold::types::FactOrFoldError`).
 
 use crate::common::*;
 pub use thiserror::Error;
@@ -36,28 +37,6 @@ pub enum ArcadeError {
     )]
     WalletInsufficientRp,
 
-    // ── Realtime channel ──────────────────────────────────────────
-    #[error("channel not registered")]
-    #[translate(
-        en = "Unknown channel",
-        ko = "알 수 없는 채널입니다."
-    )]
-    ChannelUnknown,
-
-    #[error("not allowed to subscribe to this channel")]
-    #[translate(
-        en = "You are not allowed to access this channel",
-        ko = "이 채널을 구독할 권한이 없습니다."
-    )]
-    ChannelForbidden,
-
-    #[error("channel payload invalid")]
-    #[translate(
-        en = "Channel payload is invalid",
-        ko = "채널 페이로드가 올바르지 않습니다."
-    )]
-    ChannelPayloadInvalid,
-
     // ── Stage scheduler ───────────────────────────────────────────
     #[error("no next stage from current state")]
     #[translate(
@@ -83,11 +62,8 @@ impl ArcadeError {
             ArcadeError::WalletInsufficientChip
             | ArcadeError::WalletInsufficientRp
             | ArcadeError::WalletAmountOutOfRange
-            | ArcadeError::ChannelPayloadInvalid
             | ArcadeError::SchedulerTerminalStage => StatusCode::BAD_REQUEST,
             ArcadeError::WalletRedeemDisabled => StatusCode::FORBIDDEN,
-            ArcadeError::ChannelUnknown => StatusCode::NOT_FOUND,
-            ArcadeError::ChannelForbidden => StatusCode::FORBIDDEN,
             ArcadeError::StorageFailure => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
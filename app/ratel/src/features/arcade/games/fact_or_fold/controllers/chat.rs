@@ -11,8 +11,8 @@
 //! ### v1 realtime — polling
 //!
 //! Per design doc § A2' (re-decision): chat in v1 uses HTTP short
-//! polling, not SSE. The PR4e SSE infra + PR4f stream-listener
-//! fan-out are kept in place but only fully come alive once the
+//! polling, not SSE. The PR4e SSE infra + backplane fan-out are
+//! kept in place but only fully come alive once the
 //! Lambda binary supports streaming responses (v2 alongside the
 //! WebSocket switch). Until then the client polls
 //! `GET .../chat?since=<last_msg_id>` every 2~3s alongside the
@@ -26,7 +26,9 @@ use crate::common::models::auth::User;
 #[cfg(feature = "server")]
 use crate::features::arcade::games::fact_or_fold::models::{FactFoldChatMessage, FactFoldRound};
 #[cfg(feature = "server")]
-use crate::features::arcade::games::fact_or_fold::realtime::chat_payload_from;
+use crate::features::arcade::games::fact_or_fold::realtime::{
+    chat_payload_from, publish_chat_message,
+};
 #[cfg(feature = "server")]
 use crate::features::arcade::games::fact_or_fold::services::stage_machine;
 
@@ -75,13 +77,17 @@ pub async fn post_chat_handler(
         return Err(FactOrFoldError::RationaleInvalid.into());
     }
 
-    // DB write. The DDB Stream / EventBridge fan-out (PR4f stream
-    // listener wiring below) takes care of the SSE push.
+    // DB write, then a single publish; the hub's backplane carries
+    // the SSE push to subscribers on other instances. The row is
+    // the source of truth, so a failed push never fails the post.
     let row = FactFoldChatMessage::new(&inner_round_id, user.pk.clone(), req.text);
     row.create(cli).await.map_err(|e| {
         crate::error!("post_chat_handler create failed: {e}");
         FactOrFoldError::StorageFailure
     })?;
+    if let Err(e) = publish_chat_message(&inner_round_id, row.clone()).await {
+        crate::error!("post_chat_handler publish failed: {e}");
+    }
 
     Ok(PostChatResponse {
         msg_id: row.id().unwrap_or_default(),
@@ -3,6 +3,8 @@ pub mod essence;
 pub mod subjects;
 pub mod lobby;
 pub mod queue;
+pub mod replay;
+pub mod reviews;
 pub mod round_play;
 pub mod round_read;
 pub mod settings;
@@ -14,6 +16,8 @@ pub use essence::*;
 pub use subjects::*;
 pub use lobby::*;
 pub use queue::*;
+pub use replay::*;
+pub use reviews::*;
 pub use round_play::*;
 pub use round_read::*;
 pub use settings::*;
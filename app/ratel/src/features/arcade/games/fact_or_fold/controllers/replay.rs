@@ -0,0 +1,306 @@
+//! Spectator + replay endpoints (PR8).
+//!
+//! Surface:
+//!   GET /api/fact-or-fold/rounds/{round_id}/spectate   — live snapshot (in-progress only)
+//!   GET /api/fact-or-fold/rounds/{round_id}/replay     — full timeline (settled only)
+//!
+//! Both are open to any signed-in user, not just participants —
+//! community managers link rounds out to showcase good debates.
+//!
+//! ### Spectate
+//!
+//! Read-only mirror of what the table sees, with the same stage
+//! gates as `round_read.rs`: bets and rationale text stay hidden
+//! until `Reveal`, the insider is never marked, the verdict never
+//! leaks. The same snapshot is the subscribe payload of the
+//! `fof.spectate:{round_id}` channel
+//! ([`crate::features::arcade::games::fact_or_fold::realtime::spectator`]);
+//! this GET is the polling fallback when SSE drops.
+//!
+//! ### Replay
+//!
+//! Settled rounds only. Merges every persisted round-scoped row into
+//! one chronological timeline via the pure
+//! [`crate::features::arcade::games::fact_or_fold::services::replay`]
+//! builder. Each event carries its original timestamp plus an
+//! offset from `started_at` so the game-room UI can scrub through it.
+
+use crate::common::*;
+use crate::features::arcade::games::fact_or_fold::types::*;
+
+#[cfg(feature = "server")]
+use crate::common::models::auth::User;
+#[cfg(feature = "server")]
+use crate::features::arcade::games::fact_or_fold::models::{
+    FactFoldBet, FactFoldChatMessage, FactFoldParticipant, FactFoldRationale, FactFoldRound,
+    FactFoldSettings, FactFoldSettlement, FactFoldSubject,
+};
+#[cfg(feature = "server")]
+use crate::features::arcade::games::fact_or_fold::realtime::chat_payload_from;
+#[cfg(feature = "server")]
+use crate::features::arcade::games::fact_or_fold::services::{
+    build_replay_timeline, stage_machine, ReplayInput,
+};
+
+// ── Shared loaders ────────────────────────────────────────────────
+
+/// Round-scoped rows all live under `FACT_FOLD#{round_id}`; each
+/// entity type is an sk-prefix query on that pk. v1 rounds fit in a
+/// single page per entity (4 players), so no pagination here — chat is
+/// the exception, see [`load_round_chat`].
+#[cfg(feature = "server")]
+macro_rules! query_round_rows {
+    ($ty:ty, $cli:expr, $round_pk:expr, $prefix:literal, $limit:expr) => {{
+        let opts = <$ty>::opt().sk($prefix.to_string()).limit($limit as i32);
+        <$ty>::query($cli, $round_pk.clone(), opts)
+            .await
+            .map(|(rows, _)| rows)
+            .map_err(|e| {
+                crate::error!("fof replay query {} failed: {e}", $prefix);
+                FactOrFoldError::StorageFailure
+            })
+    }};
+}
+
+/// Every chat row of the round, oldest first. Unlike the per-player
+/// rows, chat length is only bounded by the debate clock, so a lively
+/// round spills past one `CHAT_PAGE_LIMIT` page — follow the bookmark.
+#[cfg(feature = "server")]
+async fn load_round_chat(
+    cli: &aws_sdk_dynamodb::Client,
+    round_pk: &Partition,
+) -> Result<Vec<FactFoldChatMessage>> {
+    let mut rows = Vec::new();
+    let mut bookmark = None;
+    loop {
+        let opts = FactFoldChatMessage::opt_with_bookmark(bookmark)
+            .sk("FACT_FOLD_CHAT".to_string())
+            .limit(CHAT_PAGE_LIMIT as i32);
+        let (page, next) = FactFoldChatMessage::query(cli, round_pk.clone(), opts)
+            .await
+            .map_err(|e| {
+                crate::error!("fof replay chat query failed: {e}");
+                FactOrFoldError::StorageFailure
+            })?;
+        rows.extend(page);
+        match next {
+            Some(b) => bookmark = Some(b),
+            None => break,
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(feature = "server")]
+async fn load_subject(
+    cli: &aws_sdk_dynamodb::Client,
+    round: &FactFoldRound,
+    reveal_verdict: bool,
+) -> Result<RoundSubjectResponse> {
+    let pk = FactFoldSubject::anchor_pk();
+    let sk: EntityType = FactFoldSubjectEntityType(round.subject_id.clone()).into();
+    let subject = FactFoldSubject::get(cli, &pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("fof replay subject read failed: {e}");
+            FactOrFoldError::StorageFailure
+        })?
+        .ok_or(FactOrFoldError::RoundNotFound)?;
+
+    Ok(RoundSubjectResponse {
+        id: FactFoldSubjectEntityType(round.subject_id.clone()),
+        headline_text: subject.headline_text,
+        body_excerpt: subject.body_excerpt,
+        source_label: subject.source_label,
+        category_tags: subject.category_tags,
+        difficulty: subject.difficulty,
+        verdict: reveal_verdict.then_some(subject.verdict),
+        reveal_summary: if reveal_verdict { subject.reveal_summary } else { String::new() },
+        reveal_sources: if reveal_verdict { subject.reveal_sources } else { Vec::new() },
+    })
+}
+
+/// Participant rows enriched with display metadata. `reveal_insider`
+/// is only true for replays — spectators of a live round must not
+/// learn who the insider is.
+#[cfg(feature = "server")]
+async fn load_participant_summaries(
+    cli: &aws_sdk_dynamodb::Client,
+    round_pk: &Partition,
+    reveal_insider: bool,
+) -> Result<Vec<RoundParticipantSummary>> {
+    let rows = query_round_rows!(FactFoldParticipant, cli, round_pk, "FACT_FOLD_PARTICIPANT", 50)?;
+
+    let user_keys: Vec<(Partition, EntityType)> = rows
+        .iter()
+        .map(|p| (p.user_pk.clone(), EntityType::User))
+        .collect();
+    let user_rows = User::batch_get(cli, user_keys).await.map_err(|e| {
+        crate::error!("fof replay user batch load failed: {e}");
+        FactOrFoldError::StorageFailure
+    })?;
+    let user_by_pk: std::collections::HashMap<String, User> = user_rows
+        .into_iter()
+        .map(|u| (u.pk.to_string(), u))
+        .collect();
+
+    Ok(rows
+        .into_iter()
+        .map(|p| {
+            let (username, display_name, profile_url) = user_by_pk
+                .get(&p.user_pk.to_string())
+                .map(|u| (u.username.clone(), u.display_name.clone(), u.profile_url.clone()))
+                .unwrap_or_default();
+            RoundParticipantSummary {
+                user_pk: UserPartition::from(p.user_pk),
+                username,
+                display_name,
+                profile_url,
+                joined_at: p.joined_at,
+                last_seen_at: p.last_seen_at,
+                forfeited: p.forfeited,
+                is_insider: reveal_insider && p.is_insider,
+            }
+        })
+        .collect())
+}
+
+#[cfg(feature = "server")]
+async fn load_round_advanced(
+    cli: &aws_sdk_dynamodb::Client,
+    round_id: &str,
+) -> Result<FactFoldRound> {
+    let (pk, sk) = FactFoldRound::keys(round_id);
+    let round = FactFoldRound::get(cli, &pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("fof replay load_round failed: {e}");
+            FactOrFoldError::StorageFailure
+        })?
+        .ok_or(FactOrFoldError::RoundNotFound)?;
+    let settings = FactFoldSettings::get_or_default(cli)
+        .await
+        .unwrap_or_default();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    stage_machine::advance_round_if_due(cli, round, &settings, now).await
+}
+
+/// Build the spectator snapshot for an in-progress round. Shared by
+/// the polling endpoint below and the `fof.spectate` channel's
+/// authorize hook so both transports render identical state.
+#[cfg(feature = "server")]
+pub async fn load_spectator_snapshot(
+    cli: &aws_sdk_dynamodb::Client,
+    round_id: &str,
+) -> Result<SpectatorSnapshotResponse> {
+    let round = load_round_advanced(cli, round_id).await?;
+    if matches!(round.status, RoundStatus::Waiting | RoundStatus::Settled) {
+        return Err(FactOrFoldError::RoundNotSpectatable.into());
+    }
+
+    let round_pk = round.pk.clone();
+    let subject = load_subject(cli, &round, false).await?;
+    let participants = load_participant_summaries(cli, &round_pk, false).await?;
+
+    let unlocked = matches!(
+        round.status,
+        RoundStatus::Reveal | RoundStatus::Debate | RoundStatus::Settlement
+    );
+    let (bets, rationales) = if unlocked {
+        let bets = query_round_rows!(FactFoldBet, cli, round_pk, "FACT_FOLD_BET", 50)?;
+        let rationales =
+            query_round_rows!(FactFoldRationale, cli, round_pk, "FACT_FOLD_RATIONALE", 50)?;
+        (
+            bets.iter().map(BetResponse::from).collect(),
+            rationales
+                .into_iter()
+                .map(|r| RationaleResponse {
+                    user_pk: UserPartition::from(r.user_pk),
+                    text: r.text,
+                    submitted_at: r.submitted_at,
+                    essence_eligible: r.essence_eligible,
+                    essence_registered: r.essence_registered,
+                })
+                .collect(),
+        )
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    let chat = load_round_chat(cli, &round_pk).await?;
+
+    Ok(SpectatorSnapshotResponse {
+        round: RoundResponse::from(&round),
+        subject,
+        participants,
+        bets,
+        rationales,
+        chat: chat.into_iter().map(chat_payload_from).collect(),
+    })
+}
+
+// ── GET /api/fact-or-fold/rounds/{round_id}/spectate ─────────────
+
+#[get("/api/fact-or-fold/rounds/{round_id}/spectate", _user: User)]
+pub async fn get_spectator_snapshot_handler(
+    round_id: FactFoldRoundEntityType,
+) -> Result<SpectatorSnapshotResponse> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    load_spectator_snapshot(cli, &round_id.0).await
+}
+
+// ── GET /api/fact-or-fold/rounds/{round_id}/replay ───────────────
+
+#[get("/api/fact-or-fold/rounds/{round_id}/replay", _user: User)]
+pub async fn get_round_replay_handler(
+    round_id: FactFoldRoundEntityType,
+) -> Result<RoundReplayResponse> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let inner_round_id = round_id.0.clone();
+
+    let (pk, sk) = FactFoldRound::keys(&inner_round_id);
+    let round = FactFoldRound::get(cli, &pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("get_round_replay_handler round read failed: {e}");
+            FactOrFoldError::StorageFailure
+        })?
+        .ok_or(FactOrFoldError::RoundNotFound)?;
+    if !matches!(round.status, RoundStatus::Settled) {
+        return Err(FactOrFoldError::RoundNotSettled.into());
+    }
+
+    let subject = load_subject(cli, &round, true).await?;
+    let participants = load_participant_summaries(cli, &pk, true).await?;
+    let bets = query_round_rows!(FactFoldBet, cli, pk, "FACT_FOLD_BET", 50)?;
+    let rationales = query_round_rows!(FactFoldRationale, cli, pk, "FACT_FOLD_RATIONALE", 50)?;
+    let chat = load_round_chat(cli, &pk).await?;
+    let settlements =
+        query_round_rows!(FactFoldSettlement, cli, pk, "FACT_FOLD_SETTLEMENT", 50)?;
+    let settings = FactFoldSettings::get_or_default(cli)
+        .await
+        .unwrap_or_default();
+
+    let events = build_replay_timeline(ReplayInput {
+        round: &round,
+        bets: &bets,
+        rationales: &rationales,
+        chat: &chat,
+        settlements: &settlements,
+        settings: &settings,
+    });
+
+    let started_at = round.started_at.unwrap_or(round.created_at);
+    let settled_at = round.settled_at.unwrap_or(round.updated_at);
+    Ok(RoundReplayResponse {
+        round_id,
+        subject,
+        participants,
+        started_at,
+        settled_at,
+        duration_ms: (settled_at - started_at).max(0),
+        events,
+    })
+}
@@ -0,0 +1,194 @@
+//! Collusion review queue for *Fact or Fold* admin.
+//!
+//! Surface:
+//!  - GET  /api/fact-or-fold/admin/reviews?status&bookmark
+//!  - POST /api/fact-or-fold/admin/reviews/{round_id}/approve
+//!  - POST /api/fact-or-fold/admin/reviews/{round_id}/void
+//!
+//! Rows are opened by `settle_round_internal` when the
+//! `services::collusion` detector fires. Approve re-runs the normal
+//! settlement (the detector is skipped for approved rows) and clears
+//! any win-rate flag it carried; void refunds every participant's
+//! buy-in and settles the round with no stats or leaderboard movement.
+
+use crate::common::*;
+use crate::features::arcade::games::fact_or_fold::types::*;
+
+#[cfg(feature = "server")]
+use crate::common::models::auth::AdminUser;
+#[cfg(feature = "server")]
+use crate::features::arcade::games::fact_or_fold::controllers::settlement::{
+    load_review, settle_round_internal, void_round_internal,
+};
+#[cfg(feature = "server")]
+use crate::features::arcade::games::fact_or_fold::models::{
+    FactFoldCollusionReview, FactFoldUserStats,
+};
+
+#[cfg(feature = "server")]
+const REVIEW_SK_PREFIX: &str = "FACT_FOLD_COLLUSION_REVIEW";
+#[cfg(feature = "server")]
+const REVIEW_PAGE_LIMIT: i32 = 50;
+
+#[get("/api/fact-or-fold/admin/reviews?status&bookmark", _user: AdminUser)]
+pub async fn list_reviews_handler(
+    status: Option<CollusionReviewStatus>,
+    bookmark: Option<String>,
+) -> Result<ListResponse<CollusionReviewResponse>> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    // Each status is its own GSI3 partition. Pending drains oldest
+    // first so nothing ages out of the queue; resolved tabs show the
+    // latest decisions first.
+    let res = match status {
+        Some(status) => {
+            let opts =
+                FactFoldCollusionReview::opt_with_bookmark(bookmark).limit(REVIEW_PAGE_LIMIT);
+            let opts = if status == CollusionReviewStatus::Pending {
+                opts.oldest()
+            } else {
+                opts
+            };
+            FactFoldCollusionReview::find_by_status(cli, status, opts).await
+        }
+        None => {
+            let opts = FactFoldCollusionReview::opt_with_bookmark(bookmark)
+                .sk(REVIEW_SK_PREFIX.to_string())
+                .limit(REVIEW_PAGE_LIMIT);
+            FactFoldCollusionReview::query(cli, FactFoldCollusionReview::anchor_pk(), opts).await
+        }
+    };
+    let (rows, next) = res.map_err(|e| {
+        crate::error!("list_reviews_handler query failed: {e}");
+        FactOrFoldError::StorageFailure
+    })?;
+
+    let items: Vec<CollusionReviewResponse> = rows
+        .into_iter()
+        .map(CollusionReviewResponse::from)
+        .collect();
+    Ok((items, next).into())
+}
+
+#[post("/api/fact-or-fold/admin/reviews/{round_id}/approve", user: AdminUser)]
+pub async fn approve_review_handler(
+    round_id: FactFoldRoundEntityType,
+    req: ResolveCollusionReviewRequest,
+) -> Result<CollusionReviewResponse> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let review = resolve_review(
+        cli,
+        &round_id.0,
+        CollusionReviewStatus::Approved,
+        user.pk.clone(),
+        req.note,
+    )
+    .await?;
+    clear_win_rate_flags(cli, &review).await;
+    settle_round_internal(cli, &round_id.0).await?;
+    Ok(review.into())
+}
+
+#[post("/api/fact-or-fold/admin/reviews/{round_id}/void", user: AdminUser)]
+pub async fn void_review_handler(
+    round_id: FactFoldRoundEntityType,
+    req: ResolveCollusionReviewRequest,
+) -> Result<CollusionReviewResponse> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let review = resolve_review(
+        cli,
+        &round_id.0,
+        CollusionReviewStatus::Voided,
+        user.pk.clone(),
+        req.note,
+    )
+    .await?;
+    void_round_internal(cli, &round_id.0).await?;
+    Ok(review.into())
+}
+
+/// Flip a pending review to its terminal status. The write is
+/// conditional on the stored row still being `Pending`, so a
+/// concurrent approve and void can never both land for one round.
+#[cfg(feature = "server")]
+async fn resolve_review(
+    cli: &aws_sdk_dynamodb::Client,
+    round_id: &str,
+    status: CollusionReviewStatus,
+    resolved_by: Partition,
+    note: String,
+) -> Result<FactFoldCollusionReview> {
+    use aws_sdk_dynamodb::types::AttributeValue as AV;
+
+    let mut review = load_review(cli, round_id)
+        .await?
+        .ok_or(FactOrFoldError::ReviewNotFound)?;
+    if review.status != CollusionReviewStatus::Pending {
+        return Err(FactOrFoldError::ReviewAlreadyResolved.into());
+    }
+
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    review.status = status;
+    review.resolved_at = Some(now);
+    review.resolved_by = Some(resolved_by);
+    review.note = note.trim().to_string();
+    review.updated_at = now;
+
+    // Full put so the GSI3 status key moves along with `status`.
+    let item = review.indexed_fields(serde_dynamo::to_item(&review)?);
+    let res = cli
+        .put_item()
+        .table_name(FactFoldCollusionReview::table_name())
+        .set_item(Some(item))
+        .condition_expression("#status = :pending")
+        .expression_attribute_names("#status", "status")
+        .expression_attribute_values(
+            ":pending",
+            AV::S(CollusionReviewStatus::Pending.to_string()),
+        )
+        .send()
+        .await;
+    if let Err(e) = res {
+        let svc = e.into_service_error();
+        if matches!(
+            svc,
+            aws_sdk_dynamodb::operation::put_item::PutItemError::ConditionalCheckFailedException(_)
+        ) {
+            return Err(FactOrFoldError::ReviewAlreadyResolved.into());
+        }
+        crate::error!("resolve_review put failed: {svc}");
+        return Err(FactOrFoldError::StorageFailure.into());
+    }
+    Ok(review)
+}
+
+/// Record the accuracy behind every win-rate signal on an approved
+/// review as that player's clearance, so the rule does not hold their
+/// next round for the same numbers. Best-effort: a failed write only
+/// means the player may be flagged once more.
+#[cfg(feature = "server")]
+async fn clear_win_rate_flags(cli: &aws_sdk_dynamodb::Client, review: &FactFoldCollusionReview) {
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let cleared = review
+        .signals
+        .iter()
+        .filter(|s| s.kind == CollusionSignalKind::AbnormalWinRate);
+    for signal in cleared {
+        for user_pk in &signal.user_pks {
+            let (pk, sk) = FactFoldUserStats::keys(&user_pk.0);
+            if let Err(e) = FactFoldUserStats::updater(&pk, &sk)
+                .with_win_rate_cleared_bps(signal.value)
+                .with_updated_at(now)
+                .execute(cli)
+                .await
+            {
+                crate::warn!("clear_win_rate_flags failed for {}: {e}", user_pk.0);
+            }
+        }
+    }
+}
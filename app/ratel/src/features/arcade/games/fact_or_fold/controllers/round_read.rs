@@ -13,7 +13,7 @@
 //!   GET /api/fact-or-fold/rounds/{round_id}/bets           — bet roster (gated)
 //!   GET /api/fact-or-fold/rounds/{round_id}/rationale      — rationales (gated)
 //!   GET /api/fact-or-fold/rounds/{round_id}/participants   — participants + display meta
-//!   GET /api/fact-or-fold/rounds/{round_id}/settlement     — final breakdown (settled-only)
+//!   GET /api/fact-or-fold/rounds/{round_id}/settlement     — final breakdown (settled or held for review)
 
 use crate::common::*;
 use crate::features::arcade::games::fact_or_fold::controllers::settlement::SettleRoundResponse;
@@ -271,6 +271,16 @@ pub async fn get_round_settlement_handler(
     let round = load_round_advanced_or_404(cli, &inner_round_id).await?;
     ensure_participant(&round, &user.pk)?;
 
+    // A held round is a normal state, not a failure: the payout waits
+    // on an admin review, same as the tick path in `round_play` treats it.
+    if round.held_for_review {
+        return Ok(SettleRoundResponse {
+            round_id: inner_round_id,
+            outcomes: Vec::new(),
+            held_for_review: true,
+        });
+    }
+
     if !matches!(round.status, RoundStatus::Settled) {
         return Err(FactOrFoldError::RoundNotSettled.into());
     }
@@ -13,6 +13,11 @@
 //!    can retry without doubling pay-outs).
 //! 2. Load subject (verdict), bets, rationales, participants,
 //!    arcade settings, FOF settings (for chip multipliers).
+//!    Unless an admin already approved the round, run the
+//!    `services::collusion` detector; any signal holds the round in
+//!    `Settlement` with a pending `FactFoldCollusionReview` and
+//!    returns [`FactOrFoldError::RoundHeldForReview`] before any
+//!    chips move. A pending review short-circuits the same way.
 //! 3. Run the pure `settle_round` formula.
 //! 4. For each `SettlementOutcome`:
 //!    a. `FactFoldSettlement::create` (server defaults to
@@ -43,12 +48,13 @@ use rmcp::schemars;
 use crate::common::models::auth::AdminUser;
 #[cfg(feature = "server")]
 use crate::features::arcade::games::fact_or_fold::models::{
-    FactFoldBet, FactFoldLeaderboardEntry, FactFoldParticipant, FactFoldRationale, FactFoldRound,
-    FactFoldSettings, FactFoldSettlement, FactFoldSubject, FactFoldSubjectPlay, FactFoldUserStats,
+    FactFoldBet, FactFoldCollusionReview, FactFoldLeaderboardEntry, FactFoldParticipant,
+    FactFoldRationale, FactFoldRound, FactFoldSettings, FactFoldSettlement, FactFoldSubject,
+    FactFoldSubjectPlay, FactFoldUserStats,
 };
 #[cfg(feature = "server")]
 use crate::features::arcade::games::fact_or_fold::services::{
-    settle_round, SettleRoundInput, SettlementOutcome,
+    detect_round_collusion, settle_round, SettleRoundInput, SettlementOutcome,
 };
 #[cfg(feature = "server")]
 use crate::features::arcade::models::ArcadeSettings;
@@ -64,6 +70,10 @@ pub struct SettleRoundResponse {
     /// Per-user outcomes. Already settled rounds return the
     /// previously-persisted breakdown.
     pub outcomes: Vec<SettlementBreakdown>,
+    /// The payout is parked behind a pending collusion review;
+    /// `outcomes` stays empty until an admin resolves it.
+    #[serde(default)]
+    pub held_for_review: bool,
 }
 
 #[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
@@ -128,9 +138,22 @@ pub async fn settle_round_internal(
         return Ok(SettleRoundResponse {
             round_id: round_id.to_string(),
             outcomes: breakdowns,
+            held_for_review: false,
         });
     }
 
+    // Collusion gate. A pending review holds the payout; an approved
+    // one means an admin already cleared the signals, so skip the
+    // detector and settle normally.
+    let review = load_review(cli, round_id).await?;
+    let approved = match review.as_ref().map(|r| r.status) {
+        Some(CollusionReviewStatus::Pending) => {
+            return Err(FactOrFoldError::RoundHeldForReview.into());
+        }
+        Some(CollusionReviewStatus::Approved) => true,
+        Some(CollusionReviewStatus::Voided) | None => false,
+    };
+
     // Load all the round-scoped rows. v1 fits comfortably in a
     // single pk query (4 players, ≤8 rows total — round + 4
     // participants + 4 bets + ≤4 rationales + settlement targets).
@@ -174,6 +197,16 @@ pub async fn settle_round_internal(
             FactOrFoldError::StorageFailure
         })?;
 
+    if !approved {
+        let participant_pks: Vec<Partition> =
+            participants.iter().map(|p| p.user_pk.clone()).collect();
+        let signals = detect_round_collusion(cli, round_id, &participant_pks, &bets).await?;
+        if !signals.is_empty() {
+            hold_for_review(cli, &mut round, round_id, participant_pks, signals, &bets).await?;
+            return Err(FactOrFoldError::RoundHeldForReview.into());
+        }
+    }
+
     let fof_settings = FactFoldSettings::get_or_default(cli)
         .await
         .unwrap_or_default();
@@ -254,9 +287,9 @@ pub async fn settle_round_internal(
         // user against the same subject rejects with
         // `SubjectAlreadyPlayed`. The marker is keyed by subject id
         // so the user can still pick up a fresh subject when the
-        // window rotates.
+        // window rotates. Upsert: a held round already wrote it.
         let play = FactFoldSubjectPlay::new(&o.user_id, &round.subject_id, round_id);
-        if let Err(e) = play.create(cli).await {
+        if let Err(e) = play.upsert(cli).await {
             crate::error!(
                 "settle_round_internal subject_play marker write failed for {}: {e}",
                 o.user_id
@@ -299,6 +332,8 @@ pub async fn settle_round_internal(
     // settlement rows that exist (or didn't).
     round.status = RoundStatus::Settled;
     round.settled_at = Some(now);
+    round.held_for_review = false;
+    round.push_stage_mark(RoundStatus::Settled, now);
     round.updated_at = now;
     round.upsert(cli).await.map_err(|e| {
         crate::error!("settle_round_internal round upsert failed: {e}");
@@ -308,6 +343,169 @@ pub async fn settle_round_internal(
     Ok(SettleRoundResponse {
         round_id: round_id.to_string(),
         outcomes: breakdowns,
+        held_for_review: false,
+    })
+}
+
+#[cfg(feature = "server")]
+pub async fn load_review(
+    cli: &aws_sdk_dynamodb::Client,
+    round_id: &str,
+) -> Result<Option<FactFoldCollusionReview>> {
+    let (pk, sk) = FactFoldCollusionReview::keys(round_id);
+    FactFoldCollusionReview::get(cli, &pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("load_review read failed: {e}");
+            FactOrFoldError::StorageFailure.into()
+        })
+}
+
+/// Park the round in `Settlement` behind a pending review. Players
+/// are released (`current_round_id` cleared) and the subject-play
+/// marker is written now so a held round neither blocks the next
+/// queue nor lets the same subject be replayed while it waits.
+#[cfg(feature = "server")]
+async fn hold_for_review(
+    cli: &aws_sdk_dynamodb::Client,
+    round: &mut FactFoldRound,
+    round_id: &str,
+    participant_pks: Vec<Partition>,
+    signals: Vec<CollusionSignal>,
+    bets: &[FactFoldBet],
+) -> Result<()> {
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let total_stake = bets.iter().map(|b| b.amount_rp).sum();
+    let user_ids: Vec<String> = participant_pks
+        .iter()
+        .map(|pk| UserPartition::from(pk.clone()).0)
+        .collect();
+
+    let review = FactFoldCollusionReview::new_pending(
+        round_id,
+        round.subject_id.clone(),
+        participant_pks,
+        signals,
+        total_stake,
+    );
+    // `create` fails if a concurrent settle already opened the
+    // review — either way the round is held.
+    if let Err(e) = review.create(cli).await {
+        crate::debug!("hold_for_review review create skipped: {e}");
+    }
+
+    round.status = RoundStatus::Settlement;
+    round.held_for_review = true;
+    round.updated_at = now;
+    round.upsert(cli).await.map_err(|e| {
+        crate::error!("hold_for_review round upsert failed: {e}");
+        FactOrFoldError::StorageFailure
+    })?;
+
+    for user_id in user_ids.iter() {
+        let mut stats = FactFoldUserStats::get_or_default(cli, user_id).await?;
+        stats.current_round_id = None;
+        stats.updated_at = now;
+        if let Err(e) = stats.upsert(cli).await {
+            crate::error!("hold_for_review user_stats upsert failed for {user_id}: {e}");
+        }
+        let play = FactFoldSubjectPlay::new(user_id, &round.subject_id, round_id);
+        if let Err(e) = play.upsert(cli).await {
+            crate::error!("hold_for_review subject_play marker write failed for {user_id}: {e}");
+        }
+    }
+    Ok(())
+}
+
+/// Void a held round: every participant gets the buy-in back and no
+/// stats or leaderboard rows move. Gated per user by the same
+/// `FactFoldSettlement` create-if-not-exists row as a normal payout,
+/// so a retry never double refunds.
+#[cfg(feature = "server")]
+pub async fn void_round_internal(
+    cli: &aws_sdk_dynamodb::Client,
+    round_id: &str,
+) -> Result<SettleRoundResponse> {
+    let (round_pk, round_sk) = FactFoldRound::keys(round_id);
+    let mut round = FactFoldRound::get(cli, &round_pk, Some(round_sk))
+        .await
+        .map_err(|e| {
+            crate::error!("void_round_internal round read failed: {e}");
+            FactOrFoldError::StorageFailure
+        })?
+        .ok_or(FactOrFoldError::RoundNotFound)?;
+
+    if matches!(round.status, RoundStatus::Settled) {
+        let breakdowns = load_existing_breakdowns(cli, &round_pk).await?;
+        return Ok(SettleRoundResponse {
+            round_id: round_id.to_string(),
+            outcomes: breakdowns,
+            held_for_review: false,
+        });
+    }
+
+    let opts_p = FactFoldParticipant::opt()
+        .sk("FACT_FOLD_PARTICIPANT".to_string())
+        .limit(50);
+    let (participants, _) = FactFoldParticipant::query(cli, round_pk.clone(), opts_p)
+        .await
+        .map_err(|e| {
+            crate::error!("void_round_internal participants query failed: {e}");
+            FactOrFoldError::StorageFailure
+        })?;
+
+    let arcade_settings = ArcadeSettings::get_or_default(cli).await.unwrap_or_default();
+    let buy_in = arcade_settings.default_buy_in_chips;
+    let wallet = DdbArcadeWallet::new(cli.clone());
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let mut breakdowns = Vec::with_capacity(participants.len());
+
+    for p in participants.iter() {
+        let user_id = UserPartition::from(p.user_pk.clone()).0;
+        let (settle_pk, settle_sk) = FactFoldSettlement::keys(round_id, &user_id);
+        let row = FactFoldSettlement {
+            pk: settle_pk,
+            sk: settle_sk,
+            created_at: now,
+            updated_at: now,
+            user_pk: p.user_pk.clone(),
+            idempotency_key: FactFoldSettlement::idempotency_key_for(round_id, &user_id),
+            base_refund: buy_in,
+            correct_bonus: 0,
+            pool_share: 0,
+            influence_bonus: 0,
+            insider_bonus: 0,
+            chips_out: buy_in,
+        };
+        let inserted = row.create(cli).await.is_ok();
+        breakdowns.push(SettlementBreakdown {
+            user_pk: UserPartition(user_id.clone()),
+            base_refund: buy_in,
+            chips_out: buy_in,
+            ..Default::default()
+        });
+        if !inserted {
+            continue;
+        }
+        if let Err(e) = wallet.settle(&user_id, round_id, buy_in).await {
+            crate::error!("void_round_internal wallet.settle failed for {user_id}: {e}");
+        }
+    }
+
+    round.status = RoundStatus::Settled;
+    round.settled_at = Some(now);
+    round.held_for_review = false;
+    round.push_stage_mark(RoundStatus::Settled, now);
+    round.updated_at = now;
+    round.upsert(cli).await.map_err(|e| {
+        crate::error!("void_round_internal round upsert failed: {e}");
+        FactOrFoldError::StorageFailure
+    })?;
+
+    Ok(SettleRoundResponse {
+        round_id: round_id.to_string(),
+        outcomes: breakdowns,
+        held_for_review: false,
     })
 }
 
@@ -1,9 +1,13 @@
+pub mod use_admin_reviews;
 pub mod use_admin_subjects;
 pub mod use_admin_schedule;
 pub mod use_admin_settings;
 pub mod use_round;
+pub mod use_round_replay;
 
+pub use use_admin_reviews::*;
 pub use use_admin_subjects::*;
 pub use use_admin_schedule::*;
 pub use use_admin_settings::*;
 pub use use_round::*;
+pub use use_round_replay::*;
@@ -0,0 +1,67 @@
+//! `UseFactFoldAdminReviews` — drive the collusion review queue.
+//!
+//! Defaults to the `Pending` filter since that is the only tab with
+//! work in it. The queue pages through the status index; approve /
+//! void are async methods that refresh it, and the row component owns
+//! its own busy / error UX.
+
+use crate::common::hooks::{InfiniteQuery, use_infinite_query};
+use crate::features::arcade::games::fact_or_fold::{
+    CollusionReviewResponse, CollusionReviewStatus, ResolveCollusionReviewRequest,
+    approve_review_handler, list_reviews_handler, void_review_handler,
+};
+use crate::*;
+
+#[derive(Clone, Copy, DioxusController)]
+pub struct UseFactFoldAdminReviews {
+    /// Active filter — drives the query's status param.
+    pub status_filter: Signal<Option<CollusionReviewStatus>>,
+    pub reviews:
+        InfiniteQuery<String, CollusionReviewResponse, ListResponse<CollusionReviewResponse>>,
+}
+
+impl UseFactFoldAdminReviews {
+    /// Switch tabs and drop the pages loaded for the previous one.
+    pub fn set_status(&mut self, status: CollusionReviewStatus) {
+        self.status_filter.set(Some(status));
+        self.reviews.restart();
+    }
+
+    pub async fn approve(
+        &mut self,
+        round_id: crate::FactFoldRoundEntityType,
+        note: String,
+    ) -> crate::common::Result<CollusionReviewResponse> {
+        let res = approve_review_handler(round_id, ResolveCollusionReviewRequest { note }).await?;
+        self.reviews.refresh();
+        Ok(res)
+    }
+
+    pub async fn void(
+        &mut self,
+        round_id: crate::FactFoldRoundEntityType,
+        note: String,
+    ) -> crate::common::Result<CollusionReviewResponse> {
+        let res = void_review_handler(round_id, ResolveCollusionReviewRequest { note }).await?;
+        self.reviews.refresh();
+        Ok(res)
+    }
+}
+
+pub fn use_fact_fold_admin_reviews_provider()
+-> std::result::Result<UseFactFoldAdminReviews, RenderError> {
+    if let Some(ctx) = try_use_context::<UseFactFoldAdminReviews>() {
+        return Ok(ctx);
+    }
+
+    let status_filter = use_signal(|| Some(CollusionReviewStatus::Pending));
+    let reviews = use_infinite_query(move |bookmark| {
+        let status = status_filter();
+        async move { list_reviews_handler(status, bookmark).await }
+    })?;
+
+    Ok(use_context_provider(|| UseFactFoldAdminReviews {
+        status_filter,
+        reviews,
+    }))
+}
@@ -0,0 +1,57 @@
+//! `UseFactFoldRoundReplay` — data + scrubber state for replaying a
+//! settled round in the game-room shell.
+//!
+//! The whole timeline is fetched once (`GET .../replay`); playback is
+//! purely client-side. `position_ms` is the scrubber head, measured
+//! from `started_at`, and [`UseFactFoldRoundReplay::events_until`]
+//! yields the prefix of events a view should render at that point.
+
+use crate::features::arcade::games::fact_or_fold::{
+    get_round_replay_handler, ReplayEvent, RoundReplayResponse,
+};
+use crate::*;
+
+#[derive(Clone, Copy, DioxusController)]
+pub struct UseFactFoldRoundReplay {
+    pub round_id: Signal<FactFoldRoundEntityType>,
+    pub position_ms: Signal<i64>,
+}
+
+impl UseFactFoldRoundReplay {
+    pub fn replay(&self) -> std::result::Result<Loader<RoundReplayResponse>, Loading> {
+        let round_id = self.round_id;
+        use_loader(move || async move { get_round_replay_handler(round_id()).await })
+    }
+
+    /// Events whose offset is at or before `position_ms`. Timeline is
+    /// already sorted server-side, so this is a prefix scan.
+    pub fn events_until(&self, replay: &RoundReplayResponse) -> Vec<ReplayEvent> {
+        let position = (self.position_ms)();
+        replay
+            .events
+            .iter()
+            .take_while(|e| e.offset_ms <= position)
+            .cloned()
+            .collect()
+    }
+
+    pub fn seek(&mut self, offset_ms: i64) {
+        self.position_ms.set(offset_ms.max(0));
+    }
+}
+
+pub fn use_fact_fold_round_replay_provider(
+    round_id: FactFoldRoundEntityType,
+) -> std::result::Result<UseFactFoldRoundReplay, RenderError> {
+    if let Some(ctx) = try_use_context::<UseFactFoldRoundReplay>() {
+        return Ok(ctx);
+    }
+
+    let round_id = use_signal(|| round_id);
+    let position_ms = use_signal(|| 0i64);
+
+    Ok(use_context_provider(|| UseFactFoldRoundReplay {
+        round_id,
+        position_ms,
+    }))
+}
@@ -0,0 +1,114 @@
+use crate::common::*;
+use crate::features::arcade::games::fact_or_fold::types::{
+    CollusionReviewResponse, CollusionReviewStatus, CollusionSignal,
+};
+
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Review-queue row for a round whose settlement is held because the
+/// collusion detector fired. Lives at the `Partition::FactFoldReviews`
+/// anchor; the admin queue pages each status through the GSI3
+/// `find_by_status` index, ordered by `created_at`.
+///
+/// The row's existence is also the settlement gate: `Pending` holds
+/// the payout, `Approved` lets the normal formula run without
+/// re-checking, `Voided` means the round was refunded instead.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(
+    feature = "server",
+    derive(DynamoEntity, rmcp::schemars::JsonSchema)
+)]
+pub struct FactFoldCollusionReview {
+    pub pk: Partition,  // Partition::FactFoldReviews
+    pub sk: EntityType, // EntityType::FactFoldCollusionReview(round_id)
+
+    #[cfg_attr(feature = "server", dynamo(prefix = "FLAGGED", index = "gsi3", sk))]
+    pub created_at: i64,
+    pub updated_at: i64,
+
+    pub subject_id: String,
+    #[cfg_attr(
+        feature = "server",
+        dynamo(prefix = "FFCR", name = "find_by_status", index = "gsi3", pk)
+    )]
+    pub status: CollusionReviewStatus,
+    #[serde(default)]
+    pub participant_pks: Vec<Partition>,
+    #[serde(default)]
+    pub signals: Vec<CollusionSignal>,
+    pub total_stake: i64,
+
+    pub resolved_at: Option<i64>,
+    pub resolved_by: Option<Partition>,
+    #[serde(default)]
+    pub note: String,
+}
+
+#[cfg(feature = "server")]
+impl FactFoldCollusionReview {
+    pub fn anchor_pk() -> Partition {
+        Partition::FactFoldReviews
+    }
+
+    pub fn keys(round_id: &str) -> (Partition, EntityType) {
+        (
+            Partition::FactFoldReviews,
+            EntityType::FactFoldCollusionReview(round_id.to_string()),
+        )
+    }
+
+    pub fn new_pending(
+        round_id: &str,
+        subject_id: String,
+        participant_pks: Vec<Partition>,
+        signals: Vec<CollusionSignal>,
+        total_stake: i64,
+    ) -> Self {
+        let now = crate::common::utils::time::get_now_timestamp_millis();
+        let (pk, sk) = Self::keys(round_id);
+        Self {
+            pk,
+            sk,
+            created_at: now,
+            updated_at: now,
+            subject_id,
+            status: CollusionReviewStatus::Pending,
+            participant_pks,
+            signals,
+            total_stake,
+            resolved_at: None,
+            resolved_by: None,
+            note: String::new(),
+        }
+    }
+
+    pub fn round_id(&self) -> String {
+        match &self.sk {
+            EntityType::FactFoldCollusionReview(id) => id.clone(),
+            _ => String::new(),
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl From<FactFoldCollusionReview> for CollusionReviewResponse {
+    fn from(row: FactFoldCollusionReview) -> Self {
+        Self {
+            round_id: FactFoldRoundEntityType(row.round_id()),
+            subject_id: FactFoldSubjectEntityType(row.subject_id),
+            status: row.status,
+            participant_pks: row
+                .participant_pks
+                .into_iter()
+                .map(UserPartition::from)
+                .collect(),
+            signals: row.signals,
+            total_stake: row.total_stake,
+            flagged_at: row.created_at,
+            resolved_at: row.resolved_at,
+            resolved_by: row.resolved_by.map(UserPartition::from),
+            note: row.note,
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::common::*;
-use crate::features::arcade::games::fact_or_fold::types::RoundStatus;
+use crate::features::arcade::games::fact_or_fold::types::{RoundStageMark, RoundStatus};
 
 #[allow(unused_imports)]
 use rmcp::schemars;
@@ -49,6 +49,18 @@ pub struct FactFoldRound {
     /// `FactFoldSettings`.
     #[serde(default)]
     pub stage_deadline_at: Option<i64>,
+    /// Every stage the round has entered, in order, with the moment
+    /// it was entered. Appended by the stage machine and by
+    /// settlement; read by the replay endpoint. Rounds created
+    /// before this field existed deserialize to an empty list and
+    /// replay falls back to re-deriving the boundaries from settings.
+    #[serde(default)]
+    pub stage_history: Vec<RoundStageMark>,
+    /// Set by settlement when the collusion detector flags the round.
+    /// Payout waits for an admin decision on the matching
+    /// `FactFoldCollusionReview` row; cleared when it is resolved.
+    #[serde(default)]
+    pub held_for_review: bool,
 }
 
 #[cfg(feature = "server")]
@@ -75,6 +87,8 @@ impl FactFoldRound {
             settled_at: None,
             stage_started_at: None,
             stage_deadline_at: None,
+            stage_history: Vec::new(),
+            held_for_review: false,
         }
     }
 
@@ -84,4 +98,17 @@ impl FactFoldRound {
             _ => None,
         }
     }
+
+    /// Record that the round entered `status` at `at`. No-op when the
+    /// last mark already carries the same status, so a retried
+    /// settlement doesn't stack duplicate markers.
+    pub fn push_stage_mark(&mut self, status: RoundStatus, at: i64) {
+        if self.stage_history.last().map(|m| m.status) == Some(status) {
+            return;
+        }
+        self.stage_history.push(RoundStageMark {
+            status,
+            started_at: at,
+        });
+    }
 }
@@ -46,6 +46,12 @@ pub struct FactFoldUserStats {
     /// player cannot start a second round before their first finishes.
     #[serde(default)]
     pub current_round_id: Option<String>,
+
+    /// Accuracy (bps) an admin accepted when approving a review the
+    /// win-rate rule raised. The rule stays quiet for this user until
+    /// their accuracy climbs past it. `0` = never cleared.
+    #[serde(default)]
+    pub win_rate_cleared_bps: i64,
 }
 
 #[cfg(feature = "server")]
@@ -74,6 +80,7 @@ impl FactFoldUserStats {
             lifetime_delta_chips: 0,
             last_played_at: 0,
             current_round_id: None,
+            win_rate_cleared_bps: 0,
         }))
     }
 }
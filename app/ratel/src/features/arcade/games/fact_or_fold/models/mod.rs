@@ -1,5 +1,6 @@
 pub mod fact_fold_bet;
 pub mod fact_fold_chat;
+pub mod fact_fold_collusion_review;
 pub mod fact_fold_subject;
 pub mod fact_fold_subject_play;
 pub mod fact_fold_leaderboard_entry;
@@ -13,6 +14,7 @@ pub mod fact_fold_user_stats;
 
 pub use fact_fold_bet::*;
 pub use fact_fold_chat::*;
+pub use fact_fold_collusion_review::*;
 pub use fact_fold_subject::*;
 pub use fact_fold_subject_play::*;
 pub use fact_fold_leaderboard_entry::*;
@@ -10,6 +10,7 @@ translate! {
     tab_schedule: { en: "Schedule", ko: "스케줄" },
     tab_stats: { en: "Stats", ko: "통계" },
     tab_reports: { en: "Reports", ko: "신고" },
+    tab_reviews: { en: "Reviews", ko: "검토" },
     tab_settings: { en: "Settings", ko: "설정" },
 
     new_subject_cta: { en: "New subject", ko: "신규 대상" },
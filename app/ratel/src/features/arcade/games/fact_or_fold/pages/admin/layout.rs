@@ -3,9 +3,9 @@ use crate::features::arcade::games::fact_or_fold::pages::admin::FactFoldAdminLay
 use crate::route::Route;
 
 /// Sub-layout for `/admin/fact-or-fold/*` pages. Adds the
-/// arcade-themed brand row + tab navigation between the 5 admin
-/// surfaces (Subjects, Schedule, Stats, Reports, Settings) so each
-/// page module can render only its own content.
+/// arcade-themed brand row + tab navigation between the 6 admin
+/// surfaces (Subjects, Schedule, Stats, Reports, Reviews, Settings)
+/// so each page module can render only its own content.
 #[component]
 pub fn FactFoldAdminLayout() -> Element {
     let tr: FactFoldAdminLayoutTranslate = use_translate();
@@ -18,6 +18,7 @@ pub fn FactFoldAdminLayout() -> Element {
     let r_schedule = Route::FactFoldAdminSchedulePage {};
     let r_stats = Route::FactFoldAdminStatsPage {};
     let r_reports = Route::FactFoldAdminReportsPage {};
+    let r_reviews = Route::FactFoldAdminReviewsPage {};
     let r_settings = Route::FactFoldAdminSettingsPage {};
 
     rsx! {
@@ -52,6 +53,13 @@ pub fn FactFoldAdminLayout() -> Element {
                         to: r_reports.clone(),
                         "{tr.tab_reports}"
                     }
+                    Link {
+                        class: "ff-admin-arena__tab",
+                        "data-testid": "ff-admin-tab-reviews",
+                        "aria-selected": route == r_reviews,
+                        to: r_reviews.clone(),
+                        "{tr.tab_reviews}"
+                    }
                     Link {
                         class: "ff-admin-arena__tab",
                         "data-testid": "ff-admin-tab-settings",
@@ -7,6 +7,7 @@ pub mod i18n;
 pub mod layout;
 pub mod new_subject;
 pub mod reports;
+pub mod reviews;
 pub mod schedule;
 pub mod settings;
 pub mod stats;
@@ -16,6 +17,7 @@ pub use i18n::*;
 pub use layout::*;
 pub use new_subject::*;
 pub use reports::*;
+pub use reviews::*;
 pub use schedule::*;
 pub use settings::*;
 pub use stats::*;
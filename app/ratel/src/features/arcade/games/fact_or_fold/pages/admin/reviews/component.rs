@@ -0,0 +1,215 @@
+use crate::features::arcade::games::fact_or_fold::hooks::use_fact_fold_admin_reviews_provider;
+use crate::features::arcade::games::fact_or_fold::{
+    CollusionReviewResponse, CollusionReviewStatus, CollusionSignal, CollusionSignalKind,
+};
+use crate::*;
+
+use super::i18n::FactFoldAdminReviewsTranslate;
+
+/// `/admin/fact-or-fold/reviews` — rounds whose settlement the
+/// collusion detector held. Each pending row shows the signals that
+/// fired and lets the operator approve (normal payout) or void
+/// (buy-in refund) with a note for the audit trail.
+#[component]
+pub fn FactFoldAdminReviewsPage() -> Element {
+    let mut ctx = use_fact_fold_admin_reviews_provider()?;
+    let status_filter = ctx.status_filter;
+    let mut reviews = ctx.reviews;
+    let rows = reviews.items();
+    let tr: FactFoldAdminReviewsTranslate = use_translate();
+    let current = status_filter();
+
+    rsx! {
+        SeoMeta { title: "{tr.page_title} · Fact or Fold" }
+        section { class: "ff-reviews",
+            div { class: "ff-reviews__notice",
+                strong { "{tr.notice_title}" }
+                p { "{tr.notice_body}" }
+            }
+
+            div { class: "ff-reviews__tabs",
+                ReviewTab {
+                    label: "{tr.tab_pending}",
+                    active: current == Some(CollusionReviewStatus::Pending),
+                    onclick: move |_| ctx.set_status(CollusionReviewStatus::Pending),
+                }
+                ReviewTab {
+                    label: "{tr.tab_approved}",
+                    active: current == Some(CollusionReviewStatus::Approved),
+                    onclick: move |_| ctx.set_status(CollusionReviewStatus::Approved),
+                }
+                ReviewTab {
+                    label: "{tr.tab_voided}",
+                    active: current == Some(CollusionReviewStatus::Voided),
+                    onclick: move |_| ctx.set_status(CollusionReviewStatus::Voided),
+                }
+            }
+
+            div { class: "ff-reviews__panel",
+                if rows.is_empty() {
+                    div { class: "ff-reviews__empty", "{tr.empty}" }
+                } else {
+                    for row in rows {
+                        ReviewCard { key: "{row.round_id.0}", row }
+                    }
+                    {reviews.more_element()}
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ReviewTab(label: String, active: bool, onclick: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        button {
+            r#type: "button",
+            class: "ff-reviews__tab",
+            "aria-selected": active,
+            onclick: move |e| onclick.call(e),
+            "{label}"
+        }
+    }
+}
+
+#[component]
+fn ReviewCard(row: CollusionReviewResponse) -> Element {
+    let tr: FactFoldAdminReviewsTranslate = use_translate();
+    let mut ctx = use_fact_fold_admin_reviews_provider()?;
+
+    let mut note = use_signal(|| row.note.clone());
+    let mut error_msg = use_signal(|| Option::<String>::None);
+    let mut busy = use_signal(|| false);
+
+    let id_for_approve = row.round_id.clone();
+    let id_for_void = row.round_id.clone();
+
+    let on_approve = move |_| {
+        let id = id_for_approve.clone();
+        async move {
+            busy.set(true);
+            if let Err(e) = ctx.approve(id, note()).await {
+                error_msg.set(Some(format!("{e}")));
+            }
+            busy.set(false);
+        }
+    };
+
+    let on_void = move |_| {
+        let id = id_for_void.clone();
+        async move {
+            busy.set(true);
+            if let Err(e) = ctx.void(id, note()).await {
+                error_msg.set(Some(format!("{e}")));
+            }
+            busy.set(false);
+        }
+    };
+
+    let pending = row.status == CollusionReviewStatus::Pending;
+    let players = row
+        .participant_pks
+        .iter()
+        .map(|p| short_id(&p.0))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let card_testid = format!("ff-admin-review-{}", row.round_id.0);
+    let approve_testid = format!("ff-admin-review-approve-{}", row.round_id.0);
+    let void_testid = format!("ff-admin-review-void-{}", row.round_id.0);
+
+    rsx! {
+        article { class: "ff-reviews__card", "data-testid": "{card_testid}",
+            dl { class: "ff-reviews__meta",
+                dt { "{tr.label_round}" }
+                dd { class: "ff-reviews__mono", "{short_id(&row.round_id.0)}" }
+                dt { "{tr.label_players}" }
+                dd { class: "ff-reviews__mono", "{players}" }
+                dt { "{tr.label_stake}" }
+                dd { "{row.total_stake}" }
+            }
+
+            ul { class: "ff-reviews__signals",
+                for (idx, signal) in row.signals.iter().enumerate() {
+                    SignalPill { key: "{idx}", signal: signal.clone() }
+                }
+            }
+
+            if pending {
+                label { class: "ff-reviews__note",
+                    span { "{tr.label_note}" }
+                    textarea {
+                        placeholder: "{tr.note_placeholder}",
+                        value: "{note}",
+                        disabled: busy(),
+                        oninput: move |e| note.set(e.value()),
+                    }
+                }
+                div { class: "ff-reviews__actions",
+                    button {
+                        r#type: "button",
+                        class: "ff-reviews__btn",
+                        "data-testid": "{approve_testid}",
+                        disabled: busy(),
+                        onclick: on_approve,
+                        "{tr.action_approve}"
+                    }
+                    button {
+                        r#type: "button",
+                        class: "ff-reviews__btn ff-reviews__btn--danger",
+                        "data-testid": "{void_testid}",
+                        disabled: busy(),
+                        onclick: on_void,
+                        "{tr.action_void}"
+                    }
+                }
+            } else if !row.note.is_empty() {
+                p { class: "ff-reviews__resolved-note", "{row.note}" }
+            }
+
+            if let Some(err) = error_msg() {
+                div { class: "ff-reviews__error", "{err}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn SignalPill(signal: CollusionSignal) -> Element {
+    let tr: FactFoldAdminReviewsTranslate = use_translate();
+    let (variant, label, detail) = match signal.kind {
+        CollusionSignalKind::PairCoOccurrence => (
+            "pair",
+            tr.signal_pair.to_string(),
+            format!("{} / {}", signal.value, signal.threshold),
+        ),
+        CollusionSignalKind::CorrelatedFlips => (
+            "flips",
+            tr.signal_flips.to_string(),
+            format!("{} / {}", signal.value, signal.threshold),
+        ),
+        CollusionSignalKind::AbnormalWinRate => (
+            "win-rate",
+            tr.signal_win_rate.to_string(),
+            format!("{}% / {}%", signal.value / 100, signal.threshold / 100),
+        ),
+    };
+    let users = signal
+        .user_pks
+        .iter()
+        .map(|p| short_id(&p.0))
+        .collect::<Vec<_>>()
+        .join(" · ");
+
+    rsx! {
+        li { class: "ff-reviews__signal", "data-variant": "{variant}",
+            strong { "{label}" }
+            span { "{detail}" }
+            span { class: "ff-reviews__mono", "{users}" }
+        }
+    }
+}
+
+/// First 8 chars of an id — display only; actions carry the full id.
+fn short_id(id: &str) -> String {
+    id.chars().take(8).collect()
+}
@@ -0,0 +1,29 @@
+use dioxus_translate::*;
+
+translate! {
+    FactFoldAdminReviewsTranslate;
+
+    page_title: { en: "Reviews", ko: "검토" },
+    notice_title: { en: "Settlement held", ko: "정산 보류" },
+    notice_body: {
+        en: "These rounds tripped the collusion detector. Payouts stay frozen until you approve (normal settlement) or void (every player gets their buy-in back).",
+        ko: "담합 탐지에 걸린 라운드입니다. 승인(정상 정산) 또는 무효(전원 참가비 환불) 처리 전까지 지급이 보류됩니다.",
+    },
+    tab_pending: { en: "Pending", ko: "검토 대기" },
+    tab_approved: { en: "Approved", ko: "승인됨" },
+    tab_voided: { en: "Voided", ko: "무효 처리" },
+    empty: { en: "No rounds to review.", ko: "검토할 라운드가 없습니다." },
+
+    label_round: { en: "Round", ko: "라운드" },
+    label_players: { en: "Players", ko: "참가자" },
+    label_stake: { en: "Total stake", ko: "총 베팅" },
+    label_note: { en: "Note", ko: "메모" },
+    note_placeholder: { en: "Why are you approving or voiding?", ko: "승인 또는 무효 사유" },
+
+    signal_pair: { en: "Frequent pair", ko: "반복 동반 참가" },
+    signal_flips: { en: "Correlated flips", ko: "연계된 플립" },
+    signal_win_rate: { en: "Abnormal win rate", ko: "비정상 승률" },
+
+    action_approve: { en: "Approve & settle", ko: "승인 후 정산" },
+    action_void: { en: "Void & refund", ko: "무효 후 환불" },
+}
@@ -0,0 +1,5 @@
+mod component;
+mod i18n;
+
+pub use component::*;
+pub use i18n::*;
@@ -267,7 +267,11 @@ fn render_stage(round: &RoundResponse) -> Element {
         },
         RoundStatus::Settlement => rsx! {
             section { class: "view", "data-active": true,
-                div { class: "ff-room__placeholder", "{tr.settling}" }
+                if round.held_for_review {
+                    div { class: "ff-room__placeholder", "{tr.settlement_held}" }
+                } else {
+                    div { class: "ff-room__placeholder", "{tr.settling}" }
+                }
             }
         },
         RoundStatus::Settled => rsx! {
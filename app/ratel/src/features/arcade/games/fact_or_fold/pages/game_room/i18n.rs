@@ -38,6 +38,10 @@ translate! {
         en: "Settling the round — results land in a moment.",
         ko: "라운드 정산 중 — 잠시 후 결과가 표시됩니다.",
     },
+    settlement_held: {
+        en: "This round is under review — payouts are released once an admin signs off.",
+        ko: "검토 중인 라운드입니다 — 관리자 확인 후 정산됩니다.",
+    },
 
     // ── News reveal stage ─────────────────────────────────────────
     news_source_lock: {
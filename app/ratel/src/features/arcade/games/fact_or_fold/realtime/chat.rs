@@ -12,19 +12,20 @@
 //!
 //! ### Fan-out
 //!
-//! v1 doesn't publish from inside this handler. The
-//! `POST /rounds/{id}/chat` controller only writes a
-//! `FactFoldChatMessage` row; the DDB Stream listener
-//! ([`crate::common::stream_handler`] for local-dev,
-//! `EventBridgeEnvelope::proc` for Lambda) detects the new row and
-//! calls `global_hub().publish(...)` so every SSE invocation in
-//! the cluster fans it out to its own subscribers.
+//! The `POST /rounds/{id}/chat` controller writes the
+//! `FactFoldChatMessage` row and then calls [`publish_chat_message`]
+//! once. The hub's backplane assigns the event id and carries it to
+//! other instances (`realtime::backplane`), so the message is
+//! appended to the channel log exactly once no matter how many
+//! instances hold subscribers.
 
 use crate::common::*;
-use crate::features::arcade::ArcadeError;
+use crate::common::realtime::RealtimeError;
 use crate::features::arcade::games::fact_or_fold::models::{FactFoldChatMessage, FactFoldRound};
 use crate::features::arcade::games::fact_or_fold::types::ChatMessagePayload;
-use crate::features::arcade::realtime::channel::{ChannelContext, ChannelId, RoomChannel};
+use crate::features::arcade::games::fact_or_fold::realtime::spectator::spectator_channel_id;
+use crate::common::realtime::channel::{ChannelContext, ChannelId, RoomChannel};
+use crate::common::realtime::hub::global_hub;
 use async_trait::async_trait;
 
 /// How many recent chat messages to surface on the subscribe
@@ -43,6 +44,30 @@ pub fn chat_payload_from(row: FactFoldChatMessage) -> ChatMessagePayload {
     }
 }
 
+/// Publish a freshly written chat row to the participant channel
+/// and, for read-only spectators (PR8), the spectator channel — the
+/// participant channel's authorize gate stays participant-only.
+pub async fn publish_chat_message(
+    round_id: &str,
+    row: FactFoldChatMessage,
+) -> crate::common::Result<()> {
+    let payload = serde_json::to_value(chat_payload_from(row)).map_err(|e| {
+        crate::error!("FactFoldChat fan-out serialize failed: {e}");
+        RealtimeError::ChannelPayloadInvalid
+    })?;
+
+    let hub = global_hub();
+    hub.publish(
+        &ChannelId::from_parts("fof.chat", round_id),
+        "chat_message",
+        payload.clone(),
+    )
+    .await?;
+    hub.publish(&spectator_channel_id(round_id), "chat_message", payload)
+        .await?;
+    Ok(())
+}
+
 pub struct FactFoldChatChannel {
     cli: aws_sdk_dynamodb::Client,
 }
@@ -67,7 +92,7 @@ impl RoomChannel for FactFoldChatChannel {
     ) -> crate::common::Result<serde_json::Value> {
         let round_id = channel.inner().to_string();
         if round_id.is_empty() {
-            return Err(ArcadeError::ChannelPayloadInvalid.into());
+            return Err(RealtimeError::ChannelPayloadInvalid.into());
         }
 
         // Participant gate: the caller's USER#{user_id} pk must be
@@ -79,13 +104,13 @@ impl RoomChannel for FactFoldChatChannel {
             .await
             .map_err(|e| {
                 crate::error!("fof.chat authorize round read failed: {e}");
-                ArcadeError::StorageFailure
+                RealtimeError::StorageFailure
             })?
-            .ok_or(ArcadeError::ChannelForbidden)?;
+            .ok_or(RealtimeError::ChannelForbidden)?;
 
         let user_pk = Partition::User(ctx.user_id.clone());
         if !round.participant_pks.iter().any(|p| p == &user_pk) {
-            return Err(ArcadeError::ChannelForbidden.into());
+            return Err(RealtimeError::ChannelForbidden.into());
         }
 
         // Backlog: load the latest CHAT_HISTORY_LIMIT messages for
@@ -99,7 +124,7 @@ impl RoomChannel for FactFoldChatChannel {
             .await
             .map_err(|e| {
                 crate::error!("fof.chat history query failed: {e}");
-                ArcadeError::StorageFailure
+                RealtimeError::StorageFailure
             })?;
 
         let payloads: Vec<ChatMessagePayload> = rows.into_iter().map(chat_payload_from).collect();
@@ -6,8 +6,12 @@
 pub mod chat;
 #[cfg(feature = "server")]
 pub mod register;
+#[cfg(feature = "server")]
+pub mod spectator;
 
 #[cfg(feature = "server")]
 pub use chat::*;
 #[cfg(feature = "server")]
 pub use register::*;
+#[cfg(feature = "server")]
+pub use spectator::*;
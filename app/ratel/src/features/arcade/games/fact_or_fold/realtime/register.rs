@@ -1,13 +1,15 @@
-//! Register FOF's `RoomChannel` impls with the global arcade hub at
+//! Register FOF's `RoomChannel` impls with the global realtime hub at
 //! process start. Idempotent — last registration wins so tests that
 //! swap in a mock channel still work.
 
 use crate::features::arcade::games::fact_or_fold::realtime::chat::FactFoldChatChannel;
-use crate::features::arcade::realtime::hub::global_hub;
+use crate::features::arcade::games::fact_or_fold::realtime::spectator::FactFoldSpectatorChannel;
+use crate::common::realtime::hub::global_hub;
 
 pub async fn register_channels() {
     let cfg = crate::common::CommonConfig::default();
     let cli = cfg.dynamodb().clone();
     let hub = global_hub();
-    hub.register(FactFoldChatChannel::new(cli)).await;
+    hub.register(FactFoldChatChannel::new(cli.clone())).await;
+    hub.register(FactFoldSpectatorChannel::new(cli)).await;
 }
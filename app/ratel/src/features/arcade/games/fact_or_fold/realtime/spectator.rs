@@ -0,0 +1,74 @@
+//! `RoomChannel` implementation for read-only spectators (PR8).
+//!
+//! Channel kind: `"fof.spectate"`. ChannelId form:
+//! `fof.spectate:{round_id}`.
+//!
+//! ### Authorize hook
+//!
+//! Any signed-in user may subscribe while the round is in progress
+//! (past `Waiting`, not yet `Settled`). The initial state is the same
+//! [`SpectatorSnapshotResponse`] the polling fallback returns, so
+//! stage-gated redaction (bets / rationale text hidden until
+//! `Reveal`, insider never marked) lives in one place.
+//!
+//! ### Fan-out
+//!
+//! Spectators receive:
+//! - `chat_message` — published next to the participant `fof.chat`
+//!   event by [`super::chat::publish_chat_message`].
+//! - `stage_changed` — published by
+//!   [`crate::features::arcade::games::fact_or_fold::services::stage_machine::advance_round_if_due`]
+//!   with the new `RoundResponse`. Clients refetch the snapshot on
+//!   this event to pick up anything the new stage unlocks.
+//!
+//! Nothing participant-private is ever published on this channel,
+//! so the default `before_publish` pass-through is enough.
+
+use crate::common::*;
+use crate::common::realtime::RealtimeError;
+use crate::features::arcade::games::fact_or_fold::controllers::replay::load_spectator_snapshot;
+use crate::features::arcade::games::fact_or_fold::types::SpectatorSnapshotResponse;
+use crate::common::realtime::channel::{ChannelContext, ChannelId, RoomChannel};
+use async_trait::async_trait;
+
+pub const SPECTATOR_CHANNEL_KIND: &str = "fof.spectate";
+
+pub fn spectator_channel_id(round_id: &str) -> ChannelId {
+    ChannelId::from_parts(SPECTATOR_CHANNEL_KIND, round_id)
+}
+
+pub struct FactFoldSpectatorChannel {
+    cli: aws_sdk_dynamodb::Client,
+}
+
+impl FactFoldSpectatorChannel {
+    pub fn new(cli: aws_sdk_dynamodb::Client) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl RoomChannel for FactFoldSpectatorChannel {
+    fn kind(&self) -> &'static str {
+        SPECTATOR_CHANNEL_KIND
+    }
+
+    async fn authorize(
+        &self,
+        _ctx: &ChannelContext,
+        channel: &ChannelId,
+        _params: serde_json::Value,
+    ) -> crate::common::Result<serde_json::Value> {
+        let round_id = channel.inner();
+        if round_id.is_empty() {
+            return Err(RealtimeError::ChannelPayloadInvalid.into());
+        }
+
+        let snapshot: SpectatorSnapshotResponse =
+            load_spectator_snapshot(&self.cli, round_id).await?;
+        serde_json::to_value(snapshot).map_err(|e| {
+            crate::error!("fof.spectate snapshot serialize failed: {e}");
+            RealtimeError::ChannelPayloadInvalid.into()
+        })
+    }
+}
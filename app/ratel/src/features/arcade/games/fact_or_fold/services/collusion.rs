@@ -0,0 +1,415 @@
+//! Anti-collusion detector. Runs inside `settle_round_internal`
+//! before any chips move; a non-empty result holds the payout and
+//! opens a `FactFoldCollusionReview` for an admin.
+//!
+//! Three rules, each cheap enough to run on every settlement:
+//!
+//! 1. **Pair co-occurrence** — two players share at least
+//!    [`PAIR_SHARED_ROUNDS_MIN`] past rounds *and* those make up at
+//!    least [`PAIR_SHARED_RATIO_BPS`] of the lighter player's
+//!    history. Random 4-seat matchmaking rarely repeats a pair that
+//!    often; friends queueing together do.
+//! 2. **Correlated flips** — across this round and the shared past
+//!    rounds, one player of the pair flipped onto the other's side
+//!    (or cited them) at least [`ALIGNED_FLIPS_MIN`] times. The
+//!    insider telling a friend which way to go looks exactly like
+//!    this.
+//! 3. **Abnormal win rate** — lifetime accuracy at or above
+//!    [`WIN_RATE_BPS_MIN`] over at least [`WIN_RATE_MIN_ROUNDS`]
+//!    rounds. Approving such a review clears the player up to the
+//!    accuracy the admin saw, so an honest strong player is not
+//!    re-flagged every round.
+//!
+//! [`detect_collusion`] is pure (unit tests below);
+//! [`detect_round_collusion`] loads the history it needs.
+
+use crate::features::arcade::games::fact_or_fold::models::FactFoldBet;
+use crate::features::arcade::games::fact_or_fold::types::{
+    CollusionSignal, CollusionSignalKind,
+};
+use crate::common::types::{Partition, UserPartition};
+use std::collections::{HashMap, HashSet};
+
+/// Past rounds a pair must share before co-occurrence counts.
+pub const PAIR_SHARED_ROUNDS_MIN: i64 = 5;
+/// Shared rounds as a share of the lighter player's history.
+pub const PAIR_SHARED_RATIO_BPS: i64 = 5_000;
+/// Flips onto / citing the same partner before it counts.
+pub const ALIGNED_FLIPS_MIN: i64 = 3;
+/// Rounds played before the win-rate rule applies.
+pub const WIN_RATE_MIN_ROUNDS: i64 = 20;
+/// Lifetime accuracy that trips the win-rate rule.
+pub const WIN_RATE_BPS_MIN: i64 = 8_500;
+/// Settled rounds per player read from their play history.
+pub const HISTORY_LIMIT: i32 = 100;
+/// Most recent shared rounds whose bets are loaded for the flip rule.
+pub const FLIP_LOOKBACK_ROUNDS: usize = 20;
+
+/// One participant's history as the detector sees it.
+#[derive(Debug, Clone, Default)]
+pub struct PlayerHistory {
+    pub user_id: String,
+    /// Settled rounds the player took part in, excluding the one
+    /// being settled.
+    pub round_ids: HashSet<String>,
+    pub total_rounds: i64,
+    pub correct_count: i64,
+    /// `FactFoldUserStats::win_rate_cleared_bps`.
+    pub win_rate_cleared_bps: i64,
+}
+
+pub struct CollusionInput<'a> {
+    pub players: &'a [PlayerHistory],
+    /// Bets of the round being settled plus any loaded past bets for
+    /// the same players. Grouped by round via the bet pk.
+    pub bets: &'a [FactFoldBet],
+}
+
+fn user_id_of(pk: &Partition) -> String {
+    match pk {
+        Partition::User(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn round_id_of(pk: &Partition) -> String {
+    match pk {
+        Partition::FactFold(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// True when `flipper` flipped in this round onto `partner`'s final
+/// side or cited `partner`.
+fn flipped_toward(flipper: &FactFoldBet, partner: &FactFoldBet) -> bool {
+    let Some(to) = flipper.flipped_to else {
+        return false;
+    };
+    let cited = flipper
+        .flip_cite_user_pk
+        .as_ref()
+        .map(|c| *c == partner.user_pk)
+        .unwrap_or(false);
+    let partner_side = partner.flipped_to.unwrap_or(partner.side);
+    cited || to == partner_side
+}
+
+/// Run the three rules. Signals come out in a stable order (pairs in
+/// input order, then win-rate) so the review row is deterministic.
+pub fn detect_collusion(input: CollusionInput<'_>) -> Vec<CollusionSignal> {
+    let mut signals = Vec::new();
+
+    // round_id → user_id → bet
+    let mut bets_by_round: HashMap<String, HashMap<String, &FactFoldBet>> = HashMap::new();
+    for b in input.bets {
+        bets_by_round
+            .entry(round_id_of(&b.pk))
+            .or_default()
+            .insert(user_id_of(&b.user_pk), b);
+    }
+
+    for (i, a) in input.players.iter().enumerate() {
+        for b in input.players.iter().skip(i + 1) {
+            let pair = vec![
+                UserPartition(a.user_id.clone()),
+                UserPartition(b.user_id.clone()),
+            ];
+
+            let shared = a.round_ids.intersection(&b.round_ids).count() as i64;
+            let lighter = a.round_ids.len().min(b.round_ids.len()) as i64;
+            if shared >= PAIR_SHARED_ROUNDS_MIN
+                && lighter > 0
+                && shared * 10_000 / lighter >= PAIR_SHARED_RATIO_BPS
+            {
+                signals.push(CollusionSignal {
+                    kind: CollusionSignalKind::PairCoOccurrence,
+                    user_pks: pair.clone(),
+                    value: shared,
+                    threshold: PAIR_SHARED_ROUNDS_MIN,
+                });
+            }
+
+            let aligned = bets_by_round
+                .values()
+                .filter(|round| {
+                    match (round.get(&a.user_id), round.get(&b.user_id)) {
+                        (Some(ab), Some(bb)) => flipped_toward(ab, bb) || flipped_toward(bb, ab),
+                        _ => false,
+                    }
+                })
+                .count() as i64;
+            if aligned >= ALIGNED_FLIPS_MIN {
+                signals.push(CollusionSignal {
+                    kind: CollusionSignalKind::CorrelatedFlips,
+                    user_pks: pair,
+                    value: aligned,
+                    threshold: ALIGNED_FLIPS_MIN,
+                });
+            }
+        }
+    }
+
+    for p in input.players {
+        if p.total_rounds < WIN_RATE_MIN_ROUNDS {
+            continue;
+        }
+        let accuracy_bps = p.correct_count * 10_000 / p.total_rounds;
+        if accuracy_bps >= WIN_RATE_BPS_MIN && accuracy_bps > p.win_rate_cleared_bps {
+            signals.push(CollusionSignal {
+                kind: CollusionSignalKind::AbnormalWinRate,
+                user_pks: vec![UserPartition(p.user_id.clone())],
+                value: accuracy_bps,
+                threshold: WIN_RATE_BPS_MIN,
+            });
+        }
+    }
+
+    signals
+}
+
+/// Load every participant's history and run [`detect_collusion`]
+/// for `round_id`. `bets` are the round's own bets (already loaded
+/// by settlement).
+#[cfg(feature = "server")]
+pub async fn detect_round_collusion(
+    cli: &aws_sdk_dynamodb::Client,
+    round_id: &str,
+    participant_pks: &[Partition],
+    bets: &[FactFoldBet],
+) -> crate::common::Result<Vec<CollusionSignal>> {
+    use crate::features::arcade::games::fact_or_fold::models::{
+        FactFoldSubjectPlay, FactFoldUserStats,
+    };
+    use crate::features::arcade::games::fact_or_fold::types::FactOrFoldError;
+
+    let mut players = Vec::with_capacity(participant_pks.len());
+    for pk in participant_pks {
+        let user_id = user_id_of(pk);
+        let opts = FactFoldSubjectPlay::opt()
+            .sk("FACT_FOLD_SUBJECT_PLAY".to_string())
+            .limit(HISTORY_LIMIT);
+        let (plays, _) = FactFoldSubjectPlay::query(cli, pk.clone(), opts)
+            .await
+            .map_err(|e| {
+                crate::error!("detect_round_collusion plays query failed for {user_id}: {e}");
+                FactOrFoldError::StorageFailure
+            })?;
+        let stats = FactFoldUserStats::get_or_default(cli, &user_id).await?;
+        players.push(PlayerHistory {
+            round_ids: plays
+                .into_iter()
+                .map(|p| p.round_id)
+                .filter(|r| r != round_id)
+                .collect(),
+            user_id,
+            total_rounds: stats.total_rounds,
+            correct_count: stats.correct_count,
+            win_rate_cleared_bps: stats.win_rate_cleared_bps,
+        });
+    }
+
+    // Bets from the most recent rounds any pair shared. Round ids are
+    // uuid_v7, so a descending string sort is newest-first.
+    let mut shared: HashSet<&String> = HashSet::new();
+    for (i, a) in players.iter().enumerate() {
+        for b in players.iter().skip(i + 1) {
+            shared.extend(a.round_ids.intersection(&b.round_ids));
+        }
+    }
+    let mut shared: Vec<&String> = shared.into_iter().collect();
+    shared.sort_by(|a, b| b.cmp(a));
+    shared.truncate(FLIP_LOOKBACK_ROUNDS);
+
+    let keys: Vec<(Partition, crate::common::types::EntityType)> = shared
+        .iter()
+        .flat_map(|r| {
+            players
+                .iter()
+                .filter(|p| p.round_ids.contains(*r))
+                .map(|p| FactFoldBet::keys(r, &p.user_id))
+        })
+        .collect();
+    let mut all_bets = FactFoldBet::batch_get(cli, keys).await.map_err(|e| {
+        crate::error!("detect_round_collusion past bets read failed: {e}");
+        FactOrFoldError::StorageFailure
+    })?;
+    all_bets.extend(bets.iter().cloned());
+
+    Ok(detect_collusion(CollusionInput {
+        players: &players,
+        bets: &all_bets,
+    }))
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::types::EntityType;
+    use crate::features::arcade::games::fact_or_fold::types::BetSide;
+
+    fn history(user_id: &str, rounds: &[&str]) -> PlayerHistory {
+        PlayerHistory {
+            user_id: user_id.into(),
+            round_ids: rounds.iter().map(|r| r.to_string()).collect(),
+            total_rounds: rounds.len() as i64,
+            correct_count: 0,
+            win_rate_cleared_bps: 0,
+        }
+    }
+
+    fn bet(round_id: &str, user_id: &str, side: BetSide) -> FactFoldBet {
+        FactFoldBet {
+            pk: Partition::FactFold(round_id.into()),
+            sk: EntityType::FactFoldBet(user_id.into()),
+            user_pk: Partition::User(user_id.into()),
+            side,
+            amount_rp: 100,
+            ..Default::default()
+        }
+    }
+
+    fn flip(round_id: &str, user_id: &str, to: BetSide, cite: Option<&str>) -> FactFoldBet {
+        let from = match to {
+            BetSide::Real => BetSide::Fake,
+            BetSide::Fake => BetSide::Real,
+        };
+        let mut b = bet(round_id, user_id, from);
+        b.flipped_to = Some(to);
+        b.flip_cite_user_pk = cite.map(|c| Partition::User(c.into()));
+        b
+    }
+
+    fn kinds(signals: &[CollusionSignal]) -> Vec<CollusionSignalKind> {
+        signals.iter().map(|s| s.kind).collect()
+    }
+
+    #[test]
+    fn clean_round_has_no_signals() {
+        let players = vec![history("a", &["r1", "r2"]), history("b", &["r3", "r4"])];
+        let bets = vec![bet("now", "a", BetSide::Real), bet("now", "b", BetSide::Fake)];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &bets,
+        });
+        assert!(signals.is_empty(), "{signals:?}");
+    }
+
+    #[test]
+    fn frequent_pair_is_flagged() {
+        let shared = ["r1", "r2", "r3", "r4", "r5"];
+        let players = vec![history("a", &shared), history("b", &shared)];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &[],
+        });
+        assert_eq!(kinds(&signals), vec![CollusionSignalKind::PairCoOccurrence]);
+        assert_eq!(signals[0].value, 5);
+    }
+
+    #[test]
+    fn shared_rounds_diluted_by_long_history_are_ignored() {
+        // 5 shared rounds out of 40 each — ordinary matchmaking noise.
+        let mut a_rounds: Vec<String> = (0..35).map(|i| format!("a{i}")).collect();
+        let mut b_rounds: Vec<String> = (0..35).map(|i| format!("b{i}")).collect();
+        for i in 0..5 {
+            a_rounds.push(format!("s{i}"));
+            b_rounds.push(format!("s{i}"));
+        }
+        let players = vec![
+            PlayerHistory {
+                user_id: "a".into(),
+                round_ids: a_rounds.into_iter().collect(),
+                ..Default::default()
+            },
+            PlayerHistory {
+                user_id: "b".into(),
+                round_ids: b_rounds.into_iter().collect(),
+                ..Default::default()
+            },
+        ];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &[],
+        });
+        assert!(signals.is_empty(), "{signals:?}");
+    }
+
+    #[test]
+    fn repeated_flips_toward_partner_are_flagged() {
+        let players = vec![history("a", &["r1", "r2"]), history("b", &["r1", "r2"])];
+        let bets = vec![
+            flip("r1", "a", BetSide::Real, Some("b")),
+            bet("r1", "b", BetSide::Real),
+            flip("r2", "b", BetSide::Fake, None),
+            bet("r2", "a", BetSide::Fake),
+            flip("now", "a", BetSide::Fake, None),
+            bet("now", "b", BetSide::Fake),
+        ];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &bets,
+        });
+        assert_eq!(kinds(&signals), vec![CollusionSignalKind::CorrelatedFlips]);
+        assert_eq!(signals[0].value, 3);
+    }
+
+    #[test]
+    fn flips_away_from_partner_do_not_count() {
+        let players = vec![history("a", &["r1", "r2"]), history("b", &["r1", "r2"])];
+        let bets = vec![
+            flip("r1", "a", BetSide::Real, None),
+            bet("r1", "b", BetSide::Fake),
+            flip("r2", "a", BetSide::Real, None),
+            bet("r2", "b", BetSide::Fake),
+            flip("now", "a", BetSide::Real, None),
+            bet("now", "b", BetSide::Fake),
+        ];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &bets,
+        });
+        assert!(signals.is_empty(), "{signals:?}");
+    }
+
+    #[test]
+    fn abnormal_win_rate_needs_minimum_rounds() {
+        let mut veteran = history("a", &[]);
+        veteran.total_rounds = 20;
+        veteran.correct_count = 18;
+        let mut rookie = history("b", &[]);
+        rookie.total_rounds = 5;
+        rookie.correct_count = 5;
+        let players = vec![veteran, rookie];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &[],
+        });
+        assert_eq!(kinds(&signals), vec![CollusionSignalKind::AbnormalWinRate]);
+        assert_eq!(signals[0].user_pks, vec![UserPartition("a".into())]);
+        assert_eq!(signals[0].value, 9_000);
+    }
+
+    #[test]
+    fn cleared_win_rate_is_flagged_only_once_it_climbs() {
+        let mut cleared = history("a", &[]);
+        cleared.total_rounds = 20;
+        cleared.correct_count = 18;
+        cleared.win_rate_cleared_bps = 9_000;
+        let players = vec![cleared.clone()];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &[],
+        });
+        assert!(signals.is_empty(), "{signals:?}");
+
+        cleared.correct_count = 19;
+        let players = vec![cleared];
+        let signals = detect_collusion(CollusionInput {
+            players: &players,
+            bets: &[],
+        });
+        assert_eq!(kinds(&signals), vec![CollusionSignalKind::AbnormalWinRate]);
+    }
+}
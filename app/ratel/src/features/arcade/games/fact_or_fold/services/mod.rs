@@ -1,4 +1,8 @@
+pub mod collusion;
+pub mod replay;
 pub mod settle_round;
 pub mod stage_machine;
 
+pub use collusion::*;
+pub use replay::*;
 pub use settle_round::*;
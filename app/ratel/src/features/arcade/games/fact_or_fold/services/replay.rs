@@ -0,0 +1,277 @@
+//! Pure replay-timeline builder for settled rounds. No I/O: the
+//! replay controller loads the round-scoped rows (all under the
+//! same `FACT_FOLD#{round_id}` pk) and hands them here; this module
+//! merges them into one chronological [`ReplayEvent`] list.
+//!
+//! Timestamps come straight from the persisted rows:
+//! - stage changes — `FactFoldRound.stage_history`, or re-derived
+//!   from `started_at` + the current stage durations for rounds
+//!   written before the history field existed
+//! - bets — `locked_at`; flips — the bet row's `updated_at`
+//! - rationales — `submitted_at`; chat — `sent_at`
+//! - settlement — `round.settled_at`
+//!
+//! Chat rows are absent when a participant already cleared the
+//! transcript via `DELETE .../chat`; the replay then simply has no
+//! chat events.
+
+use crate::features::arcade::games::fact_or_fold::models::{
+    FactFoldBet, FactFoldChatMessage, FactFoldRationale, FactFoldRound, FactFoldSettlement,
+};
+use crate::features::arcade::games::fact_or_fold::services::stage_machine;
+use crate::features::arcade::games::fact_or_fold::types::{
+    FactOrFoldSettingsResponse, ReplayEvent, ReplayEventKind, RoundStageMark, RoundStatus,
+};
+use crate::common::types::UserPartition;
+
+pub struct ReplayInput<'a> {
+    pub round: &'a FactFoldRound,
+    pub bets: &'a [FactFoldBet],
+    pub rationales: &'a [FactFoldRationale],
+    pub chat: &'a [FactFoldChatMessage],
+    pub settlements: &'a [FactFoldSettlement],
+    pub settings: &'a FactOrFoldSettingsResponse,
+}
+
+/// Stage boundaries for the round. Prefers the persisted history;
+/// falls back to walking the stage chain from `started_at` with the
+/// supplied settings (same anchoring as the live walker) and stops
+/// at `settled_at`.
+pub fn stage_marks_for(
+    round: &FactFoldRound,
+    settings: &FactOrFoldSettingsResponse,
+) -> Vec<RoundStageMark> {
+    if !round.stage_history.is_empty() {
+        return round.stage_history.clone();
+    }
+    let Some(started_at) = round.started_at else {
+        return Vec::new();
+    };
+    let end = round.settled_at.unwrap_or(i64::MAX);
+    let mut marks = Vec::new();
+    let mut stage = RoundStatus::NewsReveal;
+    let mut at = started_at;
+    loop {
+        if at > end {
+            break;
+        }
+        marks.push(RoundStageMark {
+            status: stage,
+            started_at: at,
+        });
+        let (Some(next), Some(duration)) = (
+            stage_machine::next_stage(stage),
+            stage_machine::stage_duration_ms(stage, settings),
+        ) else {
+            break;
+        };
+        stage = next;
+        at += duration;
+    }
+    if let Some(settled_at) = round.settled_at {
+        marks.push(RoundStageMark {
+            status: RoundStatus::Settled,
+            started_at: settled_at,
+        });
+    }
+    marks
+}
+
+/// Tie-break rank so events sharing a millisecond keep a readable
+/// order (stage marker first, settlement last).
+fn kind_rank(kind: ReplayEventKind) -> u8 {
+    match kind {
+        ReplayEventKind::StageChanged => 0,
+        ReplayEventKind::BetPlaced => 1,
+        ReplayEventKind::RationaleSubmitted => 2,
+        ReplayEventKind::ChatMessage => 3,
+        ReplayEventKind::BetFlipped => 4,
+        ReplayEventKind::Settled => 5,
+    }
+}
+
+pub fn build_replay_timeline(input: ReplayInput<'_>) -> Vec<ReplayEvent> {
+    let origin = input.round.started_at.unwrap_or(input.round.created_at);
+    let event = |kind: ReplayEventKind, at: i64| ReplayEvent {
+        kind,
+        at,
+        offset_ms: (at - origin).max(0),
+        ..Default::default()
+    };
+
+    let mut events = Vec::new();
+
+    for mark in stage_marks_for(input.round, input.settings) {
+        if matches!(mark.status, RoundStatus::Settled) {
+            continue;
+        }
+        events.push(ReplayEvent {
+            status: Some(mark.status),
+            ..event(ReplayEventKind::StageChanged, mark.started_at)
+        });
+    }
+
+    for bet in input.bets {
+        events.push(ReplayEvent {
+            user_pk: Some(UserPartition::from(bet.user_pk.clone())),
+            side: Some(bet.side),
+            amount: Some(bet.amount_rp),
+            ..event(ReplayEventKind::BetPlaced, bet.locked_at)
+        });
+        if let Some(flipped_to) = bet.flipped_to {
+            events.push(ReplayEvent {
+                user_pk: Some(UserPartition::from(bet.user_pk.clone())),
+                side: Some(flipped_to),
+                amount: Some(bet.amount_rp),
+                cite_user_pk: bet.flip_cite_user_pk.clone().map(UserPartition::from),
+                ..event(ReplayEventKind::BetFlipped, bet.updated_at)
+            });
+        }
+    }
+
+    for r in input.rationales {
+        events.push(ReplayEvent {
+            user_pk: Some(UserPartition::from(r.user_pk.clone())),
+            text: Some(r.text.clone()),
+            ..event(ReplayEventKind::RationaleSubmitted, r.submitted_at)
+        });
+    }
+
+    for m in input.chat {
+        events.push(ReplayEvent {
+            user_pk: Some(UserPartition::from(m.author_pk.clone())),
+            text: Some(m.text.clone()),
+            ..event(ReplayEventKind::ChatMessage, m.sent_at)
+        });
+    }
+
+    if let Some(settled_at) = input.round.settled_at {
+        events.push(ReplayEvent {
+            status: Some(RoundStatus::Settled),
+            ..event(ReplayEventKind::StageChanged, settled_at)
+        });
+        for s in input.settlements {
+            events.push(ReplayEvent {
+                user_pk: Some(UserPartition::from(s.user_pk.clone())),
+                chips_out: Some(s.chips_out),
+                ..event(ReplayEventKind::Settled, settled_at)
+            });
+        }
+    }
+
+    events.sort_by_key(|e| (e.at, kind_rank(e.kind)));
+    events
+}
+
+// ── Tests ───────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::types::{EntityType, Partition};
+    use crate::features::arcade::games::fact_or_fold::types::BetSide;
+
+    fn settings() -> FactOrFoldSettingsResponse {
+        FactOrFoldSettingsResponse::default()
+    }
+
+    fn round(started_at: i64, settled_at: i64) -> FactFoldRound {
+        FactFoldRound {
+            pk: Partition::FactFold("r".into()),
+            sk: EntityType::FactFoldRound("r".into()),
+            status: RoundStatus::Settled,
+            started_at: Some(started_at),
+            settled_at: Some(settled_at),
+            ..Default::default()
+        }
+    }
+
+    fn bet(user_id: &str, locked_at: i64, flip_at: Option<i64>) -> FactFoldBet {
+        FactFoldBet {
+            pk: Partition::FactFold("r".into()),
+            sk: EntityType::FactFoldBet(user_id.into()),
+            created_at: locked_at,
+            updated_at: flip_at.unwrap_or(locked_at),
+            user_pk: Partition::User(user_id.into()),
+            side: BetSide::Real,
+            amount_rp: 100,
+            locked_at,
+            flipped_to: flip_at.map(|_| BetSide::Fake),
+            flip_cite_user_pk: flip_at.map(|_| Partition::User("cited".into())),
+        }
+    }
+
+    fn chat(user_id: &str, sent_at: i64) -> FactFoldChatMessage {
+        FactFoldChatMessage {
+            pk: Partition::FactFold("r".into()),
+            sk: EntityType::FactFoldChat(format!("m{sent_at}")),
+            created_at: sent_at,
+            updated_at: sent_at,
+            author_pk: Partition::User(user_id.into()),
+            text: "hi".into(),
+            sent_at,
+        }
+    }
+
+    #[test]
+    fn derives_stage_marks_when_history_missing() {
+        let s = settings();
+        let r = round(0, 1_000_000);
+        let marks = stage_marks_for(&r, &s);
+        let statuses: Vec<RoundStatus> = marks.iter().map(|m| m.status).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                RoundStatus::NewsReveal,
+                RoundStatus::Bet,
+                RoundStatus::Rationale,
+                RoundStatus::Reveal,
+                RoundStatus::Debate,
+                RoundStatus::Settled,
+            ],
+        );
+        assert_eq!(marks[1].started_at, (s.stage_news_reveal_sec as i64) * 1000);
+    }
+
+    #[test]
+    fn persisted_history_wins_over_settings() {
+        let mut r = round(0, 500);
+        r.push_stage_mark(RoundStatus::NewsReveal, 0);
+        r.push_stage_mark(RoundStatus::Bet, 7);
+        r.push_stage_mark(RoundStatus::Settled, 500);
+        let marks = stage_marks_for(&r, &settings());
+        assert_eq!(marks.len(), 3);
+        assert_eq!(marks[1].started_at, 7);
+    }
+
+    #[test]
+    fn timeline_is_chronological_with_offsets() {
+        let s = settings();
+        let r = round(1_000, 200_000);
+        let bets = vec![bet("a", 40_000, Some(150_000)), bet("b", 35_000, None)];
+        let chats = vec![chat("a", 120_000), chat("b", 110_000)];
+        let events = build_replay_timeline(ReplayInput {
+            round: &r,
+            bets: &bets,
+            rationales: &[],
+            chat: &chats,
+            settlements: &[],
+            settings: &s,
+        });
+
+        assert!(events.windows(2).all(|w| w[0].at <= w[1].at));
+        assert_eq!(events[0].kind, ReplayEventKind::StageChanged);
+        assert_eq!(events[0].offset_ms, 0);
+
+        let flip = events
+            .iter()
+            .find(|e| e.kind == ReplayEventKind::BetFlipped)
+            .expect("flip event");
+        assert_eq!(flip.at, 150_000);
+        assert_eq!(flip.side, Some(BetSide::Fake));
+        assert_eq!(flip.offset_ms, 149_000);
+
+        let last = events.last().unwrap();
+        assert_eq!(last.status, Some(RoundStatus::Settled));
+    }
+}
@@ -32,9 +32,11 @@
 
 use crate::common::Result;
 use crate::features::arcade::games::fact_or_fold::models::FactFoldRound;
+use crate::features::arcade::games::fact_or_fold::realtime::spectator_channel_id;
 use crate::features::arcade::games::fact_or_fold::types::{
-    FactOrFoldError, FactOrFoldSettingsResponse, RoundStatus,
+    FactOrFoldError, FactOrFoldSettingsResponse, RoundResponse, RoundStageMark, RoundStatus,
 };
+use crate::common::realtime::hub::global_hub;
 use crate::features::arcade::services::{self as arcade_services, StageClock, StageScheduler};
 
 // ── arcade::StageScheduler impl for FOF ─────────────────────────────
@@ -115,6 +117,7 @@ pub fn stamp_initial_stage(
     let mut clock = clock_from_round(round);
     arcade_services::stamp_initial_stage::<FactFoldStageScheduler>(&mut clock, settings, now_ms);
     write_clock_back(round, clock);
+    round.push_stage_mark(round.status, now_ms);
 }
 
 /// Walk the round forward through every stage whose deadline has
@@ -126,20 +129,73 @@ pub async fn advance_round_if_due(
     now_ms: i64,
 ) -> Result<FactFoldRound> {
     let mut clock = clock_from_round(&round);
+    let before = clock.clone();
     let outcome = arcade_services::advance_if_due::<FactFoldStageScheduler>(
         &mut clock, settings, now_ms,
     );
     if outcome.persisted_needed {
+        for mark in crossed_stage_marks(&before, outcome.stages_advanced, settings) {
+            round.push_stage_mark(mark.status, mark.started_at);
+        }
         write_clock_back(&mut round, clock);
         round.updated_at = now_ms;
         round.upsert(cli).await.map_err(|e| {
             crate::error!("advance_round_if_due upsert failed: {e}");
             FactOrFoldError::StorageFailure
         })?;
+        publish_stage_changed(&round).await;
     }
     Ok(round)
 }
 
+/// Push the new round state to spectators on this process's hub.
+/// Best-effort: a failed publish never fails the read/write that
+/// triggered the advance.
+async fn publish_stage_changed(round: &FactFoldRound) {
+    let Some(round_id) = round.id() else {
+        return;
+    };
+    let payload = match serde_json::to_value(RoundResponse::from(round)) {
+        Ok(v) => v,
+        Err(e) => {
+            crate::error!("stage_changed serialize failed: {e}");
+            return;
+        }
+    };
+    if let Err(e) = global_hub()
+        .publish(&spectator_channel_id(&round_id), "stage_changed", payload)
+        .await
+    {
+        crate::error!("stage_changed publish failed: {e}");
+    }
+}
+
+/// Re-walk the `stages_advanced` transitions the arcade walker just
+/// made from `before`, returning one mark per stage entered. Mirrors
+/// the walker's anchoring rule (each stage starts at the previous
+/// deadline) so the marks match the persisted clock exactly.
+fn crossed_stage_marks(
+    before: &StageClock<RoundStatus>,
+    stages_advanced: usize,
+    settings: &FactOrFoldSettingsResponse,
+) -> Vec<RoundStageMark> {
+    let mut marks = Vec::with_capacity(stages_advanced);
+    let mut stage = before.stage;
+    let mut deadline = before.stage_deadline_at;
+    for _ in 0..stages_advanced {
+        let (Some(next), Some(at)) = (next_stage(stage), deadline) else {
+            break;
+        };
+        marks.push(RoundStageMark {
+            status: next,
+            started_at: at,
+        });
+        stage = next;
+        deadline = stage_duration_ms(next, settings).map(|d| at + d);
+    }
+    marks
+}
+
 // ── FOF-specific tests ──────────────────────────────────────────────
 //
 // Generic walker tests live alongside the arcade impl in
@@ -185,4 +241,31 @@ mod tests {
         assert_eq!(stage_duration_ms(RoundStatus::Waiting, &s), None);
         assert_eq!(stage_duration_ms(RoundStatus::Settled, &s), None);
     }
+
+    #[test]
+    fn crossed_stage_marks_anchor_to_previous_deadline() {
+        let s = settings();
+        let news_ms = (s.stage_news_reveal_sec as i64) * 1000;
+        let bet_ms = (s.stage_bet_sec as i64) * 1000;
+        let before = StageClock {
+            stage: RoundStatus::NewsReveal,
+            stage_started_at: Some(1_000),
+            stage_deadline_at: Some(1_000 + news_ms),
+        };
+        let marks = crossed_stage_marks(&before, 2, &s);
+        assert_eq!(
+            marks,
+            vec![
+                RoundStageMark {
+                    status: RoundStatus::Bet,
+                    started_at: 1_000 + news_ms,
+                },
+                RoundStageMark {
+                    status: RoundStatus::Rationale,
+                    started_at: 1_000 + news_ms + bet_ms,
+                },
+            ],
+        );
+        assert!(crossed_stage_marks(&before, 0, &s).is_empty());
+    }
 }
@@ -167,6 +167,18 @@ pub enum RoundStatus {
     Settled,
 }
 
+/// One entry in `FactFoldRound.stage_history`. Appended by the stage
+/// machine every time the round enters a new stage so the replay
+/// endpoint can rebuild the timeline with the original timestamps,
+/// even if the operator retunes stage durations afterwards.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoundStageMark {
+    pub status: RoundStatus,
+    /// Millis-since-epoch when the round entered `status`.
+    pub started_at: i64,
+}
+
 #[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
 #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct RoundResponse {
@@ -186,6 +198,10 @@ pub struct RoundResponse {
     /// Server-verified by [`crate::features::arcade::games::fact_or_fold::services::
     /// stage_machine`] on every round read/write (§FR-9).
     pub stage_deadline_at: Option<i64>,
+    /// True while settlement is held for a collusion review. The
+    /// round stays in `Settlement` until an admin approves or voids.
+    #[serde(default)]
+    pub held_for_review: bool,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -479,6 +495,79 @@ pub struct ListParticipantsResponse {
     pub items: Vec<RoundParticipantSummary>,
 }
 
+// ── Spectator + replay (PR8) ─────────────────────────────────────
+
+/// Read-only view of an in-progress round served to non-participants,
+/// both as the `fof.spectate` channel's subscribe snapshot and from
+/// the polling fallback `GET .../rounds/{id}/spectate`. Redaction
+/// follows the player-side rules: bets and rationale text only fill
+/// in once the round reaches `Reveal`, the insider is never marked,
+/// and the verdict stays hidden.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SpectatorSnapshotResponse {
+    pub round: RoundResponse,
+    pub subject: RoundSubjectResponse,
+    pub participants: Vec<RoundParticipantSummary>,
+    /// Empty until `Reveal`.
+    pub bets: Vec<BetResponse>,
+    /// Empty until `Reveal`.
+    pub rationales: Vec<RationaleResponse>,
+    pub chat: Vec<ChatMessagePayload>,
+}
+
+/// Kind of a single entry in a round replay timeline.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReplayEventKind {
+    #[default]
+    StageChanged,
+    BetPlaced,
+    BetFlipped,
+    RationaleSubmitted,
+    ChatMessage,
+    Settled,
+}
+
+/// One event in a replay timeline. Fields that don't apply to the
+/// event's `kind` are left `None` so the client can switch on `kind`
+/// without a per-variant payload type.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplayEvent {
+    pub kind: ReplayEventKind,
+    /// Original millis-since-epoch timestamp of the event.
+    pub at: i64,
+    /// `at - round.started_at`. Drives the scrubber position.
+    pub offset_ms: i64,
+    pub user_pk: Option<UserPartition>,
+    pub status: Option<RoundStatus>,
+    pub side: Option<BetSide>,
+    pub amount: Option<i64>,
+    pub cite_user_pk: Option<UserPartition>,
+    pub text: Option<String>,
+    pub chips_out: Option<i64>,
+}
+
+/// Full replay of a settled round. Everything is unredacted because
+/// the round is over — including the insider flag, which settlement
+/// already implies through `insider_bonus`.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoundReplayResponse {
+    pub round_id: FactFoldRoundEntityType,
+    pub subject: RoundSubjectResponse,
+    pub participants: Vec<RoundParticipantSummary>,
+    pub started_at: i64,
+    pub settled_at: i64,
+    /// `settled_at - started_at` — the scrubber's total length.
+    pub duration_ms: i64,
+    /// Chronological. Ties keep the stage → bet → rationale → chat →
+    /// settlement order so a stage marker always precedes the events
+    /// that happened inside it.
+    pub events: Vec<ReplayEvent>,
+}
+
 // ── Bet + Rationale constants ─────────────────────────────────────
 
 /// Minimum chars for a rationale to count as "Essence-eligible"
@@ -520,6 +609,81 @@ pub struct LeaderboardEntryResponse {
     pub last_played_at: i64,
 }
 
+// ── Collusion review ──────────────────────────────────────────────
+
+/// Which detector rule fired. See `services::collusion`.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CollusionSignalKind {
+    /// Two players keep landing in the same rounds.
+    #[default]
+    PairCoOccurrence,
+    /// A player's flips keep siding with (or citing) the same partner.
+    CorrelatedFlips,
+    /// A player's lifetime accuracy is implausibly high.
+    AbnormalWinRate,
+}
+
+/// One detector hit. `value` is the observed number (shared rounds,
+/// aligned flips, or accuracy in bps) and `threshold` the rule's
+/// trigger, so the review page can show how far over the line it is.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CollusionSignal {
+    pub kind: CollusionSignalKind,
+    pub user_pks: Vec<UserPartition>,
+    pub value: i64,
+    pub threshold: i64,
+}
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+pub enum CollusionReviewStatus {
+    /// Settlement held; waiting on an admin.
+    #[default]
+    Pending,
+    /// Admin cleared the round — settled with the normal formula.
+    Approved,
+    /// Admin voided the round — every buy-in refunded, no bonuses.
+    Voided,
+}
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CollusionReviewResponse {
+    pub round_id: FactFoldRoundEntityType,
+    pub subject_id: FactFoldSubjectEntityType,
+    pub status: CollusionReviewStatus,
+    pub participant_pks: Vec<UserPartition>,
+    pub signals: Vec<CollusionSignal>,
+    /// Sum of every stake in the round — what is at risk.
+    pub total_stake: i64,
+    pub flagged_at: i64,
+    pub resolved_at: Option<i64>,
+    pub resolved_by: Option<UserPartition>,
+    #[serde(default)]
+    pub note: String,
+}
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResolveCollusionReviewRequest {
+    /// Free-text reviewer note kept on the review row.
+    #[serde(default)]
+    pub note: String,
+}
+
 // ── Queue health ──────────────────────────────────────────────────
 
 /// Queue depth + FR-45 alert flag for the admin dashboard. Computed
@@ -650,6 +814,7 @@ impl From<&FactFoldRound> for RoundResponse {
             settled_at: row.settled_at,
             stage_started_at: row.stage_started_at,
             stage_deadline_at: row.stage_deadline_at,
+            held_for_review: row.held_for_review,
             created_at: row.created_at,
             updated_at: row.updated_at,
         }
@@ -189,6 +189,34 @@ pub enum FactOrFoldError {
     )]
     RoundNotSettled,
 
+    // ── Spectator + replay (PR8) ──────────────────────────────────
+    #[error("round is not in progress")]
+    #[translate(
+        en = "Only rounds that are currently being played can be spectated",
+        ko = "진행 중인 라운드만 관전할 수 있습니다.",
+    )]
+    RoundNotSpectatable,
+
+    // ── Collusion review ──────────────────────────────────────────
+    #[error("round settlement held for review")]
+    #[translate(
+        en = "This round is being reviewed before payouts are released",
+        ko = "정산 전 검토 중인 라운드입니다.",
+    )]
+    RoundHeldForReview,
+    #[error("review not found")]
+    #[translate(
+        en = "Review not found",
+        ko = "검토 항목을 찾을 수 없습니다.",
+    )]
+    ReviewNotFound,
+    #[error("review already resolved")]
+    #[translate(
+        en = "This review has already been resolved",
+        ko = "이미 처리된 검토입니다.",
+    )]
+    ReviewAlreadyResolved,
+
     // ── Settings ──────────────────────────────────────────────────
     #[error("settings field out of range")]
     #[translate(
@@ -237,7 +265,13 @@ impl FactOrFoldError {
             | FactOrFoldError::RoundInProgress => StatusCode::CONFLICT,
             FactOrFoldError::LobbyNoSubjectAvailable => StatusCode::SERVICE_UNAVAILABLE,
             FactOrFoldError::RoundNotFound => StatusCode::NOT_FOUND,
-            FactOrFoldError::RoundNotSettled => StatusCode::CONFLICT,
+            FactOrFoldError::RoundNotSettled | FactOrFoldError::RoundNotSpectatable => {
+                StatusCode::CONFLICT
+            }
+            FactOrFoldError::RoundHeldForReview | FactOrFoldError::ReviewAlreadyResolved => {
+                StatusCode::CONFLICT
+            }
+            FactOrFoldError::ReviewNotFound => StatusCode::NOT_FOUND,
             FactOrFoldError::StorageFailure => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -1,16 +1,17 @@
 //! 라텔 오락실 — 미니게임 플랫폼.
 //!
-//! arcade-level 추상(wallet / realtime / services)과 그 위에 얹히는
+//! arcade-level 추상(wallet / services)과 그 위에 얹히는
 //! 게임 모듈들 (`games::<name>`) 의 owner. v1은 Fact or Fold 1개.
 //!
 //! Module layout (design doc 2026-05-15):
 //! - `wallet/`    — `ArcadeWallet` trait (chip ↔ RP, buy_in, settle)
-//! - `realtime/`  — `RoomChannel` trait + in-process hub (SSE-first, future WS)
 //! - `services/`  — `StageScheduler` trait + generic `advance_if_due`
 //! - `models/`    — arcade-level DDB entities (wallet balance, txn, settings)
 //! - `games/`     — registered mini-games (each implements the traits)
 //! - `error.rs`   — `ArcadeError` umbrella
 //!
+//! Realtime rooms use the app-wide `common::realtime` hub.
+//!
 //! pages / hooks / components / layout / route etc. land in
 //! follow-up PRs (PR4c+).
 
@@ -24,7 +25,6 @@ pub mod layout;
 #[cfg(feature = "server")]
 pub mod models;
 pub mod pages;
-pub mod realtime;
 #[cfg(feature = "server")]
 pub mod server;
 pub mod services;
@@ -42,7 +42,6 @@ pub use layout::*;
 #[cfg(feature = "server")]
 pub use models::*;
 pub use pages::*;
-pub use realtime::*;
 pub use services::*;
 pub use types::*;
 #[cfg(feature = "server")]
@@ -1,13 +0,0 @@
-#[cfg(feature = "server")]
-pub mod channel;
-#[cfg(feature = "server")]
-pub mod hub;
-#[cfg(feature = "server")]
-pub mod sse;
-
-#[cfg(feature = "server")]
-pub use channel::*;
-#[cfg(feature = "server")]
-pub use hub::*;
-#[cfg(feature = "server")]
-pub use sse::*;
@@ -1,9 +1,12 @@
 //! Raw axum router for arcade endpoints that the Dioxus
 //! `#[get]`/`#[post]` macros can't represent — currently just the
-//! SSE realtime stream.
+//! legacy `/api/arcade/events` alias of the realtime SSE stream.
 
-use crate::common::axum::Router;
+use crate::common::axum::{Router, routing::get};
 
 pub fn router() -> Router {
-    crate::features::arcade::realtime::sse::router()
+    Router::new().route(
+        "/api/arcade/events",
+        get(crate::common::realtime::sse::events_handler),
+    )
 }
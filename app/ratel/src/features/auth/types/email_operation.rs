@@ -54,6 +54,13 @@ pub enum EmailOperation {
         comment_preview: String,
         cta_url: String,
     },
+    MeetNotification {
+        headline: String,
+        body: String,
+        space_title: String,
+        meet_title: String,
+        cta_url: String,
+    },
 }
 
 impl Default for EmailOperation {
@@ -84,6 +91,7 @@ impl EmailOperation {
             EmailOperation::DiscussionCommentNotification { .. } => {
                 "discussion_comment_notification"
             }
+            EmailOperation::MeetNotification { .. } => "meet_notification",
         }
     }
 }
@@ -0,0 +1,17 @@
+use crate::common::*;
+use crate::features::badge::dto::BadgeVaultResponse;
+
+#[get("/api/me/badges", user: crate::features::auth::User)]
+pub async fn get_my_badges_handler() -> Result<BadgeVaultResponse> {
+    use crate::features::badge::models::{UserBadge, UserBadgeProgress};
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let (earned, progress) = tokio::try_join!(
+        UserBadge::list_for_user(cli, &user.pk),
+        UserBadgeProgress::get_or_default(cli, &user.pk),
+    )?;
+
+    Ok(BadgeVaultResponse::for_owner(&earned, &progress))
+}
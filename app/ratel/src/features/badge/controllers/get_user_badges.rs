@@ -0,0 +1,22 @@
+use crate::common::*;
+use crate::features::badge::dto::BadgeVaultResponse;
+
+/// Public Trophy Case: earned badges only, never progress.
+#[get("/api/users/{username}/badges")]
+pub async fn get_user_badges_handler(username: String) -> Result<BadgeVaultResponse> {
+    use crate::common::models::auth::User;
+    use crate::features::badge::models::UserBadge;
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let opt = User::opt().limit(1);
+    let (users, _) = User::find_by_username(cli, &username, opt).await?;
+    let target = users
+        .into_iter()
+        .next()
+        .ok_or_else(|| Error::NotFound(format!("no user with username {username}")))?;
+
+    let earned = UserBadge::list_for_user(cli, &target.pk).await?;
+    Ok(BadgeVaultResponse::for_visitor(&earned))
+}
@@ -0,0 +1,5 @@
+mod get_my_badges;
+mod get_user_badges;
+
+pub use get_my_badges::*;
+pub use get_user_badges::*;
@@ -0,0 +1,94 @@
+use crate::common::*;
+use crate::features::badge::models::{UserBadge, UserBadgeProgress};
+use crate::features::badge::types::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Trophy Vault payload: the catalog merged with one user's ledger.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct BadgeVaultResponse {
+    pub earned_count: i64,
+    /// Whole catalog size, including coming-soon badges (FR9).
+    pub total_count: i64,
+    /// Catalog order for the owner; earned only, most recent first, for
+    /// other viewers.
+    pub items: Vec<BadgeResponse>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct BadgeResponse {
+    pub badge_id: BadgeId,
+    pub name: String,
+    pub category: BadgeCategory,
+    pub rarity: BadgeRarity,
+    pub criterion_text: String,
+    pub coming_soon: bool,
+    pub awarded_at: Option<i64>,
+    /// Owner view only; `None` for other viewers and bar-less badges.
+    pub progress: Option<i64>,
+    pub threshold: Option<i64>,
+}
+
+impl BadgeVaultResponse {
+    /// Full catalog with private progress — only for the badge owner.
+    pub fn for_owner(earned: &[UserBadge], progress: &UserBadgeProgress) -> Self {
+        let items: Vec<BadgeResponse> = BADGE_CATALOG
+            .iter()
+            .map(|def| {
+                let awarded_at = earned
+                    .iter()
+                    .find(|b| b.badge_id == def.id)
+                    .map(|b| b.awarded_at);
+                let threshold = def.threshold();
+                BadgeResponse {
+                    progress: threshold
+                        .and(def.metric())
+                        .map(|m| progress.value(m)),
+                    threshold,
+                    awarded_at,
+                    ..BadgeResponse::from_def(def)
+                }
+            })
+            .collect();
+        Self {
+            earned_count: earned.len() as i64,
+            total_count: BADGE_CATALOG.len() as i64,
+            items,
+        }
+    }
+
+    /// Earned badges only, no progress (privacy constraint).
+    pub fn for_visitor(earned: &[UserBadge]) -> Self {
+        let items: Vec<BadgeResponse> = earned
+            .iter()
+            .map(|b| BadgeResponse {
+                awarded_at: Some(b.awarded_at),
+                ..BadgeResponse::from_def(b.badge_id.def())
+            })
+            .collect();
+        Self {
+            earned_count: items.len() as i64,
+            total_count: BADGE_CATALOG.len() as i64,
+            items,
+        }
+    }
+}
+
+impl BadgeResponse {
+    pub fn from_def(def: &BadgeDef) -> Self {
+        Self {
+            badge_id: def.id,
+            name: def.name.to_string(),
+            category: def.category,
+            rarity: def.rarity,
+            criterion_text: def.criterion_text.to_string(),
+            coming_soon: matches!(def.criterion, BadgeCriterion::ComingSoon),
+            awarded_at: None,
+            progress: None,
+            threshold: None,
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod badge_response;
+pub use badge_response::*;
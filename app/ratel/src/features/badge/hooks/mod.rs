@@ -0,0 +1,2 @@
+mod use_badges;
+pub use use_badges::*;
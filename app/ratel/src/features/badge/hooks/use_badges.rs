@@ -0,0 +1,43 @@
+use crate::features::badge::controllers::get_my_badges_handler;
+use crate::features::badge::dto::BadgeVaultResponse;
+use crate::features::badge::types::BadgeCategory;
+use crate::*;
+
+/// Controller for the Trophy Vault page (`/badges`).
+///
+/// Bundles the signed-in user's `Loader<BadgeVaultResponse>` (full catalog
+/// with progress) and the category filter chip selection; `None` shows
+/// every category.
+#[derive(Clone, Copy, DioxusController)]
+pub struct UseBadges {
+    pub vault: Loader<BadgeVaultResponse>,
+    pub category_filter: Signal<Option<BadgeCategory>>,
+}
+
+/// Provider — installs the controller into context (or returns the
+/// already-installed instance).
+#[track_caller]
+pub fn use_badges() -> std::result::Result<UseBadges, Loading> {
+    if let Some(ctx) = try_use_context::<UseBadges>() {
+        return Ok(ctx);
+    }
+
+    let user_ctx = crate::features::auth::hooks::use_user_context();
+
+    let vault = use_loader(move || {
+        let logged_in = user_ctx().is_logged_in();
+        async move {
+            if !logged_in {
+                return Ok(BadgeVaultResponse::default());
+            }
+            get_my_badges_handler().await
+        }
+    })?;
+
+    let category_filter = use_signal(|| None);
+
+    Ok(use_context_provider(move || UseBadges {
+        vault,
+        category_filter,
+    }))
+}
@@ -0,0 +1,19 @@
+pub mod controllers;
+pub mod dto;
+pub mod hooks;
+pub mod models;
+pub mod pages;
+pub mod services;
+pub mod types;
+
+pub use controllers::*;
+pub use dto::*;
+pub use models::*;
+pub use pages::*;
+pub use types::*;
+
+#[allow(unused_imports)]
+use crate::common::*;
+
+#[cfg(test)]
+mod tests;
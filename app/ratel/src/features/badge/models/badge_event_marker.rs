@@ -0,0 +1,37 @@
+use crate::common::*;
+
+/// Stream records can be redelivered well within a day; a week covers
+/// every retry path with room to spare.
+pub const BADGE_EVENT_MARKER_TTL_DAYS: i64 = 7;
+
+/// One row per source event already counted into a user's
+/// `UserBadgeProgress`. Created conditionally before the counter `ADD`, so
+/// a replayed stream record finds it and counts nothing.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+pub struct BadgeEventMarker {
+    pub pk: Partition,  // Partition::User(user_id)
+    pub sk: EntityType, // EntityType::BadgeEventMarker(source_hash)
+
+    pub created_at: i64,
+
+    /// DynamoDB TTL field (epoch seconds).
+    pub expires_at: i64,
+}
+
+impl BadgeEventMarker {
+    pub fn new(user_pk: Partition, source_id: &str, now_ms: i64) -> Self {
+        Self {
+            pk: user_pk,
+            sk: EntityType::BadgeEventMarker(Self::hash_source(source_id)),
+            created_at: now_ms,
+            expires_at: now_ms / 1000 + BADGE_EVENT_MARKER_TTL_DAYS * 86_400,
+        }
+    }
+
+    fn hash_source(source_id: &str) -> String {
+        use std::hash::{Hash, Hasher};
+        let mut h = std::collections::hash_map::DefaultHasher::new();
+        source_id.hash(&mut h);
+        format!("{:016x}", h.finish())
+    }
+}
@@ -0,0 +1,7 @@
+mod badge_event_marker;
+mod user_badge;
+mod user_badge_progress;
+
+pub use badge_event_marker::*;
+pub use user_badge::*;
+pub use user_badge_progress::*;
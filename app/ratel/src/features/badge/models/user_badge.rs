@@ -0,0 +1,51 @@
+use crate::common::*;
+use crate::features::badge::types::BadgeId;
+
+/// One earned badge. Append-only: a row is never updated or deleted once
+/// written, so badges stay earned when the counter later drops (FR5).
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+pub struct UserBadge {
+    pub pk: Partition,  // Partition::User(user_id)
+    pub sk: EntityType, // EntityType::UserBadge(badge_id)
+
+    pub badge_id: BadgeId,
+    pub awarded_at: i64,
+    /// Counter value at the moment of award (0 for windowed badges).
+    #[serde(default)]
+    pub progress_at_award: i64,
+}
+
+impl UserBadge {
+    pub fn keys(user_pk: &Partition, badge_id: BadgeId) -> (Partition, EntityType) {
+        (
+            user_pk.clone(),
+            EntityType::UserBadge(badge_id.as_str().to_string()),
+        )
+    }
+}
+
+#[cfg(feature = "server")]
+impl UserBadge {
+    pub fn new(user_pk: Partition, badge_id: BadgeId, progress_at_award: i64) -> Self {
+        let (pk, sk) = Self::keys(&user_pk, badge_id);
+        Self {
+            pk,
+            sk,
+            badge_id,
+            awarded_at: crate::common::utils::time::get_now_timestamp_millis(),
+            progress_at_award,
+        }
+    }
+
+    /// Every badge the user has earned, most recent first. Bounded by the
+    /// catalog size, so a single page is always enough.
+    pub async fn list_for_user(
+        cli: &aws_sdk_dynamodb::Client,
+        user_pk: &Partition,
+    ) -> crate::common::Result<Vec<Self>> {
+        let opts = Self::opt().sk("USER_BADGE#".to_string()).limit(100);
+        let (mut rows, _) = Self::query(cli, user_pk.clone(), opts).await?;
+        rows.sort_by(|a, b| b.awarded_at.cmp(&a.awarded_at));
+        Ok(rows)
+    }
+}
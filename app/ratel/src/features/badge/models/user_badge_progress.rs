@@ -0,0 +1,65 @@
+use crate::common::*;
+use crate::features::badge::types::BadgeMetric;
+
+/// Singleton per user holding every counter badge criteria read. Count
+/// fields move by atomic `ADD`, once per source event (see
+/// `BadgeEventMarker`); the peak and streak fields only ever rise.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+#[serde(default)]
+pub struct UserBadgeProgress {
+    pub pk: Partition,  // Partition::User(user_id)
+    pub sk: EntityType, // EntityType::UserBadgeProgress
+
+    pub spaces_joined: i64,
+    pub poll_votes_cast: i64,
+    pub quizzes_perfect: i64,
+    pub discussion_comments: i64,
+    pub followings: i64,
+    pub followers: i64,
+    pub follow_quests_completed: i64,
+    pub spaces_created_ongoing: i64,
+    pub peak_space_participants: i64,
+
+    /// Mirror of `UserActivityStreak::longest_streak_days`, the ledger
+    /// that owns streaks.
+    pub longest_streak_days: i64,
+
+    pub updated_at: i64,
+}
+
+impl UserBadgeProgress {
+    pub fn keys(user_pk: &Partition) -> (Partition, EntityType) {
+        (user_pk.clone(), EntityType::UserBadgeProgress)
+    }
+
+    pub fn value(&self, metric: BadgeMetric) -> i64 {
+        match metric {
+            BadgeMetric::SpacesJoined => self.spaces_joined,
+            BadgeMetric::PollVotesCast => self.poll_votes_cast,
+            BadgeMetric::QuizzesPerfect => self.quizzes_perfect,
+            BadgeMetric::DiscussionComments => self.discussion_comments,
+            BadgeMetric::Followings => self.followings,
+            BadgeMetric::Followers => self.followers,
+            BadgeMetric::FollowQuestsCompleted => self.follow_quests_completed,
+            BadgeMetric::SpacesCreatedOngoing => self.spaces_created_ongoing,
+            BadgeMetric::PeakSpaceParticipants => self.peak_space_participants,
+            BadgeMetric::LongestStreakDays => self.longest_streak_days,
+            BadgeMetric::AccountCreated => 0,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl UserBadgeProgress {
+    pub async fn get_or_default(
+        cli: &aws_sdk_dynamodb::Client,
+        user_pk: &Partition,
+    ) -> crate::common::Result<Self> {
+        let (pk, sk) = Self::keys(user_pk);
+        Ok(Self::get(cli, &pk, Some(&sk)).await?.unwrap_or(Self {
+            pk,
+            sk,
+            ..Default::default()
+        }))
+    }
+}
@@ -0,0 +1,3 @@
+mod trophy_vault;
+
+pub use trophy_vault::*;
@@ -0,0 +1,292 @@
+use super::*;
+use crate::common::components::SeoMeta;
+use crate::components::RatelArenaTopbar;
+use crate::features::badge::dto::BadgeResponse;
+use crate::features::badge::hooks::{use_badges, UseBadges};
+use crate::features::badge::types::{BadgeCategory, BadgeRarity};
+use crate::*;
+
+/// Circumference of the hero ring (`r = 52`).
+const RING_CIRCUMFERENCE: f64 = 326.73;
+
+/// Trophy Vault page (`/badges`).
+///
+/// Reads from the [`UseBadges`] controller and renders the hero ring,
+/// category filter chips and one medallion grid per category. Class names
+/// follow `app/ratel/assets/design/badge/badges.html`; the shared ones
+/// (`progress-ring`, `filter-chip`, `category-head`) are scoped under
+/// `.badges-page` in `main.css`.
+#[component]
+pub fn BadgeVaultPage() -> Element {
+    let tr: TrophyVaultTranslate = use_translate();
+    let UseBadges {
+        vault,
+        mut category_filter,
+    } = use_badges()?;
+
+    let response = vault();
+    let selected = category_filter();
+
+    let ratio = if response.total_count > 0 {
+        response.earned_count as f64 / response.total_count as f64
+    } else {
+        0.0
+    };
+    let dash_offset = format!("{:.2}", RING_CIRCUMFERENCE * (1.0 - ratio));
+
+    let categories: Vec<(usize, BadgeCategory)> = BadgeCategory::ALL
+        .into_iter()
+        .enumerate()
+        .filter(|(_, c)| selected.map(|s| s == *c).unwrap_or(true))
+        .collect();
+
+    rsx! {
+        SeoMeta { title: "{tr.page_title}" }
+
+        div { class: "badges-arena",
+            RatelArenaTopbar { active: None }
+
+            main { class: "badges-page", id: "badges-page",
+
+                section { class: "badges-hero",
+                    div { class: "badges-hero__text",
+                        span { class: "badges-hero__eyebrow", "{tr.hero_eyebrow}" }
+                        h1 { class: "badges-hero__title", "{tr.hero_title}" }
+                        p { class: "badges-hero__sub", "{tr.hero_sub}" }
+                    }
+                    div { class: "badges-hero__progress",
+                        div { class: "progress-ring",
+                            svg { width: "120", height: "120",
+                                circle {
+                                    class: "progress-ring__track",
+                                    cx: "60",
+                                    cy: "60",
+                                    r: "52",
+                                }
+                                circle {
+                                    class: "progress-ring__fill",
+                                    cx: "60",
+                                    cy: "60",
+                                    r: "52",
+                                    stroke_dasharray: "{RING_CIRCUMFERENCE}",
+                                    stroke_dashoffset: "{dash_offset}",
+                                }
+                            }
+                            div { class: "progress-ring__text",
+                                strong { "data-testid": "badges-earned-count",
+                                    "{response.earned_count} / {response.total_count}"
+                                }
+                                span { "{tr.earned_label}" }
+                            }
+                        }
+                    }
+                }
+
+                nav { class: "badges-filter",
+                    button {
+                        class: "filter-chip",
+                        r#type: "button",
+                        aria_pressed: selected.is_none(),
+                        onclick: move |_| category_filter.set(None),
+                        "{tr.filter_all} "
+                        span { class: "filter-chip__count", "{response.items.len()}" }
+                    }
+                    for category in BadgeCategory::ALL {
+                        button {
+                            key: "{category_label(&tr, category)}",
+                            class: "filter-chip",
+                            r#type: "button",
+                            aria_pressed: selected == Some(category),
+                            onclick: move |_| category_filter.set(Some(category)),
+                            "{category_label(&tr, category)} "
+                            span { class: "filter-chip__count",
+                                "{response.items.iter().filter(|b| b.category == category).count()}"
+                            }
+                        }
+                    }
+                }
+
+                for (index, category) in categories {
+                    BadgeCategorySection {
+                        key: "{index}",
+                        index,
+                        category,
+                        items: response
+                            .items
+                            .iter()
+                            .filter(|b| b.category == category)
+                            .cloned()
+                            .collect::<Vec<_>>(),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn BadgeCategorySection(
+    index: usize,
+    category: BadgeCategory,
+    items: Vec<BadgeResponse>,
+) -> Element {
+    let tr: TrophyVaultTranslate = use_translate();
+    let earned = items.iter().filter(|b| b.awarded_at.is_some()).count();
+    let total = items.len();
+    let sub = match category {
+        BadgeCategory::Participation => tr.participation_sub,
+        BadgeCategory::Creator => tr.creator_sub,
+        BadgeCategory::Social => tr.social_sub,
+        BadgeCategory::Achievement => tr.achievement_sub,
+        BadgeCategory::Special => tr.special_sub,
+    };
+
+    rsx! {
+        header { class: "category-head",
+            div { class: "category-head__left",
+                span { class: "category-head__title",
+                    "{index + 1:02} · {category_label(&tr, category)}"
+                }
+                span { class: "category-head__sub", "{sub}" }
+            }
+            span { class: "category-head__earned", "{earned} / {total} {tr.earned_suffix}" }
+        }
+        section { class: "badge-vault",
+            for item in items {
+                BadgeMedal { key: "{item.badge_id.as_str()}", item }
+            }
+        }
+    }
+}
+
+#[component]
+fn BadgeMedal(item: BadgeResponse) -> Element {
+    let tr: TrophyVaultTranslate = use_translate();
+    let locked = item.awarded_at.is_none();
+    let rarity = match item.rarity {
+        BadgeRarity::Common => "common",
+        BadgeRarity::Rare => "rare",
+        BadgeRarity::Legendary => "legendary",
+    };
+
+    rsx! {
+        article {
+            class: "badge-medal",
+            "data-rarity": rarity,
+            "data-locked": locked,
+            "data-testid": "badge-medal-{item.badge_id.as_str()}",
+            div { class: "badge-medal__emblem",
+                div { class: "badge-medal__frame" }
+                div { class: "badge-medal__inner",
+                    div { class: "badge-medal__icon", {category_icon(item.category)} }
+                }
+                if item.rarity == BadgeRarity::Legendary {
+                    span { class: "badge-medal__chip", "{tr.rarity_legendary}" }
+                }
+                if locked {
+                    div { class: "badge-medal__lock",
+                        svg {
+                            view_box: "0 0 24 24",
+                            fill: "none",
+                            stroke: "currentColor",
+                            stroke_width: "2",
+                            stroke_linecap: "round",
+                            stroke_linejoin: "round",
+                            rect {
+                                x: "3",
+                                y: "11",
+                                width: "18",
+                                height: "11",
+                                rx: "2",
+                                ry: "2",
+                            }
+                            path { d: "M7 11V7a5 5 0 0 1 10 0v4" }
+                        }
+                    }
+                }
+            }
+            div { class: "badge-medal__info",
+                span { class: "badge-medal__banner", "{item.name}" }
+                {medal_meta(&tr, &item)}
+            }
+            div { class: "badge-medal__tip", "{item.criterion_text}" }
+        }
+    }
+}
+
+fn medal_meta(tr: &TrophyVaultTranslate, item: &BadgeResponse) -> Element {
+    if let Some(awarded_at) = item.awarded_at {
+        let when = crate::common::utils::time::time_ago(awarded_at);
+        return rsx! {
+            span { class: "badge-medal__meta badge-medal__meta--earned",
+                svg {
+                    view_box: "0 0 24 24",
+                    fill: "none",
+                    stroke: "currentColor",
+                    stroke_width: "2",
+                    stroke_linecap: "round",
+                    stroke_linejoin: "round",
+                    polyline { points: "20 6 9 17 4 12" }
+                }
+                "{when}"
+            }
+        };
+    }
+    if item.coming_soon {
+        return rsx! {
+            span { class: "badge-medal__meta badge-medal__meta--soon", "{tr.coming_soon}" }
+        };
+    }
+    match (item.progress, item.threshold) {
+        (Some(progress), Some(threshold)) if threshold > 0 => {
+            let shown = progress.min(threshold);
+            let pct = shown * 100 / threshold;
+            let remaining = threshold - shown;
+            rsx! {
+                div { class: "badge-medal__progress",
+                    div {
+                        class: "badge-medal__progress-fill",
+                        style: "width:{pct}%",
+                    }
+                }
+                span { class: "badge-medal__meta",
+                    "{shown} / {threshold} · {remaining} {tr.to_go}"
+                }
+            }
+        }
+        _ => rsx! {
+            span { class: "badge-medal__meta", "{tr.unlock_pending}" }
+        },
+    }
+}
+
+fn category_label(tr: &TrophyVaultTranslate, category: BadgeCategory) -> &'static str {
+    match category {
+        BadgeCategory::Participation => tr.participation,
+        BadgeCategory::Creator => tr.creator,
+        BadgeCategory::Social => tr.social,
+        BadgeCategory::Achievement => tr.achievement,
+        BadgeCategory::Special => tr.special,
+    }
+}
+
+fn category_icon(category: BadgeCategory) -> Element {
+    let path = match category {
+        BadgeCategory::Participation => "M22 11.08V12a10 10 0 1 1-5.93-9.14M22 4 12 14.01l-3-3",
+        BadgeCategory::Creator => "M12 2 2 7l10 5 10-5-10-5zM2 17l10 5 10-5M2 12l10 5 10-5",
+        BadgeCategory::Social => "M17 21v-2a4 4 0 0 0-4-4H5a4 4 0 0 0-4 4v2M9 11a4 4 0 1 0 0-8 4 4 0 0 0 0 8z",
+        BadgeCategory::Achievement => "M12 2l3 7h7l-5.5 4.5L18 21l-6-4-6 4 1.5-7.5L2 9h7z",
+        BadgeCategory::Special => "M12 2v4M12 18v4M4.93 4.93l2.83 2.83M16.24 16.24l2.83 2.83M2 12h4M18 12h4",
+    };
+    rsx! {
+        svg {
+            view_box: "0 0 24 24",
+            fill: "none",
+            stroke: "currentColor",
+            stroke_width: "2",
+            stroke_linecap: "round",
+            stroke_linejoin: "round",
+            path { d: path }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::*;
+
+translate! {
+    TrophyVaultTranslate;
+
+    page_title: { en: "Badges", ko: "배지" },
+
+    // Hero
+    hero_eyebrow: { en: "◆ Trophy Vault", ko: "◆ 트로피 금고" },
+    hero_title: { en: "Your Badge Ledger", ko: "나의 배지 기록" },
+    hero_sub: {
+        en: "Badges are permanent recognition for civic milestones on Ratel — joining spaces, voting, deliberating and building. Once earned, a badge is yours for good.",
+        ko: "배지는 Ratel에서의 시민 활동 이정표 — 스페이스 참여, 투표, 토론, 구축 — 에 대한 영구적인 인정입니다. 한 번 획득한 배지는 사라지지 않습니다.",
+    },
+    earned_label: { en: "Earned", ko: "획득" },
+
+    // Filter + category heads
+    filter_all: { en: "All", ko: "전체" },
+    participation: { en: "Participation", ko: "참여" },
+    participation_sub: { en: "Show up, deliberate, and vote", ko: "참여하고, 토론하고, 투표하세요" },
+    creator: { en: "Creator", ko: "크리에이터" },
+    creator_sub: { en: "Build spaces others want to join", ko: "다른 사람이 참여하고 싶은 스페이스를 만드세요" },
+    social: { en: "Social", ko: "소셜" },
+    social_sub: { en: "Grow the network around you", ko: "주변의 네트워크를 넓히세요" },
+    achievement: { en: "Achievement", ko: "업적" },
+    achievement_sub: { en: "Mastery and consistency", ko: "숙련과 꾸준함" },
+    special: { en: "Special", ko: "스페셜" },
+    special_sub: { en: "Time-boxed and one-of-a-kind", ko: "기간 한정 및 특별 배지" },
+    earned_suffix: { en: "earned", ko: "획득" },
+
+    // Medal meta
+    to_go: { en: "to go", ko: "남음" },
+    unlock_pending: { en: "Unlock pending", ko: "잠금 해제 대기" },
+    coming_soon: { en: "Coming soon", ko: "준비 중" },
+    rarity_legendary: { en: "Legendary", ko: "전설" },
+}
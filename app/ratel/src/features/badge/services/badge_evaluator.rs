@@ -0,0 +1,274 @@
+//! Badge evaluator. Every stream source funnels into
+//! [`record_badge_event`]: bump the user's `UserBadgeProgress`, then award
+//! each catalog badge the new counters satisfy. Each source event is
+//! counted once — a conditional `BadgeEventMarker` guards the counter
+//! `ADD`. Awards are create-only `UserBadge` rows; the inbox notification
+//! is sent only when the create wins, so a replayed event never
+//! re-notifies.
+
+use crate::common::*;
+use crate::features::badge::models::{BadgeEventMarker, UserBadge, UserBadgeProgress};
+use crate::features::badge::types::*;
+
+/// A single unit of badge-relevant activity, already attributed to the
+/// user it counts for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeEvent {
+    SpaceJoined,
+    PollVoted,
+    QuizAttempted { perfect: bool },
+    DiscussionCommented,
+    /// The user followed someone; `quest` when it completed a follow action.
+    Followed { quest: bool },
+    GainedFollower,
+    SpaceWentOngoing,
+    SpaceFinished { participants: i64 },
+    AccountCreated,
+    /// `UserActivityStreak` advanced; `longest` is its longest streak.
+    StreakAdvanced { longest: i64 },
+}
+
+impl BadgeEvent {
+    /// Metrics this event can move — the only badges worth re-checking.
+    pub fn metrics(&self) -> &'static [BadgeMetric] {
+        match self {
+            BadgeEvent::SpaceJoined => &[BadgeMetric::SpacesJoined],
+            BadgeEvent::PollVoted => &[BadgeMetric::PollVotesCast],
+            BadgeEvent::QuizAttempted { .. } => &[BadgeMetric::QuizzesPerfect],
+            BadgeEvent::DiscussionCommented => &[BadgeMetric::DiscussionComments],
+            BadgeEvent::Followed { .. } => {
+                &[BadgeMetric::Followings, BadgeMetric::FollowQuestsCompleted]
+            }
+            BadgeEvent::GainedFollower => &[BadgeMetric::Followers],
+            BadgeEvent::SpaceWentOngoing => &[BadgeMetric::SpacesCreatedOngoing],
+            BadgeEvent::SpaceFinished { .. } => &[BadgeMetric::PeakSpaceParticipants],
+            BadgeEvent::AccountCreated => &[BadgeMetric::AccountCreated],
+            BadgeEvent::StreakAdvanced { .. } => &[BadgeMetric::LongestStreakDays],
+        }
+    }
+}
+
+/// Badges `progress` satisfies among those reading `metrics`, for an
+/// event stamped `at`. Pure — the caller filters out already-earned ones.
+pub fn satisfied_badges(
+    progress: &UserBadgeProgress,
+    metrics: &[BadgeMetric],
+    at: i64,
+) -> Vec<&'static BadgeDef> {
+    BADGE_CATALOG
+        .iter()
+        .filter(|def| match def.criterion {
+            BadgeCriterion::Threshold { metric, threshold } => {
+                metrics.contains(&metric) && progress.value(metric) >= threshold
+            }
+            BadgeCriterion::Window {
+                metric,
+                from_ms,
+                to_ms,
+            } => metrics.contains(&metric) && at >= from_ms && at < to_ms,
+            BadgeCriterion::ComingSoon => false,
+        })
+        .collect()
+}
+
+/// Record `event` for `user_pk` at `at` (ms) and award any badge it
+/// unlocks. `source_id` names the row that produced the event (e.g.
+/// `"{pk}#{sk}"`); a replay of the same source counts nothing and awards
+/// nothing new. Returns the newly awarded ids. Non-user partitions (teams)
+/// are ignored — badges are per citizen.
+#[cfg(feature = "server")]
+pub async fn record_badge_event(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    event: BadgeEvent,
+    source_id: &str,
+    at: i64,
+) -> Result<Vec<BadgeId>> {
+    if !matches!(user_pk, Partition::User(_)) {
+        return Ok(vec![]);
+    }
+
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let marker = BadgeEventMarker::new(user_pk.clone(), source_id, now);
+    match marker.create(cli).await {
+        Ok(_) => {}
+        Err(Error::Aws(crate::common::utils::aws::error::AwsError::DynamoDb(
+            aws_sdk_dynamodb::Error::ConditionalCheckFailedException(_),
+        ))) => {
+            tracing::debug!(user_pk = %user_pk, source_id, "badge event already counted; skipping");
+            return Ok(vec![]);
+        }
+        Err(e) => return Err(e),
+    }
+
+    let progress = match bump_progress(cli, user_pk, event, at).await {
+        Ok(progress) => progress,
+        Err(e) => {
+            // Release the marker so the stream retry can count the event.
+            if let Err(del) = BadgeEventMarker::delete(cli, &marker.pk, Some(marker.sk)).await {
+                tracing::error!(
+                    user_pk = %user_pk,
+                    source_id,
+                    error = %del,
+                    "badge event marker release failed"
+                );
+            }
+            return Err(e);
+        }
+    };
+    let candidates = satisfied_badges(&progress, event.metrics(), at);
+    if candidates.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut awarded = vec![];
+    for def in candidates {
+        let value = def.metric().map(|m| progress.value(m)).unwrap_or_default();
+        if award_badge(cli, user_pk, def, value, true).await? {
+            awarded.push(def.id);
+        }
+    }
+    Ok(awarded)
+}
+
+#[cfg(feature = "server")]
+async fn bump_progress(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    event: BadgeEvent,
+    at: i64,
+) -> Result<UserBadgeProgress> {
+    let (pk, sk) = UserBadgeProgress::keys(user_pk);
+
+    // Counters first, via atomic ADD — this also creates the row.
+    let updater = UserBadgeProgress::updater(&pk, &sk).with_updated_at(at);
+    let updater = match event {
+        BadgeEvent::SpaceJoined => updater.increase_spaces_joined(1),
+        BadgeEvent::PollVoted => updater.increase_poll_votes_cast(1),
+        BadgeEvent::QuizAttempted { perfect: true } => updater.increase_quizzes_perfect(1),
+        BadgeEvent::DiscussionCommented => updater.increase_discussion_comments(1),
+        BadgeEvent::Followed { quest: true } => updater
+            .increase_followings(1)
+            .increase_follow_quests_completed(1),
+        BadgeEvent::Followed { quest: false } => updater.increase_followings(1),
+        BadgeEvent::GainedFollower => updater.increase_followers(1),
+        BadgeEvent::SpaceWentOngoing => updater.increase_spaces_created_ongoing(1),
+        BadgeEvent::QuizAttempted { perfect: false }
+        | BadgeEvent::SpaceFinished { .. }
+        | BadgeEvent::AccountCreated
+        | BadgeEvent::StreakAdvanced { .. } => updater,
+    };
+    let mut progress = updater.execute(cli).await?;
+
+    // Peaks only ever rise, so a second write that keeps the max is
+    // safe to repeat.
+    let follow_up = match event {
+        BadgeEvent::SpaceFinished { participants }
+            if participants > progress.peak_space_participants =>
+        {
+            Some(UserBadgeProgress::updater(&pk, &sk).with_peak_space_participants(participants))
+        }
+        BadgeEvent::StreakAdvanced { longest } if longest > progress.longest_streak_days => {
+            Some(UserBadgeProgress::updater(&pk, &sk).with_longest_streak_days(longest))
+        }
+        _ => None,
+    };
+    if let Some(updater) = follow_up {
+        progress = updater.execute(cli).await?;
+    }
+
+    Ok(progress)
+}
+
+/// Write the `UserBadge` row if absent. Returns `true` only for the call
+/// that actually created it; that call alone sends the notification.
+#[cfg(feature = "server")]
+pub async fn award_badge(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    def: &BadgeDef,
+    progress_value: i64,
+    notify: bool,
+) -> Result<bool> {
+    let (pk, sk) = UserBadge::keys(user_pk, def.id);
+    if UserBadge::get(cli, &pk, Some(&sk)).await?.is_some() {
+        return Ok(false);
+    }
+
+    // `create` is conditional on the row being absent, so a concurrent
+    // evaluator that lost the race lands here and stays silent.
+    let row = UserBadge::new(user_pk.clone(), def.id, progress_value);
+    if let Err(e) = row.create(cli).await {
+        tracing::debug!(
+            user_pk = %user_pk,
+            badge = def.id.as_str(),
+            error = %e,
+            "award_badge: create lost race or failed; skipping notification"
+        );
+        return Ok(false);
+    }
+
+    tracing::info!(user_pk = %user_pk, badge = def.id.as_str(), "badge awarded");
+
+    if notify {
+        let payload = InboxPayload::BadgeAwarded {
+            badge_id: def.id,
+            badge_name: def.name.to_string(),
+            badge_rarity: def.rarity,
+            badge_category: def.category,
+            criterion_text: def.criterion_text.to_string(),
+            cta_url: "/badges".to_string(),
+        };
+        crate::common::utils::inbox::create_inbox_row(user_pk.clone(), payload).await?;
+    }
+    Ok(true)
+}
+
+/// Reconcile a user's counters with a recomputed snapshot and award
+/// whatever it already satisfies, without notifications. Counters only
+/// ever move up, so re-running converges and never un-earns (FR5).
+/// The longest streak comes from the activity ledger.
+#[cfg(feature = "server")]
+pub async fn backfill_user_badges(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    snapshot: &UserBadgeProgress,
+) -> Result<Vec<BadgeId>> {
+    if !matches!(user_pk, Partition::User(_)) {
+        return Ok(vec![]);
+    }
+
+    let mut progress = UserBadgeProgress::get_or_default(cli, user_pk).await?;
+    progress.spaces_joined = progress.spaces_joined.max(snapshot.spaces_joined);
+    progress.poll_votes_cast = progress.poll_votes_cast.max(snapshot.poll_votes_cast);
+    progress.quizzes_perfect = progress.quizzes_perfect.max(snapshot.quizzes_perfect);
+    progress.discussion_comments = progress
+        .discussion_comments
+        .max(snapshot.discussion_comments);
+    progress.followings = progress.followings.max(snapshot.followings);
+    progress.followers = progress.followers.max(snapshot.followers);
+    progress.follow_quests_completed = progress
+        .follow_quests_completed
+        .max(snapshot.follow_quests_completed);
+    progress.spaces_created_ongoing = progress
+        .spaces_created_ongoing
+        .max(snapshot.spaces_created_ongoing);
+    progress.peak_space_participants = progress
+        .peak_space_participants
+        .max(snapshot.peak_space_participants);
+    let streak =
+        crate::features::activity::models::UserActivityStreak::get_or_default(cli, user_pk).await?;
+    progress.longest_streak_days = progress.longest_streak_days.max(streak.longest_streak_days);
+    progress.updated_at = crate::common::utils::time::get_now_timestamp_millis();
+    progress.upsert(cli).await?;
+
+    let metrics: Vec<BadgeMetric> = BADGE_CATALOG.iter().filter_map(|d| d.metric()).collect();
+    let mut awarded = vec![];
+    for def in satisfied_badges(&progress, &metrics, 0) {
+        let value = def.metric().map(|m| progress.value(m)).unwrap_or_default();
+        if award_badge(cli, user_pk, def, value, false).await? {
+            awarded.push(def.id);
+        }
+    }
+    Ok(awarded)
+}
@@ -0,0 +1,176 @@
+//! Stream-side adapters: one per source entity, each mapping the row to a
+//! [`BadgeEvent`] for the user it counts toward, keyed on the source row
+//! so a redelivered record counts once. Called from
+//! `common::stream_handler` next to the XP handlers; errors are returned
+//! for logging there and never block the XP path.
+
+use crate::common::*;
+use crate::features::badge::services::{record_badge_event, BadgeEvent};
+
+/// SpaceParticipant INSERT — one row per (space, user), so one join.
+pub async fn handle_space_join_badge(
+    participant: crate::common::models::space::SpaceParticipant,
+) -> Result<()> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    record_badge_event(
+        cli,
+        &participant.user_pk,
+        BadgeEvent::SpaceJoined,
+        &format!("{}#{}", participant.pk, participant.sk),
+        participant.created_at,
+    )
+    .await?;
+    Ok(())
+}
+
+/// SpacePollUserAnswer INSERT. Answer edits arrive as MODIFY and don't
+/// count again.
+pub async fn handle_poll_badge(
+    answer: crate::features::spaces::pages::actions::actions::poll::SpacePollUserAnswer,
+) -> Result<()> {
+    let Some(user_pk) = answer.user_pk.clone() else {
+        tracing::warn!("PollBadge: missing user_pk, skipping");
+        return Ok(());
+    };
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    record_badge_event(
+        cli,
+        &user_pk,
+        BadgeEvent::PollVoted,
+        &format!("{}#{}", answer.pk, answer.sk),
+        answer.created_at,
+    )
+    .await?;
+    Ok(())
+}
+
+/// SpaceQuizAttempt INSERT. A perfect attempt earned full credit on
+/// every question it presented.
+pub async fn handle_quiz_badge(
+    attempt: crate::features::spaces::pages::actions::actions::quiz::SpaceQuizAttempt,
+) -> Result<()> {
+    let Some(user_pk) = attempt.user_pk.clone() else {
+        tracing::warn!("QuizBadge: missing user_pk, skipping");
+        return Ok(());
+    };
+    let perfect = attempt.is_perfect();
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    record_badge_event(
+        cli,
+        &user_pk,
+        BadgeEvent::QuizAttempted { perfect },
+        &format!("{}#{}", attempt.pk, attempt.sk),
+        attempt.created_at,
+    )
+    .await?;
+    Ok(())
+}
+
+/// Discussion comment or reply INSERT.
+pub async fn handle_discussion_badge(
+    comment: crate::features::spaces::pages::actions::actions::discussion::SpacePostComment,
+) -> Result<()> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    record_badge_event(
+        cli,
+        &comment.author_pk,
+        BadgeEvent::DiscussionCommented,
+        &format!("{}#{}", comment.pk, comment.sk),
+        comment.created_at,
+    )
+    .await?;
+    Ok(())
+}
+
+/// `FOLLOWER#` INSERT. Each follow writes a Follower and a Following row;
+/// only the Follower row is routed here, so one follow counts once for
+/// each side.
+pub async fn handle_follow_badge(follow: crate::common::models::auth::UserFollow) -> Result<()> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let quest = follow
+        .action_id
+        .as_deref()
+        .map(|id| !id.is_empty())
+        .unwrap_or(false);
+    // Both sides key on the Follower row; markers are per user, so they
+    // don't collide.
+    let source_id = format!("{}#{}", follow.pk, follow.sk);
+    record_badge_event(
+        cli,
+        &follow.user_pk,
+        BadgeEvent::Followed { quest },
+        &source_id,
+        follow.created_at,
+    )
+    .await?;
+    record_badge_event(
+        cli,
+        &follow.target_user_pk,
+        BadgeEvent::GainedFollower,
+        &source_id,
+        follow.created_at,
+    )
+    .await?;
+    Ok(())
+}
+
+/// SpaceStatusChangeEvent INSERT — credits the space creator when their
+/// space starts (Curator / Architect) or finishes (Foundation).
+pub async fn handle_space_status_badge(
+    event: crate::common::models::space::SpaceStatusChangeEvent,
+) -> Result<()> {
+    let badge_event = match event.new_status {
+        SpaceStatus::Ongoing => BadgeEvent::SpaceWentOngoing,
+        SpaceStatus::Finished => BadgeEvent::SpaceFinished { participants: 0 },
+        _ => return Ok(()),
+    };
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let Some(space) = crate::common::models::space::SpaceCommon::get(
+        cli,
+        &event.space_pk,
+        Some(EntityType::SpaceCommon),
+    )
+    .await?
+    else {
+        tracing::warn!(space_pk = %event.space_pk, "SpaceStatusBadge: space not found, skipping");
+        return Ok(());
+    };
+
+    let badge_event = match badge_event {
+        BadgeEvent::SpaceFinished { .. } => BadgeEvent::SpaceFinished {
+            participants: space.participants,
+        },
+        other => other,
+    };
+    record_badge_event(
+        cli,
+        &space.user_pk,
+        badge_event,
+        &format!("{}#{}", event.pk, event.sk),
+        event.created_at,
+    )
+    .await?;
+    Ok(())
+}
+
+/// User INSERT — only windowed badges (Founding Voice) read it.
+pub async fn handle_user_created_badge(user: crate::common::models::auth::User) -> Result<()> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    record_badge_event(
+        cli,
+        &user.pk,
+        BadgeEvent::AccountCreated,
+        &format!("{}#{}", user.pk, user.sk),
+        user.created_at,
+    )
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,7 @@
+mod badge_evaluator;
+#[cfg(feature = "server")]
+mod badge_sources;
+
+pub use badge_evaluator::*;
+#[cfg(feature = "server")]
+pub use badge_sources::*;
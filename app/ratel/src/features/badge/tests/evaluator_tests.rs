@@ -0,0 +1,326 @@
+use crate::common::models::notification::UserInboxNotification;
+use crate::common::types::*;
+use crate::features::activity::models::UserActivityStreak;
+use crate::features::badge::models::{UserBadge, UserBadgeProgress};
+use crate::features::badge::services::*;
+use crate::features::badge::types::*;
+use crate::tests::TestContext;
+
+const DAY_MS: i64 = 86_400_000;
+const NOW_MS: i64 = 1_780_000_000_000;
+
+async fn badge_inbox_rows(
+    ctx: &TestContext,
+    user_pk: &Partition,
+) -> Vec<UserInboxNotification> {
+    let (rows, _) = UserInboxNotification::query(
+        &ctx.ddb,
+        user_pk.clone(),
+        UserInboxNotification::opt().sk("USER_INBOX_NOTIFICATION".to_string()),
+    )
+    .await
+    .unwrap();
+    rows.into_iter()
+        .filter(|r| r.kind == InboxKind::BadgeAwarded)
+        .collect()
+}
+
+#[test]
+fn satisfied_badges_only_reads_event_metrics() {
+    let progress = UserBadgeProgress {
+        spaces_joined: 1,
+        poll_votes_cast: 100,
+        ..Default::default()
+    };
+    let ids: Vec<BadgeId> = satisfied_badges(&progress, &[BadgeMetric::SpacesJoined], NOW_MS)
+        .into_iter()
+        .map(|d| d.id)
+        .collect();
+    assert_eq!(ids, vec![BadgeId::FirstSteps]);
+
+    let ids: Vec<BadgeId> = satisfied_badges(&progress, &[BadgeMetric::PollVotesCast], NOW_MS)
+        .into_iter()
+        .map(|d| d.id)
+        .collect();
+    assert!(ids.contains(&BadgeId::Kingmaker));
+    assert!(!ids.contains(&BadgeId::FirstSteps));
+}
+
+#[test]
+fn coming_soon_badges_never_satisfy() {
+    let progress = UserBadgeProgress {
+        spaces_joined: i64::MAX,
+        ..Default::default()
+    };
+    let all: Vec<BadgeMetric> = BADGE_CATALOG.iter().filter_map(|d| d.metric()).collect();
+    assert!(satisfied_badges(&progress, &all, NOW_MS)
+        .iter()
+        .all(|d| d.criterion != BadgeCriterion::ComingSoon));
+}
+
+#[tokio::test]
+async fn test_first_join_awards_first_steps_and_notifies_once() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &user_pk,
+        BadgeEvent::SpaceJoined,
+        "join#1",
+        NOW_MS,
+    )
+    .await
+    .unwrap();
+    assert_eq!(awarded, vec![BadgeId::FirstSteps]);
+
+    let (pk, sk) = UserBadge::keys(&user_pk, BadgeId::FirstSteps);
+    let row = UserBadge::get(&ctx.ddb, &pk, Some(&sk))
+        .await
+        .unwrap()
+        .expect("badge row written");
+    assert_eq!(row.progress_at_award, 1);
+    assert_eq!(badge_inbox_rows(&ctx, &user_pk).await.len(), 1);
+
+    // A second join moves the counter but never re-awards or re-notifies.
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &user_pk,
+        BadgeEvent::SpaceJoined,
+        "join#2",
+        NOW_MS,
+    )
+    .await
+    .unwrap();
+    assert!(awarded.is_empty());
+    assert_eq!(badge_inbox_rows(&ctx, &user_pk).await.len(), 1);
+
+    let progress = UserBadgeProgress::get_or_default(&ctx.ddb, &user_pk)
+        .await
+        .unwrap();
+    assert_eq!(progress.spaces_joined, 2);
+}
+
+#[tokio::test]
+async fn test_replayed_source_event_counts_once() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+
+    for _ in 0..3 {
+        record_badge_event(&ctx.ddb, &user_pk, BadgeEvent::PollVoted, "vote#1", NOW_MS)
+            .await
+            .unwrap();
+    }
+
+    let progress = UserBadgeProgress::get_or_default(&ctx.ddb, &user_pk)
+        .await
+        .unwrap();
+    assert_eq!(progress.poll_votes_cast, 1);
+}
+
+#[tokio::test]
+async fn test_award_badge_is_idempotent() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+    let def = BadgeId::Scholar.def();
+
+    assert!(award_badge(&ctx.ddb, &user_pk, def, 10, true).await.unwrap());
+    assert!(!award_badge(&ctx.ddb, &user_pk, def, 11, true).await.unwrap());
+    assert_eq!(badge_inbox_rows(&ctx, &user_pk).await.len(), 1);
+}
+
+#[tokio::test]
+async fn test_backfill_never_revokes_or_lowers_counters() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+
+    record_badge_event(
+        &ctx.ddb,
+        &user_pk,
+        BadgeEvent::SpaceJoined,
+        "join#1",
+        NOW_MS,
+    )
+    .await
+    .unwrap();
+
+    // A snapshot that undercounts (e.g. the participant row was deleted
+    // after leaving) must not pull the counter down or remove the badge.
+    let awarded = backfill_user_badges(&ctx.ddb, &user_pk, &UserBadgeProgress::default())
+        .await
+        .unwrap();
+    assert!(awarded.is_empty());
+
+    let progress = UserBadgeProgress::get_or_default(&ctx.ddb, &user_pk)
+        .await
+        .unwrap();
+    assert_eq!(progress.spaces_joined, 1);
+    let (pk, sk) = UserBadge::keys(&user_pk, BadgeId::FirstSteps);
+    assert!(UserBadge::get(&ctx.ddb, &pk, Some(&sk))
+        .await
+        .unwrap()
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_backfill_awards_silently() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+
+    let snapshot = UserBadgeProgress {
+        poll_votes_cast: 120,
+        ..Default::default()
+    };
+    let awarded = backfill_user_badges(&ctx.ddb, &user_pk, &snapshot)
+        .await
+        .unwrap();
+    assert!(awarded.contains(&BadgeId::Kingmaker));
+    assert!(badge_inbox_rows(&ctx, &user_pk).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_founding_voice_respects_window() {
+    let ctx = TestContext::setup().await;
+    let before = ctx.test_user.0.pk.clone();
+    let inside = crate::tests::create_test_user(&ctx.ddb).await.pk;
+
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &before,
+        BadgeEvent::AccountCreated,
+        &before.to_string(),
+        FOUNDING_WINDOW_FROM_MS - DAY_MS,
+    )
+    .await
+    .unwrap();
+    assert!(awarded.is_empty(), "sign-up before the window never qualifies");
+
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &inside,
+        BadgeEvent::AccountCreated,
+        &inside.to_string(),
+        FOUNDING_WINDOW_FROM_MS + DAY_MS,
+    )
+    .await
+    .unwrap();
+    assert_eq!(awarded, vec![BadgeId::FoundingVoice]);
+}
+
+#[tokio::test]
+async fn test_streak_awards_marathoner_at_thirty_days() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &user_pk,
+        BadgeEvent::StreakAdvanced { longest: 29 },
+        "activity#29",
+        NOW_MS,
+    )
+    .await
+    .unwrap();
+    assert!(awarded.is_empty());
+
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &user_pk,
+        BadgeEvent::StreakAdvanced { longest: 30 },
+        "activity#30",
+        NOW_MS + DAY_MS,
+    )
+    .await
+    .unwrap();
+    assert_eq!(awarded, vec![BadgeId::Marathoner]);
+
+    let progress = UserBadgeProgress::get_or_default(&ctx.ddb, &user_pk)
+        .await
+        .unwrap();
+    assert_eq!(progress.longest_streak_days, 30);
+}
+
+#[tokio::test]
+async fn test_backfill_reads_streak_from_activity_ledger() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+
+    let (pk, sk) = UserActivityStreak::keys(&user_pk);
+    UserActivityStreak::updater(&pk, &sk)
+        .with_longest_streak_days(30)
+        .execute(&ctx.ddb)
+        .await
+        .unwrap();
+
+    let awarded = backfill_user_badges(&ctx.ddb, &user_pk, &UserBadgeProgress::default())
+        .await
+        .unwrap();
+    assert!(awarded.contains(&BadgeId::Marathoner));
+}
+
+#[tokio::test]
+async fn test_team_partitions_are_ignored() {
+    let ctx = TestContext::setup().await;
+    let team_pk = Partition::Team(uuid::Uuid::new_v4().to_string());
+
+    let awarded = record_badge_event(
+        &ctx.ddb,
+        &team_pk,
+        BadgeEvent::SpaceJoined,
+        "join#1",
+        NOW_MS,
+    )
+    .await
+    .unwrap();
+    assert!(awarded.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_my_badges_requires_auth() {
+    let ctx = TestContext::setup().await;
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/me/badges",
+    };
+    assert_ne!(status, 200);
+}
+
+#[tokio::test]
+async fn test_get_my_badges_brand_new_user() {
+    let ctx = TestContext::setup().await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/me/badges",
+        headers: ctx.test_user.1.clone(),
+        response_type: crate::features::badge::dto::BadgeVaultResponse,
+    };
+    assert_eq!(status, 200, "brand new user: {:?}", body);
+    assert_eq!(body.earned_count, 0);
+    assert_eq!(body.total_count, BADGE_CATALOG.len() as i64);
+    assert_eq!(body.items.len(), BADGE_CATALOG.len());
+}
+
+#[tokio::test]
+async fn test_get_user_badges_hides_progress() {
+    let ctx = TestContext::setup().await;
+    let user = ctx.test_user.0.clone();
+    record_badge_event(
+        &ctx.ddb,
+        &user.pk,
+        BadgeEvent::SpaceJoined,
+        "join#1",
+        NOW_MS,
+    )
+    .await
+    .unwrap();
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/users/{}/badges", user.username),
+        response_type: crate::features::badge::dto::BadgeVaultResponse,
+    };
+    assert_eq!(status, 200, "{:?}", body);
+    assert_eq!(body.earned_count, 1);
+    assert_eq!(body.items.len(), 1);
+    assert!(body.items.iter().all(|b| b.progress.is_none()));
+}
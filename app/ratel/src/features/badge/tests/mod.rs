@@ -0,0 +1,8 @@
+//! Feature-local tests. Declared from `features/badge/mod.rs` under
+//! `#[cfg(test)] mod tests;` so they compile only for `cargo test`.
+//!
+//! Layout:
+//! - `evaluator_tests` pure criterion checks + record_badge_event
+//!   against DynamoDB Local + GET /api/me/badges
+
+mod evaluator_tests;
@@ -0,0 +1,437 @@
+//! Code-resident badge catalog (roadmap/badge.md FR1–FR3). Names,
+//! rarities and criterion text follow
+//! `app/ratel/assets/design/badge/badges.html`.
+//!
+//! Adding a badge = one `BadgeId` variant + one [`BADGE_CATALOG`] row.
+//! The evaluator reads `criterion` only, so a new badge over an existing
+//! metric needs no service change.
+
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Stable badge identifier. The `snake_case` form is stored in
+/// `EntityType::UserBadge(...)` and sent over the wire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum BadgeId {
+    // Participation
+    #[default]
+    FirstSteps,
+    ActiveVoice,
+    Kingmaker,
+    Legislator,
+    PrereqMaster,
+    Deliberator,
+    // Creator
+    Curator,
+    Architect,
+    QuestDesigner,
+    Foundation,
+    // Social
+    Connector,
+    CommunityPillar,
+    VoiceOfReason,
+    MindMeld,
+    // Achievement
+    Scholar,
+    RewardHunter,
+    TrailBlazer,
+    Marathoner,
+    // Special
+    FoundingVoice,
+    EarlyBird,
+    SignalBoost,
+    BetaPilot,
+}
+
+impl BadgeId {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BadgeId::FirstSteps => "first_steps",
+            BadgeId::ActiveVoice => "active_voice",
+            BadgeId::Kingmaker => "kingmaker",
+            BadgeId::Legislator => "legislator",
+            BadgeId::PrereqMaster => "prereq_master",
+            BadgeId::Deliberator => "deliberator",
+            BadgeId::Curator => "curator",
+            BadgeId::Architect => "architect",
+            BadgeId::QuestDesigner => "quest_designer",
+            BadgeId::Foundation => "foundation",
+            BadgeId::Connector => "connector",
+            BadgeId::CommunityPillar => "community_pillar",
+            BadgeId::VoiceOfReason => "voice_of_reason",
+            BadgeId::MindMeld => "mind_meld",
+            BadgeId::Scholar => "scholar",
+            BadgeId::RewardHunter => "reward_hunter",
+            BadgeId::TrailBlazer => "trail_blazer",
+            BadgeId::Marathoner => "marathoner",
+            BadgeId::FoundingVoice => "founding_voice",
+            BadgeId::EarlyBird => "early_bird",
+            BadgeId::SignalBoost => "signal_boost",
+            BadgeId::BetaPilot => "beta_pilot",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        BADGE_CATALOG
+            .iter()
+            .map(|d| d.id)
+            .find(|id| id.as_str() == s)
+    }
+
+    pub fn def(&self) -> &'static BadgeDef {
+        BADGE_CATALOG
+            .iter()
+            .find(|d| d.id == *self)
+            .expect("every BadgeId has a catalog row")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum BadgeCategory {
+    #[default]
+    Participation,
+    Creator,
+    Social,
+    Achievement,
+    Special,
+}
+
+impl BadgeCategory {
+    pub const ALL: [BadgeCategory; 5] = [
+        BadgeCategory::Participation,
+        BadgeCategory::Creator,
+        BadgeCategory::Social,
+        BadgeCategory::Achievement,
+        BadgeCategory::Special,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum BadgeRarity {
+    #[default]
+    Common,
+    Rare,
+    Legendary,
+}
+
+/// A per-user counter on `UserBadgeProgress` that criteria read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum BadgeMetric {
+    SpacesJoined,
+    PollVotesCast,
+    QuizzesPerfect,
+    DiscussionComments,
+    Followings,
+    Followers,
+    FollowQuestsCompleted,
+    /// Spaces the user created that reached `Ongoing`.
+    SpacesCreatedOngoing,
+    /// Largest final participant count among the user's finished spaces.
+    PeakSpaceParticipants,
+    /// Longest run of consecutive UTC days with any participation.
+    LongestStreakDays,
+    /// Account creation. Carries no counter; only `Window` reads it.
+    AccountCreated,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeCriterion {
+    /// Awarded once the metric's counter reaches `threshold`.
+    Threshold { metric: BadgeMetric, threshold: i64 },
+    /// Awarded on a `metric` event stamped inside `[from_ms, to_ms)`.
+    /// Activity outside the window never qualifies (FR6).
+    Window {
+        metric: BadgeMetric,
+        from_ms: i64,
+        to_ms: i64,
+    },
+    /// Data source not available yet. Never evaluated; the vault shows
+    /// it locked with no progress.
+    ComingSoon,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BadgeDef {
+    pub id: BadgeId,
+    pub name: &'static str,
+    pub category: BadgeCategory,
+    pub rarity: BadgeRarity,
+    pub criterion_text: &'static str,
+    pub criterion: BadgeCriterion,
+}
+
+impl BadgeDef {
+    pub fn metric(&self) -> Option<BadgeMetric> {
+        match self.criterion {
+            BadgeCriterion::Threshold { metric, .. } | BadgeCriterion::Window { metric, .. } => {
+                Some(metric)
+            }
+            BadgeCriterion::ComingSoon => None,
+        }
+    }
+
+    /// `None` for windowed and coming-soon badges, which have no bar.
+    pub fn threshold(&self) -> Option<i64> {
+        match self.criterion {
+            BadgeCriterion::Threshold { threshold, .. } => Some(threshold),
+            _ => None,
+        }
+    }
+}
+
+/// Founding Voice launch window: the first 30 days after badges ship
+/// (2026-11-01T00:00Z .. 2026-12-01T00:00Z).
+pub const FOUNDING_WINDOW_FROM_MS: i64 = 1_793_491_200_000;
+pub const FOUNDING_WINDOW_TO_MS: i64 = 1_796_083_200_000;
+
+pub const BADGE_CATALOG: &[BadgeDef] = &[
+    // ── Participation ──
+    BadgeDef {
+        id: BadgeId::FirstSteps,
+        name: "First Steps",
+        category: BadgeCategory::Participation,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Join your first space and complete a prerequisite.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::SpacesJoined,
+            threshold: 1,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::ActiveVoice,
+        name: "Active Voice",
+        category: BadgeCategory::Participation,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Participate in 5 different spaces.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::SpacesJoined,
+            threshold: 5,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::Kingmaker,
+        name: "Kingmaker",
+        category: BadgeCategory::Participation,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Cast 100 poll votes across any spaces.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::PollVotesCast,
+            threshold: 100,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::Legislator,
+        name: "Legislator",
+        category: BadgeCategory::Participation,
+        rarity: BadgeRarity::Legendary,
+        criterion_text: "Participate in 100 spaces and pass the prerequisite in each.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::SpacesJoined,
+            threshold: 100,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::PrereqMaster,
+        name: "Prereq Master",
+        category: BadgeCategory::Participation,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Pass 10 space prerequisites within 1 hour of joining.",
+        criterion: BadgeCriterion::ComingSoon,
+    },
+    BadgeDef {
+        id: BadgeId::Deliberator,
+        name: "Deliberator",
+        category: BadgeCategory::Participation,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Participate in 20 different spaces.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::SpacesJoined,
+            threshold: 20,
+        },
+    },
+    // ── Creator ──
+    BadgeDef {
+        id: BadgeId::Curator,
+        name: "Curator",
+        category: BadgeCategory::Creator,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Create your first space with at least 1 action.",
+        // A space can only start once it has an action, so "reached
+        // Ongoing" is the observable proxy.
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::SpacesCreatedOngoing,
+            threshold: 1,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::Architect,
+        name: "Architect",
+        category: BadgeCategory::Creator,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Create 5 spaces that reach \"Ongoing\" status.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::SpacesCreatedOngoing,
+            threshold: 5,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::QuestDesigner,
+        name: "Quest Designer",
+        category: BadgeCategory::Creator,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Publish a space with all 4 action types (poll, discussion, quiz, follow).",
+        criterion: BadgeCriterion::ComingSoon,
+    },
+    BadgeDef {
+        id: BadgeId::Foundation,
+        name: "Foundation",
+        category: BadgeCategory::Creator,
+        rarity: BadgeRarity::Legendary,
+        criterion_text: "Have a space finish with 500+ participants.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::PeakSpaceParticipants,
+            threshold: 500,
+        },
+    },
+    // ── Social ──
+    BadgeDef {
+        id: BadgeId::Connector,
+        name: "Connector",
+        category: BadgeCategory::Social,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Follow 10 other citizens on Ratel.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::Followings,
+            threshold: 10,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::CommunityPillar,
+        name: "Community Pillar",
+        category: BadgeCategory::Social,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Reach 1,000 followers.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::Followers,
+            threshold: 1_000,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::VoiceOfReason,
+        name: "Voice of Reason",
+        category: BadgeCategory::Social,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Leave 10 substantive comments in discussion actions.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::DiscussionComments,
+            threshold: 10,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::MindMeld,
+        name: "Mind Meld",
+        category: BadgeCategory::Social,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Reach 100 discussion comments across any spaces.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::DiscussionComments,
+            threshold: 100,
+        },
+    },
+    // ── Achievement ──
+    BadgeDef {
+        id: BadgeId::Scholar,
+        name: "Scholar",
+        category: BadgeCategory::Achievement,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Score 100% on 10 quiz actions.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::QuizzesPerfect,
+            threshold: 10,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::RewardHunter,
+        name: "Reward Hunter",
+        category: BadgeCategory::Achievement,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Accumulate 50,000 CR across space rewards.",
+        criterion: BadgeCriterion::ComingSoon,
+    },
+    BadgeDef {
+        id: BadgeId::TrailBlazer,
+        name: "Trail Blazer",
+        category: BadgeCategory::Achievement,
+        rarity: BadgeRarity::Common,
+        criterion_text: "Complete 10 follow quest actions.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::FollowQuestsCompleted,
+            threshold: 10,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::Marathoner,
+        name: "Marathoner",
+        category: BadgeCategory::Achievement,
+        rarity: BadgeRarity::Legendary,
+        criterion_text: "Participate in at least one space every day for 30 days.",
+        criterion: BadgeCriterion::Threshold {
+            metric: BadgeMetric::LongestStreakDays,
+            threshold: 30,
+        },
+    },
+    // ── Special ──
+    BadgeDef {
+        id: BadgeId::FoundingVoice,
+        name: "Founding Voice",
+        category: BadgeCategory::Special,
+        rarity: BadgeRarity::Legendary,
+        criterion_text: "Joined Ratel within the first 30 days of mainnet launch. Non-repeatable.",
+        criterion: BadgeCriterion::Window {
+            metric: BadgeMetric::AccountCreated,
+            from_ms: FOUNDING_WINDOW_FROM_MS,
+            to_ms: FOUNDING_WINDOW_TO_MS,
+        },
+    },
+    BadgeDef {
+        id: BadgeId::EarlyBird,
+        name: "Early Bird",
+        category: BadgeCategory::Special,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Join a space within 1 hour of its publication.",
+        criterion: BadgeCriterion::ComingSoon,
+    },
+    BadgeDef {
+        id: BadgeId::SignalBoost,
+        name: "Signal Boost",
+        category: BadgeCategory::Special,
+        rarity: BadgeRarity::Legendary,
+        criterion_text: "Have one of your posts featured on the home arena Hot list.",
+        criterion: BadgeCriterion::ComingSoon,
+    },
+    BadgeDef {
+        id: BadgeId::BetaPilot,
+        name: "Beta Pilot",
+        category: BadgeCategory::Special,
+        rarity: BadgeRarity::Rare,
+        criterion_text: "Submit 3 bug reports or feedback items during mainnet beta.",
+        criterion: BadgeCriterion::ComingSoon,
+    },
+];
+
+/// Every badge whose criterion reads `metric`, in catalog order.
+pub fn badges_for_metric(metric: BadgeMetric) -> impl Iterator<Item = &'static BadgeDef> {
+    BADGE_CATALOG
+        .iter()
+        .filter(move |d| d.metric() == Some(metric))
+}
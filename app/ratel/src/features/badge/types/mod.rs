@@ -0,0 +1,2 @@
+mod catalog;
+pub use catalog::*;
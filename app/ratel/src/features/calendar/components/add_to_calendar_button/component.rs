@@ -0,0 +1,42 @@
+use crate::features::calendar::*;
+use crate::*;
+
+/// Downloads the action as an `.ics` file the user can open in any
+/// calendar app.
+#[component]
+pub fn AddToCalendarButton(space_id: ReadSignal<SpacePartition>, action_id: String) -> Element {
+    let tr: AddToCalendarTranslate = use_translate();
+    let mut toast = use_toast();
+    let mut pending = use_signal(|| false);
+
+    let onclick = move |_| {
+        let action_id = action_id.clone();
+        spawn(async move {
+            pending.set(true);
+            let result = async {
+                let file = get_action_calendar(space_id(), action_id).await?;
+                download_ics(&file).await
+            }
+            .await;
+            if let Err(e) = result {
+                toast.error(e);
+            }
+            pending.set(false);
+        });
+    };
+
+    rsx! {
+        Button {
+            style: ButtonStyle::Secondary,
+            size: ButtonSize::Small,
+            disabled: pending(),
+            "data-testid": "add-to-calendar",
+            onclick,
+            if pending() {
+                "{tr.downloading}"
+            } else {
+                "{tr.add_to_calendar}"
+            }
+        }
+    }
+}
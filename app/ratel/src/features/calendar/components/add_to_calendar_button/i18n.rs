@@ -0,0 +1,8 @@
+use crate::*;
+
+translate! {
+    AddToCalendarTranslate;
+
+    add_to_calendar: { en: "Add to calendar", ko: "캘린더에 추가" },
+    downloading: { en: "Preparing…", ko: "준비 중…" },
+}
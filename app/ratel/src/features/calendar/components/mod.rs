@@ -0,0 +1,3 @@
+pub mod add_to_calendar_button;
+
+pub use add_to_calendar_button::*;
@@ -0,0 +1,39 @@
+use crate::features::calendar::*;
+use crate::*;
+
+/// Whether the logged-in user has a calendar feed token. The raw token is
+/// never returned here; it is only available right after generation.
+#[get("/api/me/calendar-feed", user: crate::features::auth::User)]
+pub async fn get_calendar_feed_secret_handler() -> Result<CalendarFeedSecretResponse> {
+    let conf = crate::common::config::ServerConfig::default();
+    let cli = conf.dynamodb();
+
+    let existing =
+        CalendarFeedSecret::get(cli, &user.pk, Some(EntityType::CalendarFeedSecret)).await?;
+
+    Ok(CalendarFeedSecretResponse {
+        secret: None,
+        has_secret: existing.is_some(),
+    })
+}
+
+/// Generate or rotate the feed token. Rotating revokes the old feed URL.
+#[post("/api/me/calendar-feed/regenerate", user: crate::features::auth::User)]
+pub async fn regenerate_calendar_feed_secret_handler() -> Result<CalendarFeedSecretResponse> {
+    let conf = crate::common::config::ServerConfig::default();
+    let cli = conf.dynamodb();
+
+    let existing =
+        CalendarFeedSecret::get(cli, &user.pk, Some(EntityType::CalendarFeedSecret)).await?;
+    if existing.is_some() {
+        CalendarFeedSecret::delete(cli, &user.pk, Some(EntityType::CalendarFeedSecret)).await?;
+    }
+
+    let (secret, raw_token) = CalendarFeedSecret::new(user.pk.clone());
+    secret.create(cli).await?;
+
+    Ok(CalendarFeedSecretResponse {
+        secret: Some(raw_token),
+        has_secret: true,
+    })
+}
@@ -0,0 +1,69 @@
+use crate::features::calendar::*;
+use crate::*;
+#[cfg(feature = "server")]
+use crate::features::posts::models::Post;
+#[cfg(feature = "server")]
+use crate::features::spaces::pages::actions::models::SpaceAction;
+#[cfg(feature = "server")]
+use crate::features::spaces::pages::actions::types::SpaceActionStatus;
+
+#[mcp_tool(
+    name = "get_action_calendar",
+    description = "Export a space action as an iCalendar (.ics) file. Meets export their time slot; other actions export the space deadline. Cancelled meets carry STATUS:CANCELLED."
+)]
+#[get("/api/spaces/{space_id}/actions/{action_id}/calendar", role: SpaceUserRole, space: crate::common::models::space::SpaceCommon)]
+pub async fn get_action_calendar(
+    #[mcp(description = "Space partition key")] space_id: SpacePartition,
+    #[mcp(description = "Action id (e.g. 'SpaceMeet#<uuid>' or the poll id)")] action_id: String,
+) -> Result<ActionCalendarResponse> {
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+
+    let action = SpaceAction::get(
+        cli,
+        &CompositePartition(space_id.clone(), action_id.clone()),
+        Some(EntityType::SpaceAction),
+    )
+    .await?
+    .ok_or(Error::SpaceActionNotFound)?;
+    // Drafts are only visible to the creator.
+    if action.status == Some(SpaceActionStatus::Designing) && role != SpaceUserRole::Creator {
+        return Err(Error::SpaceActionNotFound);
+    }
+
+    let post_pk = space.pk.clone().to_post_key()?;
+    let space_title = Post::get(cli, &post_pk, Some(&EntityType::Post))
+        .await?
+        .map(|post| post.title)
+        .unwrap_or_default();
+
+    let events = action_calendar_events(cli, &space, &space_title, &action).await?;
+    if events.is_empty() {
+        return Err(CalendarError::NoSchedule.into());
+    }
+
+    let name = if action.title.is_empty() {
+        space_title
+    } else {
+        action.title.clone()
+    };
+    Ok(ActionCalendarResponse {
+        file_name: format!("{}.ics", ics_file_stem(&name)),
+        content: render_calendar(&name, &events),
+    })
+}
+
+/// `Weekly sync: Q3` -> `weekly-sync-q3`; falls back to `event`.
+pub fn ics_file_stem(name: &str) -> String {
+    let stem = name
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-");
+    if stem.is_empty() {
+        "event".to_string()
+    } else {
+        stem.chars().take(64).collect()
+    }
+}
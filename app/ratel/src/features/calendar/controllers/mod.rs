@@ -0,0 +1,5 @@
+mod calendar_feed_secret;
+mod get_action_calendar;
+
+pub use calendar_feed_secret::*;
+pub use get_action_calendar::*;
@@ -0,0 +1,22 @@
+//! JS interop for calendar export. `dx_eval` is a no-op outside web, so
+//! no per-target cfg gates are needed at the call site.
+
+use dioxus::document::eval as dx_eval;
+
+use crate::features::calendar::*;
+use crate::*;
+
+pub async fn download_ics(file: &ActionCalendarResponse) -> Result<()> {
+    let mut runner = dx_eval(include_str!("web/download_ics.js"));
+    runner
+        .send(serde_json::to_value(file).map_err(|_| CalendarError::DownloadFailed)?)
+        .map_err(|_| CalendarError::DownloadFailed)?;
+    let ok = runner
+        .recv::<Option<bool>>()
+        .await
+        .map_err(|_| CalendarError::DownloadFailed)?;
+    match ok {
+        Some(true) => Ok(()),
+        _ => Err(CalendarError::DownloadFailed.into()),
+    }
+}
@@ -0,0 +1,21 @@
+// Saves a rendered `.ics` body as a file. Receives
+// `{ file_name, content }` (an `ActionCalendarResponse`) from Rust.
+//
+// Returns `true` on success; sends `null` on a thrown error so Rust can
+// surface a typed `CalendarError::DownloadFailed`.
+const req = await dioxus.recv();
+try {
+  const blob = new Blob([req.content], { type: "text/calendar;charset=utf-8" });
+  const url = URL.createObjectURL(blob);
+  const a = document.createElement("a");
+  a.href = url;
+  a.download = req.file_name;
+  document.body.appendChild(a);
+  a.click();
+  a.remove();
+  setTimeout(() => URL.revokeObjectURL(url), 0);
+  dioxus.send(true);
+} catch (e) {
+  console.error("downloadIcs failed", e);
+  dioxus.send(null);
+}
@@ -0,0 +1,16 @@
+pub mod components;
+pub mod controllers;
+pub mod interop;
+pub mod models;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "server")]
+pub mod services;
+pub mod types;
+
+pub use components::*;
+pub use controllers::*;
+pub use models::*;
+#[cfg(feature = "server")]
+pub use services::*;
+pub use types::*;
@@ -0,0 +1,61 @@
+use crate::common::*;
+
+/// Per-user token that authenticates the subscribable calendar feed.
+///
+/// Calendar clients poll the feed URL without cookies, so the token sits in
+/// the path. Like `McpClientSecret`, only a SHA-256 hash is stored and the
+/// raw token is shown once at generation time.
+///
+/// - pk: USER#<user_id>
+/// - sk: CalendarFeedSecret
+/// - gsi1 pk: CFS#<hashed_secret> (for lookup by hashed secret)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+pub struct CalendarFeedSecret {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    #[dynamo(prefix = "CFS", name = "find_by_secret", index = "gsi1", pk)]
+    pub secret: String,
+
+    #[dynamo(index = "gsi1", sk)]
+    pub created_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl CalendarFeedSecret {
+    /// Returns `(entity, raw_token)`; the entity stores only the hash.
+    pub fn new(user_pk: Partition) -> (Self, String) {
+        use base64::Engine;
+
+        let mut buf = [0u8; 32];
+        rand::fill(&mut buf);
+        let raw_token = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(buf);
+
+        let entity = Self {
+            pk: user_pk,
+            sk: EntityType::CalendarFeedSecret,
+            secret: Self::hash_secret(&raw_token),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+
+        (entity, raw_token)
+    }
+
+    pub fn hash_secret(raw_token: &str) -> String {
+        use base64::Engine;
+        use sha2::Digest;
+
+        let hash = sha2::Sha256::digest(raw_token.as_bytes());
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(hash)
+    }
+
+    /// Resolve a raw token to its owner.
+    pub async fn find_owner(
+        cli: &aws_sdk_dynamodb::Client,
+        raw_token: &str,
+    ) -> Result<Option<Partition>> {
+        let hashed = Self::hash_secret(raw_token);
+        let (rows, _) = Self::find_by_secret(cli, &hashed, Self::opt().limit(1)).await?;
+        Ok(rows.into_iter().next().map(|row| row.pk))
+    }
+}
@@ -0,0 +1,3 @@
+mod calendar_feed_secret;
+
+pub use calendar_feed_secret::*;
@@ -0,0 +1,49 @@
+//! Subscribable calendar feed. Calendar clients (Google, Outlook, Apple)
+//! poll without cookies, so the route is unauthenticated and resolves the
+//! user from the hashed token in the path instead.
+
+use crate::common::axum::{
+    Router,
+    extract::Path,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use crate::features::calendar::*;
+
+pub fn router() -> Router {
+    Router::new().route("/api/calendar/feed/{token}", get(calendar_feed))
+}
+
+async fn calendar_feed(Path(token): Path<String>) -> Response {
+    // Clients insist on a `.ics` suffix; accept the URL with or without it.
+    let token = token.strip_suffix(".ics").unwrap_or(&token);
+    let conf = crate::common::CommonConfig::default();
+    let cli = conf.dynamodb();
+
+    let user_pk = match CalendarFeedSecret::find_owner(cli, token).await {
+        Ok(Some(pk)) => pk,
+        Ok(None) => return CalendarError::FeedNotFound.into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "calendar feed: token lookup failed");
+            return CalendarError::ExportFailed.into_response();
+        }
+    };
+
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    match user_calendar_events(cli, &user_pk, now).await {
+        Ok(events) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (header::CACHE_CONTROL, "private, max-age=900"),
+            ],
+            render_calendar("Ratel", &events),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!(user_pk = %user_pk, error = %e, "calendar feed: build failed");
+            CalendarError::ExportFailed.into_response()
+        }
+    }
+}
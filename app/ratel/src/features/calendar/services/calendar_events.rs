@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+
+use crate::common::models::space::{SpaceCommon, SpaceParticipant};
+use crate::features::calendar::*;
+use crate::*;
+use crate::features::posts::models::Post;
+use crate::features::spaces::pages::actions::actions::meet::{
+    MeetStatus, SpaceMeet, SpaceMeetEntityType,
+};
+use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::pages::actions::types::{SpaceActionStatus, SpaceActionType};
+
+/// Feed events that ended longer ago than this are dropped; calendar
+/// clients keep their own copy and re-fetching history is wasted reads.
+pub const FEED_LOOKBACK_MS: i64 = 30 * 24 * 60 * 60 * 1000;
+/// Upper bound on spaces scanned for one feed.
+const MAX_FEED_SPACES: usize = 100;
+const BATCH_GET_LIMIT: usize = 100;
+
+/// A meet's slot. Cancelled and expired meets stay in the feed as
+/// `STATUS:CANCELLED` so subscribed clients strike them out instead of
+/// silently keeping a stale invite.
+pub fn meet_event(meet: &SpaceMeet, action: &SpaceAction, space_title: &str) -> IcsEvent {
+    let updated_at = meet.updated_at.max(action.updated_at);
+    let starts_at = meet.start_time;
+    let planned_end = starts_at + i64::from(meet.duration_min.max(0)) * 60 * 1000;
+    let ends_at = match meet.status {
+        MeetStatus::Ended => meet.ended_at.unwrap_or(planned_end),
+        _ => planned_end,
+    };
+    let status = match meet.status {
+        MeetStatus::Cancelled | MeetStatus::Expired => IcsStatus::Cancelled,
+        _ => IcsStatus::Confirmed,
+    };
+
+    IcsEvent {
+        uid: event_uid("meet", &action.pk.1),
+        sequence: ics_sequence(meet.created_at, updated_at),
+        status,
+        starts_at,
+        ends_at: Some(ends_at),
+        updated_at,
+        summary: non_empty(&action.title, "Meet"),
+        description: describe(space_title, &action.description),
+        url: action.get_cta_url(),
+    }
+}
+
+/// Actions other than meets close with their space, so the deadline is
+/// the space's `ended_at`. `None` while the space has no end date.
+pub fn deadline_event(
+    action: &SpaceAction,
+    space: &SpaceCommon,
+    space_title: &str,
+) -> Option<IcsEvent> {
+    let deadline = space.ended_at?;
+    let updated_at = action.updated_at.max(space.updated_at);
+    let title = non_empty(&action.title, "Action");
+
+    Some(IcsEvent {
+        uid: event_uid("deadline", &format!("{}-{}", action.pk.0, action.pk.1)),
+        sequence: ics_sequence(action.created_at, updated_at),
+        status: IcsStatus::Confirmed,
+        starts_at: deadline,
+        ends_at: None,
+        updated_at,
+        summary: format!("{title} closes"),
+        description: describe(space_title, &action.description),
+        url: action.get_cta_url(),
+    })
+}
+
+pub fn space_end_event(space: &SpaceCommon, space_title: &str) -> Option<IcsEvent> {
+    let ended_at = space.ended_at?;
+    let space_id: SpacePartition = space.pk.clone().into();
+    let url = format!(
+        "{}{}",
+        crate::common::CommonConfig::default().env.web_endpoint(),
+        Route::SpaceIndexPage {
+            space_id: space_id.clone(),
+        }
+    );
+
+    Some(IcsEvent {
+        uid: event_uid("space-end", &space_id.to_string()),
+        sequence: ics_sequence(space.created_at, space.updated_at),
+        status: IcsStatus::Confirmed,
+        starts_at: ended_at,
+        ends_at: None,
+        updated_at: space.updated_at,
+        summary: format!("{} ends", non_empty(space_title, "Space")),
+        description: String::new(),
+        url,
+    })
+}
+
+/// Events for a single action's `.ics` download.
+pub async fn action_calendar_events(
+    cli: &aws_sdk_dynamodb::Client,
+    space: &SpaceCommon,
+    space_title: &str,
+    action: &SpaceAction,
+) -> Result<Vec<IcsEvent>> {
+    if action.space_action_type == SpaceActionType::Meet {
+        let meet_id: SpaceMeetEntityType = action.pk.1.clone().into();
+        let meet = SpaceMeet::get(cli, &space.pk, Some(EntityType::from(meet_id))).await?;
+        return Ok(meet
+            .map(|meet| vec![meet_event(&meet, action, space_title)])
+            .unwrap_or_default());
+    }
+    Ok(deadline_event(action, space, space_title)
+        .into_iter()
+        .collect())
+}
+
+/// Meets, poll deadlines and the end date of one space. Actions still
+/// being designed are not public yet and are skipped.
+pub async fn space_calendar_events(
+    cli: &aws_sdk_dynamodb::Client,
+    space: &SpaceCommon,
+    space_title: &str,
+) -> Result<Vec<IcsEvent>> {
+    let (actions, _) =
+        SpaceAction::find_by_space(cli, space.pk.clone(), SpaceAction::opt_all()).await?;
+
+    let mut events = Vec::new();
+    for action in actions {
+        if action.status == Some(SpaceActionStatus::Designing) {
+            continue;
+        }
+        match action.space_action_type {
+            SpaceActionType::Meet => {
+                events.extend(action_calendar_events(cli, space, space_title, &action).await?)
+            }
+            SpaceActionType::Poll => events.extend(deadline_event(&action, space, space_title)),
+            _ => {}
+        }
+    }
+    events.extend(space_end_event(space, space_title));
+    Ok(events)
+}
+
+/// The subscribable feed: every published space the user joined or
+/// created, oldest first, minus anything that ended before the lookback.
+pub async fn user_calendar_events(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    now: i64,
+) -> Result<Vec<IcsEvent>> {
+    let mut space_pks: Vec<Partition> = Vec::new();
+    let mut bookmark: Option<String> = None;
+    loop {
+        let opt = SpaceParticipant::opt_with_bookmark(bookmark.take()).limit(100);
+        let (rows, next) = SpaceParticipant::find_by_user(cli, user_pk, opt).await?;
+        for row in rows {
+            if !space_pks.contains(&row.space_pk) {
+                space_pks.push(row.space_pk);
+            }
+        }
+        match next {
+            Some(bk) if space_pks.len() < MAX_FEED_SPACES => bookmark = Some(bk),
+            _ => break,
+        }
+    }
+    let created_opt = SpaceCommon::opt()
+        .filter_sk_eq("SPACE_COMMON")
+        .limit(MAX_FEED_SPACES as i32);
+    let (created, _) = SpaceCommon::find_by_user_pk(cli, user_pk, created_opt).await?;
+    for space in &created {
+        if !space_pks.contains(&space.pk) {
+            space_pks.push(space.pk.clone());
+        }
+    }
+    space_pks.truncate(MAX_FEED_SPACES);
+
+    let mut spaces: Vec<SpaceCommon> = Vec::new();
+    for chunk in space_pks.chunks(BATCH_GET_LIMIT) {
+        let keys = chunk
+            .iter()
+            .map(|pk| (pk.clone(), EntityType::SpaceCommon))
+            .collect::<Vec<_>>();
+        spaces.extend(SpaceCommon::batch_get(cli, keys).await?);
+    }
+    spaces.retain(|s| s.publish_state == SpacePublishState::Published);
+
+    let mut titles: HashMap<String, String> = HashMap::new();
+    for chunk in spaces.chunks(BATCH_GET_LIMIT) {
+        let keys = chunk
+            .iter()
+            .map(|s| (s.post_pk.clone(), EntityType::Post))
+            .collect::<Vec<_>>();
+        for post in Post::batch_get(cli, keys).await? {
+            titles.insert(post.pk.to_string(), post.title);
+        }
+    }
+
+    let cutoff = now - FEED_LOOKBACK_MS;
+    let mut events = Vec::new();
+    for space in &spaces {
+        let title = titles.get(&space.post_pk.to_string()).cloned().unwrap_or_default();
+        match space_calendar_events(cli, space, &title).await {
+            Ok(found) => events.extend(
+                found
+                    .into_iter()
+                    .filter(|e| e.ends_at.unwrap_or(e.starts_at) >= cutoff),
+            ),
+            // One broken space must not take the whole feed down.
+            Err(e) => {
+                tracing::warn!(space_pk = %space.pk, error = %e, "calendar feed: skipped space")
+            }
+        }
+    }
+    events.sort_by_key(|e| e.starts_at);
+    Ok(events)
+}
+
+fn event_uid(kind: &str, id: &str) -> String {
+    let id: String = id
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '-' })
+        .collect();
+    format!("{kind}-{id}@{ICS_UID_DOMAIN}")
+}
+
+fn describe(space_title: &str, description: &str) -> String {
+    match (space_title.is_empty(), description.trim().is_empty()) {
+        (true, _) => description.trim().to_string(),
+        (false, true) => space_title.to_string(),
+        (false, false) => format!("{space_title}\n\n{}", description.trim()),
+    }
+}
+
+fn non_empty(value: &str, fallback: &str) -> String {
+    if value.trim().is_empty() {
+        fallback.to_string()
+    } else {
+        value.to_string()
+    }
+}
@@ -0,0 +1,3 @@
+mod calendar_events;
+
+pub use calendar_events::*;
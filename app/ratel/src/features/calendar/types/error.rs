@@ -0,0 +1,56 @@
+use crate::common::*;
+pub use thiserror::Error;
+
+#[derive(Debug, Error, Serialize, Deserialize, Translate, Clone)]
+pub enum CalendarError {
+    #[error("calendar feed not found")]
+    #[translate(en = "Calendar feed not found", ko = "캘린더 피드를 찾을 수 없습니다")]
+    FeedNotFound,
+
+    #[error("action has no date")]
+    #[translate(
+        en = "This action has no date to add to a calendar",
+        ko = "이 액션에는 캘린더에 추가할 날짜가 없습니다"
+    )]
+    NoSchedule,
+
+    #[error("calendar export failed")]
+    #[translate(en = "Failed to build the calendar", ko = "캘린더를 만들지 못했습니다")]
+    ExportFailed,
+
+    #[error("calendar download failed")]
+    #[translate(
+        en = "Failed to download the calendar file",
+        ko = "캘린더 파일을 내려받지 못했습니다"
+    )]
+    DownloadFailed,
+}
+
+#[cfg(feature = "server")]
+impl CalendarError {
+    pub fn status_code(&self) -> crate::axum::http::StatusCode {
+        use crate::axum::http::StatusCode;
+        match self {
+            CalendarError::FeedNotFound => StatusCode::NOT_FOUND,
+            CalendarError::NoSchedule => StatusCode::BAD_REQUEST,
+            CalendarError::ExportFailed | CalendarError::DownloadFailed => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::axum::response::IntoResponse for CalendarError {
+    fn into_response(self) -> crate::axum::response::Response {
+        use crate::axum::response::IntoResponse;
+        (self.status_code(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::AsStatusCode for CalendarError {
+    fn as_status_code(&self) -> crate::axum::http::StatusCode {
+        self.status_code()
+    }
+}
@@ -0,0 +1,130 @@
+use crate::common::*;
+
+/// Domain suffix for event `UID`s, which RFC 5545 wants globally unique.
+pub const ICS_UID_DOMAIN: &str = "ratel.foundation";
+const ICS_PRODID: &str = "-//Ratel//Space Calendar//EN";
+/// RFC 5545 §3.1: content lines SHOULD NOT exceed 75 octets.
+const ICS_LINE_LIMIT: usize = 75;
+
+/// RFC 5545 `STATUS` values for a `VEVENT`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub enum IcsStatus {
+    #[default]
+    Confirmed,
+    Tentative,
+    Cancelled,
+}
+
+impl IcsStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            IcsStatus::Confirmed => "CONFIRMED",
+            IcsStatus::Tentative => "TENTATIVE",
+            IcsStatus::Cancelled => "CANCELLED",
+        }
+    }
+}
+
+/// One exported event. Timestamps are epoch millis, rendered as UTC.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct IcsEvent {
+    pub uid: String,
+    /// Revision counter clients use to decide whether an update
+    /// supersedes their copy. See [`ics_sequence`].
+    pub sequence: i64,
+    pub status: IcsStatus,
+    pub starts_at: i64,
+    /// `None` renders a point-in-time event (deadlines).
+    pub ends_at: Option<i64>,
+    pub updated_at: i64,
+    pub summary: String,
+    pub description: String,
+    pub url: String,
+}
+
+/// `SEQUENCE` derived from the row's own timestamps: whole seconds between
+/// creation and the last update. Monotonic as long as `updated_at` only
+/// moves forward, so every edit supersedes what a client cached without us
+/// persisting a revision counter. Fits the 32-bit ICS integer for ~68 years.
+pub fn ics_sequence(created_at: i64, updated_at: i64) -> i64 {
+    ((updated_at - created_at) / 1000).max(0)
+}
+
+/// Render a `VCALENDAR` document with CRLF line endings and 75-octet folding.
+pub fn render_calendar(name: &str, events: &[IcsEvent]) -> String {
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".into(),
+        "VERSION:2.0".into(),
+        format!("PRODID:{ICS_PRODID}"),
+        "CALSCALE:GREGORIAN".into(),
+        "METHOD:PUBLISH".into(),
+        format!("X-WR-CALNAME:{}", escape_text(name)),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".into());
+        lines.push(format!("UID:{}", event.uid));
+        lines.push(format!("DTSTAMP:{}", format_utc(event.updated_at)));
+        lines.push(format!("LAST-MODIFIED:{}", format_utc(event.updated_at)));
+        lines.push(format!("SEQUENCE:{}", event.sequence));
+        lines.push(format!("STATUS:{}", event.status.as_str()));
+        lines.push(format!("DTSTART:{}", format_utc(event.starts_at)));
+        if let Some(ends_at) = event.ends_at.filter(|end| *end > event.starts_at) {
+            lines.push(format!("DTEND:{}", format_utc(ends_at)));
+        }
+        lines.push(format!("SUMMARY:{}", escape_text(&event.summary)));
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape_text(&event.description)));
+        }
+        if !event.url.is_empty() {
+            lines.push(format!("URL:{}", event.url));
+        }
+        lines.push("END:VEVENT".into());
+    }
+    lines.push("END:VCALENDAR".into());
+
+    let mut out = String::new();
+    for line in lines {
+        fold_line(&line, &mut out);
+    }
+    out
+}
+
+/// `20260102T030405Z`.
+pub fn format_utc(millis: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .unwrap_or_default()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+/// RFC 5545 §3.3.11 TEXT escaping.
+fn escape_text(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold at 75 octets without splitting a UTF-8 sequence; continuation
+/// lines start with a single space, which counts toward their limit.
+fn fold_line(line: &str, out: &mut String) {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > ICS_LINE_LIMIT {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+}
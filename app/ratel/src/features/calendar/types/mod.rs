@@ -0,0 +1,7 @@
+mod error;
+mod ics;
+mod response;
+
+pub use error::*;
+pub use ics::*;
+pub use response::*;
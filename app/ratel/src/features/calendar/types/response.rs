@@ -0,0 +1,22 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct CalendarFeedSecretResponse {
+    /// The raw feed token (only present immediately after generate/regenerate).
+    pub secret: Option<String>,
+    /// Whether a feed token has been generated for this user.
+    pub has_secret: bool,
+}
+
+/// A single-action `.ics` file, rendered server-side so the client only
+/// has to hand it to the browser as a download.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActionCalendarResponse {
+    pub file_name: String,
+    pub content: String,
+}
@@ -8,7 +8,7 @@ pub async fn level_up_handler(skill_id: String) -> Result<CharacterResponse> {
     use crate::features::character::models::{CharacterSkill, CharacterXp};
 
     let id = SkillId::from_str(&skill_id).ok_or(CharacterError::SkillNotFound)?;
-    if !id.is_mvp() {
+    if !id.is_released() {
         return Err(CharacterError::SkillNotReleased.into());
     }
 
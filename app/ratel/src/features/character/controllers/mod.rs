@@ -1,7 +1,9 @@
 mod get_character;
 mod get_public_character;
 mod level_up;
+mod respec;
 
 pub use get_character::*;
 pub use get_public_character::*;
 pub use level_up::*;
+pub use respec::*;
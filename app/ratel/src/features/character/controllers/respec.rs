@@ -0,0 +1,48 @@
+use crate::common::*;
+use crate::features::character::dto::CharacterResponse;
+use crate::features::character::types::CharacterError;
+
+/// Refund every spent skill point: all skill rows are dropped back to level
+/// 0 and `total_sp_spent` resets, so `unspent_sp == total_sp_granted`.
+/// Free and unlimited for now — spec Q4 leaves a cost / cooldown to balance
+/// feedback.
+#[post("/api/me/skills/respec", user: crate::features::auth::User)]
+pub async fn respec_handler() -> Result<CharacterResponse> {
+    use crate::features::character::models::{CharacterSkill, CharacterXp};
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let (xp_pk, xp_sk) = CharacterXp::user_keys(&user.pk);
+    let xp = CharacterXp::get(cli, &xp_pk, Some(&xp_sk))
+        .await?
+        .filter(|xp| xp.total_sp_spent > 0)
+        .ok_or(CharacterError::NothingToRespec)?;
+
+    let skill_rows = CharacterSkill::list_for_user(cli, &user.pk).await?;
+    for row in skill_rows.iter() {
+        CharacterSkill::delete(cli, &row.pk, Some(&row.sk)).await?;
+    }
+
+    // Skills first, then the SP ledger: a crash in between leaves the user
+    // with zeroed skills and SP still marked spent, and a retry converges.
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    CharacterXp::updater(&xp_pk, &xp_sk)
+        .with_total_sp_spent(0)
+        .with_updated_at(now)
+        .execute(cli)
+        .await?;
+
+    tracing::info!(
+        user_pk = %user.pk,
+        refunded_sp = xp.total_sp_spent,
+        "character skills respecced"
+    );
+
+    let xp = CharacterXp {
+        total_sp_spent: 0,
+        updated_at: now,
+        ..xp
+    };
+    Ok(CharacterResponse::from_parts(&xp, vec![]))
+}
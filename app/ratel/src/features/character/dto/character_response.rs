@@ -1,6 +1,7 @@
 use crate::common::*;
 use crate::features::character::leveling;
 use crate::features::character::models::CharacterXp;
+use crate::features::character::skill_effects::{self, SkillEffectKind};
 use crate::features::character::types::SkillId;
 #[cfg(feature = "server")]
 #[allow(unused_imports)]
@@ -31,11 +32,20 @@ pub struct CharacterSkillResponse {
     pub max_level: i32,
     /// `None` when at max level.
     pub next_level_cost: Option<i32>,
-    /// 1000 = 1.0×, 1500 = 1.5×.
+    /// 1000 = 1.0×, 1500 = 1.5×. Only meaningful for
+    /// `effect_kind == Multiplier`; 1000 otherwise.
     pub multiplier_permille: i32,
-    /// Whether this skill is part of the MVP set; non-MVP skills appear in
-    /// the response with level=0 and `next_level_cost=None`.
+    /// Whether the skill has a row in the effect registry; unreleased
+    /// skills appear with level=0 and `next_level_cost=None`.
     pub is_released: bool,
+    #[serde(default)]
+    pub effect_kind: SkillEffectKind,
+    /// Current effect in `effect_kind` units (`+N%`, `−N`, or `N%`).
+    #[serde(default)]
+    pub effect_value: i64,
+    /// Effect after the next level-up; `None` at max level.
+    #[serde(default)]
+    pub next_effect_value: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -50,9 +60,6 @@ impl CharacterResponse {
         let cur_threshold = leveling::cumulative_xp_at_level(xp.level);
         let next_threshold = leveling::cumulative_xp_at_level(xp.level + 1);
 
-        let mvp = [SkillId::MoneyTree, SkillId::Ranker];
-        let v2 = [SkillId::Influencer, SkillId::Sweeper];
-
         let level_for = |id: SkillId| -> i32 {
             skills
                 .iter()
@@ -61,28 +68,38 @@ impl CharacterResponse {
                 .unwrap_or(0)
         };
 
-        let mut response_skills = Vec::with_capacity(4);
-        for id in mvp.iter().copied() {
-            let lv = level_for(id);
-            response_skills.push(CharacterSkillResponse {
-                skill_id: id,
-                level: lv,
-                max_level: leveling::MAX_SKILL_LEVEL,
-                next_level_cost: leveling::skill_cost_next(lv),
-                multiplier_permille: leveling::multiplier_permille(lv),
-                is_released: true,
-            });
-        }
-        for id in v2.iter().copied() {
-            response_skills.push(CharacterSkillResponse {
-                skill_id: id,
-                level: 0,
-                max_level: leveling::MAX_SKILL_LEVEL,
-                next_level_cost: None,
-                multiplier_permille: 1000,
-                is_released: false,
-            });
-        }
+        let response_skills = SkillId::ALL
+            .into_iter()
+            .map(|id| match skill_effects::effect_for(id) {
+                Some(spec) => {
+                    let lv = level_for(id);
+                    let next_level_cost = leveling::skill_cost_next(lv);
+                    CharacterSkillResponse {
+                        skill_id: id,
+                        level: lv,
+                        max_level: leveling::MAX_SKILL_LEVEL,
+                        next_level_cost,
+                        multiplier_permille: match spec.effect.kind() {
+                            SkillEffectKind::Multiplier => leveling::multiplier_permille(lv),
+                            _ => 1000,
+                        },
+                        is_released: true,
+                        effect_kind: spec.effect.kind(),
+                        effect_value: spec.effect.preview(lv),
+                        next_effect_value: next_level_cost.map(|_| spec.effect.preview(lv + 1)),
+                    }
+                }
+                None => CharacterSkillResponse {
+                    skill_id: id,
+                    level: 0,
+                    max_level: leveling::MAX_SKILL_LEVEL,
+                    next_level_cost: None,
+                    multiplier_permille: 1000,
+                    is_released: false,
+                    ..Default::default()
+                },
+            })
+            .collect();
 
         Self {
             total_xp: xp.total_xp,
@@ -1,19 +1,22 @@
-use crate::features::character::controllers::{get_character_handler, level_up_handler};
+use crate::features::character::controllers::{
+    get_character_handler, level_up_handler, respec_handler,
+};
 use crate::features::character::dto::CharacterResponse;
 use crate::features::character::types::SkillId;
 use crate::*;
 
 /// Controller for the Character page (XP, level, skill points, skills).
 ///
-/// Bundles the character `Loader<CharacterResponse>` plus a single
-/// `level_up_action` that takes a [`SkillId`] and refreshes the loader on
-/// success. Components consume this via [`use_character`] and never call
+/// Bundles the character `Loader<CharacterResponse>` plus a
+/// `level_up_action` that takes a [`SkillId`] and a `respec_action` that
+/// refunds every spent SP; both refresh the loader on success. Components consume this via [`use_character`] and never call
 /// the server `_handler` functions directly (per
 /// `conventions/hooks-and-actions.md`).
 #[derive(Clone, Copy, DioxusController)]
 pub struct UseCharacter {
     pub character: Loader<CharacterResponse>,
     pub level_up_action: Action<(SkillId,), ()>,
+    pub respec_action: Action<(), ()>,
 }
 
 /// Provider — installs the controller into context (or returns the
@@ -43,8 +46,15 @@ pub fn use_character() -> std::result::Result<UseCharacter, Loading> {
         Ok::<(), crate::common::Error>(())
     });
 
+    let respec_action = use_action(move || async move {
+        respec_handler().await?;
+        character.restart();
+        Ok::<(), crate::common::Error>(())
+    });
+
     Ok(use_context_provider(move || UseCharacter {
         character,
         level_up_action,
+        respec_action,
     }))
 }
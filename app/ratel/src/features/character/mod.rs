@@ -6,6 +6,7 @@ pub mod leveling;
 pub mod models;
 pub mod pages;
 pub mod services;
+pub mod skill_effects;
 pub mod types;
 
 pub use components::*;
@@ -14,6 +15,7 @@ pub use dto::*;
 pub use leveling::*;
 pub use models::*;
 pub use pages::*;
+pub use skill_effects::*;
 pub use types::*;
 
 #[allow(unused_imports)]
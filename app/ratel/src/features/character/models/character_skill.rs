@@ -86,14 +86,9 @@ impl CharacterSkill {
                 .map(|r| r.level)
                 .unwrap_or(0)
         };
-        [
-            SkillId::MoneyTree,
-            SkillId::Ranker,
-            SkillId::Influencer,
-            SkillId::Sweeper,
-        ]
-        .into_iter()
-        .map(|id| (id, level_for(id)))
-        .collect()
+        SkillId::ALL
+            .into_iter()
+            .map(|id| (id, level_for(id)))
+            .collect()
     }
 }
@@ -17,11 +17,13 @@ pub fn CharacterPage() -> Element {
     let UseCharacter {
         character,
         mut level_up_action,
+        mut respec_action,
     } = use_character()?;
 
     let response = character();
     let skills = response.skills.clone();
     let unspent_sp = response.unspent_sp;
+    let nothing_to_respec = response.total_sp_spent == 0;
 
     rsx! {
         SeoMeta { title: "{tr.page_title}" }
@@ -36,6 +38,14 @@ pub fn CharacterPage() -> Element {
                 header { class: "section-header",
                     h2 { class: "section-header__title", "{tr.skill_tree_title}" }
                     span { class: "section-header__hint", "{tr.skill_tree_hint}" }
+                    button {
+                        class: "section-header__action",
+                        r#type: "button",
+                        "data-testid": "skill-respec",
+                        disabled: nothing_to_respec,
+                        onclick: move |_| respec_action.call(),
+                        "{tr.respec_label}"
+                    }
                 }
 
                 div { class: "skill-grid",
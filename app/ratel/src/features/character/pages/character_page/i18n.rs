@@ -19,8 +19,8 @@ translate! {
     // Section header
     skill_tree_title: { en: "Skill Tree", ko: "스킬 트리" },
     skill_tree_hint: {
-        en: "+5% per level · Max +50% at L10",
-        ko: "레벨당 +5% · L10에서 최대 +50%",
+        en: "Spend SP to level up skills · Respec refunds every point",
+        ko: "SP로 스킬 레벨 업 · 초기화 시 모든 포인트 환급",
     },
 
     // Skill cards — names and copy
@@ -70,9 +70,10 @@ translate! {
     locked_label: { en: "Locked", ko: "잠김" },
     coming_soon: { en: "v2 · Coming soon", ko: "v2 · 곧 출시" },
     not_released: { en: "Not yet released", ko: "아직 출시되지 않음" },
-    next_boost: { en: "Next: +", ko: "다음: +" },
-    next_was: { en: "% (was +", ko: "% (현재 +" },
-    max_reached: { en: "Maximum boost reached", ko: "최대 부스트 도달" },
+    next_label: { en: "Next: ", ko: "다음: " },
+    was_label: { en: " (was ", ko: " (현재 " },
+    max_reached: { en: "Maximum level reached", ko: "최대 레벨 도달" },
+    respec_label: { en: "Respec", ko: "스킬 초기화" },
 
     // Footer labels
     level_meta_label: { en: "Level", ko: "레벨" },
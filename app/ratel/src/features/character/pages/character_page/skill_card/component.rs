@@ -1,5 +1,6 @@
 use crate::features::character::dto::CharacterSkillResponse;
 use crate::features::character::pages::character_page::CharacterPageTranslate;
+use crate::features::character::skill_effects::SkillEffectKind;
 use crate::features::character::types::SkillId;
 use crate::*;
 
@@ -46,8 +47,14 @@ pub fn SkillCard(
         ),
     };
 
-    let multiplier_pct = level * 5;
-    let next_pct = (level + 1) * 5;
+    // Render by effect shape so new registry rows need no card changes.
+    let format_effect = |value: i64| match response.effect_kind {
+        SkillEffectKind::Multiplier => format!("+{value}%"),
+        SkillEffectKind::Reduction => format!("−{value}"),
+        SkillEffectKind::Share => format!("{value}%"),
+    };
+    let effect_label = format_effect(response.effect_value);
+    let next_effect_label = response.next_effect_value.map(format_effect);
 
     let pip_filled = (0..max_level)
         .map(|i| i < level)
@@ -75,7 +82,7 @@ pub fn SkillCard(
                     div { class: "skill-card__name-ko", "{sub}" }
                 }
                 if is_released {
-                    span { class: "skill-card__multiplier", "+{multiplier_pct}%" }
+                    span { class: "skill-card__multiplier", "{effect_label}" }
                 } else {
                     span { class: "skill-card__lock-tag", "{tr.coming_soon}" }
                 }
@@ -102,12 +109,12 @@ pub fn SkillCard(
                     if is_released {
                         span { class: "skill-card__level-meta-label", "{tr.level_meta_label}" }
                         span { class: "skill-card__level-meta-value", "{level_meta_value}" }
-                        if is_maxed {
-                            span { class: "skill-card__level-meta-next", "{tr.max_reached}" }
-                        } else {
+                        if let Some(next) = next_effect_label {
                             span { class: "skill-card__level-meta-next",
-                                "{tr.next_boost}{next_pct}{tr.next_was}{multiplier_pct}%)"
+                                "{tr.next_label}{next}{tr.was_label}{effect_label})"
                             }
+                        } else {
+                            span { class: "skill-card__level-meta-next", "{tr.max_reached}" }
                         }
                     } else {
                         span { class: "skill-card__level-meta-label", "{tr.status_meta_label}" }
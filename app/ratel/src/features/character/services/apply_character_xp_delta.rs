@@ -2,6 +2,8 @@ use crate::common::*;
 use crate::features::activity::models::SpaceScore;
 use crate::features::character::leveling;
 use crate::features::character::models::{CharacterXp, CharacterXpSource};
+use crate::features::character::services::apply_skill_effects;
+use crate::features::character::skill_effects::SkillPipeline;
 
 /// Apply the change in `SpaceScore.total_score` for a (user, space) into the
 /// user's CharacterXp. Idempotent under stream replay: a re-delivered MODIFY
@@ -66,6 +68,10 @@ pub async fn apply_character_xp_delta(
         return Ok(());
     }
 
+    // `last_seen` tracks the raw score; only the XP credit goes through
+    // the skill registry.
+    let delta = apply_skill_effects(cli, &user_pk, SkillPipeline::CharacterXp, delta).await;
+
     // Read current CharacterXp; if absent we INSERT a fresh row, otherwise we
     // UPDATE only the changed fields. `update_item` does not back-fill the
     // required `created_at` column, so the loaded-vs-fresh distinction matters
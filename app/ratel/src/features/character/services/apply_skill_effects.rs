@@ -0,0 +1,25 @@
+use crate::common::*;
+use crate::features::character::models::CharacterSkill;
+use crate::features::character::skill_effects::{self, SkillPipeline};
+
+/// Adjust `value` on `pipeline` by `user_pk`'s skills, per the
+/// [`SKILL_EFFECTS`](skill_effects::SKILL_EFFECTS) registry.
+///
+/// Only the skills that target `pipeline` are read (one point read each).
+/// A failed read counts as level 0 — a skill lookup must never fail the
+/// payout or insert it decorates.
+pub async fn apply_skill_effects(
+    cli: &aws_sdk_dynamodb::Client,
+    user_pk: &Partition,
+    pipeline: SkillPipeline,
+    value: i64,
+) -> i64 {
+    let mut levels = vec![];
+    for spec in skill_effects::effects_for(pipeline) {
+        let level = CharacterSkill::level_or_zero(cli, user_pk, spec.skill)
+            .await
+            .unwrap_or(0);
+        levels.push((spec.skill, level));
+    }
+    skill_effects::apply_effects(pipeline, &levels, value)
+}
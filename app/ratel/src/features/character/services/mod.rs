@@ -1,4 +1,8 @@
 #[cfg(feature = "server")]
 mod apply_character_xp_delta;
 #[cfg(feature = "server")]
+mod apply_skill_effects;
+#[cfg(feature = "server")]
 pub use apply_character_xp_delta::*;
+#[cfg(feature = "server")]
+pub use apply_skill_effects::*;
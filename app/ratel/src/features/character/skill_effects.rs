@@ -0,0 +1,175 @@
+//! Declarative skill-effect registry. Single source of truth for *what*
+//! each skill changes and *how*; the pipelines themselves (reward payout,
+//! activity scoring, hot-space fanout, owner bonus) only ask the registry
+//! for the adjusted value via
+//! [`apply_skill_effects`](crate::features::character::services::apply_skill_effects).
+//!
+//! Adding a skill = one `SkillId` variant + one [`SKILL_EFFECTS`] row.
+//! A skill with no row is not released (the level-up endpoint rejects it).
+//!
+//! Per-level numbers follow `roadmap/character-xp-skills.md`
+//! (§"Skill: Money Tree", §"Skill: Ranker", §"Skills v2").
+
+use crate::features::character::leveling;
+use crate::features::character::types::SkillId;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// A value some server path computes that a skill may adjust. The value
+/// passed in is always the un-skilled one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillPipeline {
+    /// Participant's RatelPoint payout from `SpaceReward::award`.
+    RewardPayout,
+    /// `additional_score` of a new `SpaceActivity` (`base_score` is never
+    /// boosted — spec Q1).
+    ActivityXp,
+    /// Per-event delta `apply_character_xp_delta` adds to `CharacterXp`.
+    /// No skill targets it today: Ranker boosts upstream, so the delta is
+    /// already boosted by the time it gets here.
+    CharacterXp,
+    /// `MIN_PARTICIPANTS_FOR_HOT` for spaces the skill owner created.
+    HotSpaceThreshold,
+    /// Owner-bonus cut of a participant's raw payout in the owner's space.
+    OwnerBonus,
+}
+
+/// Shape of the adjustment, so the UI can render it without knowing
+/// which skill it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum SkillEffectKind {
+    /// `+N%` on top of the value.
+    #[default]
+    Multiplier,
+    /// `−N` off a threshold.
+    Reduction,
+    /// `N%` of the value.
+    Share,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillEffect {
+    /// `value × (1000 + per_level_permille · L) / 1000`, rounded.
+    Multiplier { per_level_permille: i32 },
+    /// `value − min(per_level · L, max)`, never below 1.
+    Reduction { per_level: i64, max: i64 },
+    /// `value × (base_percent + per_level_percent · L) / 100`. Level 0
+    /// still yields `base_percent`, so the pipeline's default lives here.
+    Share {
+        base_percent: i64,
+        per_level_percent: i64,
+    },
+}
+
+impl SkillEffect {
+    pub fn apply(&self, level: i32, value: i64) -> i64 {
+        let level = level.clamp(0, leveling::MAX_SKILL_LEVEL) as i64;
+        match *self {
+            SkillEffect::Multiplier { per_level_permille } => leveling::apply_permille(
+                value,
+                1000 + per_level_permille * level as i32,
+            ),
+            SkillEffect::Reduction { per_level, max } => {
+                (value - (per_level * level).min(max)).max(value.min(1))
+            }
+            SkillEffect::Share {
+                base_percent,
+                per_level_percent,
+            } => value * (base_percent + per_level_percent * level) / 100,
+        }
+    }
+
+    /// The number the skill card shows for `level`: the `+N%` bonus, the
+    /// `−N` reduction, or the `N%` share.
+    pub fn preview(&self, level: i32) -> i64 {
+        let level = level.clamp(0, leveling::MAX_SKILL_LEVEL) as i64;
+        match *self {
+            SkillEffect::Multiplier { per_level_permille } => {
+                per_level_permille as i64 * level / 10
+            }
+            SkillEffect::Reduction { per_level, max } => (per_level * level).min(max),
+            SkillEffect::Share {
+                base_percent,
+                per_level_percent,
+            } => base_percent + per_level_percent * level,
+        }
+    }
+
+    pub fn kind(&self) -> SkillEffectKind {
+        match self {
+            SkillEffect::Multiplier { .. } => SkillEffectKind::Multiplier,
+            SkillEffect::Reduction { .. } => SkillEffectKind::Reduction,
+            SkillEffect::Share { .. } => SkillEffectKind::Share,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SkillEffectSpec {
+    pub skill: SkillId,
+    pub pipeline: SkillPipeline,
+    pub effect: SkillEffect,
+}
+
+pub const SKILL_EFFECTS: &[SkillEffectSpec] = &[
+    SkillEffectSpec {
+        skill: SkillId::MoneyTree,
+        pipeline: SkillPipeline::RewardPayout,
+        effect: SkillEffect::Multiplier {
+            per_level_permille: leveling::MULTIPLIER_PER_LEVEL_PERMILLE,
+        },
+    },
+    SkillEffectSpec {
+        skill: SkillId::Ranker,
+        pipeline: SkillPipeline::ActivityXp,
+        effect: SkillEffect::Multiplier {
+            per_level_permille: leveling::MULTIPLIER_PER_LEVEL_PERMILLE,
+        },
+    },
+    // 10 → 1 by L9; L10 is a prestige tier with no further reduction.
+    SkillEffectSpec {
+        skill: SkillId::Influencer,
+        pipeline: SkillPipeline::HotSpaceThreshold,
+        effect: SkillEffect::Reduction {
+            per_level: 1,
+            max: 9,
+        },
+    },
+    // Default owner bonus is 10%; +5% per level → 60% at L10.
+    SkillEffectSpec {
+        skill: SkillId::Sweeper,
+        pipeline: SkillPipeline::OwnerBonus,
+        effect: SkillEffect::Share {
+            base_percent: 10,
+            per_level_percent: 5,
+        },
+    },
+];
+
+/// Registry row for `skill`, or `None` if the skill is not released.
+pub fn effect_for(skill: SkillId) -> Option<&'static SkillEffectSpec> {
+    SKILL_EFFECTS.iter().find(|s| s.skill == skill)
+}
+
+/// Every registry row that adjusts `pipeline`, in registry order.
+pub fn effects_for(pipeline: SkillPipeline) -> impl Iterator<Item = &'static SkillEffectSpec> {
+    SKILL_EFFECTS.iter().filter(move |s| s.pipeline == pipeline)
+}
+
+/// Fold every effect on `pipeline` over `value`, given the user's skill
+/// levels. Skills missing from `levels` count as level 0. `Share`
+/// effects still apply at level 0 — that is the pipeline's default.
+pub fn apply_effects(pipeline: SkillPipeline, levels: &[(SkillId, i32)], value: i64) -> i64 {
+    effects_for(pipeline).fold(value, |acc, spec| {
+        let level = levels
+            .iter()
+            .find(|(id, _)| *id == spec.skill)
+            .map(|(_, l)| *l)
+            .unwrap_or(0);
+        spec.effect.apply(level, acc)
+    })
+}
@@ -68,3 +68,30 @@ fn apply_permille_rounding() {
     // 9 × 1.05 = 9.45 → rounds to 9
     assert_eq!(apply_permille(9, 1050), 9);
 }
+
+#[test]
+fn skill_effect_registry_curves() {
+    use crate::features::character::skill_effects::{apply_effects, SkillPipeline};
+    use crate::features::character::types::SkillId;
+
+    // Influencer: 10 → 9 at L1, floor 1 by L9, no further drop at L10.
+    let hot = |l| apply_effects(SkillPipeline::HotSpaceThreshold, &[(SkillId::Influencer, l)], 10);
+    assert_eq!(hot(0), 10);
+    assert_eq!(hot(1), 9);
+    assert_eq!(hot(9), 1);
+    assert_eq!(hot(10), 1);
+
+    // Sweeper: 10% at L0, +5% per level.
+    let owner = |l| apply_effects(SkillPipeline::OwnerBonus, &[(SkillId::Sweeper, l)], 10_000);
+    assert_eq!(owner(0), 1_000);
+    assert_eq!(owner(1), 1_500);
+    assert_eq!(owner(10), 6_000);
+
+    // Money Tree matches the legacy permille curve.
+    assert_eq!(
+        apply_effects(SkillPipeline::RewardPayout, &[(SkillId::MoneyTree, 1)], 10_000),
+        10_500
+    );
+    // No skill targets CharacterXp: identity.
+    assert_eq!(apply_effects(SkillPipeline::CharacterXp, &[], 42), 42);
+}
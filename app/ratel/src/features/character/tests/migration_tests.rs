@@ -80,23 +80,22 @@ async fn test_run_migrations_runs_m001() {
 
     // Exercise the real `run_migrations` entry point so this test actually
     // covers the runner's "stored < 1 → run m001" branch. m002 was
-    // deleted alongside BiyardService removal, so the runner now only
-    // advances to version 1; we no longer seed M002_CSV_PATH.
+    // deleted alongside BiyardService removal, so a fresh environment
+    // runs m001 then m003 (badge backfill); we no longer seed M002_CSV_PATH.
     let ddb = ctx.ddb.clone();
     run_with_env("MIGRATE", "true", move || async move {
         crate::common::migrations::run_migrations(&ddb).await.unwrap();
     })
     .await;
 
-    // Verify the runner advanced past m001. With m002 deleted, version
-    // stops at 1; the m001 execution itself is also confirmed via the
-    // `CharacterXp` row check below.
+    // Verify the runner advanced past m001 and m003. The m001 execution
+    // itself is also confirmed via the `CharacterXp` row check below.
     let (pk, sk) = LastBackfillVersion::singleton_keys();
     let row = LastBackfillVersion::get(&ctx.ddb, &pk, Some(&sk))
         .await
         .unwrap()
         .unwrap();
-    assert_eq!(row.version, 1);
+    assert_eq!(row.version, 3);
 
     // Verify CharacterXp seeded.
     use crate::features::character::models::CharacterXp;
@@ -118,6 +117,30 @@ async fn test_run_migrations_idempotent_at_version() {
     // the "already at HEAD = no-op" invariant honest.
     LastBackfillVersion::advance_to(&ctx.ddb, 0, 1).await.unwrap();
     LastBackfillVersion::advance_to(&ctx.ddb, 1, 2).await.unwrap();
+    LastBackfillVersion::advance_to(&ctx.ddb, 2, 3).await.unwrap();
+
+    let ddb = ctx.ddb.clone();
+    run_with_env("MIGRATE", "true", move || async move {
+        crate::common::migrations::run_migrations(&ddb).await.unwrap();
+    })
+    .await;
+
+    let (pk, sk) = LastBackfillVersion::singleton_keys();
+    let row = LastBackfillVersion::get(&ctx.ddb, &pk, Some(&sk))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(row.version, 3, "no further migrations to run");
+}
+
+#[tokio::test]
+async fn test_run_migrations_from_prod_version_runs_m003() {
+    let ctx = TestContext::setup().await;
+    reset_migration_state(&ctx.ddb).await;
+    // Prod is parked at 2 (m002 ran before it was deleted); the runner
+    // must advance 2 → 3 without re-running m001.
+    LastBackfillVersion::advance_to(&ctx.ddb, 0, 1).await.unwrap();
+    LastBackfillVersion::advance_to(&ctx.ddb, 1, 2).await.unwrap();
 
     let ddb = ctx.ddb.clone();
     run_with_env("MIGRATE", "true", move || async move {
@@ -130,8 +153,8 @@ async fn test_run_migrations_idempotent_at_version() {
         .await
         .unwrap()
         .unwrap();
-    assert_eq!(row.version, 2, "no further migrations to run");
+    assert_eq!(row.version, 3);
 }
 
 // m002 (backfill_pending_rewards) tests were removed alongside the
-// BiyardService deletion; the runner now stops at version 1.
+// BiyardService deletion; the runner skips from 1 straight to 3.
@@ -5,7 +5,7 @@
 //! - `helpers`           shared fixtures: TestContext, make_score, award_xp, run_with_env
 //! - `leveling_tests`    pure-Rust unit tests for `leveling.rs`
 //! - `character_xp_tests` apply_character_xp_delta + GET handlers
-//! - `skill_tests`       level_up handler + Money Tree + Ranker effects
+//! - `skill_tests`       level_up + respec handlers + registry-driven skill effects
 //! - `migration_tests`   LastBackfillVersion conditional advance + m001 + MIGRATE gate
 
 mod character_xp_tests;
@@ -54,15 +54,69 @@ async fn test_level_up_unknown_skill_rejected() {
 }
 
 #[tokio::test]
-async fn test_level_up_v2_skill_rejected() {
+async fn test_level_up_influencer_success() {
     let ctx = TestContext::setup().await;
-    let (status, _, _) = crate::test_post! {
+    let (status, _, body) = crate::test_post! {
         app: ctx.app.clone(),
         path: "/api/me/skills/influencer/level-up",
         headers: ctx.test_user.1.clone(),
+        body: {},
+        response_type: crate::features::character::dto::CharacterResponse,
+    };
+    assert_eq!(status, 200, "{:?}", body);
+    let inf = body
+        .skills
+        .iter()
+        .find(|s| matches!(s.skill_id, crate::features::character::types::SkillId::Influencer))
+        .unwrap();
+    assert!(inf.is_released);
+    assert_eq!(inf.level, 1);
+    assert_eq!(
+        inf.effect_kind,
+        crate::features::character::skill_effects::SkillEffectKind::Reduction
+    );
+    assert_eq!(inf.effect_value, 1);
+    assert_eq!(inf.next_effect_value, Some(2));
+}
+
+#[tokio::test]
+async fn test_respec_refunds_all_sp() {
+    let ctx = TestContext::setup().await;
+    let _ = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/me/skills/money_tree/level-up",
+        headers: ctx.test_user.1.clone(),
         body: {}
     };
-    assert_eq!(status, 400, "v2 skill must be gated");
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/me/skills/respec",
+        headers: ctx.test_user.1.clone(),
+        body: {},
+        response_type: crate::features::character::dto::CharacterResponse,
+    };
+    assert_eq!(status, 200, "{:?}", body);
+    assert_eq!(body.total_sp_spent, 0);
+    assert_eq!(body.unspent_sp, body.total_sp_granted);
+    assert!(body.skills.iter().all(|s| s.level == 0));
+
+    let level = crate::features::character::models::CharacterSkill::level_or_zero(
+        &ctx.ddb,
+        &ctx.test_user.0.pk,
+        crate::features::character::types::SkillId::MoneyTree,
+    )
+    .await
+    .unwrap();
+    assert_eq!(level, 0, "skill row must be gone after respec");
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/me/skills/respec",
+        headers: ctx.test_user.1.clone(),
+        body: {}
+    };
+    assert_eq!(status, 400, "respec with nothing spent must be rejected");
 }
 
 #[tokio::test]
@@ -181,3 +235,55 @@ async fn test_ranker_boosts_additional_score() {
     assert_eq!(activity.total_score, 153);
     assert_eq!(activity.base_score, 100, "base unchanged");
 }
+
+#[tokio::test]
+async fn test_sweeper_raises_owner_bonus() {
+    let ctx = TestContext::setup().await;
+    let owner_pk = ctx.test_user.0.pk.clone();
+
+    // Owner buys Sweeper L1 → owner cut goes 10% → 15%.
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/me/skills/sweeper/level-up",
+        headers: ctx.test_user.1.clone(),
+        body: {}
+    };
+    assert_eq!(status, 200);
+
+    use crate::common::models::auth::User;
+    use crate::features::spaces::space_common::models::space_reward::SpaceReward;
+
+    let points_before = User::get(&ctx.ddb, &owner_pk, Some(EntityType::User))
+        .await
+        .unwrap()
+        .map(|u| u.points)
+        .unwrap_or_default();
+
+    let nonce = crate::common::utils::time::get_now_timestamp_millis();
+    let space_id = SpacePartition(format!("space-sw-{nonce}"));
+    let participant_pk = Partition::User(format!("participant-sw-{nonce}"));
+    let reward = SpaceReward::new(
+        space_id,
+        format!("action-sw-{nonce}"),
+        RewardUserBehavior::RespondPoll,
+        "test reward".into(),
+        1,      // credits
+        10_000, // point
+        RewardPeriod::Once,
+        RewardCondition::None,
+    );
+    reward.create(&ctx.ddb).await.unwrap();
+
+    let participant_reward =
+        SpaceReward::award(&ctx.ddb, &reward, participant_pk, Some(owner_pk.clone()))
+            .await
+            .unwrap();
+    assert_eq!(participant_reward.total_points, 10_000, "participant unboosted");
+
+    let points_after = User::get(&ctx.ddb, &owner_pk, Some(EntityType::User))
+        .await
+        .unwrap()
+        .map(|u| u.points)
+        .unwrap_or_default();
+    assert_eq!(points_after - points_before, 1_500, "owner cut = 15% of raw");
+}
@@ -25,6 +25,13 @@ pub enum CharacterError {
         ko = "이미 최대 레벨입니다"
     )]
     AlreadyMaxLevel,
+
+    #[error("no skill points spent")]
+    #[translate(
+        en = "There are no spent skill points to refund",
+        ko = "환급할 스킬 포인트가 없습니다"
+    )]
+    NothingToRespec,
 }
 
 #[cfg(feature = "server")]
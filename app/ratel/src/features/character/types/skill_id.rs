@@ -13,13 +13,20 @@ pub enum SkillId {
     #[default]
     MoneyTree,
     Ranker,
-    /// v2 — declared so the data model can store them, but the level-up
-    /// endpoint rejects any non-MVP id until the v2 spec ships.
+    /// v2 creator-side skills — see `skill_effects::SKILL_EFFECTS`.
     Influencer,
     Sweeper,
 }
 
 impl SkillId {
+    /// Every known skill, in skill-tree display order.
+    pub const ALL: [SkillId; 4] = [
+        SkillId::MoneyTree,
+        SkillId::Ranker,
+        SkillId::Influencer,
+        SkillId::Sweeper,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             SkillId::MoneyTree => "money_tree",
@@ -39,8 +46,9 @@ impl SkillId {
         }
     }
 
-    /// MVP skills the level-up endpoint accepts.
-    pub fn is_mvp(&self) -> bool {
-        matches!(self, SkillId::MoneyTree | SkillId::Ranker)
+    /// Skills the level-up endpoint accepts — exactly those with a row in
+    /// the effect registry.
+    pub fn is_released(&self) -> bool {
+        crate::features::character::skill_effects::effect_for(*self).is_some()
     }
 }
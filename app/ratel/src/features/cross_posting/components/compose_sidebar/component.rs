@@ -1,14 +1,23 @@
 use crate::common::*;
+use crate::common::utils::time;
 use crate::features::cross_posting::hooks::{UseCrossPosting, use_cross_posting};
 use crate::features::cross_posting::i18n::ComposeSidebarTranslate;
 use crate::features::cross_posting::models::ConnectionStatus;
-use crate::features::cross_posting::types::{ConnectionResponse, SocialPlatform};
+use crate::features::cross_posting::types::{
+    ConnectionResponse, QueuedJobResponse, SchedulePlan, SocialPlatform,
+};
+
+/// Default slot offered when the author switches a platform to "At…".
+const DEFAULT_AT_LEAD_MS: i64 = 60 * 60 * 1000;
 
 /// Compose-time cross-post sidebar.
 ///
 /// Drives `UseCrossPosting::per_post_enabled` (the `HashMap<SocialPlatform, bool>`
 /// the publish action serializes into `enabled_platforms`) and exposes a live
 /// "Reaching N networks" summary plus per-platform char-count warnings.
+/// Each enabled platform also picks its timing (`per_post_schedule`), and
+/// the author's upcoming scheduled cross-posts are listed at the bottom
+/// with a cancel action.
 ///
 /// The parent (`post_edit`) controls visibility — Private / team-shared posts
 /// don't render this component at all (FR-9 #50). Disconnected platforms emit
@@ -22,13 +31,17 @@ pub fn CrossPostSidebar(
     content: Signal<String>,
     on_connect_request: EventHandler<SocialPlatform>,
 ) -> Element {
+    let mut cp = use_cross_posting();
     let UseCrossPosting {
         connections,
         mut per_post_enabled,
         reach_count,
+        mut per_post_schedule,
+        queue,
         ..
-    } = use_cross_posting();
+    } = cp;
     let t: ComposeSidebarTranslate = use_translate();
+    let mut toast = use_toast();
 
     let conn_list: Vec<ConnectionResponse> = connections();
     let bsky = conn_list
@@ -43,6 +56,10 @@ pub fn CrossPostSidebar(
         .iter()
         .find(|c| c.platform == SocialPlatform::Threads)
         .cloned();
+    let mastodon = conn_list
+        .iter()
+        .find(|c| c.platform == SocialPlatform::Mastodon)
+        .cloned();
 
     // Match `post_edit`'s `re-char-count` value (which strips HTML tags)
     // so the editor's footer count and the sidebar's per-platform footer
@@ -113,6 +130,10 @@ pub fn CrossPostSidebar(
                         platform: SocialPlatform::Threads,
                         active: is_active(&threads, &per_post_enabled(), SocialPlatform::Threads),
                     }
+                    ReachChip {
+                        platform: SocialPlatform::Mastodon,
+                        active: is_active(&mastodon, &per_post_enabled(), SocialPlatform::Mastodon),
+                    }
                 }
             }
 
@@ -127,6 +148,12 @@ pub fn CrossPostSidebar(
                     map.insert(SocialPlatform::Bluesky, new_val);
                     per_post_enabled.set(map);
                 },
+                plan: plan_for(&per_post_schedule(), SocialPlatform::Bluesky),
+                on_plan: move |plan: SchedulePlan| {
+                    let mut map = per_post_schedule();
+                    map.insert(SocialPlatform::Bluesky, plan);
+                    per_post_schedule.set(map);
+                },
                 on_connect: move |_| on_connect_request.call(SocialPlatform::Bluesky),
             }
             PlatformCard {
@@ -139,6 +166,12 @@ pub fn CrossPostSidebar(
                     map.insert(SocialPlatform::LinkedIn, new_val);
                     per_post_enabled.set(map);
                 },
+                plan: plan_for(&per_post_schedule(), SocialPlatform::LinkedIn),
+                on_plan: move |plan: SchedulePlan| {
+                    let mut map = per_post_schedule();
+                    map.insert(SocialPlatform::LinkedIn, plan);
+                    per_post_schedule.set(map);
+                },
                 on_connect: move |_| on_connect_request.call(SocialPlatform::LinkedIn),
             }
             PlatformCard {
@@ -151,12 +184,53 @@ pub fn CrossPostSidebar(
                     map.insert(SocialPlatform::Threads, new_val);
                     per_post_enabled.set(map);
                 },
+                plan: plan_for(&per_post_schedule(), SocialPlatform::Threads),
+                on_plan: move |plan: SchedulePlan| {
+                    let mut map = per_post_schedule();
+                    map.insert(SocialPlatform::Threads, plan);
+                    per_post_schedule.set(map);
+                },
                 on_connect: move |_| on_connect_request.call(SocialPlatform::Threads),
             }
+            PlatformCard {
+                platform: SocialPlatform::Mastodon,
+                connection: mastodon.clone(),
+                enabled: is_active(&mastodon, &per_post_enabled(), SocialPlatform::Mastodon),
+                char_count,
+                on_toggle: move |new_val: bool| {
+                    let mut map = per_post_enabled();
+                    map.insert(SocialPlatform::Mastodon, new_val);
+                    per_post_enabled.set(map);
+                },
+                plan: plan_for(&per_post_schedule(), SocialPlatform::Mastodon),
+                on_plan: move |plan: SchedulePlan| {
+                    let mut map = per_post_schedule();
+                    map.insert(SocialPlatform::Mastodon, plan);
+                    per_post_schedule.set(map);
+                },
+                on_connect: move |_| on_connect_request.call(SocialPlatform::Mastodon),
+            }
+
+            // ── Queue ──────────────────────────────────────────────
+            QueueList {
+                items: queue(),
+                on_cancel: move |item: QueuedJobResponse| async move {
+                    if let Err(e) = cp.cancel_scheduled(item.post_id, item.platform).await {
+                        toast.error(e);
+                    }
+                },
+            }
         }
     }
 }
 
+fn plan_for(
+    schedule: &std::collections::HashMap<SocialPlatform, SchedulePlan>,
+    platform: SocialPlatform,
+) -> SchedulePlan {
+    schedule.get(&platform).copied().unwrap_or_default()
+}
+
 /// True when the platform is connected AND the per-post override resolves to
 /// "include in this publish". The override falls back to the persistent
 /// `auto_post_enabled` flag when the user hasn't explicitly toggled this
@@ -179,6 +253,7 @@ fn ReachChip(platform: SocialPlatform, active: bool) -> Element {
         SocialPlatform::Bluesky => "reach-chip reach-chip--bsky",
         SocialPlatform::LinkedIn => "reach-chip reach-chip--linkedin",
         SocialPlatform::Threads => "reach-chip reach-chip--threads",
+        SocialPlatform::Mastodon => "reach-chip reach-chip--mastodon",
     };
     rsx! {
         span { class: "{chip_class}", "data-off": "{!active}",
@@ -205,6 +280,11 @@ fn PlatformLogo(platform: SocialPlatform) -> Element {
                 path { "d": "M12.186 24h-.007c-3.581-.024-6.334-1.205-8.184-3.509C2.35 18.44 1.5 15.586 1.472 12.01v-.017c.03-3.579.879-6.43 2.525-8.482C5.845 1.205 8.6.024 12.18 0h.014c2.746.02 5.043.725 6.826 2.098 1.677 1.29 2.858 3.13 3.509 5.467l-2.04.569c-1.104-3.96-3.898-5.984-8.304-6.015-2.91.022-5.11.936-6.54 2.717C4.307 6.504 3.616 8.914 3.589 12c.027 3.086.718 5.496 2.057 7.164 1.43 1.783 3.631 2.698 6.54 2.717 2.623-.02 4.358-.631 5.8-2.045 1.647-1.613 1.618-3.593 1.09-4.798z" }
             }
         },
+        SocialPlatform::Mastodon => rsx! {
+            svg { "viewBox": "0 0 24 24", "fill": "currentColor",
+                path { "d": "M23.268 5.313c-.35-2.578-2.617-4.61-5.304-5.004C17.51.242 15.792 0 11.813 0h-.03c-3.98 0-4.835.242-5.288.309C3.882.692 1.496 2.518.917 5.127.64 6.412.61 7.837.661 9.143c.074 1.874.088 3.745.26 5.611.118 1.24.325 2.47.62 3.68.55 2.237 2.777 4.098 4.96 4.857 2.336.792 4.849.923 7.256.38.265-.061.527-.132.786-.213.585-.184 1.27-.39 1.774-.753a.057.057 0 0 0 .023-.043v-1.809a.052.052 0 0 0-.02-.041.053.053 0 0 0-.046-.01 20.282 20.282 0 0 1-4.709.545c-2.73 0-3.463-1.284-3.674-1.818a5.593 5.593 0 0 1-.319-1.433.053.053 0 0 1 .066-.054c1.517.363 3.072.546 4.632.546.376 0 .75 0 1.125-.01 1.57-.044 3.224-.124 4.768-.422.038-.008.077-.015.11-.024 2.435-.464 4.753-1.92 4.989-5.604.008-.145.03-1.52.03-1.67.002-.512.167-3.63-.024-5.545zm-3.748 9.195h-2.561V8.29c0-1.309-.55-1.976-1.67-1.976-1.23 0-1.846.79-1.846 2.35v3.403h-2.546V8.663c0-1.56-.617-2.35-1.848-2.35-1.112 0-1.668.668-1.67 1.977v6.218H4.822V8.102c0-1.31.337-2.35 1.011-3.12.696-.77 1.608-1.164 2.74-1.164 1.311 0 2.302.5 2.962 1.498l.638 1.06.638-1.06c.66-.999 1.65-1.498 2.96-1.498 1.13 0 2.043.395 2.74 1.164.675.77 1.012 1.81 1.012 3.12z" }
+            }
+        },
     }
 }
 
@@ -215,6 +295,8 @@ fn PlatformCard(
     enabled: bool,
     char_count: usize,
     on_toggle: EventHandler<bool>,
+    plan: SchedulePlan,
+    on_plan: EventHandler<SchedulePlan>,
     on_connect: EventHandler<()>,
 ) -> Element {
     let t: ComposeSidebarTranslate = use_translate();
@@ -228,11 +310,13 @@ fn PlatformCard(
         SocialPlatform::Bluesky => "bluesky",
         SocialPlatform::LinkedIn => "linkedin",
         SocialPlatform::Threads => "threads",
+        SocialPlatform::Mastodon => "mastodon",
     };
     let logo_class = match platform {
         SocialPlatform::Bluesky => "pp-logo pp-logo--bsky",
         SocialPlatform::LinkedIn => "pp-logo pp-logo--linkedin",
         SocialPlatform::Threads => "pp-logo pp-logo--threads",
+        SocialPlatform::Mastodon => "pp-logo pp-logo--mastodon",
     };
 
     let limit = platform.char_limit();
@@ -250,11 +334,25 @@ fn PlatformCard(
         SocialPlatform::Bluesky => t.connect_hint_bluesky,
         SocialPlatform::LinkedIn => t.connect_hint_linkedin,
         SocialPlatform::Threads => t.connect_hint_threads,
+        SocialPlatform::Mastodon => t.connect_hint_mastodon,
     };
     let connect_label = match platform {
         SocialPlatform::Bluesky => t.connect_btn_bluesky,
         SocialPlatform::LinkedIn => t.connect_btn_linkedin,
         SocialPlatform::Threads => t.connect_btn_threads,
+        SocialPlatform::Mastodon => t.connect_btn_mastodon,
+    };
+
+    // "Auto" means the connection's best time when one is set, otherwise
+    // immediately — spell out which in the option label.
+    let auto_label = match connection.as_ref().and_then(|c| c.best_time) {
+        Some(best) => format!("{} ({})", t.schedule_best_time, best.to_hhmm()),
+        None => t.schedule_now.to_string(),
+    };
+    let plan_kind = match plan {
+        SchedulePlan::Auto => "auto",
+        SchedulePlan::Now => "now",
+        SchedulePlan::At { .. } => "at",
     };
 
     rsx! {
@@ -294,12 +392,7 @@ fn PlatformCard(
                 }
             }
 
-            if platform == SocialPlatform::Threads {
-                div { class: "connect-cta",
-                    p { class: "connect-cta__hint", "{connect_hint}" }
-                    span { class: "connect-cta__coming-soon", "{t.coming_soon}" }
-                }
-            } else if !connected {
+            if !connected {
                 div { class: "connect-cta",
                     p { class: "connect-cta__hint", "{connect_hint}" }
                     button {
@@ -338,11 +431,95 @@ fn PlatformCard(
                         }
                     }
                 }
+                div { class: "pp-schedule",
+                    span { class: "pp-schedule__label", "{t.schedule_label}" }
+                    select {
+                        class: "pp-schedule__select",
+                        value: "{plan_kind}",
+                        onchange: move |e: FormEvent| {
+                            let next = match e.value().as_str() {
+                                "now" => SchedulePlan::Now,
+                                "at" => SchedulePlan::At {
+                                    at: time::now() + DEFAULT_AT_LEAD_MS,
+                                },
+                                _ => SchedulePlan::Auto,
+                            };
+                            on_plan.call(next);
+                        },
+                        option { value: "auto", "{auto_label}" }
+                        option { value: "now", "{t.schedule_now}" }
+                        option { value: "at", "{t.schedule_at}" }
+                    }
+                    if let SchedulePlan::At { at } = plan {
+                        input {
+                            class: "pp-schedule__at",
+                            r#type: "datetime-local",
+                            value: "{time::epoch_ms_to_datetime_local(at)}",
+                            onchange: move |e: FormEvent| {
+                                if let Some(at) = time::datetime_local_to_epoch_ms(&e.value()) {
+                                    on_plan.call(SchedulePlan::At { at });
+                                }
+                            },
+                        }
+                    }
+                }
             }
         }
     }
 }
 
+/// The author's upcoming scheduled cross-posts across all their posts,
+/// soonest first. Renders nothing when the queue is empty.
+#[component]
+fn QueueList(
+    items: Vec<QueuedJobResponse>,
+    on_cancel: EventHandler<QueuedJobResponse>,
+) -> Element {
+    let t: ComposeSidebarTranslate = use_translate();
+    if items.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        section { class: "cp-queue", "data-testid": "cross-post-queue",
+            h3 { class: "cp-queue__title", "{t.queue_title}" }
+            ul { class: "cp-queue__list",
+                for item in items {
+                    li {
+                        key: "{item.post_id}-{item.platform}",
+                        class: "cp-queue__item",
+                        div { class: "cp-queue__main",
+                            span { class: "cp-queue__when",
+                                "{local_when(item.scheduled_at)} · {item.platform.display_name()}"
+                            }
+                            span { class: "cp-queue__post",
+                                if item.post_title.is_empty() {
+                                    "{t.queue_untitled}"
+                                } else {
+                                    "{item.post_title}"
+                                }
+                            }
+                        }
+                        button {
+                            class: "cp-queue__cancel",
+                            onclick: {
+                                let item = item.clone();
+                                move |_| on_cancel.call(item.clone())
+                            },
+                            "{t.queue_cancel}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// `YYYY-MM-DD HH:MM` in the browser's timezone.
+fn local_when(ms: i64) -> String {
+    time::epoch_ms_to_datetime_local(ms).replace('T', " ")
+}
+
 /// Strip HTML tags so the sidebar's character count matches the
 /// `.re-char-count` footer in `post_edit` (which does the same).
 /// Mirrors the private helper in `post_edit/component.rs:1078`.
@@ -0,0 +1,269 @@
+use crate::common::*;
+use crate::features::cross_posting::hooks::{
+    UseCrossPostingAnalytics, use_cross_posting_analytics,
+};
+use crate::features::cross_posting::i18n::CrossPostingAnalyticsTranslate;
+use crate::features::cross_posting::types::{
+    AnalyticsCounts, DailyAnalyticsView, PlatformAnalyticsView, PostAnalyticsView, SocialPlatform,
+};
+
+/// Width of one day's column in the chart's SVG user units; the bar
+/// leaves a 2-unit gap.
+const DAY_WIDTH: i64 = 12;
+
+/// Settings → Connections section answering "which network brings
+/// readers back": network totals ranked by backlink visits, the last 30
+/// days stacked by network (visits or engagement), and the top posts
+/// with their per-network split.
+#[component]
+pub fn CrossPostingAnalytics() -> Element {
+    let UseCrossPostingAnalytics { analytics } = use_cross_posting_analytics()?;
+    let t: CrossPostingAnalyticsTranslate = use_translate();
+    let mut show_engagement = use_signal(|| false);
+
+    let data = analytics();
+
+    rsx! {
+        section { class: "cp-analytics", "data-testid": "cross-posting-analytics",
+            div { class: "cp-analytics__head",
+                h2 { class: "cp-analytics__title", "{t.title}" }
+                p { class: "cp-analytics__sub", "{t.subtitle}" }
+            }
+
+            if data.platforms.is_empty() {
+                p { class: "cp-analytics__empty", "{t.empty}" }
+            } else {
+                NetworkTotals { platforms: data.platforms.clone() }
+
+                div { class: "cp-analytics__section-head",
+                    h3 { class: "cp-analytics__section", "{t.daily_title}" }
+                    div { class: "cp-analytics__tabs", role: "tablist",
+                        button {
+                            class: "cp-analytics__tab",
+                            role: "tab",
+                            "aria-selected": !show_engagement(),
+                            onclick: move |_| show_engagement.set(false),
+                            "{t.visits}"
+                        }
+                        button {
+                            class: "cp-analytics__tab",
+                            role: "tab",
+                            "aria-selected": show_engagement(),
+                            onclick: move |_| show_engagement.set(true),
+                            "{t.engagement}"
+                        }
+                    }
+                }
+                DailyChart {
+                    from_day: data.from_day,
+                    to_day: data.to_day,
+                    daily: data.daily.clone(),
+                    engagement: show_engagement(),
+                }
+
+                PostList { posts: data.posts.clone() }
+            }
+        }
+    }
+}
+
+/// One card per network, ranked as the server sent them (most visits
+/// first), each with its share of all backlink visits.
+#[component]
+fn NetworkTotals(platforms: Vec<PlatformAnalyticsView>) -> Element {
+    let t: CrossPostingAnalyticsTranslate = use_translate();
+    let total_visits: i64 = platforms.iter().map(|p| p.counts.visits).sum();
+
+    rsx! {
+        div { class: "cp-analytics__networks",
+            for p in platforms {
+                {
+                    let share = percent(p.counts.visits, total_visits);
+                    rsx! {
+                        article {
+                            key: "{p.platform}",
+                            class: "cp-analytics__network",
+                            "data-platform": "{p.platform}",
+                            div { class: "cp-analytics__network-name",
+                                span { class: "cp-analytics__dot", "data-platform": "{p.platform}" }
+                                "{p.platform.display_name()}"
+                                span { class: "cp-analytics__muted", "{p.posts} {t.posts_count}" }
+                            }
+                            div { class: "cp-analytics__big",
+                                strong { "{p.counts.visits}" }
+                                span { "{t.visits}" }
+                            }
+                            div { class: "cp-analytics__share",
+                                div {
+                                    class: "cp-analytics__share-fill",
+                                    "data-platform": "{p.platform}",
+                                    style: "width: {share}%",
+                                }
+                            }
+                            Counts { counts: p.counts }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn Counts(counts: AnalyticsCounts) -> Element {
+    let t: CrossPostingAnalyticsTranslate = use_translate();
+
+    rsx! {
+        dl { class: "cp-analytics__counts",
+            div {
+                dt { "{t.likes}" }
+                dd { "{counts.likes}" }
+            }
+            div {
+                dt { "{t.comments}" }
+                dd { "{counts.comments}" }
+            }
+            div {
+                dt { "{t.reposts}" }
+                dd { "{counts.reposts}" }
+            }
+        }
+    }
+}
+
+/// One stacked bar per day of the window, a segment per network. Days
+/// that lost engagement (withdrawn likes) draw as empty rather than
+/// below the axis.
+#[component]
+fn DailyChart(
+    from_day: i64,
+    to_day: i64,
+    daily: Vec<DailyAnalyticsView>,
+    engagement: bool,
+) -> Element {
+    let days = (to_day - from_day + 1).max(1);
+    let value = |c: &AnalyticsCounts| {
+        if engagement {
+            c.engagement().max(0)
+        } else {
+            c.visits
+        }
+    };
+
+    let mut stacks: Vec<Vec<(SocialPlatform, i64)>> = vec![Vec::new(); days as usize];
+    for d in &daily {
+        let v = value(&d.counts);
+        if v > 0 && (from_day..=to_day).contains(&d.day) {
+            stacks[(d.day - from_day) as usize].push((d.platform, v));
+        }
+    }
+    let max = stacks
+        .iter()
+        .map(|s| s.iter().map(|(_, v)| v).sum::<i64>())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut bars = Vec::new();
+    for (i, stack) in stacks.iter().enumerate() {
+        let mut top = 100.0;
+        for (platform, v) in stack {
+            let height = *v as f64 * 100.0 / max as f64;
+            top -= height;
+            bars.push((
+                i as i64 * DAY_WIDTH + 1,
+                top,
+                height,
+                *platform,
+                *v,
+                from_day + i as i64,
+            ));
+        }
+    }
+
+    rsx! {
+        div { class: "cp-analytics__chart",
+            svg {
+                "viewBox": "0 0 {days * DAY_WIDTH} 100",
+                "preserveAspectRatio": "none",
+                "role": "img",
+                for (x, y, height, platform, v, day) in bars {
+                    rect {
+                        key: "{day}-{platform}",
+                        class: "cp-analytics__bar",
+                        "data-platform": "{platform}",
+                        "x": "{x}",
+                        "y": "{y}",
+                        "width": "{DAY_WIDTH - 2}",
+                        "height": "{height}",
+                        title { "{day_label(day)} · {platform.display_name()} · {v}" }
+                    }
+                }
+            }
+            div { class: "cp-analytics__axis",
+                span { "{day_label(from_day)}" }
+                span { "{day_label(to_day)}" }
+            }
+        }
+    }
+}
+
+#[component]
+fn PostList(posts: Vec<PostAnalyticsView>) -> Element {
+    let t: CrossPostingAnalyticsTranslate = use_translate();
+    if posts.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        h3 { class: "cp-analytics__section", "{t.posts_title}" }
+        ul { class: "cp-analytics__posts",
+            for post in posts {
+                li { key: "{post.post_id}", class: "cp-analytics__post",
+                    div { class: "cp-analytics__post-main",
+                        Link {
+                            to: crate::Route::PostDetail {
+                                post_id: post.post_id.clone(),
+                            },
+                            class: "cp-analytics__post-title",
+                            if post.post_title.is_empty() {
+                                "{t.untitled}"
+                            } else {
+                                "{post.post_title}"
+                            }
+                        }
+                        div { class: "cp-analytics__post-split",
+                            for split in post.platforms.iter() {
+                                span {
+                                    key: "{split.platform}",
+                                    class: "cp-analytics__chip",
+                                    "data-platform": "{split.platform}",
+                                    "{split.platform.display_name()} {split.counts.visits}"
+                                }
+                            }
+                        }
+                    }
+                    div { class: "cp-analytics__post-stat",
+                        strong { "{post.counts.visits}" }
+                        span { "{t.visits}" }
+                    }
+                    div { class: "cp-analytics__post-stat",
+                        strong { "{post.counts.engagement()}" }
+                        span { "{t.engagement}" }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn percent(part: i64, total: i64) -> i64 {
+    if total <= 0 { 0 } else { part * 100 / total }
+}
+
+/// "Mar 04" for a day number (days since the Unix epoch, UTC).
+fn day_label(day: i64) -> String {
+    chrono::DateTime::from_timestamp(day * 24 * 60 * 60, 0)
+        .map(|d| d.format("%b %d").to_string())
+        .unwrap_or_default()
+}
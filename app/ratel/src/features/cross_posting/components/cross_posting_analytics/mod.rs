@@ -0,0 +1,3 @@
+mod component;
+
+pub use component::*;
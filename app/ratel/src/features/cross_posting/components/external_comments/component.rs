@@ -0,0 +1,79 @@
+use crate::common::utils::time::time_ago;
+use crate::common::*;
+use crate::features::cross_posting::hooks::{UseExternalComments, use_external_comments};
+use crate::features::cross_posting::i18n::ExternalCommentsTranslate;
+use crate::features::cross_posting::types::ExternalCommentResponse;
+
+/// Replies imported from the syndicated copies of a post, listed under the
+/// native comments. Read-only: no like / reply actions, and each item
+/// links back to the reply on its platform. Renders nothing when there
+/// are no imported replies.
+#[component]
+pub fn ExternalComments(post_id: FeedPartition) -> Element {
+    let UseExternalComments { comments } = use_external_comments(post_id)?;
+    let t: ExternalCommentsTranslate = use_translate();
+
+    let list = comments();
+    if list.is_empty() {
+        return rsx! {};
+    }
+    let total = list.len();
+
+    rsx! {
+        section { class: "ext-comments", "data-testid": "external-comments",
+            div { class: "ext-comments__head",
+                span { class: "ext-comments__title", "{t.title}" }
+                span { class: "ext-comments__count", "{total}" }
+            }
+            p { class: "ext-comments__hint", "{t.hint}" }
+            div { class: "ext-comments__list",
+                for (idx, comment) in list.into_iter().enumerate() {
+                    ExternalCommentItem { key: "{idx}", comment }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn ExternalCommentItem(comment: ExternalCommentResponse) -> Element {
+    let t: ExternalCommentsTranslate = use_translate();
+    let platform = comment.platform.display_name();
+    let posted = (comment.posted_at > 0).then(|| time_ago(comment.posted_at));
+
+    rsx! {
+        article {
+            class: "ext-comment",
+            "data-platform": "{comment.platform}",
+            if let Some(avatar) = comment.author_avatar_url.clone() {
+                img {
+                    class: "ext-comment__avatar",
+                    src: "{avatar}",
+                    alt: "{comment.author_display_name}",
+                }
+            } else {
+                div { class: "ext-comment__avatar ext-comment__avatar--empty" }
+            }
+            div { class: "ext-comment__body",
+                div { class: "ext-comment__top",
+                    span { class: "ext-comment__name", "{comment.author_display_name}" }
+                    span { class: "ext-comment__handle", "{comment.author_handle}" }
+                    span { class: "ext-comment__badge", "{platform}" }
+                    if let Some(posted) = posted {
+                        span { class: "ext-comment__time", "{posted}" }
+                    }
+                }
+                div { class: "ext-comment__text", "{comment.content}" }
+                if !comment.url.is_empty() {
+                    a {
+                        class: "ext-comment__link",
+                        href: "{comment.url}",
+                        target: "_blank",
+                        rel: "noopener noreferrer",
+                        "{t.view_on} {platform}"
+                    }
+                }
+            }
+        }
+    }
+}
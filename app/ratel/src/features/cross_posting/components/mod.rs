@@ -4,13 +4,21 @@
 // PR E3 (1A) — Post-detail author syndication panel (`post_detail`).
 // PR E1 (1A) — Bluesky connect modal (used by both ConnectionsPage and
 //              OnboardingPage views).
+// Mastodon server picker — reuses the Bluesky modal styling.
+// Imported replies — read-only list under the post-detail comments.
+// Team settings — the team's shared accounts and approval queue.
+// Connections page — cross-posting analytics dashboard.
 //
 // Page-level routed components live under `features::cross_posting::views`
 // (per `feature-module-structure.md` "Page-level views → views/<page>/").
 
 pub mod bluesky_connect_modal;
 pub mod compose_sidebar;
+pub mod cross_posting_analytics;
+pub mod external_comments;
+pub mod mastodon_connect_modal;
 pub mod syndication_panel;
+pub mod team_connections;
 
 // Explicit re-exports — glob would clash on internal helpers like
 // `PlatformLogo` that compose_sidebar and syndication_panel both define
@@ -18,4 +26,8 @@ pub mod syndication_panel;
 // `<Name>Props` types alongside even private fns).
 pub use bluesky_connect_modal::BlueskyConnectModal;
 pub use compose_sidebar::CrossPostSidebar;
+pub use cross_posting_analytics::CrossPostingAnalytics;
+pub use external_comments::ExternalComments;
+pub use mastodon_connect_modal::MastodonConnectModal;
 pub use syndication_panel::SyndicationPanel;
+pub use team_connections::TeamConnections;
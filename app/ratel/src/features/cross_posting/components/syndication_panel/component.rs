@@ -2,12 +2,15 @@ use crate::common::*;
 use crate::features::auth::hooks::use_user_context;
 use crate::features::cross_posting::hooks::{UseSyndicationPanel, use_syndication_panel};
 use crate::features::cross_posting::i18n::SyndicationPanelTranslate;
-use crate::features::cross_posting::models::{ConnectionStatus, JobState};
-use crate::features::cross_posting::types::{SocialPlatform, SyndicationJobView};
+use crate::features::cross_posting::models::{AuditAction, ConnectionStatus, JobState};
+use crate::features::cross_posting::types::{
+    AuditEntryView, SocialPlatform, SyndicationJobView,
+};
 
 /// Author-only post-detail panel.
 ///
-/// Always mounts the full platform matrix (Bluesky / LinkedIn / Threads)
+/// Always mounts the full platform matrix (Bluesky / LinkedIn / Threads /
+/// Mastodon)
 /// when the parent post is Public, so the author sees a coherent view
 /// straight after publish — no waiting for the async Stage 1 factory to
 /// insert SyndicationJob rows. Each card renders one of four states:
@@ -17,7 +20,10 @@ use crate::features::cross_posting::types::{SocialPlatform, SyndicationJobView};
 ///   - `Bluesky connected + no job yet` — "Awaiting dispatch" placeholder.
 ///     The header refresh button re-fetches when the row arrives.
 ///   - `Bluesky not connected` — Connect CTA → Settings → Connections.
-///   - `LinkedIn / Threads` — static "Coming soon" until 1B / 1C ship.
+///   - `LinkedIn / Threads / Mastodon` — the same three states as Bluesky.
+///
+/// Below the matrix, the audit history lists who requested, approved or
+/// retried each cross-post — the trail team posts are reviewed against.
 #[component]
 pub fn SyndicationPanel(post_id: FeedPartition) -> Element {
     let mut sp = use_syndication_panel(post_id)?;
@@ -32,6 +38,7 @@ pub fn SyndicationPanel(post_id: FeedPartition) -> Element {
         return rsx! {};
     };
     let jobs = data.jobs;
+    let audit = data.audit;
     let conn_list = connections();
 
     let total = jobs.len();
@@ -68,6 +75,20 @@ pub fn SyndicationPanel(post_id: FeedPartition) -> Element {
     let linkedin_connected = conn_list.iter().any(|c| {
         c.platform == SocialPlatform::LinkedIn && c.status == ConnectionStatus::Connected
     });
+    let threads_job = jobs
+        .iter()
+        .find(|j| j.platform == SocialPlatform::Threads)
+        .cloned();
+    let threads_connected = conn_list.iter().any(|c| {
+        c.platform == SocialPlatform::Threads && c.status == ConnectionStatus::Connected
+    });
+    let mastodon_job = jobs
+        .iter()
+        .find(|j| j.platform == SocialPlatform::Mastodon)
+        .cloned();
+    let mastodon_connected = conn_list.iter().any(|c| {
+        c.platform == SocialPlatform::Mastodon && c.status == ConnectionStatus::Connected
+    });
 
     let username = user_ctx
         .read()
@@ -135,6 +156,11 @@ pub fn SyndicationPanel(post_id: FeedPartition) -> Element {
                                 toast.error(e);
                             }
                         },
+                        on_cancel: move |p: SocialPlatform| async move {
+                            if let Err(e) = sp.cancel(p).await {
+                                toast.error(e);
+                            }
+                        },
                     }
                 } else if bsky_connected {
                     AwaitingDispatchCard { platform: SocialPlatform::Bluesky }
@@ -153,6 +179,11 @@ pub fn SyndicationPanel(post_id: FeedPartition) -> Element {
                                 toast.error(e);
                             }
                         },
+                        on_cancel: move |p: SocialPlatform| async move {
+                            if let Err(e) = sp.cancel(p).await {
+                                toast.error(e);
+                            }
+                        },
                     }
                 } else if linkedin_connected {
                     AwaitingDispatchCard { platform: SocialPlatform::LinkedIn }
@@ -162,15 +193,114 @@ pub fn SyndicationPanel(post_id: FeedPartition) -> Element {
                         username: username.clone(),
                     }
                 }
-                ComingSoonCard {
-                    platform: SocialPlatform::Threads,
-                    hint: t.panel_threads_coming_soon_hint,
+                // Threads — same three sub-states as Bluesky.
+                if let Some(job) = threads_job {
+                    SyndicationCard {
+                        job,
+                        on_retry: move |p: SocialPlatform| async move {
+                            if let Err(e) = sp.retry(p).await {
+                                toast.error(e);
+                            }
+                        },
+                        on_cancel: move |p: SocialPlatform| async move {
+                            if let Err(e) = sp.cancel(p).await {
+                                toast.error(e);
+                            }
+                        },
+                    }
+                } else if threads_connected {
+                    AwaitingDispatchCard { platform: SocialPlatform::Threads }
+                } else {
+                    NotConnectedCard {
+                        platform: SocialPlatform::Threads,
+                        username: username.clone(),
+                    }
+                }
+                // Mastodon — same three sub-states as Bluesky.
+                if let Some(job) = mastodon_job {
+                    SyndicationCard {
+                        job,
+                        on_retry: move |p: SocialPlatform| async move {
+                            if let Err(e) = sp.retry(p).await {
+                                toast.error(e);
+                            }
+                        },
+                        on_cancel: move |p: SocialPlatform| async move {
+                            if let Err(e) = sp.cancel(p).await {
+                                toast.error(e);
+                            }
+                        },
+                    }
+                } else if mastodon_connected {
+                    AwaitingDispatchCard { platform: SocialPlatform::Mastodon }
+                } else {
+                    NotConnectedCard {
+                        platform: SocialPlatform::Mastodon,
+                        username: username.clone(),
+                    }
+                }
+            }
+
+            if !audit.is_empty() {
+                AuditHistory { entries: audit }
+            }
+        }
+    }
+}
+
+/// Newest-first audit trail: who requested, approved, rejected, retried
+/// or cancelled each cross-post, and when it went out.
+#[component]
+fn AuditHistory(entries: Vec<AuditEntryView>) -> Element {
+    let t: SyndicationPanelTranslate = use_translate();
+
+    rsx! {
+        div { class: "syn-audit", "data-testid": "syn-audit",
+            h4 { class: "syn-audit__title", "{t.audit_title}" }
+            ul { class: "syn-audit__list",
+                for (i , entry) in entries.into_iter().enumerate() {
+                    li { key: "{i}", class: "syn-audit__item",
+                        span { class: "syn-audit__when", "{local_time(entry.created_at)}" }
+                        span { class: "syn-audit__what",
+                            strong {
+                                if entry.actor_name.is_empty() {
+                                    "{t.audit_unknown_actor}"
+                                } else {
+                                    "{entry.actor_name}"
+                                }
+                            }
+                            " {audit_action_label(entry.action, &t)} · {entry.platform.display_name()}"
+                        }
+                        if let Some(url) = entry.external_post_url {
+                            a {
+                                class: "syn-audit__link",
+                                href: "{url}",
+                                target: "_blank",
+                                "{t.btn_view}"
+                            }
+                        }
+                    }
                 }
             }
         }
     }
 }
 
+fn local_time(ms: i64) -> String {
+    crate::common::utils::time::epoch_ms_to_datetime_local(ms).replace('T', " ")
+}
+
+fn audit_action_label(action: AuditAction, tr: &SyndicationPanelTranslate) -> &'static str {
+    match action {
+        AuditAction::Requested => tr.audit_requested,
+        AuditAction::Approved => tr.audit_approved,
+        AuditAction::Rejected => tr.audit_rejected,
+        AuditAction::Cancelled => tr.audit_cancelled,
+        AuditAction::Retried => tr.audit_retried,
+        AuditAction::Published => tr.audit_published,
+    }
+}
+
 /// Connected-but-no-job-yet card. Stage 1 factory enqueues
 /// SyndicationJob rows asynchronously after publish (~1-3s); this card
 /// fills the gap so the panel never renders empty after a Public publish.
@@ -181,6 +311,7 @@ fn AwaitingDispatchCard(platform: SocialPlatform) -> Element {
         SocialPlatform::Bluesky => "syn-logo syn-logo--bsky",
         SocialPlatform::LinkedIn => "syn-logo syn-logo--linkedin",
         SocialPlatform::Threads => "syn-logo syn-logo--threads",
+        SocialPlatform::Mastodon => "syn-logo syn-logo--mastodon",
     };
     rsx! {
         article { class: "syn-card", "data-status": "awaiting",
@@ -199,7 +330,8 @@ fn AwaitingDispatchCard(platform: SocialPlatform) -> Element {
     }
 }
 
-/// Not-connected card for any active platform (Bluesky 1A, LinkedIn 1B).
+/// Not-connected card for any active platform (Bluesky 1A, LinkedIn 1B,
+/// Threads 1C, Mastodon).
 /// Surfaces a Connect CTA pointing at Settings → Connections so the
 /// author can hook up the platform without leaving post-detail context.
 /// The label + testid switch on `platform` so each surface remains
@@ -211,11 +343,13 @@ fn NotConnectedCard(platform: SocialPlatform, username: String) -> Element {
         SocialPlatform::Bluesky => "syn-logo syn-logo--bsky",
         SocialPlatform::LinkedIn => "syn-logo syn-logo--linkedin",
         SocialPlatform::Threads => "syn-logo syn-logo--threads",
+        SocialPlatform::Mastodon => "syn-logo syn-logo--mastodon",
     };
     let (btn_label, btn_testid) = match platform {
         SocialPlatform::Bluesky => (t.btn_connect_bluesky, "syn-connect-bluesky"),
         SocialPlatform::LinkedIn => (t.btn_connect_linkedin, "syn-connect-linkedin"),
-        SocialPlatform::Threads => (t.btn_connect_bluesky, "syn-connect-threads"),
+        SocialPlatform::Threads => (t.btn_connect_threads, "syn-connect-threads"),
+        SocialPlatform::Mastodon => (t.btn_connect_mastodon, "syn-connect-mastodon"),
     };
     rsx! {
         article { class: "syn-card", "data-status": "not-connected",
@@ -244,42 +378,12 @@ fn NotConnectedCard(platform: SocialPlatform, username: String) -> Element {
     }
 }
 
-/// Static placeholder for platforms not yet implemented (LinkedIn 1B,
-/// Threads 1C). Mirrors the compose-sidebar's "Coming soon" affordance
-/// so the matrix stays consistent across the two surfaces.
-#[component]
-fn ComingSoonCard(platform: SocialPlatform, hint: &'static str) -> Element {
-    let t: SyndicationPanelTranslate = use_translate();
-    let logo_class = match platform {
-        SocialPlatform::Bluesky => "syn-logo syn-logo--bsky",
-        SocialPlatform::LinkedIn => "syn-logo syn-logo--linkedin",
-        SocialPlatform::Threads => "syn-logo syn-logo--threads",
-    };
-    rsx! {
-        article {
-            class: "syn-card",
-            "data-status": "coming-soon",
-            "data-soon": "true",
-            div { class: "syn-card__body",
-                span { class: "{logo_class}",
-                    PlatformLogo { platform }
-                }
-                div { class: "syn-card__main",
-                    div { class: "syn-card__name-row",
-                        span { class: "syn-card__name", "{platform.display_name()}" }
-                        span { class: "status-pill status-pill--soon", "{t.panel_coming_soon}" }
-                    }
-                    div { class: "syn-card__sub",
-                        span { class: "syn-card__sub-item", "{hint}" }
-                    }
-                }
-            }
-        }
-    }
-}
-
 #[component]
-fn SyndicationCard(job: SyndicationJobView, on_retry: EventHandler<SocialPlatform>) -> Element {
+fn SyndicationCard(
+    job: SyndicationJobView,
+    on_retry: EventHandler<SocialPlatform>,
+    on_cancel: EventHandler<SocialPlatform>,
+) -> Element {
     let t: SyndicationPanelTranslate = use_translate();
 
     let status_data = match job.state {
@@ -287,27 +391,45 @@ fn SyndicationCard(job: SyndicationJobView, on_retry: EventHandler<SocialPlatfor
         JobState::Pending => "pending",
         JobState::Failed => "failed",
         JobState::Skipped => "skipped",
+        JobState::Scheduled => "scheduled",
+        JobState::Cancelled => "cancelled",
+        JobState::AwaitingApproval => "pending",
+        JobState::Rejected => "skipped",
     };
     let pill_class = match job.state {
         JobState::Published => "status-pill status-pill--success",
         JobState::Pending => "status-pill status-pill--pending",
         JobState::Failed => "status-pill status-pill--failed",
         JobState::Skipped => "status-pill status-pill--skipped",
+        JobState::Scheduled => "status-pill status-pill--scheduled",
+        JobState::Cancelled => "status-pill status-pill--skipped",
+        JobState::AwaitingApproval => "status-pill status-pill--pending",
+        JobState::Rejected => "status-pill status-pill--skipped",
     };
     let pill_label = match job.state {
         JobState::Published => t.status_published,
         JobState::Pending => t.status_pending,
         JobState::Failed => t.status_failed,
         JobState::Skipped => t.status_skipped,
+        JobState::Scheduled => t.status_scheduled,
+        JobState::Cancelled => t.status_cancelled,
+        JobState::AwaitingApproval => t.status_awaiting_approval,
+        JobState::Rejected => t.status_rejected,
     };
     let logo_class = match job.platform {
         SocialPlatform::Bluesky => "syn-logo syn-logo--bsky",
         SocialPlatform::LinkedIn => "syn-logo syn-logo--linkedin",
         SocialPlatform::Threads => "syn-logo syn-logo--threads",
+        SocialPlatform::Mastodon => "syn-logo syn-logo--mastodon",
     };
 
     let attempts = job.attempts;
     let platform = job.platform;
+    let thread_len = job.thread_len;
+    let scheduled_for = job
+        .scheduled_at
+        .map(|at| crate::common::utils::time::epoch_ms_to_datetime_local(at).replace('T', " "))
+        .unwrap_or_default();
 
     rsx! {
         article { class: "syn-card", "data-status": "{status_data}",
@@ -369,14 +491,97 @@ fn SyndicationCard(job: SyndicationJobView, on_retry: EventHandler<SocialPlatfor
                             }
                         }
                     }
+                    if job.state == JobState::AwaitingApproval {
+                        div { class: "syn-card__sub",
+                            span { class: "syn-card__sub-item",
+                                svg {
+                                    "viewBox": "0 0 24 24",
+                                    "fill": "none",
+                                    "stroke": "currentColor",
+                                    "stroke-width": "2",
+                                    "stroke-linecap": "round",
+                                    "stroke-linejoin": "round",
+                                    path { "d": "M17 21v-2a4 4 0 0 0-4-4H5a4 4 0 0 0-4 4v2" }
+                                    circle { "cx": "9", "cy": "7", "r": "4" }
+                                    polyline { "points": "16 11 18 13 22 9" }
+                                }
+                                span { "{t.approval_hint}" }
+                            }
+                        }
+                    }
+                    if job.state == JobState::Published && thread_len > 1 {
+                        div { class: "syn-card__sub",
+                            span { class: "syn-card__sub-item",
+                                svg {
+                                    "viewBox": "0 0 24 24",
+                                    "fill": "none",
+                                    "stroke": "currentColor",
+                                    "stroke-width": "2",
+                                    "stroke-linecap": "round",
+                                    "stroke-linejoin": "round",
+                                    path { "d": "M21 15a2 2 0 0 1-2 2H7l-4 4V5a2 2 0 0 1 2-2h14a2 2 0 0 1 2 2z" }
+                                }
+                                span { "{thread_len} {t.thread_posts}" }
+                            }
+                        }
+                    }
+                    if job.state == JobState::Scheduled {
+                        div { class: "syn-card__sub",
+                            span { class: "syn-card__sub-item",
+                                svg {
+                                    "viewBox": "0 0 24 24",
+                                    "fill": "none",
+                                    "stroke": "currentColor",
+                                    "stroke-width": "2",
+                                    "stroke-linecap": "round",
+                                    "stroke-linejoin": "round",
+                                    rect {
+                                        "x": "3",
+                                        "y": "4",
+                                        "width": "18",
+                                        "height": "18",
+                                        "rx": "2",
+                                    }
+                                    line {
+                                        "x1": "16",
+                                        "y1": "2",
+                                        "x2": "16",
+                                        "y2": "6",
+                                    }
+                                    line {
+                                        "x1": "8",
+                                        "y1": "2",
+                                        "x2": "8",
+                                        "y2": "6",
+                                    }
+                                    line {
+                                        "x1": "3",
+                                        "y1": "10",
+                                        "x2": "21",
+                                        "y2": "10",
+                                    }
+                                }
+                                span { "{t.scheduled_for} {scheduled_for}" }
+                            }
+                        }
+                    }
                 }
                 div { class: "syn-card__actions",
                     // Published → External "View" link to the platform post.
+                    // Scheduled → Cancel (it goes out on its own when due).
+                    // AwaitingApproval / Rejected → nothing; a team admin
+                    // decides from the team settings approval queue.
                     // Anything else (Pending stuck, Failed of any category,
-                    // Skipped) → author-initiated Retry. Backend re-validates
-                    // the state; the only block is on Published rows so we
-                    // don't double-post.
-                    if job.state == JobState::Published {
+                    // Skipped, Cancelled) → author-initiated Retry. Backend
+                    // re-validates the state; Published rows are blocked so
+                    // we don't double-post.
+                    if job.state == JobState::Scheduled {
+                        button {
+                            class: "mini-btn",
+                            onclick: move |_| on_cancel.call(platform),
+                            "{t.btn_cancel}"
+                        }
+                    } else if job.state == JobState::Published {
                         if let Some(url) = job.external_post_url.as_ref() {
                             a {
                                 class: "mini-btn",
@@ -396,7 +601,7 @@ fn SyndicationCard(job: SyndicationJobView, on_retry: EventHandler<SocialPlatfor
                                 "{t.btn_view}"
                             }
                         }
-                    } else {
+                    } else if !matches!(job.state, JobState::AwaitingApproval | JobState::Rejected) {
                         button {
                             class: "mini-btn mini-btn--retry",
                             onclick: move |_| on_retry.call(platform),
@@ -484,5 +689,10 @@ fn PlatformLogo(platform: SocialPlatform) -> Element {
                 path { "d": "M12.186 24h-.007c-3.581-.024-6.334-1.205-8.184-3.509C2.35 18.44 1.5 15.586 1.472 12.01v-.017c.03-3.579.879-6.43 2.525-8.482C5.845 1.205 8.6.024 12.18 0h.014c2.746.02 5.043.725 6.826 2.098 1.677 1.29 2.858 3.13 3.509 5.467l-2.04.569c-1.104-3.96-3.898-5.984-8.304-6.015-2.91.022-5.11.936-6.54 2.717C4.307 6.504 3.616 8.914 3.589 12c.027 3.086.718 5.496 2.057 7.164 1.43 1.783 3.631 2.698 6.54 2.717 2.623-.02 4.358-.631 5.8-2.045 1.647-1.613 1.618-3.593 1.09-4.798z" }
             }
         },
+        SocialPlatform::Mastodon => rsx! {
+            svg { "viewBox": "0 0 24 24", "fill": "currentColor",
+                path { "d": "M23.268 5.313c-.35-2.578-2.617-4.61-5.304-5.004C17.51.242 15.792 0 11.813 0h-.03c-3.98 0-4.835.242-5.288.309C3.882.692 1.496 2.518.917 5.127.64 6.412.61 7.837.661 9.143c.074 1.874.088 3.745.26 5.611.118 1.24.325 2.47.62 3.68.55 2.237 2.777 4.098 4.96 4.857 2.336.792 4.849.923 7.256.38.265-.061.527-.132.786-.213.585-.184 1.27-.39 1.774-.753a.057.057 0 0 0 .023-.043v-1.809a.052.052 0 0 0-.02-.041.053.053 0 0 0-.046-.01 20.282 20.282 0 0 1-4.709.545c-2.73 0-3.463-1.284-3.674-1.818a5.593 5.593 0 0 1-.319-1.433.053.053 0 0 1 .066-.054c1.517.363 3.072.546 4.632.546.376 0 .75 0 1.125-.01 1.57-.044 3.224-.124 4.768-.422.038-.008.077-.015.11-.024 2.435-.464 4.753-1.92 4.989-5.604.008-.145.03-1.52.03-1.67.002-.512.167-3.63-.024-5.545zm-3.748 9.195h-2.561V8.29c0-1.309-.55-1.976-1.67-1.976-1.23 0-1.846.79-1.846 2.35v3.403h-2.546V8.663c0-1.56-.617-2.35-1.848-2.35-1.112 0-1.668.668-1.67 1.977v6.218H4.822V8.102c0-1.31.337-2.35 1.011-3.12.696-.77 1.608-1.164 2.74-1.164 1.311 0 2.302.5 2.962 1.498l.638 1.06.638-1.06c.66-.999 1.65-1.498 2.96-1.498 1.13 0 2.043.395 2.74 1.164.675.77 1.012 1.81 1.012 3.12z" }
+            }
+        },
     }
 }
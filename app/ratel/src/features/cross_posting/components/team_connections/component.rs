@@ -0,0 +1,262 @@
+use crate::common::*;
+use crate::features::cross_posting::components::bluesky_connect_modal::BlueskyConnectModal;
+use crate::features::cross_posting::hooks::{UseTeamConnections, use_team_connections};
+use crate::features::cross_posting::i18n::TeamConnectionsTranslate;
+use crate::features::cross_posting::models::ConnectionStatus;
+use crate::features::cross_posting::types::{ApprovalResponse, ConnectionResponse, SocialPlatform};
+
+/// Team settings section for the team's own social accounts; the parent
+/// mounts it for admins only.
+///
+/// Team posts syndicate through these rows instead of the author's
+/// personal connections. Bluesky connects with an app password; LinkedIn
+/// connects the company page the admin manages. Each connected row
+/// carries the "Require approval" switch, and the queue underneath lists
+/// cross-posts parked until an admin approves or rejects them.
+#[component]
+pub fn TeamConnections(team_pk: TeamPartition) -> Element {
+    let mut tc = use_team_connections(team_pk)?;
+    let UseTeamConnections {
+        connections,
+        approvals,
+        ..
+    } = tc;
+    let mut modal_open = use_signal(|| false);
+    let mut toast = use_toast();
+    let t: TeamConnectionsTranslate = use_translate();
+
+    let conn_list = connections();
+    let connected = |platform: SocialPlatform| {
+        conn_list
+            .iter()
+            .find(|c| c.platform == platform && c.status == ConnectionStatus::Connected)
+            .cloned()
+    };
+    let bsky = connected(SocialPlatform::Bluesky);
+    let linkedin = connected(SocialPlatform::LinkedIn);
+
+    rsx! {
+        BlueskyConnectModal {
+            open: modal_open,
+            on_submit: move |(handle, app_password): (String, String)| async move {
+                if let Err(e) = tc.connect_bluesky(handle, app_password).await {
+                    toast.error(e);
+                    return;
+                }
+                modal_open.set(false);
+            },
+        }
+
+        section { class: "flex flex-col gap-4", "data-testid": "team-connections",
+            div { class: "flex flex-col gap-1",
+                h3 { class: "text-lg font-semibold text-text-primary", "{t.title}" }
+                p { class: "text-sm text-foreground-muted", "{t.subtitle}" }
+            }
+
+            div { class: "platforms",
+                TeamConnectionRow {
+                    platform: SocialPlatform::Bluesky,
+                    connection: bsky,
+                    on_connect: move |_| modal_open.set(true),
+                    on_disconnect: move |p: SocialPlatform| async move {
+                        if let Err(e) = tc.disconnect(p).await {
+                            toast.error(e);
+                        }
+                    },
+                    on_toggle_approval: move |(p, on): (SocialPlatform, bool)| async move {
+                        if let Err(e) = tc.set_approval_required(p, on).await {
+                            toast.error(e);
+                        }
+                    },
+                }
+                TeamConnectionRow {
+                    platform: SocialPlatform::LinkedIn,
+                    connection: linkedin,
+                    on_connect: move |_| async move {
+                        if let Err(e) = tc.connect_linkedin().await {
+                            toast.error(e);
+                        }
+                    },
+                    on_disconnect: move |p: SocialPlatform| async move {
+                        if let Err(e) = tc.disconnect(p).await {
+                            toast.error(e);
+                        }
+                    },
+                    on_toggle_approval: move |(p, on): (SocialPlatform, bool)| async move {
+                        if let Err(e) = tc.set_approval_required(p, on).await {
+                            toast.error(e);
+                        }
+                    },
+                }
+            }
+
+            ApprovalQueue {
+                items: approvals(),
+                on_approve: move |item: ApprovalResponse| async move {
+                    if let Err(e) = tc.approve(item.post_id, item.platform).await {
+                        toast.error(e);
+                    }
+                },
+                on_reject: move |item: ApprovalResponse| async move {
+                    if let Err(e) = tc.reject(item.post_id, item.platform).await {
+                        toast.error(e);
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// One platform row — same `plat` card as Settings → Connections, minus
+/// the per-user toggles.
+#[component]
+fn TeamConnectionRow(
+    platform: SocialPlatform,
+    connection: Option<ConnectionResponse>,
+    on_connect: EventHandler<()>,
+    on_disconnect: EventHandler<SocialPlatform>,
+    on_toggle_approval: EventHandler<(SocialPlatform, bool)>,
+) -> Element {
+    let t: TeamConnectionsTranslate = use_translate();
+    let (data_platform, logo_class, hint) = match platform {
+        SocialPlatform::LinkedIn => (
+            "linkedin",
+            "plat__logo plat__logo--linkedin",
+            t.linkedin_hint,
+        ),
+        _ => ("bsky", "plat__logo plat__logo--bsky", t.bluesky_hint),
+    };
+    let is_connected = connection.is_some();
+
+    rsx! {
+        article {
+            class: "plat",
+            "data-platform": "{data_platform}",
+            "data-connected": "{is_connected}",
+            div { class: "plat__body",
+                span { class: "{logo_class}",
+                    svg { "viewBox": "0 0 24 24", "fill": "currentColor",
+                        if platform == SocialPlatform::LinkedIn {
+                            path { "d": "M20.447 20.452h-3.554v-5.569c0-1.328-.027-3.037-1.852-3.037-1.853 0-2.136 1.445-2.136 2.939v5.667H9.351V9h3.414v1.561h.046c.477-.9 1.637-1.85 3.37-1.85 3.601 0 4.267 2.37 4.267 5.455v6.286zM5.337 7.433a2.062 2.062 0 01-2.063-2.065 2.063 2.063 0 112.063 2.065zm1.782 13.019H3.555V9h3.564v11.452zM22.225 0H1.771C.792 0 0 .774 0 1.729v20.542C0 23.227.792 24 1.771 24h20.451C23.2 24 24 23.227 24 22.271V1.729C24 .774 23.2 0 22.222 0h.003z" }
+                        } else {
+                            path { "d": "M12 10.5c-1.3-2.5-4.9-7.2-8.2-9.5C.7-1.2 0 .5 0 1.5c0 1.1.6 9.1 1 10.3.8 4 5.5 5.1 9.6 4.4-6.5 1.1-12.3 3.5-4.7 11.4 2.5 2.6 3.5-2.6 4-5.2.5 2.6 1.6 7.8 4 5.2 7.7-7.9 1.9-10.4-4.6-11.5 4 .7 8.8-.4 9.6-4.4.4-1.2 1-9.2 1-10.3 0-1-.7-2.7-3.8-.5-3.3 2.3-6.9 7-8.2 9.6z" }
+                        }
+                    }
+                }
+                div { class: "plat__main",
+                    div { class: "plat__name-row",
+                        span { class: "plat__name", "{platform.display_name()}" }
+                        if is_connected {
+                            span { class: "status-pill status-pill--connected",
+                                "{t.status_connected}"
+                            }
+                        } else {
+                            span { class: "status-pill status-pill--off",
+                                "{t.status_not_connected}"
+                            }
+                        }
+                    }
+                    div { class: "plat__handle",
+                        if let Some(c) = connection.as_ref() {
+                            span { "{c.external_handle}" }
+                        } else {
+                            span { "{hint}" }
+                        }
+                    }
+                }
+                div { class: "plat__actions",
+                    if is_connected {
+                        button {
+                            class: "connections-btn connections-btn--ghost",
+                            onclick: move |_| on_disconnect.call(platform),
+                            "{t.btn_disconnect}"
+                        }
+                    } else {
+                        button {
+                            class: "connections-btn connections-btn--connect-{data_platform}",
+                            onclick: move |_| on_connect.call(()),
+                            "{t.btn_connect}"
+                        }
+                    }
+                }
+            }
+            if let Some(c) = connection {
+                {
+                    let approval_required = c.approval_required;
+                    rsx! {
+                        div { class: "plat__subrow",
+                            div { class: "plat__subrow-item",
+                                span { "{t.approval_required}" }
+                                button {
+                                    class: "switch",
+                                    "aria-checked": "{approval_required}",
+                                    "aria-label": "{t.approval_required}",
+                                    title: "{t.approval_required_hint}",
+                                    onclick: move |_| on_toggle_approval.call((platform, !approval_required)),
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Cross-posts waiting for an admin, oldest first. Renders nothing when
+/// the queue is empty.
+#[component]
+fn ApprovalQueue(
+    items: Vec<ApprovalResponse>,
+    on_approve: EventHandler<ApprovalResponse>,
+    on_reject: EventHandler<ApprovalResponse>,
+) -> Element {
+    let t: TeamConnectionsTranslate = use_translate();
+    if items.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        section { class: "cp-queue", "data-testid": "team-approval-queue",
+            h3 { class: "cp-queue__title", "{t.approvals_title}" }
+            ul { class: "cp-queue__list",
+                for item in items {
+                    li {
+                        key: "{item.post_id}-{item.platform}",
+                        class: "cp-queue__item",
+                        div { class: "cp-queue__main",
+                            span { class: "cp-queue__when",
+                                "{item.platform.display_name()} · {t.approvals_by} {item.requested_by}"
+                            }
+                            span { class: "cp-queue__post",
+                                if item.post_title.is_empty() {
+                                    "{t.approvals_untitled}"
+                                } else {
+                                    "{item.post_title}"
+                                }
+                            }
+                        }
+                        div { class: "flex gap-2",
+                            button {
+                                class: "cp-queue__approve",
+                                onclick: {
+                                    let item = item.clone();
+                                    move |_| on_approve.call(item.clone())
+                                },
+                                "{t.btn_approve}"
+                            }
+                            button {
+                                class: "cp-queue__cancel",
+                                onclick: {
+                                    let item = item.clone();
+                                    move |_| on_reject.call(item.clone())
+                                },
+                                "{t.btn_reject}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
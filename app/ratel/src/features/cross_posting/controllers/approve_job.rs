@@ -0,0 +1,24 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
+
+/// Approve a team cross-post waiting in the approval queue. It goes out
+/// now, or at its scheduled time if that is still ahead. Team admins
+/// only; `ReviewNotAllowed` once the job has left the queue.
+#[post("/api/cross-posting/posts/{post_id}/jobs/{platform}/approve", user: User)]
+pub async fn approve_job_handler(post_id: FeedPartition, platform: SocialPlatform) -> Result<()> {
+    use crate::features::cross_posting::services::approval;
+
+    let conf = crate::features::posts::config::get();
+    let cli = conf.dynamodb();
+
+    let post_pk: Partition = post_id.into();
+    let job = approval::job_for_review(cli, &post_pk, platform, &user.pk).await?;
+
+    let now = crate::common::utils::time::now();
+    if !approval::review(cli, &job, true, &user.pk, now).await? {
+        return Err(CrossPostingError::ReviewNotAllowed.into());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::{
+    AuditAction, JobState, SyndicationAuditEntry, SyndicationJob,
+};
+use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
+use crate::features::posts::models::Post;
+
+/// Author-initiated cancel of a queued cross-post. Only `Scheduled` jobs
+/// can be cancelled — once the scheduler has released a job to `Pending`
+/// the dispatcher may already be talking to the platform. The cancelled
+/// job stays on the syndication panel and can still be sent with Retry.
+#[post("/api/cross-posting/posts/{post_id}/jobs/{platform}/cancel", user: User)]
+pub async fn cancel_job_handler(post_id: FeedPartition, platform: SocialPlatform) -> Result<()> {
+    use crate::features::cross_posting::services::{scheduler, team_access};
+
+    let conf = crate::features::posts::config::get();
+    let cli = conf.dynamodb();
+
+    let post_pk: Partition = post_id.into();
+
+    let post = Post::get(cli, &post_pk, Some(EntityType::Post))
+        .await
+        .map_err(|e| {
+            crate::error!("cancel_job post lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::NotAuthorized)?;
+
+    if !team_access::can_view_post_jobs(cli, &post, &user.pk).await? {
+        return Err(CrossPostingError::NotAuthorized.into());
+    }
+
+    let sk = EntityType::SyndicationJob(platform.to_string());
+    let job = SyndicationJob::get(cli, &post_pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("cancel_job lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::SyndicationJobNotFound)?;
+
+    if job.state != JobState::Scheduled {
+        return Err(CrossPostingError::CancelNotAllowed.into());
+    }
+
+    let now = crate::common::utils::time::now();
+    // Conditional on `state = scheduled`: losing the race to a scheduler
+    // tick means the job is already on its way out.
+    if !scheduler::release_scheduled(cli, &job, JobState::Cancelled, now).await? {
+        return Err(CrossPostingError::CancelNotAllowed.into());
+    }
+    SyndicationAuditEntry::record(cli, &job, AuditAction::Cancelled, user.pk, now).await;
+
+    Ok(())
+}
@@ -27,6 +27,19 @@ pub async fn connect_linkedin_init_handler(
 ) -> Result<LinkedInOauthInitResponse> {
     use crate::features::cross_posting::services::oauth_state;
 
+    let state = oauth_state::encode(&user.pk, req.return_to.as_deref()).map_err(|e| {
+        crate::error!("connect_linkedin_init state encode failed: {e}");
+        CrossPostingError::LinkedInAuthFailed
+    })?;
+
+    let authorize_url = linkedin_authorize_url(LINKEDIN_SCOPES, &state)?;
+    Ok(LinkedInOauthInitResponse { authorize_url })
+}
+
+/// LinkedIn consent page URL for `scopes`, carrying the signed `state`.
+/// Shared with the team connect flow, which asks for organization scopes.
+#[cfg(feature = "server")]
+pub fn linkedin_authorize_url(scopes: &str, state: &str) -> Result<String> {
     let client_id = option_env!("LINKEDIN_CLIENT_ID")
         .filter(|v| !v.is_empty())
         .ok_or_else(|| {
@@ -34,11 +47,6 @@ pub async fn connect_linkedin_init_handler(
             CrossPostingError::LinkedInAuthFailed
         })?;
 
-    let state = oauth_state::encode(&user.pk, req.return_to.as_deref()).map_err(|e| {
-        crate::error!("connect_linkedin_init state encode failed: {e}");
-        CrossPostingError::LinkedInAuthFailed
-    })?;
-
     // Redirect URI must EXACTLY match the value registered under "Authorized
     // redirect URLs" in the LinkedIn Developer Portal — including scheme,
     // host, port, and trailing-slash. We register one URL per Ratel env
@@ -50,7 +58,7 @@ pub async fn connect_linkedin_init_handler(
     // screen even on re-auth — useful for testing, but skipped in
     // production so existing users with valid sessions can reconnect
     // in one click.
-    let authorize_url = format!(
+    Ok(format!(
         "{host}/oauth/v2/authorization?response_type=code\
          &client_id={cid}\
          &redirect_uri={redir}\
@@ -59,11 +67,9 @@ pub async fn connect_linkedin_init_handler(
         host = LINKEDIN_AUTHORIZE_HOST,
         cid = urlencoding::encode(client_id),
         redir = urlencoding::encode(&redirect_uri),
-        scopes = urlencoding::encode(LINKEDIN_SCOPES),
-        state = urlencoding::encode(&state),
-    );
-
-    Ok(LinkedInOauthInitResponse { authorize_url })
+        scopes = urlencoding::encode(scopes),
+        state = urlencoding::encode(state),
+    ))
 }
 
 /// Build the canonical callback URL for the current Ratel environment.
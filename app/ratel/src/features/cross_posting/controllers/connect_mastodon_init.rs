@@ -0,0 +1,52 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::types::{
+    CrossPostingError, MastodonOauthInitRequest, MastodonOauthInitResponse,
+};
+
+/// Start the Mastodon OAuth flow against the instance the user typed in.
+/// Unlike LinkedIn / Threads there is no compile-time client id: the
+/// instance's app registration is looked up (or created on first use)
+/// here, and the normalized instance URL rides along in the signed state
+/// so the callback knows where to exchange the code.
+#[post(
+    "/api/cross-posting/connections/mastodon/init",
+    user: User
+)]
+pub async fn connect_mastodon_init_handler(
+    req: MastodonOauthInitRequest,
+) -> Result<MastodonOauthInitResponse> {
+    use crate::features::cross_posting::services::{adapters, mastodon_app, oauth_state};
+
+    // Resolves the host too, so nothing below — app registration
+    // included — ever reaches a private or loopback address.
+    let instance_url = adapters::normalize_instance(&req.instance)
+        .await
+        .ok_or(CrossPostingError::MastodonInvalidInstance)?;
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let redirect_uri = mastodon_redirect_uri();
+    let app = mastodon_app::find_or_register(cli, &instance_url, &redirect_uri).await?;
+
+    let state = oauth_state::encode_for_instance(&user.pk, req.return_to.as_deref(), &instance_url)
+        .map_err(|e| {
+            crate::error!("connect_mastodon_init state encode failed: {e}");
+            CrossPostingError::MastodonAuthFailed
+        })?;
+
+    let authorize_url =
+        adapters::authorize_url(&instance_url, &app.client_id, &redirect_uri, &state);
+
+    Ok(MastodonOauthInitResponse { authorize_url })
+}
+
+/// Canonical callback URL, registered as the app's only redirect URI on
+/// every instance and reused by the callback's code exchange.
+#[cfg(feature = "server")]
+pub fn mastodon_redirect_uri() -> String {
+    format!(
+        "{}/api/cross-posting/connections/mastodon/callback",
+        crate::common::config::site_base_url()
+    )
+}
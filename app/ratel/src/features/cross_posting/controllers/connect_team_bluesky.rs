@@ -0,0 +1,63 @@
+use crate::common::*;
+use crate::features::cross_posting::types::{
+    ConnectBlueskyRequest, ConnectionResponse, CrossPostingError, SocialPlatform,
+};
+use crate::features::posts::models::Team;
+use crate::features::social::pages::member::dto::TeamRole;
+
+#[cfg(feature = "server")]
+use crate::features::cross_posting::services::{
+    adapters::{BlueskyAdapter, DecryptedCredentials},
+    connection::{ConnectionUpsert, seal_and_upsert_connection},
+};
+
+/// Connect the team's shared Bluesky account with an app password. Same
+/// flow as [`super::connect_bluesky_handler`], stored under the team;
+/// admins only.
+#[post("/api/teams/:team_pk/cross-posting/connections/bluesky/connect", user: crate::features::auth::User, team: Team, role: TeamRole)]
+pub async fn connect_team_bluesky_handler(
+    team_pk: TeamPartition,
+    req: ConnectBlueskyRequest,
+) -> Result<ConnectionResponse> {
+    let _ = (team_pk, user);
+    if !role.is_admin_or_owner() {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+
+    let handle = req.handle.trim();
+    let app_password = req.app_password.trim();
+    if handle.is_empty() || app_password.is_empty() {
+        return Err(CrossPostingError::ConnectFailed.into());
+    }
+
+    let adapter = BlueskyAdapter::new();
+    let session = adapter
+        .create_session(handle, app_password)
+        .await
+        .map_err(|e| {
+            crate::error!("connect_team_bluesky createSession failed: {e}");
+            CrossPostingError::BlueskyAuthFailed
+        })?;
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let row = seal_and_upsert_connection(
+        cli,
+        ConnectionUpsert {
+            user_pk: team.pk.clone(),
+            platform: SocialPlatform::Bluesky,
+            decrypted: DecryptedCredentials::Bluesky {
+                did: session.did.clone(),
+                handle: session.handle.clone(),
+                access_jwt: session.access_jwt,
+                refresh_jwt: session.refresh_jwt,
+            },
+            external_handle: session.handle,
+            external_user_id: session.did,
+            token_expires_at: None,
+        },
+    )
+    .await?;
+
+    Ok(row.into())
+}
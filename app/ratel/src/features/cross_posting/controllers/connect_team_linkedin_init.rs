@@ -0,0 +1,39 @@
+use crate::common::*;
+use crate::features::cross_posting::types::{
+    CrossPostingError, LinkedInOauthInitRequest, LinkedInOauthInitResponse,
+};
+use crate::features::posts::models::Team;
+use crate::features::social::pages::member::dto::TeamRole;
+
+/// Personal scopes plus the two a company page needs:
+/// - `r_organization_admin` — find the page the admin manages
+///   (`/v2/organizationAcls`).
+/// - `w_organization_social` — post as `urn:li:organization:{id}`.
+const TEAM_LINKEDIN_SCOPES: &str =
+    "openid profile w_member_social w_organization_social r_organization_admin";
+
+/// Start connecting the team's LinkedIn company page. The signed state
+/// carries the team, so the shared callback stores the connection under
+/// it (after checking the user still manages the team); admins only.
+#[post("/api/teams/:team_pk/cross-posting/connections/linkedin/init", user: crate::features::auth::User, team: Team, role: TeamRole)]
+pub async fn connect_team_linkedin_init_handler(
+    team_pk: TeamPartition,
+    req: LinkedInOauthInitRequest,
+) -> Result<LinkedInOauthInitResponse> {
+    use super::connect_linkedin_init::linkedin_authorize_url;
+    use crate::features::cross_posting::services::oauth_state;
+
+    let _ = team_pk;
+    if !role.is_admin_or_owner() {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+
+    let state = oauth_state::encode_for_team(&user.pk, req.return_to.as_deref(), &team.pk)
+        .map_err(|e| {
+            crate::error!("connect_team_linkedin_init state encode failed: {e}");
+            CrossPostingError::LinkedInAuthFailed
+        })?;
+
+    let authorize_url = linkedin_authorize_url(TEAM_LINKEDIN_SCOPES, &state)?;
+    Ok(LinkedInOauthInitResponse { authorize_url })
+}
@@ -0,0 +1,70 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::types::{
+    CrossPostingError, ThreadsOauthInitRequest, ThreadsOauthInitResponse,
+};
+
+/// Threads OAuth scopes Phase 1C needs:
+/// - `threads_basic` — `/me` for the user id + username.
+/// - `threads_content_publish` — container create + `threads_publish`.
+/// - `threads_manage_insights` — per-post insights for the Stage 4
+///   engagement refresh.
+/// - `threads_read_replies` — `/{id}/conversation` for reply import.
+///   Connections made before it was requested get a permission error on
+///   import until the user reconnects; engagement is unaffected.
+const THREADS_SCOPES: &str =
+    "threads_basic,threads_content_publish,threads_manage_insights,threads_read_replies";
+
+#[cfg(feature = "server")]
+const THREADS_AUTHORIZE_HOST: &str = "https://threads.net";
+
+#[post(
+    "/api/cross-posting/connections/threads/init",
+    user: User
+)]
+pub async fn connect_threads_init_handler(
+    req: ThreadsOauthInitRequest,
+) -> Result<ThreadsOauthInitResponse> {
+    use crate::features::cross_posting::services::oauth_state;
+
+    let client_id = option_env!("THREADS_APP_ID")
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| {
+            crate::error!("THREADS_APP_ID not configured at compile time");
+            CrossPostingError::ThreadsAuthFailed
+        })?;
+
+    let state = oauth_state::encode(&user.pk, req.return_to.as_deref()).map_err(|e| {
+        crate::error!("connect_threads_init state encode failed: {e}");
+        CrossPostingError::ThreadsAuthFailed
+    })?;
+
+    // Must match a "Redirect Callback URL" registered on the Meta app
+    // byte-for-byte, same as LinkedIn.
+    let redirect_uri = threads_redirect_uri();
+
+    let authorize_url = format!(
+        "{host}/oauth/authorize?response_type=code\
+         &client_id={cid}\
+         &redirect_uri={redir}\
+         &scope={scopes}\
+         &state={state}",
+        host = THREADS_AUTHORIZE_HOST,
+        cid = urlencoding::encode(client_id),
+        redir = urlencoding::encode(&redirect_uri),
+        scopes = urlencoding::encode(THREADS_SCOPES),
+        state = urlencoding::encode(&state),
+    );
+
+    Ok(ThreadsOauthInitResponse { authorize_url })
+}
+
+/// Canonical callback URL for the current Ratel environment, shared with
+/// the callback route's `ThreadsAdapter::exchange_code` call.
+#[cfg(feature = "server")]
+pub fn threads_redirect_uri() -> String {
+    format!(
+        "{}/api/cross-posting/connections/threads/callback",
+        crate::common::config::site_base_url()
+    )
+}
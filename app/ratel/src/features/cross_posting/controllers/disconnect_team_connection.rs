@@ -0,0 +1,52 @@
+use crate::common::*;
+use crate::features::cross_posting::models::{ConnectionStatus, SocialConnection};
+use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
+use crate::features::posts::models::Team;
+use crate::features::social::pages::member::dto::TeamRole;
+
+/// Soft-delete one of the team's connections, as
+/// [`super::disconnect_handler`] does for a user's; admins only. Jobs
+/// already waiting for approval fail at dispatch like any revoked
+/// connection.
+#[delete("/api/teams/:team_pk/cross-posting/connections/:platform", user: crate::features::auth::User, team: Team, role: TeamRole)]
+pub async fn disconnect_team_connection_handler(
+    team_pk: TeamPartition,
+    platform: SocialPlatform,
+) -> Result<()> {
+    let _ = (team_pk, user);
+    if !role.is_admin_or_owner() {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let now = crate::common::utils::time::now();
+
+    let existing = SocialConnection::get(cli, team.pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("disconnect_team_connection lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+    if existing.is_none() {
+        return Ok(());
+    }
+
+    SocialConnection::updater(team.pk.clone(), sk)
+        .with_status(ConnectionStatus::Revoked)
+        .with_credential_ciphertext(Vec::new())
+        .with_platform_status(SocialConnection::platform_status_key(
+            platform,
+            ConnectionStatus::Revoked,
+        ))
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("disconnect_team_connection update failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::{
+    SyndicationDailyStats, SyndicationPostStats, stats_day,
+};
+use crate::features::cross_posting::types::{
+    CrossPostingAnalyticsResponse, CrossPostingError, DailyAnalyticsView,
+};
+use crate::features::posts::models::Post;
+
+/// Days of daily totals the dashboard charts, today included.
+#[cfg(feature = "server")]
+const WINDOW_DAYS: i64 = 30;
+
+/// A day has at most one row per network (four of them).
+#[cfg(feature = "server")]
+const DAILY_ROW_LIMIT: i32 = (WINDOW_DAYS * 4) as i32;
+
+/// Upper bound on per-(post × platform) rows folded into the totals.
+#[cfg(feature = "server")]
+const MAX_POST_ROWS: usize = 2_000;
+
+/// Posts listed on the dashboard; the network totals cover all of them.
+#[cfg(feature = "server")]
+const POSTS_LIMIT: usize = 50;
+
+/// Cross-posting analytics for the session user's posts: all-time totals
+/// per network, the last 30 days per network, and per-post totals. Reads
+/// only the rollups in the user's own partition, so there is nothing to
+/// authorize beyond the session.
+#[get("/api/cross-posting/analytics", user: User)]
+pub async fn get_cross_posting_analytics_handler() -> Result<CrossPostingAnalyticsResponse> {
+    use crate::features::cross_posting::services::analytics;
+
+    let conf = crate::features::posts::config::get();
+    let cli = conf.dynamodb();
+
+    let to_day = stats_day(crate::common::utils::time::now());
+    let from_day = to_day - (WINDOW_DAYS - 1);
+
+    let sk_prefix = EntityType::SyndicationPostStats(String::new()).to_string();
+    let mut rows: Vec<SyndicationPostStats> = Vec::new();
+    let mut bookmark = None;
+    loop {
+        let opt = SyndicationPostStats::opt_with_bookmark(bookmark)
+            .sk(sk_prefix.clone())
+            .limit(100);
+        let (page, next) = SyndicationPostStats::query(cli, &user.pk, opt)
+            .await
+            .map_err(|e| {
+                crate::error!("analytics post stats query failed: {e}");
+                CrossPostingError::ListFailed
+            })?;
+        rows.extend(page);
+        match next {
+            Some(b) if rows.len() < MAX_POST_ROWS => bookmark = Some(b),
+            _ => break,
+        }
+    }
+    let (platforms, mut posts) = analytics::summarize(rows);
+    posts.truncate(POSTS_LIMIT);
+
+    // Newest first, so the window is the head of the partition range.
+    let sk_prefix = EntityType::SyndicationDailyStats(String::new()).to_string();
+    let opt = SyndicationDailyStats::opt_with_bookmark(None)
+        .sk(sk_prefix)
+        .scan_index_forward(false)
+        .limit(DAILY_ROW_LIMIT);
+    let (days, _next) = SyndicationDailyStats::query(cli, &user.pk, opt)
+        .await
+        .map_err(|e| {
+            crate::error!("analytics daily stats query failed: {e}");
+            CrossPostingError::ListFailed
+        })?;
+    let mut daily: Vec<DailyAnalyticsView> = days
+        .into_iter()
+        .filter(|d| d.day >= from_day)
+        .map(DailyAnalyticsView::from)
+        .collect();
+    daily.reverse();
+
+    let post_keys: Vec<(Partition, EntityType)> = posts
+        .iter()
+        .map(|p| (Partition::Feed(p.post_id.0.clone()), EntityType::Post))
+        .collect();
+    let titles: HashMap<String, String> = if post_keys.is_empty() {
+        HashMap::new()
+    } else {
+        Post::batch_get(cli, post_keys)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| (p.pk.to_string(), p.title))
+            .collect()
+    };
+    for post in &mut posts {
+        let pk = Partition::Feed(post.post_id.0.clone()).to_string();
+        post.post_title = titles.get(&pk).cloned().unwrap_or_default();
+    }
+
+    Ok(CrossPostingAnalyticsResponse {
+        from_day,
+        to_day,
+        platforms,
+        daily,
+        posts,
+    })
+}
@@ -1,21 +1,30 @@
 use crate::common::*;
 use crate::features::auth::User;
-use crate::features::cross_posting::models::{EngagementSnapshot, JobState, SyndicationJob};
+use crate::features::cross_posting::models::{
+    EngagementSnapshot, JobState, SyndicationAuditEntry, SyndicationJob,
+};
 use crate::features::cross_posting::types::{
-    CrossPostingError, EngagementCountsView, SyndicationJobView, SyndicationPanelResponse,
+    AuditEntryView, CrossPostingError, EngagementCountsView, SyndicationJobView,
+    SyndicationPanelResponse,
 };
 use crate::features::posts::models::Post;
 
+/// Upper bound on audit entries shown; a post collects a handful per
+/// network.
+#[cfg(feature = "server")]
+const AUDIT_LIMIT: i32 = 50;
+
 /// Author-only syndication panel (FR-7 #41–#45). Shows per-platform
-/// dispatch state, retry diagnostics, and engagement counts for one of
-/// the user's own posts. Returns `NotAuthorized` for posts whose
-/// `user_pk` does not match the session user (Phase 1 supports only
-/// user-owned cross-posting; team-owned posts have no
-/// `PostSyndicationDirective` written and therefore no jobs to show).
+/// dispatch state, retry diagnostics, engagement counts and the audit
+/// history for one of the user's own posts — or, for a team post, for any
+/// member who can cross-post for the team. Returns `NotAuthorized`
+/// otherwise.
 #[get("/api/cross-posting/posts/{post_id}/syndication", user: User)]
 pub async fn get_syndication_panel_handler(
     post_id: FeedPartition,
 ) -> Result<SyndicationPanelResponse> {
+    use crate::features::cross_posting::services::{approval, team_access};
+
     let conf = crate::features::posts::config::get();
     let cli = conf.dynamodb();
 
@@ -29,7 +38,7 @@ pub async fn get_syndication_panel_handler(
         })?
         .ok_or(CrossPostingError::NotAuthorized)?;
 
-    if post.user_pk != user.pk {
+    if !team_access::can_view_post_jobs(cli, &post, &user.pk).await? {
         return Err(CrossPostingError::NotAuthorized.into());
     }
 
@@ -62,5 +71,31 @@ pub async fn get_syndication_panel_handler(
         views.push(SyndicationJobView::from_job(job, engagement));
     }
 
-    Ok(SyndicationPanelResponse { post_id, jobs: views })
+    // Audit history shares the post's partition. Best-effort: a failed
+    // read leaves the list empty rather than failing the panel.
+    let sk_prefix = EntityType::SyndicationAudit(String::new()).to_string();
+    let opt = SyndicationAuditEntry::opt_with_bookmark(None)
+        .sk(sk_prefix)
+        .limit(AUDIT_LIMIT);
+    let entries = SyndicationAuditEntry::query(cli, &post_pk, opt)
+        .await
+        .map(|(entries, _)| entries)
+        .unwrap_or_default();
+    let names = approval::display_names(cli, entries.iter().map(|e| e.actor.clone())).await;
+    let audit = entries
+        .into_iter()
+        .map(|e| AuditEntryView {
+            platform: e.platform,
+            action: e.action,
+            actor_name: names.get(&e.actor.to_string()).cloned().unwrap_or_default(),
+            external_post_url: e.external_post_url,
+            created_at: e.created_at,
+        })
+        .collect();
+
+    Ok(SyndicationPanelResponse {
+        post_id,
+        jobs: views,
+        audit,
+    })
 }
@@ -0,0 +1,50 @@
+use crate::common::*;
+use crate::features::auth::OptionalUser;
+use crate::features::cross_posting::models::ExternalComment;
+use crate::features::cross_posting::types::{CrossPostingError, ExternalCommentResponse};
+use crate::features::posts::models::Post;
+use crate::features::posts::types::{PostError, TeamGroupPermission};
+
+/// Replies imported from the platforms a post was syndicated to, oldest
+/// first. Readable by anyone who can read the post itself. Imports are
+/// capped per platform, so the whole set comes back in one response.
+#[get("/api/cross-posting/posts/{post_id}/external-comments", user: OptionalUser)]
+pub async fn list_external_comments_handler(
+    post_id: FeedPartition,
+) -> Result<Vec<ExternalCommentResponse>> {
+    let conf = crate::features::posts::config::get();
+    let cli = conf.dynamodb();
+    let user: Option<crate::features::auth::User> = user.into();
+    let post_pk: Partition = post_id.into();
+
+    let post = Post::get(cli, &post_pk, Some(EntityType::Post))
+        .await?
+        .ok_or(Error::NotFound("Post not found".into()))?;
+    let permissions = post.get_permissions(cli, user).await?;
+    if !permissions.contains(TeamGroupPermission::PostRead) {
+        return Err(PostError::NotAccessible.into());
+    }
+
+    let sk_prefix = EntityType::ExternalComment(String::new()).to_string();
+    let mut comments: Vec<ExternalComment> = Vec::new();
+    let mut bookmark = None;
+    loop {
+        let opt = ExternalComment::opt_with_bookmark(bookmark)
+            .sk(sk_prefix.clone())
+            .limit(100);
+        let (page, next) = ExternalComment::query(cli, &post_pk, opt)
+            .await
+            .map_err(|e| {
+                crate::error!("list_external_comments query failed: {e}");
+                CrossPostingError::ListFailed
+            })?;
+        comments.extend(page);
+        match next {
+            Some(b) => bookmark = Some(b),
+            None => break,
+        }
+    }
+
+    comments.sort_by_key(|c| (c.posted_at, c.imported_at));
+    Ok(comments.into_iter().map(Into::into).collect())
+}
@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::SyndicationJob;
+use crate::features::cross_posting::types::{CrossPostingError, QueuedJobResponse};
+use crate::features::posts::models::Post;
+
+/// Upper bound on queue entries returned; the sidebar shows the soonest.
+const QUEUE_LIMIT: i32 = 50;
+
+/// The session user's upcoming cross-posts, soonest first. Only
+/// `Scheduled` jobs are in the queue index — released, cancelled and
+/// finished jobs drop out of it.
+#[get("/api/cross-posting/queue", user: User)]
+pub async fn list_queue_handler() -> Result<Vec<QueuedJobResponse>> {
+    let conf = crate::features::posts::config::get();
+    let cli = conf.dynamodb();
+
+    let opt = SyndicationJob::opt_with_bookmark(None)
+        .scan_index_forward(true)
+        .limit(QUEUE_LIMIT);
+    let (jobs, _next) = SyndicationJob::find_queued_by_author(cli, &user.pk, opt)
+        .await
+        .map_err(|e| {
+            crate::error!("list_queue query failed: {e}");
+            CrossPostingError::ListFailed
+        })?;
+
+    let mut post_keys: Vec<(Partition, EntityType)> = jobs
+        .iter()
+        .map(|j| (j.pk.clone(), EntityType::Post))
+        .collect();
+    post_keys.sort_by_key(|(pk, _)| pk.to_string());
+    post_keys.dedup();
+    let titles: HashMap<String, String> = if post_keys.is_empty() {
+        HashMap::new()
+    } else {
+        Post::batch_get(cli, post_keys)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| (p.pk.to_string(), p.title))
+            .collect()
+    };
+
+    Ok(jobs
+        .into_iter()
+        .filter_map(|job| {
+            let Partition::Feed(id) = &job.pk else {
+                return None;
+            };
+            Some(QueuedJobResponse {
+                post_id: FeedPartition(id.clone()),
+                post_title: titles.get(&job.pk.to_string()).cloned().unwrap_or_default(),
+                platform: job.platform,
+                scheduled_at: job.scheduled_at.unwrap_or_default(),
+            })
+        })
+        .collect())
+}
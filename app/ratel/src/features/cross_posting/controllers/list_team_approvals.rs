@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::common::*;
+use crate::features::cross_posting::models::SyndicationJob;
+use crate::features::cross_posting::types::{ApprovalResponse, CrossPostingError};
+use crate::features::posts::models::{Post, Team};
+use crate::features::social::pages::member::dto::TeamRole;
+
+/// Upper bound on queued approvals returned; the admin page shows the oldest.
+#[cfg(feature = "server")]
+const APPROVALS_LIMIT: i32 = 50;
+
+/// The team's cross-posts waiting for approval, oldest first; admins only.
+#[get("/api/teams/:team_pk/cross-posting/approvals", user: crate::features::auth::User, team: Team, role: TeamRole)]
+pub async fn list_team_approvals_handler(team_pk: TeamPartition) -> Result<Vec<ApprovalResponse>> {
+    use crate::features::cross_posting::services::approval;
+
+    let _ = (team_pk, user);
+    if !role.is_admin_or_owner() {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let opt = SyndicationJob::opt_with_bookmark(None)
+        .scan_index_forward(true)
+        .limit(APPROVALS_LIMIT);
+    let (jobs, _next) = SyndicationJob::find_awaiting_approval(cli, &team.pk, opt)
+        .await
+        .map_err(|e| {
+            crate::error!("list_team_approvals query failed: {e}");
+            CrossPostingError::ListFailed
+        })?;
+
+    let mut post_keys: Vec<(Partition, EntityType)> = jobs
+        .iter()
+        .map(|j| (j.pk.clone(), EntityType::Post))
+        .collect();
+    post_keys.sort_by_key(|(pk, _)| pk.to_string());
+    post_keys.dedup();
+    let titles: HashMap<String, String> = if post_keys.is_empty() {
+        HashMap::new()
+    } else {
+        Post::batch_get(cli, post_keys)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|p| (p.pk.to_string(), p.title))
+            .collect()
+    };
+    let names = approval::display_names(cli, jobs.iter().map(|j| j.author_user_id.clone())).await;
+
+    Ok(jobs
+        .into_iter()
+        .filter_map(|job| {
+            let Partition::Feed(id) = &job.pk else {
+                return None;
+            };
+            Some(ApprovalResponse {
+                post_id: FeedPartition(id.clone()),
+                post_title: titles.get(&job.pk.to_string()).cloned().unwrap_or_default(),
+                platform: job.platform,
+                requested_by: names
+                    .get(&job.author_user_id.to_string())
+                    .cloned()
+                    .unwrap_or_default(),
+                scheduled_at: job.scheduled_at,
+                created_at: job.created_at,
+            })
+        })
+        .collect())
+}
@@ -0,0 +1,36 @@
+use crate::common::*;
+use crate::features::cross_posting::models::SocialConnection;
+use crate::features::cross_posting::types::{ConnectionResponse, CrossPostingError};
+use crate::features::posts::models::Team;
+use crate::features::social::pages::member::dto::TeamRole;
+
+/// Lists the team's `SocialConnection` rows. Open to every member who can
+/// cross-post for the team — the compose sidebar needs them to offer the
+/// team's networks — not just the admins who manage them.
+#[get("/api/teams/:team_pk/cross-posting/connections", user: crate::features::auth::User, team: Team, role: TeamRole)]
+pub async fn list_team_connections_handler(
+    team_pk: TeamPartition,
+) -> Result<Vec<ConnectionResponse>> {
+    use crate::features::cross_posting::services::team_access;
+
+    let _ = (team_pk, role);
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    if !team_access::can_cross_post(cli, &team.pk, &user.pk).await? {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+
+    let sk_prefix = EntityType::SocialConnection(String::new()).to_string();
+    let opt = SocialConnection::opt_with_bookmark(None)
+        .sk(sk_prefix)
+        .limit(10);
+    let (rows, _next): (Vec<SocialConnection>, _) = SocialConnection::query(cli, &team.pk, opt)
+        .await
+        .map_err(|e| {
+            crate::error!("list_team_connections query failed: {e}");
+            CrossPostingError::ListFailed
+        })?;
+
+    Ok(rows.into_iter().map(Into::into).collect())
+}
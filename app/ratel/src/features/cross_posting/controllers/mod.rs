@@ -4,29 +4,93 @@
 //   GET    /api/cross-posting/connections
 //   POST   /api/cross-posting/connections/bluesky
 //   PATCH  /api/cross-posting/connections/{platform}
+//   PATCH  /api/cross-posting/connections/{platform}/reply-import
+//   PATCH  /api/cross-posting/connections/{platform}/thread-mode
+//   PATCH  /api/cross-posting/connections/{platform}/space-cards
+//   PATCH  /api/cross-posting/connections/{platform}/best-time
 //   DELETE /api/cross-posting/connections/{platform}
 //
 // PR B2 (1A post-detail syndication panel):
 //   GET    /api/cross-posting/posts/{post_id}/syndication
 //   POST   /api/cross-posting/posts/{post_id}/jobs/{platform}/retry
+//   GET    /api/cross-posting/posts/{post_id}/external-comments
+//
+// Scheduled cross-posts (queue):
+//   GET    /api/cross-posting/queue
+//   POST   /api/cross-posting/posts/{post_id}/jobs/{platform}/cancel
+//
+// Team connections + approval queue:
+//   GET    /api/teams/{team_pk}/cross-posting/connections
+//   POST   /api/teams/{team_pk}/cross-posting/connections/bluesky/connect
+//   POST   /api/teams/{team_pk}/cross-posting/connections/linkedin/init
+//   PATCH  /api/teams/{team_pk}/cross-posting/connections/{platform}/approval
+//   DELETE /api/teams/{team_pk}/cross-posting/connections/{platform}
+//   GET    /api/teams/{team_pk}/cross-posting/approvals
+//   POST   /api/cross-posting/posts/{post_id}/jobs/{platform}/approve
+//   POST   /api/cross-posting/posts/{post_id}/jobs/{platform}/reject
+//
+// Cross-posting analytics:
+//   GET    /api/cross-posting/analytics
+//   POST   /api/cross-posting/posts/{post_id}/visits/{platform}
+//
+// 1B / 1C / Mastodon OAuth connect (callbacks live in `server/mod.rs`):
+//   POST   /api/cross-posting/connections/linkedin/init
+//   POST   /api/cross-posting/connections/threads/init
+//   POST   /api/cross-posting/connections/mastodon/init
 //
 // To be added in subsequent PRs:
-//   GET  /api/cross-posting/oauth/{platform}/start            (1B/1C)
-//   GET  /api/cross-posting/oauth/{platform}/callback         (1B/1C)
 //   POST /api/cross-posting/onboarding/dismiss                (1D)
 
+pub mod approve_job;
+pub mod cancel_job;
 pub mod connect_bluesky;
 pub mod connect_linkedin_init;
+pub mod connect_mastodon_init;
+pub mod connect_team_bluesky;
+pub mod connect_team_linkedin_init;
+pub mod connect_threads_init;
 pub mod disconnect;
+pub mod disconnect_team_connection;
+pub mod get_cross_posting_analytics;
 pub mod get_syndication_panel;
 pub mod list_connections;
+pub mod list_external_comments;
+pub mod list_queue;
+pub mod list_team_approvals;
+pub mod list_team_connections;
+pub mod record_backlink_visit;
+pub mod reject_job;
 pub mod retry_job;
+pub mod set_best_time;
+pub mod set_team_approval;
 pub mod toggle_auto_post;
+pub mod toggle_reply_import;
+pub mod toggle_space_cards;
+pub mod toggle_thread_mode;
 
+pub use approve_job::*;
+pub use cancel_job::*;
 pub use connect_bluesky::*;
 pub use connect_linkedin_init::*;
+pub use connect_mastodon_init::*;
+pub use connect_team_bluesky::*;
+pub use connect_team_linkedin_init::*;
+pub use connect_threads_init::*;
 pub use disconnect::*;
+pub use disconnect_team_connection::*;
+pub use get_cross_posting_analytics::*;
 pub use get_syndication_panel::*;
 pub use list_connections::*;
+pub use list_external_comments::*;
+pub use list_queue::*;
+pub use list_team_approvals::*;
+pub use list_team_connections::*;
+pub use record_backlink_visit::*;
+pub use reject_job::*;
 pub use retry_job::*;
+pub use set_best_time::*;
+pub use set_team_approval::*;
 pub use toggle_auto_post::*;
+pub use toggle_reply_import::*;
+pub use toggle_space_cards::*;
+pub use toggle_thread_mode::*;
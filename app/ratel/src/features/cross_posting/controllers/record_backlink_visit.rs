@@ -0,0 +1,52 @@
+use crate::common::*;
+use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
+
+/// Count a reader landing on a post through a cross-posted backlink
+/// (`?utm_source={platform}`). Anonymous on purpose — readers arriving
+/// from another network are usually signed out — and sent once per tab
+/// session by the post page. Since the beacon is unauthenticated, the
+/// reader is told apart by client address and user agent, and counted at
+/// most once per post, platform and day. Landings that name a network the
+/// post never went out to are accepted and ignored.
+#[post("/api/cross-posting/posts/{post_id}/visits/{platform}", headers: crate::axum::http::HeaderMap)]
+pub async fn record_backlink_visit_handler(
+    post_id: FeedPartition,
+    platform: SocialPlatform,
+) -> Result<()> {
+    use crate::features::cross_posting::services::analytics;
+
+    let conf = crate::features::posts::config::get();
+    let cli = conf.dynamodb();
+
+    // Clients can send any `X-Forwarded-For` they like; only the last
+    // hop, appended by the load balancer, is the peer it actually saw.
+    let client_ip = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(str::trim)
+        .filter(|hop| !hop.is_empty())
+        .last()
+        .unwrap_or("-");
+    let user_agent = headers
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("-");
+    let visitor = format!("{client_ip}#{user_agent}");
+
+    let post_pk: Partition = post_id.into();
+    analytics::record_visit(
+        cli,
+        &post_pk,
+        platform,
+        &visitor,
+        crate::common::utils::time::now(),
+    )
+    .await
+    .map_err(|e| {
+        crate::error!("record_backlink_visit failed: {e}");
+        CrossPostingError::UpdateFailed
+    })?;
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use crate::common::*;
 use crate::features::auth::User;
-use crate::features::cross_posting::models::{JobState, SyndicationJob};
+use crate::features::cross_posting::models::{
+    AuditAction, JobState, SyndicationAuditEntry, SyndicationJob,
+};
 use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
 use crate::features::posts::models::Post;
 
@@ -10,20 +12,26 @@ use crate::features::posts::models::Post;
 /// timestamp. The MODIFY event re-enters Stage 2 via the same Pipe filter
 /// (`state=Pending`) — no special re-enqueue path needed.
 ///
-/// `Published` is the only blocked state — re-publishing an already-published
-/// job risks double posting on the platform. Every other state retries:
+/// `Published` is blocked — re-publishing an already-published job risks
+/// double posting on the platform — and so is `Scheduled`, which is waiting
+/// for its slot (cancel it first to send it now). `AwaitingApproval` and
+/// `Rejected` are a team admin's call, not the author's. Every other state
+/// retries:
 ///   - `Failed` (any category, including `auth_expired`) — user-initiated.
 ///   - `Pending` — covers stuck rows where the dispatcher Lambda died
 ///     mid-flight or EventBridge dropped the event; UI exposes this so the
 ///     author isn't dependent on infra recovery.
 ///   - `Skipped` — privacy guard re-runs in the dispatcher; if the post is
 ///     still private the row settles back to Skipped (idempotent no-op).
+///   - `Cancelled` — sends the cancelled cross-post immediately.
 ///
 /// `attempts` is reset to 0 — the auto-retry sweeper that previously owned
 /// the 3-attempt budget was removed when failure handling moved to inline
 /// retry + inbox notification, so the counter is purely informational now.
 #[post("/api/cross-posting/posts/{post_id}/jobs/{platform}/retry", user: User)]
 pub async fn retry_job_handler(post_id: FeedPartition, platform: SocialPlatform) -> Result<()> {
+    use crate::features::cross_posting::services::team_access;
+
     let conf = crate::features::posts::config::get();
     let cli = conf.dynamodb();
 
@@ -37,7 +45,7 @@ pub async fn retry_job_handler(post_id: FeedPartition, platform: SocialPlatform)
         })?
         .ok_or(CrossPostingError::NotAuthorized)?;
 
-    if post.user_pk != user.pk {
+    if !team_access::can_view_post_jobs(cli, &post, &user.pk).await? {
         return Err(CrossPostingError::NotAuthorized.into());
     }
 
@@ -50,7 +58,13 @@ pub async fn retry_job_handler(post_id: FeedPartition, platform: SocialPlatform)
         })?
         .ok_or(CrossPostingError::SyndicationJobNotFound)?;
 
-    if job.state == JobState::Published {
+    if matches!(
+        job.state,
+        JobState::Published
+            | JobState::Scheduled
+            | JobState::AwaitingApproval
+            | JobState::Rejected
+    ) {
         return Err(CrossPostingError::RetryNotAllowed.into());
     }
 
@@ -71,6 +85,7 @@ pub async fn retry_job_handler(post_id: FeedPartition, platform: SocialPlatform)
             crate::error!("retry_job update failed: {e}");
             CrossPostingError::UpdateFailed
         })?;
+    SyndicationAuditEntry::record(cli, &job, AuditAction::Retried, user.pk, now).await;
 
     Ok(())
 }
@@ -0,0 +1,50 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::SocialConnection;
+use crate::features::cross_posting::types::{
+    ConnectionResponse, CrossPostingError, SetBestTimeRequest, SocialPlatform,
+};
+
+/// Set or clear a connection's best time to post. Only affects posts
+/// published afterwards — jobs already in the queue keep their slot.
+#[patch("/api/cross-posting/connections/{platform}/best-time", user: User)]
+pub async fn set_best_time_handler(
+    platform: SocialPlatform,
+    req: SetBestTimeRequest,
+) -> Result<ConnectionResponse> {
+    if req.best_time.is_some_and(|t| !t.is_valid()) {
+        return Err(CrossPostingError::InvalidBestTime.into());
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let now = crate::common::utils::time::now();
+
+    let existing = SocialConnection::get(cli, user.pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("set_best_time lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::ConnectionNotFound)?;
+
+    let updater = SocialConnection::updater(user.pk.clone(), sk);
+    let updater = match req.best_time {
+        Some(t) => updater.with_best_time(t),
+        None => updater.remove_best_time(),
+    };
+    updater
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("set_best_time update failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+
+    let mut updated = existing;
+    updated.best_time = req.best_time;
+    updated.updated_at = now;
+    Ok(updated.into())
+}
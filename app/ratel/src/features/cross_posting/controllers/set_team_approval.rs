@@ -0,0 +1,50 @@
+use crate::common::*;
+use crate::features::cross_posting::models::SocialConnection;
+use crate::features::cross_posting::types::{
+    ConnectionResponse, CrossPostingError, SetApprovalRequiredRequest, SocialPlatform,
+};
+use crate::features::posts::models::Team;
+use crate::features::social::pages::member::dto::TeamRole;
+
+/// Require (or stop requiring) an admin's approval before cross-posts go
+/// out through one of the team's connections; admins only. Only posts
+/// published afterwards are affected — jobs already queued stay queued.
+#[patch("/api/teams/:team_pk/cross-posting/connections/:platform/approval", user: crate::features::auth::User, team: Team, role: TeamRole)]
+pub async fn set_team_approval_handler(
+    team_pk: TeamPartition,
+    platform: SocialPlatform,
+    req: SetApprovalRequiredRequest,
+) -> Result<ConnectionResponse> {
+    let _ = (team_pk, user);
+    if !role.is_admin_or_owner() {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let now = crate::common::utils::time::now();
+
+    let existing = SocialConnection::get(cli, team.pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("set_team_approval lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::ConnectionNotFound)?;
+
+    SocialConnection::updater(team.pk.clone(), sk)
+        .with_approval_required(req.approval_required)
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("set_team_approval update failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+
+    let mut updated = existing;
+    updated.approval_required = req.approval_required;
+    updated.updated_at = now;
+    Ok(updated.into())
+}
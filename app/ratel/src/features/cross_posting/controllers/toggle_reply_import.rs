@@ -0,0 +1,46 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::SocialConnection;
+use crate::features::cross_posting::types::{
+    ConnectionResponse, CrossPostingError, SocialPlatform, ToggleReplyImportRequest,
+};
+
+/// Opt a connection in or out of reply import. Turning it off stops
+/// future imports only — comments already imported stay on their posts.
+#[patch("/api/cross-posting/connections/{platform}/reply-import", user: User)]
+pub async fn toggle_reply_import_handler(
+    platform: SocialPlatform,
+    req: ToggleReplyImportRequest,
+) -> Result<ConnectionResponse> {
+    if req.import_replies_enabled && !platform.supports_reply_import() {
+        return Err(CrossPostingError::ReplyImportUnsupported.into());
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let now = crate::common::utils::time::now();
+
+    let existing = SocialConnection::get(cli, user.pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("toggle_reply_import lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::ConnectionNotFound)?;
+
+    SocialConnection::updater(user.pk.clone(), sk)
+        .with_import_replies_enabled(req.import_replies_enabled)
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("toggle_reply_import update failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+
+    let mut updated = existing;
+    updated.import_replies_enabled = req.import_replies_enabled;
+    updated.updated_at = now;
+    Ok(updated.into())
+}
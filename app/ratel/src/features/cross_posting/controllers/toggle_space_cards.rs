@@ -0,0 +1,42 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::SocialConnection;
+use crate::features::cross_posting::types::{
+    ConnectionResponse, CrossPostingError, SocialPlatform, ToggleSpaceCardsRequest,
+};
+
+/// Opt a connection in or out of space cards. Applies to spaces that
+/// start or finish afterwards.
+#[patch("/api/cross-posting/connections/{platform}/space-cards", user: User)]
+pub async fn toggle_space_cards_handler(
+    platform: SocialPlatform,
+    req: ToggleSpaceCardsRequest,
+) -> Result<ConnectionResponse> {
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let now = crate::common::utils::time::now();
+
+    let existing = SocialConnection::get(cli, user.pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("toggle_space_cards lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::ConnectionNotFound)?;
+
+    SocialConnection::updater(user.pk.clone(), sk)
+        .with_space_cards_enabled(req.space_cards_enabled)
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("toggle_space_cards update failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+
+    let mut updated = existing;
+    updated.space_cards_enabled = req.space_cards_enabled;
+    updated.updated_at = now;
+    Ok(updated.into())
+}
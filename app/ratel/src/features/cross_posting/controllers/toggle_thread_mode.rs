@@ -0,0 +1,46 @@
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::cross_posting::models::SocialConnection;
+use crate::features::cross_posting::types::{
+    ConnectionResponse, CrossPostingError, SocialPlatform, ToggleThreadModeRequest,
+};
+
+/// Opt a connection in or out of thread mode. Applies to posts dispatched
+/// afterwards, including ones already scheduled.
+#[patch("/api/cross-posting/connections/{platform}/thread-mode", user: User)]
+pub async fn toggle_thread_mode_handler(
+    platform: SocialPlatform,
+    req: ToggleThreadModeRequest,
+) -> Result<ConnectionResponse> {
+    if req.thread_mode_enabled && !platform.supports_threads() {
+        return Err(CrossPostingError::ThreadModeUnsupported.into());
+    }
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let now = crate::common::utils::time::now();
+
+    let existing = SocialConnection::get(cli, user.pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("toggle_thread_mode lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::ConnectionNotFound)?;
+
+    SocialConnection::updater(user.pk.clone(), sk)
+        .with_thread_mode_enabled(req.thread_mode_enabled)
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("toggle_thread_mode update failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?;
+
+    let mut updated = existing;
+    updated.thread_mode_enabled = req.thread_mode_enabled;
+    updated.updated_at = now;
+    Ok(updated.into())
+}
@@ -1,7 +1,15 @@
 //! Dioxus controller hooks for the cross-posting feature.
 
+mod use_backlink_visit;
 mod use_cross_posting;
+mod use_cross_posting_analytics;
+mod use_external_comments;
 mod use_syndication_panel;
+mod use_team_connections;
 
+pub use use_backlink_visit::*;
 pub use use_cross_posting::*;
+pub use use_cross_posting_analytics::*;
+pub use use_external_comments::*;
 pub use use_syndication_panel::*;
+pub use use_team_connections::*;
@@ -0,0 +1,19 @@
+//! `use_backlink_visit` — reports a backlink landing from the post page.
+
+use crate::common::*;
+use crate::features::cross_posting::controllers::record_backlink_visit_handler;
+use crate::features::cross_posting::interop::take_backlink_source;
+
+/// Count this page view for the cross-posting analytics when it arrived
+/// through a backlink (`?utm_source={platform}`). Fire-and-forget: the
+/// reader never sees the report, so a failed one is dropped.
+pub fn use_backlink_visit(post_id: ReadSignal<FeedPartition>) {
+    use_effect(move || {
+        let post_id = post_id();
+        spawn(async move {
+            if let Some(platform) = take_backlink_source().await {
+                let _ = record_backlink_visit_handler(post_id, platform).await;
+            }
+        });
+    });
+}
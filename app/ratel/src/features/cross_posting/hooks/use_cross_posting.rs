@@ -21,22 +21,34 @@
 
 use crate::common::*;
 use crate::features::cross_posting::controllers::{
-    connect_bluesky_handler, connect_linkedin_init_handler, disconnect_handler,
-    list_connections_handler, toggle_auto_post_handler,
+    cancel_job_handler, connect_bluesky_handler, connect_linkedin_init_handler,
+    connect_mastodon_init_handler, connect_threads_init_handler, disconnect_handler,
+    list_connections_handler, list_queue_handler, list_team_connections_handler,
+    set_best_time_handler, toggle_auto_post_handler, toggle_reply_import_handler,
+    toggle_space_cards_handler, toggle_thread_mode_handler,
 };
 use crate::features::cross_posting::models::ConnectionStatus;
 use crate::features::cross_posting::types::{
-    ConnectBlueskyRequest, ConnectionResponse, SocialPlatform, ToggleAutoPostRequest,
+    ConnectBlueskyRequest, ConnectionResponse, PostingTime, QueuedJobResponse, SchedulePlan,
+    SetBestTimeRequest, SocialPlatform, ToggleAutoPostRequest, ToggleReplyImportRequest,
+    ToggleSpaceCardsRequest, ToggleThreadModeRequest,
 };
 use std::collections::HashMap;
 
 #[derive(Clone, Copy, DioxusController)]
 pub struct UseCrossPosting {
     /// Server-loaded list of the user's social connections (Bluesky /
-    /// LinkedIn / Threads). Populated from `GET /api/cross-posting/connections`.
+    /// LinkedIn / Threads / Mastodon). Populated from `GET /api/cross-posting/connections`.
     /// Empty Vec when the user is logged out (the loader short-circuits).
+    /// While `author_team` is set, the team's connections instead.
     pub connections: Loader<Vec<ConnectionResponse>>,
 
+    /// Team the post being composed is published as. Team posts
+    /// syndicate through the team's connections, so setting this swaps
+    /// `connections` over to them — empty when the user lacks the
+    /// team's cross-post permission. `None` = the user's own.
+    pub author_team: Signal<Option<TeamPartition>>,
+
     /// Count of connections in `Connected` state — drives the Settings
     /// page header stat ("X Connected").
     pub connected_count: Memo<usize>,
@@ -59,6 +71,15 @@ pub struct UseCrossPosting {
     /// Drives the compose sidebar's "Reaching N networks" summary and
     /// the Publish button's "Publish to N" label.
     pub reach_count: Memo<usize>,
+
+    /// Compose-time per-post timing, keyed by platform. Platforms absent
+    /// here publish with `SchedulePlan::Auto` (the connection's best
+    /// time, or immediately when none is set).
+    pub per_post_schedule: Signal<HashMap<SocialPlatform, SchedulePlan>>,
+
+    /// The user's upcoming scheduled cross-posts, soonest first. Populated
+    /// from `GET /api/cross-posting/queue`; empty when logged out.
+    pub queue: Loader<Vec<QueuedJobResponse>>,
 }
 
 impl UseCrossPosting {
@@ -97,6 +118,84 @@ impl UseCrossPosting {
         Ok(())
     }
 
+    /// Toggle `import_replies_enabled` on an existing connection. Fails
+    /// with `ReplyImportUnsupported` for platforms whose API can't read
+    /// replies (LinkedIn).
+    pub async fn toggle_reply_import(
+        &mut self,
+        platform: SocialPlatform,
+        enabled: bool,
+    ) -> crate::common::Result<()> {
+        toggle_reply_import_handler(
+            platform,
+            ToggleReplyImportRequest {
+                import_replies_enabled: enabled,
+            },
+        )
+        .await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    /// Toggle `thread_mode_enabled` on an existing connection. Fails with
+    /// `ThreadModeUnsupported` for platforms without self-replies
+    /// (LinkedIn).
+    pub async fn toggle_thread_mode(
+        &mut self,
+        platform: SocialPlatform,
+        enabled: bool,
+    ) -> crate::common::Result<()> {
+        toggle_thread_mode_handler(
+            platform,
+            ToggleThreadModeRequest {
+                thread_mode_enabled: enabled,
+            },
+        )
+        .await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    /// Toggle `space_cards_enabled` on an existing connection.
+    pub async fn toggle_space_cards(
+        &mut self,
+        platform: SocialPlatform,
+        enabled: bool,
+    ) -> crate::common::Result<()> {
+        toggle_space_cards_handler(
+            platform,
+            ToggleSpaceCardsRequest {
+                space_cards_enabled: enabled,
+            },
+        )
+        .await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    /// Set or clear a connection's best time to post. Only posts
+    /// published afterwards pick up the change.
+    pub async fn set_best_time(
+        &mut self,
+        platform: SocialPlatform,
+        best_time: Option<PostingTime>,
+    ) -> crate::common::Result<()> {
+        set_best_time_handler(platform, SetBestTimeRequest { best_time }).await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    /// Cancel one queued cross-post from the compose sidebar's queue.
+    pub async fn cancel_scheduled(
+        &mut self,
+        post_id: FeedPartition,
+        platform: SocialPlatform,
+    ) -> crate::common::Result<()> {
+        cancel_job_handler(post_id, platform).await?;
+        self.queue.restart();
+        Ok(())
+    }
+
     /// Disconnect (soft-delete: status=Revoked, ciphertext zeroed).
     pub async fn disconnect(&mut self, platform: SocialPlatform) -> crate::common::Result<()> {
         disconnect_handler(platform).await?;
@@ -127,6 +226,37 @@ impl UseCrossPosting {
         crate::features::cross_posting::interop::redirect_to_external(&resp.authorize_url);
         Ok(())
     }
+
+    /// Kick off the Threads OAuth flow. Same shape as
+    /// [`Self::connect_linkedin`]; the callback lands on
+    /// `/api/cross-posting/connections/threads/callback`.
+    pub async fn connect_threads(
+        &mut self,
+        return_to: Option<String>,
+    ) -> crate::common::Result<()> {
+        let req = crate::features::cross_posting::types::ThreadsOauthInitRequest { return_to };
+        let resp = connect_threads_init_handler(req).await?;
+        crate::features::cross_posting::interop::redirect_to_external(&resp.authorize_url);
+        Ok(())
+    }
+
+    /// Kick off the Mastodon OAuth flow on the user's own server. The
+    /// init endpoint registers Ratel with `instance` on first use, so it
+    /// can fail with `MastodonInvalidInstance` before any redirect —
+    /// callers should keep the picker open and surface the error.
+    pub async fn connect_mastodon(
+        &mut self,
+        instance: String,
+        return_to: Option<String>,
+    ) -> crate::common::Result<()> {
+        let req = crate::features::cross_posting::types::MastodonOauthInitRequest {
+            instance,
+            return_to,
+        };
+        let resp = connect_mastodon_init_handler(req).await?;
+        crate::features::cross_posting::interop::redirect_to_external(&resp.authorize_url);
+        Ok(())
+    }
 }
 
 /// Provider — runs every cross-posting signal / loader once and installs
@@ -143,14 +273,21 @@ pub fn use_cross_posting_provider() -> std::result::Result<UseCrossPosting, Rend
     // login/logout. Logged-out users see an empty list without hitting
     // the API, mirroring the inbox-loader pattern.
     let user_ctx = crate::features::auth::hooks::use_user_context();
+    let author_team = use_signal(|| Option::<TeamPartition>::None);
 
     let connections = use_loader(move || {
         let logged_in = user_ctx().is_logged_in();
+        let team = author_team();
         async move {
             if !logged_in {
                 return Ok(Vec::<ConnectionResponse>::new());
             }
-            list_connections_handler().await
+            match team {
+                Some(team_pk) => Ok(list_team_connections_handler(team_pk)
+                    .await
+                    .unwrap_or_default()),
+                None => list_connections_handler().await,
+            }
         }
     })?;
 
@@ -165,13 +302,27 @@ pub fn use_cross_posting_provider() -> std::result::Result<UseCrossPosting, Rend
     let per_post_enabled = use_signal(HashMap::<SocialPlatform, bool>::new);
 
     let reach_count = use_memo(move || per_post_enabled().values().filter(|v| **v).count());
+    let per_post_schedule = use_signal(HashMap::<SocialPlatform, SchedulePlan>::new);
+
+    let queue = use_loader(move || {
+        let logged_in = user_ctx().is_logged_in();
+        async move {
+            if !logged_in {
+                return Ok(Vec::<QueuedJobResponse>::new());
+            }
+            list_queue_handler().await
+        }
+    })?;
 
     Ok(use_context_provider(|| UseCrossPosting {
         connections,
+        author_team,
         connected_count,
         posts_this_month,
         per_post_enabled,
         reach_count,
+        per_post_schedule,
+        queue,
     }))
 }
 
@@ -0,0 +1,29 @@
+//! `UseCrossPostingAnalytics` controller hook for the analytics dashboard
+//! under Settings → Connections.
+//!
+//! Read-only, so no provider/consumer split and no mutations — the
+//! dashboard is the only reader. A failed load renders as an empty
+//! dashboard rather than a failed page.
+
+use crate::common::*;
+use crate::features::cross_posting::controllers::get_cross_posting_analytics_handler;
+use crate::features::cross_posting::types::CrossPostingAnalyticsResponse;
+
+#[derive(Clone, Copy, DioxusController)]
+pub struct UseCrossPostingAnalytics {
+    /// Network totals, the last 30 days per network and per-post totals
+    /// for the session user's cross-posts.
+    pub analytics: Loader<CrossPostingAnalyticsResponse>,
+}
+
+#[track_caller]
+pub fn use_cross_posting_analytics() -> std::result::Result<UseCrossPostingAnalytics, RenderError> {
+    let analytics = use_loader(move || async move {
+        match get_cross_posting_analytics_handler().await {
+            Ok(data) => Ok::<CrossPostingAnalyticsResponse, crate::common::Error>(data),
+            Err(_) => Ok(CrossPostingAnalyticsResponse::default()),
+        }
+    })?;
+
+    Ok(UseCrossPostingAnalytics { analytics })
+}
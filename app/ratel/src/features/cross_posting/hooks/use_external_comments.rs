@@ -0,0 +1,35 @@
+//! `UseExternalComments` — read-only replies imported from syndicated
+//! copies of a post (Stage 4 reply import).
+//!
+//! Same per-post shape as `UseSyndicationPanel`: bound to one
+//! `FeedPartition`, no provider/consumer split, and the loader collapses
+//! every server error to an empty list so the comments panel never fails
+//! to render because of an imported-reply fetch.
+
+use crate::common::*;
+use crate::features::cross_posting::controllers::list_external_comments_handler;
+use crate::features::cross_posting::types::ExternalCommentResponse;
+
+#[derive(Clone, Copy, DioxusController)]
+pub struct UseExternalComments {
+    /// Oldest first. Empty when the author hasn't enabled reply import on
+    /// any platform, or on any server error.
+    pub comments: Loader<Vec<ExternalCommentResponse>>,
+}
+
+#[track_caller]
+pub fn use_external_comments(
+    post_id: FeedPartition,
+) -> std::result::Result<UseExternalComments, RenderError> {
+    let comments = use_loader(move || {
+        let post_id = post_id.clone();
+        async move {
+            match list_external_comments_handler(post_id).await {
+                Ok(list) => Ok::<Vec<ExternalCommentResponse>, crate::common::Error>(list),
+                Err(_) => Ok(Vec::new()),
+            }
+        }
+    })?;
+
+    Ok(UseExternalComments { comments })
+}
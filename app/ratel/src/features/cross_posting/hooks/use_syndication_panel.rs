@@ -14,13 +14,15 @@
 //!   gates the mount, but defense-in-depth keeps non-authors safe even on
 //!   a race).
 //!
-//! Per `conventions/hooks-and-actions.md`, retry lives as an `async fn`
-//! method on the controller — components await `panel.retry(platform)`
-//! and decide UX (toast, navigation) on the result. No `use_action`.
+//! Per `conventions/hooks-and-actions.md`, retry and cancel live as
+//! `async fn` methods on the controller — components await
+//! `panel.retry(platform)` and decide UX (toast, navigation) on the
+//! result. No `use_action`.
 
 use crate::common::*;
 use crate::features::cross_posting::controllers::{
-    get_syndication_panel_handler, list_connections_handler, retry_job_handler,
+    cancel_job_handler, get_syndication_panel_handler, list_connections_handler,
+    retry_job_handler,
 };
 use crate::features::cross_posting::types::{
     ConnectionResponse, SocialPlatform, SyndicationPanelResponse,
@@ -56,6 +58,14 @@ impl UseSyndicationPanel {
         Ok(())
     }
 
+    /// Cancel a `Scheduled` job before its slot. Fails with
+    /// `CancelNotAllowed` once the scheduler has released it.
+    pub async fn cancel(&mut self, platform: SocialPlatform) -> crate::common::Result<()> {
+        cancel_job_handler((self.post_id)(), platform).await?;
+        self.panel.restart();
+        Ok(())
+    }
+
     /// Manual refresh from the panel header — re-fetches both loaders so
     /// the user can pull SyndicationJob rows that arrived after the
     /// initial mount (publish → factory enqueue is async, ~1-3s).
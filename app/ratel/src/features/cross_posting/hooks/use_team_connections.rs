@@ -0,0 +1,133 @@
+//! `UseTeamConnections` controller hook for the team settings page.
+//!
+//! Same shape as [`super::UseSyndicationPanel`]: bound to one team, no
+//! provider/consumer split — only the admin section reads it. Both
+//! loaders swallow server errors into empty lists so a member without
+//! access sees an empty section instead of a failed render.
+//!
+//! Mutations are `async fn` methods; the component awaits them and owns
+//! the toast.
+
+use crate::common::*;
+use crate::features::cross_posting::controllers::{
+    approve_job_handler, connect_team_bluesky_handler, connect_team_linkedin_init_handler,
+    disconnect_team_connection_handler, list_team_approvals_handler, list_team_connections_handler,
+    reject_job_handler, set_team_approval_handler,
+};
+use crate::features::cross_posting::types::{
+    ApprovalResponse, ConnectBlueskyRequest, ConnectionResponse, LinkedInOauthInitRequest,
+    SetApprovalRequiredRequest, SocialPlatform,
+};
+
+#[derive(Clone, Copy, DioxusController)]
+pub struct UseTeamConnections {
+    /// The team's own `SocialConnection` rows.
+    pub connections: Loader<Vec<ConnectionResponse>>,
+
+    /// Cross-posts through the team's connections waiting for an admin,
+    /// oldest first.
+    pub approvals: Loader<Vec<ApprovalResponse>>,
+
+    team_pk: Signal<TeamPartition>,
+}
+
+impl UseTeamConnections {
+    /// Connect the team's Bluesky account with an app password.
+    pub async fn connect_bluesky(
+        &mut self,
+        handle: String,
+        app_password: String,
+    ) -> crate::common::Result<()> {
+        connect_team_bluesky_handler(
+            (self.team_pk)(),
+            ConnectBlueskyRequest {
+                handle,
+                app_password,
+            },
+        )
+        .await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    /// Kick off LinkedIn OAuth for the team's company page. The callback
+    /// bounces back to the team settings page, so `return_to` is unused.
+    pub async fn connect_linkedin(&mut self) -> crate::common::Result<()> {
+        let resp = connect_team_linkedin_init_handler(
+            (self.team_pk)(),
+            LinkedInOauthInitRequest { return_to: None },
+        )
+        .await?;
+        crate::features::cross_posting::interop::redirect_to_external(&resp.authorize_url);
+        Ok(())
+    }
+
+    pub async fn disconnect(&mut self, platform: SocialPlatform) -> crate::common::Result<()> {
+        disconnect_team_connection_handler((self.team_pk)(), platform).await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    /// Toggle whether cross-posts through `platform` wait for an admin.
+    pub async fn set_approval_required(
+        &mut self,
+        platform: SocialPlatform,
+        approval_required: bool,
+    ) -> crate::common::Result<()> {
+        set_team_approval_handler(
+            (self.team_pk)(),
+            platform,
+            SetApprovalRequiredRequest { approval_required },
+        )
+        .await?;
+        self.connections.restart();
+        Ok(())
+    }
+
+    pub async fn approve(
+        &mut self,
+        post_id: FeedPartition,
+        platform: SocialPlatform,
+    ) -> crate::common::Result<()> {
+        approve_job_handler(post_id, platform).await?;
+        self.approvals.restart();
+        Ok(())
+    }
+
+    pub async fn reject(
+        &mut self,
+        post_id: FeedPartition,
+        platform: SocialPlatform,
+    ) -> crate::common::Result<()> {
+        reject_job_handler(post_id, platform).await?;
+        self.approvals.restart();
+        Ok(())
+    }
+}
+
+#[track_caller]
+pub fn use_team_connections(
+    team_pk: TeamPartition,
+) -> std::result::Result<UseTeamConnections, RenderError> {
+    let team_pk_signal = use_signal(|| team_pk);
+
+    let connections = use_loader(move || async move {
+        match list_team_connections_handler(team_pk_signal()).await {
+            Ok(list) => Ok::<Vec<ConnectionResponse>, crate::common::Error>(list),
+            Err(_) => Ok(Vec::new()),
+        }
+    })?;
+
+    let approvals = use_loader(move || async move {
+        match list_team_approvals_handler(team_pk_signal()).await {
+            Ok(list) => Ok::<Vec<ApprovalResponse>, crate::common::Error>(list),
+            Err(_) => Ok(Vec::new()),
+        }
+    })?;
+
+    Ok(UseTeamConnections {
+        connections,
+        approvals,
+        team_pk: team_pk_signal,
+    })
+}
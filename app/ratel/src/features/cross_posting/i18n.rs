@@ -20,7 +20,7 @@ translate! {
 
     // Section heading
     section_platforms: { en: "Networks", ko: "네트워크" },
-    section_meta_phase1: { en: "Phase 1 · Bluesky / LinkedIn / Threads", ko: "Phase 1 · Bluesky / LinkedIn / Threads" },
+    section_meta_phase1: { en: "Bluesky / LinkedIn / Threads / Mastodon", ko: "Bluesky / LinkedIn / Threads / Mastodon" },
 
     // Status pills
     status_connected: { en: "Connected", ko: "연결됨" },
@@ -41,7 +41,12 @@ translate! {
     // Threads card (1C)
     threads_name: { en: "Threads", ko: "Threads" },
     threads_limit: { en: "500 chars · Requires IG account", ko: "500자 · 인스타그램 프로페셔널 필요" },
-    threads_subtitle: { en: "Meta OAuth integration arrives in Phase 1C", ko: "Meta OAuth 연동은 Phase 1C 에서 활성화됩니다" },
+    threads_subtitle_default: { en: "Meta OAuth flow · revocable anytime", ko: "Meta OAuth 방식 · 언제든 폐기 가능" },
+
+    // Mastodon card — per-instance OAuth
+    mastodon_name: { en: "Mastodon", ko: "Mastodon" },
+    mastodon_limit: { en: "500 chars · 4 images · any server", ko: "500자 · 이미지 4장 · 모든 서버" },
+    mastodon_subtitle_default: { en: "OAuth on your own server · revocable anytime", ko: "내 서버의 OAuth 방식 · 언제든 폐기 가능" },
 
     // Farcaster card (Phase 2)
     farcaster_name: { en: "Farcaster", ko: "Farcaster" },
@@ -54,6 +59,18 @@ translate! {
     // Sub-row (connected card)
     posts_syndicated_count_label: { en: " posts syndicated", ko: " 건 게시됨" },
     auto_post: { en: "Auto-post new posts", ko: "새 글 자동 게시" },
+    import_replies: { en: "Import replies", ko: "답글 가져오기" },
+    best_time: { en: "Best time", ko: "최적 게시 시간" },
+    thread_mode: { en: "Thread long posts", ko: "긴 글 스레드로 게시" },
+    thread_mode_hint: {
+        en: "Posts over the limit go out as a numbered reply chain instead of being cut short",
+        ko: "글자 수 제한을 넘는 글을 줄이지 않고 번호가 붙은 답글 체인으로 게시합니다",
+    },
+    space_cards: { en: "Share space cards", ko: "스페이스 카드 공유" },
+    space_cards_hint: {
+        en: "Post a card when your public spaces go live, and a results chart when they finish",
+        ko: "공개 스페이스가 시작되면 카드를, 종료되면 결과 차트를 게시합니다",
+    },
 
     // Buttons
     btn_connect: { en: "Connect", ko: "연결하기" },
@@ -90,6 +107,30 @@ translate! {
     btn_connect: { en: "Connect", ko: "연결하기" },
 }
 
+translate! {
+    MastodonConnectModalTranslate;
+
+    title: { en: "Connect Mastodon", ko: "Mastodon 연결" },
+    subtitle: { en: "Enter the server your account lives on", ko: "계정이 있는 서버를 입력하세요" },
+    close: { en: "Close", ko: "닫기" },
+
+    info: {
+        en: "You'll be sent to your server to approve Ratel. We only ask to post and read your own statuses — never your DMs or follows.",
+        ko: "서버로 이동해 Ratel 을 승인하게 됩니다. 내 게시물 작성·조회 권한만 요청하며 DM 이나 팔로우 목록은 요청하지 않습니다.",
+    },
+
+    label_instance: { en: "Server", ko: "서버" },
+    placeholder_instance: { en: "mastodon.social", ko: "mastodon.social" },
+    hint_instance: {
+        en: "A domain like mastodon.social, or your full address @you@server",
+        ko: "mastodon.social 같은 도메인 또는 전체 주소 @you@server",
+    },
+
+    foot_hint: { en: "Encrypted at rest · revocable anytime", ko: "저장 시 암호화 · 언제든 폐기" },
+    btn_cancel: { en: "Cancel", ko: "취소" },
+    btn_connect: { en: "Continue", ko: "계속" },
+}
+
 translate! {
     ComposeSidebarTranslate;
 
@@ -118,12 +159,26 @@ translate! {
         en: "Connect Threads to reach Meta's audience with every post.",
         ko: "Threads 를 연결해 Meta 의 사용자에게 모든 게시물을 전달하세요.",
     },
+    connect_hint_mastodon: {
+        en: "Connect your Mastodon server to reach the fediverse.",
+        ko: "Mastodon 서버를 연결해 연합우주(fediverse)에 도달하세요.",
+    },
 
     connect_btn_bluesky: { en: "Connect Bluesky", ko: "Bluesky 연결" },
     connect_btn_linkedin: { en: "Connect LinkedIn", ko: "LinkedIn 연결" },
     connect_btn_threads: { en: "Connect Threads", ko: "Threads 연결" },
+    connect_btn_mastodon: { en: "Connect Mastodon", ko: "Mastodon 연결" },
 
     coming_soon: { en: "Coming soon", ko: "곧 출시" },
+
+    schedule_label: { en: "Send", ko: "발송" },
+    schedule_best_time: { en: "At best time", ko: "최적 시간" },
+    schedule_now: { en: "Immediately", ko: "즉시" },
+    schedule_at: { en: "At a set time…", ko: "시간 지정…" },
+
+    queue_title: { en: "Scheduled", ko: "예약된 게시" },
+    queue_untitled: { en: "Untitled post", ko: "제목 없는 게시물" },
+    queue_cancel: { en: "Cancel", ko: "취소" },
 }
 
 translate! {
@@ -141,12 +196,23 @@ translate! {
     status_pending: { en: "Pending", ko: "대기 중" },
     status_failed: { en: "Failed", ko: "실패" },
     status_skipped: { en: "Skipped", ko: "건너뜀" },
+    status_scheduled: { en: "Scheduled", ko: "예약됨" },
+    status_cancelled: { en: "Cancelled", ko: "취소됨" },
+    status_awaiting_approval: { en: "Awaiting approval", ko: "승인 대기" },
+    status_rejected: { en: "Rejected", ko: "반려됨" },
 
     queued_hint: { en: "Queued — awaiting dispatch", ko: "대기열 — 곧 발송됩니다" },
+    approval_hint: {
+        en: "A team admin has to approve this cross-post",
+        ko: "팀 관리자의 승인 후 발송됩니다",
+    },
     attempts_label: { en: "Attempt", ko: "시도" },
 
     btn_view: { en: "View", ko: "열기" },
     btn_retry: { en: "Retry now", ko: "재시도" },
+    btn_cancel: { en: "Cancel", ko: "취소" },
+    scheduled_for: { en: "Goes out", ko: "발송 예정" },
+    thread_posts: { en: "posts in a thread", ko: "개 글로 이어진 스레드" },
 
     engage_likes: { en: "likes", ko: "좋아요" },
     engage_comments: { en: "comments", ko: "댓글" },
@@ -160,15 +226,34 @@ translate! {
     not_connected: { en: "Not connected", ko: "연결 안 됨" },
     btn_connect_bluesky: { en: "Connect Bluesky", ko: "Bluesky 연결하기" },
     btn_connect_linkedin: { en: "Connect LinkedIn", ko: "LinkedIn 연결하기" },
+    btn_connect_threads: { en: "Connect Threads", ko: "Threads 연결하기" },
+    btn_connect_mastodon: { en: "Connect Mastodon", ko: "Mastodon 연결하기" },
     panel_coming_soon: { en: "Coming soon", ko: "곧 출시" },
     panel_linkedin_coming_soon_hint: {
         en: "LinkedIn cross-posting arrives in Phase 1B.",
         ko: "LinkedIn 크로스포스팅은 Phase 1B 에서 활성화됩니다.",
     },
-    panel_threads_coming_soon_hint: {
-        en: "Threads cross-posting arrives in Phase 1C.",
-        ko: "Threads 크로스포스팅은 Phase 1C 에서 활성화됩니다.",
+
+    // Audit trail — who sent the post to which network, and when.
+    audit_title: { en: "History", ko: "기록" },
+    audit_requested: { en: "requested", ko: "요청" },
+    audit_approved: { en: "approved", ko: "승인" },
+    audit_rejected: { en: "rejected", ko: "반려" },
+    audit_cancelled: { en: "cancelled", ko: "취소" },
+    audit_retried: { en: "retried", ko: "재시도" },
+    audit_published: { en: "published", ko: "게시" },
+    audit_unknown_actor: { en: "Former member", ko: "탈퇴한 멤버" },
+}
+
+translate! {
+    ExternalCommentsTranslate;
+
+    title: { en: "Replies from other networks", ko: "다른 네트워크의 답글" },
+    hint: {
+        en: "Imported from the syndicated copies of this post. Reply on the original network.",
+        ko: "이 글의 외부 게시본에서 가져온 답글입니다. 답글은 원래 네트워크에서 달아 주세요.",
     },
+    view_on: { en: "View on", ko: "원문 보기 ·" },
 }
 
 translate! {
@@ -183,7 +268,7 @@ translate! {
     // Hero
     eyebrow: { en: "Connect your networks · Optional", ko: "네트워크 연결 · 선택" },
     title_lead: { en: "Your first post reaches", ko: "첫 게시글 한 번에" },
-    title_accent: { en: "three networks instantly.", ko: "세 네트워크에 도달합니다." },
+    title_accent: { en: "every network at once.", ko: "모든 네트워크에 도달합니다." },
     sub: {
         en: "Connect the social accounts you want Ratel to cross-post to. Every post becomes a link back to your home, bringing new subscribers in.",
         ko: "Ratel 이 자동 크로스포스팅할 소셜 계정을 연결하세요. 모든 글이 본진으로 돌아오는 백링크가 됩니다.",
@@ -197,6 +282,7 @@ translate! {
     },
     linkedin_meta: { en: "Your professional network · 3,000 chars", ko: "전문 네트워크 · 3,000자" },
     threads_meta: { en: "Meta · ~275M users · 500 chars", ko: "Meta · 약 2.75억 명 · 500자" },
+    mastodon_meta: { en: "Fediverse · any server · 500 chars", ko: "연합우주 · 모든 서버 · 500자" },
     coming_soon: { en: "Coming soon", ko: "곧 출시" },
     status_connected: { en: "Connected", ko: "연결됨" },
     btn_connect: { en: "Connect", ko: "연결" },
@@ -229,3 +315,61 @@ translate! {
         ko: "2개 이상의 네트워크를 연결한 크리에이터는 첫 30일에 3.4배 더 많은 구독자를 얻습니다.",
     },
 }
+
+translate! {
+    TeamConnectionsTranslate;
+
+    title: { en: "Shared social accounts", ko: "팀 소셜 계정" },
+    subtitle: {
+        en: "Team posts cross-post through these accounts. Members need the Cross-post permission to use them.",
+        ko: "팀 게시물은 이 계정으로 확장 게시됩니다. 사용하려면 멤버에게 확장 게시 권한이 필요합니다.",
+    },
+
+    bluesky_hint: { en: "App password flow · revocable anytime", ko: "앱 비밀번호 방식 · 언제든 폐기 가능" },
+    linkedin_hint: { en: "Posts as your LinkedIn company page", ko: "LinkedIn 회사 페이지로 게시" },
+    status_connected: { en: "Connected", ko: "연결됨" },
+    status_not_connected: { en: "Not connected", ko: "연결 안 됨" },
+    btn_connect: { en: "Connect", ko: "연결" },
+    btn_disconnect: { en: "Disconnect", ko: "연결 해제" },
+    approval_required: { en: "Require approval", ko: "승인 필요" },
+    approval_required_hint: {
+        en: "Cross-posts wait until a team admin approves them",
+        ko: "팀 관리자가 승인해야 확장 게시됩니다",
+    },
+
+    // Approval queue
+    approvals_title: { en: "Waiting for approval", ko: "승인 대기" },
+    approvals_untitled: { en: "Untitled post", ko: "제목 없는 게시물" },
+    approvals_by: { en: "by", ko: "요청:" },
+    btn_approve: { en: "Approve", ko: "승인" },
+    btn_reject: { en: "Reject", ko: "반려" },
+}
+
+translate! {
+    CrossPostingAnalyticsTranslate;
+
+    title: { en: "Cross-posting analytics", ko: "크로스포스팅 분석" },
+    subtitle: {
+        en: "Which networks bring readers back to Ratel. Visits count readers who followed a cross-post's link.",
+        ko: "어떤 네트워크가 독자를 Ratel 로 데려오는지 보여줍니다. 방문은 외부 게시물의 링크를 따라온 독자 수입니다.",
+    },
+    empty: {
+        en: "No numbers yet. They appear an hour after a cross-post goes out, or as soon as someone follows its link.",
+        ko: "아직 데이터가 없습니다. 외부 게시 한 시간 후, 또는 누군가 링크를 따라오면 표시됩니다.",
+    },
+
+    // Network totals
+    visits: { en: "Visits", ko: "방문" },
+    engagement: { en: "Engagement", ko: "반응" },
+    posts_count: { en: "posts", ko: "개 게시물" },
+    likes: { en: "Likes", ko: "좋아요" },
+    comments: { en: "Comments", ko: "댓글" },
+    reposts: { en: "Reposts", ko: "재게시" },
+
+    // Daily chart
+    daily_title: { en: "Last 30 days", ko: "최근 30일" },
+
+    // Post list
+    posts_title: { en: "Top posts", ko: "인기 게시물" },
+    untitled: { en: "Untitled post", ko: "제목 없는 게시물" },
+}
@@ -0,0 +1,17 @@
+//! Backlink landing detection for cross-posting analytics.
+//!
+//! Same `eval` channel pattern as [`super::redirect_to_external`]; off
+//! the web the runner never answers and the call resolves to `None`.
+
+use dioxus::document::eval as dx_eval;
+
+use crate::features::cross_posting::types::SocialPlatform;
+
+/// The network named by the page's `?utm_source=`, the first time this
+/// tab session lands on the page with it. `None` for unknown sources and
+/// for repeat landings.
+pub async fn take_backlink_source() -> Option<SocialPlatform> {
+    let mut runner = dx_eval(include_str!("web/take_backlink_source.js"));
+    let source: Option<String> = runner.recv().await.ok()?;
+    source?.parse().ok()
+}
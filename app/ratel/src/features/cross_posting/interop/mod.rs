@@ -1,2 +1,4 @@
+mod backlink;
 mod redirect;
+pub use backlink::*;
 pub use redirect::*;
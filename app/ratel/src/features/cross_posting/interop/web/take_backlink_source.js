@@ -0,0 +1,16 @@
+// Report `?utm_source=` once per tab session and page, so reloads and
+// back-navigation don't count as new landings.
+let fresh = null;
+const source = new URLSearchParams(window.location.search).get("utm_source");
+if (source) {
+  const key = `ratel:backlink:${window.location.pathname}:${source}`;
+  try {
+    if (!window.sessionStorage.getItem(key)) {
+      window.sessionStorage.setItem(key, "1");
+      fresh = source;
+    }
+  } catch (_) {
+    fresh = source;
+  }
+}
+dioxus.send(fresh);
@@ -0,0 +1,42 @@
+use crate::common::*;
+use crate::features::cross_posting::models::SyndicationJob;
+use crate::features::cross_posting::types::SocialPlatform;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// One point of a syndication's engagement history. Stage 4 overwrites
+/// the `EngagementSnapshot` on every refresh; each refresh also appends
+/// one of these, so the adaptive cadence (hourly, then 6-hourly, then
+/// daily) leaves about 70 points per job over its 30-day window.
+///
+/// - pk: same partition as the `SyndicationJob` it samples
+/// - sk: ENGAGEMENT_SAMPLE#{job sk inner}#{fetched_at}
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct EngagementSample {
+    pub pk: Partition,  // Feed(post_id), or Space(id) for space cards
+    pub sk: EntityType, // EngagementSample("{job sk inner}#{fetched_at}")
+
+    pub platform: SocialPlatform,
+
+    pub likes: i32,
+    pub comments: i32,
+    pub reposts: i32,
+
+    pub fetched_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl EngagementSample {
+    /// Epoch-ms timestamps are 13 digits until the year 2286, so the sort
+    /// key orders samples chronologically.
+    pub fn sk_for(job: &SyndicationJob, fetched_at: i64) -> EntityType {
+        match &job.sk {
+            EntityType::SyndicationJob(inner) => {
+                EntityType::EngagementSample(format!("{inner}#{fetched_at}"))
+            }
+            _ => EntityType::EngagementSample(format!("{}#{fetched_at}", job.platform)),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::common::*;
+use crate::features::cross_posting::types::SocialPlatform;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// A reply to a syndicated post, imported back from the platform and shown
+/// read-only under the original `Post`. Written only by the Stage 4
+/// engagement refresh for connections with `import_replies_enabled`; the
+/// key is derived from the platform-native reply id, so every refresh
+/// upserts the same row (edits propagate, nothing duplicates).
+///
+/// - pk: FEED#{post_id}
+/// - sk: EXTERNAL_COMMENT#{platform}#{hashed reply id}
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ExternalComment {
+    pub pk: Partition,  // Feed(post_id)
+    pub sk: EntityType, // ExternalComment("{platform}#{hash}")
+
+    pub platform: SocialPlatform,
+    /// Platform-native reply id as returned by the adapter.
+    pub external_id: String,
+
+    pub author_handle: String,
+    pub author_display_name: String,
+    /// `https://` only; anything else the platform sent is dropped.
+    pub author_avatar_url: Option<String>,
+
+    /// Plain text — never rendered as HTML.
+    pub content: String,
+    /// Public link to the reply on the platform. Empty when the
+    /// platform's link was not `https://`.
+    pub url: String,
+
+    /// When the reply was posted on the platform (epoch ms, 0 if unknown).
+    pub posted_at: i64,
+    pub imported_at: i64,
+    pub updated_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl ExternalComment {
+    /// Reply ids are `at://` URIs on Bluesky, so they are hashed rather
+    /// than embedded in the sort key verbatim.
+    pub fn keys(
+        post_pk: &Partition,
+        platform: SocialPlatform,
+        external_id: &str,
+    ) -> (Partition, EntityType) {
+        use sha2::Digest;
+
+        let digest = sha2::Sha256::digest(external_id.as_bytes());
+        (
+            post_pk.clone(),
+            EntityType::ExternalComment(format!("{platform}#{}", hex::encode(&digest[..16]))),
+        )
+    }
+}
@@ -0,0 +1,41 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Ratel's OAuth client registration on one Mastodon instance. Mastodon
+/// has no central developer portal — every instance issues its own
+/// `client_id` / `client_secret` via `POST /api/v1/apps` — so the first
+/// user to connect from an instance registers the app and everyone after
+/// them reuses this row.
+///
+/// - pk: MASTODON_INSTANCE#{host}
+/// - sk: MASTODON_APP
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct MastodonApp {
+    pub pk: Partition,  // MastodonInstance(host)
+    pub sk: EntityType, // MastodonApp (singleton)
+
+    /// Normalized `https://{host}` base URL.
+    pub instance_url: String,
+    pub client_id: String,
+    /// AEAD-sealed client secret (`crate::common::utils::aead::seal`).
+    pub client_secret_ciphertext: Vec<u8>,
+    /// Redirect URI the app was registered with. Mastodon rejects the
+    /// authorize / token calls if it differs, so a changed site URL
+    /// forces a re-registration.
+    pub redirect_uri: String,
+
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl MastodonApp {
+    pub fn keys(host: &str) -> (Partition, EntityType) {
+        (
+            Partition::MastodonInstance(host.to_string()),
+            EntityType::MastodonApp,
+        )
+    }
+}
@@ -1,11 +1,21 @@
+mod engagement_sample;
 mod engagement_snapshot;
+mod external_comment;
+mod mastodon_app;
 mod post_syndication_directive;
 mod social_connection;
+mod syndication_audit_entry;
 mod syndication_job;
+mod syndication_stats;
 mod user_onboarding_flags;
 
+pub use engagement_sample::*;
 pub use engagement_snapshot::*;
+pub use external_comment::*;
+pub use mastodon_app::*;
 pub use post_syndication_directive::*;
 pub use social_connection::*;
+pub use syndication_audit_entry::*;
 pub use syndication_job::*;
+pub use syndication_stats::*;
 pub use user_onboarding_flags::*;
@@ -1,5 +1,5 @@
 use crate::common::*;
-use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::cross_posting::types::{SchedulePlan, SocialPlatform};
 use std::collections::HashMap;
 #[cfg(feature = "server")]
 #[allow(unused_imports)]
@@ -29,6 +29,11 @@ pub struct PostSyndicationDirective {
     /// to `SyndicationJob.body_override`.
     pub platform_overrides: HashMap<SocialPlatform, String>,
 
+    /// Per-platform timing picked at compose time. Platforms missing here
+    /// use `SchedulePlan::Auto` (the connection's best time, if any).
+    #[serde(default)]
+    pub schedule: HashMap<SocialPlatform, SchedulePlan>,
+
     /// User who published the post; Stage 1 reads this to resolve the
     /// matching SocialConnections.
     pub author_user_id: Partition,
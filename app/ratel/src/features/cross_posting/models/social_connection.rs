@@ -1,10 +1,12 @@
 use crate::common::*;
-use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::cross_posting::types::{PostingTime, SocialPlatform};
 #[cfg(feature = "server")]
 #[allow(unused_imports)]
 use rmcp::schemars;
 
-/// Per-user, per-platform external account credential. KMS-encrypted.
+/// Per-owner, per-platform external account credential. KMS-encrypted.
+/// Owned by a user, or by a team for an organization's shared account
+/// (members with `TeamGroupPermission::CrossPost` publish through it).
 ///
 /// Design doc: docs/superpowers/specs/2026-04-28-cross-posting-design.md
 /// (`SocialConnection` section). FR-1 #1–#7.
@@ -12,7 +14,7 @@ use rmcp::schemars;
 #[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
 pub struct SocialConnection {
     #[dynamo(prefix = "SC", index = "gsi1", name = "find_by_platform", pk)]
-    pub pk: Partition, // User(user_id)
+    pub pk: Partition, // User(user_id) | Team(team_id)
 
     pub sk: EntityType, // SocialConnection(platform.to_string())
 
@@ -44,6 +46,38 @@ pub struct SocialConnection {
     /// Per-platform auto-post toggle (FR-3 #17). Default `true` on connect.
     pub auto_post_enabled: bool,
 
+    /// Reply-import toggle. When on, the Stage 4 engagement refresh also
+    /// pulls replies to this connection's syndicated posts back as
+    /// read-only `ExternalComment`s. Off by default; absent on rows
+    /// written before the toggle existed.
+    #[serde(default)]
+    pub import_replies_enabled: bool,
+
+    /// Thread-mode toggle. When on, posts too long for one platform post
+    /// go out as a numbered reply chain instead of being cut to their
+    /// first sentence. Off by default; absent on older rows.
+    #[serde(default)]
+    pub thread_mode_enabled: bool,
+
+    /// Space-card toggle. When on, this connection also posts a "vote
+    /// now" card when one of the user's public spaces starts and a poll
+    /// results card when it finishes. Off by default; absent on older
+    /// rows.
+    #[serde(default)]
+    pub space_cards_enabled: bool,
+
+    /// Team connections only. When on, cross-posts through this
+    /// connection wait in `JobState::AwaitingApproval` until a team admin
+    /// approves them. Off by default; always off for user connections.
+    #[serde(default)]
+    pub approval_required: bool,
+
+    /// Default send time for this network. Posts published with the
+    /// compose-time `SchedulePlan::Auto` are held until the next
+    /// occurrence; `None` means they go out immediately.
+    #[serde(default)]
+    pub best_time: Option<PostingTime>,
+
     /// Cumulative count of syndicated posts (FR-3 #17). Atomic ADD on each
     /// successful Stage 2 dispatch.
     pub posts_syndicated_count: i64,
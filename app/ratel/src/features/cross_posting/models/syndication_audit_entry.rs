@@ -0,0 +1,79 @@
+use crate::common::*;
+use crate::features::cross_posting::models::SyndicationJob;
+use crate::features::cross_posting::types::SocialPlatform;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Append-only record of who sent a post to which network. One row per
+/// decision or outcome on a `SyndicationJob` — requested on publish,
+/// approved / rejected by a team admin, cancelled, retried, published —
+/// so the trail survives the job row being reset by a retry.
+///
+/// - pk: FEED#{post_id} (same partition as the jobs it describes)
+/// - sk: SYNDICATION_AUDIT#{created_at}#{platform}#{action}
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct SyndicationAuditEntry {
+    pub pk: Partition,  // Feed(post_id)
+    pub sk: EntityType, // SyndicationAudit("{created_at}#{platform}#{action}")
+
+    pub platform: SocialPlatform,
+    pub action: AuditAction,
+    /// User who took the action. For `Published` this is the member who
+    /// requested the cross-post, not the approver.
+    pub actor: Partition,
+    /// Whose account the post went (or would go) out through — the author
+    /// for personal connections, the team for team connections.
+    pub connection_owner: Partition,
+    /// Public link on the platform, `Published` entries only.
+    #[serde(default)]
+    pub external_post_url: Option<String>,
+
+    pub created_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl SyndicationAuditEntry {
+    pub fn new(job: &SyndicationJob, action: AuditAction, actor: Partition, now: i64) -> Self {
+        Self {
+            pk: job.pk.clone(),
+            sk: EntityType::SyndicationAudit(format!("{now}#{}#{action}", job.platform)),
+            platform: job.platform,
+            action,
+            actor,
+            connection_owner: job.connection_owner().clone(),
+            external_post_url: None,
+            created_at: now,
+        }
+    }
+
+    /// Best-effort write. The job row is already correct by the time this
+    /// runs, so a failed audit write is logged and never surfaces.
+    pub async fn record(
+        cli: &aws_sdk_dynamodb::Client,
+        job: &SyndicationJob,
+        action: AuditAction,
+        actor: Partition,
+        now: i64,
+    ) {
+        if let Err(e) = Self::new(job, action, actor, now).create(cli).await {
+            tracing::warn!(pk = ?job.pk, ?action, error = %e, "syndication audit write failed");
+        }
+    }
+}
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditAction {
+    /// Enqueued on publish (or straight into the approval queue).
+    #[default]
+    Requested,
+    Approved,
+    Rejected,
+    Cancelled,
+    Retried,
+    Published,
+}
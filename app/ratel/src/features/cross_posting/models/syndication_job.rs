@@ -16,10 +16,12 @@ pub const SHARD_COUNT: u32 = 4;
 /// external posts; setting it too high slows recovery from a dead Lambda.
 pub const LOCK_TTL_SEC: i64 = 60;
 
-/// One row per (post × platform). Tracks the lifecycle of a single
-/// syndication attempt: pending → dispatching (lock held) → published /
-/// failed / skipped, with retry scheduling on the `dispatch_shard` sparse
-/// GSI and engagement polling on the `engagement_shard` sparse GSI.
+/// One row per (post × platform), or per (space × platform × card) for
+/// space cards. Tracks the lifecycle of a single syndication attempt:
+/// [awaiting_approval →] [scheduled →] pending → dispatching (lock held) →
+/// published / failed / skipped, with send scheduling on the
+/// `dispatch_shard` sparse GSI and engagement polling on the
+/// `engagement_shard` sparse GSI.
 ///
 /// Design doc: docs/superpowers/specs/2026-04-28-cross-posting-design.md
 /// (`SyndicationJob` section). FR-5 #29–#34, FR-6 #39, FR-7 #45.
@@ -31,12 +33,13 @@ pub struct SyndicationJob {
 
     pub sk: EntityType, // SyndicationJob(platform.to_string())
 
-    /// Sparse GSI partition key for the **retry sweeper** (Stage 3, 1D).
-    /// Computed via the single shared `services::shard::shard_for(post_id)`
-    /// utility (deterministic non-cryptographic hash, never `DefaultHasher`).
-    /// Set when the job enters the retry queue (state=Failed & retryable);
-    /// cleared (`None`) when the job reaches a terminal state — sparse GSI
-    /// drops the row entirely.
+    /// Sparse GSI partition key for the **syndication scheduler** (the
+    /// retry sweeper it was built for was dropped in favour of manual
+    /// retry). Computed via the single shared
+    /// `services::shard::shard_for(post_id)` utility (deterministic
+    /// non-cryptographic hash, never `DefaultHasher`). Set while the job is
+    /// `Scheduled`; cleared (`None`) when it is released or cancelled —
+    /// sparse GSI drops the row entirely.
     #[dynamo(index = "gsi1", name = "find_due_jobs", pk)]
     pub dispatch_shard: Option<String>,
 
@@ -61,7 +64,48 @@ pub struct SyndicationJob {
     /// For fan-out / privacy re-check at dispatch time.
     pub author_user_id: Partition,
 
+    /// Sparse GSI partition key for the author's **send queue**. Equal to
+    /// `author_user_id` while the job is `Scheduled`; removed when the
+    /// scheduler releases it or the author cancels it.
+    #[serde(default)]
+    #[dynamo(prefix = "SJQ", index = "gsi3", name = "find_queued_by_author", pk)]
+    pub queue_owner: Option<Partition>,
+
+    /// When the job is due to dispatch (epoch ms); `None` for jobs that
+    /// went out on publish. Kept after release so the panel can show the
+    /// planned time. While `Scheduled` the job also sits in the
+    /// `find_due_jobs` GSI with `next_attempt_at = scheduled_at / 1000`.
+    #[serde(default)]
+    #[dynamo(index = "gsi3", sk)]
+    pub scheduled_at: Option<i64>,
+
+    /// Owner of the `SocialConnection` the job sends through when it isn't
+    /// `author_user_id` — the post's team, for team connections. Read via
+    /// [`Self::connection_owner`]; absent on user-connection jobs.
+    #[serde(default)]
+    pub connection_pk: Option<Partition>,
+
+    /// Sparse GSI partition key for a team's **approval queue**. Set to the
+    /// team while the job is `AwaitingApproval`; removed when an admin
+    /// approves or rejects it.
+    #[serde(default)]
+    #[dynamo(prefix = "SJA", index = "gsi4", name = "find_awaiting_approval", pk)]
+    pub approval_team: Option<Partition>,
+
+    /// Team admin who approved or rejected the job, and when (epoch ms).
+    #[serde(default)]
+    pub reviewed_by: Option<Partition>,
+    #[serde(default)]
+    pub reviewed_at: Option<i64>,
+
     pub platform: SocialPlatform,
+
+    /// What the job publishes. Post jobs live under the post's `Feed` pk;
+    /// space cards under the space's `Space` pk (see [`Self::sk_for`]).
+    /// Absent on rows written before space cards.
+    #[serde(default)]
+    pub source: JobSource,
+
     pub state: JobState,
 
     /// 0..=3. Incremented inside Stage 2 on each terminal-failure write.
@@ -73,6 +117,12 @@ pub struct SyndicationJob {
     pub external_post_id: Option<String>,
     pub external_post_url: Option<String>,
 
+    /// Ids of the replies that continue a thread-mode post, in order after
+    /// `external_post_id`. Empty for single posts. Stage 4 sums engagement
+    /// across the root and these, and skips them on reply import.
+    #[serde(default)]
+    pub thread_post_ids: Vec<String>,
+
     // body_override: Option<String>      // RESERVED for v1.5 (per-network compose variants).
     //                                     // Stage 2 dispatcher reads this in front of
     //                                     // format_for_platform() — Phase 1 always None,
@@ -99,10 +149,53 @@ pub struct SyndicationJob {
     pub dispatch_lock_id: Option<String>,
     pub lock_acquired_at: Option<i64>,
 
+    #[dynamo(index = "gsi4", sk)]
     pub created_at: i64,
     pub updated_at: i64,
 }
 
+impl SyndicationJob {
+    /// Partition of the `SocialConnection` this job publishes through.
+    pub fn connection_owner(&self) -> &Partition {
+        self.connection_pk.as_ref().unwrap_or(&self.author_user_id)
+    }
+
+    /// Sort key of the job for `platform`. A post has one job per
+    /// platform, keyed by the bare platform; a space can get both cards,
+    /// so the card kind is appended.
+    pub fn sk_for(platform: SocialPlatform, source: JobSource) -> EntityType {
+        match source {
+            JobSource::Post => EntityType::SyndicationJob(platform.to_string()),
+            _ => EntityType::SyndicationJob(format!("{platform}#{source}")),
+        }
+    }
+
+    /// Sort key of this job's `EngagementSnapshot`, which shares the job's
+    /// inner key (so a post job's snapshot stays keyed by platform).
+    pub fn snapshot_sk(&self) -> EntityType {
+        match &self.sk {
+            EntityType::SyndicationJob(inner) => EntityType::EngagementSnapshot(inner.clone()),
+            _ => EntityType::EngagementSnapshot(self.platform.to_string()),
+        }
+    }
+}
+
+/// What a [`SyndicationJob`] publishes.
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum JobSource {
+    /// The post itself, formatted for the platform.
+    #[default]
+    Post,
+    /// "Vote now" card, enqueued when a public space with a poll starts.
+    SpaceLive,
+    /// Poll results card with a rendered chart, enqueued when a public
+    /// space finishes.
+    SpaceResults,
+}
+
 #[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, strum::Display)]
 #[serde(rename_all = "snake_case")]
@@ -115,6 +208,16 @@ pub enum JobState {
     /// Privacy guard tripped at dispatch (post became Private / team-shared
     /// after enqueue). FR-6 #39–#40.
     Skipped,
+    /// Held until `scheduled_at`; the syndication scheduler flips it to
+    /// `Pending`, which enters Stage 2 like any fresh job.
+    Scheduled,
+    /// Author cancelled a `Scheduled` job before it went out.
+    Cancelled,
+    /// Sent through a team connection that requires approval; parked in
+    /// the team's `find_awaiting_approval` queue until an admin decides.
+    AwaitingApproval,
+    /// A team admin turned the cross-post down. Terminal, like `Cancelled`.
+    Rejected,
 }
 
 #[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
@@ -0,0 +1,139 @@
+use crate::common::*;
+use crate::features::cross_posting::types::SocialPlatform;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Days since the Unix epoch (UTC) of an epoch-ms timestamp — the bucket
+/// of [`SyndicationDailyStats`].
+pub fn stats_day(at_ms: i64) -> i64 {
+    at_ms.div_euclid(24 * 60 * 60 * 1000)
+}
+
+/// Per-(post × platform) rollup in the author's partition, so the
+/// analytics dashboard reads every syndicated post with one query.
+/// Engagement mirrors the latest `EngagementSnapshot` (Stage 4 sets it);
+/// `visits` counts backlink click-throughs and moves by atomic `ADD`.
+///
+/// - pk: USER#{author_id} (the job's `author_user_id`)
+/// - sk: SYNDICATION_POST_STATS#{post_id}#{platform}
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(default)]
+pub struct SyndicationPostStats {
+    pub pk: Partition,  // User(author_id)
+    pub sk: EntityType, // SyndicationPostStats("{post_id}#{platform}")
+
+    pub post_pk: Partition,
+    pub platform: SocialPlatform,
+
+    pub likes: i64,
+    pub comments: i64,
+    pub reposts: i64,
+    pub visits: i64,
+
+    pub updated_at: i64,
+}
+
+/// Per-(UTC day × platform) totals in the author's partition: the change
+/// in engagement Stage 4 observed that day plus the backlink visits that
+/// landed that day. Every field moves by atomic `ADD`, which also creates
+/// the row.
+///
+/// - pk: USER#{author_id}
+/// - sk: SYNDICATION_DAILY_STATS#{day:06}#{platform}
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(default)]
+pub struct SyndicationDailyStats {
+    pub pk: Partition,  // User(author_id)
+    pub sk: EntityType, // SyndicationDailyStats("{day:06}#{platform}")
+
+    /// Days since the Unix epoch, see [`stats_day`].
+    pub day: i64,
+    pub platform: SocialPlatform,
+
+    pub likes: i64,
+    pub comments: i64,
+    pub reposts: i64,
+    pub visits: i64,
+
+    pub updated_at: i64,
+}
+
+impl SyndicationPostStats {
+    pub fn keys(
+        author_pk: &Partition,
+        post_pk: &Partition,
+        platform: SocialPlatform,
+    ) -> (Partition, EntityType) {
+        let post_id = match post_pk {
+            Partition::Feed(id) => id.clone(),
+            other => other.to_string(),
+        };
+        (
+            author_pk.clone(),
+            EntityType::SyndicationPostStats(format!("{post_id}#{platform}")),
+        )
+    }
+}
+
+impl SyndicationDailyStats {
+    /// The day is zero-padded so the sort key orders by day; six digits
+    /// last until the year 4707.
+    pub fn keys(
+        author_pk: &Partition,
+        day: i64,
+        platform: SocialPlatform,
+    ) -> (Partition, EntityType) {
+        (
+            author_pk.clone(),
+            EntityType::SyndicationDailyStats(format!("{day:06}#{platform}")),
+        )
+    }
+}
+
+/// How long a [`SyndicationVisitMark`] outlives its day. Only today's
+/// mark is ever read, so one spare day covers clock skew at midnight.
+pub const VISIT_MARK_TTL_DAYS: i64 = 2;
+
+/// One visitor's backlink landing on a post from a platform on a UTC
+/// day. `record_visit` creates it conditionally before counting, so a
+/// reader reloading the page — or a script replaying the beacon — adds
+/// one visit per day at most. DynamoDB expires the row afterwards.
+///
+/// - pk: FEED#{post_id}
+/// - sk: SYNDICATION_VISIT_MARK#{platform}#{day:06}#{visitor_hash}
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(default)]
+pub struct SyndicationVisitMark {
+    pub pk: Partition,  // Feed(post_id)
+    pub sk: EntityType, // SyndicationVisitMark("{platform}#{day:06}#{visitor_hash}")
+
+    pub created_at: i64,
+
+    /// DynamoDB TTL field (epoch seconds).
+    pub expires_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl SyndicationVisitMark {
+    /// `visitor` is an opaque fingerprint of the reader (client address
+    /// and user agent); only a truncated hash of it is stored.
+    pub fn new(post_pk: &Partition, platform: SocialPlatform, visitor: &str, now_ms: i64) -> Self {
+        use sha2::Digest;
+
+        let day = stats_day(now_ms);
+        let digest = sha2::Sha256::digest(format!("{day}#{visitor}").as_bytes());
+        Self {
+            pk: post_pk.clone(),
+            sk: EntityType::SyndicationVisitMark(format!(
+                "{platform}#{day:06}#{}",
+                hex::encode(&digest[..16])
+            )),
+            created_at: now_ms,
+            expires_at: now_ms / 1000 + VISIT_MARK_TTL_DAYS * 86_400,
+        }
+    }
+}
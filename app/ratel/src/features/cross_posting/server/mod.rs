@@ -1,11 +1,12 @@
 //! Server-only axum routes for cross-posting that don't fit Dioxus'
 //! `Result<JSON>` server-function shape.
 //!
-//! Currently just the LinkedIn OAuth callback, which LinkedIn redirects
-//! the user's browser to with `?code=&state=` query params. The handler
-//! consumes those, persists the new SocialConnection row, and 302s the
-//! user back to the connections page — none of which fits the
-//! JSON-response contract that `#[get]/#[post]` macros generate.
+//! Currently the LinkedIn, Threads and Mastodon OAuth callbacks, which the
+//! platform redirects the user's browser to with `?code=&state=` query
+//! params. Each handler consumes those, persists the new
+//! SocialConnection row, and 302s the user back to the connections page
+//! — none of which fits the JSON-response contract that `#[get]/#[post]`
+//! macros generate.
 
 use crate::common::axum::{
     Extension, Router,
@@ -15,56 +16,45 @@ use crate::common::axum::{
 };
 use crate::common::config::site_base_url;
 use crate::common::models::auth::SESSION_KEY_USER_ID;
-use crate::features::cross_posting::services::adapters::{DecryptedCredentials, LinkedInAdapter};
+use crate::features::cross_posting::services::adapters::{
+    DecryptedCredentials, LinkedInAdapter, MastodonAdapter, ThreadsAdapter, instance_host,
+    resolves_publicly,
+};
 use crate::features::cross_posting::services::connection::{
     ConnectionUpsert, seal_and_upsert_connection,
 };
-use crate::features::cross_posting::services::oauth_state;
+use crate::features::cross_posting::services::{mastodon_app, oauth_state, team_access};
 use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::posts::models::Team;
 use serde::Deserialize;
 
-/// LinkedIn callback query string. LinkedIn either sends `code+state` on
-/// success, or `error+error_description+state` on user-denial / invalid
-/// scope / etc — we accept both shapes via `Option`s and surface the
-/// appropriate redirect query.
-#[derive(Deserialize)]
-struct LinkedInCallbackQuery {
-    code: Option<String>,
-    state: Option<String>,
-    error: Option<String>,
-    #[serde(rename = "error_description")]
-    error_description: Option<String>,
+/// The signed-in user an OAuth callback is completing the flow for.
+struct SessionUser {
+    user_pk: crate::common::Partition,
+    user_pk_str: String,
+    username: String,
 }
 
-/// `GET /api/cross-posting/connections/linkedin/callback`. The browser
-/// arrives here from LinkedIn's redirect after the user clicks Allow (or
-/// Deny). We:
-///   1. Reject if the user isn't signed in (no session) — mid-flow
-///      sign-out is rare but recoverable: redirect to login with a
-///      reason.
-///   2. Surface user-cancel / LinkedIn errors via `?linkedin=denied|error`.
-///   3. Verify the state HMAC + user_pk match.
-///   4. Exchange the code for tokens, derive the member URN.
-///   5. Seal + upsert the SocialConnection row via the shared helper.
-///   6. 302 to `/settings/connections?linkedin=ok`.
-async fn linkedin_callback(
-    Extension(session): Extension<tower_sessions::Session>,
-    Query(q): Query<LinkedInCallbackQuery>,
-) -> Response {
-    // (1) Auth check.
+/// Resolve the session user and their username. The connections page is
+/// nested under `#[nest("/:username")]` in `route.rs`, so every redirect
+/// from a callback MUST include the username segment — otherwise the
+/// Dioxus router renders "Page not found". Resolved once up-front so every
+/// error / success branch can build its URL without an extra DB hop.
+///
+/// `Err` carries the redirect to return as-is: to login when there is no
+/// session (mid-flow sign-out is rare but recoverable), or to the site
+/// root when the session user can't be resolved.
+async fn session_user(
+    session: &tower_sessions::Session,
+    platform: &str,
+) -> std::result::Result<SessionUser, Response> {
     let user_pk_str: String = match session.get(SESSION_KEY_USER_ID).await {
         Ok(Some(s)) => s,
         _ => {
-            return Redirect::to(&format!("{}/login", site_base_url())).into_response();
+            return Err(Redirect::to(&format!("{}/login", site_base_url())).into_response());
         }
     };
 
-    // Parse the session's user_pk and look up the username. The connections
-    // page is nested under `#[nest("/:username")]` in `route.rs`, so every
-    // redirect from this handler MUST include the username segment —
-    // otherwise the Dioxus router renders "Page not found". We resolve it
-    // once up-front so every error / success branch below can call
-    // `connections_url(...)` without an extra DB hop.
     let cfg = crate::common::CommonConfig::default();
     let cli = cfg.dynamodb();
     let user_pk: crate::common::Partition = match user_pk_str.parse() {
@@ -72,9 +62,10 @@ async fn linkedin_callback(
         Err(_) => {
             tracing::error!(
                 user_pk_str,
-                "linkedin callback: session user_pk failed to parse"
+                platform,
+                "oauth callback: session user_pk failed to parse"
             );
-            return Redirect::to(site_base_url()).into_response();
+            return Err(Redirect::to(site_base_url()).into_response());
         }
     };
     let username = match crate::features::auth::User::get(
@@ -86,10 +77,75 @@ async fn linkedin_callback(
     {
         Ok(Some(u)) => u.username,
         _ => {
-            tracing::error!(?user_pk, "linkedin callback: user record not found");
-            return Redirect::to(site_base_url()).into_response();
+            tracing::error!(?user_pk, platform, "oauth callback: user record not found");
+            return Err(Redirect::to(site_base_url()).into_response());
         }
     };
+
+    Ok(SessionUser {
+        user_pk,
+        user_pk_str,
+        username,
+    })
+}
+
+/// Success destination: the `return_to` carried in the state token when
+/// present (already sanitized to a same-origin path by `oauth_state`),
+/// else `default`. `{platform}=ok` is appended so the page can toast.
+fn success_redirect(return_to: Option<&str>, platform: &str, default: String) -> String {
+    match return_to {
+        Some(path) => {
+            let sep = if path.contains('?') { '&' } else { '?' };
+            format!("{}{path}{sep}{platform}=ok", site_base_url())
+        }
+        None => default,
+    }
+}
+
+/// LinkedIn callback query string. LinkedIn either sends `code+state` on
+/// success, or `error+error_description+state` on user-denial / invalid
+/// scope / etc — we accept both shapes via `Option`s and surface the
+/// appropriate redirect query.
+#[derive(Deserialize)]
+struct LinkedInCallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+    #[serde(rename = "error_description")]
+    error_description: Option<String>,
+}
+
+/// `GET /api/cross-posting/connections/linkedin/callback`. The browser
+/// arrives here from LinkedIn's redirect after the user clicks Allow (or
+/// Deny). We:
+///   1. Reject if the user isn't signed in (no session) — mid-flow
+///      sign-out is rare but recoverable: redirect to login with a
+///      reason.
+///   2. Surface user-cancel / LinkedIn errors via `?linkedin=denied|error`.
+///   3. Verify the state HMAC + user_pk match. A team flow (state carries
+///      `team_pk`) also re-checks that the user still manages the team.
+///   4. Exchange the code for tokens, derive the member URN — or, for a
+///      team, the URN of the company page the user administers
+///      (`?linkedin=no_page` when there is none, `?linkedin=multiple_pages`
+///      when there are several: we never guess which page to bind).
+///   5. Seal + upsert the SocialConnection row via the shared helper.
+///   6. 302 to `/settings/connections?linkedin=ok` (the team's
+///      `/settings` page for a team flow).
+async fn linkedin_callback(
+    Extension(session): Extension<tower_sessions::Session>,
+    Query(q): Query<LinkedInCallbackQuery>,
+) -> Response {
+    // (1) Auth check + username resolution.
+    let SessionUser {
+        user_pk,
+        user_pk_str,
+        username,
+    } = match session_user(&session, "linkedin").await {
+        Ok(u) => u,
+        Err(resp) => return resp,
+    };
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
     let connections_url = |linkedin: &str| -> String {
         format!(
             "{}/{username}/settings/connections?linkedin={linkedin}",
@@ -138,6 +194,26 @@ async fn linkedin_callback(
         return Redirect::to(&connections_url("error")).into_response();
     }
 
+    // Team flow: the connection belongs to the team, and the result page
+    // is the team's settings. Permission is re-checked here — the user
+    // may have lost admin rights while on LinkedIn's consent screen.
+    let team = match decoded.team_pk.clone() {
+        Some(team_pk) => match managed_team_username(cli, &team_pk, &user_pk).await {
+            Some(team_username) => Some((team_pk, team_username)),
+            None => return Redirect::to(&connections_url("error")).into_response(),
+        },
+        None => None,
+    };
+    let result_url = |linkedin: &str| -> String {
+        match &team {
+            Some((_, team_username)) => format!(
+                "{}/{team_username}/settings?linkedin={linkedin}",
+                site_base_url()
+            ),
+            None => connections_url(linkedin),
+        }
+    };
+
     // (4) Code exchange → token + member URN. Redirect URI MUST match
     // the one used at /init byte-for-byte.
     let adapter = LinkedInAdapter::new();
@@ -147,60 +223,355 @@ async fn linkedin_callback(
         Ok(s) => s,
         Err(e) => {
             tracing::error!(error = %e, "linkedin callback: code exchange failed");
-            return Redirect::to(&connections_url("error")).into_response();
+            return Redirect::to(&result_url("error")).into_response();
         }
     };
 
+    // Team connections post as the company page: its URN stands in for
+    // the member URN (the adapter passes any `urn:li:` value through as
+    // the author), and its name is the row's label.
+    let (owner_pk, member_urn, display_name) = match &team {
+        Some((team_pk, _)) => match adapter
+            .administered_organizations(&session_data.access_token)
+            .await
+        {
+            Ok(orgs) if orgs.len() > 1 => {
+                tracing::info!(
+                    count = orgs.len(),
+                    "linkedin callback: user administers several pages"
+                );
+                return Redirect::to(&result_url("multiple_pages")).into_response();
+            }
+            Ok(orgs) => match orgs.into_iter().next() {
+                Some(org) => (team_pk.clone(), org.urn, org.name),
+                None => return Redirect::to(&result_url("no_page")).into_response(),
+            },
+            Err(e) => {
+                tracing::error!(error = %e, "linkedin callback: organization lookup failed");
+                return Redirect::to(&result_url("error")).into_response();
+            }
+        },
+        None => (
+            user_pk,
+            session_data.member_urn.clone(),
+            session_data.display_name.clone(),
+        ),
+    };
+
     // (5) Seal + upsert via shared helper. LinkedIn's access tokens are
     // typically 60-day-lived; we don't currently parse `expires_in`
     // from the response (LinkedInAdapter::TokenResponse drops it) so we
     // pass `None`. The dispatcher's `try_refresh_credentials` handles
     // expiry-driven refresh based on AuthExpired errors.
     let upsert = ConnectionUpsert {
-        user_pk,
+        user_pk: owner_pk,
         platform: SocialPlatform::LinkedIn,
         decrypted: DecryptedCredentials::LinkedIn {
             access_token: session_data.access_token.clone(),
             refresh_token: session_data.refresh_token.clone(),
-            member_urn: session_data.member_urn.clone(),
+            member_urn: member_urn.clone(),
         },
         // `external_handle` is the human-facing label shown on the
         // connections page row. Prefer the OIDC `name` from /v2/userinfo
-        // when present; fall back to the raw member URN if LinkedIn
-        // omits it (OIDC spec lists `name` as optional). `external_user_id`
-        // always stays as the stable URN — it's what the dispatcher uses
-        // to build per-post URLs and run reconcile probes.
-        external_handle: session_data
-            .display_name
-            .clone()
-            .unwrap_or_else(|| session_data.member_urn.clone()),
-        external_user_id: session_data.member_urn,
+        // (the page name for a team) when present; fall back to the raw
+        // URN if LinkedIn omits it (OIDC spec lists `name` as optional).
+        // `external_user_id` always stays as the stable URN — it's what
+        // the dispatcher uses to build per-post URLs and run reconcile
+        // probes.
+        external_handle: display_name.unwrap_or_else(|| member_urn.clone()),
+        external_user_id: member_urn,
         token_expires_at: None,
     };
 
     if let Err(e) = seal_and_upsert_connection(cli, upsert).await {
         tracing::error!(error = %e, "linkedin callback: seal+upsert failed");
-        return Redirect::to(&connections_url("error")).into_response();
+        return Redirect::to(&result_url("error")).into_response();
     }
 
     // (6) Success — prefer the `return_to` carried in the state token
     // (e.g. the post-edit page the user was composing on when they hit
-    // Connect) over the default connections page. `return_to` is
-    // already sanitized to a same-origin path by `oauth_state`. We
-    // tack on `?linkedin=ok` so the destination can show a toast.
-    let success_url = match decoded.return_to.as_deref() {
-        Some(path) => {
-            let sep = if path.contains('?') { '&' } else { '?' };
-            format!("{}{path}{sep}linkedin=ok", site_base_url())
+    // Connect) over the default connections page.
+    Redirect::to(&success_redirect(
+        decoded.return_to.as_deref(),
+        "linkedin",
+        result_url("ok"),
+    ))
+    .into_response()
+}
+
+/// Username of `team_pk` when `user_pk` may manage its connections, for
+/// the team flow's result redirect. `None` (logged) otherwise.
+async fn managed_team_username(
+    cli: &aws_sdk_dynamodb::Client,
+    team_pk: &crate::common::Partition,
+    user_pk: &crate::common::Partition,
+) -> Option<String> {
+    let can_manage = team_access::can_manage(cli, team_pk, user_pk).await;
+    if !matches!(can_manage, Ok(true)) {
+        tracing::warn!(?team_pk, "oauth callback: user can't manage team");
+        return None;
+    }
+    match Team::get(cli, team_pk, Some(crate::common::EntityType::Team)).await {
+        Ok(Some(team)) => Some(team.username),
+        _ => {
+            tracing::error!(?team_pk, "oauth callback: team not found");
+            None
+        }
+    }
+}
+
+/// Threads callback query string. On denial Meta sends `error`,
+/// `error_reason` and `error_description` instead of `code`.
+#[derive(Deserialize)]
+struct ThreadsCallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+    error_reason: Option<String>,
+}
+
+/// `GET /api/cross-posting/connections/threads/callback`. Same six steps
+/// as [`linkedin_callback`], with the Threads code → long-lived token
+/// exchange in step 4 and `?threads=` as the result query.
+async fn threads_callback(
+    Extension(session): Extension<tower_sessions::Session>,
+    Query(q): Query<ThreadsCallbackQuery>,
+) -> Response {
+    let SessionUser {
+        user_pk,
+        user_pk_str,
+        username,
+    } = match session_user(&session, "threads").await {
+        Ok(u) => u,
+        Err(resp) => return resp,
+    };
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let connections_url = |threads: &str| -> String {
+        format!(
+            "{}/{username}/settings/connections?threads={threads}",
+            site_base_url()
+        )
+    };
+
+    if let Some(err) = q.error.as_deref() {
+        tracing::info!(
+            error = err,
+            error_reason = ?q.error_reason,
+            "threads callback received error from provider"
+        );
+        return Redirect::to(&connections_url("denied")).into_response();
+    }
+
+    let (code, state) = match (q.code, q.state) {
+        (Some(c), Some(s)) if !c.is_empty() && !s.is_empty() => (c, s),
+        _ => return Redirect::to(&connections_url("error")).into_response(),
+    };
+
+    let decoded = match oauth_state::decode_and_verify(&state) {
+        Ok(d) => d,
+        Err(e) => {
+            tracing::warn!(error = %e, "threads callback: state verify failed");
+            return Redirect::to(&connections_url("error")).into_response();
+        }
+    };
+    if decoded.user_pk.to_string() != user_pk_str {
+        tracing::warn!(
+            "threads callback: state.user_pk does not match session user — possible cross-user replay"
+        );
+        return Redirect::to(&connections_url("error")).into_response();
+    }
+
+    let adapter = ThreadsAdapter::new();
+    let redirect_uri =
+        crate::features::cross_posting::controllers::connect_threads_init::threads_redirect_uri();
+    let session_data = match adapter.exchange_code(&code, &redirect_uri).await {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!(error = %e, "threads callback: code exchange failed");
+            return Redirect::to(&connections_url("error")).into_response();
         }
-        None => connections_url("ok"),
     };
-    Redirect::to(&success_url).into_response()
+
+    // Long-lived tokens last 60 days; record the expiry so the UI can
+    // warn before the dispatcher has to refresh.
+    let token_expires_at = session_data
+        .expires_in
+        .map(|secs| crate::common::utils::time::now() + secs * 1000);
+    let upsert = ConnectionUpsert {
+        user_pk,
+        platform: SocialPlatform::Threads,
+        decrypted: DecryptedCredentials::Threads {
+            access_token: session_data.access_token.clone(),
+            refresh_token: session_data.access_token.clone(),
+            ig_user_id: session_data.user_id.clone(),
+        },
+        external_handle: session_data
+            .username
+            .unwrap_or_else(|| session_data.user_id.clone()),
+        external_user_id: session_data.user_id,
+        token_expires_at,
+    };
+
+    if let Err(e) = seal_and_upsert_connection(cli, upsert).await {
+        tracing::error!(error = %e, "threads callback: seal+upsert failed");
+        return Redirect::to(&connections_url("error")).into_response();
+    }
+
+    Redirect::to(&success_redirect(
+        decoded.return_to.as_deref(),
+        "threads",
+        connections_url("ok"),
+    ))
+    .into_response()
+}
+
+/// Mastodon callback query string. Mastodon sends `error` +
+/// `error_description` when the user denies access.
+#[derive(Deserialize)]
+struct MastodonCallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// `GET /api/cross-posting/connections/mastodon/callback`. Same six steps
+/// as [`linkedin_callback`]; the instance to exchange the code with comes
+/// from the signed state, and its client credentials from `MastodonApp`.
+async fn mastodon_callback(
+    Extension(session): Extension<tower_sessions::Session>,
+    Query(q): Query<MastodonCallbackQuery>,
+) -> Response {
+    let SessionUser {
+        user_pk,
+        user_pk_str,
+        username,
+    } = match session_user(&session, "mastodon").await {
+        Ok(u) => u,
+        Err(resp) => return resp,
+    };
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+    let connections_url = |mastodon: &str| -> String {
+        format!(
+            "{}/{username}/settings/connections?mastodon={mastodon}",
+            site_base_url()
+        )
+    };
+
+    if let Some(err) = q.error.as_deref() {
+        tracing::info!(
+            error = err,
+            error_description = ?q.error_description,
+            "mastodon callback received error from provider"
+        );
+        return Redirect::to(&connections_url("denied")).into_response();
+    }
+
+    let (code, state) = match (q.code, q.state) {
+        (Some(c), Some(s)) if !c.is_empty() && !s.is_empty() => (c, s),
+        _ => return Redirect::to(&connections_url("error")).into_response(),
+    };
+
+    let decoded = match oauth_state::decode_and_verify(&state) {
+        Ok(d) => d,
+        Err(e) => {
+            tracing::warn!(error = %e, "mastodon callback: state verify failed");
+            return Redirect::to(&connections_url("error")).into_response();
+        }
+    };
+    if decoded.user_pk.to_string() != user_pk_str {
+        tracing::warn!(
+            "mastodon callback: state.user_pk does not match session user — possible cross-user replay"
+        );
+        return Redirect::to(&connections_url("error")).into_response();
+    }
+    let Some(instance_url) = decoded.instance.clone() else {
+        tracing::warn!("mastodon callback: state carries no instance");
+        return Redirect::to(&connections_url("error")).into_response();
+    };
+    // The host passed `normalize_instance` at init, but DNS may have
+    // moved since; re-check before sending it the code.
+    if !resolves_publicly(instance_host(&instance_url)).await {
+        tracing::warn!(
+            %instance_url,
+            "mastodon callback: instance no longer resolves publicly"
+        );
+        return Redirect::to(&connections_url("error")).into_response();
+    }
+
+    let app = match mastodon_app::load(cli, &instance_url).await {
+        Ok(app) => app,
+        Err(e) => {
+            tracing::error!(
+                error = %e,
+                %instance_url,
+                "mastodon callback: app registration missing"
+            );
+            return Redirect::to(&connections_url("error")).into_response();
+        }
+    };
+    let redirect_uri =
+        crate::features::cross_posting::controllers::connect_mastodon_init::mastodon_redirect_uri();
+    let session_data = match MastodonAdapter::new()
+        .exchange_code(&instance_url, &app, &code, &redirect_uri)
+        .await
+    {
+        Ok(s) => s,
+        Err(e) => {
+            tracing::error!(
+                error = %e,
+                %instance_url,
+                "mastodon callback: code exchange failed"
+            );
+            return Redirect::to(&connections_url("error")).into_response();
+        }
+    };
+
+    // Mastodon tokens don't expire. The handle is the full fediverse
+    // address (`alice@mastodon.social`) since the username alone is
+    // ambiguous across instances; the account id is only unique per
+    // instance, so the stable id is qualified the same way.
+    let host = instance_host(&instance_url).to_string();
+    let upsert = ConnectionUpsert {
+        user_pk,
+        platform: SocialPlatform::Mastodon,
+        decrypted: DecryptedCredentials::Mastodon {
+            instance_url: instance_url.clone(),
+            access_token: session_data.access_token,
+            account_id: session_data.account_id.clone(),
+        },
+        external_handle: format!("{}@{host}", session_data.username),
+        external_user_id: format!("{}@{host}", session_data.account_id),
+        token_expires_at: None,
+    };
+
+    if let Err(e) = seal_and_upsert_connection(cli, upsert).await {
+        tracing::error!(error = %e, "mastodon callback: seal+upsert failed");
+        return Redirect::to(&connections_url("error")).into_response();
+    }
+
+    Redirect::to(&success_redirect(
+        decoded.return_to.as_deref(),
+        "mastodon",
+        connections_url("ok"),
+    ))
+    .into_response()
 }
 
 pub fn router() -> Router {
-    Router::new().route(
-        "/api/cross-posting/connections/linkedin/callback",
-        get(linkedin_callback),
-    )
+    Router::new()
+        .route(
+            "/api/cross-posting/connections/linkedin/callback",
+            get(linkedin_callback),
+        )
+        .route(
+            "/api/cross-posting/connections/threads/callback",
+            get(threads_callback),
+        )
+        .route(
+            "/api/cross-posting/connections/mastodon/callback",
+            get(mastodon_callback),
+        )
 }
@@ -5,8 +5,12 @@
 //! - `com.atproto.repo.uploadBlob` — image upload before embedding
 //! - `com.atproto.repo.createRecord` (collection: `app.bsky.feed.post`) —
 //!   the actual publish, with optional `app.bsky.embed.external` (rich-link
-//!   card pointing at the Ratel backlink) or `app.bsky.embed.images`
-//! - `app.bsky.feed.getPostThread` — engagement counts (Stage 4)
+//!   card pointing at the Ratel backlink) or `app.bsky.embed.images`.
+//!   Thread-mode segments add a `reply` ref to the same record.
+//! - `com.atproto.repo.getRecord` — resolves the `cid` half of the root /
+//!   parent strong refs a reply needs
+//! - `app.bsky.feed.getPostThread` — engagement counts (Stage 4), and
+//!   with `depth > 0` the reply tree for reply import
 //! - `app.bsky.feed.getAuthorFeed` — recent-post scan for the
 //!   `find_by_backlink` lock-recovery probe (Stage 2 step (b))
 //!
@@ -19,8 +23,9 @@
 //! deferred).
 
 use super::{
-    CrossPostAdapter, DecryptedCredentials, EngagementCounts, ImageRef, LinkCard, PlatformError,
-    PublishedRef,
+    CrossPostAdapter, DecryptedCredentials, EngagementCounts, ExternalReply, FacetFeature,
+    FormattedBody, ImageRef, LinkCard, MAX_IMPORTED_REPLIES, PlatformError, PublishedRef,
+    ReplyTarget,
 };
 use crate::features::cross_posting::types::SocialPlatform;
 use async_trait::async_trait;
@@ -29,6 +34,10 @@ use serde::{Deserialize, Serialize};
 const PUBLIC_PDS: &str = "https://bsky.social";
 const POST_COLLECTION: &str = "app.bsky.feed.post";
 
+/// How deep `fetch_replies` walks the thread. Deeper sub-threads are cut
+/// off by the AppView anyway; six levels covers real conversations.
+const REPLY_DEPTH: u32 = 6;
+
 /// Bluesky AT Protocol adapter. Stateless aside from a shared
 /// `reqwest::Client` (Arc internally — cheap to clone).
 #[derive(Debug, Clone)]
@@ -75,7 +84,7 @@ impl CrossPostAdapter for BlueskyAdapter {
     async fn publish(
         &self,
         creds: DecryptedCredentials,
-        formatted_body: String,
+        formatted_body: FormattedBody,
         images: Vec<ImageRef>,
         link_card: LinkCard,
     ) -> Result<PublishedRef, PlatformError> {
@@ -92,17 +101,38 @@ impl CrossPostAdapter for BlueskyAdapter {
 
         let body = build_publish_body(&did, &formatted_body, &link_card.backlink_url, embed);
 
-        let resp = self
-            .post_authed(&format!("{}/xrpc/com.atproto.repo.createRecord", self.pds_host), &jwt, &body)
-            .await?
-            .json::<CreateRecordResponse>()
-            .await
-            .map_err(|e| PlatformError::Unknown(format!("createRecord parse: {e}")))?;
+        self.create_record(&jwt, &handle, &body).await
+    }
 
-        Ok(PublishedRef {
-            external_post_id: resp.uri.clone(),
-            external_post_url: post_url_from_uri(&handle, &resp.uri),
-        })
+    async fn publish_reply(
+        &self,
+        creds: DecryptedCredentials,
+        reply_to: ReplyTarget,
+        formatted_body: FormattedBody,
+        images: Vec<ImageRef>,
+    ) -> Result<PublishedRef, PlatformError> {
+        let (did, handle, jwt) = unwrap_bluesky_creds(creds)?;
+
+        // Reply refs are strong refs (uri + cid), but only the uri was
+        // kept from the earlier createRecord calls.
+        let root = self.get_strong_ref(&jwt, &reply_to.root_id).await?;
+        let parent = if reply_to.parent_id == reply_to.root_id {
+            root.clone()
+        } else {
+            self.get_strong_ref(&jwt, &reply_to.parent_id).await?
+        };
+
+        let embed = if images.is_empty() {
+            None
+        } else {
+            let blobs = self.upload_blobs(&jwt, images).await?;
+            Some(build_images_embed(blobs))
+        };
+
+        let mut body = build_publish_body(&did, &formatted_body, "", embed);
+        body["record"]["reply"] = build_reply_ref(&root, &parent);
+
+        self.create_record(&jwt, &handle, &body).await
     }
 
     async fn fetch_engagement(
@@ -129,6 +159,28 @@ impl CrossPostAdapter for BlueskyAdapter {
         Ok(parse_engagement(&resp))
     }
 
+    async fn fetch_replies(
+        &self,
+        creds: DecryptedCredentials,
+        external_post_id: &str,
+    ) -> Result<Vec<ExternalReply>, PlatformError> {
+        let (_did, _handle, jwt) = unwrap_bluesky_creds(creds)?;
+
+        let url = format!(
+            "{}/xrpc/app.bsky.feed.getPostThread?depth={REPLY_DEPTH}&parentHeight=0&uri={}",
+            self.pds_host,
+            urlencoding::encode(external_post_id)
+        );
+        let resp = self
+            .get_authed(&url, &jwt)
+            .await?
+            .json::<GetPostRepliesResponse>()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("getPostThread parse: {e}")))?;
+
+        Ok(parse_replies(&resp))
+    }
+
     async fn find_by_backlink(
         &self,
         creds: DecryptedCredentials,
@@ -307,6 +359,43 @@ impl BlueskyAdapter {
         check_status(resp).await
     }
 
+    async fn create_record(
+        &self,
+        jwt: &str,
+        handle: &str,
+        body: &serde_json::Value,
+    ) -> Result<PublishedRef, PlatformError> {
+        let url = format!("{}/xrpc/com.atproto.repo.createRecord", self.pds_host);
+        let resp = self
+            .post_authed(&url, jwt, body)
+            .await?
+            .json::<CreateRecordResponse>()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("createRecord parse: {e}")))?;
+
+        Ok(PublishedRef {
+            external_post_id: resp.uri.clone(),
+            external_post_url: post_url_from_uri(handle, &resp.uri),
+        })
+    }
+
+    /// `{uri, cid}` of one of our own posts, for a reply's `root` / `parent`.
+    async fn get_strong_ref(&self, jwt: &str, at_uri: &str) -> Result<StrongRef, PlatformError> {
+        let (repo, rkey) = split_at_uri(at_uri)
+            .ok_or_else(|| PlatformError::Unknown(format!("not a post uri: {at_uri}")))?;
+        let url = format!(
+            "{}/xrpc/com.atproto.repo.getRecord?repo={}&collection={POST_COLLECTION}&rkey={}",
+            self.pds_host,
+            urlencoding::encode(repo),
+            urlencoding::encode(rkey)
+        );
+        self.get_authed(&url, jwt)
+            .await?
+            .json::<StrongRef>()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("getRecord parse: {e}")))
+    }
+
     async fn upload_blobs(
         &self,
         jwt: &str,
@@ -359,15 +448,49 @@ impl BlueskyAdapter {
 // Pure helpers (testable without HTTP)
 // ─────────────────────────────────────────────────────────────────────────
 
-/// Build the JSON body for `com.atproto.repo.createRecord`.
+/// Build the JSON body for `com.atproto.repo.createRecord`. The body's
+/// own facets go out as-is; the backlink gets a link facet wherever it
+/// isn't already covered by one.
 fn build_publish_body(
     did: &str,
-    text: &str,
+    body: &FormattedBody,
     backlink_url: &str,
     embed: Option<serde_json::Value>,
 ) -> serde_json::Value {
     let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
-    let facets = link_facets(text, backlink_url);
+    let text = body.text.as_str();
+    let mut facets: Vec<(usize, serde_json::Value)> = body
+        .facets
+        .iter()
+        .map(|f| {
+            let feature = match &f.feature {
+                FacetFeature::Link(uri) => {
+                    serde_json::json!({ "$type": "app.bsky.richtext.facet#link", "uri": uri })
+                }
+                FacetFeature::Mention(did) => {
+                    serde_json::json!({ "$type": "app.bsky.richtext.facet#mention", "did": did })
+                }
+            };
+            let facet = serde_json::json!({
+                "index": { "byteStart": f.byte_start, "byteEnd": f.byte_end },
+                "features": [feature],
+            });
+            (f.byte_start, facet)
+        })
+        .collect();
+    for facet in link_facets(text, backlink_url) {
+        let start = facet["index"]["byteStart"].as_u64().unwrap_or_default() as usize;
+        let end = facet["index"]["byteEnd"].as_u64().unwrap_or_default() as usize;
+        let covered = body
+            .facets
+            .iter()
+            .any(|f| f.byte_start < end && start < f.byte_end);
+        if !covered {
+            facets.push((start, facet));
+        }
+    }
+    facets.sort_by_key(|(start, _)| *start);
+    let facets: Vec<serde_json::Value> = facets.into_iter().map(|(_, f)| f).collect();
 
     let mut record = serde_json::json!({
         "$type": POST_COLLECTION,
@@ -441,6 +564,23 @@ fn link_facets(text: &str, backlink_url: &str) -> Vec<serde_json::Value> {
     facets
 }
 
+/// `app.bsky.feed.post#replyRef` — every reply names both the thread
+/// root and its direct parent.
+fn build_reply_ref(root: &StrongRef, parent: &StrongRef) -> serde_json::Value {
+    serde_json::json!({
+        "root": { "uri": root.uri, "cid": root.cid },
+        "parent": { "uri": parent.uri, "cid": parent.cid },
+    })
+}
+
+/// `at://{repo}/app.bsky.feed.post/{rkey}` → `(repo, rkey)`.
+fn split_at_uri(at_uri: &str) -> Option<(&str, &str)> {
+    let rest = at_uri.strip_prefix("at://")?;
+    let (repo, rest) = rest.split_once('/')?;
+    let rkey = rest.strip_prefix(POST_COLLECTION)?.strip_prefix('/')?;
+    (!repo.is_empty() && !rkey.is_empty()).then_some((repo, rkey))
+}
+
 /// `at://did:plc:.../app.bsky.feed.post/{rkey}` → `https://bsky.app/profile/{handle}/post/{rkey}`.
 fn post_url_from_uri(handle: &str, at_uri: &str) -> String {
     let rkey = at_uri.rsplit('/').next().unwrap_or("");
@@ -455,6 +595,42 @@ fn parse_engagement(resp: &GetPostThreadResponse) -> EngagementCounts {
     }
 }
 
+/// Depth-first walk of the reply tree, so replies stay next to what they
+/// answer. Blocked / deleted nodes carry no `post` and are skipped along
+/// with their subtree.
+fn parse_replies(resp: &GetPostRepliesResponse) -> Vec<ExternalReply> {
+    fn walk(node: &ReplyThreadNode, out: &mut Vec<ExternalReply>) {
+        for reply in &node.replies {
+            if out.len() >= MAX_IMPORTED_REPLIES {
+                return;
+            }
+            let Some(post) = &reply.post else {
+                continue;
+            };
+            out.push(ExternalReply {
+                external_id: post.uri.clone(),
+                author_handle: post.author.handle.clone(),
+                author_display_name: post.author.display_name.clone().unwrap_or_default(),
+                author_avatar_url: post.author.avatar.clone(),
+                content: post.record.text.clone().unwrap_or_default(),
+                url: post_url_from_uri(&post.author.handle, &post.uri),
+                posted_at: post
+                    .record
+                    .created_at
+                    .as_deref()
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                    .map(|t| t.timestamp_millis())
+                    .unwrap_or(0),
+            });
+            walk(reply, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&resp.thread, &mut out);
+    out
+}
+
 fn scan_for_backlink(
     resp: &GetAuthorFeedResponse,
     backlink_url: &str,
@@ -543,7 +719,7 @@ fn classify_http_error(status: reqwest::StatusCode, body: &str) -> PlatformError
     }
 }
 
-fn guess_mime_from_url(url: &str) -> &'static str {
+pub(super) fn guess_mime_from_url(url: &str) -> &'static str {
     let lower = url.rsplit('?').last().unwrap_or(url).to_ascii_lowercase();
     if lower.ends_with(".png") {
         "image/png"
@@ -567,6 +743,14 @@ struct CreateRecordResponse {
     cid: String,
 }
 
+/// `com.atproto.repo.strongRef`; also the relevant part of a
+/// `getRecord` response.
+#[derive(Debug, Clone, Deserialize)]
+struct StrongRef {
+    uri: String,
+    cid: String,
+}
+
 #[derive(Debug, Deserialize)]
 struct UploadBlobResponse {
     blob: BlobRef,
@@ -603,6 +787,43 @@ struct ThreadViewPost {
     repost_count: Option<u32>,
 }
 
+/// `getPostThread` with replies. Kept apart from `GetPostThreadResponse`
+/// so the counts-only path doesn't depend on the reply shape.
+#[derive(Debug, Deserialize)]
+struct GetPostRepliesResponse {
+    thread: ReplyThreadNode,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplyThreadNode {
+    /// Absent on `#notFoundPost` / `#blockedPost` nodes.
+    post: Option<ReplyPostView>,
+    #[serde(default)]
+    replies: Vec<ReplyThreadNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplyPostView {
+    uri: String,
+    author: ReplyAuthor,
+    record: ReplyRecord,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplyAuthor {
+    handle: String,
+    #[serde(rename = "displayName")]
+    display_name: Option<String>,
+    avatar: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplyRecord {
+    text: Option<String>,
+    #[serde(rename = "createdAt")]
+    created_at: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct GetAuthorFeedResponse {
     feed: Vec<FeedEntry>,
@@ -669,7 +890,7 @@ mod tests {
     // ── build_publish_body ──────────────────────────────────────────────
     #[test]
     fn build_publish_body_sets_repo_collection_and_record_type() {
-        let body = build_publish_body("did:plc:test", "hello", "https://r/p", None);
+        let body = build_publish_body("did:plc:test", &"hello".into(), "https://r/p", None);
         assert_eq!(body["repo"], "did:plc:test");
         assert_eq!(body["collection"], POST_COLLECTION);
         assert_eq!(body["record"]["$type"], POST_COLLECTION);
@@ -681,7 +902,7 @@ mod tests {
     fn build_publish_body_emits_link_facet_for_backlink() {
         let text = "Check this out: https://r/p?utm_source=bluesky";
         let backlink = "https://r/p?utm_source=bluesky";
-        let body = build_publish_body("did:plc:test", text, backlink, None);
+        let body = build_publish_body("did:plc:test", &text.into(), backlink, None);
         let facets = body["record"]["facets"].as_array().unwrap();
         assert_eq!(facets.len(), 1);
         assert_eq!(
@@ -695,11 +916,59 @@ mod tests {
         assert_eq!(&text[start..end], backlink);
     }
 
+    #[test]
+    fn build_publish_body_carries_mention_and_link_facets_in_byte_order() {
+        let backlink = "https://r/p?utm_source=bluesky";
+        let mut text = FormattedBody::from("안녕 ");
+        text.push_with(
+            "@alice.bsky.social",
+            FacetFeature::Mention("did:plc:alice".into()),
+        );
+        text.push_str(" read ");
+        text.push_with(
+            "the docs",
+            FacetFeature::Link("https://docs.example".into()),
+        );
+        text.push_str(&format!("\n{backlink}"));
+        let body = build_publish_body("did:plc:test", &text, backlink, None);
+
+        let facets = body["record"]["facets"].as_array().unwrap();
+        assert_eq!(facets.len(), 3);
+        let span = |i: usize| {
+            let start = facets[i]["index"]["byteStart"].as_u64().unwrap() as usize;
+            let end = facets[i]["index"]["byteEnd"].as_u64().unwrap() as usize;
+            &text.text[start..end]
+        };
+        assert_eq!(span(0), "@alice.bsky.social");
+        assert_eq!(
+            facets[0]["features"][0]["$type"],
+            "app.bsky.richtext.facet#mention"
+        );
+        assert_eq!(facets[0]["features"][0]["did"], "did:plc:alice");
+        assert_eq!(span(1), "the docs");
+        assert_eq!(facets[1]["features"][0]["uri"], "https://docs.example");
+        assert_eq!(span(2), backlink);
+    }
+
+    #[test]
+    fn build_publish_body_skips_backlink_facet_already_covered() {
+        let backlink = "https://r/p";
+        let mut text = FormattedBody::default();
+        text.push_with(backlink, FacetFeature::Link(backlink.into()));
+        let body = build_publish_body("did:plc:test", &text, backlink, None);
+        assert_eq!(body["record"]["facets"].as_array().unwrap().len(), 1);
+    }
+
     #[test]
     fn build_publish_body_includes_embed_when_provided() {
         let card = sample_card();
         let embed = build_external_embed_value(&card);
-        let body = build_publish_body("did:plc:test", "txt", &card.backlink_url, Some(embed));
+        let body = build_publish_body(
+            "did:plc:test",
+            &"txt".into(),
+            &card.backlink_url,
+            Some(embed),
+        );
         assert_eq!(body["record"]["embed"]["$type"], "app.bsky.embed.external");
         assert_eq!(body["record"]["embed"]["external"]["uri"], card.backlink_url);
         assert_eq!(body["record"]["embed"]["external"]["title"], "My Post");
@@ -707,7 +976,12 @@ mod tests {
 
     #[test]
     fn build_publish_body_omits_facets_when_no_backlink_in_text() {
-        let body = build_publish_body("did:plc:test", "no urls here", "https://other/p", None);
+        let body = build_publish_body(
+            "did:plc:test",
+            &"no urls here".into(),
+            "https://other/p",
+            None,
+        );
         assert!(body["record"].get("facets").is_none());
     }
 
@@ -745,6 +1019,33 @@ mod tests {
         assert_eq!(url, "https://bsky.app/profile/user.bsky.social/post/3kxyz");
     }
 
+    // ── reply refs ──────────────────────────────────────────────────────
+    #[test]
+    fn build_reply_ref_names_root_and_parent() {
+        let root = StrongRef {
+            uri: "at://did:plc:abc/app.bsky.feed.post/1".into(),
+            cid: "cid-1".into(),
+        };
+        let parent = StrongRef {
+            uri: "at://did:plc:abc/app.bsky.feed.post/2".into(),
+            cid: "cid-2".into(),
+        };
+        let v = build_reply_ref(&root, &parent);
+        assert_eq!(v["root"]["uri"], "at://did:plc:abc/app.bsky.feed.post/1");
+        assert_eq!(v["root"]["cid"], "cid-1");
+        assert_eq!(v["parent"]["cid"], "cid-2");
+    }
+
+    #[test]
+    fn split_at_uri_extracts_repo_and_rkey() {
+        assert_eq!(
+            split_at_uri("at://did:plc:abc/app.bsky.feed.post/3kxyz"),
+            Some(("did:plc:abc", "3kxyz"))
+        );
+        assert_eq!(split_at_uri("at://did:plc:abc/app.bsky.feed.like/3kxyz"), None);
+        assert_eq!(split_at_uri("https://bsky.app/profile/a/post/1"), None);
+    }
+
     // ── build_external_embed_value ──────────────────────────────────────
     #[test]
     fn build_external_embed_value_includes_uri_title_description() {
@@ -787,6 +1088,53 @@ mod tests {
         assert_eq!(counts, EngagementCounts::default());
     }
 
+    // ── parse_replies ───────────────────────────────────────────────────
+    #[test]
+    fn parse_replies_flattens_tree_depth_first_and_skips_blocked() {
+        let resp: GetPostRepliesResponse = serde_json::from_value(serde_json::json!({
+            "thread": {
+                "post": {
+                    "uri": "at://did:plc:me/app.bsky.feed.post/root",
+                    "author": { "handle": "me.bsky.social" },
+                    "record": { "text": "root" }
+                },
+                "replies": [
+                    {
+                        "post": {
+                            "uri": "at://did:plc:a/app.bsky.feed.post/r1",
+                            "author": { "handle": "a.bsky.social", "displayName": "A" },
+                            "record": { "text": "first", "createdAt": "2026-05-01T12:00:00Z" }
+                        },
+                        "replies": [{
+                            "post": {
+                                "uri": "at://did:plc:b/app.bsky.feed.post/r2",
+                                "author": { "handle": "b.bsky.social" },
+                                "record": { "text": "nested" }
+                            }
+                        }]
+                    },
+                    { "$type": "app.bsky.feed.defs#blockedPost", "blocked": true },
+                    {
+                        "post": {
+                            "uri": "at://did:plc:c/app.bsky.feed.post/r3",
+                            "author": { "handle": "c.bsky.social" },
+                            "record": { "text": "second" }
+                        }
+                    }
+                ]
+            }
+        }))
+        .unwrap();
+
+        let replies = parse_replies(&resp);
+        let texts: Vec<&str> = replies.iter().map(|r| r.content.as_str()).collect();
+        assert_eq!(texts, ["first", "nested", "second"]);
+        assert_eq!(replies[0].author_display_name, "A");
+        assert_eq!(replies[0].url, "https://bsky.app/profile/a.bsky.social/post/r1");
+        assert_eq!(replies[0].posted_at, 1_777_636_800_000);
+        assert_eq!(replies[1].posted_at, 0);
+    }
+
     // ── scan_for_backlink ───────────────────────────────────────────────
     fn make_feed_entry(uri: &str, text: Option<&str>, embed_uri: Option<&str>) -> FeedEntry {
         FeedEntry {
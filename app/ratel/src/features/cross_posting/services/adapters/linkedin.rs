@@ -21,10 +21,13 @@
 //! in Phase 1B — the engagement UI isn't surfaced and the dispatcher's
 //! `had_existing_lock` fix prevents the false-positive reconcile probe.
 //! Real LinkedIn impls of those endpoints can return when they're needed.
+//! `fetch_replies` keeps the trait default (no replies): reading comments
+//! needs `r_member_social`, which LinkedIn only grants to approved
+//! partners.
 
 use super::{
-    CrossPostAdapter, DecryptedCredentials, EngagementCounts, ImageRef, LinkCard, PlatformError,
-    PublishedRef,
+    CrossPostAdapter, DecryptedCredentials, EngagementCounts, FacetFeature, FormattedBody,
+    ImageRef, LinkCard, PlatformError, PublishedRef,
 };
 use crate::features::cross_posting::types::SocialPlatform;
 use async_trait::async_trait;
@@ -82,7 +85,7 @@ impl CrossPostAdapter for LinkedInAdapter {
     async fn publish(
         &self,
         creds: DecryptedCredentials,
-        formatted_body: String,
+        formatted_body: FormattedBody,
         images: Vec<ImageRef>,
         link_card: LinkCard,
     ) -> Result<PublishedRef, PlatformError> {
@@ -340,6 +343,74 @@ impl LinkedInAdapter {
     }
 }
 
+/// Company page a member administers, for team-owned connections. `urn`
+/// (`urn:li:organization:{id}`) is stored as the connection's
+/// `member_urn`, so posts go out authored by the page.
+#[derive(Debug, Clone)]
+pub struct LinkedInOrganization {
+    pub urn: String,
+    pub name: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct OrganizationAclsResponse {
+    #[serde(default)]
+    elements: Vec<OrganizationAcl>,
+}
+
+#[derive(Deserialize)]
+struct OrganizationAcl {
+    organization: String,
+}
+
+#[derive(Deserialize)]
+struct OrganizationResponse {
+    #[serde(rename = "localizedName")]
+    localized_name: Option<String>,
+}
+
+impl LinkedInAdapter {
+    /// Every company page the member is an approved administrator of,
+    /// in LinkedIn's order; empty when they administer none. Needs the
+    /// `r_organization_admin` scope, which only the team connect flow
+    /// requests.
+    pub async fn administered_organizations(
+        &self,
+        access_token: &str,
+    ) -> Result<Vec<LinkedInOrganization>, PlatformError> {
+        let acls_url = format!(
+            "{}/v2/organizationAcls?q=roleAssignee&role=ADMINISTRATOR&state=APPROVED",
+            self.api_host
+        );
+        let acls: OrganizationAclsResponse = self
+            .get_authed(&acls_url, access_token)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("organizationAcls parse: {e}")))?;
+
+        let mut orgs = Vec::with_capacity(acls.elements.len());
+        for acl in acls.elements {
+            // The name is only a UI label; a failed lookup keeps the URN.
+            let id = acl.organization.rsplit(':').next().unwrap_or_default();
+            let org_url = format!("{}/v2/organizations/{id}", self.api_host);
+            let name = match self.get_authed(&org_url, access_token).await {
+                Ok(resp) => resp
+                    .json::<OrganizationResponse>()
+                    .await
+                    .ok()
+                    .and_then(|o| o.localized_name),
+                Err(_) => None,
+            };
+            orgs.push(LinkedInOrganization {
+                urn: acl.organization,
+                name,
+            });
+        }
+        Ok(orgs)
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // HTTP helpers
 // ─────────────────────────────────────────────────────────────────────────
@@ -455,15 +526,21 @@ impl LinkedInAdapter {
 /// pre-built `shareMediaCategory` + `media` pair (article vs image).
 fn build_ugc_post_body(
     author_urn: &str,
-    text: &str,
+    body: &FormattedBody,
     media_block: serde_json::Value,
 ) -> serde_json::Value {
+    let mut commentary = serde_json::json!({ "text": body.text });
+    let attributes = mention_attributes(body);
+    if !attributes.is_empty() {
+        commentary["attributes"] = serde_json::Value::Array(attributes);
+    }
+
     serde_json::json!({
         "author": author_urn,
         "lifecycleState": "PUBLISHED",
         "specificContent": {
             "com.linkedin.ugc.ShareContent": {
-                "shareCommentary": { "text": text },
+                "shareCommentary": commentary,
                 "shareMediaCategory": media_block["shareMediaCategory"],
                 "media": media_block["media"],
             }
@@ -477,6 +554,32 @@ fn build_ugc_post_body(
     })
 }
 
+/// `shareCommentary.attributes` for the body's organization mentions.
+/// LinkedIn indexes the commentary in UTF-16 code units, so the byte
+/// offsets are re-counted. Links need no attribute — LinkedIn links URLs
+/// in the text itself.
+fn mention_attributes(body: &FormattedBody) -> Vec<serde_json::Value> {
+    let utf16_len = |s: &str| s.encode_utf16().count();
+    body.facets
+        .iter()
+        .filter_map(|f| {
+            let FacetFeature::Mention(urn) = &f.feature else {
+                return None;
+            };
+            if !urn.starts_with("urn:li:organization:") {
+                return None;
+            }
+            Some(serde_json::json!({
+                "start": utf16_len(&body.text[..f.byte_start]),
+                "length": utf16_len(&body.text[f.byte_start..f.byte_end]),
+                "value": {
+                    "com.linkedin.common.CompanyAttributedEntity": { "company": urn },
+                },
+            }))
+        })
+        .collect()
+}
+
 /// Build the `media` block for an article share — wraps the Ratel backlink
 /// with the LinkCard's title/description as `originalUrl`/`title`/`description`.
 fn article_media_block(card: &LinkCard) -> serde_json::Value {
@@ -678,6 +781,14 @@ mod tests {
         assert_eq!(person_urn("urn:li:person:abc123"), "urn:li:person:abc123");
     }
 
+    #[test]
+    fn person_urn_passes_through_organization_urn() {
+        assert_eq!(
+            person_urn("urn:li:organization:42"),
+            "urn:li:organization:42"
+        );
+    }
+
     // ── post_url_from_urn ───────────────────────────────────────────────
     #[test]
     fn post_url_from_urn_uses_feed_update_path() {
@@ -692,7 +803,7 @@ mod tests {
     #[test]
     fn build_ugc_post_body_sets_author_lifecycle_and_visibility() {
         let media = article_media_block(&sample_card());
-        let body = build_ugc_post_body("urn:li:person:abc", "hello world", media);
+        let body = build_ugc_post_body("urn:li:person:abc", &"hello world".into(), media);
         assert_eq!(body["author"], "urn:li:person:abc");
         assert_eq!(body["lifecycleState"], "PUBLISHED");
         assert_eq!(
@@ -705,11 +816,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_ugc_post_body_attributes_org_mentions_in_utf16_units() {
+        use super::super::Facet;
+
+        let mut text = FormattedBody::from("🎉 Thanks ");
+        text.push_with(
+            "Ratel Inc",
+            FacetFeature::Mention("urn:li:organization:42".into()),
+        );
+        text.push_with(" site", FacetFeature::Link("https://r/p".into()));
+        text.facets.push(Facet {
+            byte_start: 0,
+            byte_end: 4,
+            feature: FacetFeature::Mention("did:plc:abc".into()),
+        });
+        let body = build_ugc_post_body("urn:li:organization:1", &text, serde_json::json!({}));
+
+        let attrs = body["specificContent"]["com.linkedin.ugc.ShareContent"]["shareCommentary"]
+            ["attributes"]
+            .as_array()
+            .unwrap();
+        assert_eq!(attrs.len(), 1);
+        // "🎉" is two UTF-16 units, so "Ratel Inc" starts at 2 + 8.
+        assert_eq!(attrs[0]["start"], 10);
+        assert_eq!(attrs[0]["length"], 9);
+        assert_eq!(
+            attrs[0]["value"]["com.linkedin.common.CompanyAttributedEntity"]["company"],
+            "urn:li:organization:42"
+        );
+    }
+
     #[test]
     fn build_ugc_post_body_with_article_media_carries_backlink() {
         let card = sample_card();
         let media = article_media_block(&card);
-        let body = build_ugc_post_body("urn:li:person:abc", "txt", media);
+        let body = build_ugc_post_body("urn:li:person:abc", &"txt".into(), media);
         let arr = body["specificContent"]["com.linkedin.ugc.ShareContent"]["media"]
             .as_array()
             .unwrap();
@@ -729,7 +871,7 @@ mod tests {
             "urn:li:digitalmediaAsset:img1".to_string(),
             "urn:li:digitalmediaAsset:img2".to_string(),
         ]);
-        let body = build_ugc_post_body("urn:li:person:abc", "txt", media);
+        let body = build_ugc_post_body("urn:li:person:abc", &"txt".into(), media);
         assert_eq!(
             body["specificContent"]["com.linkedin.ugc.ShareContent"]["shareMediaCategory"],
             "IMAGE"
@@ -0,0 +1,1301 @@
+//! Mastodon REST API adapter — the first fediverse target.
+//!
+//! There is no central API host: every call goes to the user's home
+//! instance, carried in `DecryptedCredentials::Mastodon.instance_url`.
+//! Endpoints used (Mastodon 4.x):
+//! - `POST /api/v2/media` — multipart image upload with `description`
+//!   (alt text). Large files come back `202` with `url: null` while the
+//!   instance is still processing them.
+//! - `GET /api/v1/media/{id}` — polled until `url` is set; a status that
+//!   references unprocessed media is rejected with 422.
+//! - `POST /api/v1/statuses` — publish. Sent with an `Idempotency-Key`
+//!   derived from the backlink so the dispatcher's inline retry after a
+//!   lost response returns the original status instead of double-posting.
+//!   Thread-mode segments set `in_reply_to_id` and key on the parent.
+//! - `GET /api/v1/statuses/{id}` — favourites / reblogs / replies counts.
+//! - `GET /api/v1/statuses/{id}/context` — the reply tree, for reply import.
+//! - `GET /api/v1/accounts/{id}/statuses` — reconcile probe.
+//!
+//! OAuth is per instance:
+//! - `POST /api/v1/apps` registers Ratel as a client on an instance the
+//!   first time anyone connects from it (cached in `MastodonApp`).
+//! - `POST /oauth/token` exchanges the code for a non-expiring token.
+//! - `GET /api/v1/accounts/verify_credentials` resolves the account id +
+//!   username for the connections page.
+
+use super::{
+    CrossPostAdapter, DecryptedCredentials, EngagementCounts, ExternalReply, FormattedBody,
+    ImageRef, LinkCard, MAX_IMPORTED_REPLIES, PlatformError, PublishedRef, ReplyTarget,
+};
+use crate::features::cross_posting::services::format::strip_html;
+use crate::features::cross_posting::types::SocialPlatform;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Scopes requested at app registration and authorization. Granular
+/// scopes keep the token from being able to read DMs or follow lists.
+pub const MASTODON_SCOPES: &str = "read:accounts read:statuses write:statuses write:media";
+
+/// Media processing polling budget. Images are usually ready on the
+/// first poll; anything slower is surfaced as a retryable network error.
+const MEDIA_POLL_ATTEMPTS: u32 = 10;
+const MEDIA_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Number of recent statuses scanned by `find_by_backlink`.
+const RECONCILE_SCAN_LIMIT: u32 = 25;
+
+/// Mastodon adapter. Stateless aside from a shared `reqwest::Client` —
+/// the instance URL comes from the credentials on every call.
+///
+/// Instances are whatever host a user typed in, so the client never
+/// follows redirects and resolves names through [`PublicOnlyResolver`]:
+/// every request, not just the connect step, is kept off internal
+/// addresses even if the instance's DNS changes later.
+#[derive(Debug, Clone)]
+pub struct MastodonAdapter {
+    client: reqwest::Client,
+}
+
+impl MastodonAdapter {
+    pub fn new() -> Self {
+        let client = reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicOnlyResolver))
+            .build()
+            .expect("mastodon http client");
+        Self { client }
+    }
+}
+
+/// DNS resolver that drops every address [`is_public_ip`] rejects; a
+/// host left with none fails to connect. URLs with an IP-literal host
+/// skip resolution altogether — [`parse_instance`] keeps those out of
+/// instance URLs, and only the adapter tests' loopback mock uses them.
+#[derive(Debug)]
+struct PublicOnlyResolver;
+
+impl reqwest::dns::Resolve for PublicOnlyResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|a| is_public_ip(a.ip()))
+                .collect();
+            if addrs.is_empty() {
+                return Err(format!("{host} resolves to no public address").into());
+            }
+            let addrs: reqwest::dns::Addrs = Box::new(addrs.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+impl Default for MastodonAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CrossPostAdapter for MastodonAdapter {
+    fn platform(&self) -> SocialPlatform {
+        SocialPlatform::Mastodon
+    }
+
+    fn char_limit(&self) -> usize {
+        SocialPlatform::Mastodon.char_limit()
+    }
+
+    fn max_images(&self) -> usize {
+        SocialPlatform::Mastodon.max_images()
+    }
+
+    async fn publish(
+        &self,
+        creds: DecryptedCredentials,
+        formatted_body: FormattedBody,
+        images: Vec<ImageRef>,
+        link_card: LinkCard,
+    ) -> Result<PublishedRef, PlatformError> {
+        // Mastodon builds its own preview card from the first link in the
+        // status, so the backlink already in `formatted_body` is enough.
+        // It links URLs and `@user@host` mentions in plain text itself,
+        // so the facets are dropped.
+        let (instance_url, access_token, _account_id) = unwrap_mastodon_creds(creds)?;
+
+        let media_ids = self
+            .upload_all_media(&instance_url, &access_token, &images)
+            .await?;
+        let body = build_status_body(&formatted_body.text, &media_ids);
+        let key = idempotency_key(&link_card.backlink_url);
+
+        self.post_status(&instance_url, &access_token, &key, &body).await
+    }
+
+    async fn publish_reply(
+        &self,
+        creds: DecryptedCredentials,
+        reply_to: ReplyTarget,
+        formatted_body: FormattedBody,
+        images: Vec<ImageRef>,
+    ) -> Result<PublishedRef, PlatformError> {
+        let (instance_url, access_token, _account_id) = unwrap_mastodon_creds(creds)?;
+
+        let media_ids = self
+            .upload_all_media(&instance_url, &access_token, &images)
+            .await?;
+        let mut body = build_status_body(&formatted_body.text, &media_ids);
+        body["in_reply_to_id"] = reply_to.parent_id.clone().into();
+        // One segment per parent, so the parent id makes the key unique
+        // within the thread and stable across the inline retry.
+        let key = idempotency_key(&format!("reply:{}", reply_to.parent_id));
+
+        self.post_status(&instance_url, &access_token, &key, &body).await
+    }
+
+    async fn fetch_engagement(
+        &self,
+        creds: DecryptedCredentials,
+        external_post_id: &str,
+    ) -> Result<EngagementCounts, PlatformError> {
+        let (instance_url, access_token, _account_id) = unwrap_mastodon_creds(creds)?;
+        let url = format!("{instance_url}/api/v1/statuses/{external_post_id}");
+        let status: StatusResponse = self
+            .get_authed(&url, &access_token)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("status parse: {e}")))?;
+
+        Ok(engagement_counts(&status))
+    }
+
+    async fn fetch_replies(
+        &self,
+        creds: DecryptedCredentials,
+        external_post_id: &str,
+    ) -> Result<Vec<ExternalReply>, PlatformError> {
+        let (instance_url, access_token, _account_id) = unwrap_mastodon_creds(creds)?;
+        let url = format!("{instance_url}/api/v1/statuses/{external_post_id}/context");
+        let context: ContextResponse = self
+            .get_authed(&url, &access_token)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("status context parse: {e}")))?;
+
+        Ok(external_replies(&context, instance_host(&instance_url)))
+    }
+
+    async fn find_by_backlink(
+        &self,
+        creds: DecryptedCredentials,
+        backlink_url: &str,
+    ) -> Result<Option<PublishedRef>, PlatformError> {
+        let (instance_url, access_token, account_id) = unwrap_mastodon_creds(creds)?;
+        let url = format!(
+            "{instance_url}/api/v1/accounts/{account_id}/statuses\
+             ?limit={RECONCILE_SCAN_LIMIT}&exclude_replies=true&exclude_reblogs=true"
+        );
+        let statuses: Vec<StatusResponse> = self
+            .get_authed(&url, &access_token)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("account statuses parse: {e}")))?;
+
+        Ok(scan_for_backlink(&statuses, backlink_url))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Connect-time OAuth (called from the init controller and the OAuth
+// callback, NOT the dispatcher trait).
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Client credentials returned by `POST /api/v1/apps`, persisted per
+/// instance in `MastodonApp`.
+#[derive(Clone)]
+pub struct MastodonAppRegistration {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+/// Newly-issued Mastodon token plus the account it belongs to, returned
+/// to the OAuth callback for AEAD-sealing.
+#[derive(Clone)]
+pub struct MastodonSession {
+    pub access_token: String,
+    pub account_id: String,
+    /// Local username (no `@`, no domain) from `verify_credentials`.
+    pub username: String,
+}
+
+#[derive(Deserialize)]
+struct AppResponse {
+    client_id: String,
+    client_secret: String,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct AccountResponse {
+    id: String,
+    username: String,
+}
+
+impl MastodonAdapter {
+    /// Register Ratel as an OAuth client on `instance_url`. Only called
+    /// when no `MastodonApp` row exists for the instance yet.
+    pub async fn register_app(
+        &self,
+        instance_url: &str,
+        redirect_uri: &str,
+        website: &str,
+    ) -> Result<MastodonAppRegistration, PlatformError> {
+        let form = [
+            ("client_name", "Ratel"),
+            ("redirect_uris", redirect_uri),
+            ("scopes", MASTODON_SCOPES),
+            ("website", website),
+        ];
+        let resp = self
+            .client
+            .post(format!("{instance_url}/api/v1/apps"))
+            .form(&form)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let app: AppResponse = check_status(resp)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("apps parse: {e}")))?;
+
+        Ok(MastodonAppRegistration {
+            client_id: app.client_id,
+            client_secret: app.client_secret,
+        })
+    }
+
+    /// Exchange an OAuth `code` for a token and resolve the account it
+    /// belongs to. Used by the connect-callback route.
+    pub async fn exchange_code(
+        &self,
+        instance_url: &str,
+        app: &MastodonAppRegistration,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<MastodonSession, PlatformError> {
+        let form = [
+            ("grant_type", "authorization_code"),
+            ("client_id", app.client_id.as_str()),
+            ("client_secret", app.client_secret.as_str()),
+            ("redirect_uri", redirect_uri),
+            ("code", code),
+            ("scope", MASTODON_SCOPES),
+        ];
+        let resp = self
+            .client
+            .post(format!("{instance_url}/oauth/token"))
+            .form(&form)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let token: TokenResponse = check_status(resp)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("oauth token parse: {e}")))?;
+
+        let account: AccountResponse = self
+            .get_authed(
+                &format!("{instance_url}/api/v1/accounts/verify_credentials"),
+                &token.access_token,
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("verify_credentials parse: {e}")))?;
+
+        Ok(MastodonSession {
+            access_token: token.access_token,
+            account_id: account.id,
+            username: account.username,
+        })
+    }
+}
+
+/// Consent page URL on the user's instance.
+pub fn authorize_url(
+    instance_url: &str,
+    client_id: &str,
+    redirect_uri: &str,
+    state: &str,
+) -> String {
+    format!(
+        "{instance_url}/oauth/authorize?response_type=code\
+         &client_id={cid}\
+         &redirect_uri={redir}\
+         &scope={scopes}\
+         &state={state}",
+        cid = urlencoding::encode(client_id),
+        redir = urlencoding::encode(redirect_uri),
+        scopes = urlencoding::encode(MASTODON_SCOPES),
+        state = urlencoding::encode(state),
+    )
+}
+
+/// Normalize what the user typed into the instance field into an
+/// `https://{host}` base URL, or `None` unless it names a public host.
+/// The value ends up in server-side requests, so on top of
+/// [`parse_instance`] the host must resolve, and only to public
+/// addresses — a domain pointing at `127.0.0.1`, the VPC or the cloud
+/// metadata endpoint is rejected before anything is sent to it.
+pub async fn normalize_instance(raw: &str) -> Option<String> {
+    let instance_url = parse_instance(raw)?;
+    resolves_publicly(instance_host(&instance_url))
+        .await
+        .then_some(instance_url)
+}
+
+/// Syntactic half of [`normalize_instance`]. Accepts a bare domain, a
+/// URL, or a full `@user@domain` handle; paths, ports, credentials and
+/// IP-literal-style hosts are rejected.
+pub fn parse_instance(raw: &str) -> Option<String> {
+    let raw = raw.trim();
+    let raw = raw
+        .strip_prefix("https://")
+        .or_else(|| raw.strip_prefix("http://"))
+        .unwrap_or(raw);
+    let host = match raw.rsplit_once('@') {
+        Some((_, host)) => host,
+        None => raw,
+    };
+    let host = host.trim_end_matches('/').to_ascii_lowercase();
+
+    let valid_chars = host
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    let has_tld = host
+        .rsplit('.')
+        .next()
+        .is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()));
+    let labels_ok = host
+        .split('.')
+        .all(|l| !l.is_empty() && !l.starts_with('-') && !l.ends_with('-'));
+    if host.is_empty() || host.len() > 253 || !valid_chars || !has_tld || !labels_ok {
+        return None;
+    }
+    Some(format!("https://{host}"))
+}
+
+/// `true` when `host` resolves and every address it resolves to is
+/// publicly routable. Lookup failures count as not public.
+pub async fn resolves_publicly(host: &str) -> bool {
+    match tokio::net::lookup_host((host, 443)).await {
+        Ok(addrs) => {
+            let addrs: Vec<_> = addrs.collect();
+            !addrs.is_empty() && addrs.iter().all(|a| is_public_ip(a.ip()))
+        }
+        Err(e) => {
+            tracing::info!(host, error = %e, "mastodon instance lookup failed");
+            false
+        }
+    }
+}
+
+/// Rejects loopback, private, link-local, carrier-grade NAT, multicast
+/// and otherwise reserved ranges; IPv4-mapped IPv6 is judged as IPv4.
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            !(v4.is_private()
+                || v4.is_loopback()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_multicast()
+                || v4.is_documentation()
+                || a == 0
+                || a >= 240
+                || (a == 100 && (64..128).contains(&b)))
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return is_public_ip(IpAddr::V4(v4));
+            }
+            let first = v6.segments()[0];
+            !(v6.is_loopback()
+                || v6.is_unspecified()
+                || v6.is_multicast()
+                || (first & 0xfe00) == 0xfc00
+                || (first & 0xffc0) == 0xfe80)
+        }
+    }
+}
+
+/// Host part of a normalized instance URL, used as the `MastodonApp`
+/// partition key and in the `user@host` handle.
+pub fn instance_host(instance_url: &str) -> &str {
+    instance_url
+        .strip_prefix("https://")
+        .or_else(|| instance_url.strip_prefix("http://"))
+        .unwrap_or(instance_url)
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// HTTP helpers
+// ─────────────────────────────────────────────────────────────────────────
+
+impl MastodonAdapter {
+    async fn get_authed(
+        &self,
+        url: &str,
+        access_token: &str,
+    ) -> Result<reqwest::Response, PlatformError> {
+        let resp = self
+            .client
+            .get(url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        check_status(resp).await
+    }
+
+    async fn post_status(
+        &self,
+        instance_url: &str,
+        access_token: &str,
+        key: &str,
+        body: &serde_json::Value,
+    ) -> Result<PublishedRef, PlatformError> {
+        let resp = self
+            .client
+            .post(format!("{instance_url}/api/v1/statuses"))
+            .bearer_auth(access_token)
+            .header("Idempotency-Key", key)
+            .json(body)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let status: StatusResponse = check_status(resp)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("statuses parse: {e}")))?;
+
+        Ok(published_ref(&status))
+    }
+
+    async fn upload_all_media(
+        &self,
+        instance_url: &str,
+        access_token: &str,
+        images: &[ImageRef],
+    ) -> Result<Vec<String>, PlatformError> {
+        let mut media_ids = Vec::with_capacity(images.len());
+        for image in images {
+            media_ids.push(self.upload_media(instance_url, access_token, image).await?);
+        }
+        Ok(media_ids)
+    }
+
+    /// Re-upload one image to the instance and wait until it has been
+    /// processed. Returns the media attachment id.
+    async fn upload_media(
+        &self,
+        instance_url: &str,
+        access_token: &str,
+        image: &ImageRef,
+    ) -> Result<String, PlatformError> {
+        let bytes = self
+            .client
+            .get(&image.url)
+            .send()
+            .await
+            .map_err(|e| PlatformError::NetworkError(format!("fetch image: {e}")))?
+            .error_for_status()
+            .map_err(|e| PlatformError::NetworkError(format!("fetch image status: {e}")))?
+            .bytes()
+            .await
+            .map_err(|e| PlatformError::NetworkError(format!("fetch image bytes: {e}")))?;
+
+        let part = reqwest::multipart::Part::bytes(bytes.to_vec())
+            .file_name(file_name_from_url(&image.url))
+            .mime_str(super::bluesky::guess_mime_from_url(&image.url))
+            .map_err(|e| PlatformError::Unknown(format!("media mime: {e}")))?;
+        let mut form = reqwest::multipart::Form::new().part("file", part);
+        if let Some(alt) = &image.alt {
+            form = form.text("description", alt.clone());
+        }
+
+        let resp = self
+            .client
+            .post(format!("{instance_url}/api/v2/media"))
+            .bearer_auth(access_token)
+            .multipart(form)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let media: MediaResponse = check_status(resp)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("media parse: {e}")))?;
+        if media.url.is_some() {
+            return Ok(media.id);
+        }
+
+        let url = format!("{instance_url}/api/v1/media/{}", media.id);
+        for _ in 0..MEDIA_POLL_ATTEMPTS {
+            tokio::time::sleep(MEDIA_POLL_INTERVAL).await;
+            let polled: MediaResponse = self
+                .get_authed(&url, access_token)
+                .await?
+                .json()
+                .await
+                .map_err(|e| PlatformError::Unknown(format!("media status parse: {e}")))?;
+            if polled.url.is_some() {
+                return Ok(media.id);
+            }
+        }
+        Err(PlatformError::NetworkError(format!(
+            "media {} still processing",
+            media.id
+        )))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Pure helpers (testable without HTTP)
+// ─────────────────────────────────────────────────────────────────────────
+
+fn build_status_body(text: &str, media_ids: &[String]) -> serde_json::Value {
+    serde_json::json!({
+        "status": text,
+        "media_ids": media_ids,
+        "visibility": "public",
+    })
+}
+
+/// Same backlink ⇒ same key, so a retried publish of the same job is
+/// deduplicated by the instance (Mastodon keeps keys for an hour).
+fn idempotency_key(backlink_url: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(backlink_url.as_bytes());
+    format!("ratel-{}", hex::encode(&digest[..16]))
+}
+
+fn published_ref(status: &StatusResponse) -> PublishedRef {
+    PublishedRef {
+        external_post_id: status.id.clone(),
+        // `url` is the HTML page; `uri` is the ActivityPub id and is
+        // always present, so it's the fallback.
+        external_post_url: status.url.clone().unwrap_or_else(|| status.uri.clone()),
+    }
+}
+
+fn engagement_counts(status: &StatusResponse) -> EngagementCounts {
+    let clamp = |v: i64| v.clamp(0, i32::MAX as i64) as i32;
+    EngagementCounts {
+        likes: clamp(status.favourites_count),
+        comments: clamp(status.replies_count),
+        reposts: clamp(status.reblogs_count),
+    }
+}
+
+/// `descendants` is the whole reply tree in thread order. Local accounts
+/// come back with a bare `acct`, so the instance host is appended to give
+/// every author a fediverse-wide `user@host` handle.
+fn external_replies(context: &ContextResponse, host: &str) -> Vec<ExternalReply> {
+    context
+        .descendants
+        .iter()
+        .take(MAX_IMPORTED_REPLIES)
+        .map(|status| ExternalReply {
+            external_id: status.id.clone(),
+            author_handle: if status.account.acct.contains('@') {
+                status.account.acct.clone()
+            } else {
+                format!("{}@{host}", status.account.acct)
+            },
+            author_display_name: status.account.display_name.clone(),
+            author_avatar_url: status.account.avatar.clone(),
+            content: strip_html(&status.content),
+            url: status.url.clone().unwrap_or_else(|| status.uri.clone()),
+            posted_at: chrono::DateTime::parse_from_rfc3339(&status.created_at)
+                .map(|t| t.timestamp_millis())
+                .unwrap_or(0),
+        })
+        .collect()
+}
+
+/// Status `content` is HTML, so the backlink appears both inside an
+/// `href` and as link text — with `&` escaped as `&amp;` in both places.
+fn scan_for_backlink(statuses: &[StatusResponse], backlink_url: &str) -> Option<PublishedRef> {
+    if backlink_url.is_empty() {
+        return None;
+    }
+    let escaped = backlink_url.replace('&', "&amp;");
+    statuses
+        .iter()
+        .find(|s| s.content.contains(backlink_url) || s.content.contains(&escaped))
+        .map(published_ref)
+}
+
+fn file_name_from_url(url: &str) -> String {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    path.rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or("image")
+        .to_string()
+}
+
+fn unwrap_mastodon_creds(
+    creds: DecryptedCredentials,
+) -> Result<(String, String, String), PlatformError> {
+    match creds {
+        DecryptedCredentials::Mastodon {
+            instance_url,
+            access_token,
+            account_id,
+        } => Ok((instance_url, access_token, account_id)),
+        _ => Err(PlatformError::Unknown(
+            "non-Mastodon credentials passed to MastodonAdapter".into(),
+        )),
+    }
+}
+
+fn map_transport_error(e: reqwest::Error) -> PlatformError {
+    if e.is_timeout() {
+        PlatformError::NetworkError(format!("timeout: {e}"))
+    } else if e.is_connect() {
+        PlatformError::NetworkError(format!("connect: {e}"))
+    } else {
+        PlatformError::NetworkError(format!("transport: {e}"))
+    }
+}
+
+async fn check_status(resp: reqwest::Response) -> Result<reqwest::Response, PlatformError> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+    let body = resp.text().await.unwrap_or_default();
+    Err(classify_http_error(status, &body))
+}
+
+/// Mastodon uses plain HTTP statuses: 401 for revoked / invalid tokens,
+/// 403 for suspended accounts, 422 for validation failures (too long,
+/// unprocessed media, …) and 413 for oversized uploads.
+fn classify_http_error(status: reqwest::StatusCode, body: &str) -> PlatformError {
+    let msg = format!("status={status} body={body}");
+    match status.as_u16() {
+        401 | 403 => PlatformError::AuthExpired(msg),
+        429 => PlatformError::RateLimited(msg),
+        400 | 413 | 422 => PlatformError::ContentRejected(msg),
+        500..=599 => PlatformError::NetworkError(msg),
+        _ => PlatformError::Unknown(msg),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Mastodon API response shapes
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct MediaResponse {
+    id: String,
+    /// `null` until the instance finished processing the upload.
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatusResponse {
+    id: String,
+    uri: String,
+    url: Option<String>,
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    replies_count: i64,
+    #[serde(default)]
+    reblogs_count: i64,
+    #[serde(default)]
+    favourites_count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextResponse {
+    #[serde(default)]
+    descendants: Vec<ReplyStatus>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplyStatus {
+    id: String,
+    uri: String,
+    url: Option<String>,
+    #[serde(default)]
+    content: String,
+    #[serde(default)]
+    created_at: String,
+    account: ReplyAccount,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReplyAccount {
+    acct: String,
+    #[serde(default)]
+    display_name: String,
+    avatar: Option<String>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(id: &str, content: &str) -> StatusResponse {
+        StatusResponse {
+            id: id.into(),
+            uri: format!("https://m.example/users/alice/statuses/{id}"),
+            url: Some(format!("https://m.example/@alice/{id}")),
+            content: content.into(),
+            replies_count: 0,
+            reblogs_count: 0,
+            favourites_count: 0,
+        }
+    }
+
+    // ── adapter metadata ────────────────────────────────────────────────
+    #[test]
+    fn metadata_matches_platform() {
+        let a = MastodonAdapter::new();
+        assert_eq!(a.platform(), SocialPlatform::Mastodon);
+        assert_eq!(a.char_limit(), 500);
+        assert_eq!(a.max_images(), 4);
+    }
+
+    // ── normalize_instance ──────────────────────────────────────────────
+    #[test]
+    fn parse_instance_accepts_domain_url_and_handle() {
+        let want = Some("https://mastodon.social".to_string());
+        assert_eq!(parse_instance("mastodon.social"), want);
+        assert_eq!(parse_instance(" https://Mastodon.Social/ "), want);
+        assert_eq!(parse_instance("@alice@mastodon.social"), want);
+    }
+
+    #[test]
+    fn parse_instance_rejects_non_hostnames() {
+        assert_eq!(parse_instance(""), None);
+        assert_eq!(parse_instance("localhost"), None);
+        assert_eq!(parse_instance("127.0.0.1"), None);
+        assert_eq!(parse_instance("evil.example/path"), None);
+        assert_eq!(parse_instance("evil.example:8080"), None);
+        assert_eq!(parse_instance("-bad.example"), None);
+    }
+
+    #[test]
+    fn is_public_ip_rejects_internal_ranges() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:10.0.0.1",
+        ] {
+            assert!(!is_public_ip(ip.parse().unwrap()), "{ip} must be rejected");
+        }
+        for ip in ["1.1.1.1", "2606:4700:4700::1111"] {
+            assert!(is_public_ip(ip.parse().unwrap()), "{ip} must be accepted");
+        }
+    }
+
+    #[tokio::test]
+    async fn resolves_publicly_rejects_loopback_names() {
+        assert!(!resolves_publicly("localhost").await);
+        assert!(!resolves_publicly("no-such-host.invalid").await);
+    }
+
+    #[test]
+    fn instance_host_strips_scheme() {
+        assert_eq!(instance_host("https://mastodon.social"), "mastodon.social");
+    }
+
+    #[test]
+    fn authorize_url_encodes_params() {
+        let url = authorize_url(
+            "https://m.example",
+            "cid",
+            "https://ratel.foundation/cb",
+            "a.b",
+        );
+        assert!(url.starts_with("https://m.example/oauth/authorize?response_type=code"));
+        assert!(url.contains("&redirect_uri=https%3A%2F%2Fratel.foundation%2Fcb"));
+        assert!(url.contains("&scope=read%3Aaccounts%20read%3Astatuses"));
+        assert!(url.contains("&state=a.b"));
+    }
+
+    // ── pure helpers ────────────────────────────────────────────────────
+    #[test]
+    fn idempotency_key_is_stable_per_backlink() {
+        let a = idempotency_key("https://r/p?utm_source=mastodon");
+        assert_eq!(a, idempotency_key("https://r/p?utm_source=mastodon"));
+        assert_ne!(a, idempotency_key("https://r/q?utm_source=mastodon"));
+    }
+
+    #[test]
+    fn published_ref_falls_back_to_uri() {
+        let mut s = status("1", "");
+        s.url = None;
+        assert_eq!(
+            published_ref(&s).external_post_url,
+            "https://m.example/users/alice/statuses/1"
+        );
+    }
+
+    #[test]
+    fn scan_for_backlink_matches_html_escaped_url() {
+        let backlink = "https://r/p?utm_source=mastodon&x=1";
+        let statuses = vec![
+            status("1", "<p>unrelated</p>"),
+            status(
+                "2",
+                "<p>Title<br /><a href=\"https://r/p?utm_source=mastodon&amp;x=1\">link</a></p>",
+            ),
+        ];
+        let found = scan_for_backlink(&statuses, backlink).unwrap();
+        assert_eq!(found.external_post_id, "2");
+        assert!(scan_for_backlink(&statuses, "").is_none());
+    }
+
+    #[test]
+    fn external_replies_qualify_local_handles_and_strip_html() {
+        let context: ContextResponse = serde_json::from_value(serde_json::json!({
+            "ancestors": [],
+            "descendants": [
+                {
+                    "id": "201",
+                    "uri": "https://m.example/users/bob/statuses/201",
+                    "url": "https://m.example/@bob/201",
+                    "content": "<p>Great <b>post</b> &amp; thanks</p>",
+                    "created_at": "2026-05-01T12:00:00.000Z",
+                    "account": { "acct": "bob", "display_name": "Bob", "avatar": "https://m/a.png" }
+                },
+                {
+                    "id": "202",
+                    "uri": "https://other.example/users/carol/statuses/9",
+                    "url": null,
+                    "content": "<p>+1</p>",
+                    "created_at": "not a date",
+                    "account": { "acct": "carol@other.example" }
+                }
+            ]
+        }))
+        .unwrap();
+
+        let replies = external_replies(&context, "m.example");
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies[0].author_handle, "bob@m.example");
+        assert_eq!(replies[0].content, "Great post & thanks");
+        assert_eq!(replies[0].posted_at, 1_777_636_800_000);
+        assert_eq!(replies[1].author_handle, "carol@other.example");
+        assert_eq!(replies[1].url, "https://other.example/users/carol/statuses/9");
+        assert_eq!(replies[1].posted_at, 0);
+    }
+
+    #[test]
+    fn file_name_strips_query() {
+        assert_eq!(
+            file_name_from_url("https://s3/x/photo.png?sig=1"),
+            "photo.png"
+        );
+        assert_eq!(file_name_from_url("https://s3/"), "image");
+    }
+
+    #[test]
+    fn classify_http_error_maps_statuses() {
+        use reqwest::StatusCode;
+        assert!(matches!(
+            classify_http_error(StatusCode::UNAUTHORIZED, ""),
+            PlatformError::AuthExpired(_)
+        ));
+        assert!(matches!(
+            classify_http_error(StatusCode::TOO_MANY_REQUESTS, ""),
+            PlatformError::RateLimited(_)
+        ));
+        assert!(matches!(
+            classify_http_error(StatusCode::UNPROCESSABLE_ENTITY, ""),
+            PlatformError::ContentRejected(_)
+        ));
+        assert!(matches!(
+            classify_http_error(StatusCode::BAD_GATEWAY, ""),
+            PlatformError::NetworkError(_)
+        ));
+    }
+
+    // ── against a local mock instance ───────────────────────────────────
+    mod mock {
+        use super::*;
+        use crate::common::axum::{
+            self, Json, Router,
+            body::Bytes,
+            extract::{Path, State},
+            http::{HeaderMap, StatusCode},
+            routing::{get, post},
+        };
+        use std::sync::{Arc, Mutex};
+
+        /// What the mock instance saw, for assertions.
+        #[derive(Default)]
+        struct Seen {
+            uploads: Vec<String>,
+            statuses: Vec<serde_json::Value>,
+            idempotency_keys: Vec<String>,
+            bearer: Vec<String>,
+        }
+
+        type Shared = Arc<Mutex<Seen>>;
+
+        fn bearer(headers: &HeaderMap) -> Option<String> {
+            headers
+                .get("authorization")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Bearer "))
+                .map(str::to_string)
+        }
+
+        async fn image() -> &'static [u8] {
+            b"\x89PNG fake image bytes"
+        }
+
+        async fn upload(
+            State(seen): State<Shared>,
+            headers: HeaderMap,
+            body: Bytes,
+        ) -> (StatusCode, Json<serde_json::Value>) {
+            let mut seen = seen.lock().unwrap();
+            seen.bearer.extend(bearer(&headers));
+            seen.uploads
+                .push(String::from_utf8_lossy(&body).into_owned());
+            let id = seen.uploads.len().to_string();
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({ "id": id, "url": format!("https://files/{id}.png") })),
+            )
+        }
+
+        async fn create_status(
+            State(seen): State<Shared>,
+            headers: HeaderMap,
+            Json(body): Json<serde_json::Value>,
+        ) -> (StatusCode, Json<serde_json::Value>) {
+            let mut seen = seen.lock().unwrap();
+            if bearer(&headers).as_deref() != Some("good-token") {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    Json(serde_json::json!({ "error": "The access token is invalid" })),
+                );
+            }
+            seen.idempotency_keys.extend(
+                headers
+                    .get("idempotency-key")
+                    .and_then(|v| v.to_str().ok())
+                    .map(str::to_string),
+            );
+            seen.statuses.push(body);
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "id": "109",
+                    "uri": "https://m.example/users/alice/statuses/109",
+                    "url": "https://m.example/@alice/109",
+                    "content": "",
+                })),
+            )
+        }
+
+        async fn get_status(Path(id): Path<String>) -> Json<serde_json::Value> {
+            Json(serde_json::json!({
+                "id": id,
+                "uri": format!("https://m.example/users/alice/statuses/{id}"),
+                "url": null,
+                "content": "<p>hi</p>",
+                "favourites_count": 7,
+                "reblogs_count": 3,
+                "replies_count": 2,
+            }))
+        }
+
+        async fn status_context(Path(id): Path<String>) -> Json<serde_json::Value> {
+            assert_eq!(id, "109");
+            Json(serde_json::json!({
+                "ancestors": [],
+                "descendants": [{
+                    "id": "110",
+                    "uri": "https://m.example/users/bob/statuses/110",
+                    "url": "https://m.example/@bob/110",
+                    "content": "<p>Nice</p>",
+                    "created_at": "2026-05-01T12:00:00Z",
+                    "account": { "acct": "bob", "display_name": "Bob", "avatar": null },
+                }],
+            }))
+        }
+
+        async fn account_statuses(Path(id): Path<String>) -> Json<serde_json::Value> {
+            assert_eq!(id, "42");
+            Json(serde_json::json!([{
+                "id": "108",
+                "uri": "https://m.example/users/alice/statuses/108",
+                "url": "https://m.example/@alice/108",
+                "content": "<p><a href=\"https://r/p?utm_source=mastodon\">https://r/p</a></p>",
+            }]))
+        }
+
+        async fn apps() -> Json<serde_json::Value> {
+            Json(serde_json::json!({ "client_id": "cid", "client_secret": "secret" }))
+        }
+
+        async fn token(body: String) -> Json<serde_json::Value> {
+            assert!(body.contains("code=the-code"));
+            assert!(body.contains("client_secret=secret"));
+            Json(serde_json::json!({ "access_token": "good-token", "token_type": "Bearer" }))
+        }
+
+        async fn verify_credentials(headers: HeaderMap) -> Json<serde_json::Value> {
+            assert_eq!(bearer(&headers).as_deref(), Some("good-token"));
+            Json(serde_json::json!({ "id": "42", "username": "alice", "acct": "alice" }))
+        }
+
+        /// Serve a minimal Mastodon API on an ephemeral port and return
+        /// its base URL.
+        async fn spawn_instance() -> (String, Shared) {
+            let seen = Shared::default();
+            let app = Router::new()
+                .route("/img/{name}", get(image))
+                .route("/api/v2/media", post(upload))
+                .route("/api/v1/statuses", post(create_status))
+                .route("/api/v1/statuses/{id}", get(get_status))
+                .route("/api/v1/statuses/{id}/context", get(status_context))
+                .route("/api/v1/accounts/{id}/statuses", get(account_statuses))
+                .route("/api/v1/apps", post(apps))
+                .route("/oauth/token", post(token))
+                .route(
+                    "/api/v1/accounts/verify_credentials",
+                    get(verify_credentials),
+                )
+                .with_state(seen.clone());
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move {
+                axum::serve(listener, app).await.unwrap();
+            });
+            (format!("http://{addr}"), seen)
+        }
+
+        fn creds(instance_url: &str, token: &str) -> DecryptedCredentials {
+            DecryptedCredentials::Mastodon {
+                instance_url: instance_url.into(),
+                access_token: token.into(),
+                account_id: "42".into(),
+            }
+        }
+
+        fn card() -> LinkCard {
+            LinkCard {
+                backlink_url: "https://r/p?utm_source=mastodon".into(),
+                fallback_title: "T".into(),
+                fallback_description: String::new(),
+                fallback_thumb_url: None,
+            }
+        }
+
+        #[tokio::test]
+        async fn publish_uploads_media_with_alt_text_then_posts_status() {
+            let (base, seen) = spawn_instance().await;
+            let images = vec![
+                ImageRef::from_s3(format!("{base}/img/a.png"))
+                    .with_alt(Some("A ratel on a rock".into())),
+                ImageRef::from_s3(format!("{base}/img/b.jpg")),
+            ];
+
+            let published = MastodonAdapter::new()
+                .publish(
+                    creds(&base, "good-token"),
+                    "Hello fediverse\nhttps://r/p?utm_source=mastodon".into(),
+                    images,
+                    card(),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(published.external_post_id, "109");
+            assert_eq!(published.external_post_url, "https://m.example/@alice/109");
+
+            let seen = seen.lock().unwrap();
+            assert_eq!(seen.uploads.len(), 2);
+            assert!(seen.uploads[0].contains("name=\"description\""));
+            assert!(seen.uploads[0].contains("A ratel on a rock"));
+            assert!(seen.uploads[0].contains("filename=\"a.png\""));
+            assert!(!seen.uploads[1].contains("name=\"description\""));
+            assert!(seen.bearer.iter().all(|t| t == "good-token"));
+
+            let body = &seen.statuses[0];
+            assert_eq!(
+                body["status"],
+                "Hello fediverse\nhttps://r/p?utm_source=mastodon"
+            );
+            assert_eq!(body["media_ids"], serde_json::json!(["1", "2"]));
+            assert_eq!(body["visibility"], "public");
+            assert_eq!(
+                seen.idempotency_keys,
+                vec![idempotency_key("https://r/p?utm_source=mastodon")]
+            );
+        }
+
+        #[tokio::test]
+        async fn publish_reply_sets_in_reply_to_and_keys_on_parent() {
+            let (base, seen) = spawn_instance().await;
+
+            MastodonAdapter::new()
+                .publish_reply(
+                    creds(&base, "good-token"),
+                    ReplyTarget {
+                        root_id: "108".into(),
+                        parent_id: "108".into(),
+                    },
+                    "Second part 2/2".into(),
+                    vec![],
+                )
+                .await
+                .unwrap();
+
+            let seen = seen.lock().unwrap();
+            let body = &seen.statuses[0];
+            assert_eq!(body["status"], "Second part 2/2");
+            assert_eq!(body["in_reply_to_id"], "108");
+            assert_eq!(seen.idempotency_keys, vec![idempotency_key("reply:108")]);
+        }
+
+        #[tokio::test]
+        async fn publish_with_revoked_token_is_auth_expired() {
+            let (base, _) = spawn_instance().await;
+            let err = MastodonAdapter::new()
+                .publish(creds(&base, "revoked"), "x".into(), vec![], card())
+                .await
+                .unwrap_err();
+            assert!(matches!(err, PlatformError::AuthExpired(_)));
+        }
+
+        #[tokio::test]
+        async fn fetch_engagement_maps_counts() {
+            let (base, _) = spawn_instance().await;
+            let counts = MastodonAdapter::new()
+                .fetch_engagement(creds(&base, "good-token"), "109")
+                .await
+                .unwrap();
+            assert_eq!(
+                counts,
+                EngagementCounts {
+                    likes: 7,
+                    comments: 2,
+                    reposts: 3,
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn fetch_replies_reads_status_context() {
+            let (base, _) = spawn_instance().await;
+            let replies = MastodonAdapter::new()
+                .fetch_replies(creds(&base, "good-token"), "109")
+                .await
+                .unwrap();
+            assert_eq!(replies.len(), 1);
+            assert_eq!(replies[0].external_id, "110");
+            assert_eq!(replies[0].content, "Nice");
+            assert_eq!(replies[0].url, "https://m.example/@bob/110");
+        }
+
+        #[tokio::test]
+        async fn find_by_backlink_scans_account_statuses() {
+            let (base, _) = spawn_instance().await;
+            let adapter = MastodonAdapter::new();
+            let found = adapter
+                .find_by_backlink(
+                    creds(&base, "good-token"),
+                    "https://r/p?utm_source=mastodon",
+                )
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(found.external_post_id, "108");
+
+            let missing = adapter
+                .find_by_backlink(creds(&base, "good-token"), "https://r/other")
+                .await
+                .unwrap();
+            assert!(missing.is_none());
+        }
+
+        #[tokio::test]
+        async fn register_app_and_exchange_code() {
+            let (base, _) = spawn_instance().await;
+            let adapter = MastodonAdapter::new();
+            let app = adapter
+                .register_app(
+                    &base,
+                    "https://ratel.foundation/cb",
+                    "https://ratel.foundation",
+                )
+                .await
+                .unwrap();
+            assert_eq!(app.client_id, "cid");
+
+            let session = adapter
+                .exchange_code(&base, &app, "the-code", "https://ratel.foundation/cb")
+                .await
+                .unwrap();
+            assert_eq!(session.access_token, "good-token");
+            assert_eq!(session.account_id, "42");
+            assert_eq!(session.username, "alice");
+        }
+
+        #[tokio::test]
+        async fn client_refuses_names_resolving_to_loopback() {
+            let (base, _) = spawn_instance().await;
+            let by_name = base.replace("127.0.0.1", "localhost");
+            let err = MastodonAdapter::new()
+                .register_app(
+                    &by_name,
+                    "https://ratel.foundation/cb",
+                    "https://ratel.foundation",
+                )
+                .await
+                .unwrap_err();
+            assert!(matches!(err, PlatformError::NetworkError(_)), "{err:?}");
+        }
+    }
+}
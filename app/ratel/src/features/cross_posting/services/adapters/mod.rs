@@ -1,15 +1,20 @@
 //! Per-platform adapter trait and supporting types.
 //!
 //! Stage 2 dispatcher selects an adapter via `match job.platform` and calls
-//! `adapter.publish(creds, body, images)`. Adding a new platform = new
+//! `adapter.publish(creds, body, images)` — followed by `publish_reply`
+//! per segment for thread-mode posts. Adding a new platform = new
 //! adapter struct that implements `CrossPostAdapter`; the dispatcher itself
 //! is unchanged.
 
 mod bluesky;
 mod linkedin;
+mod mastodon;
+mod threads;
 
 pub use bluesky::*;
 pub use linkedin::*;
+pub use mastodon::*;
+pub use threads::*;
 
 use crate::features::cross_posting::types::SocialPlatform;
 use async_trait::async_trait;
@@ -52,22 +57,145 @@ pub enum DecryptedCredentials {
     },
     Threads {
         access_token: String,
+        /// Threads has no separate refresh token — this is the same
+        /// long-lived token as `access_token`, kept so the refresh path
+        /// reads like the other platforms. Both rotate together.
         refresh_token: String,
+        /// Threads user id (the `{user_id}` segment of publish calls).
         ig_user_id: String,
     },
+    Mastodon {
+        /// `https://{host}` of the user's home instance. Every API call
+        /// goes here — there is no central Mastodon host.
+        instance_url: String,
+        /// Non-expiring user token minted by the instance's OAuth app
+        /// (see `MastodonApp`). Revoked only by the user.
+        access_token: String,
+        /// Local account id, used by the reconcile probe's
+        /// `/api/v1/accounts/{id}/statuses` call.
+        account_id: String,
+    },
 }
 
 /// Reference to an image attached to a post. Holds the canonical S3 URL —
 /// each adapter is responsible for fetching the bytes and re-uploading to
-/// the platform's blob store as needed.
+/// the platform's blob store as needed. `alt` comes from the matching
+/// `MediaBlock` of a structured-content post; adapters that support image
+/// descriptions (Mastodon) forward it, the rest ignore it.
 #[derive(Debug, Clone)]
 pub struct ImageRef {
     pub url: String,
+    pub alt: Option<String>,
 }
 
 impl ImageRef {
     pub fn from_s3(url: impl Into<String>) -> Self {
-        Self { url: url.into() }
+        Self {
+            url: url.into(),
+            alt: None,
+        }
+    }
+
+    pub fn with_alt(mut self, alt: Option<String>) -> Self {
+        self.alt = alt.filter(|a| !a.trim().is_empty());
+        self
+    }
+}
+
+/// A syndicated post body: the text as published plus the rich-text
+/// spans over it. Facet offsets are UTF-8 byte offsets into `text` (AT
+/// Protocol's unit); adapters for platforms that count differently
+/// convert, and adapters with no rich text just send `text`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormattedBody {
+    pub text: String,
+    pub facets: Vec<Facet>,
+}
+
+/// One rich-text span of a [`FormattedBody`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Facet {
+    pub byte_start: usize,
+    pub byte_end: usize,
+    pub feature: FacetFeature,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FacetFeature {
+    /// Link to the URL, over anchor text or the spelled-out URL.
+    Link(String),
+    /// Mention of a platform account by its stable id — a Bluesky DID or
+    /// a LinkedIn organization URN.
+    Mention(String),
+}
+
+impl FormattedBody {
+    pub fn push_str(&mut self, s: &str) {
+        self.text.push_str(s);
+    }
+
+    /// Append `s` with `feature` spanning it.
+    pub fn push_with(&mut self, s: &str, feature: FacetFeature) {
+        let byte_start = self.text.len();
+        self.text.push_str(s);
+        if !s.is_empty() {
+            self.facets.push(Facet {
+                byte_start,
+                byte_end: self.text.len(),
+                feature,
+            });
+        }
+    }
+
+    /// Append another body, shifting its facets past the current text.
+    pub fn append(&mut self, other: FormattedBody) {
+        let shift = self.text.len();
+        self.text.push_str(&other.text);
+        self.facets.extend(other.facets.into_iter().map(|f| Facet {
+            byte_start: f.byte_start + shift,
+            byte_end: f.byte_end + shift,
+            ..f
+        }));
+    }
+
+    /// The `range` bytes of the text with the facets that lie wholly
+    /// inside it. `range` must fall on char boundaries.
+    pub fn slice(&self, range: std::ops::Range<usize>) -> FormattedBody {
+        FormattedBody {
+            text: self.text[range.clone()].to_string(),
+            facets: self
+                .facets
+                .iter()
+                .filter(|f| f.byte_start >= range.start && f.byte_end <= range.end)
+                .map(|f| Facet {
+                    byte_start: f.byte_start - range.start,
+                    byte_end: f.byte_end - range.start,
+                    feature: f.feature.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Cut the text to its first `byte_len` bytes, dropping facets that
+    /// no longer fit. `byte_len` must fall on a char boundary.
+    pub fn truncate(&mut self, byte_len: usize) {
+        self.text.truncate(byte_len);
+        self.facets.retain(|f| f.byte_end <= byte_len);
+    }
+}
+
+impl From<String> for FormattedBody {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            facets: Vec::new(),
+        }
+    }
+}
+
+impl From<&str> for FormattedBody {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
     }
 }
 
@@ -92,6 +220,15 @@ pub struct PublishedRef {
     pub external_post_url: String,
 }
 
+/// Where a thread-mode segment attaches: the thread's first post and the
+/// segment right before this one. Both are `PublishedRef::external_post_id`
+/// values returned earlier in the same dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplyTarget {
+    pub root_id: String,
+    pub parent_id: String,
+}
+
 /// Engagement counts pulled from the platform during Stage 4 refresh.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct EngagementCounts {
@@ -100,6 +237,28 @@ pub struct EngagementCounts {
     pub reposts: i32,
 }
 
+/// One reply to a syndicated post, flattened to what the read-only
+/// external comment needs. `content` is plain text — adapters strip the
+/// platform's markup so nothing remote is ever rendered as HTML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalReply {
+    /// Platform-native id (`at://` URI, status id, media id). Stable, so
+    /// re-imports overwrite instead of duplicating.
+    pub external_id: String,
+    pub author_handle: String,
+    pub author_display_name: String,
+    pub author_avatar_url: Option<String>,
+    pub content: String,
+    /// Public URL of the reply on the platform.
+    pub url: String,
+    /// Epoch ms; 0 when the platform didn't say.
+    pub posted_at: i64,
+}
+
+/// Cap on replies imported per syndicated post and refresh. Keeps one
+/// viral post from turning a refresh tick into thousands of writes.
+pub const MAX_IMPORTED_REPLIES: usize = 100;
+
 /// Platform-side error returned from any adapter call. Maps directly into
 /// `SyndicationJob.last_error_category` for retry policy classification.
 #[derive(Debug, thiserror::Error)]
@@ -122,6 +281,17 @@ pub enum PlatformError {
     Unknown(String),
 }
 
+/// The adapter for `platform`. Boxed trait object so each platform can
+/// return its own concrete type; shared by Stage 2 and Stage 4.
+pub fn adapter_for(platform: SocialPlatform) -> Box<dyn CrossPostAdapter> {
+    match platform {
+        SocialPlatform::Bluesky => Box::new(BlueskyAdapter::new()),
+        SocialPlatform::LinkedIn => Box::new(LinkedInAdapter::new()),
+        SocialPlatform::Threads => Box::new(ThreadsAdapter::new()),
+        SocialPlatform::Mastodon => Box::new(MastodonAdapter::new()),
+    }
+}
+
 /// One adapter per external platform. All methods take credentials by value
 /// because the dispatcher decrypts once per attempt.
 #[async_trait]
@@ -132,18 +302,37 @@ pub trait CrossPostAdapter: Send + Sync {
 
     /// Publish a single post to the platform. The body is already formatted
     /// (truncated, backlink appended) by the time it arrives here — the
-    /// adapter just sends it. `link_card` carries the rich-link metadata
+    /// adapter just sends it, with whichever of its facets the platform
+    /// can express. `link_card` carries the rich-link metadata
     /// (backlink URL + fallback title/description/thumb) so platforms that
     /// render explicit external embeds (Bluesky) can build them without
     /// re-deriving from the body.
     async fn publish(
         &self,
         creds: DecryptedCredentials,
-        formatted_body: String,
+        formatted_body: FormattedBody,
         images: Vec<ImageRef>,
         link_card: LinkCard,
     ) -> Result<PublishedRef, PlatformError>;
 
+    /// Publish one follow-up segment of a thread-mode post as a reply to
+    /// `reply_to.parent_id`. Same pre-formatted body contract as
+    /// [`Self::publish`]; no link card — the backlink rides on the root.
+    /// The default is for platforms without self-replies (LinkedIn), which
+    /// never reach here because the thread-mode toggle is refused for them.
+    async fn publish_reply(
+        &self,
+        creds: DecryptedCredentials,
+        reply_to: ReplyTarget,
+        formatted_body: FormattedBody,
+        images: Vec<ImageRef>,
+    ) -> Result<PublishedRef, PlatformError> {
+        let _ = (creds, reply_to, formatted_body, images);
+        Err(PlatformError::ContentRejected(
+            "replies not supported on this platform".into(),
+        ))
+    }
+
     /// Fetch likes / comments / reposts for an existing platform post.
     /// Called by Stage 4 (1D) on its adaptive cadence.
     async fn fetch_engagement(
@@ -152,6 +341,20 @@ pub trait CrossPostAdapter: Send + Sync {
         external_post_id: &str,
     ) -> Result<EngagementCounts, PlatformError>;
 
+    /// Fetch replies to an existing platform post for reply import.
+    /// Called by Stage 4 alongside `fetch_engagement`, only for
+    /// connections that opted in. Returns at most
+    /// [`MAX_IMPORTED_REPLIES`]; the default is for platforms whose API
+    /// gives us no read access to replies (LinkedIn).
+    async fn fetch_replies(
+        &self,
+        creds: DecryptedCredentials,
+        external_post_id: &str,
+    ) -> Result<Vec<ExternalReply>, PlatformError> {
+        let _ = (creds, external_post_id);
+        Ok(Vec::new())
+    }
+
     /// Reconcile path used when Stage 2 steals a lock from a dead attempt.
     /// Searches the user's recent posts on the platform for a copy whose
     /// body contains `backlink_url` (the URL is unique per Ratel post
@@ -171,6 +374,7 @@ pub trait CrossPostAdapter: Send + Sync {
     ///     (`grant_type=refresh_token`). 365-day refresh TTL, no rotation.
     ///   - Threads (1C): Meta `/refresh_access_token` extending the
     ///     long-lived 60-day token in place.
+    ///   - Mastodon: tokens don't expire, so the default applies.
     ///
     /// The default impl is the *credentials-not-rotatable* path — adapters
     /// that don't (yet) know how to refresh propagate the original
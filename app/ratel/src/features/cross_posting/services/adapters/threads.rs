@@ -0,0 +1,1048 @@
+//! Threads Graph API adapter (Phase 1C).
+//!
+//! Endpoints used (all under `https://graph.threads.net`):
+//! - `POST /v1.0/{user_id}/threads` — create a media container (`TEXT`,
+//!   `IMAGE`, or a `CAROUSEL` whose children are image containers created
+//!   with `is_carousel_item=true`). Threads fetches `image_url` itself, so
+//!   unlike Bluesky / LinkedIn there is no byte upload step.
+//! - `GET /v1.0/{container_id}?fields=status,error_message` — image
+//!   containers are processed asynchronously; publishing before `FINISHED`
+//!   fails, so we poll briefly.
+//! - `POST /v1.0/{user_id}/threads_publish?creation_id=` — publish the
+//!   container. Returns the media id. Thread-mode segments are the same
+//!   call with `reply_to_id` set on the top-level container.
+//! - `GET /v1.0/{media_id}?fields=permalink` — public URL of the post.
+//! - `GET /v1.0/{media_id}/insights?metric=likes,replies,reposts,quotes` —
+//!   engagement counts for the Stage 4 refresh.
+//! - `GET /v1.0/{media_id}/conversation?fields=...` — every reply in the
+//!   thread, flattened, for reply import.
+//! - `GET /v1.0/me/threads?fields=id,text,permalink` — reconcile probe.
+//!
+//! OAuth:
+//! - `POST /oauth/access_token` exchanges the authorization code for a
+//!   short-lived (1h) token, immediately swapped for a long-lived (60-day)
+//!   one via `GET /access_token?grant_type=th_exchange_token`.
+//! - `GET /refresh_access_token?grant_type=th_refresh_token` extends the
+//!   long-lived token in place. Threads has no separate refresh token: the
+//!   long-lived token is stored in both `access_token` and `refresh_token`
+//!   of `DecryptedCredentials::Threads` and both are rotated together.
+
+use super::{
+    CrossPostAdapter, DecryptedCredentials, EngagementCounts, ExternalReply, FormattedBody,
+    ImageRef, LinkCard, MAX_IMPORTED_REPLIES, PlatformError, PublishedRef, ReplyTarget,
+};
+use crate::features::cross_posting::types::SocialPlatform;
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::time::Duration;
+
+const GRAPH_HOST: &str = "https://graph.threads.net";
+const GRAPH_VERSION: &str = "v1.0";
+
+/// Container status polling budget. Images usually finish in a few
+/// seconds; anything slower is surfaced as a retryable network error so
+/// the inline retry (or the user's "Retry now") picks it up later.
+const CONTAINER_POLL_ATTEMPTS: u32 = 10;
+const CONTAINER_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Number of recent posts scanned by `find_by_backlink`.
+const RECONCILE_SCAN_LIMIT: u32 = 25;
+
+/// Threads Graph API adapter. Stateless aside from a shared
+/// `reqwest::Client`.
+#[derive(Debug, Clone)]
+pub struct ThreadsAdapter {
+    pub graph_host: String,
+    client: reqwest::Client,
+}
+
+impl ThreadsAdapter {
+    pub fn new() -> Self {
+        Self::with_host(GRAPH_HOST)
+    }
+
+    pub fn with_host(graph_host: impl Into<String>) -> Self {
+        Self {
+            graph_host: graph_host.into(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn api_url(&self, path: &str) -> String {
+        format!("{}/{GRAPH_VERSION}/{path}", self.graph_host)
+    }
+}
+
+impl Default for ThreadsAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CrossPostAdapter for ThreadsAdapter {
+    fn platform(&self) -> SocialPlatform {
+        SocialPlatform::Threads
+    }
+
+    fn char_limit(&self) -> usize {
+        SocialPlatform::Threads.char_limit()
+    }
+
+    fn max_images(&self) -> usize {
+        SocialPlatform::Threads.max_images()
+    }
+
+    async fn publish(
+        &self,
+        creds: DecryptedCredentials,
+        formatted_body: FormattedBody,
+        images: Vec<ImageRef>,
+        _link_card: LinkCard,
+    ) -> Result<PublishedRef, PlatformError> {
+        // Threads renders its own link preview for the first URL in the
+        // text, so the backlink already in `formatted_body` is enough.
+        // It links URLs and `@username`s in plain text itself, so the
+        // facets are dropped.
+        self.publish_post(creds, &formatted_body.text, &images, None).await
+    }
+
+    async fn publish_reply(
+        &self,
+        creds: DecryptedCredentials,
+        reply_to: ReplyTarget,
+        formatted_body: FormattedBody,
+        images: Vec<ImageRef>,
+    ) -> Result<PublishedRef, PlatformError> {
+        self.publish_post(
+            creds,
+            &formatted_body.text,
+            &images,
+            Some(reply_to.parent_id.as_str()),
+        )
+        .await
+    }
+
+    async fn fetch_engagement(
+        &self,
+        creds: DecryptedCredentials,
+        external_post_id: &str,
+    ) -> Result<EngagementCounts, PlatformError> {
+        let (access_token, _user_id) = unwrap_threads_creds(creds)?;
+        let url = self.api_url(&format!(
+            "{external_post_id}/insights?metric=likes,replies,reposts,quotes"
+        ));
+        let resp = self
+            .get_authed(&url, &access_token)
+            .await?
+            .json::<InsightsResponse>()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("insights parse: {e}")))?;
+
+        Ok(parse_insights(&resp))
+    }
+
+    async fn fetch_replies(
+        &self,
+        creds: DecryptedCredentials,
+        external_post_id: &str,
+    ) -> Result<Vec<ExternalReply>, PlatformError> {
+        let (access_token, _user_id) = unwrap_threads_creds(creds)?;
+        let url = self.api_url(&format!(
+            "{external_post_id}/conversation\
+             ?fields=id,text,username,permalink,timestamp,hide_status\
+             &reverse=false&limit={MAX_IMPORTED_REPLIES}"
+        ));
+        let resp = self
+            .get_authed(&url, &access_token)
+            .await?
+            .json::<ConversationResponse>()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("conversation parse: {e}")))?;
+
+        Ok(parse_conversation(&resp))
+    }
+
+    async fn find_by_backlink(
+        &self,
+        creds: DecryptedCredentials,
+        backlink_url: &str,
+    ) -> Result<Option<PublishedRef>, PlatformError> {
+        let (access_token, _user_id) = unwrap_threads_creds(creds)?;
+        let url = self.api_url(&format!(
+            "me/threads?fields=id,text,permalink&limit={RECONCILE_SCAN_LIMIT}"
+        ));
+        let resp = self
+            .get_authed(&url, &access_token)
+            .await?
+            .json::<UserThreadsResponse>()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("me/threads parse: {e}")))?;
+
+        Ok(scan_for_backlink(&resp, backlink_url))
+    }
+
+    /// Threads implementation of FR-5 #35: extend the long-lived token via
+    /// `/refresh_access_token`. Meta only refreshes tokens that are at
+    /// least 24h old and not yet expired — an expired token comes back as
+    /// a 400/401 and maps to `AuthExpired`, which asks the user to
+    /// reconnect. Pure — the dispatcher re-seals and persists.
+    async fn try_refresh_credentials(
+        &self,
+        creds: DecryptedCredentials,
+    ) -> Result<DecryptedCredentials, PlatformError> {
+        let (refresh_token, ig_user_id) = match creds {
+            DecryptedCredentials::Threads {
+                refresh_token,
+                ig_user_id,
+                ..
+            } => (refresh_token, ig_user_id),
+            _ => {
+                return Err(PlatformError::Unknown(
+                    "Threads adapter received non-Threads credentials for refresh".into(),
+                ));
+            }
+        };
+
+        let url = format!(
+            "{}/refresh_access_token?grant_type=th_refresh_token&access_token={}",
+            self.graph_host,
+            urlencoding::encode(&refresh_token)
+        );
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let token: TokenResponse = check_status(resp)
+            .await
+            .map_err(refresh_error)?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("refresh_access_token parse: {e}")))?;
+
+        Ok(DecryptedCredentials::Threads {
+            access_token: token.access_token.clone(),
+            refresh_token: token.access_token,
+            ig_user_id,
+        })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Connect-time OAuth token-exchange (called from the OAuth callback, NOT
+// the dispatcher trait — same shape as LinkedIn's `exchange_code`).
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Newly-issued long-lived Threads token, returned to the OAuth callback
+/// for AEAD-sealing into `SocialConnection.credential_ciphertext`.
+#[derive(Clone)]
+pub struct ThreadsSession {
+    pub access_token: String,
+    /// Threads user id — the `{user_id}` path segment of every publish
+    /// call. Persisted as `DecryptedCredentials::Threads.ig_user_id`.
+    pub user_id: String,
+    /// `@handle` from `/me?fields=username`, shown on the connections
+    /// page. `None` when the profile lookup fails.
+    pub username: Option<String>,
+    /// Long-lived token lifetime in seconds (60 days at issue time).
+    pub expires_in: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct ShortLivedTokenResponse {
+    access_token: String,
+    /// Meta returns the id as a JSON number on this endpoint.
+    user_id: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<i64>,
+}
+
+#[derive(Deserialize)]
+struct ProfileResponse {
+    id: String,
+    username: Option<String>,
+}
+
+impl ThreadsAdapter {
+    /// Exchange an OAuth `code` for a long-lived token and resolve the
+    /// Threads user id + username. Used by the connect-callback route.
+    pub async fn exchange_code(
+        &self,
+        code: &str,
+        redirect_uri: &str,
+    ) -> Result<ThreadsSession, PlatformError> {
+        let (client_id, client_secret) = oauth_client_credentials()?;
+
+        let form = [
+            ("client_id", client_id.as_str()),
+            ("client_secret", client_secret.as_str()),
+            ("grant_type", "authorization_code"),
+            ("redirect_uri", redirect_uri),
+            ("code", code),
+        ];
+        let resp = self
+            .client
+            .post(format!("{}/oauth/access_token", self.graph_host))
+            .form(&form)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let short: ShortLivedTokenResponse = check_status(resp)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("oauth exchange parse: {e}")))?;
+
+        let url = format!(
+            "{}/access_token?grant_type=th_exchange_token&client_secret={}&access_token={}",
+            self.graph_host,
+            urlencoding::encode(&client_secret),
+            urlencoding::encode(&short.access_token)
+        );
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        let long: TokenResponse = check_status(resp)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("long-lived exchange parse: {e}")))?;
+
+        // The profile lookup only feeds the UI label; fall back to the id
+        // carried by the code exchange if it fails.
+        let profile = match self
+            .get_authed(&self.api_url("me?fields=id,username"), &long.access_token)
+            .await
+        {
+            Ok(resp) => resp.json::<ProfileResponse>().await.ok(),
+            Err(e) => {
+                tracing::warn!(error = %e, "threads: profile lookup failed after code exchange");
+                None
+            }
+        };
+        let (user_id, username) = match profile {
+            Some(p) => (p.id, p.username),
+            None => (json_id(&short.user_id), None),
+        };
+
+        Ok(ThreadsSession {
+            access_token: long.access_token,
+            user_id,
+            username,
+            expires_in: long.expires_in,
+        })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// HTTP helpers
+// ─────────────────────────────────────────────────────────────────────────
+
+impl ThreadsAdapter {
+    async fn post_form(
+        &self,
+        url: &str,
+        access_token: &str,
+        form: &[(&str, &str)],
+    ) -> Result<reqwest::Response, PlatformError> {
+        let resp = self
+            .client
+            .post(url)
+            .bearer_auth(access_token)
+            .form(form)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        check_status(resp).await
+    }
+
+    async fn get_authed(
+        &self,
+        url: &str,
+        access_token: &str,
+    ) -> Result<reqwest::Response, PlatformError> {
+        let resp = self
+            .client
+            .get(url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(map_transport_error)?;
+        check_status(resp).await
+    }
+
+    /// Create, await and publish the container(s) for one post; a reply
+    /// when `reply_to` names the parent media id.
+    async fn publish_post(
+        &self,
+        creds: DecryptedCredentials,
+        formatted_body: &str,
+        images: &[ImageRef],
+        reply_to: Option<&str>,
+    ) -> Result<PublishedRef, PlatformError> {
+        let (access_token, user_id) = unwrap_threads_creds(creds)?;
+        let threads_url = self.api_url(&format!("{user_id}/threads"));
+
+        let container_id = match images {
+            [] => {
+                self.create_container(
+                    &threads_url,
+                    &access_token,
+                    &with_reply_to(text_container(formatted_body), reply_to),
+                )
+                .await?
+            }
+            [image] => {
+                let params = image_container(&image.url, Some(formatted_body));
+                let id = self
+                    .create_container(
+                        &threads_url,
+                        &access_token,
+                        &with_reply_to(params, reply_to),
+                    )
+                    .await?;
+                self.wait_until_finished(&id, &access_token).await?;
+                id
+            }
+            images => {
+                let mut children = Vec::with_capacity(images.len());
+                for image in images {
+                    let id = self
+                        .create_container(
+                            &threads_url,
+                            &access_token,
+                            &image_container(&image.url, None),
+                        )
+                        .await?;
+                    children.push(id);
+                }
+                for id in &children {
+                    self.wait_until_finished(id, &access_token).await?;
+                }
+                let params = carousel_container(&children, formatted_body);
+                let id = self
+                    .create_container(
+                        &threads_url,
+                        &access_token,
+                        &with_reply_to(params, reply_to),
+                    )
+                    .await?;
+                self.wait_until_finished(&id, &access_token).await?;
+                id
+            }
+        };
+
+        let published: IdResponse = self
+            .post_form(
+                &self.api_url(&format!("{user_id}/threads_publish")),
+                &access_token,
+                &[("creation_id", container_id.as_str())],
+            )
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("threads_publish parse: {e}")))?;
+
+        // The permalink is only needed for the syndication panel link; a
+        // failed lookup must not turn a published post into a retry (which
+        // would double-post), so fall back to the profile-less media URL.
+        let permalink = self
+            .get_authed(
+                &self.api_url(&format!("{}?fields=permalink", published.id)),
+                &access_token,
+            )
+            .await
+            .ok();
+        let permalink = match permalink {
+            Some(resp) => resp
+                .json::<MediaResponse>()
+                .await
+                .ok()
+                .and_then(|m| m.permalink),
+            None => None,
+        };
+
+        Ok(PublishedRef {
+            external_post_url: permalink.unwrap_or_else(|| fallback_post_url(&published.id)),
+            external_post_id: published.id,
+        })
+    }
+
+    async fn create_container(
+        &self,
+        threads_url: &str,
+        access_token: &str,
+        params: &[(&'static str, String)],
+    ) -> Result<String, PlatformError> {
+        let form: Vec<(&str, &str)> = params.iter().map(|(k, v)| (*k, v.as_str())).collect();
+        let resp: IdResponse = self
+            .post_form(threads_url, access_token, &form)
+            .await?
+            .json()
+            .await
+            .map_err(|e| PlatformError::Unknown(format!("container parse: {e}")))?;
+        Ok(resp.id)
+    }
+
+    /// Poll a container until Threads finished fetching / transcoding its
+    /// media. Text containers are ready immediately and never polled.
+    async fn wait_until_finished(
+        &self,
+        container_id: &str,
+        access_token: &str,
+    ) -> Result<(), PlatformError> {
+        let url = self.api_url(&format!("{container_id}?fields=status,error_message"));
+        for attempt in 0..CONTAINER_POLL_ATTEMPTS {
+            if attempt > 0 {
+                tokio::time::sleep(CONTAINER_POLL_INTERVAL).await;
+            }
+            let status: ContainerStatusResponse = self
+                .get_authed(&url, access_token)
+                .await?
+                .json()
+                .await
+                .map_err(|e| PlatformError::Unknown(format!("container status parse: {e}")))?;
+            if container_ready(&status)? {
+                return Ok(());
+            }
+        }
+        Err(PlatformError::NetworkError(format!(
+            "container {container_id} still processing"
+        )))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Pure helpers (testable without HTTP)
+// ─────────────────────────────────────────────────────────────────────────
+
+fn text_container(text: &str) -> Vec<(&'static str, String)> {
+    vec![("media_type", "TEXT".into()), ("text", text.into())]
+}
+
+/// Image container. Carousel children carry no text — the caption lives
+/// on the parent `CAROUSEL` container.
+fn image_container(image_url: &str, text: Option<&str>) -> Vec<(&'static str, String)> {
+    let mut params = vec![
+        ("media_type", "IMAGE".to_string()),
+        ("image_url", image_url.to_string()),
+    ];
+    match text {
+        Some(text) => params.push(("text", text.to_string())),
+        None => params.push(("is_carousel_item", "true".to_string())),
+    }
+    params
+}
+
+/// Add `reply_to_id` to a top-level container (never a carousel item).
+fn with_reply_to(
+    mut params: Vec<(&'static str, String)>,
+    reply_to: Option<&str>,
+) -> Vec<(&'static str, String)> {
+    if let Some(id) = reply_to {
+        params.push(("reply_to_id", id.to_string()));
+    }
+    params
+}
+
+fn carousel_container(children: &[String], text: &str) -> Vec<(&'static str, String)> {
+    vec![
+        ("media_type", "CAROUSEL".into()),
+        ("children", children.join(",")),
+        ("text", text.into()),
+    ]
+}
+
+/// `Ok(true)` once the container can be published, `Ok(false)` while it
+/// is still processing, and a terminal error when Threads gave up on it.
+fn container_ready(status: &ContainerStatusResponse) -> Result<bool, PlatformError> {
+    match status.status.as_deref() {
+        Some("FINISHED") | Some("PUBLISHED") => Ok(true),
+        Some("IN_PROGRESS") | None => Ok(false),
+        Some("EXPIRED") => Err(PlatformError::Unknown("threads container expired".into())),
+        Some(other) => Err(PlatformError::ContentRejected(format!(
+            "threads container {other}: {}",
+            status.error_message.as_deref().unwrap_or_default()
+        ))),
+    }
+}
+
+fn parse_insights(resp: &InsightsResponse) -> EngagementCounts {
+    let metric = |name: &str| -> i32 {
+        resp.data
+            .iter()
+            .find(|m| m.name == name)
+            .map(|m| {
+                m.total_value
+                    .as_ref()
+                    .map(|t| t.value)
+                    .or_else(|| m.values.first().map(|v| v.value))
+                    .unwrap_or(0)
+            })
+            .unwrap_or(0)
+            .clamp(0, i32::MAX as i64) as i32
+    };
+    EngagementCounts {
+        likes: metric("likes"),
+        comments: metric("replies"),
+        // Quotes are reposts with commentary; count both as reach.
+        reposts: metric("reposts").saturating_add(metric("quotes")),
+    }
+}
+
+/// Replies the author hid (or Threads covered / restricted) on the
+/// platform stay hidden here too. The API gives no display name or
+/// avatar for repliers, only the username.
+fn parse_conversation(resp: &ConversationResponse) -> Vec<ExternalReply> {
+    resp.data
+        .iter()
+        .filter(|r| matches!(r.hide_status.as_deref(), None | Some("NOT_HUSHED" | "UNHUSHED")))
+        .take(MAX_IMPORTED_REPLIES)
+        .map(|r| ExternalReply {
+            external_id: r.id.clone(),
+            author_handle: r.username.clone().unwrap_or_default(),
+            author_display_name: String::new(),
+            author_avatar_url: None,
+            content: r.text.clone().unwrap_or_default(),
+            url: r
+                .permalink
+                .clone()
+                .unwrap_or_else(|| fallback_post_url(&r.id)),
+            // Graph API timestamps carry a `+0000` offset, which RFC 3339
+            // parsing rejects.
+            posted_at: r
+                .timestamp
+                .as_deref()
+                .and_then(|t| chrono::DateTime::parse_from_str(t, "%Y-%m-%dT%H:%M:%S%z").ok())
+                .map(|t| t.timestamp_millis())
+                .unwrap_or(0),
+        })
+        .collect()
+}
+
+fn scan_for_backlink(resp: &UserThreadsResponse, backlink_url: &str) -> Option<PublishedRef> {
+    if backlink_url.is_empty() {
+        return None;
+    }
+    resp.data
+        .iter()
+        .find(|t| {
+            t.text
+                .as_deref()
+                .is_some_and(|text| text.contains(backlink_url))
+        })
+        .map(|t| PublishedRef {
+            external_post_id: t.id.clone(),
+            external_post_url: t
+                .permalink
+                .clone()
+                .unwrap_or_else(|| fallback_post_url(&t.id)),
+        })
+}
+
+/// Threads permalinks need the author handle and a shortcode we only get
+/// from the API; this id-based URL is the best offline approximation.
+fn fallback_post_url(media_id: &str) -> String {
+    format!("https://www.threads.net/t/{media_id}")
+}
+
+fn json_id(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn unwrap_threads_creds(creds: DecryptedCredentials) -> Result<(String, String), PlatformError> {
+    match creds {
+        DecryptedCredentials::Threads {
+            access_token,
+            ig_user_id,
+            ..
+        } => Ok((access_token, ig_user_id)),
+        _ => Err(PlatformError::Unknown(
+            "non-Threads credentials passed to ThreadsAdapter".into(),
+        )),
+    }
+}
+
+/// Read the `THREADS_APP_ID` / `THREADS_APP_SECRET` envvars baked into
+/// the binary at compile time. Missing values map to AuthExpired — same
+/// reasoning as LinkedIn's `oauth_client_credentials`.
+fn oauth_client_credentials() -> Result<(String, String), PlatformError> {
+    let client_id = option_env!("THREADS_APP_ID")
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| PlatformError::AuthExpired("THREADS_APP_ID not configured".into()))?;
+    let client_secret = option_env!("THREADS_APP_SECRET")
+        .filter(|v| !v.is_empty())
+        .ok_or_else(|| PlatformError::AuthExpired("THREADS_APP_SECRET not configured".into()))?;
+    Ok((client_id.to_string(), client_secret.to_string()))
+}
+
+/// A rejected refresh means the long-lived token is expired or revoked;
+/// only reconnecting can fix that.
+fn refresh_error(e: PlatformError) -> PlatformError {
+    match e {
+        PlatformError::ContentRejected(msg) => PlatformError::AuthExpired(msg),
+        other => other,
+    }
+}
+
+fn map_transport_error(e: reqwest::Error) -> PlatformError {
+    if e.is_timeout() {
+        PlatformError::NetworkError(format!("timeout: {e}"))
+    } else if e.is_connect() {
+        PlatformError::NetworkError(format!("connect: {e}"))
+    } else {
+        PlatformError::NetworkError(format!("transport: {e}"))
+    }
+}
+
+async fn check_status(resp: reqwest::Response) -> Result<reqwest::Response, PlatformError> {
+    let status = resp.status();
+    if status.is_success() {
+        return Ok(resp);
+    }
+    let body = resp.text().await.unwrap_or_default();
+    Err(classify_http_error(status, &body))
+}
+
+/// Graph API errors carry an `error.code`; 190 (invalid/expired token) is
+/// sent with HTTP 400, so it has to be special-cased like Bluesky's
+/// `ExpiredToken`.
+fn classify_http_error(status: reqwest::StatusCode, body: &str) -> PlatformError {
+    let msg = format!("status={status} body={body}");
+    let code = serde_json::from_str::<GraphErrorEnvelope>(body)
+        .ok()
+        .and_then(|e| e.error.code);
+    match (status.as_u16(), code) {
+        (_, Some(190)) | (401 | 403, _) => PlatformError::AuthExpired(msg),
+        (_, Some(4 | 17 | 32 | 613)) | (429, _) => PlatformError::RateLimited(msg),
+        (400 | 422, _) => PlatformError::ContentRejected(msg),
+        (500..=599, _) => PlatformError::NetworkError(msg),
+        _ => PlatformError::Unknown(msg),
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Threads API response shapes
+// ─────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+struct IdResponse {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MediaResponse {
+    permalink: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContainerStatusResponse {
+    status: Option<String>,
+    error_message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsightsResponse {
+    #[serde(default)]
+    data: Vec<InsightMetric>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsightMetric {
+    name: String,
+    /// Media insights report lifetime counts under `values[0]`; newer
+    /// responses may use `total_value` instead.
+    #[serde(default)]
+    values: Vec<InsightValue>,
+    total_value: Option<InsightValue>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InsightValue {
+    #[serde(default)]
+    value: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserThreadsResponse {
+    #[serde(default)]
+    data: Vec<UserThread>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserThread {
+    id: String,
+    text: Option<String>,
+    permalink: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConversationResponse {
+    #[serde(default)]
+    data: Vec<ConversationReply>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConversationReply {
+    id: String,
+    text: Option<String>,
+    username: Option<String>,
+    permalink: Option<String>,
+    timestamp: Option<String>,
+    hide_status: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphErrorEnvelope {
+    error: GraphError,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphError {
+    code: Option<i64>,
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Tests
+// ─────────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param<'a>(params: &'a [(&'static str, String)], key: &str) -> Option<&'a str> {
+        params
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    // ── adapter metadata ────────────────────────────────────────────────
+    #[test]
+    fn threads_adapter_reports_platform_facts() {
+        let a = ThreadsAdapter::new();
+        assert_eq!(a.platform(), SocialPlatform::Threads);
+        assert_eq!(a.char_limit(), 500);
+        assert_eq!(a.max_images(), 10);
+    }
+
+    #[test]
+    fn threads_adapter_builds_versioned_urls() {
+        let a = ThreadsAdapter::with_host("http://localhost:9999");
+        assert_eq!(
+            a.api_url("123/threads"),
+            "http://localhost:9999/v1.0/123/threads"
+        );
+        assert_eq!(
+            ThreadsAdapter::new().graph_host,
+            "https://graph.threads.net"
+        );
+    }
+
+    // ── container params ────────────────────────────────────────────────
+    #[test]
+    fn text_container_sets_media_type_and_text() {
+        let p = text_container("hello");
+        assert_eq!(param(&p, "media_type"), Some("TEXT"));
+        assert_eq!(param(&p, "text"), Some("hello"));
+    }
+
+    #[test]
+    fn single_image_container_carries_caption() {
+        let p = image_container("https://s3/a.png", Some("caption"));
+        assert_eq!(param(&p, "media_type"), Some("IMAGE"));
+        assert_eq!(param(&p, "image_url"), Some("https://s3/a.png"));
+        assert_eq!(param(&p, "text"), Some("caption"));
+        assert_eq!(param(&p, "is_carousel_item"), None);
+    }
+
+    #[test]
+    fn carousel_item_container_has_no_text() {
+        let p = image_container("https://s3/a.png", None);
+        assert_eq!(param(&p, "is_carousel_item"), Some("true"));
+        assert_eq!(param(&p, "text"), None);
+    }
+
+    #[test]
+    fn carousel_container_joins_children() {
+        let p = carousel_container(&["1".into(), "2".into(), "3".into()], "body");
+        assert_eq!(param(&p, "media_type"), Some("CAROUSEL"));
+        assert_eq!(param(&p, "children"), Some("1,2,3"));
+        assert_eq!(param(&p, "text"), Some("body"));
+    }
+
+    #[test]
+    fn with_reply_to_only_adds_param_for_replies() {
+        let p = with_reply_to(text_container("body"), Some("178"));
+        assert_eq!(param(&p, "reply_to_id"), Some("178"));
+        let p = with_reply_to(text_container("body"), None);
+        assert_eq!(param(&p, "reply_to_id"), None);
+    }
+
+    // ── container_ready ─────────────────────────────────────────────────
+    fn status(s: Option<&str>) -> ContainerStatusResponse {
+        ContainerStatusResponse {
+            status: s.map(str::to_string),
+            error_message: Some("bad image".into()),
+        }
+    }
+
+    #[test]
+    fn container_ready_tracks_processing_states() {
+        assert!(container_ready(&status(Some("FINISHED"))).unwrap());
+        assert!(!container_ready(&status(Some("IN_PROGRESS"))).unwrap());
+        assert!(!container_ready(&status(None)).unwrap());
+        assert!(matches!(
+            container_ready(&status(Some("ERROR"))),
+            Err(PlatformError::ContentRejected(m)) if m.contains("bad image")
+        ));
+        assert!(matches!(
+            container_ready(&status(Some("EXPIRED"))),
+            Err(PlatformError::Unknown(_))
+        ));
+    }
+
+    // ── parse_insights ──────────────────────────────────────────────────
+    #[test]
+    fn parse_insights_reads_values_and_total_value() {
+        let resp: InsightsResponse = serde_json::from_value(serde_json::json!({
+            "data": [
+                { "name": "likes", "period": "lifetime", "values": [{ "value": 12 }] },
+                { "name": "replies", "values": [{ "value": 3 }] },
+                { "name": "reposts", "total_value": { "value": 2 } },
+                { "name": "quotes", "values": [{ "value": 1 }] }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(
+            parse_insights(&resp),
+            EngagementCounts {
+                likes: 12,
+                comments: 3,
+                reposts: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_insights_treats_missing_metrics_as_zero() {
+        let resp: InsightsResponse = serde_json::from_value(serde_json::json!({})).unwrap();
+        assert_eq!(parse_insights(&resp), EngagementCounts::default());
+    }
+
+    // ── scan_for_backlink ───────────────────────────────────────────────
+    #[test]
+    fn scan_for_backlink_matches_text_containing_url() {
+        let resp: UserThreadsResponse = serde_json::from_value(serde_json::json!({
+            "data": [
+                { "id": "1", "text": "unrelated" },
+                {
+                    "id": "2",
+                    "text": "new post https://r/p/abc?utm_source=threads",
+                    "permalink": "https://www.threads.net/@me/post/XYZ"
+                }
+            ]
+        }))
+        .unwrap();
+        let found = scan_for_backlink(&resp, "https://r/p/abc?utm_source=threads").unwrap();
+        assert_eq!(found.external_post_id, "2");
+        assert_eq!(
+            found.external_post_url,
+            "https://www.threads.net/@me/post/XYZ"
+        );
+    }
+
+    #[test]
+    fn scan_for_backlink_returns_none_without_match_or_url() {
+        let resp: UserThreadsResponse = serde_json::from_value(serde_json::json!({
+            "data": [{ "id": "1" }]
+        }))
+        .unwrap();
+        assert!(scan_for_backlink(&resp, "https://r/p/abc").is_none());
+        assert!(scan_for_backlink(&resp, "").is_none());
+    }
+
+    // ── parse_conversation ──────────────────────────────────────────────
+    #[test]
+    fn parse_conversation_skips_hidden_and_parses_graph_timestamps() {
+        let resp: ConversationResponse = serde_json::from_value(serde_json::json!({
+            "data": [
+                {
+                    "id": "11",
+                    "text": "love this",
+                    "username": "fan",
+                    "permalink": "https://www.threads.net/@fan/post/AA",
+                    "timestamp": "2026-05-01T12:00:00+0000",
+                    "hide_status": "NOT_HUSHED"
+                },
+                { "id": "12", "text": "spam", "username": "bot", "hide_status": "HIDDEN" },
+                { "id": "13", "text": "no permalink", "username": "quiet" }
+            ]
+        }))
+        .unwrap();
+
+        let replies = parse_conversation(&resp);
+        assert_eq!(replies.len(), 2);
+        assert_eq!(replies[0].author_handle, "fan");
+        assert_eq!(replies[0].posted_at, 1_777_636_800_000);
+        assert_eq!(replies[1].url, "https://www.threads.net/t/13");
+        assert_eq!(replies[1].posted_at, 0);
+    }
+
+    // ── json_id ─────────────────────────────────────────────────────────
+    #[test]
+    fn json_id_accepts_numbers_and_strings() {
+        assert_eq!(json_id(&serde_json::json!(1234567890)), "1234567890");
+        assert_eq!(json_id(&serde_json::json!("42")), "42");
+    }
+
+    // ── classify_http_error ─────────────────────────────────────────────
+    #[test]
+    fn classify_400_with_code_190_as_auth_expired() {
+        let body = r#"{"error":{"message":"Session has expired","code":190}}"#;
+        let err = classify_http_error(reqwest::StatusCode::BAD_REQUEST, body);
+        assert!(matches!(err, PlatformError::AuthExpired(_)));
+    }
+
+    #[test]
+    fn classify_rate_limit_codes_as_rate_limited() {
+        let body = r#"{"error":{"message":"limit","code":613}}"#;
+        let err = classify_http_error(reqwest::StatusCode::BAD_REQUEST, body);
+        assert!(matches!(err, PlatformError::RateLimited(_)));
+        let err = classify_http_error(reqwest::StatusCode::TOO_MANY_REQUESTS, "slow");
+        assert!(matches!(err, PlatformError::RateLimited(_)));
+    }
+
+    #[test]
+    fn classify_400_as_content_rejected() {
+        let body = r#"{"error":{"message":"bad","code":100}}"#;
+        let err = classify_http_error(reqwest::StatusCode::BAD_REQUEST, body);
+        assert!(matches!(err, PlatformError::ContentRejected(_)));
+    }
+
+    #[test]
+    fn classify_500_as_network_error() {
+        let err = classify_http_error(reqwest::StatusCode::INTERNAL_SERVER_ERROR, "down");
+        assert!(matches!(err, PlatformError::NetworkError(_)));
+    }
+
+    #[test]
+    fn refresh_rejection_maps_to_auth_expired() {
+        let err = refresh_error(PlatformError::ContentRejected("expired".into()));
+        assert!(matches!(err, PlatformError::AuthExpired(_)));
+        let err = refresh_error(PlatformError::NetworkError("down".into()));
+        assert!(matches!(err, PlatformError::NetworkError(_)));
+    }
+}
@@ -0,0 +1,318 @@
+//! Cross-posting analytics — which network actually brings readers back.
+//!
+//! Two writers feed the author-partition rollups the dashboard reads:
+//!
+//! - **Stage 4 refresh** ([`record_engagement`]): appends an
+//!   `EngagementSample` to the job's time series, mirrors the latest
+//!   counts onto `SyndicationPostStats`, and adds the change since the
+//!   previous snapshot to today's `SyndicationDailyStats`;
+//! - **backlink visits** ([`record_visit`]): the post page reports a
+//!   landing whose `?utm_source=` names a network the post was published
+//!   to, which bumps `visits` on both rollups — once per visitor per day,
+//!   gated by a [`SyndicationVisitMark`].
+//!
+//! Space-card jobs get samples but no rollups — their backlinks land on
+//! the space, not on a post. Rollup writes are best-effort; the snapshot
+//! the syndication panel reads is already correct when they run.
+
+use std::collections::HashMap;
+
+use crate::common::*;
+use crate::features::cross_posting::models::{
+    EngagementSample, EngagementSnapshot, JobSource, JobState, SyndicationDailyStats,
+    SyndicationJob, SyndicationPostStats, SyndicationVisitMark, stats_day,
+};
+use crate::features::cross_posting::services::adapters::EngagementCounts;
+use crate::features::cross_posting::types::{
+    AnalyticsCounts, PlatformAnalyticsView, PlatformCountsView, PostAnalyticsView, SocialPlatform,
+};
+
+/// Record one Stage 4 refresh of `job`. `previous` is the snapshot the
+/// refresh is about to overwrite.
+pub async fn record_engagement(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+    previous: Option<&EngagementSnapshot>,
+    counts: EngagementCounts,
+    now_ms: i64,
+) {
+    let sample = EngagementSample {
+        pk: job.pk.clone(),
+        sk: EngagementSample::sk_for(job, now_ms),
+        platform: job.platform,
+        likes: counts.likes,
+        comments: counts.comments,
+        reposts: counts.reposts,
+        fetched_at: now_ms,
+    };
+    if let Err(e) = sample.upsert(cli).await {
+        tracing::warn!(pk = ?job.pk, error = %e, "analytics: engagement sample write failed");
+    }
+    if job.source != JobSource::Post {
+        return;
+    }
+
+    let (pk, sk) = SyndicationPostStats::keys(&job.author_user_id, &job.pk, job.platform);
+    let post_stats = SyndicationPostStats::updater(&pk, &sk)
+        .with_post_pk(job.pk.clone())
+        .with_platform(job.platform)
+        .with_likes(counts.likes as i64)
+        .with_comments(counts.comments as i64)
+        .with_reposts(counts.reposts as i64)
+        .with_updated_at(now_ms)
+        .execute(cli)
+        .await;
+    if let Err(e) = post_stats {
+        tracing::warn!(pk = ?job.pk, error = %e, "analytics: post stats write failed");
+    }
+
+    let delta = engagement_delta(previous, counts);
+    if delta == AnalyticsCounts::default() {
+        return;
+    }
+    let (pk, sk) =
+        SyndicationDailyStats::keys(&job.author_user_id, stats_day(now_ms), job.platform);
+    let daily = SyndicationDailyStats::updater(&pk, &sk)
+        .with_day(stats_day(now_ms))
+        .with_platform(job.platform)
+        .increase_likes(delta.likes)
+        .increase_comments(delta.comments)
+        .increase_reposts(delta.reposts)
+        .with_updated_at(now_ms)
+        .execute(cli)
+        .await;
+    if let Err(e) = daily {
+        tracing::warn!(pk = ?job.pk, error = %e, "analytics: daily stats write failed");
+    }
+}
+
+/// Count a backlink landing on `post_pk` from `platform` by `visitor`.
+/// `Ok(false)` — nothing recorded — unless the post actually went out
+/// there and this visitor hasn't already been counted for it today.
+pub async fn record_visit(
+    cli: &aws_sdk_dynamodb::Client,
+    post_pk: &Partition,
+    platform: SocialPlatform,
+    visitor: &str,
+    now_ms: i64,
+) -> Result<bool> {
+    let sk = EntityType::SyndicationJob(platform.to_string());
+    let Some(job) = SyndicationJob::get(cli, post_pk, Some(sk)).await? else {
+        return Ok(false);
+    };
+    if job.state != JobState::Published {
+        return Ok(false);
+    }
+
+    // `create` is conditional: a mark already there means this visitor
+    // was counted today. Any other failure is a real error.
+    let mark = SyndicationVisitMark::new(post_pk, platform, visitor, now_ms);
+    match mark.create(cli).await {
+        Ok(()) => {}
+        Err(Error::Aws(crate::common::utils::aws::error::AwsError::DynamoDb(
+            aws_sdk_dynamodb::Error::ConditionalCheckFailedException(_),
+        ))) => return Ok(false),
+        Err(e) => return Err(e),
+    }
+
+    let (pk, sk) = SyndicationPostStats::keys(&job.author_user_id, post_pk, platform);
+    SyndicationPostStats::updater(&pk, &sk)
+        .with_post_pk(post_pk.clone())
+        .with_platform(platform)
+        .increase_visits(1)
+        .with_updated_at(now_ms)
+        .execute(cli)
+        .await?;
+
+    let day = stats_day(now_ms);
+    let (pk, sk) = SyndicationDailyStats::keys(&job.author_user_id, day, platform);
+    SyndicationDailyStats::updater(&pk, &sk)
+        .with_day(day)
+        .with_platform(platform)
+        .increase_visits(1)
+        .with_updated_at(now_ms)
+        .execute(cli)
+        .await?;
+    Ok(true)
+}
+
+/// Engagement gained since `previous` (all of it on the first refresh).
+/// Negative when people took likes or reposts back.
+pub fn engagement_delta(
+    previous: Option<&EngagementSnapshot>,
+    counts: EngagementCounts,
+) -> AnalyticsCounts {
+    let (likes, comments, reposts) =
+        previous.map_or((0, 0, 0), |p| (p.likes, p.comments, p.reposts));
+    AnalyticsCounts {
+        likes: (counts.likes - likes) as i64,
+        comments: (counts.comments - comments) as i64,
+        reposts: (counts.reposts - reposts) as i64,
+        visits: 0,
+    }
+}
+
+/// Fold the author's per-(post × platform) rows into per-network totals
+/// and per-post totals, both most visits first (then most engagement).
+/// Post titles are left empty for the caller to fill in.
+pub fn summarize(
+    rows: Vec<SyndicationPostStats>,
+) -> (Vec<PlatformAnalyticsView>, Vec<PostAnalyticsView>) {
+    let mut platforms: HashMap<SocialPlatform, PlatformAnalyticsView> = HashMap::new();
+    let mut posts: HashMap<String, PostAnalyticsView> = HashMap::new();
+
+    for row in rows {
+        let Partition::Feed(post_id) = &row.post_pk else {
+            continue;
+        };
+        let counts = AnalyticsCounts {
+            likes: row.likes,
+            comments: row.comments,
+            reposts: row.reposts,
+            visits: row.visits,
+        };
+
+        let platform = platforms
+            .entry(row.platform)
+            .or_insert_with(|| PlatformAnalyticsView {
+                platform: row.platform,
+                posts: 0,
+                counts: AnalyticsCounts::default(),
+            });
+        platform.posts += 1;
+        platform.counts.add(counts);
+
+        let post = posts
+            .entry(post_id.clone())
+            .or_insert_with(|| PostAnalyticsView {
+                post_id: FeedPartition(post_id.clone()),
+                post_title: String::new(),
+                counts: AnalyticsCounts::default(),
+                platforms: Vec::new(),
+            });
+        post.counts.add(counts);
+        post.platforms.push(PlatformCountsView {
+            platform: row.platform,
+            counts,
+        });
+    }
+
+    let mut platforms: Vec<PlatformAnalyticsView> = platforms.into_values().collect();
+    platforms.sort_by_key(|p| {
+        (
+            std::cmp::Reverse(p.counts.visits),
+            std::cmp::Reverse(p.counts.engagement()),
+            p.platform as u8,
+        )
+    });
+
+    let mut posts: Vec<PostAnalyticsView> = posts.into_values().collect();
+    for post in &mut posts {
+        post.platforms.sort_by_key(|p| p.platform as u8);
+    }
+    posts.sort_by(|a, b| {
+        b.counts
+            .visits
+            .cmp(&a.counts.visits)
+            .then(b.counts.engagement().cmp(&a.counts.engagement()))
+            .then(a.post_id.0.cmp(&b.post_id.0))
+    });
+
+    (platforms, posts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(
+        post_id: &str,
+        platform: SocialPlatform,
+        likes: i64,
+        visits: i64,
+    ) -> SyndicationPostStats {
+        SyndicationPostStats {
+            post_pk: Partition::Feed(post_id.to_string()),
+            platform,
+            likes,
+            visits,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn delta_is_everything_on_the_first_refresh_and_the_change_after() {
+        let counts = EngagementCounts {
+            likes: 5,
+            comments: 2,
+            reposts: 1,
+        };
+        assert_eq!(
+            engagement_delta(None, counts),
+            AnalyticsCounts {
+                likes: 5,
+                comments: 2,
+                reposts: 1,
+                visits: 0,
+            }
+        );
+
+        let previous = EngagementSnapshot {
+            likes: 6,
+            comments: 1,
+            reposts: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            engagement_delta(Some(&previous), counts),
+            AnalyticsCounts {
+                likes: -1,
+                comments: 1,
+                reposts: 0,
+                visits: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn summarize_ranks_networks_and_posts_by_visits() {
+        let (platforms, posts) = summarize(vec![
+            row("a", SocialPlatform::Bluesky, 10, 2),
+            row("a", SocialPlatform::LinkedIn, 1, 7),
+            row("b", SocialPlatform::Bluesky, 3, 1),
+            row("c", SocialPlatform::Threads, 0, 0),
+        ]);
+
+        let order: Vec<SocialPlatform> = platforms.iter().map(|p| p.platform).collect();
+        assert_eq!(
+            order,
+            vec![
+                SocialPlatform::LinkedIn,
+                SocialPlatform::Bluesky,
+                SocialPlatform::Threads
+            ]
+        );
+        assert_eq!(platforms[1].posts, 2);
+        assert_eq!(platforms[1].counts.likes, 13);
+        assert_eq!(platforms[1].counts.visits, 3);
+
+        let order: Vec<&str> = posts.iter().map(|p| p.post_id.0.as_str()).collect();
+        assert_eq!(order, vec!["a", "b", "c"]);
+        assert_eq!(posts[0].counts.visits, 9);
+        assert_eq!(posts[0].counts.likes, 11);
+        let split: Vec<SocialPlatform> = posts[0].platforms.iter().map(|p| p.platform).collect();
+        assert_eq!(
+            split,
+            vec![SocialPlatform::Bluesky, SocialPlatform::LinkedIn]
+        );
+    }
+
+    #[test]
+    fn summarize_skips_rows_without_a_post() {
+        let mut space = row("x", SocialPlatform::Bluesky, 4, 0);
+        space.post_pk = Partition::Space("x".to_string());
+        let (platforms, posts) = summarize(vec![space]);
+        assert!(platforms.is_empty());
+        assert!(posts.is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+//! Team approval queue for cross-posts through team connections.
+//!
+//! A job sent through a team `SocialConnection` with `approval_required`
+//! is written `AwaitingApproval` by the factory and parked in the team's
+//! `find_awaiting_approval` GSI. [`review`] settles it:
+//!
+//! - **approve** → `Pending` (the Stage 2 Pipe picks it up), or back to
+//!   `Scheduled` when the author picked a slot that is still ahead;
+//! - **reject** → `Rejected`, terminal.
+//!
+//! The flip is conditional on `state = awaiting_approval`, so two admins
+//! deciding at once can't both win — the loser gets `Ok(false)`.
+
+use crate::common::*;
+use crate::features::cross_posting::models::{
+    AuditAction, JobState, SyndicationAuditEntry, SyndicationJob,
+};
+use crate::features::cross_posting::services::dispatcher::{job_state_str, table_name};
+use crate::features::cross_posting::services::{shard, team_access};
+use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
+use crate::features::posts::models::Post;
+use aws_sdk_dynamodb::types::AttributeValue as AV;
+use std::collections::HashMap;
+
+/// Load the job an approve / reject request targets, checking that the
+/// post is a team post, `reviewer` administers that team, and the job is
+/// still waiting for a decision.
+pub async fn job_for_review(
+    cli: &aws_sdk_dynamodb::Client,
+    post_pk: &Partition,
+    platform: SocialPlatform,
+    reviewer: &Partition,
+) -> Result<SyndicationJob> {
+    let post = Post::get(cli, post_pk, Some(EntityType::Post))
+        .await
+        .map_err(|e| {
+            crate::error!("approval post lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::NotAuthorized)?;
+    if !matches!(post.user_pk, Partition::Team(_)) {
+        return Err(CrossPostingError::NotAuthorized.into());
+    }
+    if !team_access::can_manage(cli, &post.user_pk, reviewer).await? {
+        return Err(CrossPostingError::TeamPermissionDenied.into());
+    }
+
+    let sk = EntityType::SyndicationJob(platform.to_string());
+    let job = SyndicationJob::get(cli, post_pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("approval job lookup failed: {e}");
+            CrossPostingError::UpdateFailed
+        })?
+        .ok_or(CrossPostingError::SyndicationJobNotFound)?;
+    if job.state != JobState::AwaitingApproval {
+        return Err(CrossPostingError::ReviewNotAllowed.into());
+    }
+    Ok(job)
+}
+
+/// Settle an `AwaitingApproval` job. `Ok(false)` when it was no longer
+/// waiting — another admin got there first.
+pub async fn review(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+    approve: bool,
+    reviewer: &Partition,
+    now_ms: i64,
+) -> Result<bool> {
+    let to = match job.scheduled_at {
+        _ if !approve => JobState::Rejected,
+        Some(at) if at > now_ms => JobState::Scheduled,
+        _ => JobState::Pending,
+    };
+
+    // A job approved ahead of its slot goes back on the scheduler's GSI
+    // and the author's queue the way the factory parks it; the GSI keys
+    // are written by hand (raw update), so derive them from the entity's
+    // key getters.
+    let mut update = cli
+        .update_item()
+        .table_name(table_name())
+        .key("pk", AV::S(job.pk.to_string()))
+        .key("sk", AV::S(job.sk.to_string()))
+        .condition_expression("#state = :awaiting")
+        .expression_attribute_names("#state", "state")
+        .expression_attribute_values(":to", AV::S(job_state_str(to).into()))
+        .expression_attribute_values(
+            ":awaiting",
+            AV::S(job_state_str(JobState::AwaitingApproval).into()),
+        )
+        .expression_attribute_values(":by", AV::S(reviewer.to_string()))
+        .expression_attribute_values(":now", AV::N(now_ms.to_string()));
+    update = if to == JobState::Scheduled {
+        let at = job.scheduled_at.unwrap_or(now_ms);
+        let dispatch_shard = shard::shard_for(&post_id(&job.pk));
+        let scheduled = SyndicationJob {
+            dispatch_shard: Some(dispatch_shard.clone()),
+            next_attempt_at: at / 1000,
+            queue_owner: Some(job.author_user_id.clone()),
+            ..job.clone()
+        };
+        update
+            .update_expression(
+                "SET #state = :to, reviewed_by = :by, reviewed_at = :now, updated_at = :now, \
+                    dispatch_shard = :shard, next_attempt_at = :next_at, queue_owner = :owner, \
+                    gsi1_pk = :gsi1_pk, gsi1_sk = :gsi1_sk, gsi3_pk = :gsi3_pk, gsi3_sk = :gsi3_sk \
+                 REMOVE approval_team, gsi4_pk, gsi4_sk",
+            )
+            .expression_attribute_values(":shard", AV::S(dispatch_shard))
+            .expression_attribute_values(":next_at", AV::N((at / 1000).to_string()))
+            .expression_attribute_values(":owner", AV::S(job.author_user_id.to_string()))
+            .expression_attribute_values(":gsi1_pk", AV::S(scheduled.get_pk_for_gsi1()))
+            .expression_attribute_values(":gsi1_sk", AV::S(scheduled.get_sk_for_gsi1()))
+            .expression_attribute_values(":gsi3_pk", AV::S(scheduled.get_pk_for_gsi3()))
+            .expression_attribute_values(":gsi3_sk", AV::S(scheduled.get_sk_for_gsi3()))
+    } else {
+        update.update_expression(
+            "SET #state = :to, reviewed_by = :by, reviewed_at = :now, updated_at = :now \
+             REMOVE approval_team, gsi4_pk, gsi4_sk",
+        )
+    };
+
+    if let Err(e) = update.send().await {
+        let svc = e.into_service_error();
+        if matches!(
+            svc,
+            aws_sdk_dynamodb::operation::update_item::UpdateItemError::ConditionalCheckFailedException(_)
+        ) {
+            return Ok(false);
+        }
+        tracing::error!(error = %svc, "approval: review update failed");
+        return Err(CrossPostingError::UpdateFailed.into());
+    }
+
+    let action = if approve {
+        AuditAction::Approved
+    } else {
+        AuditAction::Rejected
+    };
+    SyndicationAuditEntry::record(cli, job, action, reviewer.clone(), now_ms).await;
+
+    Ok(true)
+}
+
+/// Display names for the approval queue and audit history, keyed by
+/// `pk.to_string()`. Users that no longer exist are simply absent.
+pub async fn display_names(
+    cli: &aws_sdk_dynamodb::Client,
+    pks: impl IntoIterator<Item = Partition>,
+) -> HashMap<String, String> {
+    let mut keys: Vec<(Partition, EntityType)> =
+        pks.into_iter().map(|pk| (pk, EntityType::User)).collect();
+    keys.sort_by_key(|(pk, _)| pk.to_string());
+    keys.dedup();
+    if keys.is_empty() {
+        return HashMap::new();
+    }
+    crate::features::auth::User::batch_get(cli, keys)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|u| (u.pk.to_string(), u.display_name))
+        .collect()
+}
+
+fn post_id(pk: &Partition) -> String {
+    match pk {
+        Partition::Feed(id) => id.clone(),
+        _ => pk.to_string(),
+    }
+}
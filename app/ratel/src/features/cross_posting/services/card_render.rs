@@ -0,0 +1,400 @@
+//! Server-side renderer for the space results card.
+//!
+//! [`results_card_svg`] lays the card out as an SVG document and
+//! [`render_png`] rasterises it with `resvg` at 1200×630 — the link-preview
+//! size every target platform shows uncropped. Layout is a pure function of
+//! [`ResultsCard`] so it is unit-tested without a font stack. Text is shaped
+//! from faces shipped in `assets/fonts/` (Fira Sans for Latin, a Hangul
+//! subset of NanumBarunGothic for Korean, both OFL-1.1), so the card looks
+//! the same on every host regardless of its installed fonts.
+
+use crate::common::*;
+use crate::features::cross_posting::types::CrossPostingError;
+use std::sync::{Arc, LazyLock};
+
+/// Bundled faces as `(asset, file name under assets/fonts/)`.
+const CARD_FONTS: [(Asset, &str); 2] = [
+    (
+        asset!("/assets/fonts/FiraSans-Regular.ttf"),
+        "FiraSans-Regular.ttf",
+    ),
+    (
+        asset!("/assets/fonts/NanumBarunGothic.ttf"),
+        "NanumBarunGothic.ttf",
+    ),
+];
+
+/// Font database shared by every render. Built once: reading and parsing
+/// font files is the expensive part of a render. Characters missing from
+/// the sans-serif face fall back to the other bundled face; if neither can
+/// be read, the host's fonts are used instead.
+static FONTDB: LazyLock<Arc<resvg::usvg::fontdb::Database>> = LazyLock::new(|| {
+    let mut db = resvg::usvg::fontdb::Database::new();
+    for (asset, file) in CARD_FONTS {
+        match read_font(asset, file) {
+            Some(data) => db.load_font_data(data),
+            None => crate::error!("card font {file} not found; check the public/ bundle"),
+        }
+    }
+    if db.is_empty() {
+        db.load_system_fonts();
+    }
+    db.set_sans_serif_family("Fira Sans");
+    Arc::new(db)
+});
+
+/// A bundled font, read at runtime rather than compiled into the server.
+/// Deployed builds find it in the `public/` directory dx writes next to
+/// the executable; `cargo` runs without a bundle read the crate's own
+/// `assets/fonts/`.
+fn read_font(asset: Asset, file: &str) -> Option<Vec<u8>> {
+    let bundled = std::env::current_exe().ok().and_then(|exe| {
+        let public = exe.parent()?.join("public");
+        std::fs::read(public.join(asset.to_string().trim_start_matches('/'))).ok()
+    });
+    bundled.or_else(|| {
+        std::fs::read(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("assets/fonts")
+                .join(file),
+        )
+        .ok()
+    })
+}
+
+pub const CARD_WIDTH: u32 = 1200;
+pub const CARD_HEIGHT: u32 = 630;
+
+/// Questions shown on one card, most-answered first.
+pub const MAX_CARD_QUESTIONS: usize = 2;
+/// Options shown per question, most-voted first.
+pub const MAX_CARD_OPTIONS: usize = 3;
+
+const PAD: u32 = 56;
+const BAR_X: u32 = 520;
+const BAR_WIDTH: u32 = 520;
+const ROW_HEIGHT: u32 = 38;
+const SECTION_GAP: u32 = 16;
+
+const BACKGROUND: &str = "#141414";
+const PRIMARY: &str = "#FCB300";
+const SECONDARY: &str = "#6EEDD8";
+const TEXT: &str = "#FFFFFF";
+const MUTED: &str = "#A1A1AA";
+const TRACK: &str = "#2A2A2A";
+
+/// What the results card shows. Built from the space's `SpacePollResult`s
+/// by `space_cards`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResultsCard {
+    pub title: String,
+    pub participants: i64,
+    pub questions: Vec<QuestionResult>,
+    /// Host shown in the footer, e.g. `ratel.foundation`.
+    pub site: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QuestionResult {
+    pub title: String,
+    /// Respondents to this question — the denominator for percentages.
+    pub total: i64,
+    /// Already sorted most-voted first and capped at [`MAX_CARD_OPTIONS`].
+    pub options: Vec<OptionResult>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OptionResult {
+    pub label: String,
+    pub count: i64,
+}
+
+impl QuestionResult {
+    /// Share of respondents who picked `option`, rounded to a whole
+    /// percent. Multi-select questions can sum past 100.
+    pub fn percent(&self, option: &OptionResult) -> i64 {
+        if self.total <= 0 {
+            return 0;
+        }
+        (option.count * 100 + self.total / 2) / self.total
+    }
+}
+
+impl ResultsCard {
+    /// Image description for platforms that carry alt text, e.g.
+    /// `Poll results for Budget vote, 1,204 participants. Which park?
+    /// North 52%, South 31%.`
+    pub fn alt_text(&self) -> String {
+        let mut alt = format!(
+            "Poll results for {}, {} participants.",
+            self.title,
+            group_thousands(self.participants)
+        );
+        for q in &self.questions {
+            let options: Vec<String> = q
+                .options
+                .iter()
+                .map(|o| format!("{} {}%", o.label, q.percent(o)))
+                .collect();
+            alt.push_str(&format!(" {} {}.", q.title, options.join(", ")));
+        }
+        alt
+    }
+}
+
+/// Lay the card out as a standalone SVG document.
+pub fn results_card_svg(card: &ResultsCard) -> String {
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{CARD_WIDTH}" height="{CARD_HEIGHT}" "#
+    );
+    svg.push_str(&format!(
+        r#"viewBox="0 0 {CARD_WIDTH} {CARD_HEIGHT}" font-family="sans-serif">"#
+    ));
+    push_rect(&mut svg, 0, 0, CARD_WIDTH, CARD_HEIGHT, BACKGROUND);
+    push_rect(&mut svg, 0, 0, CARD_WIDTH, 8, PRIMARY);
+    push_text(&mut svg, PAD, 84, 22, PRIMARY, "start", "POLL RESULTS");
+    push_text(&mut svg, PAD, 140, 42, TEXT, "start", &fit(&card.title, 52));
+    let participants = format!("{} participants", group_thousands(card.participants));
+    push_text(&mut svg, PAD, 186, 26, MUTED, "start", &participants);
+
+    let mut y = 240;
+    for q in card.questions.iter().take(MAX_CARD_QUESTIONS) {
+        push_text(&mut svg, PAD, y + 24, 26, TEXT, "start", &fit(&q.title, 80));
+        y += 40;
+        for option in q.options.iter().take(MAX_CARD_OPTIONS) {
+            let percent = q.percent(option);
+            let fill = BAR_WIDTH * percent.clamp(0, 100) as u32 / 100;
+            push_text(
+                &mut svg,
+                PAD,
+                y + 24,
+                22,
+                MUTED,
+                "start",
+                &fit(&option.label, 36),
+            );
+            push_bar(&mut svg, y + 8, BAR_WIDTH, TRACK);
+            if fill > 0 {
+                push_bar(&mut svg, y + 8, fill, SECONDARY);
+            }
+            let label = format!("{percent}%");
+            push_text(&mut svg, CARD_WIDTH - PAD, y + 26, 24, TEXT, "end", &label);
+            y += ROW_HEIGHT;
+        }
+        y += SECTION_GAP;
+    }
+
+    push_text(
+        &mut svg,
+        CARD_WIDTH - PAD,
+        CARD_HEIGHT - 36,
+        22,
+        PRIMARY,
+        "end",
+        &card.site,
+    );
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Rasterise a card SVG to PNG bytes.
+pub fn render_png(svg: &str) -> Result<Vec<u8>> {
+    let opt = resvg::usvg::Options {
+        fontdb: FONTDB.clone(),
+        ..Default::default()
+    };
+    let tree = resvg::usvg::Tree::from_str(svg, &opt).map_err(|e| render_failed("parse", e))?;
+    let size = tree.size().to_int_size();
+    let mut pixmap = resvg::tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| render_failed("canvas", "zero-sized card"))?;
+    resvg::render(
+        &tree,
+        resvg::tiny_skia::Transform::default(),
+        &mut pixmap.as_mut(),
+    );
+    pixmap.encode_png().map_err(|e| render_failed("encode", e))
+}
+
+fn render_failed(step: &str, e: impl std::fmt::Display) -> Error {
+    tracing::error!(step, error = %e, "card render failed");
+    CrossPostingError::SpaceCardFailed.into()
+}
+
+fn push_rect(svg: &mut String, x: u32, y: u32, width: u32, height: u32, fill: &str) {
+    svg.push_str(&format!(
+        r#"<rect x="{x}" y="{y}" width="{width}" height="{height}" fill="{fill}"/>"#
+    ));
+}
+
+/// One rounded bar of the chart, starting at [`BAR_X`].
+fn push_bar(svg: &mut String, y: u32, width: u32, fill: &str) {
+    svg.push_str(&format!(
+        r#"<rect x="{BAR_X}" y="{y}" width="{width}" height="20" rx="10" fill="{fill}"/>"#
+    ));
+}
+
+fn push_text(svg: &mut String, x: u32, y: u32, size: u32, fill: &str, anchor: &str, text: &str) {
+    svg.push_str(&format!(
+        r#"<text x="{x}" y="{y}" font-size="{size}" fill="{fill}" text-anchor="{anchor}">"#
+    ));
+    svg.push_str(&escape_xml(text));
+    svg.push_str("</text>");
+}
+
+/// Cut `text` to roughly `max_units` half-width columns, ending in an
+/// ellipsis when cut. Hangul, CJK and other wide characters count as two,
+/// which keeps Korean labels inside the same box as Latin ones.
+fn fit(text: &str, max_units: usize) -> String {
+    let text = text.trim();
+    let mut used = 0;
+    let mut out = String::new();
+    for c in text.chars() {
+        let w = unit_width(c);
+        if used + w > max_units {
+            // Make room for the ellipsis.
+            while used + 1 > max_units {
+                match out.pop() {
+                    Some(p) => used -= unit_width(p),
+                    None => break,
+                }
+            }
+            out.push('…');
+            return out;
+        }
+        used += w;
+        out.push(c);
+    }
+    out
+}
+
+fn unit_width(c: char) -> usize {
+    if (c as u32) >= 0x1100 { 2 } else { 1 }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// `1204` → `1,204`.
+pub fn group_thousands(n: i64) -> String {
+    let digits = n.unsigned_abs().to_string();
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    if n < 0 { format!("-{out}") } else { out }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card() -> ResultsCard {
+        ResultsCard {
+            title: "Budget vote".into(),
+            participants: 1204,
+            questions: vec![QuestionResult {
+                title: "Which park?".into(),
+                total: 200,
+                options: vec![
+                    OptionResult {
+                        label: "North".into(),
+                        count: 104,
+                    },
+                    OptionResult {
+                        label: "South & East".into(),
+                        count: 62,
+                    },
+                ],
+            }],
+            site: "ratel.foundation".into(),
+        }
+    }
+
+    #[test]
+    fn percent_rounds_and_guards_empty_questions() {
+        let q = &card().questions[0];
+        assert_eq!(q.percent(&q.options[0]), 52);
+        assert_eq!(q.percent(&q.options[1]), 31);
+        let empty = QuestionResult::default();
+        assert_eq!(empty.percent(&OptionResult::default()), 0);
+    }
+
+    #[test]
+    fn alt_text_lists_every_option_with_its_share() {
+        assert_eq!(
+            card().alt_text(),
+            "Poll results for Budget vote, 1,204 participants. \
+             Which park? North 52%, South & East 31%."
+        );
+    }
+
+    #[test]
+    fn svg_escapes_labels_and_draws_one_bar_per_option() {
+        let svg = results_card_svg(&card());
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains("South &amp; East"));
+        assert!(!svg.contains("South & East"));
+        assert!(svg.contains(">52%<"));
+        assert!(svg.contains(r#"width="270""#), "52% of the bar: {svg}");
+        assert_eq!(svg.matches(&format!(r#"fill="{SECONDARY}""#)).count(), 2);
+    }
+
+    #[test]
+    fn render_png_uses_the_bundled_faces() {
+        assert_eq!(FONTDB.len(), 2);
+        let mut c = card();
+        c.title = "예산 투표".into();
+        let png = render_png(&results_card_svg(&c)).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn svg_caps_questions_and_options() {
+        let mut c = card();
+        let q = QuestionResult {
+            title: "q".into(),
+            total: 10,
+            options: (0..5)
+                .map(|i| OptionResult {
+                    label: format!("o{i}"),
+                    count: 1,
+                })
+                .collect(),
+        };
+        c.questions = vec![q.clone(), q.clone(), q];
+        let svg = results_card_svg(&c);
+        let bars = MAX_CARD_QUESTIONS * MAX_CARD_OPTIONS;
+        assert_eq!(svg.matches(&format!(r#"fill="{TRACK}""#)).count(), bars);
+    }
+
+    #[test]
+    fn fit_counts_wide_characters_double() {
+        assert_eq!(fit("short", 10), "short");
+        assert_eq!(fit("abcdefghij", 5), "abcd…");
+        assert_eq!(fit("공원예산투표", 6), "공원…");
+    }
+
+    #[test]
+    fn group_thousands_inserts_separators() {
+        assert_eq!(group_thousands(0), "0");
+        assert_eq!(group_thousands(999), "999");
+        assert_eq!(group_thousands(1204), "1,204");
+        assert_eq!(group_thousands(1_000_000), "1,000,000");
+    }
+}
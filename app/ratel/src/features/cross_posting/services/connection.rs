@@ -3,7 +3,7 @@
 //! Every connect-* controller (Bluesky app-password, LinkedIn OAuth
 //! callback, Threads later) ends with the same five steps:
 //!   1. AEAD-seal the platform-specific [`DecryptedCredentials`] blob.
-//!   2. Look up the user's existing `SocialConnection` row for this
+//!   2. Look up the owner's existing `SocialConnection` row for this
 //!      platform — used to preserve `auto_post_enabled`,
 //!      `posts_syndicated_count`, and `created_at` across reconnects.
 //!   3. Build the new row with `status = Connected`.
@@ -26,6 +26,8 @@ use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
 /// succeeded. Field-level docs match the homonymous `SocialConnection`
 /// fields they map onto.
 pub struct ConnectionUpsert {
+    /// Owner of the connection: the connecting user, or the team for a
+    /// team-shared account (the caller has checked the user manages it).
     pub user_pk: Partition,
     pub platform: SocialPlatform,
     /// Decrypted credential blob to seal. Caller is responsible for
@@ -100,6 +102,11 @@ pub async fn seal_and_upsert_connection(
         credential_ciphertext: ciphertext,
         token_expires_at,
         auto_post_enabled: existing.as_ref().map_or(true, |c| c.auto_post_enabled),
+        import_replies_enabled: existing.as_ref().is_some_and(|c| c.import_replies_enabled),
+        thread_mode_enabled: existing.as_ref().is_some_and(|c| c.thread_mode_enabled),
+        space_cards_enabled: existing.as_ref().is_some_and(|c| c.space_cards_enabled),
+        approval_required: existing.as_ref().is_some_and(|c| c.approval_required),
+        best_time: existing.as_ref().and_then(|c| c.best_time),
         posts_syndicated_count: existing.as_ref().map_or(0, |c| c.posts_syndicated_count),
         last_synced_at: existing.as_ref().and_then(|c| c.last_synced_at),
         created_at: existing.as_ref().map_or(now, |c| c.created_at),
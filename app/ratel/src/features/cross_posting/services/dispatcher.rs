@@ -3,7 +3,8 @@
 //! Triggered by an EventBridge Pipe on SyndicationJob INSERT or MODIFY
 //! whose `NewImage.state == "pending"`. The Pipe filter ensures we only
 //! see jobs ready for dispatch (initial enqueue from Stage 1 OR a retry
-//! sweep / user-initiated retry that flipped state Failed → Pending).
+//! sweep / user-initiated retry that flipped state Failed → Pending, or
+//! the syndication scheduler releasing a due Scheduled job).
 //!
 //! Implements the 6-step idempotency-safe flow from the design doc:
 //! 1. **Acquire lock** via conditional UpdateItem (state=Pending AND
@@ -13,11 +14,17 @@
 //!    previous attempt may have published before dying; probe the platform
 //!    via `adapter.find_by_backlink` and adopt the result if found.
 //! 3. **Privacy guard**: re-read `Post`; if it's no longer Public/
-//!    Published, mark Skipped (FR-6 #39).
+//!    Published, mark Skipped (FR-6 #39). Space-card jobs
+//!    (`JobSource::SpaceLive` / `SpaceResults`) build their card via
+//!    `space_cards::build_space_card` instead, which applies the same
+//!    guard to the space.
 //! 4. **Resolve images + body**: take up to `platform.max_images()` from
-//!    `post.urls`; format body via `format_for_platform` (Phase 1 has no
-//!    `body_override`).
-//! 5. **Publish** through the platform adapter.
+//!    `post.urls` (with alt text from matching image blocks); format body
+//!    via `format_for_platform` (Phase 1 has no `body_override`), with
+//!    mentions resolved by `structured::resolve_mentions`. For
+//!    connections in thread mode, `split_into_thread` supplies both.
+//! 5. **Publish** through the platform adapter; for a thread, follow with
+//!    one `publish_reply` per remaining segment.
 //! 6. **Commit terminal state** (Published / Failed / Skipped) atomically
 //!    with lock release. The commit's condition `dispatch_lock_id =
 //!    :my_lock_id` prevents a stolen-lock holder from overwriting the
@@ -40,14 +47,16 @@ use crate::common::types::InboxPayload;
 use crate::common::utils::time;
 use crate::common::*;
 use crate::features::cross_posting::models::{
-    ConnectionStatus, ErrorCategory, JobState, LOCK_TTL_SEC, SocialConnection, SyndicationJob,
+    AuditAction, ConnectionStatus, ErrorCategory, JobSource, JobState, LOCK_TTL_SEC,
+    SocialConnection, SyndicationAuditEntry, SyndicationJob,
 };
 use crate::features::cross_posting::services::adapters::{
-    BlueskyAdapter, CrossPostAdapter, DecryptedCredentials, ImageRef, LinkCard, LinkedInAdapter,
-    PlatformError, PublishedRef,
+    CrossPostAdapter, DecryptedCredentials, FormattedBody, ImageRef, LinkCard, PlatformError,
+    PublishedRef, ReplyTarget, adapter_for,
 };
-use crate::features::cross_posting::services::{credentials, format, shard};
-use crate::features::cross_posting::types::{CrossPostingError, SocialPlatform};
+use crate::features::cross_posting::services::space_cards::{self, SpaceCard};
+use crate::features::cross_posting::services::{credentials, format, shard, structured};
+use crate::features::cross_posting::types::CrossPostingError;
 use crate::features::posts::models::Post;
 use crate::features::posts::types::{PostStatus, Visibility};
 use aws_sdk_dynamodb::Client as DynamoClient;
@@ -84,69 +93,65 @@ pub async fn handle_syndication_job_ready(job: SyndicationJob) -> Result<()> {
         );
     }
 
-    // ── (3-prep) Read Post for privacy guard + body formatting ────────
-    let post = match Post::get(cli, &job.pk, Some(EntityType::Post)).await {
-        Ok(Some(p)) => p,
-        Ok(None) => {
-            tracing::error!(pk = ?job.pk, "dispatcher: Post not found — marking Skipped");
+    // ── (3-prep) Read the source for privacy guard + body formatting ──
+    let content = if job.source == JobSource::Post {
+        let post = match Post::get(cli, &job.pk, Some(EntityType::Post)).await {
+            Ok(Some(p)) => p,
+            Ok(None) => {
+                tracing::error!(pk = ?job.pk, "dispatcher: Post not found — marking Skipped");
+                commit_skipped(cli, &table, &job, &lock_id, now).await?;
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::error!(pk = ?job.pk, error = %e, "dispatcher: Post lookup failed");
+                // Don't release the lock — let TTL expire so the next invocation
+                // can retry. Returning error makes Lambda invocation fail and
+                // EventBridge will retry with the same event.
+                return Err(e);
+            }
+        };
+
+        // ── (3) Privacy guard re-check ─────────────────────────────────
+        if post.visibility != Some(Visibility::Public) || post.status != PostStatus::Published {
+            tracing::info!(
+                pk = ?job.pk,
+                visibility = ?post.visibility,
+                status = ?post.status,
+                "dispatcher: post no longer public/published — marking Skipped"
+            );
             commit_skipped(cli, &table, &job, &lock_id, now).await?;
             return Ok(());
         }
-        Err(e) => {
-            tracing::error!(pk = ?job.pk, error = %e, "dispatcher: Post lookup failed");
-            // Don't release the lock — let TTL expire so the next invocation
-            // can retry. Returning error makes Lambda invocation fail and
-            // EventBridge will retry with the same event.
-            return Err(e);
+        JobContent::Post(Box::new(post))
+    } else {
+        // Space cards run the same guard against the space (and its status)
+        // inside the builder, which answers `None` when it no longer holds.
+        match space_cards::build_space_card(cli, &job).await {
+            Ok(Some(card)) => JobContent::Space(card),
+            Ok(None) => {
+                tracing::info!(
+                    pk = ?job.pk, sk = ?job.sk,
+                    "dispatcher: space no longer shareable for this card — marking Skipped"
+                );
+                commit_skipped(cli, &table, &job, &lock_id, now).await?;
+                return Ok(());
+            }
+            Err(e) => {
+                tracing::error!(pk = ?job.pk, error = %e, "dispatcher: space card build failed");
+                // Same as a failed Post lookup: keep the lock, let the
+                // event be retried.
+                return Err(e);
+            }
         }
     };
 
-    // ── (3) Privacy guard re-check ─────────────────────────────────────
-    if post.visibility != Some(Visibility::Public) || post.status != PostStatus::Published {
-        tracing::info!(
-            pk = ?job.pk,
-            visibility = ?post.visibility,
-            status = ?post.status,
-            "dispatcher: post no longer public/published — marking Skipped"
-        );
-        commit_skipped(cli, &table, &job, &lock_id, now).await?;
-        return Ok(());
-    }
-
     // ── Adapter selection ─────────────────────────────────────────────
-    // Boxed trait object so each platform can return its own concrete type.
-    // Threads (1C) still falls through to the not-implemented branch until
-    // its adapter and OAuth flow land.
-    let adapter: Box<dyn CrossPostAdapter> = match job.platform {
-        SocialPlatform::Bluesky => Box::new(BlueskyAdapter::new()),
-        SocialPlatform::LinkedIn => Box::new(LinkedInAdapter::new()),
-        SocialPlatform::Threads => {
-            tracing::warn!(
-                pk = ?job.pk,
-                platform = ?job.platform,
-                "dispatcher: platform adapter not implemented yet — marking Failed"
-            );
-            let msg = "platform adapter not implemented yet".to_string();
-            commit_failed(
-                cli,
-                &table,
-                &job,
-                &lock_id,
-                ErrorCategory::Unknown,
-                &msg,
-                job.attempts.saturating_add(1),
-                now,
-            )
-            .await?;
-            notify_failure(cli, &job, ErrorCategory::Unknown, Some(msg)).await;
-            return Ok(());
-        }
-    };
+    let adapter = adapter_for(job.platform);
 
     // ── Load + decrypt credentials ────────────────────────────────────
     let connection = SocialConnection::get(
         cli,
-        &job.author_user_id,
+        job.connection_owner(),
         Some(EntityType::SocialConnection(job.platform.to_string())),
     )
     .await
@@ -244,16 +249,43 @@ pub async fn handle_syndication_job_ready(job: SyndicationJob) -> Result<()> {
     }
 
     // ── (4) Resolve images + body ──────────────────────────────────────
-    let images: Vec<ImageRef> = post
-        .urls
-        .iter()
-        .take(adapter.max_images())
-        .map(|url| ImageRef::from_s3(url))
-        .collect();
+    // Thread mode: the first segment is published like a single post and
+    // `replies` go out under it once it's up (step 5b). Space cards are
+    // always a single post.
+    let mut replies = Vec::new();
+    let (body, images, link_card): (FormattedBody, Vec<ImageRef>, LinkCard) = match content {
+        JobContent::Post(post) => {
+            let mentions = structured::resolve_mentions(cli, &post.body, job.platform).await;
+            if connection.thread_mode_enabled && job.platform.supports_threads() {
+                replies =
+                    format::split_into_thread(&post, job.platform, &job.backlink_url, &mentions);
+            }
+            let link_card = build_link_card(&post, &job);
+            if replies.len() > 1 {
+                let root = replies.remove(0);
+                (root.body, root.images, link_card)
+            } else {
+                replies.clear();
+                (
+                    format::format_for_platform(
+                        &post,
+                        job.platform,
+                        &job.backlink_url,
+                        &mentions,
+                    ),
+                    format::images_for_platform(&post, job.platform),
+                    link_card,
+                )
+            }
+        }
+        JobContent::Space(card) => (card.body.into(), card.images, card.link_card),
+    };
 
-    let body = format::format_for_platform(&post, job.platform, &job.backlink_url);
-    let body_len = body.chars().count() as i32;
-    let link_card = build_link_card(&post, &job);
+    let body_len = (body.text.chars().count()
+        + replies
+            .iter()
+            .map(|r| r.body.text.chars().count())
+            .sum::<usize>()) as i32;
 
     // ── (5) Publish — inline retry once on retryable failures ────────
     // First attempt. If it fails we may retry once within the same lock:
@@ -338,7 +370,7 @@ pub async fn handle_syndication_job_ready(job: SyndicationJob) -> Result<()> {
                 "dispatcher: first publish failed (retryable) — retrying inline"
             );
             result = adapter
-                .publish(current_creds, body, images, link_card)
+                .publish(current_creds.clone(), body, images, link_card)
                 .await;
         }
     }
@@ -352,8 +384,21 @@ pub async fn handle_syndication_job_ready(job: SyndicationJob) -> Result<()> {
                 external = %published.external_post_url,
                 "dispatcher: publish ok"
             );
-            commit_published_with_body_len(cli, &table, &job, &lock_id, &published, body_len, now)
-                .await?;
+            // ── (5b) Thread replies ───────────────────────────────────
+            let thread_post_ids =
+                publish_thread_replies(adapter.as_ref(), &current_creds, &job, &published, replies)
+                    .await;
+            commit_published_with_body_len(
+                cli,
+                &table,
+                &job,
+                &lock_id,
+                &published,
+                &thread_post_ids,
+                body_len,
+                now,
+            )
+            .await?;
             Ok(())
         }
         Err(err) => {
@@ -376,6 +421,56 @@ pub async fn handle_syndication_job_ready(job: SyndicationJob) -> Result<()> {
     }
 }
 
+/// Step 5b — post the remaining thread segments as a reply chain under
+/// `root`. A segment that still fails after one inline retry ends the
+/// chain: the root is already public, so the job commits Published with
+/// the replies that made it instead of failing into a retry that would
+/// post the root twice. Returns the reply ids in order.
+async fn publish_thread_replies(
+    adapter: &dyn CrossPostAdapter,
+    creds: &DecryptedCredentials,
+    job: &SyndicationJob,
+    root: &PublishedRef,
+    segments: Vec<format::ThreadSegment>,
+) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::with_capacity(segments.len());
+    for (i, segment) in segments.into_iter().enumerate() {
+        let reply_to = ReplyTarget {
+            root_id: root.external_post_id.clone(),
+            parent_id: ids.last().unwrap_or(&root.external_post_id).clone(),
+        };
+        let mut result = adapter
+            .publish_reply(
+                creds.clone(),
+                reply_to.clone(),
+                segment.body.clone(),
+                segment.images.clone(),
+            )
+            .await;
+        if let Err(err) = &result {
+            if is_retryable(classify_platform_error(err)) {
+                result = adapter
+                    .publish_reply(creds.clone(), reply_to, segment.body, segment.images)
+                    .await;
+            }
+        }
+        match result {
+            Ok(reply) => ids.push(reply.external_post_id),
+            Err(err) => {
+                tracing::warn!(
+                    pk = ?job.pk,
+                    platform = ?job.platform,
+                    segment = i + 2,
+                    error = %err,
+                    "dispatcher: thread reply failed — committing the partial thread"
+                );
+                break;
+            }
+        }
+    }
+    ids
+}
+
 // ─────────────────────────────────────────────────────────────────────────
 // Lock acquisition
 // ─────────────────────────────────────────────────────────────────────────
@@ -490,7 +585,8 @@ async fn commit_skipped(
         .key("sk", AV::S(job.sk.to_string()))
         .update_expression(
             "SET #state = :state, updated_at = :now \
-             REMOVE dispatch_shard, engagement_shard, dispatch_lock_id, lock_acquired_at",
+             REMOVE dispatch_shard, engagement_shard, gsi2_pk, gsi2_sk, \
+                dispatch_lock_id, lock_acquired_at",
         )
         .condition_expression("dispatch_lock_id = :my_lock_id")
         .expression_attribute_names("#state", "state")
@@ -514,7 +610,7 @@ async fn commit_published(
     pubref: &PublishedRef,
     now_ms: i64,
 ) -> Result<()> {
-    commit_published_with_body_len(cli, table, job, lock_id, pubref, 0, now_ms).await
+    commit_published_with_body_len(cli, table, job, lock_id, pubref, &[], 0, now_ms).await
 }
 
 async fn commit_published_with_body_len(
@@ -523,12 +619,22 @@ async fn commit_published_with_body_len(
     job: &SyndicationJob,
     lock_id: &str,
     pubref: &PublishedRef,
+    thread_post_ids: &[String],
     body_len: i32,
     now_ms: i64,
 ) -> Result<()> {
     let now_secs = now_ms / 1000;
     let one_hour_later = now_secs + 3600;
+    // The engagement GSI keys are written by hand here (this is a raw
+    // conditional update, not the entity updater), so derive them from the
+    // entity's own key getters to stay byte-identical with what
+    // `find_due_engagement` queries.
     let engagement_shard = shard::shard_for(&post_id_inner(&job.pk));
+    let scheduled = SyndicationJob {
+        engagement_shard: Some(engagement_shard.clone()),
+        engagement_next_at: one_hour_later,
+        ..job.clone()
+    };
 
     cli.update_item()
         .table_name(table)
@@ -538,9 +644,12 @@ async fn commit_published_with_body_len(
             "SET #state = :state, \
                 external_post_id = :ext_id, \
                 external_post_url = :ext_url, \
+                thread_post_ids = :thread_ids, \
                 body_snapshot_len = :body_len, \
                 engagement_shard = :eng_shard, \
                 engagement_next_at = :eng_at, \
+                gsi2_pk = :gsi2_pk, \
+                gsi2_sk = :gsi2_sk, \
                 updated_at = :now \
              REMOVE dispatch_shard, dispatch_lock_id, lock_acquired_at",
         )
@@ -549,18 +658,35 @@ async fn commit_published_with_body_len(
         .expression_attribute_values(":state", AV::S(job_state_str(JobState::Published).into()))
         .expression_attribute_values(":ext_id", AV::S(pubref.external_post_id.clone()))
         .expression_attribute_values(":ext_url", AV::S(pubref.external_post_url.clone()))
+        .expression_attribute_values(
+            ":thread_ids",
+            AV::L(thread_post_ids.iter().cloned().map(AV::S).collect()),
+        )
         .expression_attribute_values(":body_len", AV::N(body_len.to_string()))
         .expression_attribute_values(":eng_shard", AV::S(engagement_shard))
         .expression_attribute_values(":eng_at", AV::N(one_hour_later.to_string()))
+        .expression_attribute_values(":gsi2_pk", AV::S(scheduled.get_pk_for_gsi2()))
+        .expression_attribute_values(":gsi2_sk", AV::S(scheduled.get_sk_for_gsi2()))
         .expression_attribute_values(":my_lock_id", AV::S(lock_id.to_string()))
         .expression_attribute_values(":now", AV::N(now_ms.to_string()))
         .send()
         .await
-        .map(|_| ())
         .map_err(|e| {
             tracing::error!(error = %e.into_service_error(), "dispatcher: commit_published failed");
-            CrossPostingError::CommitFailed.into()
-        })
+            CrossPostingError::CommitFailed
+        })?;
+
+    let mut entry = SyndicationAuditEntry::new(
+        job,
+        AuditAction::Published,
+        job.author_user_id.clone(),
+        now_ms,
+    );
+    entry.external_post_url = Some(pubref.external_post_url.clone());
+    if let Err(e) = entry.create(cli).await {
+        tracing::warn!(pk = ?job.pk, error = %e, "dispatcher: audit write failed (non-fatal)");
+    }
+    Ok(())
 }
 
 /// Commit a terminal Failed state (retry-sweeper-free model). The row
@@ -588,7 +714,8 @@ async fn commit_failed(
                 last_error_category = :cat, \
                 last_error_message = :msg, \
                 updated_at = :now \
-             REMOVE dispatch_shard, engagement_shard, dispatch_lock_id, lock_acquired_at",
+             REMOVE dispatch_shard, engagement_shard, gsi2_pk, gsi2_sk, \
+                dispatch_lock_id, lock_acquired_at",
         )
         .condition_expression("dispatch_lock_id = :my_lock_id")
         .expression_attribute_names("#state", "state")
@@ -618,6 +745,7 @@ async fn commit_failed(
 ///   AuthExpired by hiding "Retry now" and surfacing a Reconnect hint.
 /// - everything else → `/posts/{post_id}` so the author can hit "Retry
 ///   now" directly from the Syndication panel.
+/// - space cards → `/spaces/{space_id}`; they have no panel to retry from.
 async fn notify_failure(
     cli: &DynamoClient,
     job: &SyndicationJob,
@@ -625,7 +753,10 @@ async fn notify_failure(
     error_message: Option<String>,
 ) {
     let post_id = post_id_inner(&job.pk).to_string();
-    let cta_url = format!("/posts/{}", post_id);
+    let cta_url = match &job.pk {
+        Partition::Space(id) => format!("/spaces/{}", id),
+        _ => format!("/posts/{}", post_id),
+    };
 
     let payload = InboxPayload::CrossPostingFailed {
         post_id,
@@ -649,6 +780,13 @@ async fn notify_failure(
 // Helpers
 // ─────────────────────────────────────────────────────────────────────────
 
+/// What step 4 publishes from: the post itself, or a space card built
+/// from the space's current state.
+enum JobContent {
+    Post(Box<Post>),
+    Space(SpaceCard),
+}
+
 fn build_link_card(post: &Post, job: &SyndicationJob) -> LinkCard {
     let stripped = post.body.to_plain_text();
     let description: String = stripped.chars().take(200).collect();
@@ -681,12 +819,16 @@ fn is_retryable(category: ErrorCategory) -> bool {
 /// `:expression_attribute_values`. Manual mapping because we're building
 /// the expression string by hand (the DynamoEntity macro's updater
 /// doesn't expose conditional UpdateItem).
-fn job_state_str(state: JobState) -> &'static str {
+pub(crate) fn job_state_str(state: JobState) -> &'static str {
     match state {
         JobState::Pending => "pending",
         JobState::Published => "published",
         JobState::Failed => "failed",
         JobState::Skipped => "skipped",
+        JobState::Scheduled => "scheduled",
+        JobState::Cancelled => "cancelled",
+        JobState::AwaitingApproval => "awaiting_approval",
+        JobState::Rejected => "rejected",
     }
 }
 
@@ -707,7 +849,7 @@ fn post_id_inner(pk: &Partition) -> String {
     }
 }
 
-fn table_name() -> String {
+pub(crate) fn table_name() -> String {
     let prefix = option_env!("DYNAMO_TABLE_PREFIX").unwrap_or("ratel-local");
     format!("{prefix}-main")
 }
@@ -715,7 +857,8 @@ fn table_name() -> String {
 /// AEAD-seal rotated credentials and persist them onto
 /// `SocialConnection.credential_ciphertext`. Platform-agnostic: the actual
 /// refresh call lives on `CrossPostAdapter::try_refresh_credentials`.
-async fn persist_refreshed_credentials(
+/// Shared with the Stage 4 engagement refresh.
+pub(crate) async fn persist_refreshed_credentials(
     cli: &DynamoClient,
     connection: &SocialConnection,
     new_creds: &DecryptedCredentials,
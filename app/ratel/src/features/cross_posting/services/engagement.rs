@@ -0,0 +1,400 @@
+//! Stage 4 of the cross-posting pipeline — the **engagement refresh**.
+//!
+//! Every published `SyndicationJob` sits in the sparse `find_due_engagement`
+//! GSI (one partition per shard, see `services::shard`) keyed by its next
+//! refresh time. A tick walks each shard oldest-first, stops at the first
+//! job that isn't due, and for each due job:
+//!
+//! 1. pulls likes / comments / reposts into the `EngagementSnapshot` the
+//!    post-detail panel reads — summed over every post of a thread-mode
+//!    syndication — and records the refresh for the analytics dashboard
+//!    (see `services::analytics`);
+//! 2. when the connection opted into reply import, pulls the replies and
+//!    upserts them as `ExternalComment`s (deleting ones that disappeared
+//!    from the platform). A thread's own segments are not imported, and
+//!    neither are replies to space cards;
+//! 3. reschedules on the adaptive cadence — hourly for the first day,
+//!    every 6 h for the first week, daily for the first month — and drops
+//!    out of the GSI after that.
+//!
+//! Triggered every 5 minutes — by an EventBridge schedule in deployed envs
+//! (`DetailType::EngagementRefreshTick`) and by [`spawn_engagement_refresher`]
+//! under `local-dev`. A failure on one job is logged and the job is
+//! rescheduled anyway, so a broken connection can't pin a shard.
+
+use std::collections::HashMap;
+
+use crate::common::*;
+use crate::features::cross_posting::models::{
+    ConnectionStatus, EngagementSnapshot, ExternalComment, JobSource, SHARD_COUNT,
+    SocialConnection, SyndicationJob,
+};
+use crate::features::cross_posting::services::adapters::{
+    CrossPostAdapter, DecryptedCredentials, EngagementCounts, ExternalReply, MAX_IMPORTED_REPLIES,
+    PlatformError, adapter_for,
+};
+use crate::features::cross_posting::services::{analytics, credentials, dispatcher, shard};
+use crate::features::cross_posting::types::CrossPostingError;
+
+const REFRESH_PAGE_SIZE: i32 = 50;
+/// Upper bound on jobs handled per tick across all shards; each costs one
+/// or two platform calls. The rest wait for the next tick.
+const MAX_JOBS_PER_TICK: usize = 200;
+
+const HOUR_SECS: i64 = 60 * 60;
+const DAY_SECS: i64 = 24 * HOUR_SECS;
+
+/// Next refresh time (epoch secs) for a post syndicated at `published_secs`,
+/// or `None` once it is past the 30-day window.
+pub fn next_refresh_at(published_secs: i64, now_secs: i64) -> Option<i64> {
+    let age = now_secs - published_secs;
+    let step = if age < DAY_SECS {
+        HOUR_SECS
+    } else if age < 7 * DAY_SECS {
+        6 * HOUR_SECS
+    } else if age < 30 * DAY_SECS {
+        DAY_SECS
+    } else {
+        return None;
+    };
+    Some(now_secs + step)
+}
+
+/// Engagement of a thread-mode syndication from the per-post counts, root
+/// first. Every post but the last has the next segment as a reply, and
+/// those self-replies are not comments.
+pub fn sum_thread_engagement(posts: &[EngagementCounts]) -> EngagementCounts {
+    let mut total = EngagementCounts::default();
+    for counts in posts {
+        total.likes += counts.likes;
+        total.comments += counts.comments;
+        total.reposts += counts.reposts;
+    }
+    let self_replies = posts.len().saturating_sub(1) as i32;
+    total.comments = (total.comments - self_replies).max(0);
+    total
+}
+
+/// One refresh pass over every shard. Returns how many jobs were handled.
+pub async fn run_engagement_refresh(cli: &aws_sdk_dynamodb::Client, now_ms: i64) -> Result<usize> {
+    let now_secs = now_ms / 1000;
+    let mut handled = 0;
+
+    for n in 0..SHARD_COUNT {
+        let shard_key = format!("SDS#{n}");
+        let mut bookmark: Option<String> = None;
+
+        'pages: loop {
+            let opt = SyndicationJob::opt_with_bookmark(bookmark.take())
+                .scan_index_forward(true)
+                .limit(REFRESH_PAGE_SIZE);
+            let (jobs, next) = SyndicationJob::find_due_engagement(cli, &shard_key, opt).await?;
+
+            for job in jobs {
+                if job.engagement_next_at > now_secs || handled >= MAX_JOBS_PER_TICK {
+                    break 'pages;
+                }
+                let key = format!("{}/{}", job.pk, job.sk);
+                if let Err(e) = refresh_job(cli, &job, now_ms).await {
+                    crate::error!("engagement refresh: {key} failed: {e}");
+                }
+                if let Err(e) = reschedule(cli, &job, now_secs).await {
+                    crate::error!("engagement refresh: {key} reschedule failed: {e}");
+                }
+                handled += 1;
+            }
+
+            match next {
+                Some(b) => bookmark = Some(b),
+                None => break,
+            }
+        }
+    }
+
+    if handled > 0 {
+        tracing::info!(handled, "engagement refresh tick");
+    }
+    Ok(handled)
+}
+
+/// The job's `created_at` stands in for the publish time: Stage 1 writes
+/// the job at publish and Stage 2 dispatches within seconds.
+async fn reschedule(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+    now_secs: i64,
+) -> Result<()> {
+    let updater = SyndicationJob::updater(&job.pk, &job.sk);
+    let updater = match next_refresh_at(job.created_at / 1000, now_secs) {
+        Some(at) => updater
+            .with_engagement_shard(shard::shard_for(&post_id(&job.pk)))
+            .with_engagement_next_at(at),
+        None => updater.remove_engagement_shard().with_engagement_next_at(0),
+    };
+    updater.execute(cli).await?;
+    Ok(())
+}
+
+fn post_id(pk: &Partition) -> String {
+    match pk {
+        Partition::Feed(id) => id.clone(),
+        other => other.to_string(),
+    }
+}
+
+async fn refresh_job(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+    now_ms: i64,
+) -> Result<()> {
+    let Some(external_post_id) = job.external_post_id.as_deref() else {
+        return Ok(());
+    };
+    let Some(connection) = SocialConnection::get(
+        cli,
+        job.connection_owner().clone(),
+        Some(EntityType::SocialConnection(job.platform.to_string())),
+    )
+    .await?
+    else {
+        return Ok(());
+    };
+    if connection.status != ConnectionStatus::Connected {
+        return Ok(());
+    }
+
+    let adapter = adapter_for(job.platform);
+    let mut creds = credentials::open_credentials(&connection.credential_ciphertext)
+        .map_err(|e| refresh_failed("credential decrypt", e))?;
+
+    let counts = match adapter
+        .fetch_engagement(creds.clone(), external_post_id)
+        .await
+    {
+        Err(PlatformError::AuthExpired(_)) => {
+            creds = refresh_credentials(cli, adapter.as_ref(), &connection, creds).await?;
+            adapter
+                .fetch_engagement(creds.clone(), external_post_id)
+                .await
+        }
+        other => other,
+    }
+    .map_err(|e| refresh_failed("fetch_engagement", e))?;
+
+    let mut per_post = vec![counts];
+    for id in &job.thread_post_ids {
+        let reply_counts = adapter
+            .fetch_engagement(creds.clone(), id)
+            .await
+            .map_err(|e| refresh_failed("fetch_engagement", e))?;
+        per_post.push(reply_counts);
+    }
+    let counts = sum_thread_engagement(&per_post);
+
+    let previous = EngagementSnapshot::get(cli, &job.pk, Some(job.snapshot_sk())).await?;
+    EngagementSnapshot {
+        pk: job.pk.clone(),
+        sk: job.snapshot_sk(),
+        platform: job.platform,
+        likes: counts.likes,
+        comments: counts.comments,
+        reposts: counts.reposts,
+        fetched_at: now_ms,
+    }
+    .upsert(cli)
+    .await?;
+    analytics::record_engagement(cli, job, previous.as_ref(), counts, now_ms).await;
+
+    // Imported replies surface under the post, so space cards skip them.
+    if connection.import_replies_enabled
+        && job.platform.supports_reply_import()
+        && job.source == JobSource::Post
+    {
+        let mut replies = adapter
+            .fetch_replies(creds, external_post_id)
+            .await
+            .map_err(|e| refresh_failed("fetch_replies", e))?;
+        replies.retain(|r| !job.thread_post_ids.contains(&r.external_id));
+        import_replies(cli, job, replies, now_ms).await?;
+    }
+    Ok(())
+}
+
+fn refresh_failed(step: &str, e: impl std::fmt::Display) -> Error {
+    tracing::error!(step, error = %e, "engagement refresh failed");
+    CrossPostingError::EngagementRefreshFailed.into()
+}
+
+async fn refresh_credentials(
+    cli: &aws_sdk_dynamodb::Client,
+    adapter: &dyn CrossPostAdapter,
+    connection: &SocialConnection,
+    creds: DecryptedCredentials,
+) -> Result<DecryptedCredentials> {
+    let refreshed = adapter
+        .try_refresh_credentials(creds)
+        .await
+        .map_err(|e| refresh_failed("credential refresh", e))?;
+    if let Err(e) = dispatcher::persist_refreshed_credentials(cli, connection, &refreshed).await {
+        tracing::warn!(
+            platform = ?connection.platform,
+            error = %e,
+            "engagement refresh: refreshed creds but persist failed — using rotated tokens"
+        );
+    }
+    Ok(refreshed)
+}
+
+/// Upsert the fetched replies and delete imported ones the platform no
+/// longer returns (deleted or hidden there). Rows whose content hasn't
+/// changed are left alone. Pruning is skipped when the fetch hit the cap,
+/// since a missing reply may just be past the cut-off.
+async fn import_replies(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+    replies: Vec<ExternalReply>,
+    now_ms: i64,
+) -> Result<()> {
+    let prefix = EntityType::ExternalComment(format!("{}#", job.platform)).to_string();
+    let mut existing: HashMap<String, ExternalComment> = HashMap::new();
+    let mut bookmark = None;
+    loop {
+        let opt = ExternalComment::opt_with_bookmark(bookmark)
+            .sk(prefix.clone())
+            .limit(100);
+        let (page, next) = ExternalComment::query(cli, &job.pk, opt).await?;
+        existing.extend(page.into_iter().map(|c| (c.sk.to_string(), c)));
+        match next {
+            Some(b) => bookmark = Some(b),
+            None => break,
+        }
+    }
+
+    let complete = replies.len() < MAX_IMPORTED_REPLIES;
+    for reply in replies {
+        let (pk, sk) = ExternalComment::keys(&job.pk, job.platform, &reply.external_id);
+        let previous = existing.remove(&sk.to_string());
+        let comment = ExternalComment {
+            pk,
+            sk,
+            platform: job.platform,
+            external_id: reply.external_id,
+            author_handle: reply.author_handle,
+            author_display_name: reply.author_display_name,
+            author_avatar_url: reply.author_avatar_url.as_deref().and_then(https_url),
+            content: reply.content,
+            url: https_url(&reply.url).unwrap_or_default(),
+            posted_at: reply.posted_at,
+            imported_at: previous.as_ref().map_or(now_ms, |p| p.imported_at),
+            updated_at: now_ms,
+        };
+        let unchanged = previous.is_some_and(|p| {
+            ExternalComment {
+                updated_at: now_ms,
+                ..p
+            } == comment
+        });
+        if !unchanged {
+            comment.upsert(cli).await?;
+        }
+    }
+
+    if complete {
+        for gone in existing.into_values() {
+            ExternalComment::delete(cli, gone.pk, Some(gone.sk)).await?;
+        }
+    }
+    Ok(())
+}
+
+/// A platform-supplied link, kept only when it is `https://`. Imported
+/// replies render their link and avatar as a live `href` / `src` under
+/// the post, so a `javascript:` or `data:` URL from a hostile instance
+/// must not get that far.
+fn https_url(raw: &str) -> Option<String> {
+    url::Url::parse(raw.trim())
+        .ok()
+        .filter(|url| url.scheme() == "https")
+        .map(String::from)
+}
+
+/// Local-dev stand-in for the EventBridge schedule: a dedicated thread
+/// running [`run_engagement_refresh`] every 5 minutes.
+#[cfg(feature = "local-dev")]
+pub fn spawn_engagement_refresher() {
+    std::thread::Builder::new()
+        .name("engagement-refresher".into())
+        .spawn(|| {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("engagement-refresher runtime");
+            rt.block_on(async {
+                let cfg = crate::common::CommonConfig::default();
+                let cli = cfg.dynamodb();
+                let mut interval = tokio::time::interval(std::time::Duration::from_secs(300));
+                loop {
+                    interval.tick().await;
+                    let now = crate::common::utils::time::get_now_timestamp_millis();
+                    if let Err(e) = run_engagement_refresh(cli, now).await {
+                        tracing::error!("engagement refresh tick failed: {e}");
+                    }
+                }
+            });
+        })
+        .expect("failed to spawn engagement-refresher thread");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cadence_slows_with_age_and_stops_after_a_month() {
+        let published = 1_000_000;
+        assert_eq!(
+            next_refresh_at(published, published + HOUR_SECS),
+            Some(published + 2 * HOUR_SECS)
+        );
+        let day_two = published + DAY_SECS + 1;
+        assert_eq!(
+            next_refresh_at(published, day_two),
+            Some(day_two + 6 * HOUR_SECS)
+        );
+        let week_two = published + 8 * DAY_SECS;
+        assert_eq!(
+            next_refresh_at(published, week_two),
+            Some(week_two + DAY_SECS)
+        );
+        assert_eq!(next_refresh_at(published, published + 30 * DAY_SECS), None);
+    }
+
+    #[test]
+    fn thread_engagement_sums_posts_and_drops_self_replies() {
+        let counts = |likes, comments, reposts| EngagementCounts {
+            likes,
+            comments,
+            reposts,
+        };
+        assert_eq!(
+            sum_thread_engagement(&[counts(5, 3, 1), counts(2, 1, 0), counts(1, 0, 2)]),
+            counts(8, 2, 3)
+        );
+        assert_eq!(sum_thread_engagement(&[counts(4, 2, 1)]), counts(4, 2, 1));
+        assert_eq!(
+            sum_thread_engagement(&[counts(0, 0, 0), counts(0, 0, 0)]),
+            counts(0, 0, 0)
+        );
+    }
+    #[test]
+    fn only_https_links_are_imported() {
+        assert_eq!(
+            https_url(" https://example.social/@bob/1 ").as_deref(),
+            Some("https://example.social/@bob/1")
+        );
+        assert_eq!(https_url("javascript:alert(1)"), None);
+        assert_eq!(https_url("data:text/html,hi"), None);
+        assert_eq!(https_url("http://example.social/@bob/1"), None);
+        assert_eq!(https_url("not a url"), None);
+    }
+}
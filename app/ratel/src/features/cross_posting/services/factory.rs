@@ -12,13 +12,26 @@
 //! is independent — per FR-5 #32, one platform's failure must not affect
 //! the others.
 //!
+//! Each job's timing comes from `directive.schedule` (default
+//! `SchedulePlan::Auto`, i.e. the connection's best time). A job due later
+//! is written `Scheduled` and parked in the scheduler's GSI; one due now is
+//! written `Pending` and the Stage 2 Pipe picks it up immediately.
+//!
+//! A team post goes out through the **team's** connections instead, and
+//! only if the member who published it holds `TeamGroupPermission::
+//! CrossPost`. A team connection with `approval_required` writes its job
+//! `AwaitingApproval` into the team's approval queue (see
+//! [`super::approval`]); the schedule is kept and applied on approval.
+//!
 //! Absent directive = silent exit (Ratel-only post; no syndication intent).
 
 use crate::common::*;
 use crate::common::utils::time;
 use crate::features::cross_posting::models::{
-    ConnectionStatus, JobState, PostSyndicationDirective, SocialConnection, SyndicationJob,
+    AuditAction, ConnectionStatus, JobSource, JobState, PostSyndicationDirective, SocialConnection,
+    SyndicationAuditEntry, SyndicationJob,
 };
+use crate::features::cross_posting::services::{shard, team_access};
 use crate::features::cross_posting::types::SocialPlatform;
 use crate::features::posts::models::Post;
 use std::collections::HashMap;
@@ -55,18 +68,34 @@ pub async fn handle_post_published_for_syndication(post: Post) -> Result<()> {
         return Ok(());
     }
 
-    // 2. Read the author's SocialConnection rows. Index by platform for
-    //    O(1) lookup in step 3. Filter to rows that are both Connected
-    //    AND have the per-platform auto_post toggle on.
+    // 2. Read the connection owner's SocialConnection rows — the author's,
+    //    or the team's for a team post. Index by platform for O(1) lookup
+    //    in step 3. Filter to rows that are both Connected AND have the
+    //    per-platform auto_post toggle on.
     let user_pk = directive.author_user_id.clone();
+    let team_pk = match &post.user_pk {
+        pk @ Partition::Team(_) => Some(pk.clone()),
+        _ => None,
+    };
+    if let Some(team_pk) = &team_pk {
+        if !team_access::can_cross_post(cli, team_pk, &user_pk).await? {
+            tracing::info!(
+                post_pk = ?post.pk,
+                user_pk = ?user_pk,
+                "factory: author lacks CrossPost on the team — skipping"
+            );
+            return Ok(());
+        }
+    }
+    let owner_pk = team_pk.clone().unwrap_or_else(|| user_pk.clone());
     let sk_prefix = EntityType::SocialConnection(String::new()).to_string();
     let opt = SocialConnection::opt_with_bookmark(None).sk(sk_prefix).limit(10);
     let (connections, _next): (Vec<SocialConnection>, _) = SocialConnection::query(
-        cli, &user_pk, opt,
+        cli, &owner_pk, opt,
     )
     .await
     .map_err(|e| {
-        tracing::error!(user_pk = ?user_pk, error = %e, "factory: connection query failed");
+        tracing::error!(owner_pk = ?owner_pk, error = %e, "factory: connection query failed");
         e
     })?;
 
@@ -86,14 +115,14 @@ pub async fn handle_post_published_for_syndication(post: Post) -> Result<()> {
     );
 
     for platform in &directive.enabled_platforms {
-        if !connected.contains_key(platform) {
+        let Some(connection) = connected.get(platform) else {
             tracing::debug!(
                 post_pk = ?post.pk,
                 ?platform,
-                "factory: platform in directive but not connected (or auto_post off) for user"
+                "factory: platform in directive but not connected (or auto_post off) for owner"
             );
             continue;
-        }
+        };
 
         let backlink_url = format!("{canonical_url}?utm_source={platform}");
 
@@ -104,21 +133,49 @@ pub async fn handle_post_published_for_syndication(post: Post) -> Result<()> {
         // Pending and causing a second publish. Phase 1 internal-staging
         // accepts this rare edge case; production hardening before 1D
         // enable should add conditional PutItem with attribute_not_exists.
+        let scheduled_at = directive
+            .schedule
+            .get(platform)
+            .copied()
+            .unwrap_or_default()
+            .resolve(connection.best_time, now);
+        let needs_approval = team_pk.is_some() && connection.approval_required;
+        let (state, dispatch_shard, queue_owner) = match scheduled_at {
+            _ if needs_approval => (JobState::AwaitingApproval, None, None),
+            Some(_) => (
+                JobState::Scheduled,
+                Some(shard::shard_for(&post_id_inner(&post.pk))),
+                Some(user_pk.clone()),
+            ),
+            None => (JobState::Pending, None, None),
+        };
+
         let job = SyndicationJob {
             pk: post.pk.clone(),
             sk: EntityType::SyndicationJob(platform.to_string()),
-            dispatch_shard: None,
+            dispatch_shard,
             engagement_shard: None,
-            next_attempt_at: 0,
+            next_attempt_at: match state {
+                JobState::Scheduled => scheduled_at.map_or(0, |at| at / 1000),
+                _ => 0,
+            },
             engagement_next_at: 0,
             author_user_id: user_pk.clone(),
+            queue_owner,
+            scheduled_at,
+            connection_pk: team_pk.clone(),
+            approval_team: team_pk.clone().filter(|_| needs_approval),
+            reviewed_by: None,
+            reviewed_at: None,
             platform: *platform,
-            state: JobState::Pending,
+            source: JobSource::Post,
+            state,
             attempts: 0,
             last_error_category: None,
             last_error_message: None,
             external_post_id: None,
             external_post_url: None,
+            thread_post_ids: Vec::new(),
             body_snapshot_len: 0,
             backlink_url,
             dispatch_lock_id: None,
@@ -140,8 +197,12 @@ pub async fn handle_post_published_for_syndication(post: Post) -> Result<()> {
             tracing::info!(
                 post_pk = ?post.pk,
                 ?platform,
+                ?scheduled_at,
+                ?state,
                 "factory: SyndicationJob created"
             );
+            SyndicationAuditEntry::record(cli, &job, AuditAction::Requested, user_pk.clone(), now)
+                .await;
         }
     }
 
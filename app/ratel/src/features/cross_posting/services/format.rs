@@ -4,13 +4,22 @@
 //! - [`format_for_platform`] — used when `SyndicationJob.body_override` is
 //!   `None` (Phase 1 always; v1.5 when the user did NOT author a per-network
 //!   variant). Builds the syndicated body from the canonical `Post`'s
-//!   `title` + HTML-stripped `html_contents` + backlink.
+//!   `title` + body + backlink. Structured-content bodies go through
+//!   [`structured`] so lists, code, links and mentions survive; HTML
+//!   bodies are tag-stripped.
 //! - [`truncate_override`] — used when `body_override` is `Some(_)` (v1.5+).
 //!   Free-text override goes through length-only truncation, preserves the
 //!   trailing backlink.
+//!
+//! [`images_for_platform`] resolves the attachments that go with the body.
+//! [`split_into_thread`] replaces both for connections in thread mode.
 
+use crate::common::types::{Block, BlockKind, ContentBody, MediaSource};
+use crate::features::cross_posting::services::adapters::{FormattedBody, ImageRef};
+use crate::features::cross_posting::services::structured::{self, MentionMap, TextUnit, Unit};
 use crate::features::cross_posting::types::SocialPlatform;
 use crate::features::posts::models::Post;
+use std::collections::{HashMap, HashSet};
 
 const ELLIPSIS: char = '…';
 
@@ -18,15 +27,26 @@ const ELLIPSIS: char = '…';
 /// `platform.char_limit()`. The backlink is appended verbatim and is never
 /// truncated (FR-5 #36 — backlink integrity is non-negotiable).
 ///
-/// Order when over-budget: `{title}\n\n{first_sentence}…\n{backlink}`.
-/// When under-budget: `{title}\n\n{full_body}\n\n{backlink}`.
+/// Order when over-budget: `{title}\n\n{leading_blocks}…\n{backlink}`,
+/// keeping whole blocks while they fit — or the first sentence when not
+/// even the first block does. When under-budget:
+/// `{title}\n\n{full_body}\n{backlink}`.
 ///
 /// If `{title}\n{backlink}` alone exceeds the budget, the title is the only
 /// thing truncated; body is omitted entirely.
-pub fn format_for_platform(post: &Post, platform: SocialPlatform, backlink: &str) -> String {
+///
+/// `mentions` come from [`structured::resolve_mentions`] for the same
+/// platform.
+pub fn format_for_platform(
+    post: &Post,
+    platform: SocialPlatform,
+    backlink: &str,
+    mentions: &MentionMap,
+) -> FormattedBody {
     let limit = platform.char_limit();
     let title = post.title.trim();
-    let body = post.body.to_plain_text();
+    let units = text_units(post, platform, mentions);
+    let body = structured::join(&units);
 
     // Reserve trailing "\n{backlink}" — backlink is non-truncatable.
     let suffix = format!("\n{backlink}");
@@ -34,20 +54,21 @@ pub fn format_for_platform(post: &Post, platform: SocialPlatform, backlink: &str
 
     if suffix_chars >= limit {
         // Pathological — backlink alone is over budget. Send raw backlink only.
-        return backlink.to_string();
+        return backlink.into();
     }
 
     // Budget for {title} + "\n\n" + {body}
     let budget = limit - suffix_chars;
 
     // Try the under-budget path first: full title + double newline + full body.
-    let full_prefix = if body.is_empty() {
-        title.to_string()
-    } else {
-        format!("{title}\n\n{body}")
-    };
-    if char_count(&full_prefix) <= budget {
-        return format!("{full_prefix}{suffix}");
+    let mut full = FormattedBody::from(title);
+    if !body.text.is_empty() {
+        full.push_str("\n\n");
+        full.append(body);
+    }
+    if char_count(&full.text) <= budget {
+        full.push_str(&suffix);
+        return full;
     }
 
     // Over budget — truncate to first sentence + ellipsis.
@@ -62,16 +83,62 @@ pub fn format_for_platform(post: &Post, platform: SocialPlatform, backlink: &str
         // Title alone exceeds the body budget. Drop body entirely; truncate
         // title hard at (budget - 1) chars + ellipsis.
         let truncated_title = take_chars(title, budget.saturating_sub(1));
-        return format!("{truncated_title}{ELLIPSIS}{suffix}");
+        return format!("{truncated_title}{ELLIPSIS}{suffix}").into();
     }
 
     let body_budget = budget - title_block_chars;
-    let first_sentence = first_sentence_of(&body);
     // -1 for the trailing ellipsis we'll append.
-    let body_chars_to_take = body_budget.saturating_sub(1);
-    let truncated_body = take_chars(&first_sentence, body_chars_to_take);
+    let truncated_body = leading_units(&units, body_budget.saturating_sub(1));
 
-    format!("{title_block}{truncated_body}{ELLIPSIS}{suffix}")
+    let mut out = FormattedBody::from(title_block);
+    out.append(truncated_body);
+    out.push_str(&format!("{ELLIPSIS}{suffix}"));
+    out
+}
+
+/// The post body as text units: one per block for structured content,
+/// the whole tag-stripped text for HTML.
+fn text_units(post: &Post, platform: SocialPlatform, mentions: &MentionMap) -> Vec<TextUnit> {
+    match &post.body {
+        ContentBody::StructuredContent(doc) => structured::units(doc, platform, mentions)
+            .into_iter()
+            .filter_map(|unit| match unit {
+                Unit::Text(text) => Some(text),
+                Unit::Image(_) => None,
+            })
+            .collect(),
+        ContentBody::HtmlContent(_) => {
+            let text = post.body.to_plain_text();
+            if text.is_empty() {
+                Vec::new()
+            } else {
+                vec![TextUnit::plain(text)]
+            }
+        }
+    }
+}
+
+/// As many whole units as fit in `max` chars; when not even the first
+/// one does, its first sentence cut to `max`.
+fn leading_units(units: &[TextUnit], max: usize) -> FormattedBody {
+    let mut kept = FormattedBody::default();
+    let mut prev: Option<bool> = None;
+    for unit in units {
+        let sep = prev.map_or("", |p| structured::separator(p, unit.list_item));
+        if char_count(&kept.text) + char_count(sep) + char_count(&unit.body.text) > max {
+            break;
+        }
+        kept.push_str(sep);
+        kept.append(unit.body.clone());
+        prev = Some(unit.list_item);
+    }
+    if prev.is_none() {
+        if let Some(first) = units.first() {
+            let cut = take_chars(&first_sentence_of(&first.body.text), max).len();
+            kept = first.body.slice(0..cut);
+        }
+    }
+    kept
 }
 
 /// Truncate a user-authored override body to fit `limit`, preserving the
@@ -102,6 +169,236 @@ pub fn truncate_override(body: String, backlink: &str, limit: usize) -> String {
     format!("{truncated}{suffix_long}")
 }
 
+/// Up to `platform.max_images()` of the post's attachments, in order. Each
+/// image carries the alt text of the structured-content `MediaBlock` that
+/// shows the same URL, so platforms with image descriptions (Mastodon)
+/// stay accessible. HTML posts have no alt text.
+pub fn images_for_platform(post: &Post, platform: SocialPlatform) -> Vec<ImageRef> {
+    let alts = image_alt_texts(&post.body);
+    post.urls
+        .iter()
+        .take(platform.max_images())
+        .map(|url| ImageRef::from_s3(url).with_alt(alts.get(url).cloned()))
+        .collect()
+}
+
+/// Most posts a thread-mode syndication is split into. Text past the last
+/// one is cut with an ellipsis, as on the single-post path.
+pub const MAX_THREAD_SEGMENTS: usize = 10;
+
+/// One post of a thread-mode syndication, already formatted: the first
+/// carries the title and backlink, and every one ends with its `n/N`.
+#[derive(Debug, Clone)]
+pub struct ThreadSegment {
+    pub body: FormattedBody,
+    pub images: Vec<ImageRef>,
+}
+
+/// Split a post into a numbered reply chain that fits `platform`. Text
+/// breaks at block boundaries first, then at sentences, then at words;
+/// facets travel with the piece of text they cover.
+/// Each image block goes on the segment holding the text before it,
+/// spilling into the next segment once one is at `max_images()`; images
+/// in `post.urls` that no block shows go on the first segment.
+///
+/// A post that fits one platform post comes back as the single
+/// [`format_for_platform`] segment, so callers thread only on `len() > 1`.
+pub fn split_into_thread(
+    post: &Post,
+    platform: SocialPlatform,
+    backlink: &str,
+    mentions: &MentionMap,
+) -> Vec<ThreadSegment> {
+    let single = || {
+        vec![ThreadSegment {
+            body: format_for_platform(post, platform, backlink, mentions),
+            images: images_for_platform(post, platform),
+        }]
+    };
+
+    let limit = platform.char_limit();
+    // Every segment reserves room for the widest counter, " 10/10"; the
+    // first also for the "\n{backlink}" line.
+    let counter = char_count(&format!(" {MAX_THREAD_SEGMENTS}/{MAX_THREAD_SEGMENTS}"));
+    let suffix = char_count(&format!("\n{backlink}"));
+    let Some(root_budget) = limit.checked_sub(counter + suffix).filter(|b| *b > 0) else {
+        return single();
+    };
+
+    let mut thread = ThreadBuilder::new(root_budget, limit - counter);
+    let title = post.title.trim();
+    if !title.is_empty() {
+        thread.push_unit(&TextUnit::plain(title));
+    }
+    let mut shown = HashSet::new();
+    match &post.body {
+        ContentBody::StructuredContent(doc) => {
+            // Image blocks attach to whichever segment is open.
+            for unit in structured::units(doc, platform, mentions) {
+                match unit {
+                    Unit::Text(text) => thread.push_unit(&text),
+                    Unit::Image(image) => {
+                        if shown.insert(image.url.clone()) {
+                            thread.open().images.push(image);
+                        }
+                    }
+                }
+            }
+        }
+        ContentBody::HtmlContent(_) => {
+            thread.push_unit(&TextUnit::plain(post.body.to_plain_text()));
+        }
+    }
+
+    let mut segments = thread.segments;
+    if segments.len() == 1 {
+        return single();
+    }
+    if segments.len() > MAX_THREAD_SEGMENTS {
+        segments.truncate(MAX_THREAD_SEGMENTS);
+        if let Some(last) = segments.last_mut() {
+            let kept = take_chars(&last.body.text, thread.budget.saturating_sub(1)).len();
+            last.body.truncate(kept);
+            last.body.text.push(ELLIPSIS);
+        }
+    }
+
+    let loose: Vec<ImageRef> = post
+        .urls
+        .iter()
+        .filter(|url| !shown.contains(*url))
+        .map(ImageRef::from_s3)
+        .collect();
+    segments[0].images.splice(0..0, loose);
+
+    let max_images = platform.max_images();
+    let total = segments.len();
+    let mut carried = Vec::new();
+    for (i, segment) in segments.iter_mut().enumerate() {
+        let mut images = std::mem::take(&mut carried);
+        images.append(&mut segment.images);
+        if images.len() > max_images {
+            carried = images.split_off(max_images);
+        }
+        segment.images = images;
+
+        let counter = if i == 0 {
+            format!(" 1/{total}\n{backlink}")
+        } else {
+            format!(" {}/{total}", i + 1)
+        };
+        segment.body.push_str(&counter);
+    }
+    segments
+}
+
+/// Greedy packer behind [`split_into_thread`]. Segment texts are raw —
+/// counters and the backlink are added once the count is known.
+struct ThreadBuilder {
+    segments: Vec<ThreadSegment>,
+    /// Text budget of the first segment, which also carries the backlink.
+    root_budget: usize,
+    budget: usize,
+    /// Whether the last unit was a list item, for [`structured::separator`].
+    list_item: bool,
+}
+
+impl ThreadBuilder {
+    fn new(root_budget: usize, budget: usize) -> Self {
+        Self {
+            segments: vec![ThreadSegment {
+                body: FormattedBody::default(),
+                images: Vec::new(),
+            }],
+            root_budget,
+            budget,
+            list_item: false,
+        }
+    }
+
+    /// Add one block's text, kept whole when it fits a segment and broken
+    /// into sentences (and overlong sentences into words) otherwise.
+    /// Pieces are sized for the tighter root budget so each one fits an
+    /// empty segment.
+    fn push_unit(&mut self, unit: &TextUnit) {
+        let mut sep = structured::separator(self.list_item, unit.list_item);
+        self.list_item = unit.list_item;
+        let text = unit.body.text.as_str();
+        let sentences = if char_count(text) <= self.root_budget {
+            vec![text]
+        } else {
+            sentences_of(text)
+        };
+        for sentence in sentences {
+            // `sentences_of` slices `text`, so this is the sentence's offset.
+            let sentence_start = sentence.as_ptr() as usize - text.as_ptr() as usize;
+            let mut cursor = 0;
+            for piece in wrap_words(sentence, self.root_budget) {
+                // A wrapped piece is a substring of the sentence unless
+                // wrapping collapsed whitespace; only then are its facets
+                // lost.
+                let body = match sentence[cursor..].find(piece.as_str()) {
+                    Some(pos) => {
+                        let start = sentence_start + cursor + pos;
+                        cursor += pos + piece.len();
+                        unit.body.slice(start..start + piece.len())
+                    }
+                    None => FormattedBody::from(piece),
+                };
+                self.push(body, sep);
+                sep = " ";
+            }
+        }
+    }
+
+    fn push(&mut self, piece: FormattedBody, sep: &str) {
+        let budget = if self.segments.len() == 1 {
+            self.root_budget
+        } else {
+            self.budget
+        };
+        let open = self.open();
+        if open.body.text.is_empty() {
+            open.body.append(piece);
+        } else if char_count(&open.body.text) + char_count(sep) + char_count(&piece.text) <= budget
+        {
+            open.body.push_str(sep);
+            open.body.append(piece);
+        } else {
+            self.segments.push(ThreadSegment {
+                body: piece,
+                images: Vec::new(),
+            });
+        }
+    }
+
+    fn open(&mut self) -> &mut ThreadSegment {
+        self.segments
+            .last_mut()
+            .expect("ThreadBuilder always has an open segment")
+    }
+}
+
+/// `url → alt` for every external image block, including nested ones.
+fn image_alt_texts(body: &ContentBody) -> HashMap<String, String> {
+    fn walk(blocks: &[Block], out: &mut HashMap<String, String>) {
+        for block in blocks {
+            if let BlockKind::Image(media) = &block.kind {
+                if let (MediaSource::External { url }, Some(alt)) = (&media.source, &media.alt) {
+                    out.entry(url.clone()).or_insert_with(|| alt.clone());
+                }
+            }
+            walk(&block.children, out);
+        }
+    }
+
+    let mut out = HashMap::new();
+    if let ContentBody::StructuredContent(doc) = body {
+        walk(&doc.blocks, &mut out);
+    }
+    out
+}
+
 /// Strip HTML tags from rich-text body. Lightweight tag stripper — does NOT
 /// fully parse HTML; just removes anything between `<` and `>` and decodes a
 /// small set of common entities. Sufficient because Ratel's rich-text editor
@@ -146,6 +443,60 @@ fn first_sentence_of(text: &str) -> String {
     text.to_string()
 }
 
+/// Every sentence of `text`, split the way [`first_sentence_of`] finds
+/// the first one.
+fn sentences_of(text: &str) -> Vec<&str> {
+    let bytes = text.as_bytes();
+    let mut out = Vec::new();
+    let mut start = 0;
+    for (i, b) in bytes.iter().enumerate() {
+        let ends_sentence = matches!(*b, b'.' | b'?' | b'!')
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_whitespace);
+        if ends_sentence {
+            out.push(text[start..=i].trim());
+            start = i + 1;
+        }
+    }
+    out.push(text[start..].trim());
+    out.retain(|s| !s.is_empty());
+    out
+}
+
+/// Break `text` into lines of at most `max` chars at whitespace. A word
+/// longer than `max` is cut hard.
+fn wrap_words(text: &str, max: usize) -> Vec<String> {
+    if char_count(text) <= max {
+        return vec![text.to_string()];
+    }
+    let mut out = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word = word.to_string();
+        while char_count(&word) > max {
+            if !line.is_empty() {
+                out.push(std::mem::take(&mut line));
+            }
+            out.push(take_chars(&word, max));
+            word = word.chars().skip(max).collect();
+        }
+        if word.is_empty() {
+            continue;
+        }
+        if line.is_empty() {
+            line = word;
+        } else if char_count(&line) + 1 + char_count(&word) <= max {
+            line.push(' ');
+            line.push_str(&word);
+        } else {
+            out.push(std::mem::replace(&mut line, word));
+        }
+    }
+    if !line.is_empty() {
+        out.push(line);
+    }
+    out
+}
+
 fn char_count(s: &str) -> usize {
     s.chars().count()
 }
@@ -162,12 +513,21 @@ fn take_chars(s: &str, n: usize) -> String {
 mod tests {
     use super::*;
     use crate::common::types::ContentBody;
+    use crate::features::cross_posting::services::adapters::FacetFeature;
     use crate::features::posts::models::Post;
 
     fn make_post(title: &str, html: &str) -> Post {
         Post { title: title.to_string(), body: ContentBody::html(html), ..Default::default() }
     }
 
+    fn format_plain(post: &Post, platform: SocialPlatform, backlink: &str) -> String {
+        format_for_platform(post, platform, backlink, &MentionMap::default()).text
+    }
+
+    fn split_plain(post: &Post, platform: SocialPlatform, backlink: &str) -> Vec<ThreadSegment> {
+        split_into_thread(post, platform, backlink, &MentionMap::default())
+    }
+
     // ── strip_html ──────────────────────────────────────────────────────
     #[test]
     fn strip_html_removes_tags() {
@@ -200,7 +560,7 @@ mod tests {
     fn format_under_budget_includes_full_body() {
         let post = make_post("Hi", "<p>Short body.</p>");
         let out =
-            format_for_platform(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+            format_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
         assert!(out.contains("Hi"));
         assert!(out.contains("Short body."));
         assert!(out.ends_with("\nhttps://r/p?utm_source=bluesky"));
@@ -213,7 +573,7 @@ mod tests {
         let html = format!("<p>First sentence. {long_body}</p>");
         let post = make_post("Title", &html);
         let backlink = "https://r/p?utm_source=bluesky";
-        let out = format_for_platform(&post, SocialPlatform::Bluesky, backlink);
+        let out = format_plain(&post, SocialPlatform::Bluesky, backlink);
 
         assert!(out.starts_with("Title\n\n"));
         assert!(out.contains(ELLIPSIS));
@@ -226,7 +586,7 @@ mod tests {
         // Bluesky: 300 char limit. Build a body that pushes us deep into truncation.
         let post = make_post("T", &"a".repeat(2_000));
         let backlink = "https://example.com/very/long/canonical/path?utm_source=bluesky&extra=1";
-        let out = format_for_platform(&post, SocialPlatform::Bluesky, backlink);
+        let out = format_plain(&post, SocialPlatform::Bluesky, backlink);
         assert!(out.ends_with(&format!("\n{backlink}")));
         assert!(char_count(&out) <= SocialPlatform::Bluesky.char_limit());
     }
@@ -234,12 +594,78 @@ mod tests {
     #[test]
     fn format_includes_utm_per_platform() {
         let post = make_post("T", "<p>body</p>");
-        let bs = format_for_platform(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
-        let li = format_for_platform(&post, SocialPlatform::LinkedIn, "https://r/p?utm_source=linkedin");
-        let th = format_for_platform(&post, SocialPlatform::Threads, "https://r/p?utm_source=threads");
+        let bs = format_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+        let li = format_plain(&post, SocialPlatform::LinkedIn, "https://r/p?utm_source=linkedin");
+        let th = format_plain(&post, SocialPlatform::Threads, "https://r/p?utm_source=threads");
+        let ma =
+            format_plain(&post, SocialPlatform::Mastodon, "https://r/p?utm_source=mastodon");
         assert!(bs.contains("utm_source=bluesky"));
         assert!(li.contains("utm_source=linkedin"));
         assert!(th.contains("utm_source=threads"));
+        assert!(ma.contains("utm_source=mastodon"));
+    }
+
+    #[test]
+    fn format_fits_mastodon_limit() {
+        let post = make_post("Title", &format!("<p>First sentence. {}</p>", "x".repeat(800)));
+        let backlink = "https://r/p?utm_source=mastodon";
+        let out = format_plain(&post, SocialPlatform::Mastodon, backlink);
+        assert!(out.ends_with(&format!("\n{backlink}")));
+        assert!(char_count(&out) <= 500);
+    }
+
+    // ── images_for_platform ─────────────────────────────────────────────
+    #[test]
+    fn images_carry_alt_text_from_media_blocks() {
+        use crate::common::types::{ContentDocument, MediaBlock};
+
+        let image = |url: &str, alt: Option<&str>| Block {
+            id: url.to_string(),
+            kind: BlockKind::Image(MediaBlock {
+                source: MediaSource::External { url: url.to_string() },
+                caption: Default::default(),
+                alt: alt.map(str::to_string),
+                width: None,
+                height: None,
+            }),
+            children: vec![],
+            created_at: 0,
+            updated_at: 0,
+        };
+        let toggle = Block {
+            id: "toggle".to_string(),
+            kind: BlockKind::Toggle(Default::default()),
+            children: vec![image("https://s3/c.png", Some("nested"))],
+            created_at: 0,
+            updated_at: 0,
+        };
+        let doc = ContentDocument {
+            schema_version: 1,
+            blocks: vec![
+                image("https://s3/a.png", Some("A ratel")),
+                image("https://s3/b.png", None),
+                toggle,
+            ],
+            meta: Default::default(),
+        };
+        let post = Post {
+            body: ContentBody::structured(doc),
+            urls: ['a', 'b', 'c', 'd', 'e']
+                .iter()
+                .map(|c| format!("https://s3/{c}.png"))
+                .collect(),
+            ..Default::default()
+        };
+
+        let images = images_for_platform(&post, SocialPlatform::Mastodon);
+        assert_eq!(images.len(), 4);
+        assert_eq!(images[0].alt.as_deref(), Some("A ratel"));
+        assert_eq!(images[1].alt, None);
+        assert_eq!(images[2].alt.as_deref(), Some("nested"));
+        assert_eq!(images[3].alt, None);
+
+        let plain = make_post("T", "<p>x</p>");
+        assert!(images_for_platform(&plain, SocialPlatform::Mastodon).is_empty());
     }
 
     #[test]
@@ -248,7 +674,7 @@ mod tests {
         // must apply, not byte-based.
         let post = make_post("제목", "<p>안녕하세요. 다음 문장입니다. 그리고 계속됩니다.</p>");
         let out =
-            format_for_platform(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+            format_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
         assert!(out.contains("제목"));
         assert!(out.contains("안녕하세요."));
         assert!(char_count(&out) <= SocialPlatform::Bluesky.char_limit());
@@ -258,11 +684,276 @@ mod tests {
     fn format_handles_title_only_when_body_empty() {
         let post = make_post("Just a title", "");
         let out =
-            format_for_platform(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+            format_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
         assert!(out.starts_with("Just a title"));
         assert!(out.ends_with("\nhttps://r/p?utm_source=bluesky"));
     }
 
+    // ── split_into_thread ───────────────────────────────────────────────
+    fn paragraph(text: &str) -> Block {
+        use crate::common::types::{InlineNode, RichText, TextBlock, TextRun};
+
+        Block {
+            id: text.chars().take(8).collect(),
+            kind: BlockKind::Paragraph(TextBlock {
+                rich_text: RichText(vec![InlineNode::Text(TextRun {
+                    content: text.to_string(),
+                    annotations: Default::default(),
+                    link: None,
+                })]),
+                color: Default::default(),
+            }),
+            children: vec![],
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn image_block(url: &str) -> Block {
+        use crate::common::types::MediaBlock;
+
+        Block {
+            id: url.to_string(),
+            kind: BlockKind::Image(MediaBlock {
+                source: MediaSource::External { url: url.to_string() },
+                caption: Default::default(),
+                alt: Some(format!("alt {url}")),
+                width: None,
+                height: None,
+            }),
+            children: vec![],
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn structured_post(title: &str, blocks: Vec<Block>, urls: &[&str]) -> Post {
+        use crate::common::types::ContentDocument;
+
+        Post {
+            title: title.to_string(),
+            body: ContentBody::structured(ContentDocument {
+                schema_version: 1,
+                blocks,
+                meta: Default::default(),
+            }),
+            urls: urls.iter().map(|u| u.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn bullet(text: &str) -> Block {
+        let mut block = paragraph(text);
+        if let BlockKind::Paragraph(body) = block.kind {
+            block.kind = BlockKind::BulletedListItem(body);
+        }
+        block
+    }
+
+    fn linked(text: &str, anchor: &str, url: &str) -> Block {
+        use crate::common::types::{InlineNode, TextRun};
+
+        let mut block = paragraph(text);
+        if let BlockKind::Paragraph(body) = &mut block.kind {
+            body.rich_text.0.push(InlineNode::Text(TextRun {
+                content: anchor.to_string(),
+                annotations: Default::default(),
+                link: Some(url.to_string()),
+            }));
+        }
+        block
+    }
+
+    #[test]
+    fn thread_of_a_short_post_is_the_single_post() {
+        let post = make_post("Hi", "<p>Short body.</p>");
+        let backlink = "https://r/p?utm_source=bluesky";
+        let thread = split_plain(&post, SocialPlatform::Bluesky, backlink);
+        assert_eq!(thread.len(), 1);
+        assert_eq!(
+            thread[0].body.text,
+            format_plain(&post, SocialPlatform::Bluesky, backlink)
+        );
+    }
+
+    #[test]
+    fn thread_keeps_every_sentence_within_the_limit() {
+        let sentences: Vec<String> = (0..30)
+            .map(|i| format!("Sentence number {i} says something."))
+            .collect();
+        let post = make_post("Title", &format!("<p>{}</p>", sentences.join(" ")));
+        let backlink = "https://r/p?utm_source=bluesky";
+        let thread = split_plain(&post, SocialPlatform::Bluesky, backlink);
+
+        let total = thread.len();
+        assert!(total > 1);
+        let root = &thread[0].body.text;
+        assert!(root.starts_with("Title\n\n"));
+        assert!(root.ends_with(&format!(" 1/{total}\n{backlink}")));
+        for (i, segment) in thread.iter().enumerate().skip(1) {
+            assert!(segment.body.text.ends_with(&format!(" {}/{total}", i + 1)));
+            assert!(!segment.body.text.contains(backlink));
+        }
+        let joined: String = thread.iter().map(|s| s.body.text.as_str()).collect();
+        for sentence in &sentences {
+            assert!(joined.contains(sentence.as_str()), "lost {sentence:?}");
+        }
+        for segment in &thread {
+            assert!(char_count(&segment.body.text) <= SocialPlatform::Bluesky.char_limit());
+        }
+        assert!(!joined.contains(ELLIPSIS));
+    }
+
+    #[test]
+    fn thread_splits_at_blocks_and_places_images_after_their_text() {
+        let long = "x ".repeat(120);
+        let post = structured_post(
+            "T",
+            vec![
+                paragraph(&format!("First {long}")),
+                image_block("https://s3/a.png"),
+                paragraph(&format!("Second {long}")),
+                image_block("https://s3/b.png"),
+            ],
+            &["https://s3/a.png", "https://s3/b.png", "https://s3/loose.png"],
+        );
+        let thread =
+            split_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+
+        assert_eq!(thread.len(), 2);
+        assert!(thread[0].body.text.contains("First"));
+        assert!(thread[1].body.text.starts_with("Second"));
+        let urls = |i: usize| -> Vec<&str> {
+            thread[i].images.iter().map(|img| img.url.as_str()).collect()
+        };
+        assert_eq!(urls(0), vec!["https://s3/loose.png", "https://s3/a.png"]);
+        assert_eq!(urls(1), vec!["https://s3/b.png"]);
+        assert_eq!(thread[1].images[0].alt.as_deref(), Some("alt https://s3/b.png"));
+    }
+
+    #[test]
+    fn thread_spills_images_past_the_per_post_cap() {
+        let mut blocks = vec![paragraph("Opening.")];
+        blocks.extend((0..5).map(|i| image_block(&format!("https://s3/{i}.png"))));
+        blocks.push(paragraph(&"y ".repeat(200)));
+        let post = structured_post("T", blocks, &[]);
+        let thread =
+            split_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+
+        assert!(thread.len() >= 2);
+        assert_eq!(thread[0].images.len(), 4);
+        assert_eq!(thread[1].images[0].url, "https://s3/4.png");
+    }
+
+    #[test]
+    fn thread_is_capped_with_an_ellipsis() {
+        let post = make_post("T", &format!("<p>{}</p>", "word ".repeat(2_000)));
+        let thread =
+            split_plain(&post, SocialPlatform::Bluesky, "https://r/p?utm_source=bluesky");
+        assert_eq!(thread.len(), MAX_THREAD_SEGMENTS);
+        let last = &thread[MAX_THREAD_SEGMENTS - 1].body.text;
+        assert!(last.ends_with(&format!("{ELLIPSIS} 10/10")));
+        assert!(char_count(last) <= SocialPlatform::Bluesky.char_limit());
+    }
+
+    #[test]
+    fn format_keeps_list_markers_of_structured_posts() {
+        let post = structured_post(
+            "Plan",
+            vec![
+                paragraph("Three things:"),
+                bullet("One"),
+                bullet("Two"),
+                bullet("Three"),
+            ],
+            &[],
+        );
+        let backlink = "https://r/p?utm_source=bluesky";
+        assert_eq!(
+            format_plain(&post, SocialPlatform::Bluesky, backlink),
+            format!("Plan\n\nThree things:\n\n• One\n• Two\n• Three\n{backlink}")
+        );
+    }
+
+    #[test]
+    fn format_over_budget_keeps_leading_whole_blocks() {
+        let post = structured_post(
+            "T",
+            vec![
+                paragraph("Intro."),
+                bullet("short"),
+                paragraph(&"y ".repeat(200)),
+            ],
+            &[],
+        );
+        let backlink = "https://r/p?utm_source=bluesky";
+        assert_eq!(
+            format_plain(&post, SocialPlatform::Bluesky, backlink),
+            format!("T\n\nIntro.\n\n• short{ELLIPSIS}\n{backlink}")
+        );
+    }
+
+    #[test]
+    fn format_shifts_link_facets_past_the_title() {
+        let post = structured_post(
+            "Title",
+            vec![linked("Read the ", "docs", "https://d/x")],
+            &[],
+        );
+        let out = format_for_platform(
+            &post,
+            SocialPlatform::Bluesky,
+            "https://r/p?utm_source=bluesky",
+            &MentionMap::default(),
+        );
+        assert!(out.text.starts_with("Title\n\nRead the docs\n"));
+        let facet = &out.facets[0];
+        assert_eq!(&out.text[facet.byte_start..facet.byte_end], "docs");
+        assert_eq!(facet.feature, FacetFeature::Link("https://d/x".into()));
+
+        let mastodon = format_plain(&post, SocialPlatform::Mastodon, "https://r/p");
+        assert!(mastodon.contains("Read the docs (https://d/x)"));
+    }
+
+    #[test]
+    fn thread_facets_land_on_their_own_segment() {
+        let post = structured_post(
+            "T",
+            vec![
+                paragraph(&"x ".repeat(128)),
+                linked("see ", "docs", "https://d/x"),
+            ],
+            &[],
+        );
+        let thread = split_plain(
+            &post,
+            SocialPlatform::Bluesky,
+            "https://r/p?utm_source=bluesky",
+        );
+        assert!(thread.len() > 1);
+        assert!(thread[0].body.facets.is_empty());
+        let body = &thread[1].body;
+        assert_eq!(body.facets.len(), 1);
+        assert_eq!(
+            &body.text[body.facets[0].byte_start..body.facets[0].byte_end],
+            "docs"
+        );
+    }
+
+    #[test]
+    fn wrap_words_breaks_at_spaces_and_cuts_long_words() {
+        assert_eq!(wrap_words("aa bb cc", 5), vec!["aa bb", "cc"]);
+        assert_eq!(wrap_words("abcdefg h", 3), vec!["abc", "def", "g h"]);
+    }
+
+    #[test]
+    fn sentences_of_splits_on_terminators() {
+        assert_eq!(
+            sentences_of("One. Two? Three! Four"),
+            vec!["One.", "Two?", "Three!", "Four"]
+        );
+    }
+
     // ── truncate_override ───────────────────────────────────────────────
     #[test]
     fn truncate_override_under_limit_keeps_body() {
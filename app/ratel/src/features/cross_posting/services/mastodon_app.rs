@@ -0,0 +1,112 @@
+//! Per-instance Mastodon OAuth client registry.
+//!
+//! Mastodon has no central developer portal: Ratel registers itself as an
+//! OAuth app on each instance the first time a user connects from it, and
+//! every later connect from the same instance reuses that registration.
+//! The client secret is AEAD-sealed at rest like user credentials.
+
+use crate::common::utils::aead;
+use crate::common::*;
+use crate::features::cross_posting::models::MastodonApp;
+use crate::features::cross_posting::services::adapters::{
+    MastodonAdapter, MastodonAppRegistration, instance_host,
+};
+use crate::features::cross_posting::types::CrossPostingError;
+
+/// Return Ratel's client credentials on `instance_url`, registering a new
+/// app when none exists yet or when the stored one was registered with a
+/// different redirect URI (site URL changed).
+pub async fn find_or_register(
+    cli: &aws_sdk_dynamodb::Client,
+    instance_url: &str,
+    redirect_uri: &str,
+) -> Result<MastodonAppRegistration> {
+    let (pk, sk) = MastodonApp::keys(instance_host(instance_url));
+    let existing = MastodonApp::get(cli, pk.clone(), Some(sk.clone()))
+        .await
+        .map_err(|e| {
+            crate::error!("mastodon app lookup failed: {e}");
+            CrossPostingError::MastodonAuthFailed
+        })?;
+    if let Some(app) = existing.as_ref().filter(|a| a.redirect_uri == redirect_uri) {
+        return open_registration(app);
+    }
+
+    let registration = MastodonAdapter::new()
+        .register_app(
+            instance_url,
+            redirect_uri,
+            crate::common::config::site_base_url(),
+        )
+        .await
+        .map_err(|e| {
+            crate::error!("mastodon app registration on {instance_url} failed: {e}");
+            CrossPostingError::MastodonAuthFailed
+        })?;
+    let client_secret_ciphertext =
+        aead::seal(registration.client_secret.as_bytes()).map_err(|e| {
+            crate::error!("mastodon client secret seal failed: {e}");
+            CrossPostingError::MastodonAuthFailed
+        })?;
+
+    let now = crate::common::utils::time::now();
+    let row = MastodonApp {
+        pk,
+        sk,
+        instance_url: instance_url.to_string(),
+        client_id: registration.client_id.clone(),
+        client_secret_ciphertext,
+        redirect_uri: redirect_uri.to_string(),
+        created_at: existing.as_ref().map_or(now, |a| a.created_at),
+        updated_at: now,
+    };
+
+    if existing.is_some() {
+        row.upsert(cli).await.map_err(|e| {
+            crate::error!("mastodon app persist failed: {e}");
+            CrossPostingError::MastodonAuthFailed
+        })?;
+        return Ok(registration);
+    }
+
+    // First connect from this instance. `create` so two users racing on a
+    // brand-new instance don't overwrite each other's client id mid-flow —
+    // the loser adopts the winner's registration instead.
+    if let Err(e) = row.create(cli).await {
+        tracing::info!(error = %e, instance_url, "mastodon app create lost race; re-reading");
+        return load(cli, instance_url).await;
+    }
+    Ok(registration)
+}
+
+/// Load the stored registration for the OAuth callback. Missing means the
+/// flow wasn't started through `find_or_register`.
+pub async fn load(
+    cli: &aws_sdk_dynamodb::Client,
+    instance_url: &str,
+) -> Result<MastodonAppRegistration> {
+    let (pk, sk) = MastodonApp::keys(instance_host(instance_url));
+    let app = MastodonApp::get(cli, pk, Some(sk))
+        .await
+        .map_err(|e| {
+            crate::error!("mastodon app lookup failed: {e}");
+            CrossPostingError::MastodonAuthFailed
+        })?
+        .ok_or(CrossPostingError::MastodonAuthFailed)?;
+    open_registration(&app)
+}
+
+fn open_registration(app: &MastodonApp) -> Result<MastodonAppRegistration> {
+    let secret = aead::open(&app.client_secret_ciphertext).map_err(|e| {
+        crate::error!("mastodon client secret open failed: {e}");
+        CrossPostingError::MastodonAuthFailed
+    })?;
+    let client_secret = String::from_utf8(secret).map_err(|_| {
+        crate::error!("mastodon client secret is not utf-8");
+        CrossPostingError::MastodonAuthFailed
+    })?;
+    Ok(MastodonAppRegistration {
+        client_id: app.client_id.clone(),
+        client_secret,
+    })
+}
@@ -1,5 +1,5 @@
-//! Stateless OAuth `state` parameter for the LinkedIn (and future Threads)
-//! connect flow. Encodes `(user_pk, nonce, expiry)` into an HMAC-signed
+//! Stateless OAuth `state` parameter for the LinkedIn / Threads / Mastodon
+//! connect flows. Encodes `(user_pk, nonce, expiry)` into an HMAC-signed
 //! base64url blob — no DB / cache lookup on the callback side.
 //!
 //! Format:
@@ -63,6 +63,15 @@ pub struct DecodedState {
     /// connect (e.g. the post-edit page they came from). `None` ⇒ use
     /// the default `/{username}/settings/connections` destination.
     pub return_to: Option<String>,
+    /// Normalized `https://{host}` of the Mastodon instance the flow was
+    /// started against. The callback has no other way to know which
+    /// instance issued the `code`; signing it here stops a tampered
+    /// callback from pointing the token exchange at another host.
+    pub instance: Option<String>,
+    /// Team the connection is being made for. The callback stores the
+    /// connection under this team instead of the user, after re-checking
+    /// that the user still manages it.
+    pub team_pk: Option<Partition>,
 }
 
 /// JSON payload shape — kept private so callers can't depend on field
@@ -77,6 +86,12 @@ struct Payload<'a> {
     /// don't bloat every state with a `null` field.
     #[serde(default, skip_serializing_if = "Option::is_none", rename = "rt")]
     return_to: Option<&'a str>,
+    /// Mastodon instance URL; omitted for single-host platforms.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "in")]
+    instance: Option<&'a str>,
+    /// Team pk for team-owned connections; omitted for personal ones.
+    #[serde(default, skip_serializing_if = "Option::is_none", rename = "tm")]
+    team_pk: Option<&'a str>,
 }
 
 /// Build a fresh state token tied to `user_pk` with a random `nonce` and
@@ -87,7 +102,32 @@ struct Payload<'a> {
 pub fn encode(user_pk: &Partition, return_to: Option<&str>) -> Result<String, OauthStateError> {
     let nonce = uuid::Uuid::now_v7().to_string();
     let exp = (time::now() / 1000) + STATE_TTL_SECS;
-    encode_with(user_pk, &nonce, exp, return_to)
+    encode_payload(user_pk, &nonce, exp, return_to, None, None)
+}
+
+/// [`encode`] for multi-host platforms (Mastodon): additionally binds the
+/// normalized instance URL into the signed payload.
+pub fn encode_for_instance(
+    user_pk: &Partition,
+    return_to: Option<&str>,
+    instance: &str,
+) -> Result<String, OauthStateError> {
+    let nonce = uuid::Uuid::now_v7().to_string();
+    let exp = (time::now() / 1000) + STATE_TTL_SECS;
+    encode_payload(user_pk, &nonce, exp, return_to, Some(instance), None)
+}
+
+/// [`encode`] for a team-owned connection: additionally binds the team the
+/// user is connecting on behalf of.
+pub fn encode_for_team(
+    user_pk: &Partition,
+    return_to: Option<&str>,
+    team_pk: &Partition,
+) -> Result<String, OauthStateError> {
+    let nonce = uuid::Uuid::now_v7().to_string();
+    let exp = (time::now() / 1000) + STATE_TTL_SECS;
+    let team_pk_str = team_pk.to_string();
+    encode_payload(user_pk, &nonce, exp, return_to, None, Some(&team_pk_str))
 }
 
 /// Lower-level encode — exposed for tests so a fixed nonce / exp can be
@@ -97,6 +137,17 @@ pub fn encode_with(
     nonce: &str,
     exp: i64,
     return_to: Option<&str>,
+) -> Result<String, OauthStateError> {
+    encode_payload(user_pk, nonce, exp, return_to, None, None)
+}
+
+fn encode_payload(
+    user_pk: &Partition,
+    nonce: &str,
+    exp: i64,
+    return_to: Option<&str>,
+    instance: Option<&str>,
+    team_pk: Option<&str>,
 ) -> Result<String, OauthStateError> {
     let user_pk_str = user_pk.to_string();
     let sanitized = return_to.and_then(sanitize_return_to);
@@ -105,6 +156,8 @@ pub fn encode_with(
         nonce,
         exp,
         return_to: sanitized.as_deref(),
+        instance,
+        team_pk,
     };
     let payload_bytes = serde_json::to_vec(&payload).map_err(|e| {
         OauthStateError::Subkey(format!("payload serialize: {e}"))
@@ -169,12 +222,19 @@ pub fn decode_and_verify(state: &str) -> Result<DecodedState, OauthStateError> {
         .map_err(|_| OauthStateError::Malformed)?;
 
     let return_to = parsed.return_to.and_then(sanitize_return_to);
+    let team_pk = parsed
+        .team_pk
+        .map(str::parse::<Partition>)
+        .transpose()
+        .map_err(|_| OauthStateError::Malformed)?;
 
     Ok(DecodedState {
         user_pk,
         nonce: parsed.nonce.to_string(),
         expires_at: parsed.exp,
         return_to,
+        instance: parsed.instance.map(str::to_string),
+        team_pk,
     })
 }
 
@@ -281,6 +341,34 @@ mod tests {
         assert_eq!(decoded.return_to.as_deref(), Some("/posts/abc/edit"));
     }
 
+    #[test]
+    fn instance_roundtrips_and_defaults_to_none() {
+        if !key_available() {
+            return;
+        }
+        let token = encode_for_instance(&pk(), None, "https://mastodon.social").unwrap();
+        let decoded = decode_and_verify(&token).unwrap();
+        assert_eq!(decoded.instance.as_deref(), Some("https://mastodon.social"));
+
+        let token = encode(&pk(), None).unwrap();
+        assert!(decode_and_verify(&token).unwrap().instance.is_none());
+    }
+
+    #[test]
+    fn team_pk_roundtrips_and_defaults_to_none() {
+        if !key_available() {
+            return;
+        }
+        let team = Partition::Team("test-team-id".into());
+        let token = encode_for_team(&pk(), None, &team).unwrap();
+        let decoded = decode_and_verify(&token).unwrap();
+        assert_eq!(decoded.user_pk, pk());
+        assert_eq!(decoded.team_pk, Some(team));
+
+        let token = encode(&pk(), None).unwrap();
+        assert!(decode_and_verify(&token).unwrap().team_pk.is_none());
+    }
+
     #[test]
     fn return_to_rejected_when_not_same_origin_path() {
         // Pure validation logic — no key needed.
@@ -0,0 +1,145 @@
+//! The **syndication scheduler** — releases `Scheduled` jobs when due.
+//!
+//! Stage 1 writes a job whose timing resolves to the future as
+//! `Scheduled`, with `dispatch_shard` / `next_attempt_at` placing it in the
+//! sparse `find_due_jobs` GSI and `queue_owner` / `scheduled_at` placing it
+//! in the author's queue (`find_queued_by_author`). A tick walks each shard
+//! oldest-first, stops at the first job that isn't due, and flips due jobs
+//! to `Pending` — from there the Stage 2 Pipe dispatches them exactly like
+//! an immediate post, with the same inline retry and manual Retry CTA.
+//!
+//! Cancelling from the queue goes through the same conditional
+//! [`release_scheduled`], so a cancel racing a tick resolves to whichever
+//! lands first and the other is a no-op.
+//!
+//! Triggered every minute — by an EventBridge schedule in deployed envs
+//! (`DetailType::SyndicationScheduleTick`) and by
+//! [`spawn_syndication_scheduler`] under `local-dev`.
+
+use crate::common::*;
+use crate::features::cross_posting::models::{JobState, SHARD_COUNT, SyndicationJob};
+use crate::features::cross_posting::services::dispatcher::{job_state_str, table_name};
+use crate::features::cross_posting::types::CrossPostingError;
+use aws_sdk_dynamodb::types::AttributeValue as AV;
+
+const SCHEDULER_PAGE_SIZE: i32 = 50;
+/// Upper bound on jobs released per tick across all shards. Releasing is a
+/// single write, but each one fans out to a Stage 2 dispatch.
+const MAX_RELEASES_PER_TICK: usize = 500;
+
+/// One pass over every shard. Returns how many jobs were released.
+pub async fn run_syndication_scheduler(
+    cli: &aws_sdk_dynamodb::Client,
+    now_ms: i64,
+) -> Result<usize> {
+    let now_secs = now_ms / 1000;
+    let mut released = 0;
+
+    for n in 0..SHARD_COUNT {
+        let shard_key = format!("SDS#{n}");
+        let mut bookmark: Option<String> = None;
+
+        'pages: loop {
+            let opt = SyndicationJob::opt_with_bookmark(bookmark.take())
+                .scan_index_forward(true)
+                .limit(SCHEDULER_PAGE_SIZE);
+            let (jobs, next) = SyndicationJob::find_due_jobs(cli, &shard_key, opt).await?;
+
+            for job in jobs {
+                if job.next_attempt_at > now_secs || released >= MAX_RELEASES_PER_TICK {
+                    break 'pages;
+                }
+                match release_scheduled(cli, &job, JobState::Pending, now_ms).await {
+                    Ok(true) => released += 1,
+                    // Cancelled (or already released) since the query.
+                    Ok(false) => {}
+                    Err(e) => {
+                        crate::error!("syndication scheduler: {}/{} failed: {e}", job.pk, job.sk)
+                    }
+                }
+            }
+
+            match next {
+                Some(b) => bookmark = Some(b),
+                None => break,
+            }
+        }
+    }
+
+    if released > 0 {
+        tracing::info!(released, "syndication scheduler tick");
+    }
+    Ok(released)
+}
+
+/// Move a `Scheduled` job to `to` (`Pending` to send it, `Cancelled` to
+/// drop it) and take it out of the due-jobs and queue indexes. Returns
+/// `false` when the job was no longer `Scheduled`.
+pub async fn release_scheduled(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+    to: JobState,
+    now_ms: i64,
+) -> Result<bool> {
+    let resp = cli
+        .update_item()
+        .table_name(table_name())
+        .key("pk", AV::S(job.pk.to_string()))
+        .key("sk", AV::S(job.sk.to_string()))
+        .update_expression(
+            "SET #state = :to, updated_at = :now \
+             REMOVE dispatch_shard, gsi1_pk, gsi1_sk, queue_owner, gsi3_pk, gsi3_sk",
+        )
+        .condition_expression("#state = :scheduled")
+        .expression_attribute_names("#state", "state")
+        .expression_attribute_values(":to", AV::S(job_state_str(to).into()))
+        .expression_attribute_values(
+            ":scheduled",
+            AV::S(job_state_str(JobState::Scheduled).into()),
+        )
+        .expression_attribute_values(":now", AV::N(now_ms.to_string()))
+        .send()
+        .await;
+
+    match resp {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let svc = e.into_service_error();
+            if matches!(
+                svc,
+                aws_sdk_dynamodb::operation::update_item::UpdateItemError::ConditionalCheckFailedException(_)
+            ) {
+                return Ok(false);
+            }
+            tracing::error!(error = %svc, "scheduler: release_scheduled failed");
+            Err(CrossPostingError::ScheduleReleaseFailed.into())
+        }
+    }
+}
+
+/// Local-dev stand-in for the EventBridge schedule: a dedicated thread
+/// running [`run_syndication_scheduler`] every minute.
+#[cfg(feature = "local-dev")]
+pub fn spawn_syndication_scheduler() {
+    std::thread::Builder::new()
+        .name("syndication-scheduler".into())
+        .spawn(|| {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("syndication-scheduler runtime");
+            rt.block_on(async {
+                let cfg = crate::common::CommonConfig::default();
+                let cli = cfg.dynamodb();
+                let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+                loop {
+                    interval.tick().await;
+                    let now = crate::common::utils::time::get_now_timestamp_millis();
+                    if let Err(e) = run_syndication_scheduler(cli, now).await {
+                        tracing::error!("syndication scheduler tick failed: {e}");
+                    }
+                }
+            });
+        })
+        .expect("failed to spawn syndication-scheduler thread");
+}
@@ -0,0 +1,463 @@
+//! Space cards — cross-posts that announce a space rather than a post.
+//!
+//! Two card kinds, each one [`SyndicationJob`] per platform under the
+//! space's `Space` pk (see [`SyndicationJob::sk_for`]):
+//!
+//! - **`SpaceLive`** — a "vote now" post when a space with a poll goes
+//!   Ongoing.
+//! - **`SpaceResults`** — a results card when the space finishes: title,
+//!   participation count and the top poll results, drawn server-side into a
+//!   chart image (`card_render`) from each poll's `SpacePollResult`.
+//!
+//! [`handle_space_status_change_for_syndication`] is the space equivalent of
+//! the Stage 1 factory and rides on the `SpaceStatusChangeEvent` stream
+//! record. Jobs go out immediately (no best-time scheduling) through the
+//! regular Stage 2 dispatcher, which calls [`build_space_card`] in place of
+//! formatting a post. Only public, published, user-owned spaces qualify, and
+//! only to connections with both auto-post and space cards on.
+
+use std::collections::HashMap;
+
+use crate::common::models::space::{SpaceCommon, SpaceStatusChangeEvent};
+use crate::common::utils::time;
+use crate::common::*;
+use crate::features::cross_posting::models::{
+    ConnectionStatus, JobSource, JobState, SocialConnection, SyndicationJob,
+};
+use crate::features::cross_posting::services::adapters::{ImageRef, LinkCard};
+use crate::features::cross_posting::services::card_render::{
+    self, MAX_CARD_OPTIONS, MAX_CARD_QUESTIONS, OptionResult, QuestionResult, ResultsCard,
+};
+use crate::features::cross_posting::services::format;
+use crate::features::cross_posting::types::CrossPostingError;
+use crate::features::posts::models::Post;
+use crate::features::spaces::pages::actions::actions::poll::{
+    Question, SpacePoll, SpacePollResult, SpacePollSummary, SpacePollUserAnswer,
+};
+
+/// What a space job publishes, ready for the adapter.
+pub struct SpaceCard {
+    pub body: String,
+    pub images: Vec<ImageRef>,
+    pub link_card: LinkCard,
+}
+
+/// Space-side Stage 1. Enqueues a `SpaceLive` card per eligible connection
+/// on Open → Ongoing and a `SpaceResults` card on Ongoing → Finished; other
+/// transitions are ignored. Replays are harmless — the conditional create
+/// refuses to overwrite an existing job.
+pub async fn handle_space_status_change_for_syndication(
+    event: SpaceStatusChangeEvent,
+) -> Result<()> {
+    let source = match (&event.old_status, &event.new_status) {
+        (Some(SpaceStatus::Open), SpaceStatus::Ongoing) => JobSource::SpaceLive,
+        (Some(SpaceStatus::Ongoing), SpaceStatus::Finished) => JobSource::SpaceResults,
+        _ => return Ok(()),
+    };
+
+    let cfg = crate::common::CommonConfig::default();
+    let cli = cfg.dynamodb();
+
+    let Some(space) =
+        SpaceCommon::get(cli, &event.space_pk, Some(&EntityType::SpaceCommon)).await?
+    else {
+        return Ok(());
+    };
+    if !is_shareable(&space) {
+        tracing::debug!(space_pk = %space.pk, "space cards: space not public — skipping");
+        return Ok(());
+    }
+    // Connections are per user; team-owned spaces have none to post from.
+    if !matches!(space.user_pk, Partition::User(_)) {
+        return Ok(());
+    }
+    // A "vote now" post only makes sense when there is something to vote on.
+    if source == JobSource::SpaceLive && list_polls(cli, &space.pk).await?.is_empty() {
+        return Ok(());
+    }
+
+    let sk_prefix = EntityType::SocialConnection(String::new()).to_string();
+    let opt = SocialConnection::opt_with_bookmark(None)
+        .sk(sk_prefix)
+        .limit(10);
+    let (connections, _next): (Vec<SocialConnection>, _) =
+        SocialConnection::query(cli, &space.user_pk, opt).await?;
+
+    let now = time::now();
+    let canonical_url = format!(
+        "{}/spaces/{}",
+        crate::common::config::site_base_url(),
+        space_id(&space.pk)
+    );
+    for connection in connections.iter().filter(|c| {
+        c.status == ConnectionStatus::Connected && c.auto_post_enabled && c.space_cards_enabled
+    }) {
+        let platform = connection.platform;
+        let job = SyndicationJob {
+            pk: space.pk.clone(),
+            sk: SyndicationJob::sk_for(platform, source),
+            author_user_id: space.user_pk.clone(),
+            platform,
+            source,
+            state: JobState::Pending,
+            backlink_url: format!("{canonical_url}?utm_source={platform}"),
+            created_at: now,
+            updated_at: now,
+            ..Default::default()
+        };
+        if let Err(e) = job.create(cli).await {
+            // Per-platform jobs are independent; a replayed event also
+            // lands here, since the row already exists.
+            tracing::warn!(
+                space_pk = %space.pk,
+                ?platform,
+                ?source,
+                error = %e,
+                "space cards: SyndicationJob create failed"
+            );
+        } else {
+            tracing::info!(space_pk = %space.pk, ?platform, ?source, "space cards: job created");
+        }
+    }
+    Ok(())
+}
+
+/// Build what `job` publishes. `None` when the space no longer qualifies —
+/// it went private, or has moved past the state the card announces — and
+/// the dispatcher marks the job Skipped.
+pub async fn build_space_card(
+    cli: &aws_sdk_dynamodb::Client,
+    job: &SyndicationJob,
+) -> Result<Option<SpaceCard>> {
+    let Some(space) = SpaceCommon::get(cli, &job.pk, Some(&EntityType::SpaceCommon)).await? else {
+        return Ok(None);
+    };
+    let expected = match job.source {
+        JobSource::SpaceLive => SpaceStatus::Ongoing,
+        _ => SpaceStatus::Finished,
+    };
+    if !is_shareable(&space) || space.status.as_ref() != Some(&expected) {
+        return Ok(None);
+    }
+    let Some(post) = Post::get(cli, &space.post_pk, Some(EntityType::Post)).await? else {
+        return Ok(None);
+    };
+
+    let limit = job.platform.char_limit();
+    let link_card = LinkCard {
+        backlink_url: job.backlink_url.clone(),
+        fallback_title: post.title.clone(),
+        fallback_description: post.body.to_plain_text().chars().take(200).collect(),
+        fallback_thumb_url: (!space.logo.is_empty())
+            .then(|| space.logo.clone())
+            .or_else(|| post.urls.first().cloned()),
+    };
+
+    if job.source == JobSource::SpaceLive {
+        return Ok(Some(SpaceCard {
+            body: format::truncate_override(live_body(&post.title), &job.backlink_url, limit),
+            images: Vec::new(),
+            link_card,
+        }));
+    }
+
+    let polls = list_polls(cli, &space.pk).await?;
+    let mut questions = Vec::new();
+    let mut respondents = 0;
+    for poll in &polls {
+        let result = poll_result(cli, &space.pk, poll).await?;
+        respondents = respondents.max(poll.user_response_count);
+        questions.extend(question_results(&poll.questions, &result));
+    }
+    questions.sort_by_key(|q| std::cmp::Reverse(q.total));
+    questions.truncate(MAX_CARD_QUESTIONS);
+
+    let card = ResultsCard {
+        title: post.title.clone(),
+        participants: space.participants.max(respondents),
+        questions,
+        site: site_host(),
+    };
+    let url = upload_card(&space.pk, &card).await?;
+    Ok(Some(SpaceCard {
+        body: format::truncate_override(results_body(&card), &job.backlink_url, limit),
+        images: vec![ImageRef {
+            url,
+            alt: Some(card.alt_text()),
+        }],
+        link_card,
+    }))
+}
+
+/// Top options of every question that can be charted — choice, checkbox,
+/// dropdown and linear-scale questions with at least one answer — in
+/// question order. Free-text questions are left off the card.
+pub fn question_results(questions: &[Question], result: &SpacePollResult) -> Vec<QuestionResult> {
+    questions
+        .iter()
+        .zip(&result.summaries)
+        .filter_map(|(question, summary)| {
+            let (total, mut options) = match (question, summary) {
+                (
+                    Question::SingleChoice(q) | Question::MultipleChoice(q),
+                    SpacePollSummary::SingleChoice {
+                        total_count,
+                        answers,
+                        ..
+                    }
+                    | SpacePollSummary::MultipleChoice {
+                        total_count,
+                        answers,
+                        ..
+                    },
+                ) => (*total_count, labelled(answers, &q.options)),
+                (
+                    Question::Checkbox(q),
+                    SpacePollSummary::Checkbox {
+                        total_count,
+                        answers,
+                    },
+                ) => (*total_count, labelled(answers, &q.options)),
+                (
+                    Question::Dropdown(q),
+                    SpacePollSummary::Dropdown {
+                        total_count,
+                        answers,
+                    },
+                ) => (*total_count, labelled(answers, &q.options)),
+                (
+                    Question::LinearScale(_),
+                    SpacePollSummary::LinearScale {
+                        total_count,
+                        answers,
+                    },
+                ) => {
+                    let options = answers
+                        .iter()
+                        .map(|(value, count)| OptionResult {
+                            label: value.to_string(),
+                            count: *count,
+                        })
+                        .collect();
+                    (*total_count, options)
+                }
+                _ => return None,
+            };
+            if total <= 0 {
+                return None;
+            }
+            options.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.label.cmp(&b.label)));
+            options.truncate(MAX_CARD_OPTIONS);
+            Some(QuestionResult {
+                title: question.title().to_string(),
+                total,
+                options,
+            })
+        })
+        .collect()
+}
+
+fn labelled(answers: &HashMap<i32, i64>, labels: &[String]) -> Vec<OptionResult> {
+    answers
+        .iter()
+        .filter_map(|(idx, count)| {
+            let label = labels.get(usize::try_from(*idx).ok()?)?;
+            Some(OptionResult {
+                label: label.clone(),
+                count: *count,
+            })
+        })
+        .collect()
+}
+
+/// Body of the "vote now" post, before the backlink is appended.
+pub fn live_body(title: &str) -> String {
+    format!("Voting is open: {title}\nAdd your voice before it closes.")
+}
+
+/// Body of the results post, before the backlink is appended. Leads with
+/// the winning option of the most-answered question.
+pub fn results_body(card: &ResultsCard) -> String {
+    let mut body = format!(
+        "Results are in: {}\n{} participants took part.",
+        card.title,
+        card_render::group_thousands(card.participants)
+    );
+    if let Some((q, top)) = card
+        .questions
+        .first()
+        .and_then(|q| q.options.first().map(|top| (q, top)))
+    {
+        body.push_str(&format!(
+            "\n{} → {} ({}%)",
+            q.title,
+            top.label,
+            q.percent(top)
+        ));
+    }
+    body
+}
+
+/// Draw the results card and store it where the platforms can fetch it.
+/// The key is fixed per space, so a retried job overwrites the same object.
+async fn upload_card(space_pk: &Partition, card: &ResultsCard) -> Result<String> {
+    let png = card_render::render_png(&card_render::results_card_svg(card))?;
+    let key = format!(
+        "cross-posting/space-cards/{}/results.png",
+        space_id(space_pk)
+    );
+    let s3 = crate::common::CommonConfig::default().s3();
+    s3.upload(&key, png, "image/png").await.map_err(|e| {
+        tracing::error!(error = %e, "space cards: card upload failed");
+        CrossPostingError::SpaceCardFailed
+    })?;
+    Ok(s3.get_url(&key))
+}
+
+async fn poll_result(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    poll: &SpacePoll,
+) -> Result<SpacePollResult> {
+    let (summaries, by_gender, by_age, by_school, samples, finals) =
+        SpacePollUserAnswer::summarize_responses_with_attribute(cli, space_pk, &poll.sk).await?;
+    Ok(SpacePollResult::new(
+        space_pk.clone(),
+        summaries,
+        by_gender,
+        by_age,
+        by_school,
+        samples,
+        finals,
+    ))
+}
+
+async fn list_polls(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+) -> Result<Vec<SpacePoll>> {
+    let prefix = EntityType::SpacePoll(String::new()).to_string();
+    let opt = SpacePoll::opt().sk(prefix).limit(20);
+    let (polls, _next) = SpacePoll::query(cli, space_pk.clone(), opt).await?;
+    Ok(polls)
+}
+
+fn is_shareable(space: &SpaceCommon) -> bool {
+    space.visibility == SpaceVisibility::Public
+        && space.publish_state == SpacePublishState::Published
+}
+
+fn space_id(pk: &Partition) -> String {
+    match pk {
+        Partition::Space(id) => id.clone(),
+        _ => pk.to_string(),
+    }
+}
+
+/// `https://ratel.foundation` → `ratel.foundation`, for the card footer.
+fn site_host() -> String {
+    let base = crate::common::config::site_base_url();
+    base.split_once("://")
+        .map_or(base, |(_, host)| host)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::features::spaces::pages::actions::actions::poll::{
+        ChoiceQuestion, SubjectiveQuestion,
+    };
+
+    fn result_of(summaries: Vec<SpacePollSummary>) -> SpacePollResult {
+        SpacePollResult {
+            summaries,
+            ..Default::default()
+        }
+    }
+
+    fn choice(title: &str, options: &[&str]) -> Question {
+        Question::SingleChoice(ChoiceQuestion {
+            title: title.into(),
+            options: options.iter().map(|o| o.to_string()).collect(),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn question_results_ranks_options_and_drops_free_text() {
+        let questions = vec![
+            choice("Which park?", &["North", "South", "East", "West"]),
+            Question::Subjective(SubjectiveQuestion {
+                title: "Why?".into(),
+                ..Default::default()
+            }),
+        ];
+        let result = result_of(vec![
+            SpacePollSummary::SingleChoice {
+                total_count: 10,
+                answers: HashMap::from([(0, 2), (1, 5), (2, 1), (3, 2), (9, 7)]),
+                other_answers: HashMap::new(),
+            },
+            SpacePollSummary::Subjective {
+                total_count: 4,
+                answers: HashMap::new(),
+            },
+        ]);
+
+        let out = question_results(&questions, &result);
+        assert_eq!(out.len(), 1);
+        assert_eq!(out[0].title, "Which park?");
+        assert_eq!(out[0].total, 10);
+        // Out-of-range index 9 is ignored; ties break alphabetically.
+        let labels: Vec<&str> = out[0].options.iter().map(|o| o.label.as_str()).collect();
+        assert_eq!(labels, ["South", "North", "West"]);
+    }
+
+    #[test]
+    fn question_results_skips_unanswered_and_mismatched_questions() {
+        let questions = vec![choice("a", &["x"]), choice("b", &["y"])];
+        let result = result_of(vec![
+            SpacePollSummary::SingleChoice {
+                total_count: 0,
+                answers: HashMap::new(),
+                other_answers: HashMap::new(),
+            },
+            SpacePollSummary::Checkbox {
+                total_count: 3,
+                answers: HashMap::from([(0, 3)]),
+            },
+        ]);
+        assert!(question_results(&questions, &result).is_empty());
+    }
+
+    #[test]
+    fn results_body_leads_with_the_top_answer() {
+        let card = ResultsCard {
+            title: "Budget vote".into(),
+            participants: 1204,
+            questions: vec![QuestionResult {
+                title: "Which park?".into(),
+                total: 200,
+                options: vec![OptionResult {
+                    label: "North".into(),
+                    count: 104,
+                }],
+            }],
+            site: String::new(),
+        };
+        assert_eq!(
+            results_body(&card),
+            "Results are in: Budget vote\n1,204 participants took part.\n\
+             Which park? → North (52%)"
+        );
+        let empty = ResultsCard {
+            questions: Vec::new(),
+            ..card
+        };
+        assert_eq!(
+            results_body(&empty),
+            "Results are in: Budget vote\n1,204 participants took part."
+        );
+    }
+}
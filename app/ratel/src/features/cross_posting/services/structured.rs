@@ -0,0 +1,776 @@
+//! Structured-content rendering for syndication.
+//!
+//! [`units`] walks a post's `ContentDocument` into the pieces one
+//! platform post is built from, keeping what `to_plain_text()` flattens:
+//!
+//! - list items keep their `•` / `1.` markers, nested ones indented two
+//!   spaces per level; to-dos get `☐` / `☑`;
+//! - code blocks keep their line breaks;
+//! - links keep their target — a link facet over the anchor text on
+//!   Bluesky, the URL spelled out after it elsewhere. Bare URLs get a
+//!   facet on Bluesky too, which links nothing by itself;
+//! - user and team mentions become the account's handle on the platform
+//!   when it has a connected `SocialConnection` there, with a mention
+//!   facet where the platform takes one, and its display name otherwise.
+//!
+//! [`super::format`] packs the units into `char_limit()`.
+
+use crate::common::types::{
+    Block, BlockKind, ContentBody, ContentDocument, InlineNode, MediaSource, Mention, RichText,
+};
+use crate::common::*;
+use crate::features::cross_posting::models::{ConnectionStatus, SocialConnection};
+use crate::features::cross_posting::services::adapters::{
+    Facet, FacetFeature, FormattedBody, ImageRef,
+};
+use crate::features::cross_posting::services::approval;
+use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::posts::models::Team;
+use std::collections::HashMap;
+
+const LINKEDIN_ORG_PREFIX: &str = "urn:li:organization:";
+
+/// One piece of a rendered document, in reading order.
+#[derive(Debug, Clone)]
+pub enum Unit {
+    Text(TextUnit),
+    /// An image block. Formatting attaches it to the post rather than
+    /// printing it.
+    Image(ImageRef),
+}
+
+/// One block's text. Consecutive list items are joined with a line
+/// break, everything else with a blank line — see [`separator`].
+#[derive(Debug, Clone)]
+pub struct TextUnit {
+    pub body: FormattedBody,
+    pub list_item: bool,
+}
+
+impl TextUnit {
+    pub fn plain(text: impl Into<FormattedBody>) -> Self {
+        Self {
+            body: text.into(),
+            list_item: false,
+        }
+    }
+}
+
+pub fn separator(prev_list_item: bool, list_item: bool) -> &'static str {
+    if prev_list_item && list_item {
+        "\n"
+    } else {
+        "\n\n"
+    }
+}
+
+/// All of `units` as one body.
+pub fn join(units: &[TextUnit]) -> FormattedBody {
+    let mut body = FormattedBody::default();
+    let mut prev: Option<bool> = None;
+    for unit in units {
+        if let Some(prev) = prev {
+            body.push_str(separator(prev, unit.list_item));
+        }
+        body.append(unit.body.clone());
+        prev = Some(unit.list_item);
+    }
+    body
+}
+
+/// Render `doc` for `platform`. Blocks without text (dividers, custom
+/// blocks) produce nothing.
+pub fn units(doc: &ContentDocument, platform: SocialPlatform, mentions: &MentionMap) -> Vec<Unit> {
+    let mut out = Vec::new();
+    Renderer { platform, mentions }.blocks(&doc.blocks, 0, &mut out);
+    out
+}
+
+/// How each mentioned user or team is written on one platform, keyed by
+/// `Partition::to_string()`. Mentions missing from the map render as
+/// nothing, as they do in `to_plain_text()`.
+#[derive(Debug, Clone, Default)]
+pub struct MentionMap(HashMap<String, MentionTarget>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MentionTarget {
+    pub text: String,
+    /// Platform account id for a mention facet. `None` when the platform
+    /// links `text` by itself, or `text` is just a display name.
+    pub account_id: Option<String>,
+}
+
+impl MentionMap {
+    /// Display names first, then every connected account on `platform`
+    /// over them.
+    pub fn build(
+        platform: SocialPlatform,
+        names: HashMap<String, String>,
+        connections: &[SocialConnection],
+    ) -> Self {
+        let mut map: HashMap<String, MentionTarget> = names
+            .into_iter()
+            .filter(|(_, name)| !name.trim().is_empty())
+            .map(|(pk, name)| {
+                let target = MentionTarget {
+                    text: name,
+                    account_id: None,
+                };
+                (pk, target)
+            })
+            .collect();
+        for connection in connections {
+            if connection.platform != platform {
+                continue;
+            }
+            if let Some(target) = handle_for(connection) {
+                map.insert(connection.pk.to_string(), target);
+            }
+        }
+        Self(map)
+    }
+
+    fn get(&self, mention: &Mention) -> Option<&MentionTarget> {
+        self.0.get(&mention_pk(mention)?.to_string())
+    }
+}
+
+/// Look up everyone `body` mentions: their `platform` connections and
+/// their display names. Lookup failures only cost the handles — the
+/// post still goes out.
+pub async fn resolve_mentions(
+    cli: &aws_sdk_dynamodb::Client,
+    body: &ContentBody,
+    platform: SocialPlatform,
+) -> MentionMap {
+    let ContentBody::StructuredContent(doc) = body else {
+        return MentionMap::default();
+    };
+    let pks = mentioned(doc);
+    if pks.is_empty() {
+        return MentionMap::default();
+    }
+
+    let sk = EntityType::SocialConnection(platform.to_string());
+    let keys = pks.iter().map(|pk| (pk.clone(), sk.clone())).collect();
+    let connections = SocialConnection::batch_get(cli, keys)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "structured: mention connection lookup failed");
+            Vec::new()
+        });
+
+    let (users, teams): (Vec<Partition>, Vec<Partition>) = pks
+        .into_iter()
+        .partition(|pk| matches!(pk, Partition::User(_)));
+    let mut names = approval::display_names(cli, users).await;
+    let team_keys = teams.into_iter().map(|pk| (pk, EntityType::Team)).collect();
+    let teams = Team::batch_get(cli, team_keys).await.unwrap_or_default();
+    names.extend(
+        teams
+            .into_iter()
+            .map(|t| (t.pk.to_string(), t.display_name)),
+    );
+
+    MentionMap::build(platform, names, &connections)
+}
+
+/// How `connection`'s account is mentioned on its own platform, or
+/// `None` when it can't be.
+fn handle_for(connection: &SocialConnection) -> Option<MentionTarget> {
+    if connection.status != ConnectionStatus::Connected {
+        return None;
+    }
+    let handle = connection.external_handle.trim_start_matches('@');
+    let id = &connection.external_user_id;
+    match connection.platform {
+        SocialPlatform::Bluesky => Some(MentionTarget {
+            text: format!("@{handle}"),
+            account_id: Some(id.clone()),
+        }),
+        // Mastodon handles are stored `user@host`, so this is the fully
+        // qualified `@user@host` other instances resolve. Threads falls
+        // back to the numeric id when no username came back — not a
+        // handle.
+        SocialPlatform::Mastodon | SocialPlatform::Threads
+            if !handle.is_empty() && handle != id.as_str() =>
+        {
+            Some(MentionTarget {
+                text: format!("@{handle}"),
+                account_id: None,
+            })
+        }
+        // Company pages are tagged by URN under their page name. Members
+        // have no public handle to write.
+        SocialPlatform::LinkedIn if id.starts_with(LINKEDIN_ORG_PREFIX) => Some(MentionTarget {
+            text: connection.external_handle.clone(),
+            account_id: Some(id.clone()),
+        }),
+        _ => None,
+    }
+}
+
+/// Every user and team `doc` mentions, once each.
+fn mentioned(doc: &ContentDocument) -> Vec<Partition> {
+    fn walk(blocks: &[Block], out: &mut Vec<Partition>) {
+        for block in blocks {
+            for rich in block_rich_texts(&block.kind) {
+                for node in &rich.0 {
+                    if let InlineNode::Mention(m) = node {
+                        if let Some(pk) = mention_pk(m) {
+                            if !out.contains(&pk) {
+                                out.push(pk);
+                            }
+                        }
+                    }
+                }
+            }
+            walk(&block.children, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&doc.blocks, &mut out);
+    out
+}
+
+fn block_rich_texts(kind: &BlockKind) -> Vec<&RichText> {
+    match kind {
+        BlockKind::Paragraph(t)
+        | BlockKind::Quote(t)
+        | BlockKind::BulletedListItem(t)
+        | BlockKind::NumberedListItem(t)
+        | BlockKind::Toggle(t) => vec![&t.rich_text],
+        BlockKind::Heading(h) => vec![&h.rich_text],
+        BlockKind::Todo(t) => vec![&t.rich_text],
+        BlockKind::Callout(c) => vec![&c.rich_text],
+        BlockKind::Bookmark(b) => vec![&b.caption],
+        BlockKind::Video(m) | BlockKind::File(m) => vec![&m.caption],
+        _ => Vec::new(),
+    }
+}
+
+/// The editor stores either the bare id or the full `USER#…` / `TEAM#…`
+/// key.
+fn mention_pk(mention: &Mention) -> Option<Partition> {
+    let (id, wrap): (&String, fn(String) -> Partition) = match mention {
+        Mention::User(id) => (id, Partition::User),
+        Mention::Team(id) => (id, Partition::Team),
+        _ => return None,
+    };
+    if id.contains('#') {
+        id.parse().ok()
+    } else {
+        Some(wrap(id.clone()))
+    }
+}
+
+struct Renderer<'a> {
+    platform: SocialPlatform,
+    mentions: &'a MentionMap,
+}
+
+impl Renderer<'_> {
+    /// `depth` counts list-item ancestors, for indenting nested items.
+    /// Numbering restarts whenever a numbered run is interrupted.
+    fn blocks(&self, blocks: &[Block], depth: usize, out: &mut Vec<Unit>) {
+        let indent = "  ".repeat(depth);
+        let mut number = 0;
+        for block in blocks {
+            number = match block.kind {
+                BlockKind::NumberedListItem(_) => number + 1,
+                _ => 0,
+            };
+            let (body, list_item) = match &block.kind {
+                BlockKind::Paragraph(t) | BlockKind::Quote(t) | BlockKind::Toggle(t) => {
+                    (self.inline(&t.rich_text), false)
+                }
+                BlockKind::Heading(h) => (self.inline(&h.rich_text), false),
+                BlockKind::Callout(c) => (self.inline(&c.rich_text), false),
+                BlockKind::BulletedListItem(t) => {
+                    (self.marked(&format!("{indent}• "), &t.rich_text), true)
+                }
+                BlockKind::NumberedListItem(t) => (
+                    self.marked(&format!("{indent}{number}. "), &t.rich_text),
+                    true,
+                ),
+                BlockKind::Todo(t) => {
+                    let mark = if t.checked { '☑' } else { '☐' };
+                    (self.marked(&format!("{indent}{mark} "), &t.rich_text), true)
+                }
+                BlockKind::Code(c) => (code_text(&c.rich_text).into(), false),
+                BlockKind::Equation(e) => (e.expression.trim().into(), false),
+                BlockKind::Bookmark(b) => {
+                    let mut body = self.inline(&b.caption);
+                    if !b.url.is_empty() {
+                        if !body.text.is_empty() {
+                            body.push_str(" ");
+                        }
+                        self.url(&mut body, &b.url);
+                    }
+                    (body, false)
+                }
+                BlockKind::Embed(e) => {
+                    let mut body = FormattedBody::default();
+                    self.url(&mut body, &e.url);
+                    (body, false)
+                }
+                BlockKind::Image(m) => {
+                    if let MediaSource::External { url } = &m.source {
+                        let image = ImageRef::from_s3(url).with_alt(m.alt.clone());
+                        out.push(Unit::Image(image));
+                    }
+                    (FormattedBody::default(), false)
+                }
+                BlockKind::Video(m) | BlockKind::File(m) => (self.inline(&m.caption), false),
+                BlockKind::Divider | BlockKind::Custom(_) | BlockKind::Unknown => {
+                    (FormattedBody::default(), false)
+                }
+            };
+            if !body.text.is_empty() {
+                out.push(Unit::Text(TextUnit { body, list_item }));
+            }
+            let child_depth = if list_item { depth + 1 } else { depth };
+            self.blocks(&block.children, child_depth, out);
+        }
+    }
+
+    /// `rich` behind a list marker; nothing when the item has no text.
+    fn marked(&self, marker: &str, rich: &RichText) -> FormattedBody {
+        let text = self.inline(rich);
+        if text.text.is_empty() {
+            return text;
+        }
+        let mut body = FormattedBody::from(marker);
+        body.append(text);
+        body
+    }
+
+    /// Inline runs with whitespace collapsed the way `to_plain_text()`
+    /// does it.
+    fn inline(&self, rich: &RichText) -> FormattedBody {
+        let mut body = FormattedBody::default();
+        for node in &rich.0 {
+            match node {
+                InlineNode::Text(run) => match &run.link {
+                    Some(url) => self.link(&mut body, &run.content, url),
+                    None => self.plain(&mut body, &run.content),
+                },
+                InlineNode::Mention(mention) => self.mention(&mut body, mention),
+                InlineNode::Equation(e) => push_collapsed(&mut body, &e.expression),
+            }
+        }
+        if body.text.ends_with(' ') {
+            body.truncate(body.text.len() - 1);
+        }
+        body
+    }
+
+    fn plain(&self, body: &mut FormattedBody, text: &str) {
+        if self.platform != SocialPlatform::Bluesky {
+            return push_collapsed(body, text);
+        }
+        let mut rest = text;
+        while let Some(start) = url_start(rest) {
+            push_collapsed(body, &rest[..start]);
+            let end = rest[start..]
+                .find(char::is_whitespace)
+                .map_or(rest.len(), |len| start + len);
+            let url = rest[start..end].trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '"']);
+            push_span(body, url, FacetFeature::Link(url.to_string()));
+            rest = &rest[start + url.len()..];
+        }
+        push_collapsed(body, rest);
+    }
+
+    fn link(&self, body: &mut FormattedBody, anchor: &str, url: &str) {
+        let trimmed = anchor.trim();
+        if trimmed.is_empty() {
+            self.url(body, url);
+        } else if self.platform == SocialPlatform::Bluesky {
+            push_span(body, anchor, FacetFeature::Link(url.to_string()));
+        } else if trimmed == url {
+            push_collapsed(body, anchor);
+        } else {
+            // `replacen` keeps the run's own spacing around the pair.
+            push_collapsed(
+                body,
+                &anchor.replacen(trimmed, &format!("{trimmed} ({url})"), 1),
+            );
+        }
+    }
+
+    /// A URL written out on its own — linked by a facet on Bluesky, by
+    /// the platform elsewhere.
+    fn url(&self, body: &mut FormattedBody, url: &str) {
+        if self.platform == SocialPlatform::Bluesky {
+            push_span(body, url, FacetFeature::Link(url.to_string()));
+        } else {
+            push_collapsed(body, url);
+        }
+    }
+
+    fn mention(&self, body: &mut FormattedBody, mention: &Mention) {
+        match mention {
+            Mention::User(_) | Mention::Team(_) => {
+                let Some(target) = self.mentions.get(mention) else {
+                    return;
+                };
+                match &target.account_id {
+                    Some(id) => push_span(body, &target.text, FacetFeature::Mention(id.clone())),
+                    None => push_collapsed(body, &target.text),
+                }
+            }
+            Mention::Url(url) => self.url(body, url),
+            Mention::Date { iso, end } => {
+                push_collapsed(body, iso);
+                if let Some(end) = end {
+                    push_collapsed(body, &format!(" – {end}"));
+                }
+            }
+            Mention::Space(_) | Mention::Post(_) => {}
+        }
+    }
+}
+
+/// Append `text` with whitespace runs collapsed to one space, dropping
+/// any that would lead the body or double a space already there.
+fn push_collapsed(body: &mut FormattedBody, text: &str) {
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            if !body.text.is_empty() && !body.text.ends_with(' ') {
+                body.text.push(' ');
+            }
+        } else {
+            body.text.push(ch);
+        }
+    }
+}
+
+/// [`push_collapsed`] with `feature` over the text, not the whitespace
+/// around it.
+fn push_span(body: &mut FormattedBody, text: &str, feature: FacetFeature) {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return push_collapsed(body, text);
+    }
+    if text.starts_with(char::is_whitespace) {
+        push_collapsed(body, " ");
+    }
+    let byte_start = body.text.len();
+    push_collapsed(body, trimmed);
+    body.facets.push(Facet {
+        byte_start,
+        byte_end: body.text.len(),
+        feature,
+    });
+    if text.ends_with(char::is_whitespace) {
+        push_collapsed(body, " ");
+    }
+}
+
+fn url_start(text: &str) -> Option<usize> {
+    ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| text.find(scheme))
+        .min()
+}
+
+/// Code keeps its line breaks and indentation; only the blank lines
+/// around it go.
+fn code_text(rich: &RichText) -> String {
+    let mut out = String::new();
+    for node in &rich.0 {
+        match node {
+            InlineNode::Text(run) => out.push_str(&run.content),
+            InlineNode::Equation(e) => out.push_str(&e.expression),
+            InlineNode::Mention(_) => {}
+        }
+    }
+    out.trim_matches('\n').trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::types::{
+        CodeBlock, HeadingBlock, HeadingLevel, TextBlock, TextRun, TodoBlock,
+    };
+
+    fn block(kind: BlockKind, children: Vec<Block>) -> Block {
+        Block {
+            id: String::new(),
+            kind,
+            children,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn text(content: &str) -> InlineNode {
+        InlineNode::Text(TextRun {
+            content: content.to_string(),
+            annotations: Default::default(),
+            link: None,
+        })
+    }
+
+    fn link(content: &str, url: &str) -> InlineNode {
+        InlineNode::Text(TextRun {
+            content: content.to_string(),
+            annotations: Default::default(),
+            link: Some(url.to_string()),
+        })
+    }
+
+    fn text_block(nodes: Vec<InlineNode>) -> TextBlock {
+        TextBlock {
+            rich_text: RichText(nodes),
+            color: Default::default(),
+        }
+    }
+
+    fn doc(blocks: Vec<Block>) -> ContentDocument {
+        ContentDocument {
+            schema_version: 1,
+            blocks,
+            meta: Default::default(),
+        }
+    }
+
+    fn render(
+        doc: &ContentDocument,
+        platform: SocialPlatform,
+        mentions: &MentionMap,
+    ) -> FormattedBody {
+        let text: Vec<TextUnit> = units(doc, platform, mentions)
+            .into_iter()
+            .filter_map(|u| match u {
+                Unit::Text(t) => Some(t),
+                Unit::Image(_) => None,
+            })
+            .collect();
+        join(&text)
+    }
+
+    fn spans(body: &FormattedBody) -> Vec<(&str, FacetFeature)> {
+        body.facets
+            .iter()
+            .map(|f| (&body.text[f.byte_start..f.byte_end], f.feature.clone()))
+            .collect()
+    }
+
+    fn connection(
+        pk: Partition,
+        platform: SocialPlatform,
+        handle: &str,
+        id: &str,
+    ) -> SocialConnection {
+        SocialConnection {
+            pk,
+            platform,
+            status: ConnectionStatus::Connected,
+            external_handle: handle.to_string(),
+            external_user_id: id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lists_keep_markers_numbering_and_nesting() {
+        let numbered = |s: &str| {
+            block(
+                BlockKind::NumberedListItem(text_block(vec![text(s)])),
+                vec![],
+            )
+        };
+        let doc = doc(vec![
+            block(
+                BlockKind::Heading(HeadingBlock {
+                    level: HeadingLevel::H2,
+                    rich_text: RichText(vec![text("Agenda")]),
+                    toggleable: false,
+                }),
+                vec![],
+            ),
+            block(
+                BlockKind::BulletedListItem(text_block(vec![text("Budget")])),
+                vec![block(
+                    BlockKind::BulletedListItem(text_block(vec![text("Q3  review")])),
+                    vec![],
+                )],
+            ),
+            numbered("First"),
+            numbered("Second"),
+            block(
+                BlockKind::Todo(TodoBlock {
+                    rich_text: RichText(vec![text("Ship")]),
+                    checked: true,
+                }),
+                vec![],
+            ),
+            block(BlockKind::Divider, vec![]),
+            numbered("Again"),
+        ]);
+
+        let body = render(&doc, SocialPlatform::Mastodon, &MentionMap::default());
+        assert_eq!(
+            body.text,
+            "Agenda\n\n• Budget\n  • Q3 review\n1. First\n2. Second\n☑ Ship\n1. Again"
+        );
+    }
+
+    #[test]
+    fn code_blocks_keep_their_lines() {
+        let doc = doc(vec![block(
+            BlockKind::Code(CodeBlock {
+                rich_text: RichText(vec![text("\nfn main() {\n    run();\n}\n\n")]),
+                language: "rust".into(),
+                caption: RichText::default(),
+            }),
+            vec![],
+        )]);
+        let body = render(&doc, SocialPlatform::Threads, &MentionMap::default());
+        assert_eq!(body.text, "fn main() {\n    run();\n}");
+    }
+
+    #[test]
+    fn bluesky_links_become_facets_with_utf8_offsets() {
+        let doc = doc(vec![block(
+            BlockKind::Paragraph(text_block(vec![
+                text("안녕 see "),
+                link(" the docs ", "https://docs.example"),
+                text("or https://r.example/a."),
+            ])),
+            vec![],
+        )]);
+        let body = render(&doc, SocialPlatform::Bluesky, &MentionMap::default());
+
+        assert_eq!(body.text, "안녕 see the docs or https://r.example/a.");
+        assert_eq!(
+            spans(&body),
+            vec![
+                (
+                    "the docs",
+                    FacetFeature::Link("https://docs.example".into())
+                ),
+                (
+                    "https://r.example/a",
+                    FacetFeature::Link("https://r.example/a".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn other_platforms_spell_links_out() {
+        let doc = doc(vec![block(
+            BlockKind::Paragraph(text_block(vec![
+                link("the docs", "https://docs.example"),
+                text(" and "),
+                link("https://r.example", "https://r.example"),
+            ])),
+            vec![],
+        )]);
+        let body = render(&doc, SocialPlatform::LinkedIn, &MentionMap::default());
+        assert_eq!(
+            body.text,
+            "the docs (https://docs.example) and https://r.example"
+        );
+        assert!(body.facets.is_empty());
+    }
+
+    #[test]
+    fn mentions_use_connected_handles_then_display_names() {
+        let alice = Partition::User("alice".into());
+        let bob = Partition::User("bob".into());
+        let ratel = Partition::Team("ratel".into());
+        let names = HashMap::from([
+            (alice.to_string(), "Alice".to_string()),
+            (bob.to_string(), "Bob".to_string()),
+            (ratel.to_string(), "Ratel".to_string()),
+        ]);
+        let connections = vec![
+            connection(
+                alice.clone(),
+                SocialPlatform::Bluesky,
+                "alice.bsky.social",
+                "did:plc:alice",
+            ),
+            connection(
+                alice.clone(),
+                SocialPlatform::Mastodon,
+                "alice@m.example",
+                "1@m.example",
+            ),
+            connection(
+                ratel.clone(),
+                SocialPlatform::LinkedIn,
+                "Ratel Inc",
+                "urn:li:organization:7",
+            ),
+        ];
+        let doc = doc(vec![block(
+            BlockKind::Paragraph(text_block(vec![
+                text("Thanks "),
+                InlineNode::Mention(Mention::User("alice".into())),
+                text(", "),
+                InlineNode::Mention(Mention::User(bob.to_string())),
+                text(" and "),
+                InlineNode::Mention(Mention::Team("ratel".into())),
+                text(" "),
+                InlineNode::Mention(Mention::User("ghost".into())),
+            ])),
+            vec![],
+        )]);
+
+        let for_platform = |platform| {
+            let mentions = MentionMap::build(platform, names.clone(), &connections);
+            render(&doc, platform, &mentions)
+        };
+
+        let bsky = for_platform(SocialPlatform::Bluesky);
+        assert_eq!(bsky.text, "Thanks @alice.bsky.social, Bob and Ratel");
+        assert_eq!(
+            spans(&bsky),
+            vec![(
+                "@alice.bsky.social",
+                FacetFeature::Mention("did:plc:alice".into())
+            )]
+        );
+
+        let mastodon = for_platform(SocialPlatform::Mastodon);
+        assert_eq!(mastodon.text, "Thanks @alice@m.example, Bob and Ratel");
+        assert!(mastodon.facets.is_empty());
+
+        let linkedin = for_platform(SocialPlatform::LinkedIn);
+        assert_eq!(linkedin.text, "Thanks Alice, Bob and Ratel Inc");
+        assert_eq!(
+            spans(&linkedin),
+            vec![(
+                "Ratel Inc",
+                FacetFeature::Mention("urn:li:organization:7".into())
+            )]
+        );
+    }
+
+    #[test]
+    fn disconnected_and_handleless_accounts_fall_back_to_names() {
+        let alice = Partition::User("alice".into());
+        let names = HashMap::from([(alice.to_string(), "Alice".to_string())]);
+        let mut revoked = connection(
+            alice.clone(),
+            SocialPlatform::Bluesky,
+            "alice.bsky.social",
+            "did:plc:a",
+        );
+        revoked.status = ConnectionStatus::Revoked;
+        let threads = connection(alice.clone(), SocialPlatform::Threads, "1789", "1789");
+
+        let bsky = MentionMap::build(SocialPlatform::Bluesky, names.clone(), &[revoked]);
+        let threads = MentionMap::build(SocialPlatform::Threads, names, &[threads]);
+        let mention = Mention::User("alice".into());
+        assert_eq!(bsky.get(&mention).unwrap().text, "Alice");
+        assert_eq!(threads.get(&mention).unwrap().text, "Alice");
+    }
+}
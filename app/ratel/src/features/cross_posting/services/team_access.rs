@@ -0,0 +1,62 @@
+//! Who may use, manage and review a team's social connections.
+//!
+//! - **Use** — publish a team post through the team's connections — needs
+//!   `TeamGroupPermission::CrossPost`, granted by the Owner / Admin roles
+//!   or by any of the member's `UserTeamGroup` rows on the team. Groups
+//!   are how a team hands the bit to, say, its comms members without
+//!   making them admins.
+//! - **Manage** (connect, disconnect, approval toggle) and **review**
+//!   (approve / reject queued cross-posts) need `TeamEdit`, i.e. an admin.
+
+use crate::common::*;
+use crate::features::auth::UserTeamGroup;
+use crate::features::posts::models::{Post, Team};
+use crate::features::posts::types::{TeamGroupPermission, TeamGroupPermissions};
+
+/// Upper bound on group memberships read per check.
+const GROUP_LIMIT: i32 = 50;
+
+pub async fn can_cross_post(
+    cli: &aws_sdk_dynamodb::Client,
+    team_pk: &Partition,
+    user_pk: &Partition,
+) -> Result<bool> {
+    // Non-members get nothing, whatever rows a removed member left behind.
+    let Some(role) = Team::get_user_role(cli, team_pk, user_pk).await? else {
+        return Ok(false);
+    };
+    let from_role: TeamGroupPermissions = role.to_legacy_permissions().into();
+    if from_role.contains(TeamGroupPermission::CrossPost) {
+        return Ok(true);
+    }
+
+    let opt = UserTeamGroup::opt()
+        .sk(user_pk.to_string())
+        .limit(GROUP_LIMIT);
+    let (groups, _) = UserTeamGroup::find_by_team_pk(cli, team_pk.clone(), opt).await?;
+    let mask = groups
+        .iter()
+        .fold(0i64, |mask, g| mask | g.team_group_permissions);
+    Ok(TeamGroupPermissions::from(mask).contains(TeamGroupPermission::CrossPost))
+}
+
+pub async fn can_manage(
+    cli: &aws_sdk_dynamodb::Client,
+    team_pk: &Partition,
+    user_pk: &Partition,
+) -> Result<bool> {
+    Team::has_permission(cli, team_pk, user_pk, TeamGroupPermission::TeamEdit).await
+}
+
+/// Whether `user_pk` may see and act on `post`'s syndication jobs: its
+/// author, or — for a team post — anyone who can cross-post for the team.
+pub async fn can_view_post_jobs(
+    cli: &aws_sdk_dynamodb::Client,
+    post: &Post,
+    user_pk: &Partition,
+) -> Result<bool> {
+    match &post.user_pk {
+        pk @ Partition::Team(_) => can_cross_post(cli, pk, user_pk).await,
+        pk => Ok(pk == user_pk),
+    }
+}
@@ -44,6 +44,33 @@ pub enum CrossPostingError {
     )]
     LinkedInAuthFailed,
 
+    /// Threads rejected our `code` exchange or the long-lived token swap,
+    /// or the token couldn't be sealed. Surfaced via `?threads=error`.
+    #[error("threads auth failed")]
+    #[translate(
+        en = "Threads rejected the connection. Please try again.",
+        ko = "Threads 연결에 실패했습니다. 다시 시도해주세요."
+    )]
+    ThreadsAuthFailed,
+
+    /// The instance field didn't normalize to a public hostname (see
+    /// `adapters::normalize_instance`).
+    #[error("invalid mastodon instance")]
+    #[translate(
+        en = "Enter a valid Mastodon server, e.g. mastodon.social",
+        ko = "올바른 Mastodon 서버를 입력해주세요 (예: mastodon.social)"
+    )]
+    MastodonInvalidInstance,
+
+    /// The instance refused our app registration or `code` exchange, or
+    /// was unreachable. Surfaced via `?mastodon=error` from the callback.
+    #[error("mastodon auth failed")]
+    #[translate(
+        en = "The Mastodon server rejected the connection. Please try again.",
+        ko = "Mastodon 서버가 연결을 거부했습니다. 다시 시도해주세요."
+    )]
+    MastodonAuthFailed,
+
     #[error("threads requires instagram professional account")]
     #[translate(
         en = "To connect Threads, please switch to an Instagram Professional account.",
@@ -62,6 +89,37 @@ pub enum CrossPostingError {
     )]
     RetryNotAllowed,
 
+    /// Only a `Scheduled` job can be cancelled; by the time it is
+    /// `Pending` the dispatcher may already be publishing it.
+    #[error("cancel not allowed")]
+    #[translate(
+        en = "This cross-post is no longer scheduled and can't be cancelled",
+        ko = "이미 예약 상태가 아니어서 취소할 수 없습니다"
+    )]
+    CancelNotAllowed,
+
+    #[error("invalid best time")]
+    #[translate(en = "Enter a valid time of day", ko = "올바른 시간을 입력해주세요")]
+    InvalidBestTime,
+
+    /// The platform gives no read access to replies (see
+    /// `SocialPlatform::supports_reply_import`).
+    #[error("reply import unsupported")]
+    #[translate(
+        en = "Importing replies isn't available for this platform",
+        ko = "이 플랫폼에서는 댓글 가져오기를 지원하지 않습니다"
+    )]
+    ReplyImportUnsupported,
+
+    /// The platform can't reply to its own posts (see
+    /// `SocialPlatform::supports_threads`).
+    #[error("thread mode unsupported")]
+    #[translate(
+        en = "Posting as a thread isn't available for this platform",
+        ko = "이 플랫폼에서는 스레드 게시를 지원하지 않습니다"
+    )]
+    ThreadModeUnsupported,
+
     #[error("not authorized")]
     #[translate(
         en = "You don't have permission to view this syndication panel",
@@ -69,6 +127,25 @@ pub enum CrossPostingError {
     )]
     NotAuthorized,
 
+    /// The caller lacks the team permission the action needs —
+    /// `TeamGroupPermission::CrossPost` to publish through a team
+    /// connection, `TeamEdit` to manage connections or review the queue.
+    #[error("team permission denied")]
+    #[translate(
+        en = "You don't have permission to use this team's social connections",
+        ko = "이 팀의 소셜 연결을 사용할 권한이 없습니다"
+    )]
+    TeamPermissionDenied,
+
+    /// Only an `AwaitingApproval` job can be approved or rejected; another
+    /// admin may have reviewed it first.
+    #[error("review not allowed")]
+    #[translate(
+        en = "This cross-post is no longer waiting for approval",
+        ko = "이미 승인 대기 상태가 아닌 외부 게시입니다"
+    )]
+    ReviewNotAllowed,
+
     /// Stage 2 dispatcher couldn't acquire / contend the per-job lock
     /// (unexpected DynamoDB error path — `ConditionalCheckFailedException`
     /// itself is handled as "lock held elsewhere", not surfaced here).
@@ -90,6 +167,36 @@ pub enum CrossPostingError {
         ko = "외부 게시 상태 저장에 실패했습니다"
     )]
     CommitFailed,
+
+    /// Stage 4 engagement refresh couldn't read counts / replies for a
+    /// job (decrypt, platform call or credential refresh). Server-only —
+    /// logged per job and the job is rescheduled.
+    #[error("engagement refresh failed")]
+    #[translate(
+        en = "Failed to refresh engagement",
+        ko = "참여 지표 갱신에 실패했습니다"
+    )]
+    EngagementRefreshFailed,
+
+    /// The syndication scheduler couldn't release a due `Scheduled` job
+    /// to `Pending` (unexpected DynamoDB error). Server-only — logged and
+    /// retried on the next tick.
+    #[error("schedule release failed")]
+    #[translate(
+        en = "Failed to release scheduled cross-post",
+        ko = "예약된 외부 게시를 발송하지 못했습니다"
+    )]
+    ScheduleReleaseFailed,
+
+    /// A space card couldn't be built (space / poll lookup, chart render
+    /// or upload). Server-only — the dispatcher keeps the lock and lets
+    /// the event be retried, as for a failed post lookup.
+    #[error("space card failed")]
+    #[translate(
+        en = "Failed to prepare the space card",
+        ko = "스페이스 카드를 준비하지 못했습니다"
+    )]
+    SpaceCardFailed,
 }
 
 #[cfg(feature = "server")]
@@ -100,17 +207,30 @@ impl CrossPostingError {
             CrossPostingError::ConnectionNotFound | CrossPostingError::SyndicationJobNotFound => {
                 StatusCode::NOT_FOUND
             }
-            CrossPostingError::NotAuthorized => StatusCode::FORBIDDEN,
+            CrossPostingError::NotAuthorized | CrossPostingError::TeamPermissionDenied => {
+                StatusCode::FORBIDDEN
+            }
             CrossPostingError::BlueskyAuthFailed
             | CrossPostingError::OAuthStateMismatch
             | CrossPostingError::LinkedInAuthFailed
+            | CrossPostingError::ThreadsAuthFailed
             | CrossPostingError::ThreadsRequiresInstagramProfessional
-            | CrossPostingError::RetryNotAllowed => StatusCode::BAD_REQUEST,
+            | CrossPostingError::MastodonInvalidInstance
+            | CrossPostingError::MastodonAuthFailed
+            | CrossPostingError::RetryNotAllowed
+            | CrossPostingError::CancelNotAllowed
+            | CrossPostingError::ReviewNotAllowed
+            | CrossPostingError::InvalidBestTime
+            | CrossPostingError::ReplyImportUnsupported
+            | CrossPostingError::ThreadModeUnsupported => StatusCode::BAD_REQUEST,
             CrossPostingError::ConnectFailed
             | CrossPostingError::ListFailed
             | CrossPostingError::UpdateFailed
             | CrossPostingError::DispatchLockFailed
-            | CrossPostingError::CommitFailed => StatusCode::INTERNAL_SERVER_ERROR,
+            | CrossPostingError::CommitFailed
+            | CrossPostingError::EngagementRefreshFailed
+            | CrossPostingError::ScheduleReleaseFailed
+            | CrossPostingError::SpaceCardFailed => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
@@ -1,9 +1,11 @@
 mod error;
 mod request;
 mod response;
+mod schedule;
 mod social_platform;
 
 pub use error::*;
 pub use request::*;
 pub use response::*;
+pub use schedule::*;
 pub use social_platform::*;
@@ -17,6 +17,37 @@ pub struct ToggleAutoPostRequest {
     pub auto_post_enabled: bool,
 }
 
+/// Body for `PATCH /api/cross-posting/connections/{platform}/reply-import`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToggleReplyImportRequest {
+    pub import_replies_enabled: bool,
+}
+
+/// Body for `PATCH /api/cross-posting/connections/{platform}/thread-mode`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToggleThreadModeRequest {
+    pub thread_mode_enabled: bool,
+}
+
+/// Body for `PATCH /api/cross-posting/connections/{platform}/space-cards`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToggleSpaceCardsRequest {
+    pub space_cards_enabled: bool,
+}
+
+/// Body for `PATCH /api/teams/{team_pk}/cross-posting/connections/{platform}/approval`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetApprovalRequiredRequest {
+    pub approval_required: bool,
+}
+
+/// Body for `PATCH /api/cross-posting/connections/{platform}/best-time`.
+/// `None` clears the best time, so `Auto` posts go out immediately again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetBestTimeRequest {
+    pub best_time: Option<super::PostingTime>,
+}
+
 /// Body for `POST /api/cross-posting/connections/linkedin/init`. The
 /// optional `return_to` is a same-origin SPA path the OAuth callback
 /// should bounce the user back to after a successful connection —
@@ -27,3 +58,22 @@ pub struct LinkedInOauthInitRequest {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub return_to: Option<String>,
 }
+
+/// Body for `POST /api/cross-posting/connections/threads/init`. Same
+/// `return_to` semantics as [`LinkedInOauthInitRequest`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThreadsOauthInitRequest {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_to: Option<String>,
+}
+
+/// Body for `POST /api/cross-posting/connections/mastodon/init`.
+/// `instance` is whatever the user typed — a domain, URL or
+/// `@user@domain` handle; the server normalizes it. Same `return_to`
+/// semantics as [`LinkedInOauthInitRequest`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MastodonOauthInitRequest {
+    pub instance: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_to: Option<String>,
+}
@@ -1,6 +1,8 @@
 use crate::common::*;
-use crate::features::cross_posting::models::{ConnectionStatus, ErrorCategory, JobState};
-use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::cross_posting::models::{
+    AuditAction, ConnectionStatus, ErrorCategory, JobState,
+};
+use crate::features::cross_posting::types::{PostingTime, SocialPlatform};
 
 /// Response shape for connection-listing / mutation endpoints. Excludes
 /// every credential-bearing field (FR-1 #6) — `credential_ciphertext`
@@ -12,6 +14,17 @@ pub struct ConnectionResponse {
     pub external_handle: String,
     pub external_user_id: String,
     pub auto_post_enabled: bool,
+    #[serde(default)]
+    pub import_replies_enabled: bool,
+    #[serde(default)]
+    pub thread_mode_enabled: bool,
+    #[serde(default)]
+    pub space_cards_enabled: bool,
+    /// Team connections only — cross-posts wait for an admin's approval.
+    #[serde(default)]
+    pub approval_required: bool,
+    #[serde(default)]
+    pub best_time: Option<PostingTime>,
     pub posts_syndicated_count: i64,
     pub last_synced_at: Option<i64>,
     pub created_at: i64,
@@ -27,6 +40,11 @@ impl From<crate::features::cross_posting::models::SocialConnection> for Connecti
             external_handle: c.external_handle,
             external_user_id: c.external_user_id,
             auto_post_enabled: c.auto_post_enabled,
+            import_replies_enabled: c.import_replies_enabled,
+            thread_mode_enabled: c.thread_mode_enabled,
+            space_cards_enabled: c.space_cards_enabled,
+            approval_required: c.approval_required,
+            best_time: c.best_time,
             posts_syndicated_count: c.posts_syndicated_count,
             last_synced_at: c.last_synced_at,
             created_at: c.created_at,
@@ -35,11 +53,26 @@ impl From<crate::features::cross_posting::models::SocialConnection> for Connecti
     }
 }
 
-/// Author-only post-detail syndication panel data (FR-7 #41–#45).
+/// Author-only post-detail syndication panel data (FR-7 #41–#45). For a
+/// team post, any member who can cross-post for the team sees it.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SyndicationPanelResponse {
     pub post_id: FeedPartition,
     pub jobs: Vec<SyndicationJobView>,
+    /// Who sent the post where, oldest first.
+    #[serde(default)]
+    pub audit: Vec<AuditEntryView>,
+}
+
+/// One `SyndicationAuditEntry` on the panel's history list.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AuditEntryView {
+    pub platform: SocialPlatform,
+    pub action: AuditAction,
+    /// Display name of the acting user; empty when the user is gone.
+    pub actor_name: String,
+    pub external_post_url: Option<String>,
+    pub created_at: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -55,8 +88,16 @@ pub struct SyndicationJobView {
     pub attempts: u8,
     /// Next scheduled retry time (epoch seconds). 0 when not scheduled.
     pub next_attempt_at: i64,
+    /// When a `Scheduled` job will be sent (epoch ms).
+    #[serde(default)]
+    pub scheduled_at: Option<i64>,
+    /// Number of platform posts a published job went out as — above 1
+    /// for thread-mode posts.
+    #[serde(default)]
+    pub thread_len: usize,
     /// Engagement counts (likes / comments / reposts) — populated when an
-    /// `EngagementSnapshot` row exists for this `(post, platform)`. None
+    /// `EngagementSnapshot` row exists for this `(post, platform)`, summed
+    /// across every post of a thread. None
     /// for `Pending` / `Failed` jobs (they were never published).
     pub engagement: Option<EngagementCountsView>,
 }
@@ -99,11 +140,153 @@ impl SyndicationJobView {
             last_error_message: job.last_error_message,
             attempts: job.attempts,
             next_attempt_at: job.next_attempt_at,
+            scheduled_at: job.scheduled_at,
+            thread_len: usize::from(job.external_post_id.is_some()) + job.thread_post_ids.len(),
             engagement,
         }
     }
 }
 
+/// Engagement and backlink visits summed over a set of cross-posts.
+/// `visits` counts readers who landed on Ratel through a backlink.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AnalyticsCounts {
+    pub likes: i64,
+    pub comments: i64,
+    pub reposts: i64,
+    pub visits: i64,
+}
+
+impl AnalyticsCounts {
+    /// Likes + comments + reposts.
+    pub fn engagement(&self) -> i64 {
+        self.likes + self.comments + self.reposts
+    }
+
+    pub fn add(&mut self, other: AnalyticsCounts) {
+        self.likes += other.likes;
+        self.comments += other.comments;
+        self.reposts += other.reposts;
+        self.visits += other.visits;
+    }
+}
+
+/// Cross-posting analytics dashboard data for the session user's posts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct CrossPostingAnalyticsResponse {
+    /// First and last day of the `daily` window, in days since the Unix
+    /// epoch (UTC). `to_day` is today.
+    pub from_day: i64,
+    pub to_day: i64,
+    /// All-time totals per network, most visits first.
+    pub platforms: Vec<PlatformAnalyticsView>,
+    /// Per-network totals for each day of the window, oldest first. Days
+    /// without activity are absent.
+    pub daily: Vec<DailyAnalyticsView>,
+    /// Per-post totals, most visits first.
+    pub posts: Vec<PostAnalyticsView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlatformAnalyticsView {
+    pub platform: SocialPlatform,
+    /// Number of posts with engagement or visits on this network.
+    pub posts: i64,
+    pub counts: AnalyticsCounts,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyAnalyticsView {
+    /// Days since the Unix epoch (UTC).
+    pub day: i64,
+    pub platform: SocialPlatform,
+    /// Engagement gained that day (negative when likes were withdrawn)
+    /// and visits that landed that day.
+    pub counts: AnalyticsCounts,
+}
+
+#[cfg(feature = "server")]
+impl From<crate::features::cross_posting::models::SyndicationDailyStats> for DailyAnalyticsView {
+    fn from(d: crate::features::cross_posting::models::SyndicationDailyStats) -> Self {
+        Self {
+            day: d.day,
+            platform: d.platform,
+            counts: AnalyticsCounts {
+                likes: d.likes,
+                comments: d.comments,
+                reposts: d.reposts,
+                visits: d.visits,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostAnalyticsView {
+    pub post_id: FeedPartition,
+    pub post_title: String,
+    pub counts: AnalyticsCounts,
+    /// The same totals split by network, in `SocialPlatform` order.
+    pub platforms: Vec<PlatformCountsView>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PlatformCountsView {
+    pub platform: SocialPlatform,
+    pub counts: AnalyticsCounts,
+}
+
+/// One upcoming cross-post in the author's queue (compose sidebar).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct QueuedJobResponse {
+    pub post_id: FeedPartition,
+    pub post_title: String,
+    pub platform: SocialPlatform,
+    /// Epoch ms.
+    pub scheduled_at: i64,
+}
+
+/// One cross-post waiting in a team's approval queue.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApprovalResponse {
+    pub post_id: FeedPartition,
+    pub post_title: String,
+    pub platform: SocialPlatform,
+    /// Display name of the member who published the post.
+    pub requested_by: String,
+    /// When the post was scheduled to go out (epoch ms), if it was.
+    pub scheduled_at: Option<i64>,
+    pub created_at: i64,
+}
+
+/// Read-only reply imported from a syndication platform, rendered under
+/// the post's native comments.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ExternalCommentResponse {
+    pub platform: SocialPlatform,
+    pub author_handle: String,
+    pub author_display_name: String,
+    pub author_avatar_url: Option<String>,
+    pub content: String,
+    pub url: String,
+    pub posted_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl From<crate::features::cross_posting::models::ExternalComment> for ExternalCommentResponse {
+    fn from(c: crate::features::cross_posting::models::ExternalComment) -> Self {
+        Self {
+            platform: c.platform,
+            author_handle: c.author_handle,
+            author_display_name: c.author_display_name,
+            author_avatar_url: c.author_avatar_url,
+            content: c.content,
+            url: c.url,
+            posted_at: c.posted_at,
+        }
+    }
+}
+
 /// Response from `POST /api/cross-posting/connections/linkedin/init`.
 /// Front-end navigates `window.location.href = authorize_url` so the
 /// browser leaves the SPA context and lands on LinkedIn's consent page.
@@ -111,3 +294,17 @@ impl SyndicationJobView {
 pub struct LinkedInOauthInitResponse {
     pub authorize_url: String,
 }
+
+/// Response from `POST /api/cross-posting/connections/threads/init` —
+/// the Threads consent page URL to navigate to.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ThreadsOauthInitResponse {
+    pub authorize_url: String,
+}
+
+/// Response from `POST /api/cross-posting/connections/mastodon/init` —
+/// the consent page URL on the user's own instance.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MastodonOauthInitResponse {
+    pub authorize_url: String,
+}
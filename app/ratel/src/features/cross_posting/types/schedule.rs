@@ -0,0 +1,157 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+const MINUTE_MS: i64 = 60_000;
+
+/// Widest real-world UTC offsets (Baker Island, Line Islands).
+pub const MIN_UTC_OFFSET_MINUTES: i16 = -12 * 60;
+pub const MAX_UTC_OFFSET_MINUTES: i16 = 14 * 60;
+
+/// A connection's "best time" to post — a wall-clock time in the
+/// audience's zone, e.g. 09:00 at UTC+9 for a Korean LinkedIn network.
+///
+/// The zone is a fixed offset (minutes east of UTC, captured from the
+/// browser when the author saves it), so a DST switch shifts the slot by
+/// an hour until the author re-saves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct PostingTime {
+    /// 0..1440, minutes after local midnight.
+    pub minute_of_day: u16,
+    pub utc_offset_minutes: i16,
+}
+
+impl PostingTime {
+    pub fn is_valid(&self) -> bool {
+        (self.minute_of_day as i64) < MINUTES_PER_DAY
+            && (MIN_UTC_OFFSET_MINUTES..=MAX_UTC_OFFSET_MINUTES).contains(&self.utc_offset_minutes)
+    }
+
+    /// First occurrence of this slot strictly after `now_ms` (epoch ms).
+    pub fn next_after(&self, now_ms: i64) -> i64 {
+        let offset_ms = self.utc_offset_minutes as i64 * MINUTE_MS;
+        let local_now = now_ms + offset_ms;
+        let local_midnight =
+            local_now.div_euclid(MINUTES_PER_DAY * MINUTE_MS) * (MINUTES_PER_DAY * MINUTE_MS);
+        let mut slot = local_midnight + self.minute_of_day as i64 * MINUTE_MS;
+        if slot <= local_now {
+            slot += MINUTES_PER_DAY * MINUTE_MS;
+        }
+        slot - offset_ms
+    }
+
+    /// `HH:MM` for `<input type="time">`.
+    pub fn to_hhmm(&self) -> String {
+        format!(
+            "{:02}:{:02}",
+            self.minute_of_day / 60,
+            self.minute_of_day % 60
+        )
+    }
+
+    /// Parses the `<input type="time">` value (`HH:MM`).
+    pub fn from_hhmm(value: &str, utc_offset_minutes: i16) -> Option<Self> {
+        let (h, m) = value.split_once(':')?;
+        let (h, m): (u16, u16) = (h.parse().ok()?, m.get(..2)?.parse().ok()?);
+        let time = Self {
+            minute_of_day: h.checked_mul(60)?.checked_add(m)?,
+            utc_offset_minutes,
+        };
+        (m < 60 && time.is_valid()).then_some(time)
+    }
+}
+
+/// When a platform's copy of a post goes out, chosen per platform at
+/// compose time and resolved into `SyndicationJob.scheduled_at` by the
+/// Stage 1 factory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum SchedulePlan {
+    /// The connection's best time when one is set, otherwise immediately.
+    #[default]
+    Auto,
+    /// Immediately on publish, ignoring any best time.
+    Now,
+    /// A fixed instant (epoch ms). Past instants go out immediately.
+    At { at: i64 },
+}
+
+impl SchedulePlan {
+    /// Resolve to a dispatch time, `None` meaning "dispatch now".
+    pub fn resolve(self, best_time: Option<PostingTime>, now_ms: i64) -> Option<i64> {
+        match self {
+            SchedulePlan::Auto => best_time.map(|t| t.next_after(now_ms)),
+            SchedulePlan::Now => None,
+            SchedulePlan::At { at } => (at > now_ms).then_some(at),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2026-05-01T00:00:00Z
+    const MAY_1_UTC: i64 = 1_777_593_600_000;
+
+    #[test]
+    fn next_after_rolls_to_the_next_local_day() {
+        let nine_kst = PostingTime {
+            minute_of_day: 9 * 60,
+            utc_offset_minutes: 9 * 60,
+        };
+        // 00:00Z is 09:00 KST — exactly on the slot, so tomorrow's.
+        assert_eq!(
+            nine_kst.next_after(MAY_1_UTC),
+            MAY_1_UTC + 24 * 60 * MINUTE_MS
+        );
+        // 23:00Z on Apr 30 is 08:00 KST May 1 — an hour away.
+        assert_eq!(nine_kst.next_after(MAY_1_UTC - 60 * MINUTE_MS), MAY_1_UTC);
+
+        let nine_est = PostingTime {
+            minute_of_day: 9 * 60,
+            utc_offset_minutes: -5 * 60,
+        };
+        assert_eq!(
+            nine_est.next_after(MAY_1_UTC),
+            MAY_1_UTC + 14 * 60 * MINUTE_MS
+        );
+    }
+
+    #[test]
+    fn hhmm_round_trips_and_rejects_garbage() {
+        let t = PostingTime::from_hhmm("09:05", 60).unwrap();
+        assert_eq!(t.minute_of_day, 545);
+        assert_eq!(t.to_hhmm(), "09:05");
+        assert!(PostingTime::from_hhmm("24:00", 0).is_none());
+        assert!(PostingTime::from_hhmm("09:75", 0).is_none());
+        assert!(PostingTime::from_hhmm("nine", 0).is_none());
+        assert!(PostingTime::from_hhmm("09:00", 15 * 60).is_none());
+    }
+
+    #[test]
+    fn plan_resolution() {
+        let best = Some(PostingTime {
+            minute_of_day: 60,
+            utc_offset_minutes: 0,
+        });
+        assert_eq!(
+            SchedulePlan::Auto.resolve(best, MAY_1_UTC),
+            Some(MAY_1_UTC + 60 * MINUTE_MS)
+        );
+        assert_eq!(SchedulePlan::Auto.resolve(None, MAY_1_UTC), None);
+        assert_eq!(SchedulePlan::Now.resolve(best, MAY_1_UTC), None);
+        assert_eq!(
+            SchedulePlan::At { at: MAY_1_UTC - 1 }.resolve(best, MAY_1_UTC),
+            None
+        );
+        assert_eq!(
+            SchedulePlan::At { at: MAY_1_UTC + 1 }.resolve(best, MAY_1_UTC),
+            Some(MAY_1_UTC + 1)
+        );
+    }
+}
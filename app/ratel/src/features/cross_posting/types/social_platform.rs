@@ -4,7 +4,8 @@ use crate::common::*;
 use rmcp::schemars;
 use std::str::FromStr;
 
-/// Phase 1 cross-posting target platforms (FR-1 #1).
+/// Cross-posting target platforms (FR-1 #1). Mastodon is the first
+/// fediverse target; its connections are per-instance.
 ///
 /// Display impl produces the lowercase identifier used in URLs / DynamoDB
 /// sort-key inners (e.g. `SocialConnection("bluesky")`); FromStr accepts
@@ -18,6 +19,7 @@ pub enum SocialPlatform {
     Bluesky,
     LinkedIn,
     Threads,
+    Mastodon,
 }
 
 impl FromStr for SocialPlatform {
@@ -27,6 +29,7 @@ impl FromStr for SocialPlatform {
             "bluesky" => Ok(SocialPlatform::Bluesky),
             "linkedin" => Ok(SocialPlatform::LinkedIn),
             "threads" => Ok(SocialPlatform::Threads),
+            "mastodon" => Ok(SocialPlatform::Mastodon),
             _ => Err(()),
         }
     }
@@ -39,6 +42,7 @@ impl SocialPlatform {
             SocialPlatform::Bluesky => "Bluesky",
             SocialPlatform::LinkedIn => "LinkedIn",
             SocialPlatform::Threads => "Threads",
+            SocialPlatform::Mastodon => "Mastodon",
         }
     }
 
@@ -49,15 +53,32 @@ impl SocialPlatform {
             SocialPlatform::Bluesky => 300,
             SocialPlatform::LinkedIn => 3_000,
             SocialPlatform::Threads => 500,
+            // Default for stock instances; some raise it, none go lower.
+            SocialPlatform::Mastodon => 500,
         }
     }
 
+    /// Whether the platform lets us read replies to our posts, i.e. whether
+    /// the reply-import toggle is offered. LinkedIn restricts comment
+    /// reads to approved partners.
+    pub fn supports_reply_import(self) -> bool {
+        !matches!(self, SocialPlatform::LinkedIn)
+    }
+
+    /// Whether long posts can go out as a reply chain, i.e. whether the
+    /// thread-mode toggle is offered. LinkedIn has no self-replies.
+    pub fn supports_threads(self) -> bool {
+        !matches!(self, SocialPlatform::LinkedIn)
+    }
+
     /// Maximum number of images the platform accepts on a single post (FR-5 #31).
     pub fn max_images(self) -> usize {
         match self {
             SocialPlatform::Bluesky => 4,
             SocialPlatform::LinkedIn => 1,
-            SocialPlatform::Threads => 1,
+            // Two or more images are published as a carousel.
+            SocialPlatform::Threads => 10,
+            SocialPlatform::Mastodon => 4,
         }
     }
 }
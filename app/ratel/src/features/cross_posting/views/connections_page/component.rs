@@ -1,16 +1,21 @@
 use crate::common::*;
 use crate::features::cross_posting::components::bluesky_connect_modal::BlueskyConnectModal;
-use crate::features::cross_posting::hooks::{use_cross_posting_provider, UseCrossPosting};
+use crate::features::cross_posting::components::cross_posting_analytics::CrossPostingAnalytics;
+use crate::features::cross_posting::components::mastodon_connect_modal::MastodonConnectModal;
+use crate::features::cross_posting::hooks::{
+    use_cross_posting, use_cross_posting_provider, UseCrossPosting,
+};
 use crate::features::cross_posting::i18n::ConnectionsPageTranslate;
 use crate::features::cross_posting::models::ConnectionStatus;
-use crate::features::cross_posting::types::{ConnectionResponse, SocialPlatform};
+use crate::features::cross_posting::types::{ConnectionResponse, PostingTime, SocialPlatform};
 
 /// Settings → Connections (`/{username}/settings/connections`).
 ///
-/// Phase 1A: Bluesky is the only fully-wired platform. LinkedIn, Threads,
-/// and Farcaster render as static "Coming soon" cards. The connect /
-/// disconnect / auto-post-toggle actions all flow through `async fn`
-/// methods on the `UseCrossPosting` controller installed at the page root.
+/// Bluesky (1A), LinkedIn (1B), Threads (1C) and Mastodon are fully
+/// wired; Farcaster renders as a static "Phase 2" card. The connect /
+/// disconnect / auto-post-toggle / best-time actions all flow through
+/// `async fn` methods on the `UseCrossPosting` controller installed at the
+/// page root.
 #[component]
 pub fn ConnectionsPage(username: String) -> Element {
     let _ = username; // Currently unused — controller scopes by session user_pk
@@ -23,6 +28,7 @@ pub fn ConnectionsPage(username: String) -> Element {
     } = cp;
 
     let mut modal_open = use_signal(|| false);
+    let mut mastodon_modal_open = use_signal(|| false);
     let mut toast = use_toast();
     let nav = use_navigator();
     let t: ConnectionsPageTranslate = use_translate();
@@ -44,6 +50,22 @@ pub fn ConnectionsPage(username: String) -> Element {
         .as_ref()
         .map(|c| c.status == ConnectionStatus::Connected)
         .unwrap_or(false);
+    let threads: Option<ConnectionResponse> = conn_list
+        .iter()
+        .find(|c| c.platform == SocialPlatform::Threads)
+        .cloned();
+    let threads_connected = threads
+        .as_ref()
+        .map(|c| c.status == ConnectionStatus::Connected)
+        .unwrap_or(false);
+    let mastodon: Option<ConnectionResponse> = conn_list
+        .iter()
+        .find(|c| c.platform == SocialPlatform::Mastodon)
+        .cloned();
+    let mastodon_connected = mastodon
+        .as_ref()
+        .map(|c| c.status == ConnectionStatus::Connected)
+        .unwrap_or(false);
 
     rsx! {
         SeoMeta { title: "{t.title}" }
@@ -59,6 +81,17 @@ pub fn ConnectionsPage(username: String) -> Element {
             },
         }
 
+        MastodonConnectModal {
+            open: mastodon_modal_open,
+            on_submit: move |instance: String| async move {
+                // Success leaves the page for the server's consent screen;
+                // only an error (bad server, registration refused) lands here.
+                if let Err(e) = cp.connect_mastodon(instance, None).await {
+                    toast.error(e);
+                }
+            },
+        }
+
         div { class: "connections-arena",
             // ── Top bar ────────────────────────────────────────────
             header { class: "arena-topbar",
@@ -216,6 +249,9 @@ pub fn ConnectionsPage(username: String) -> Element {
                             if c.status == ConnectionStatus::Connected {
                                 {
                                     let auto_post = c.auto_post_enabled;
+                                    let import_replies = c.import_replies_enabled;
+                                    let thread_mode = c.thread_mode_enabled;
+                                    let space_cards = c.space_cards_enabled;
                                     rsx! {
                                         div { class: "plat__subrow",
                                             div { class: "plat__subrow-item",
@@ -236,6 +272,55 @@ pub fn ConnectionsPage(username: String) -> Element {
                                                     },
                                                 }
                                             }
+                                            span { class: "plat__subrow-sep" }
+                                            BestTimeInput {
+                                                platform: SocialPlatform::Bluesky,
+                                                best_time: c.best_time,
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.import_replies}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{import_replies}",
+                                                    "aria-label": "{t.import_replies}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_reply_import(SocialPlatform::Bluesky, !import_replies).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.thread_mode}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{thread_mode}",
+                                                    "aria-label": "{t.thread_mode}",
+                                                    title: "{t.thread_mode_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_thread_mode(SocialPlatform::Bluesky, !thread_mode).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.space_cards}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{space_cards}",
+                                                    "aria-label": "{t.space_cards}",
+                                                    title: "{t.space_cards_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_space_cards(SocialPlatform::Bluesky, !space_cards).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -306,6 +391,7 @@ pub fn ConnectionsPage(username: String) -> Element {
                             if c.status == ConnectionStatus::Connected {
                                 {
                                     let auto_post = c.auto_post_enabled;
+                                    let space_cards = c.space_cards_enabled;
                                     rsx! {
                                         div { class: "plat__subrow",
                                             div { class: "plat__subrow-item",
@@ -326,6 +412,26 @@ pub fn ConnectionsPage(username: String) -> Element {
                                                     },
                                                 }
                                             }
+                                            span { class: "plat__subrow-sep" }
+                                            BestTimeInput {
+                                                platform: SocialPlatform::LinkedIn,
+                                                best_time: c.best_time,
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.space_cards}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{space_cards}",
+                                                    "aria-label": "{t.space_cards}",
+                                                    title: "{t.space_cards_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_space_cards(SocialPlatform::LinkedIn, !space_cards).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -333,11 +439,11 @@ pub fn ConnectionsPage(username: String) -> Element {
                         }
                     }
 
-                    // Threads — coming in Phase 1C
+                    // Threads — Phase 1C active
                     article {
                         class: "plat",
                         "data-platform": "threads",
-                        "data-soon": "true",
+                        "data-connected": "{threads_connected}",
                         div { class: "plat__body",
                             span { class: "plat__logo plat__logo--threads",
                                 svg {
@@ -351,20 +457,267 @@ pub fn ConnectionsPage(username: String) -> Element {
                             div { class: "plat__main",
                                 div { class: "plat__name-row",
                                     span { class: "plat__name", "{t.threads_name}" }
-                                    span { class: "status-pill status-pill--soon",
-                                        "{t.status_coming_soon}"
+                                    if threads_connected {
+                                        span { class: "status-pill status-pill--connected",
+                                            "{t.status_connected}"
+                                        }
+                                    } else {
+                                        span { class: "status-pill status-pill--off",
+                                            "{t.status_not_connected}"
+                                        }
                                     }
                                     span { class: "plat__limit", "{t.threads_limit}" }
                                 }
                                 div { class: "plat__handle",
-                                    span { "{t.threads_subtitle}" }
+                                    if let Some(c) = threads.clone() {
+                                        span { "{c.external_handle}" }
+                                    } else {
+                                        span { "{t.threads_subtitle_default}" }
+                                    }
                                 }
                             }
                             div { class: "plat__actions",
-                                button {
-                                    class: "connections-btn connections-btn--ghost",
-                                    disabled: true,
-                                    "{t.btn_notify}"
+                                if threads_connected {
+                                    button {
+                                        class: "connections-btn connections-btn--ghost",
+                                        onclick: move |_| async move {
+                                            if let Err(e) = cp.disconnect(SocialPlatform::Threads).await {
+                                                toast.error(e);
+                                            }
+                                        },
+                                        "{t.btn_disconnect}"
+                                    }
+                                } else {
+                                    button {
+                                        class: "connections-btn connections-btn--connect-threads",
+                                        onclick: move |_| async move {
+                                            if let Err(e) = cp.connect_threads(None).await {
+                                                toast.error(e);
+                                            }
+                                        },
+                                        "{t.btn_connect}"
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(c) = threads.clone() {
+                            if c.status == ConnectionStatus::Connected {
+                                {
+                                    let auto_post = c.auto_post_enabled;
+                                    let import_replies = c.import_replies_enabled;
+                                    let thread_mode = c.thread_mode_enabled;
+                                    let space_cards = c.space_cards_enabled;
+                                    rsx! {
+                                        div { class: "plat__subrow",
+                                            div { class: "plat__subrow-item",
+                                                strong { "{c.posts_syndicated_count}" }
+                                                span { "{t.posts_syndicated_count_label}" }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.auto_post}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{auto_post}",
+                                                    "aria-label": "{t.auto_post}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_auto_post(SocialPlatform::Threads, !auto_post).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            BestTimeInput {
+                                                platform: SocialPlatform::Threads,
+                                                best_time: c.best_time,
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.import_replies}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{import_replies}",
+                                                    "aria-label": "{t.import_replies}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_reply_import(SocialPlatform::Threads, !import_replies).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.thread_mode}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{thread_mode}",
+                                                    "aria-label": "{t.thread_mode}",
+                                                    title: "{t.thread_mode_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_thread_mode(SocialPlatform::Threads, !thread_mode).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.space_cards}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{space_cards}",
+                                                    "aria-label": "{t.space_cards}",
+                                                    title: "{t.space_cards_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_space_cards(SocialPlatform::Threads, !space_cards).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    // Mastodon — per-instance OAuth; the picker modal collects the server
+                    article {
+                        class: "plat",
+                        "data-platform": "mastodon",
+                        "data-connected": "{mastodon_connected}",
+                        div { class: "plat__body",
+                            span { class: "plat__logo plat__logo--mastodon",
+                                svg {
+                                    "viewBox": "0 0 24 24",
+                                    "fill": "currentColor",
+                                    path {
+                                        "d": "M23.268 5.313c-.35-2.578-2.617-4.61-5.304-5.004C17.51.242 15.792 0 11.813 0h-.03c-3.98 0-4.835.242-5.288.309C3.882.692 1.496 2.518.917 5.127.64 6.412.61 7.837.661 9.143c.074 1.874.088 3.745.26 5.611.118 1.24.325 2.47.62 3.68.55 2.237 2.777 4.098 4.96 4.857 2.336.792 4.849.923 7.256.38.265-.061.527-.132.786-.213.585-.184 1.27-.39 1.774-.753a.057.057 0 0 0 .023-.043v-1.809a.052.052 0 0 0-.02-.041.053.053 0 0 0-.046-.01 20.282 20.282 0 0 1-4.709.545c-2.73 0-3.463-1.284-3.674-1.818a5.593 5.593 0 0 1-.319-1.433.053.053 0 0 1 .066-.054c1.517.363 3.072.546 4.632.546.376 0 .75 0 1.125-.01 1.57-.044 3.224-.124 4.768-.422.038-.008.077-.015.11-.024 2.435-.464 4.753-1.92 4.989-5.604.008-.145.03-1.52.03-1.67.002-.512.167-3.63-.024-5.545zm-3.748 9.195h-2.561V8.29c0-1.309-.55-1.976-1.67-1.976-1.23 0-1.846.79-1.846 2.35v3.403h-2.546V8.663c0-1.56-.617-2.35-1.848-2.35-1.112 0-1.668.668-1.67 1.977v6.218H4.822V8.102c0-1.31.337-2.35 1.011-3.12.696-.77 1.608-1.164 2.74-1.164 1.311 0 2.302.5 2.962 1.498l.638 1.06.638-1.06c.66-.999 1.65-1.498 2.96-1.498 1.13 0 2.043.395 2.74 1.164.675.77 1.012 1.81 1.012 3.12z",
+                                    }
+                                }
+                            }
+                            div { class: "plat__main",
+                                div { class: "plat__name-row",
+                                    span { class: "plat__name", "{t.mastodon_name}" }
+                                    if mastodon_connected {
+                                        span { class: "status-pill status-pill--connected",
+                                            "{t.status_connected}"
+                                        }
+                                    } else {
+                                        span { class: "status-pill status-pill--off",
+                                            "{t.status_not_connected}"
+                                        }
+                                    }
+                                    span { class: "plat__limit", "{t.mastodon_limit}" }
+                                }
+                                div { class: "plat__handle",
+                                    if let Some(c) = mastodon.clone() {
+                                        span { "{c.external_handle}" }
+                                    } else {
+                                        span { "{t.mastodon_subtitle_default}" }
+                                    }
+                                }
+                            }
+                            div { class: "plat__actions",
+                                if mastodon_connected {
+                                    button {
+                                        class: "connections-btn connections-btn--ghost",
+                                        onclick: move |_| async move {
+                                            if let Err(e) = cp.disconnect(SocialPlatform::Mastodon).await {
+                                                toast.error(e);
+                                            }
+                                        },
+                                        "{t.btn_disconnect}"
+                                    }
+                                } else {
+                                    button {
+                                        class: "connections-btn connections-btn--connect-mastodon",
+                                        onclick: move |_| mastodon_modal_open.set(true),
+                                        "{t.btn_connect}"
+                                    }
+                                }
+                            }
+                        }
+                        if let Some(c) = mastodon.clone() {
+                            if c.status == ConnectionStatus::Connected {
+                                {
+                                    let auto_post = c.auto_post_enabled;
+                                    let import_replies = c.import_replies_enabled;
+                                    let thread_mode = c.thread_mode_enabled;
+                                    let space_cards = c.space_cards_enabled;
+                                    rsx! {
+                                        div { class: "plat__subrow",
+                                            div { class: "plat__subrow-item",
+                                                strong { "{c.posts_syndicated_count}" }
+                                                span { "{t.posts_syndicated_count_label}" }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.auto_post}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{auto_post}",
+                                                    "aria-label": "{t.auto_post}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_auto_post(SocialPlatform::Mastodon, !auto_post).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            BestTimeInput {
+                                                platform: SocialPlatform::Mastodon,
+                                                best_time: c.best_time,
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.import_replies}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{import_replies}",
+                                                    "aria-label": "{t.import_replies}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_reply_import(SocialPlatform::Mastodon, !import_replies).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.thread_mode}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{thread_mode}",
+                                                    "aria-label": "{t.thread_mode}",
+                                                    title: "{t.thread_mode_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_thread_mode(SocialPlatform::Mastodon, !thread_mode).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                            span { class: "plat__subrow-sep" }
+                                            div { class: "plat__subrow-item",
+                                                span { "{t.space_cards}" }
+                                                button {
+                                                    class: "switch",
+                                                    "aria-checked": "{space_cards}",
+                                                    "aria-label": "{t.space_cards}",
+                                                    title: "{t.space_cards_hint}",
+                                                    onclick: move |_| async move {
+                                                        if let Err(e) = cp.toggle_space_cards(SocialPlatform::Mastodon, !space_cards).await {
+                                                            toast.error(e);
+                                                        }
+                                                    },
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
                             }
                         }
@@ -405,6 +758,47 @@ pub fn ConnectionsPage(username: String) -> Element {
                         }
                     }
                 }
+
+                // ── Analytics ──────────────────────────────────────
+                CrossPostingAnalytics {}
+            }
+        }
+    }
+}
+
+/// Per-connection "best time to post". Saved in the browser's current
+/// UTC offset, so 09:00 means 09:00 where the author is now; clearing the
+/// field makes `Auto` posts go out immediately again.
+#[component]
+fn BestTimeInput(platform: SocialPlatform, best_time: Option<PostingTime>) -> Element {
+    let mut cp = use_cross_posting();
+    let mut toast = use_toast();
+    let t: ConnectionsPageTranslate = use_translate();
+    let value = best_time.map(|b| b.to_hhmm()).unwrap_or_default();
+
+    rsx! {
+        div { class: "plat__subrow-item",
+            span { "{t.best_time}" }
+            input {
+                class: "plat__best-time",
+                r#type: "time",
+                "aria-label": "{t.best_time}",
+                value: "{value}",
+                onchange: move |e: FormEvent| async move {
+                    let raw = e.value();
+                    let offset = crate::common::utils::time::local_utc_offset_minutes();
+                    let next = if raw.is_empty() {
+                        None
+                    } else {
+                        match PostingTime::from_hhmm(&raw, offset) {
+                            Some(b) => Some(b),
+                            None => return,
+                        }
+                    };
+                    if let Err(e) = cp.set_best_time(platform, next).await {
+                        toast.error(e);
+                    }
+                },
             }
         }
     }
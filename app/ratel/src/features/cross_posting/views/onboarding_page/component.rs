@@ -2,6 +2,7 @@ use crate::common::*;
 use crate::features::auth::controllers::mark_onboarding_seen_handler;
 use crate::features::auth::hooks::use_user_context;
 use crate::features::cross_posting::components::bluesky_connect_modal::BlueskyConnectModal;
+use crate::features::cross_posting::components::mastodon_connect_modal::MastodonConnectModal;
 use crate::features::cross_posting::hooks::{use_cross_posting_provider, UseCrossPosting};
 use crate::features::cross_posting::i18n::OnboardingPageTranslate;
 use crate::features::cross_posting::models::ConnectionStatus;
@@ -22,6 +23,7 @@ pub fn OnboardingPage() -> Element {
     let nav = use_navigator();
     let mut toast = use_toast();
     let mut modal_open = use_signal(|| false);
+    let mut mastodon_modal_open = use_signal(|| false);
     let t: OnboardingPageTranslate = use_translate();
 
     // AC-4b — if the user has already dismissed this once, never auto-show
@@ -54,6 +56,22 @@ pub fn OnboardingPage() -> Element {
         .as_ref()
         .map(|c| c.status == ConnectionStatus::Connected)
         .unwrap_or(false);
+    let threads: Option<ConnectionResponse> = conn_list
+        .iter()
+        .find(|c| c.platform == SocialPlatform::Threads)
+        .cloned();
+    let threads_connected = threads
+        .as_ref()
+        .map(|c| c.status == ConnectionStatus::Connected)
+        .unwrap_or(false);
+    let mastodon: Option<ConnectionResponse> = conn_list
+        .iter()
+        .find(|c| c.platform == SocialPlatform::Mastodon)
+        .cloned();
+    let mastodon_connected = mastodon
+        .as_ref()
+        .map(|c| c.status == ConnectionStatus::Connected)
+        .unwrap_or(false);
 
     // FR-2 #13 — flip the seen flag and land on home. Failure to update the
     // flag is non-fatal (the user can always re-trigger onboarding from
@@ -88,6 +106,15 @@ pub fn OnboardingPage() -> Element {
             },
         }
 
+        MastodonConnectModal {
+            open: mastodon_modal_open,
+            on_submit: move |instance: String| async move {
+                if let Err(e) = cp.connect_mastodon(instance, None).await {
+                    toast.error(e);
+                }
+            },
+        }
+
         div { class: "onboarding-arena",
             // Top bar — Skip-for-now link as fallback exit.
             header { class: "onboarding-topbar",
@@ -269,12 +296,11 @@ pub fn OnboardingPage() -> Element {
                             }
                         }
 
-                        // Threads — Phase 1C placeholder
+                        // Threads — Phase 1C active
                         article {
                             class: "onboarding-row",
                             "data-platform": "threads",
-                            "data-connected": "false",
-                            "data-soon": "true",
+                            "data-connected": "{threads_connected}",
                             span { class: "onboarding-logo onboarding-logo--threads",
                                 svg {
                                     "viewBox": "0 0 24 24",
@@ -284,13 +310,114 @@ pub fn OnboardingPage() -> Element {
                             }
                             div { class: "onboarding-body",
                                 div { class: "onboarding-name", "Threads" }
-                                div { class: "onboarding-meta", "{t.threads_meta}" }
+                                div { class: "onboarding-meta",
+                                    if let Some(c) = threads.as_ref() {
+                                        if c.status == ConnectionStatus::Connected {
+                                            strong { "{c.external_handle}" }
+                                        } else {
+                                            "{t.threads_meta}"
+                                        }
+                                    } else {
+                                        "{t.threads_meta}"
+                                    }
+                                }
                             }
                             div { class: "onboarding-action",
-                                button {
-                                    class: "onboarding-btn-connect onboarding-btn-connect--soon",
-                                    disabled: true,
-                                    "{t.coming_soon}"
+                                if threads_connected {
+                                    span { class: "onboarding-connected-badge",
+                                        svg {
+                                            "viewBox": "0 0 24 24",
+                                            "fill": "none",
+                                            "stroke": "currentColor",
+                                            "stroke-width": "3",
+                                            "stroke-linecap": "round",
+                                            "stroke-linejoin": "round",
+                                            polyline { "points": "20 6 9 17 4 12" }
+                                        }
+                                        "{t.status_connected}"
+                                    }
+                                } else {
+                                    button {
+                                        class: "onboarding-btn-connect onboarding-btn-connect--threads",
+                                        "data-testid": "onboarding-connect-threads",
+                                        onclick: move |_| async move {
+                                            if let Err(e) = cp.connect_threads(None).await {
+                                                toast.error(e);
+                                            }
+                                        },
+                                        svg {
+                                            "viewBox": "0 0 24 24",
+                                            "fill": "none",
+                                            "stroke": "currentColor",
+                                            "stroke-width": "2.5",
+                                            "stroke-linecap": "round",
+                                            "stroke-linejoin": "round",
+                                            polyline { "points": "5 12 19 12" }
+                                            polyline { "points": "12 5 19 12 12 19" }
+                                        }
+                                        "{t.btn_connect}"
+                                    }
+                                }
+                            }
+                        }
+
+                        // Mastodon — server picked in the modal, then OAuth on that server
+                        article {
+                            class: "onboarding-row",
+                            "data-platform": "mastodon",
+                            "data-connected": "{mastodon_connected}",
+                            span { class: "onboarding-logo onboarding-logo--mastodon",
+                                svg {
+                                    "viewBox": "0 0 24 24",
+                                    "fill": "currentColor",
+                                    path { "d": "M23.268 5.313c-.35-2.578-2.617-4.61-5.304-5.004C17.51.242 15.792 0 11.813 0h-.03c-3.98 0-4.835.242-5.288.309C3.882.692 1.496 2.518.917 5.127.64 6.412.61 7.837.661 9.143c.074 1.874.088 3.745.26 5.611.118 1.24.325 2.47.62 3.68.55 2.237 2.777 4.098 4.96 4.857 2.336.792 4.849.923 7.256.38.265-.061.527-.132.786-.213.585-.184 1.27-.39 1.774-.753a.057.057 0 0 0 .023-.043v-1.809a.052.052 0 0 0-.02-.041.053.053 0 0 0-.046-.01 20.282 20.282 0 0 1-4.709.545c-2.73 0-3.463-1.284-3.674-1.818a5.593 5.593 0 0 1-.319-1.433.053.053 0 0 1 .066-.054c1.517.363 3.072.546 4.632.546.376 0 .75 0 1.125-.01 1.57-.044 3.224-.124 4.768-.422.038-.008.077-.015.11-.024 2.435-.464 4.753-1.92 4.989-5.604.008-.145.03-1.52.03-1.67.002-.512.167-3.63-.024-5.545zm-3.748 9.195h-2.561V8.29c0-1.309-.55-1.976-1.67-1.976-1.23 0-1.846.79-1.846 2.35v3.403h-2.546V8.663c0-1.56-.617-2.35-1.848-2.35-1.112 0-1.668.668-1.67 1.977v6.218H4.822V8.102c0-1.31.337-2.35 1.011-3.12.696-.77 1.608-1.164 2.74-1.164 1.311 0 2.302.5 2.962 1.498l.638 1.06.638-1.06c.66-.999 1.65-1.498 2.96-1.498 1.13 0 2.043.395 2.74 1.164.675.77 1.012 1.81 1.012 3.12z" }
+                                }
+                            }
+                            div { class: "onboarding-body",
+                                div { class: "onboarding-name", "Mastodon" }
+                                div { class: "onboarding-meta",
+                                    if let Some(c) = mastodon.as_ref() {
+                                        if c.status == ConnectionStatus::Connected {
+                                            strong { "{c.external_handle}" }
+                                        } else {
+                                            "{t.mastodon_meta}"
+                                        }
+                                    } else {
+                                        "{t.mastodon_meta}"
+                                    }
+                                }
+                            }
+                            div { class: "onboarding-action",
+                                if mastodon_connected {
+                                    span { class: "onboarding-connected-badge",
+                                        svg {
+                                            "viewBox": "0 0 24 24",
+                                            "fill": "none",
+                                            "stroke": "currentColor",
+                                            "stroke-width": "3",
+                                            "stroke-linecap": "round",
+                                            "stroke-linejoin": "round",
+                                            polyline { "points": "20 6 9 17 4 12" }
+                                        }
+                                        "{t.status_connected}"
+                                    }
+                                } else {
+                                    button {
+                                        class: "onboarding-btn-connect onboarding-btn-connect--mastodon",
+                                        "data-testid": "onboarding-connect-mastodon",
+                                        onclick: move |_| mastodon_modal_open.set(true),
+                                        svg {
+                                            "viewBox": "0 0 24 24",
+                                            "fill": "none",
+                                            "stroke": "currentColor",
+                                            "stroke-width": "2.5",
+                                            "stroke-linecap": "round",
+                                            "stroke-linejoin": "round",
+                                            polyline { "points": "5 12 19 12" }
+                                            polyline { "points": "12 5 19 12 12 19" }
+                                        }
+                                        "{t.btn_connect}"
+                                    }
                                 }
                             }
                         }
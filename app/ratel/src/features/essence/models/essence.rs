@@ -183,6 +183,9 @@ impl Essence {
                 EssenceSourceKind::FactFoldRationale => {
                     updater.increase_total_fact_fold_rationale(source_delta)
                 }
+                EssenceSourceKind::MeetTranscript => {
+                    updater.increase_total_meet_transcript(source_delta)
+                }
             };
         }
         if let Err(e) = updater.execute(cli).await {
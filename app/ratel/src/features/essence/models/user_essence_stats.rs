@@ -41,6 +41,8 @@ pub struct UserEssenceStats {
     /// (PR6 step 4). Mirrors `total_post` / `total_comment` etc.
     #[serde(default)]
     pub total_fact_fold_rationale: i64,
+    #[serde(default)]
+    pub total_meet_transcript: i64,
 }
 
 #[cfg(feature = "server")]
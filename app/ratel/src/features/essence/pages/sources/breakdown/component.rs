@@ -45,6 +45,9 @@ pub fn EssenceBreakdown() -> Element {
                     // the FOF source kind appears on the Essence
                     // House design.
                     EssenceSourceKind::FactFoldRationale => counts.post += 1,
+                    // Meet transcripts are space actions; they share the
+                    // poll/quiz side of the breakdown until a chip exists.
+                    EssenceSourceKind::MeetTranscript => counts.poll += 1,
                 }
             }
             return counts;
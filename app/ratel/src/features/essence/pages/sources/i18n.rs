@@ -59,6 +59,7 @@ translate! {
     tag_quiz: { en: "Quiz", ko: "퀴즈" },
     tag_post_comment: { en: "Post comment", ko: "포스트 댓글" },
     tag_discussion_comment: { en: "Discussion comment", ko: "토론 댓글" },
+    tag_meet_transcript: { en: "Meet transcript", ko: "회의 기록" },
 
     // Pagination
     pagination_prefix: { en: "Showing", ko: "" },
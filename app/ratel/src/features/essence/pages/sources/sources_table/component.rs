@@ -121,6 +121,7 @@ fn SourceRow(source: EssenceResponse) -> Element {
         }
         EssenceSourceKind::Poll | EssenceSourceKind::Quiz => "essence-src-icon--action",
         EssenceSourceKind::FactFoldRationale => "essence-src-icon--post",
+        EssenceSourceKind::MeetTranscript => "essence-src-icon--action",
     };
 
     let target = navigation_target(&source);
@@ -169,6 +170,7 @@ fn SourceRow(source: EssenceResponse) -> Element {
                             // arrives when the Essence House design
                             // surfaces FOF.
                             EssenceSourceKind::FactFoldRationale => (tr.tag_post, ""),
+                            EssenceSourceKind::MeetTranscript => (tr.tag_meet_transcript, ""),
                         };
                         rsx! {
                             span { class: "essence-src-meta__badge {modifier}", "{label}" }
@@ -254,6 +256,17 @@ fn navigation_target(source: &EssenceResponse) -> Option<Route> {
         // No navigation target yet — FOF round-history page lands
         // with the leaderboard work (PR7).
         EssenceSourceKind::FactFoldRationale => None,
+        // `source_sk` is `SPACE_MEET_ARCHIVE#<meet uuid>`.
+        EssenceSourceKind::MeetTranscript => {
+            let meet_id = source.source_sk.split_once('#').map(|(_, id)| id.to_string());
+            match (source_id, meet_id) {
+                (Some(space_id), Some(meet_id)) => Some(Route::MeetActionPage {
+                    space_id: SpacePartition(space_id),
+                    meet_id: meet_id.into(),
+                }),
+                _ => None,
+            }
+        }
     }
 }
 
@@ -327,6 +340,24 @@ fn kind_icon(kind: EssenceSourceKind) -> Element {
                 path { d: "M18.5 2.5a2.12 2.12 0 0 1 3 3L12 15l-4 1 1-4z" }
             }
         },
+        EssenceSourceKind::MeetTranscript => rsx! {
+            svg {
+                view_box: "0 0 24 24",
+                fill: "none",
+                stroke: "currentColor",
+                stroke_width: "2",
+                stroke_linecap: "round",
+                stroke_linejoin: "round",
+                path { d: "m22 8-6 4 6 4V8z" }
+                rect {
+                    x: "2",
+                    y: "6",
+                    width: "14",
+                    height: "12",
+                    rx: "2",
+                }
+            }
+        },
     }
 }
 
@@ -13,6 +13,7 @@ use crate::features::essence::models::Essence;
 use crate::features::essence::types::*;
 use crate::features::posts::models::{Post, PostComment};
 use crate::features::spaces::pages::actions::actions::discussion::SpacePostComment;
+use crate::features::spaces::pages::actions::actions::meet::SpaceMeetArchive;
 use crate::features::spaces::pages::actions::actions::poll::SpacePoll;
 use crate::features::spaces::pages::actions::actions::quiz::SpaceQuiz;
 
@@ -209,6 +210,50 @@ pub async fn detach_quiz(cli: &aws_sdk_dynamodb::Client, quiz: &SpaceQuiz) -> Re
     Essence::delete_for_source(cli, creator_pk, &quiz.pk, &quiz.sk).await
 }
 
+/// Meet transcripts are space content, so like polls and quizzes they are
+/// attributed to the space creator. The title mirrors the meet's
+/// `SpaceAction` title.
+pub async fn index_meet_transcript(
+    cli: &aws_sdk_dynamodb::Client,
+    archive: &SpaceMeetArchive,
+) -> Result<()> {
+    use crate::features::spaces::pages::actions::models::SpaceAction;
+
+    let creator_pk = lookup_space_creator(cli, &archive.pk).await?;
+    let space_id: SpacePartition = archive.pk.clone().into();
+    let meet_id = archive.meet_id();
+    let action_key = CompositePartition(space_id, meet_id.to_string());
+    let action_title =
+        match SpaceAction::get(cli, &action_key, Some(EntityType::SpaceAction)).await {
+            Ok(Some(action)) => action.title,
+            _ => String::new(),
+        };
+
+    let title = if action_title.trim().is_empty() {
+        format!("Meet {meet_id}")
+    } else {
+        action_title
+    };
+    let source_path = format!(
+        "Ratel meet · {} / {}",
+        strip_prefix(&archive.pk.to_string()),
+        strip_prefix(&archive.sk.to_string())
+    );
+
+    Essence::upsert_for_source(
+        cli,
+        creator_pk,
+        archive.pk.clone(),
+        archive.sk.clone(),
+        EssenceSourceKind::MeetTranscript,
+        title,
+        source_path,
+        archive.word_count,
+        Some(archive.pk.clone()),
+    )
+    .await
+}
+
 /// `space_pk` for the essence row comes from the comment's denormalized
 /// `space_pk` field (set by `SpacePostComment::new`). Older rows missing the
 /// field index without `space_pk`, which only degrades the "open in space"
@@ -30,6 +30,10 @@ pub enum EssenceSourceKind {
     /// explicitly opts in.
     #[translate(en = "Fact or Fold rationale", ko = "팩트 오어 폴드 근거")]
     FactFoldRationale,
+    /// Speaker-attributed transcript uploaded to an ended Meet. Attributed
+    /// to the space creator, like polls and quizzes.
+    #[translate(en = "Meet transcript", ko = "회의 기록")]
+    MeetTranscript,
 }
 
 impl EssenceSourceKind {
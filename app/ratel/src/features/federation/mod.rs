@@ -0,0 +1,15 @@
+//! ActivityPub federation. Ratel users and teams are exposed as fediverse
+//! actors (WebFinger + actor documents), their public posts are served
+//! from an outbox, and a signed inbox turns remote follows, likes, boosts
+//! and replies into the same `UserFollow` / `PostLike` / `PostComment`
+//! rows local users produce.
+
+pub mod models;
+#[cfg(feature = "server")]
+pub mod server;
+#[cfg(feature = "server")]
+pub mod services;
+pub mod types;
+
+pub use models::*;
+pub use types::*;
@@ -0,0 +1,31 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// RSA key pair a local actor signs outgoing federation requests with.
+/// Generated lazily the first time the actor document is served; the
+/// private half is AEAD-sealed (`crate::common::utils::aead`) like the
+/// cross-posting credentials.
+///
+/// - pk: USER#{id} | TEAM#{id}
+/// - sk: ACTOR_KEY_PAIR
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActorKeyPair {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    /// SPKI PEM published as `publicKey.publicKeyPem`.
+    pub public_key_pem: String,
+    /// Sealed PKCS#8 DER of the private key.
+    pub private_key_ciphertext: Vec<u8>,
+
+    pub created_at: i64,
+}
+
+impl ActorKeyPair {
+    pub fn keys(actor_pk: &Partition) -> (Partition, EntityType) {
+        (actor_pk.clone(), EntityType::ActorKeyPair)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum FederatedObjectKind {
+    /// A remote `Note` stored as a `PostComment`.
+    #[default]
+    Reply,
+    /// A remote `Announce` (boost) counted in `Post.shares`.
+    Announce,
+    /// A remote `Follow` that was accepted; replays are not answered.
+    Follow,
+}
+
+/// Remote object or activity Ratel has already applied. Inbox delivery is
+/// at-least-once, so the marker is written in the same transaction as the
+/// side effect and a redelivery finds it and stops. For replies it also
+/// records where the `PostComment` landed, so nested replies, `Delete`s
+/// and `Undo`s can find it again.
+///
+/// - pk: FEDERATED_OBJECT#{hashed object id}
+/// - sk: FEDERATED_OBJECT
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct FederatedObject {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    /// Remote object / activity id as received.
+    pub object_id: String,
+    pub kind: FederatedObjectKind,
+    /// `RemoteActor` pk of the sender; only it may undo or delete.
+    pub actor_pk: Partition,
+    /// Local post the object applies to; for follows, the followed
+    /// local actor.
+    pub post_pk: Partition,
+    /// `PostComment` key for replies.
+    #[serde(default)]
+    pub comment_pk: Option<Partition>,
+    #[serde(default)]
+    pub comment_sk: Option<EntityType>,
+
+    pub created_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl FederatedObject {
+    pub fn keys(object_id: &str) -> (Partition, EntityType) {
+        (
+            Partition::FederatedObject(super::hashed_id(object_id)),
+            EntityType::FederatedObject,
+        )
+    }
+}
@@ -0,0 +1,18 @@
+mod actor_key_pair;
+mod federated_object;
+mod remote_actor;
+
+pub use actor_key_pair::*;
+pub use federated_object::*;
+pub use remote_actor::*;
+
+/// Compact, key-safe id for a remote URL: the first 16 bytes of its
+/// SHA-256, hex encoded. Remote ids are arbitrary URLs (`#`, `:` and `/`
+/// included), so they never go into a pk verbatim.
+#[cfg(feature = "server")]
+pub fn hashed_id(url: &str) -> String {
+    use sha2::Digest;
+
+    let digest = sha2::Sha256::digest(url.as_bytes());
+    hex::encode(&digest[..16])
+}
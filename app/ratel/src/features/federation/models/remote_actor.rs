@@ -0,0 +1,60 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Cached copy of a remote actor document — enough to verify its HTTP
+/// signatures, deliver to its inbox and render it as a follower or
+/// comment author.
+///
+/// - pk: REMOTE_ACTOR#{hashed actor id}
+/// - sk: REMOTE_ACTOR
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct RemoteActor {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    /// Canonical actor URL (`id` of the actor document).
+    pub actor_id: String,
+    pub inbox: String,
+    #[serde(default)]
+    pub shared_inbox: Option<String>,
+    pub preferred_username: String,
+    /// Authority of `actor_id` (host, plus port when non-default).
+    pub host: String,
+    pub display_name: String,
+    #[serde(default)]
+    pub icon_url: Option<String>,
+
+    pub key_id: String,
+    pub public_key_pem: String,
+
+    pub fetched_at: i64,
+}
+
+impl RemoteActor {
+    /// `user@host`, the handle fediverse software displays.
+    pub fn handle(&self) -> String {
+        format!("{}@{}", self.preferred_username, self.host)
+    }
+
+    /// Display name, falling back to the handle for actors without one.
+    pub fn name_or_handle(&self) -> String {
+        if self.display_name.trim().is_empty() {
+            self.handle()
+        } else {
+            self.display_name.clone()
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl RemoteActor {
+    pub fn keys(actor_id: &str) -> (Partition, EntityType) {
+        (
+            Partition::RemoteActor(super::hashed_id(actor_id)),
+            EntityType::RemoteActor,
+        )
+    }
+}
@@ -0,0 +1,175 @@
+//! ActivityPub and WebFinger routes. Remote servers fetch and deliver
+//! without cookies, so these routes are unauthenticated; the inbox
+//! authenticates each request by its HTTP signature instead.
+
+use serde_json::Value;
+
+use crate::common::axum::{
+    Router,
+    body::Bytes,
+    extract::{Path, Query},
+    http::{HeaderMap, StatusCode, Uri, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use crate::common::*;
+use crate::features::federation::services::actors::{self, ACTIVITY_JSON, ActorKind, JRD_JSON};
+use crate::features::federation::services::{inbox, keys, objects};
+use crate::features::federation::types::FederationError;
+
+pub fn router() -> Router {
+    let mut router = Router::new()
+        .route("/.well-known/webfinger", get(webfinger))
+        .route("/ap/posts/{id}", get(post_object));
+
+    for kind in [ActorKind::User, ActorKind::Team] {
+        let base = format!("/ap/{}/{{id}}", kind.segment());
+        router = router
+            .route(&base, get(move |Path(id): Path<String>| actor(kind, id)))
+            .route(
+                &format!("{base}/outbox"),
+                get(
+                    move |Path(id): Path<String>, Query(q): Query<OutboxQuery>| outbox(kind, id, q),
+                ),
+            )
+            .route(
+                &format!("{base}/followers"),
+                get(move |Path(id): Path<String>| followers(kind, id)),
+            )
+            .route(
+                &format!("{base}/inbox"),
+                post(
+                    move |Path(id): Path<String>, uri: Uri, headers: HeaderMap, body: Bytes| {
+                        receive(kind, id, uri, headers, body)
+                    },
+                ),
+            );
+    }
+    router
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct WebfingerQuery {
+    resource: String,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct OutboxQuery {
+    page: Option<String>,
+    cursor: Option<String>,
+}
+
+fn json_response(content_type: &'static str, value: Value) -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, "public, max-age=300"),
+        ],
+        value.to_string(),
+    )
+        .into_response()
+}
+
+/// Federation errors keep their status; anything else is logged and
+/// surfaces as a storage failure rather than leaking internals.
+fn error_response(route: &str, e: Error) -> Response {
+    match e {
+        Error::Federation(e) => e.into_response(),
+        e => {
+            tracing::error!(route, error = %e, "federation request failed");
+            FederationError::StorageFailed.into_response()
+        }
+    }
+}
+
+async fn webfinger(Query(q): Query<WebfingerQuery>) -> Response {
+    let username = match actors::parse_acct(&q.resource) {
+        Ok(name) => name,
+        Err(e) => return e.into_response(),
+    };
+    let conf = crate::common::CommonConfig::default();
+    match actors::find_by_username(conf.dynamodb(), &username).await {
+        Ok(actor) => json_response(JRD_JSON, actors::webfinger_document(&actor)),
+        Err(e) => error_response("webfinger", e),
+    }
+}
+
+async fn actor(kind: ActorKind, id: String) -> Response {
+    let conf = crate::common::CommonConfig::default();
+    let cli = conf.dynamodb();
+    let result = async {
+        let actor = actors::find(cli, kind, &id).await?;
+        let pem = keys::public_key_pem(cli, &actor).await?;
+        Ok::<_, Error>(actors::actor_document(&actor, &pem))
+    }
+    .await;
+    match result {
+        Ok(doc) => json_response(ACTIVITY_JSON, doc),
+        Err(e) => error_response("actor", e),
+    }
+}
+
+async fn outbox(kind: ActorKind, id: String, q: OutboxQuery) -> Response {
+    let conf = crate::common::CommonConfig::default();
+    let cli = conf.dynamodb();
+    let result = async {
+        let actor = actors::find(cli, kind, &id).await?;
+        if q.page.is_none() {
+            return Ok(objects::outbox_collection(&actor));
+        }
+        objects::outbox_page(cli, &actor, q.cursor).await
+    }
+    .await;
+    match result {
+        Ok(doc) => json_response(ACTIVITY_JSON, doc),
+        Err(e) => error_response("outbox", e),
+    }
+}
+
+async fn followers(kind: ActorKind, id: String) -> Response {
+    let conf = crate::common::CommonConfig::default();
+    match actors::find(conf.dynamodb(), kind, &id).await {
+        Ok(actor) => json_response(ACTIVITY_JSON, objects::followers_collection(&actor)),
+        Err(e) => error_response("followers", e),
+    }
+}
+
+async fn post_object(Path(id): Path<String>) -> Response {
+    let conf = crate::common::CommonConfig::default();
+    let cli = conf.dynamodb();
+    let result = async {
+        let post = objects::find_public_post(cli, &id).await?;
+        let author = actors::find_by_pk(cli, &post.user_pk).await?;
+        Ok::<_, Error>(objects::with_context(objects::post_object(&post, &author)))
+    }
+    .await;
+    match result {
+        Ok(doc) => json_response(ACTIVITY_JSON, doc),
+        Err(e) => error_response("post object", e),
+    }
+}
+
+async fn receive(
+    kind: ActorKind,
+    id: String,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let conf = crate::common::CommonConfig::default();
+    let cli = conf.dynamodb();
+    let path_and_query = uri
+        .path_and_query()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| uri.path().to_string());
+    let result = async {
+        let recipient = actors::find(cli, kind, &id).await?;
+        inbox::handle(cli, &recipient, &path_and_query, &headers, &body).await
+    }
+    .await;
+    match result {
+        Ok(()) => StatusCode::ACCEPTED.into_response(),
+        Err(e) => error_response("inbox", e),
+    }
+}
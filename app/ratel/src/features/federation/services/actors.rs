@@ -0,0 +1,290 @@
+//! Local users and teams as ActivityPub actors. Actor ids are keyed by the
+//! pk uuid rather than the username so they survive renames; the human
+//! readable `acct:` handle only lives in WebFinger.
+
+use serde_json::{Value, json};
+
+use crate::common::config::site_base_url;
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::federation::types::FederationError;
+use crate::features::posts::models::Team;
+
+pub const AS_CONTEXT: &str = "https://www.w3.org/ns/activitystreams";
+pub const SECURITY_CONTEXT: &str = "https://w3id.org/security/v1";
+/// Special collection addressing an object to everyone.
+pub const AS_PUBLIC: &str = "https://www.w3.org/ns/activitystreams#Public";
+pub const ACTIVITY_JSON: &str = "application/activity+json";
+pub const JRD_JSON: &str = "application/jrd+json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActorKind {
+    User,
+    Team,
+}
+
+impl ActorKind {
+    /// Path segment under `/ap/`.
+    pub fn segment(self) -> &'static str {
+        match self {
+            ActorKind::User => "users",
+            ActorKind::Team => "teams",
+        }
+    }
+
+    fn actor_type(self) -> &'static str {
+        match self {
+            ActorKind::User => "Person",
+            ActorKind::Team => "Organization",
+        }
+    }
+}
+
+/// The slice of a `User` / `Team` an actor document needs.
+#[derive(Debug, Clone)]
+pub struct LocalActor {
+    pub kind: ActorKind,
+    pub pk: Partition,
+    /// Inner pk value; the last segment of the actor id.
+    pub id: String,
+    pub username: String,
+    pub display_name: String,
+    pub description: String,
+    pub avatar_url: String,
+    pub followers: i64,
+}
+
+impl LocalActor {
+    fn from_user(user: User) -> Option<Self> {
+        if user.deleted_at.is_some() {
+            return None;
+        }
+        let Partition::User(id) = &user.pk else {
+            return None;
+        };
+        Some(Self {
+            kind: ActorKind::User,
+            id: id.clone(),
+            pk: user.pk,
+            username: user.username,
+            display_name: user.display_name,
+            description: user.description,
+            avatar_url: user.profile_url,
+            followers: user.followers_count,
+        })
+    }
+
+    fn from_team(team: Team) -> Option<Self> {
+        let Partition::Team(id) = &team.pk else {
+            return None;
+        };
+        Some(Self {
+            kind: ActorKind::Team,
+            id: id.clone(),
+            pk: team.pk,
+            username: team.username,
+            display_name: team.display_name,
+            description: team.description,
+            avatar_url: team.profile_url,
+            followers: team.followers,
+        })
+    }
+
+    pub fn actor_id(&self) -> String {
+        actor_url(self.kind, &self.id)
+    }
+
+    pub fn key_id(&self) -> String {
+        format!("{}#main-key", self.actor_id())
+    }
+
+    pub fn inbox_url(&self) -> String {
+        format!("{}/inbox", self.actor_id())
+    }
+
+    pub fn outbox_url(&self) -> String {
+        format!("{}/outbox", self.actor_id())
+    }
+
+    pub fn followers_url(&self) -> String {
+        format!("{}/followers", self.actor_id())
+    }
+
+    /// Human-facing profile page, linked from the actor's `url`.
+    pub fn profile_page_url(&self) -> String {
+        format!("{}/{}", site_base_url(), self.username)
+    }
+}
+
+pub fn actor_url(kind: ActorKind, id: &str) -> String {
+    format!("{}/ap/{}/{}", site_base_url(), kind.segment(), id)
+}
+
+/// Inverse of [`actor_url`], for activities that target a local actor.
+pub fn parse_actor_url(url: &str) -> Option<(ActorKind, String)> {
+    let rest = url.strip_prefix(site_base_url())?.strip_prefix("/ap/")?;
+    let (segment, id) = rest.split_once('/')?;
+    let kind = match segment {
+        "users" => ActorKind::User,
+        "teams" => ActorKind::Team,
+        _ => return None,
+    };
+    if id.is_empty() || id.contains('/') {
+        return None;
+    }
+    Some((kind, id.to_string()))
+}
+
+/// Authority of the site base URL — the `host` in `acct:user@host`.
+pub fn site_host() -> String {
+    url::Url::parse(site_base_url())
+        .map(|u| super::http_signature::authority(&u))
+        .unwrap_or_default()
+}
+
+pub async fn find(cli: &aws_sdk_dynamodb::Client, kind: ActorKind, id: &str) -> Result<LocalActor> {
+    let pk = match kind {
+        ActorKind::User => Partition::User(id.to_string()),
+        ActorKind::Team => Partition::Team(id.to_string()),
+    };
+    find_by_pk(cli, &pk).await
+}
+
+pub async fn find_by_pk(cli: &aws_sdk_dynamodb::Client, pk: &Partition) -> Result<LocalActor> {
+    let actor = match pk {
+        Partition::User(_) => User::get(cli, pk.clone(), Some(EntityType::User))
+            .await?
+            .and_then(LocalActor::from_user),
+        Partition::Team(_) => Team::get(cli, pk.clone(), Some(EntityType::Team))
+            .await?
+            .and_then(LocalActor::from_team),
+        _ => None,
+    };
+    actor.ok_or_else(|| FederationError::ActorNotFound.into())
+}
+
+/// Usernames are unique across users and teams; users are checked first
+/// as they are the common case.
+pub async fn find_by_username(
+    cli: &aws_sdk_dynamodb::Client,
+    username: &str,
+) -> Result<LocalActor> {
+    let (users, _) =
+        User::find_by_username(cli, &username.to_string(), User::opt().limit(1)).await?;
+    if let Some(actor) = users
+        .into_iter()
+        .find(|u| u.username == username)
+        .and_then(LocalActor::from_user)
+    {
+        return Ok(actor);
+    }
+
+    let opt = Team::opt().sk(Team::compose_gsi2_sk(String::default()));
+    let (teams, _) = Team::find_by_username_prefix(cli, username.to_string(), opt).await?;
+    teams
+        .into_iter()
+        .find(|t| t.username == username)
+        .and_then(LocalActor::from_team)
+        .ok_or_else(|| FederationError::ActorNotFound.into())
+}
+
+/// `acct:name@host` (or a bare `name@host`) → `name`, provided `host` is
+/// this site.
+pub fn parse_acct(resource: &str) -> std::result::Result<String, FederationError> {
+    let acct = resource.strip_prefix("acct:").unwrap_or(resource);
+    let acct = acct.strip_prefix('@').unwrap_or(acct);
+    let (name, host) = acct
+        .rsplit_once('@')
+        .ok_or(FederationError::InvalidResource)?;
+    if name.is_empty() {
+        return Err(FederationError::InvalidResource);
+    }
+    if !host.eq_ignore_ascii_case(&site_host()) {
+        return Err(FederationError::ActorNotFound);
+    }
+    Ok(name.to_string())
+}
+
+pub fn webfinger_document(actor: &LocalActor) -> Value {
+    json!({
+        "subject": format!("acct:{}@{}", actor.username, site_host()),
+        "aliases": [actor.actor_id(), actor.profile_page_url()],
+        "links": [
+            {
+                "rel": "self",
+                "type": ACTIVITY_JSON,
+                "href": actor.actor_id(),
+            },
+            {
+                "rel": "http://webfinger.net/rel/profile-page",
+                "type": "text/html",
+                "href": actor.profile_page_url(),
+            },
+        ],
+    })
+}
+
+pub fn actor_document(actor: &LocalActor, public_key_pem: &str) -> Value {
+    let actor_id = actor.actor_id();
+    let mut doc = json!({
+        "@context": [AS_CONTEXT, SECURITY_CONTEXT],
+        "id": actor_id,
+        "type": actor.kind.actor_type(),
+        "preferredUsername": actor.username,
+        "name": actor.display_name,
+        "summary": actor.description,
+        "url": actor.profile_page_url(),
+        "inbox": actor.inbox_url(),
+        "outbox": actor.outbox_url(),
+        "followers": actor.followers_url(),
+        "manuallyApprovesFollowers": false,
+        "discoverable": true,
+        "publicKey": {
+            "id": actor.key_id(),
+            "owner": actor_id,
+            "publicKeyPem": public_key_pem,
+        },
+    });
+    if !actor.avatar_url.is_empty() {
+        doc["icon"] = json!({ "type": "Image", "url": actor.avatar_url });
+    }
+    doc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actor_url_roundtrip() {
+        let url = actor_url(ActorKind::Team, "abc");
+        assert_eq!(
+            parse_actor_url(&url),
+            Some((ActorKind::Team, "abc".to_string()))
+        );
+        assert_eq!(parse_actor_url(&format!("{url}/inbox")), None);
+        assert_eq!(
+            parse_actor_url("https://elsewhere.example/ap/users/abc"),
+            None
+        );
+    }
+
+    #[test]
+    fn acct_must_name_this_host() {
+        let host = site_host();
+        assert_eq!(parse_acct(&format!("acct:alice@{host}")).unwrap(), "alice");
+        assert_eq!(parse_acct(&format!("@alice@{host}")).unwrap(), "alice");
+        assert!(matches!(
+            parse_acct("acct:alice@mastodon.social"),
+            Err(FederationError::ActorNotFound)
+        ));
+        assert!(matches!(
+            parse_acct("alice"),
+            Err(FederationError::InvalidResource)
+        ));
+        assert!(matches!(
+            parse_acct(&format!("acct:@{host}")),
+            Err(FederationError::InvalidResource)
+        ));
+    }
+}
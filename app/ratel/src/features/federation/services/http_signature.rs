@@ -0,0 +1,334 @@
+//! HTTP Signatures (draft-cavage-http-signatures-12), the dialect every
+//! ActivityPub server speaks. Only `rsa-sha256` / `hs2019` over RSA keys
+//! is supported — that is all Mastodon emits or accepts.
+//!
+//! Outgoing requests sign `(request-target) host date [digest]`; incoming
+//! POSTs must sign at least that set, carry a matching `Digest` and a
+//! `Date` within [`MAX_CLOCK_SKEW_SECS`].
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as B64;
+use chrono::{DateTime, Utc};
+use rsa::pkcs1::DecodeRsaPublicKey;
+use rsa::pkcs1v15::{Signature, SigningKey, VerifyingKey};
+use rsa::pkcs8::DecodePublicKey;
+use rsa::signature::{SignatureEncoding, Signer, Verifier};
+use rsa::{RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha256};
+
+/// Mastodon's window; deliveries retried from a queue can be stale-ish.
+pub const MAX_CLOCK_SKEW_SECS: i64 = 12 * 60 * 60;
+
+/// Headers an inbox POST must cover before it is trusted.
+pub const REQUIRED_POST_HEADERS: [&str; 4] = ["(request-target)", "host", "date", "digest"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureHeader {
+    pub key_id: String,
+    pub algorithm: Option<String>,
+    /// Lower-cased, in signing order. Defaults to `["date"]` per spec.
+    pub headers: Vec<String>,
+    pub signature: Vec<u8>,
+}
+
+impl SignatureHeader {
+    /// Actor URL the key belongs to, by the universal `actor#main-key`
+    /// convention. Still checked against the fetched `publicKey.owner`.
+    pub fn key_owner(&self) -> &str {
+        self.key_id
+            .split_once('#')
+            .map_or(self.key_id.as_str(), |(owner, _)| owner)
+    }
+
+    pub fn covers(&self, required: &[&str]) -> bool {
+        required.iter().all(|h| self.headers.iter().any(|s| s == h))
+    }
+}
+
+/// Parse `keyId="…",algorithm="…",headers="…",signature="…"`.
+pub fn parse_signature_header(value: &str) -> Option<SignatureHeader> {
+    let mut key_id = None;
+    let mut algorithm = None;
+    let mut headers = None;
+    let mut signature = None;
+
+    for part in split_params(value) {
+        let (name, raw) = part.split_once('=')?;
+        let val = raw.trim().trim_matches('"');
+        match name.trim() {
+            "keyId" => key_id = Some(val.to_string()),
+            "algorithm" => algorithm = Some(val.to_ascii_lowercase()),
+            "headers" => {
+                headers = Some(
+                    val.split_whitespace()
+                        .map(|h| h.to_ascii_lowercase())
+                        .collect(),
+                )
+            }
+            "signature" => signature = B64.decode(val).ok(),
+            _ => {}
+        }
+    }
+
+    if algorithm
+        .as_deref()
+        .is_some_and(|alg| alg != "rsa-sha256" && alg != "hs2019")
+    {
+        return None;
+    }
+
+    Some(SignatureHeader {
+        key_id: key_id.filter(|k| !k.is_empty())?,
+        algorithm,
+        headers: headers.unwrap_or_else(|| vec!["date".to_string()]),
+        signature: signature?,
+    })
+}
+
+/// Split on commas outside quoted values (base64 never contains commas,
+/// but `headers="a b"` and URLs in `keyId` may contain other separators).
+fn split_params(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut in_quotes = false;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => {
+                parts.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts.into_iter().filter(|p| !p.trim().is_empty()).collect()
+}
+
+/// `Digest` header value for a request body.
+pub fn digest_header(body: &[u8]) -> String {
+    format!("SHA-256={}", B64.encode(Sha256::digest(body)))
+}
+
+/// Whether a received `Digest` header matches the body. Other algorithms
+/// in a multi-valued header are ignored; SHA-256 must be present.
+pub fn digest_matches(header: &str, body: &[u8]) -> bool {
+    let expected = B64.encode(Sha256::digest(body));
+    header.split(',').any(|d| match d.trim().split_once('=') {
+        Some((alg, value)) => alg.eq_ignore_ascii_case("sha-256") && value == expected,
+        None => false,
+    })
+}
+
+/// Build the signing string. `lookup` returns a header value by its
+/// lower-cased name; `None` for any listed header fails the whole string.
+pub fn signing_string(
+    method: &str,
+    path_and_query: &str,
+    headers: &[String],
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Option<String> {
+    let mut lines = Vec::with_capacity(headers.len());
+    for name in headers {
+        let value = if name == "(request-target)" {
+            format!("{} {}", method.to_ascii_lowercase(), path_and_query)
+        } else {
+            lookup(name)?
+        };
+        lines.push(format!("{name}: {value}"));
+    }
+    Some(lines.join("\n"))
+}
+
+/// RFC 7231 IMF-fixdate, as `Date` headers require.
+pub fn http_date(at: DateTime<Utc>) -> String {
+    at.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+pub fn date_is_fresh(value: &str, now: DateTime<Utc>) -> bool {
+    DateTime::parse_from_rfc2822(value)
+        .map(|d| (now - d.with_timezone(&Utc)).num_seconds().abs() <= MAX_CLOCK_SKEW_SECS)
+        .unwrap_or(false)
+}
+
+pub fn sign(private_key: &RsaPrivateKey, message: &str) -> Vec<u8> {
+    SigningKey::<Sha256>::new(private_key.clone())
+        .sign(message.as_bytes())
+        .to_vec()
+}
+
+pub fn verify(public_key: &RsaPublicKey, message: &str, signature: &[u8]) -> bool {
+    let Ok(signature) = Signature::try_from(signature) else {
+        return false;
+    };
+    VerifyingKey::<Sha256>::new(public_key.clone())
+        .verify(message.as_bytes(), &signature)
+        .is_ok()
+}
+
+/// Accepts SPKI (`BEGIN PUBLIC KEY`, what Mastodon publishes) and PKCS#1
+/// (`BEGIN RSA PUBLIC KEY`, used by a few older implementations).
+pub fn parse_public_key_pem(pem: &str) -> Option<RsaPublicKey> {
+    let pem = pem.trim();
+    RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+        .ok()
+}
+
+/// Headers to attach to an outgoing request: `Host`, `Date`, `Digest`
+/// (when there is a body) and the `Signature` covering them.
+pub fn signed_request_headers(
+    method: &str,
+    url: &url::Url,
+    body: Option<&[u8]>,
+    key_id: &str,
+    private_key: &RsaPrivateKey,
+    now: DateTime<Utc>,
+) -> Vec<(&'static str, String)> {
+    let host = authority(url);
+    let date = http_date(now);
+    let digest = body.map(digest_header);
+
+    let mut names = vec![
+        "(request-target)".to_string(),
+        "host".to_string(),
+        "date".to_string(),
+    ];
+    if digest.is_some() {
+        names.push("digest".to_string());
+    }
+    let path = match url.query() {
+        Some(q) => format!("{}?{q}", url.path()),
+        None => url.path().to_string(),
+    };
+    let message = signing_string(method, &path, &names, |name| match name {
+        "host" => Some(host.clone()),
+        "date" => Some(date.clone()),
+        "digest" => digest.clone(),
+        _ => None,
+    })
+    .expect("all signed headers are known");
+    let signature = B64.encode(sign(private_key, &message));
+
+    let mut out = vec![("host", host), ("date", date)];
+    if let Some(digest) = digest {
+        out.push(("digest", digest));
+    }
+    out.push((
+        "signature",
+        format!(
+            "keyId=\"{key_id}\",algorithm=\"rsa-sha256\",headers=\"{}\",signature=\"{signature}\"",
+            names.join(" ")
+        ),
+    ));
+    out
+}
+
+/// `host[:port]` as it appears in a `Host` header.
+pub fn authority(url: &url::Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::pkcs8::{EncodePublicKey, LineEnding};
+
+    fn test_key() -> RsaPrivateKey {
+        RsaPrivateKey::new(&mut rsa::rand_core::OsRng, 1024).unwrap()
+    }
+
+    #[test]
+    fn parses_mastodon_signature_header() {
+        let header = parse_signature_header(
+            "keyId=\"https://m.example/users/alice#main-key\",algorithm=\"rsa-sha256\",\
+             headers=\"(request-target) host date digest\",signature=\"AAEC\"",
+        )
+        .unwrap();
+        assert_eq!(header.key_owner(), "https://m.example/users/alice");
+        assert_eq!(header.signature, vec![0, 1, 2]);
+        assert!(header.covers(&REQUIRED_POST_HEADERS));
+    }
+
+    #[test]
+    fn signature_header_defaults_and_rejections() {
+        let header = parse_signature_header("keyId=\"k\",signature=\"AAEC\"").unwrap();
+        assert_eq!(header.headers, vec!["date".to_string()]);
+        assert!(!header.covers(&REQUIRED_POST_HEADERS));
+
+        assert!(
+            parse_signature_header("keyId=\"k\",algorithm=\"ed25519\",signature=\"AA==\"")
+                .is_none()
+        );
+        assert!(parse_signature_header("algorithm=\"rsa-sha256\",signature=\"AA==\"").is_none());
+        assert!(parse_signature_header("keyId=\"k\"").is_none());
+    }
+
+    #[test]
+    fn digest_roundtrip() {
+        let body = br#"{"type":"Follow"}"#;
+        assert!(digest_matches(&digest_header(body), body));
+        assert!(!digest_matches(&digest_header(b"other"), body));
+    }
+
+    #[test]
+    fn signing_string_layout() {
+        let names: Vec<String> = ["(request-target)", "host", "date"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let s = signing_string("POST", "/ap/users/1/inbox", &names, |n| match n {
+            "host" => Some("ratel.foundation".into()),
+            "date" => Some("Tue, 20 Oct 2026 10:00:00 GMT".into()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(
+            s,
+            "(request-target): post /ap/users/1/inbox\nhost: ratel.foundation\n\
+             date: Tue, 20 Oct 2026 10:00:00 GMT"
+        );
+        assert!(signing_string("POST", "/", &["digest".into()], |_| None).is_none());
+    }
+
+    #[test]
+    fn date_window() {
+        let now = Utc::now();
+        assert!(date_is_fresh(&http_date(now), now));
+        let stale = now - chrono::Duration::seconds(MAX_CLOCK_SKEW_SECS + 60);
+        assert!(!date_is_fresh(&http_date(stale), now));
+        assert!(!date_is_fresh("yesterday", now));
+    }
+
+    #[test]
+    fn signed_headers_verify_with_published_pem() {
+        let key = test_key();
+        let pem = key
+            .to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+        let url = url::Url::parse("https://ratel.foundation:8443/ap/users/1/inbox?x=1").unwrap();
+        let body = b"{}";
+        let now = Utc::now();
+        let headers = signed_request_headers("POST", &url, Some(body), "k#main-key", &key, now);
+        let get = |name: &str| {
+            headers
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, v)| v.clone())
+        };
+        assert_eq!(get("host").as_deref(), Some("ratel.foundation:8443"));
+
+        let parsed = parse_signature_header(&get("signature").unwrap()).unwrap();
+        assert!(parsed.covers(&REQUIRED_POST_HEADERS));
+        let message =
+            signing_string("POST", "/ap/users/1/inbox?x=1", &parsed.headers, get).unwrap();
+        let public = parse_public_key_pem(&pem).unwrap();
+        assert!(verify(&public, &message, &parsed.signature));
+        assert!(!verify(&public, &format!("{message}x"), &parsed.signature));
+    }
+}
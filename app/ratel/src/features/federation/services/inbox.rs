@@ -0,0 +1,643 @@
+//! Signed inbox. Every POST is authenticated by its HTTP signature before
+//! the activity is looked at, and the activity's `actor` must be the
+//! signer — relays and forwarded activities are not trusted.
+//!
+//! Supported: `Follow`, `Like`, `Announce`, `Create` (replies to our
+//! posts), `Delete` (of such replies) and `Undo` of the first three.
+//! Anything else is accepted and dropped, as servers expect.
+
+use dioxus::fullstack::axum::http::HeaderMap;
+use serde_json::{Value, json};
+
+use super::actors::{AS_CONTEXT, AS_PUBLIC, LocalActor};
+use super::http_signature::{self, REQUIRED_POST_HEADERS};
+use super::keys::{self, ActorSigner};
+use super::objects::{find_public_post, parse_post_url};
+use super::remote;
+use crate::common::models::auth::UserFollow;
+use crate::common::*;
+use crate::features::auth::User;
+use crate::features::federation::models::{
+    FederatedObject, FederatedObjectKind, RemoteActor, hashed_id,
+};
+use crate::features::federation::types::FederationError;
+use crate::features::posts::models::{Post, PostComment, PostLike, Team};
+
+/// Remote replies may carry a few images; more than Ratel's own comment
+/// composer allows are dropped.
+const MAX_REPLY_IMAGES: usize = 4;
+
+pub async fn handle(
+    cli: &aws_sdk_dynamodb::Client,
+    recipient: &LocalActor,
+    path_and_query: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<()> {
+    let activity: Value =
+        serde_json::from_slice(body).map_err(|_| FederationError::InvalidActivity)?;
+
+    // Signed fetches of the sender's actor document go out as the
+    // recipient; servers in authorized-fetch mode require it.
+    let signer = keys::signer(cli, recipient).await.ok();
+    let sender = verify_request(cli, signer.as_ref(), path_and_query, headers, body).await?;
+
+    if id_of(&activity["actor"]) != Some(sender.actor_id.as_str()) {
+        return Err(FederationError::ActorMismatch.into());
+    }
+    // Ids are claimed by whoever sends them first (markers, Follow
+    // dedupe), so a server may only use ids on its own host.
+    if id_of(activity).is_some_and(|id| !hosted_by(&sender, id)) {
+        return Err(FederationError::ActorMismatch.into());
+    }
+
+    match activity["type"].as_str().unwrap_or_default() {
+        "Follow" => on_follow(cli, recipient, &sender, &activity, signer.as_ref()).await,
+        "Like" => on_like(cli, &sender, &activity).await,
+        "Announce" => on_announce(cli, &sender, &activity).await,
+        "Create" => on_create(cli, &sender, &activity["object"]).await,
+        "Delete" => on_delete(cli, &sender, &activity["object"]).await,
+        "Undo" => on_undo(cli, recipient, &sender, &activity["object"]).await,
+        other => {
+            tracing::debug!(activity_type = other, actor = %sender.actor_id, "inbox: ignored");
+            Ok(())
+        }
+    }
+}
+
+/// Authenticate the request and return the actor that signed it.
+async fn verify_request(
+    cli: &aws_sdk_dynamodb::Client,
+    signer: Option<&ActorSigner>,
+    path_and_query: &str,
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<RemoteActor> {
+    let header = |name: &str| -> Option<String> {
+        let values: Vec<&str> = headers
+            .get_all(name)
+            .iter()
+            .filter_map(|v| v.to_str().ok())
+            .collect();
+        (!values.is_empty()).then(|| values.join(", "))
+    };
+
+    let signature = header("signature")
+        .as_deref()
+        .and_then(http_signature::parse_signature_header)
+        .filter(|s| s.covers(&REQUIRED_POST_HEADERS))
+        .ok_or(FederationError::SignatureInvalid)?;
+    let digest_ok = header("digest").is_some_and(|d| http_signature::digest_matches(&d, body));
+    let date_ok =
+        header("date").is_some_and(|d| http_signature::date_is_fresh(&d, chrono::Utc::now()));
+    if !digest_ok || !date_ok {
+        return Err(FederationError::SignatureInvalid.into());
+    }
+    let message =
+        http_signature::signing_string("POST", path_and_query, &signature.headers, &header)
+            .ok_or(FederationError::SignatureInvalid)?;
+
+    let owner = signature.key_owner();
+    let verifies = |actor: &RemoteActor| {
+        actor.key_id == signature.key_id
+            && http_signature::parse_public_key_pem(&actor.public_key_pem)
+                .is_some_and(|key| http_signature::verify(&key, &message, &signature.signature))
+    };
+
+    let cached = remote::resolve_actor(cli, owner, signer, false).await?;
+    if verifies(&cached) {
+        return Ok(cached);
+    }
+    // The actor may have rotated its key since we cached it. Refetch at
+    // most once per `ACTOR_REFETCH_MIN_MS`, so bad signatures can't make
+    // us hammer the actor's server.
+    if crate::common::utils::time::now() - cached.fetched_at < remote::ACTOR_REFETCH_MIN_MS {
+        return Err(FederationError::SignatureInvalid.into());
+    }
+    let fresh = remote::resolve_actor(cli, owner, signer, true).await?;
+    if verifies(&fresh) {
+        return Ok(fresh);
+    }
+    Err(FederationError::SignatureInvalid.into())
+}
+
+/// Whether `id` lives on the sender's own server.
+fn hosted_by(sender: &RemoteActor, id: &str) -> bool {
+    url::Url::parse(id).is_ok_and(|url| http_signature::authority(&url) == sender.host)
+}
+
+/// `id` of an embedded object, or the value itself when it is a bare URL.
+fn id_of(value: &Value) -> Option<&str> {
+    value.as_str().or_else(|| value["id"].as_str())
+}
+
+fn is_public(object: &Value) -> bool {
+    let addressed = |field: &Value| match field {
+        Value::String(s) => is_public_collection(s),
+        Value::Array(items) => items
+            .iter()
+            .any(|v| v.as_str().is_some_and(is_public_collection)),
+        _ => false,
+    };
+    addressed(&object["to"]) || addressed(&object["cc"])
+}
+
+/// JSON-LD compaction lets servers shorten the public collection.
+fn is_public_collection(id: &str) -> bool {
+    id == AS_PUBLIC || id == "as:Public" || id == "Public"
+}
+
+async fn transact(
+    cli: &aws_sdk_dynamodb::Client,
+    items: Vec<aws_sdk_dynamodb::types::TransactWriteItem>,
+    what: &str,
+) -> Result<()> {
+    cli.transact_write_items()
+        .set_transact_items(Some(items))
+        .send()
+        .await
+        .map_err(|e| {
+            crate::error!("federation {what} failed: {e}");
+            FederationError::StorageFailed
+        })?;
+    Ok(())
+}
+
+/// Follower counter update for a local actor; `delta` may be negative.
+fn followers_update(
+    target_pk: &Partition,
+    delta: i64,
+) -> Option<aws_sdk_dynamodb::types::TransactWriteItem> {
+    let item = match target_pk {
+        Partition::User(_) => User::updater(target_pk.clone(), EntityType::User)
+            .increase_followers_count(delta)
+            .transact_write_item(),
+        Partition::Team(_) => Team::updater(target_pk.clone(), EntityType::Team)
+            .increase_followers(delta)
+            .transact_write_item(),
+        _ => return None,
+    };
+    Some(item)
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Follow
+// ─────────────────────────────────────────────────────────────────────────
+
+async fn on_follow(
+    cli: &aws_sdk_dynamodb::Client,
+    recipient: &LocalActor,
+    sender: &RemoteActor,
+    activity: &Value,
+    signer: Option<&ActorSigner>,
+) -> Result<()> {
+    if id_of(&activity["object"]) != Some(recipient.actor_id().as_str()) {
+        return Err(FederationError::InvalidActivity.into());
+    }
+    let Some(activity_id) = id_of(activity) else {
+        return Err(FederationError::InvalidActivity.into());
+    };
+    // A Follow we already accepted is a replay; answering it again would
+    // let anyone holding a copy make us re-send Accepts.
+    let (marker_pk, marker_sk) = FederatedObject::keys(activity_id);
+    if FederatedObject::get(cli, marker_pk.clone(), Some(marker_sk.clone()))
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let (pk, sk) = UserFollow::follower_keys(&recipient.pk, &sender.pk);
+    if UserFollow::get(cli, pk, Some(sk)).await?.is_none() {
+        // Only the follower side is stored: the remote actor has no
+        // `Following` list on Ratel to keep in sync.
+        let (follower_record, _) = UserFollow::new_follow_records_with_space(
+            sender.pk.clone(),
+            recipient.pk.clone(),
+            None,
+            None,
+            Some(sender.name_or_handle()),
+            sender.icon_url.clone(),
+        );
+        let mut items = vec![follower_record.create_transact_write_item()];
+        items.extend(followers_update(&recipient.pk, 1));
+        transact(cli, items, "follow").await?;
+    }
+
+    // Until an Accept goes through, redeliveries are answered again: the
+    // sender may never have received the first one. A failed Accept
+    // leaves the follow pending on their side and they will retry, so it
+    // is not an error here.
+    let Some(signer) = signer else {
+        tracing::warn!(recipient = %recipient.pk, "follow accepted without a signing key");
+        return Ok(());
+    };
+    let accept = json!({
+        "@context": AS_CONTEXT,
+        "id": format!(
+            "{}#accepts/follows/{}",
+            recipient.actor_id(),
+            hashed_id(activity_id)
+        ),
+        "type": "Accept",
+        "actor": recipient.actor_id(),
+        "object": activity,
+    });
+    if let Err(e) = remote::deliver(&sender.inbox, &accept, signer).await {
+        tracing::warn!(error = %e, inbox = %sender.inbox, "follow Accept delivery failed");
+        return Ok(());
+    }
+    let marker = FederatedObject {
+        pk: marker_pk,
+        sk: marker_sk,
+        object_id: activity_id.to_string(),
+        kind: FederatedObjectKind::Follow,
+        actor_pk: sender.pk.clone(),
+        post_pk: recipient.pk.clone(),
+        comment_pk: None,
+        comment_sk: None,
+        created_at: crate::common::utils::time::now(),
+    };
+    if let Err(e) = marker.create(cli).await {
+        tracing::warn!(error = %e, activity_id, "follow marker write failed");
+    }
+    Ok(())
+}
+
+async fn on_unfollow(
+    cli: &aws_sdk_dynamodb::Client,
+    recipient: &LocalActor,
+    sender: &RemoteActor,
+    follow: &Value,
+) -> Result<()> {
+    if id_of(&follow["object"]) != Some(recipient.actor_id().as_str()) {
+        return Ok(());
+    }
+    let (pk, sk) = UserFollow::follower_keys(&recipient.pk, &sender.pk);
+    if UserFollow::get(cli, pk.clone(), Some(sk.clone()))
+        .await?
+        .is_none()
+    {
+        return Ok(());
+    }
+    let mut items = vec![UserFollow::delete_transact_write_item(pk, sk)];
+    items.extend(followers_update(&recipient.pk, -1));
+    transact(cli, items, "unfollow").await
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Like / Announce
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Local post an activity's `object` points at, if it is one of ours.
+async fn target_post(cli: &aws_sdk_dynamodb::Client, activity: &Value) -> Result<Option<Post>> {
+    let Some(post_id) = id_of(&activity["object"]).and_then(parse_post_url) else {
+        return Ok(None);
+    };
+    match find_public_post(cli, &post_id).await {
+        Ok(post) => Ok(Some(post)),
+        Err(Error::Federation(FederationError::ObjectNotFound)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+async fn on_like(
+    cli: &aws_sdk_dynamodb::Client,
+    sender: &RemoteActor,
+    activity: &Value,
+) -> Result<()> {
+    let Some(post) = target_post(cli, activity).await? else {
+        return Ok(());
+    };
+    if PostLike::find_one(cli, &post.pk, &sender.pk)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+    Post::like(cli, post.pk, sender.pk.clone()).await
+}
+
+async fn on_unlike(
+    cli: &aws_sdk_dynamodb::Client,
+    sender: &RemoteActor,
+    like: &Value,
+) -> Result<()> {
+    let Some(post) = target_post(cli, like).await? else {
+        return Ok(());
+    };
+    if PostLike::find_one(cli, &post.pk, &sender.pk)
+        .await?
+        .is_none()
+    {
+        return Ok(());
+    }
+    Post::unlike(cli, post.pk, sender.pk.clone()).await
+}
+
+async fn on_announce(
+    cli: &aws_sdk_dynamodb::Client,
+    sender: &RemoteActor,
+    activity: &Value,
+) -> Result<()> {
+    let Some(activity_id) = id_of(activity) else {
+        return Err(FederationError::InvalidActivity.into());
+    };
+    let Some(post) = target_post(cli, activity).await? else {
+        return Ok(());
+    };
+    let (pk, sk) = FederatedObject::keys(activity_id);
+    if FederatedObject::get(cli, pk.clone(), Some(sk.clone()))
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let marker = FederatedObject {
+        pk,
+        sk,
+        object_id: activity_id.to_string(),
+        kind: FederatedObjectKind::Announce,
+        actor_pk: sender.pk.clone(),
+        post_pk: post.pk.clone(),
+        comment_pk: None,
+        comment_sk: None,
+        created_at: crate::common::utils::time::now(),
+    };
+    let items = vec![
+        marker.create_transact_write_item(),
+        Post::updater(&post.pk, EntityType::Post)
+            .increase_shares(1)
+            .transact_write_item(),
+    ];
+    transact(cli, items, "announce").await
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Undo
+// ─────────────────────────────────────────────────────────────────────────
+
+async fn on_undo(
+    cli: &aws_sdk_dynamodb::Client,
+    recipient: &LocalActor,
+    sender: &RemoteActor,
+    object: &Value,
+) -> Result<()> {
+    // The undone activity must be the sender's own.
+    if object.is_object() && id_of(&object["actor"]) != Some(sender.actor_id.as_str()) {
+        return Err(FederationError::ActorMismatch.into());
+    }
+    match object["type"].as_str() {
+        Some("Follow") => on_unfollow(cli, recipient, sender, object).await,
+        Some("Like") => on_unlike(cli, sender, object).await,
+        // Announces are tracked by id, so a bare id is enough to undo one.
+        Some("Announce") | None => match id_of(object) {
+            Some(id) => on_unannounce(cli, sender, id).await,
+            None => Ok(()),
+        },
+        Some(_) => Ok(()),
+    }
+}
+
+async fn on_unannounce(
+    cli: &aws_sdk_dynamodb::Client,
+    sender: &RemoteActor,
+    activity_id: &str,
+) -> Result<()> {
+    let (pk, sk) = FederatedObject::keys(activity_id);
+    let Some(marker) = FederatedObject::get(cli, pk, Some(sk)).await? else {
+        return Ok(());
+    };
+    if marker.kind != FederatedObjectKind::Announce || marker.actor_pk != sender.pk {
+        return Ok(());
+    }
+    let items = vec![
+        FederatedObject::delete_transact_write_item(marker.pk, marker.sk),
+        Post::updater(&marker.post_pk, EntityType::Post)
+            .decrease_shares(1)
+            .transact_write_item(),
+    ];
+    transact(cli, items, "unannounce").await
+}
+
+// ─────────────────────────────────────────────────────────────────────────
+// Replies
+// ─────────────────────────────────────────────────────────────────────────
+
+/// Where a remote reply lands: directly under a post, or under one of
+/// that post's top-level comments (Ratel threads are one level deep, so
+/// replies to replies attach to the same top-level comment).
+enum ReplyTarget {
+    Post(Partition),
+    Comment {
+        post_pk: Partition,
+        parent_id: String,
+    },
+}
+
+async fn reply_target(
+    cli: &aws_sdk_dynamodb::Client,
+    in_reply_to: &str,
+) -> Result<Option<ReplyTarget>> {
+    if let Some(post_id) = parse_post_url(in_reply_to) {
+        return match find_public_post(cli, &post_id).await {
+            Ok(post) => Ok(Some(ReplyTarget::Post(post.pk))),
+            Err(Error::Federation(FederationError::ObjectNotFound)) => Ok(None),
+            Err(e) => Err(e),
+        };
+    }
+
+    let (pk, sk) = FederatedObject::keys(in_reply_to);
+    let Some(marker) = FederatedObject::get(cli, pk, Some(sk)).await? else {
+        return Ok(None);
+    };
+    let parent_id = match marker.comment_sk {
+        Some(EntityType::PostComment(id)) => id,
+        Some(EntityType::PostCommentReply(parent_id, _)) => parent_id,
+        _ => return Ok(None),
+    };
+    Ok(Some(ReplyTarget::Comment {
+        post_pk: marker.post_pk,
+        parent_id,
+    }))
+}
+
+fn reply_images(object: &Value) -> Vec<String> {
+    let Some(attachments) = object["attachment"].as_array() else {
+        return vec![];
+    };
+    attachments
+        .iter()
+        .filter(|a| {
+            a["type"].as_str() == Some("Image")
+                || a["mediaType"]
+                    .as_str()
+                    .is_some_and(|m| m.starts_with("image/"))
+        })
+        .filter_map(|a| a["url"].as_str().map(str::to_string))
+        .take(MAX_REPLY_IMAGES)
+        .collect()
+}
+
+async fn on_create(
+    cli: &aws_sdk_dynamodb::Client,
+    sender: &RemoteActor,
+    object: &Value,
+) -> Result<()> {
+    if object["type"].as_str() != Some("Note")
+        || id_of(&object["attributedTo"]) != Some(sender.actor_id.as_str())
+    {
+        return Ok(());
+    }
+    // Followers-only and direct replies stay off public Ratel threads.
+    if !is_public(object) {
+        return Ok(());
+    }
+    let (Some(object_id), Some(in_reply_to)) = (id_of(object), id_of(&object["inReplyTo"])) else {
+        return Ok(());
+    };
+    if !hosted_by(sender, object_id) {
+        return Err(FederationError::ActorMismatch.into());
+    }
+    let content = ContentBody::html(object["content"].as_str().unwrap_or_default()).to_plain_text();
+    let images = reply_images(object);
+    if content.trim().is_empty() && images.is_empty() {
+        return Ok(());
+    }
+
+    let (marker_pk, marker_sk) = FederatedObject::keys(object_id);
+    if FederatedObject::get(cli, marker_pk.clone(), Some(marker_sk.clone()))
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+    let Some(target) = reply_target(cli, in_reply_to).await? else {
+        return Ok(());
+    };
+
+    let uuid = crate::features::auth::utils::uuid::sorted_uuid();
+    let (post_pk, comment_pk, comment_sk, parent_comment_sk) = match target {
+        ReplyTarget::Post(post_pk) => (
+            post_pk.clone(),
+            post_pk,
+            EntityType::PostComment(uuid.to_string()),
+            None,
+        ),
+        ReplyTarget::Comment { post_pk, parent_id } => (
+            post_pk.clone(),
+            Partition::PostReply(post_pk.to_string()),
+            EntityType::PostCommentReply(parent_id.clone(), uuid.to_string()),
+            Some(EntityType::PostComment(parent_id)),
+        ),
+    };
+
+    let comment = PostComment {
+        pk: comment_pk.clone(),
+        sk: comment_sk.clone(),
+        updated_at: chrono::Utc::now().timestamp(),
+        content,
+        images,
+        likes: 0,
+        reports: 0,
+        replies: 0,
+        parent_comment_sk: parent_comment_sk.clone(),
+        author_pk: sender.pk.clone(),
+        author_display_name: sender.name_or_handle(),
+        author_username: sender.handle(),
+        author_profile_url: sender.icon_url.clone().unwrap_or_default(),
+    };
+    let marker = FederatedObject {
+        pk: marker_pk,
+        sk: marker_sk,
+        object_id: object_id.to_string(),
+        kind: FederatedObjectKind::Reply,
+        actor_pk: sender.pk.clone(),
+        post_pk: post_pk.clone(),
+        comment_pk: Some(comment_pk),
+        comment_sk: Some(comment_sk),
+        created_at: crate::common::utils::time::now(),
+    };
+
+    let mut items = vec![
+        comment.create_transact_write_item(),
+        marker.create_transact_write_item(),
+        Post::updater(&post_pk, EntityType::Post)
+            .increase_comments(1)
+            .transact_write_item(),
+    ];
+    if let Some(parent_sk) = parent_comment_sk {
+        items.push(
+            PostComment::updater(&post_pk, &parent_sk)
+                .increase_replies(1)
+                .transact_write_item(),
+        );
+    }
+    transact(cli, items, "reply").await
+}
+
+async fn on_delete(
+    cli: &aws_sdk_dynamodb::Client,
+    sender: &RemoteActor,
+    object: &Value,
+) -> Result<()> {
+    let Some(object_id) = id_of(object) else {
+        return Ok(());
+    };
+    let (pk, sk) = FederatedObject::keys(object_id);
+    let Some(marker) = FederatedObject::get(cli, pk, Some(sk)).await? else {
+        return Ok(());
+    };
+    if marker.kind != FederatedObjectKind::Reply || marker.actor_pk != sender.pk {
+        return Ok(());
+    }
+    let (Some(comment_pk), Some(comment_sk)) = (marker.comment_pk, marker.comment_sk) else {
+        return Ok(());
+    };
+
+    let mut items = vec![
+        FederatedObject::delete_transact_write_item(marker.pk, marker.sk),
+        PostComment::delete_transact_write_item(&comment_pk, &comment_sk),
+        Post::updater(&marker.post_pk, EntityType::Post)
+            .decrease_comments(1)
+            .transact_write_item(),
+    ];
+    if let EntityType::PostCommentReply(parent_id, _) = &comment_sk {
+        items.push(
+            PostComment::updater(&marker.post_pk, EntityType::PostComment(parent_id.clone()))
+                .decrease_replies(1)
+                .transact_write_item(),
+        );
+    }
+    transact(cli, items, "reply delete").await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_addressing_accepts_compacted_forms() {
+        assert!(is_public(&json!({ "to": [AS_PUBLIC] })));
+        assert!(is_public(&json!({ "cc": "as:Public" })));
+        assert!(!is_public(
+            &json!({ "to": ["https://m.example/users/alice/followers"] })
+        ));
+    }
+
+    #[test]
+    fn reply_images_keep_only_images() {
+        let object = json!({
+            "attachment": [
+                { "type": "Document", "mediaType": "image/png", "url": "https://m.example/a.png" },
+                { "type": "Document", "mediaType": "video/mp4", "url": "https://m.example/b.mp4" },
+                { "type": "Image", "url": "https://m.example/c.jpg" },
+            ]
+        });
+        assert_eq!(
+            reply_images(&object),
+            vec!["https://m.example/a.png", "https://m.example/c.jpg"]
+        );
+    }
+}
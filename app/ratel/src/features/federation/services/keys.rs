@@ -0,0 +1,102 @@
+//! Per-actor signing keys. Created on first use and cached in
+//! `ActorKeyPair`; the private half never leaves the server unsealed.
+
+use rsa::RsaPrivateKey;
+use rsa::pkcs8::{DecodePrivateKey, EncodePrivateKey, EncodePublicKey, LineEnding};
+
+use super::actors::LocalActor;
+use crate::common::utils::aead;
+use crate::common::*;
+use crate::features::federation::models::ActorKeyPair;
+use crate::features::federation::types::FederationError;
+
+/// Mastodon rejects anything shorter; larger keys only slow every inbox
+/// that verifies us.
+const RSA_BITS: usize = 2048;
+
+/// Key material for signing requests as one local actor.
+#[derive(Clone)]
+pub struct ActorSigner {
+    pub key_id: String,
+    pub private_key: RsaPrivateKey,
+}
+
+/// Public key PEM for the actor document, generating the pair if needed.
+pub async fn public_key_pem(cli: &aws_sdk_dynamodb::Client, actor: &LocalActor) -> Result<String> {
+    Ok(load_or_create(cli, &actor.pk).await?.public_key_pem)
+}
+
+pub async fn signer(cli: &aws_sdk_dynamodb::Client, actor: &LocalActor) -> Result<ActorSigner> {
+    let pair = load_or_create(cli, &actor.pk).await?;
+    let der = aead::open(&pair.private_key_ciphertext).map_err(|e| {
+        crate::error!("actor key open failed for {}: {e}", actor.pk);
+        FederationError::KeyUnavailable
+    })?;
+    let private_key = RsaPrivateKey::from_pkcs8_der(&der).map_err(|e| {
+        crate::error!("actor key decode failed for {}: {e}", actor.pk);
+        FederationError::KeyUnavailable
+    })?;
+    Ok(ActorSigner {
+        key_id: actor.key_id(),
+        private_key,
+    })
+}
+
+async fn load_or_create(
+    cli: &aws_sdk_dynamodb::Client,
+    actor_pk: &Partition,
+) -> Result<ActorKeyPair> {
+    let (pk, sk) = ActorKeyPair::keys(actor_pk);
+    if let Some(pair) = ActorKeyPair::get(cli, pk.clone(), Some(sk.clone())).await? {
+        return Ok(pair);
+    }
+
+    // Key generation is a few hundred ms of pure CPU; keep it off the
+    // async workers.
+    let (public_key_pem, private_key_ciphertext) =
+        tokio::task::spawn_blocking(generate).await.map_err(|e| {
+            crate::error!("actor key generation panicked: {e}");
+            FederationError::KeyUnavailable
+        })??;
+
+    let pair = ActorKeyPair {
+        pk,
+        sk,
+        public_key_pem,
+        private_key_ciphertext,
+        created_at: crate::common::utils::time::now(),
+    };
+    // Two first requests racing (actor fetch + delivery) must agree on one
+    // key: `create` and let the loser adopt the stored pair, otherwise a
+    // remote could cache a public key we no longer sign with.
+    if let Err(e) = pair.create(cli).await {
+        tracing::info!(error = %e, actor_pk = %actor_pk, "actor key create lost race; re-reading");
+        return ActorKeyPair::get(cli, pair.pk, Some(pair.sk))
+            .await?
+            .ok_or_else(|| FederationError::KeyUnavailable.into());
+    }
+    Ok(pair)
+}
+
+fn generate() -> Result<(String, Vec<u8>)> {
+    let private_key = RsaPrivateKey::new(&mut rsa::rand_core::OsRng, RSA_BITS).map_err(|e| {
+        crate::error!("actor key generation failed: {e}");
+        FederationError::KeyUnavailable
+    })?;
+    let public_key_pem = private_key
+        .to_public_key()
+        .to_public_key_pem(LineEnding::LF)
+        .map_err(|e| {
+            crate::error!("actor public key encode failed: {e}");
+            FederationError::KeyUnavailable
+        })?;
+    let der = private_key.to_pkcs8_der().map_err(|e| {
+        crate::error!("actor private key encode failed: {e}");
+        FederationError::KeyUnavailable
+    })?;
+    let sealed = aead::seal(der.as_bytes()).map_err(|e| {
+        crate::error!("actor private key seal failed: {e}");
+        FederationError::KeyUnavailable
+    })?;
+    Ok((public_key_pem, sealed))
+}
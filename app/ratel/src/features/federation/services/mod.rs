@@ -0,0 +1,6 @@
+pub mod actors;
+pub mod http_signature;
+pub mod inbox;
+pub mod keys;
+pub mod objects;
+pub mod remote;
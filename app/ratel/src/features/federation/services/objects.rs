@@ -0,0 +1,196 @@
+//! Ratel posts as ActivityStreams objects. Only published, public posts
+//! authored by a user or team federate; everything else is a 404 to
+//! remote servers, whatever the local viewer could see.
+
+use serde_json::{Value, json};
+
+use super::actors::{AS_CONTEXT, AS_PUBLIC, LocalActor};
+use crate::common::config::site_base_url;
+use crate::common::*;
+use crate::features::federation::types::FederationError;
+use crate::features::posts::models::Post;
+use crate::features::posts::types::{PostStatus, Visibility};
+
+/// Mastodon pages outboxes by 20; anything it doesn't render it ignores.
+const OUTBOX_PAGE_SIZE: i32 = 20;
+
+pub fn post_object_url(post_id: &str) -> String {
+    format!("{}/ap/posts/{}", site_base_url(), post_id)
+}
+
+/// Post id from a URL remote servers may reply to: the object id we
+/// publish, or the human `url` people paste.
+pub fn parse_post_url(url: &str) -> Option<String> {
+    let rest = url.strip_prefix(site_base_url())?;
+    let id = rest
+        .strip_prefix("/ap/posts/")
+        .or_else(|| rest.strip_prefix("/posts/"))?;
+    if id.is_empty() || id.contains('/') {
+        return None;
+    }
+    Some(id.to_string())
+}
+
+pub fn federates(post: &Post) -> bool {
+    post.is_published()
+        && post.is_public()
+        && matches!(post.user_pk, Partition::User(_) | Partition::Team(_))
+}
+
+pub async fn find_public_post(cli: &aws_sdk_dynamodb::Client, post_id: &str) -> Result<Post> {
+    Post::get(
+        cli,
+        Partition::Feed(post_id.to_string()),
+        Some(EntityType::Post),
+    )
+    .await?
+    .filter(federates)
+    .ok_or_else(|| FederationError::ObjectNotFound.into())
+}
+
+fn post_id(post: &Post) -> &str {
+    match &post.pk {
+        Partition::Feed(id) => id,
+        _ => "",
+    }
+}
+
+fn published(ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms)
+        .unwrap_or_default()
+        .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// `Article` when the post has a title (Mastodon shows the name and links
+/// out), `Note` otherwise so short posts render inline.
+pub fn post_object(post: &Post, author: &LocalActor) -> Value {
+    let id = post_id(post);
+    let title = post.title.trim();
+    let mut object = json!({
+        "id": post_object_url(id),
+        "type": if title.is_empty() { "Note" } else { "Article" },
+        "attributedTo": author.actor_id(),
+        "content": post.body.to_html(),
+        "url": format!("{}/posts/{}", site_base_url(), id),
+        "published": published(post.created_at),
+        "to": [AS_PUBLIC],
+        "cc": [author.followers_url()],
+        "attachment": post
+            .urls
+            .iter()
+            .map(|url| json!({ "type": "Image", "url": url }))
+            .collect::<Vec<_>>(),
+    });
+    if !title.is_empty() {
+        object["name"] = json!(title);
+    }
+    if post.updated_at > post.created_at {
+        object["updated"] = json!(published(post.updated_at));
+    }
+    object
+}
+
+pub fn create_activity(post: &Post, author: &LocalActor) -> Value {
+    let object = post_object(post, author);
+    json!({
+        "id": format!("{}/activity", object["id"].as_str().unwrap_or_default()),
+        "type": "Create",
+        "actor": author.actor_id(),
+        "published": object["published"],
+        "to": object["to"],
+        "cc": object["cc"],
+        "object": object,
+    })
+}
+
+pub fn with_context(mut value: Value) -> Value {
+    value["@context"] = json!(AS_CONTEXT);
+    value
+}
+
+/// Collection root: counts plus a link to the first page, as Mastodon
+/// expects when it backfills a newly followed account.
+pub fn outbox_collection(author: &LocalActor) -> Value {
+    json!({
+        "@context": AS_CONTEXT,
+        "id": author.outbox_url(),
+        "type": "OrderedCollection",
+        "first": format!("{}?page=true", author.outbox_url()),
+    })
+}
+
+/// One page of `Create` activities, newest first. `cursor` is the post
+/// query bookmark of the previous page.
+pub async fn outbox_page(
+    cli: &aws_sdk_dynamodb::Client,
+    author: &LocalActor,
+    cursor: Option<String>,
+) -> Result<Value> {
+    let mut opt = Post::opt()
+        .limit(OUTBOX_PAGE_SIZE)
+        .scan_index_forward(false)
+        .sk(format!("{}#{}", PostStatus::Published, Visibility::Public));
+    let page_id = match &cursor {
+        Some(c) => format!(
+            "{}?page=true&cursor={}",
+            author.outbox_url(),
+            urlencoding::encode(c)
+        ),
+        None => format!("{}?page=true", author.outbox_url()),
+    };
+    if let Some(cursor) = cursor {
+        opt = opt.bookmark(cursor);
+    }
+    let (posts, bookmark) = Post::find_by_user_and_status(cli, &author.pk, opt).await?;
+
+    let items: Vec<Value> = posts
+        .iter()
+        .filter(|p| federates(p))
+        .map(|p| create_activity(p, author))
+        .collect();
+    let mut page = json!({
+        "@context": AS_CONTEXT,
+        "id": page_id,
+        "type": "OrderedCollectionPage",
+        "partOf": author.outbox_url(),
+        "orderedItems": items,
+    });
+    if let Some(next) = bookmark {
+        page["next"] = json!(format!(
+            "{}?page=true&cursor={}",
+            author.outbox_url(),
+            urlencoding::encode(&next)
+        ));
+    }
+    Ok(page)
+}
+
+/// Follower count only; the list itself stays private, as on Mastodon
+/// accounts that hide their network.
+pub fn followers_collection(author: &LocalActor) -> Value {
+    json!({
+        "@context": AS_CONTEXT,
+        "id": author.followers_url(),
+        "type": "OrderedCollection",
+        "totalItems": author.followers.max(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn post_urls_parse_both_forms() {
+        assert_eq!(
+            parse_post_url(&post_object_url("p1")).as_deref(),
+            Some("p1")
+        );
+        assert_eq!(
+            parse_post_url(&format!("{}/posts/p1", site_base_url())).as_deref(),
+            Some("p1")
+        );
+        assert_eq!(parse_post_url("https://elsewhere.example/posts/p1"), None);
+        assert_eq!(parse_post_url(&format!("{}/posts/", site_base_url())), None);
+    }
+}
@@ -0,0 +1,242 @@
+//! Talking to other servers: fetching (and caching) remote actor documents
+//! and delivering signed activities to their inboxes.
+
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::actors::ACTIVITY_JSON;
+use super::http_signature;
+use super::keys::ActorSigner;
+use crate::common::*;
+use crate::features::federation::models::RemoteActor;
+use crate::features::federation::types::FederationError;
+
+/// Actor documents change rarely (display name, avatar, key rotation);
+/// a day-old copy is fine, and a failed signature forces a refetch anyway.
+const ACTOR_CACHE_TTL_MS: i64 = 24 * 60 * 60 * 1000;
+/// A failed signature refetches the actor only when the cached copy is
+/// older than this; anyone can send a bad signature naming any key.
+pub const ACTOR_REFETCH_MIN_MS: i64 = 5 * 60 * 1000;
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Both spellings of the ActivityStreams media type; servers answer
+/// HTML to anything else.
+const ACCEPT_ACTIVITY: &str = concat!(
+    "application/activity+json, ",
+    "application/ld+json; profile=\"https://www.w3.org/ns/activitystreams\""
+);
+
+fn client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .unwrap_or_default()
+}
+
+/// Whether a remote URL may be requested from the server. Remote ids come
+/// straight from untrusted JSON, so anything that could reach internal
+/// services (plain http, IP literals, localhost) is refused. Tests run a
+/// fake instance on loopback and are exempt.
+pub fn is_fetchable(url: &url::Url) -> bool {
+    if cfg!(test) {
+        return matches!(url.scheme(), "http" | "https") && url.host_str().is_some();
+    }
+    if url.scheme() != "https" || !url.username().is_empty() || url.password().is_some() {
+        return false;
+    }
+    match url.host() {
+        Some(url::Host::Domain(host)) => {
+            let host = host.to_ascii_lowercase();
+            host.contains('.') && host != "localhost" && !host.ends_with(".localhost")
+        }
+        _ => false,
+    }
+}
+
+fn parse_fetchable(raw: &str) -> std::result::Result<url::Url, FederationError> {
+    url::Url::parse(raw)
+        .ok()
+        .filter(is_fetchable)
+        .ok_or(FederationError::InvalidActivity)
+}
+
+/// Cached actor, refetched when older than the TTL or when `refresh` is
+/// set (a signature failed against the cached key).
+pub async fn resolve_actor(
+    cli: &aws_sdk_dynamodb::Client,
+    actor_id: &str,
+    signer: Option<&ActorSigner>,
+    refresh: bool,
+) -> Result<RemoteActor> {
+    let (pk, sk) = RemoteActor::keys(actor_id);
+    if !refresh {
+        if let Some(cached) = RemoteActor::get(cli, pk, Some(sk)).await? {
+            if crate::common::utils::time::now() - cached.fetched_at < ACTOR_CACHE_TTL_MS {
+                return Ok(cached);
+            }
+        }
+    }
+
+    let actor = fetch_actor(actor_id, signer).await?;
+    actor.upsert(cli).await.map_err(|e| {
+        crate::error!("remote actor cache write failed for {actor_id}: {e}");
+        FederationError::StorageFailed
+    })?;
+    Ok(actor)
+}
+
+/// Fetch an ActivityPub document. Signed when a local signer is at hand
+/// — servers in "authorized fetch" mode refuse anonymous GETs.
+pub async fn fetch_json(url: &str, signer: Option<&ActorSigner>) -> Result<Value> {
+    let parsed = parse_fetchable(url)?;
+    let mut req = client()
+        .get(parsed.clone())
+        .header(reqwest::header::ACCEPT, ACCEPT_ACTIVITY);
+    if let Some(signer) = signer {
+        for (name, value) in http_signature::signed_request_headers(
+            "GET",
+            &parsed,
+            None,
+            &signer.key_id,
+            &signer.private_key,
+            chrono::Utc::now(),
+        ) {
+            req = req.header(name, value);
+        }
+    }
+
+    let resp = req.send().await.map_err(|e| {
+        tracing::warn!(url, error = %e, "federation fetch failed");
+        FederationError::RemoteFetchFailed
+    })?;
+    if !resp.status().is_success() {
+        tracing::warn!(url, status = %resp.status(), "federation fetch rejected");
+        return Err(FederationError::RemoteFetchFailed.into());
+    }
+    resp.json::<Value>().await.map_err(|e| {
+        tracing::warn!(url, error = %e, "federation fetch returned invalid json");
+        FederationError::RemoteFetchFailed.into()
+    })
+}
+
+async fn fetch_actor(actor_id: &str, signer: Option<&ActorSigner>) -> Result<RemoteActor> {
+    let doc = fetch_json(actor_id, signer).await?;
+    parse_actor_document(actor_id, &doc).ok_or_else(|| {
+        tracing::warn!(actor_id, "remote actor document rejected");
+        FederationError::RemoteFetchFailed.into()
+    })
+}
+
+/// Validate and flatten an actor document. The document must describe
+/// the id it was fetched from and own the key it publishes — otherwise
+/// any server could vouch for keys on another's behalf.
+pub fn parse_actor_document(actor_id: &str, doc: &Value) -> Option<RemoteActor> {
+    if doc["id"].as_str()? != actor_id {
+        return None;
+    }
+    let key = &doc["publicKey"];
+    let key_id = key["id"].as_str()?;
+    if key["owner"].as_str()? != actor_id {
+        return None;
+    }
+    let public_key_pem = key["publicKeyPem"].as_str()?;
+    http_signature::parse_public_key_pem(public_key_pem)?;
+
+    let inbox = doc["inbox"].as_str()?;
+    parse_fetchable(inbox).ok()?;
+    let shared_inbox = doc["endpoints"]["sharedInbox"]
+        .as_str()
+        .filter(|s| parse_fetchable(s).is_ok())
+        .map(str::to_string);
+    let host = http_signature::authority(&url::Url::parse(actor_id).ok()?);
+    let (pk, sk) = RemoteActor::keys(actor_id);
+
+    Some(RemoteActor {
+        pk,
+        sk,
+        actor_id: actor_id.to_string(),
+        inbox: inbox.to_string(),
+        shared_inbox,
+        preferred_username: doc["preferredUsername"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        host,
+        display_name: doc["name"].as_str().unwrap_or_default().to_string(),
+        icon_url: doc["icon"]["url"].as_str().map(str::to_string),
+        key_id: key_id.to_string(),
+        public_key_pem: public_key_pem.to_string(),
+        fetched_at: crate::common::utils::time::now(),
+    })
+}
+
+/// POST a signed activity to a remote inbox.
+pub async fn deliver(inbox: &str, activity: &Value, signer: &ActorSigner) -> Result<()> {
+    let url = parse_fetchable(inbox)?;
+    let body = serde_json::to_vec(activity).map_err(|e| {
+        crate::error!("federation activity serialize failed: {e}");
+        FederationError::DeliveryFailed
+    })?;
+
+    let mut req = client()
+        .post(url.clone())
+        .header(reqwest::header::CONTENT_TYPE, ACTIVITY_JSON);
+    for (name, value) in http_signature::signed_request_headers(
+        "POST",
+        &url,
+        Some(&body),
+        &signer.key_id,
+        &signer.private_key,
+        chrono::Utc::now(),
+    ) {
+        req = req.header(name, value);
+    }
+
+    let resp = req.body(body).send().await.map_err(|e| {
+        tracing::warn!(inbox, error = %e, "federation delivery failed");
+        FederationError::DeliveryFailed
+    })?;
+    if !resp.status().is_success() {
+        tracing::warn!(inbox, status = %resp.status(), "federation delivery rejected");
+        return Err(FederationError::DeliveryFailed.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::pkcs8::{EncodePublicKey, LineEnding};
+
+    fn actor_doc(id: &str) -> Value {
+        let pem = rsa::RsaPrivateKey::new(&mut rsa::rand_core::OsRng, 1024)
+            .unwrap()
+            .to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+        serde_json::json!({
+            "id": id,
+            "type": "Person",
+            "preferredUsername": "alice",
+            "inbox": format!("{id}/inbox"),
+            "publicKey": { "id": format!("{id}#main-key"), "owner": id, "publicKeyPem": pem },
+        })
+    }
+
+    #[test]
+    fn actor_document_must_own_its_key() {
+        let id = "https://m.example/users/alice";
+        let actor = parse_actor_document(id, &actor_doc(id)).unwrap();
+        assert_eq!(actor.handle(), "alice@m.example");
+        assert_eq!(actor.key_id, format!("{id}#main-key"));
+
+        assert!(parse_actor_document("https://m.example/users/bob", &actor_doc(id)).is_none());
+        let mut stolen = actor_doc(id);
+        stolen["publicKey"]["owner"] = "https://evil.example/users/mallory".into();
+        assert!(parse_actor_document(id, &stolen).is_none());
+        let mut no_key = actor_doc(id);
+        no_key["publicKey"]["publicKeyPem"] = "not a key".into();
+        assert!(parse_actor_document(id, &no_key).is_none());
+    }
+}
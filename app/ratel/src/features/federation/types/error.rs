@@ -0,0 +1,99 @@
+use crate::common::*;
+pub use thiserror::Error;
+
+#[derive(Debug, Error, Serialize, Deserialize, Translate, Clone)]
+pub enum FederationError {
+    #[error("actor not found")]
+    #[translate(en = "Actor not found", ko = "액터를 찾을 수 없습니다")]
+    ActorNotFound,
+
+    #[error("object not found")]
+    #[translate(en = "Object not found", ko = "오브젝트를 찾을 수 없습니다")]
+    ObjectNotFound,
+
+    #[error("invalid webfinger resource")]
+    #[translate(
+        en = "Invalid WebFinger resource",
+        ko = "잘못된 WebFinger 리소스입니다"
+    )]
+    InvalidResource,
+
+    #[error("invalid activity")]
+    #[translate(en = "Invalid activity", ko = "잘못된 액티비티입니다")]
+    InvalidActivity,
+
+    #[error("http signature missing or invalid")]
+    #[translate(en = "Invalid HTTP signature", ko = "HTTP 서명이 올바르지 않습니다")]
+    SignatureInvalid,
+
+    #[error("activity actor does not match signer")]
+    #[translate(
+        en = "Activity actor does not match the signing key",
+        ko = "액티비티 작성자가 서명 키와 일치하지 않습니다"
+    )]
+    ActorMismatch,
+
+    #[error("remote actor fetch failed")]
+    #[translate(
+        en = "Failed to fetch the remote actor",
+        ko = "원격 액터를 가져오지 못했습니다"
+    )]
+    RemoteFetchFailed,
+
+    #[error("actor key unavailable")]
+    #[translate(en = "Actor key unavailable", ko = "액터 키를 사용할 수 없습니다")]
+    KeyUnavailable,
+
+    #[error("activity delivery failed")]
+    #[translate(
+        en = "Failed to deliver the activity",
+        ko = "액티비티를 전달하지 못했습니다"
+    )]
+    DeliveryFailed,
+
+    #[error("federation storage failed")]
+    #[translate(
+        en = "Failed to process the activity",
+        ko = "액티비티를 처리하지 못했습니다"
+    )]
+    StorageFailed,
+}
+
+#[cfg(feature = "server")]
+impl FederationError {
+    pub fn status_code(&self) -> crate::axum::http::StatusCode {
+        use crate::axum::http::StatusCode;
+        match self {
+            FederationError::ActorNotFound | FederationError::ObjectNotFound => {
+                StatusCode::NOT_FOUND
+            }
+            FederationError::InvalidResource | FederationError::InvalidActivity => {
+                StatusCode::BAD_REQUEST
+            }
+            FederationError::SignatureInvalid | FederationError::ActorMismatch => {
+                StatusCode::UNAUTHORIZED
+            }
+            FederationError::RemoteFetchFailed | FederationError::DeliveryFailed => {
+                StatusCode::BAD_GATEWAY
+            }
+            FederationError::KeyUnavailable | FederationError::StorageFailed => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::axum::response::IntoResponse for FederationError {
+    fn into_response(self) -> crate::axum::response::Response {
+        use crate::axum::response::IntoResponse;
+        (self.status_code(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::AsStatusCode for FederationError {
+    fn as_status_code(&self) -> crate::axum::http::StatusCode {
+        self.status_code()
+    }
+}
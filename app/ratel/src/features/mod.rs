@@ -17,6 +17,8 @@ pub mod ai_moderator;
 pub mod rag;
 
 pub mod activity;
+pub mod badge;
+pub mod calendar;
 
 pub mod character;
 
@@ -29,6 +31,7 @@ pub mod notifications;
 pub mod sub_team;
 
 pub mod cross_posting;
+pub mod federation;
 
 pub mod arcade;
 
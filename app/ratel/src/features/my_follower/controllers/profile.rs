@@ -1,4 +1,5 @@
 use crate::features::my_follower::controllers::dto::FollowUserProfile;
+use crate::features::federation::models::RemoteActor;
 use crate::features::my_follower::*;
 use crate::features::posts::models::Team;
 use std::collections::HashMap;
@@ -10,10 +11,12 @@ pub async fn build_profiles(
 ) -> Result<HashMap<String, FollowUserProfile>> {
     let mut user_keys = Vec::new();
     let mut team_keys = Vec::new();
+    let mut remote_keys = Vec::new();
 
     for pk in target_pks {
         match pk {
             Partition::Team(_) => team_keys.push((pk.clone(), EntityType::Team)),
+            Partition::RemoteActor(_) => remote_keys.push((pk.clone(), EntityType::RemoteActor)),
             _ => user_keys.push((pk.clone(), EntityType::User)),
         }
     }
@@ -28,6 +31,12 @@ pub async fn build_profiles(
     } else {
         Team::batch_get(cli, team_keys).await?
     };
+    // Fediverse followers (see `features::federation`) render by handle.
+    let remote_actors: Vec<RemoteActor> = if remote_keys.is_empty() {
+        vec![]
+    } else {
+        RemoteActor::batch_get(cli, remote_keys).await?
+    };
 
     let mut profiles = HashMap::new();
     for user in users {
@@ -54,6 +63,18 @@ pub async fn build_profiles(
             },
         );
     }
+    for actor in remote_actors {
+        profiles.insert(
+            actor.pk.to_string(),
+            FollowUserProfile {
+                display_name: actor.name_or_handle(),
+                profile_url: actor.icon_url.clone().unwrap_or_default(),
+                username: actor.handle(),
+                user_type: UserType::Individual,
+                description: String::new(),
+            },
+        );
+    }
 
     Ok(profiles)
 }
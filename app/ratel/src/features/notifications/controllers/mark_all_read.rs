@@ -62,5 +62,9 @@ pub async fn mark_all_read_handler(
         }
     }
 
+    if affected > 0 {
+        crate::features::notifications::realtime::publish_inbox_changed(&user.pk).await;
+    }
+
     Ok(MarkAllReadResponse { affected, has_more })
 }
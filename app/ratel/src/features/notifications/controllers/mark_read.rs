@@ -34,5 +34,7 @@ pub async fn mark_read_handler(inbox_id: UserInboxNotificationEntityType) -> Res
             NotificationsError::MarkReadFailed
         })?;
 
+    crate::features::notifications::realtime::publish_inbox_changed(&user.pk).await;
+
     Ok(())
 }
@@ -1,19 +1,80 @@
+use crate::common::realtime::subscribe_realtime_channel;
 use crate::common::*;
+use crate::features::auth::UserContext;
 use crate::features::notifications::controllers::get_unread_count::get_unread_count_handler;
 use std::time::Duration;
 
-const POLL_INTERVAL_SECS: u64 = 60;
+/// How often to re-check login state before the channel can be opened.
+/// Local signal read only — no network.
+const LOGIN_CHECK_SECS: u64 = 2;
+
+/// Events on `user.inbox` that change the unread count.
+const INBOX_EVENTS: &[&str] = &["inbox_changed"];
+
+/// Sends once every time the tab becomes visible again.
+#[cfg(not(feature = "server"))]
+const VISIBLE_JS: &str = r#"
+    document.addEventListener("visibilitychange", () => {
+        if (document.visibilityState === "visible") dioxus.send(true);
+    });
+"#;
+
+/// Refetch `count` whenever it may have drifted: on every `user.inbox`
+/// event, on the `subscribed` event each (re)connect delivers, and when
+/// the tab comes back into view (events are missed while a background
+/// tab's stream is suspended, and rows expire by TTL). There is no
+/// periodic resync while the channel is live. Both tasks run for the
+/// lifetime of the owning scope.
+fn spawn_unread_sync(
+    user_ctx: Store<UserContext>,
+    count: Signal<i64>,
+    space_id: Option<ReadSignal<SpacePartition>>,
+) {
+    let refetch = move || async move {
+        let mut count = count;
+        if !user_ctx().is_logged_in() {
+            return;
+        }
+        match get_unread_count_handler(space_id.map(|s| s())).await {
+            Ok(resp) => count.set(resp.count),
+            Err(e) => debug!("unread count refetch failed: {e}"),
+        }
+    };
+
+    #[cfg(not(feature = "server"))]
+    spawn(async move {
+        let mut runner = document::eval(VISIBLE_JS);
+        while runner.recv::<bool>().await.is_ok() {
+            refetch().await;
+        }
+    });
+
+    spawn(async move {
+        let user_id = loop {
+            if let Some(id) = user_ctx().user_id() {
+                break id;
+            }
+            crate::common::utils::time::sleep(Duration::from_secs(LOGIN_CHECK_SECS)).await;
+        };
+        subscribe_realtime_channel(format!("user.inbox:{user_id}"), INBOX_EVENTS, move |_| {
+            spawn(refetch());
+        })
+        .await;
+    });
+}
 
 // Shared wrapper so every caller of `use_unread_count` — `NotificationBell`
 // and `NotificationPanel` in particular — reads the same signal. Writing
 // 0 from `mark_all_read` in the panel propagates to the bell instantly
-// rather than waiting for the next poll tick.
+// rather than waiting for the next `user.inbox` event.
 #[derive(Clone, Copy)]
 struct UnreadCountSignal(Signal<i64>);
 
 /// Returns a `Signal<i64>` that tracks the current user's unread
-/// notification count. Initialized to `0` and refreshed every
-/// `POLL_INTERVAL_SECS` seconds by a single spawned async loop.
+/// notification count. Initialized to `0` and refreshed whenever the
+/// `user.inbox` realtime channel reports a change (see
+/// `features::notifications::realtime`), reconnects, or the tab regains
+/// focus.
 ///
 /// The whole initialization runs inside `use_hook`, which guarantees the
 /// closure executes exactly once per caller scope and registers a single
@@ -23,7 +84,7 @@ struct UnreadCountSignal(Signal<i64>);
 /// of the same scope, which Dioxus rejects with
 /// "rules of hooks" panic — see the bootstrap-panic we hit on 2026-04-22.
 pub fn use_unread_count() -> Signal<i64> {
-    // Read login state — the polling loop below checks it before each
+    // Read login state — the refetch below checks it before each
     // request so we avoid pointless 401s while the viewer is signed out.
     // `use_user_context` returns a `Signal<UserContext>` that's `Copy`,
     // so we can move it into the spawned future without cloning.
@@ -39,24 +100,14 @@ pub fn use_unread_count() -> Signal<i64> {
         // deliberately don't use `provide_root_context` here — that would
         // survive logout → login cycles while pointing at a signal whose
         // owning scope has already been dropped.
-        let mut count = Signal::new(0i64);
-        spawn(async move {
-            loop {
-                if user_ctx().is_logged_in() {
-                    match get_unread_count_handler(None).await {
-                        Ok(resp) => count.set(resp.count),
-                        Err(e) => debug!("use_unread_count poll failed: {e}"),
-                    }
-                }
-                crate::common::utils::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)).await;
-            }
-        });
+        let count = Signal::new(0i64);
+        spawn_unread_sync(user_ctx, count, None);
         provide_context(UnreadCountSignal(count));
         count
     })
 }
 
-/// Space-scoped variant: installs an `UnreadCountSignal` whose polling loop
+/// Space-scoped variant: installs an `UnreadCountSignal` whose refetch
 /// counts only unread notifications belonging to `space_id`. Call it **once**
 /// in the space page scope (`SpaceIndexPage`), BEFORE `use_provide_space_inbox`
 /// — descendants (the in-space `NotificationBell`) then read this scoped count
@@ -68,18 +119,8 @@ pub fn use_unread_count() -> Signal<i64> {
 pub fn use_provide_space_unread_count(space_id: ReadSignal<SpacePartition>) {
     let user_ctx = crate::features::auth::hooks::use_user_context();
     use_hook(|| {
-        let mut count = Signal::new(0i64);
-        spawn(async move {
-            loop {
-                if user_ctx().is_logged_in() {
-                    match get_unread_count_handler(Some(space_id())).await {
-                        Ok(resp) => count.set(resp.count),
-                        Err(e) => debug!("use_provide_space_unread_count poll failed: {e}"),
-                    }
-                }
-                crate::common::utils::time::sleep(Duration::from_secs(POLL_INTERVAL_SECS)).await;
-            }
-        });
+        let count = Signal::new(0i64);
+        spawn_unread_sync(user_ctx, count, Some(space_id));
         provide_context(UnreadCountSignal(count));
     });
 }
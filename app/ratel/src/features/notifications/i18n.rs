@@ -8,6 +8,7 @@ translate! {
     reply_title: { en: "{name} replied to your comment", ko: "{name}님이 답글을 남겼습니다" },
     discussion_comment_title: { en: "{name} commented on {discussion}", ko: "{name}님이 '{discussion}'에 댓글을 남겼습니다" },
     mention_title: { en: "{name} mentioned you", ko: "{name}님이 나를 언급했습니다" },
+    badge_awarded_title: { en: "You earned the {badge} badge", ko: "{badge} 배지를 획득했습니다" },
     space_status_title: { en: "{space} is now {status}", ko: "{space}가 {status}로 변경되었습니다" },
     space_invite_title: { en: "{name} invited you to {space}", ko: "{name}님이 {space}에 초대했습니다" },
     action_ongoing_title: {
@@ -18,6 +19,17 @@ translate! {
         en: "in {space_title}",
         ko: "{space_title}에서",
     },
+    meet_scheduled_title: { en: "Meet scheduled: {meet}", ko: "회의 예정: {meet}" },
+    meet_starting_soon_title: {
+        en: "Starting in 10 minutes: {meet}",
+        ko: "10분 후 시작: {meet}",
+    },
+    meet_live_title: { en: "Live now: {meet}", ko: "지금 진행 중: {meet}" },
+    meet_cancelled_title: { en: "Meet cancelled: {meet}", ko: "회의 취소: {meet}" },
+    meet_recording_title: {
+        en: "Recording available: {meet}",
+        ko: "녹화 공개: {meet}",
+    },
     relative_now: { en: "just now", ko: "방금" },
     relative_minute: { en: "{n}m ago", ko: "{n}분 전" },
     relative_hour: { en: "{n}h ago", ko: "{n}시간 전" },
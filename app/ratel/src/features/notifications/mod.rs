@@ -5,6 +5,9 @@ pub mod controllers;
 #[cfg(feature = "server")]
 pub mod services;
 
+#[cfg(feature = "server")]
+pub mod realtime;
+
 #[cfg(feature = "server")]
 pub mod route;
 
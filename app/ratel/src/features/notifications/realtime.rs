@@ -0,0 +1,63 @@
+//! `RoomChannel` for the signed-in user's notification inbox.
+//!
+//! Channel kind: `"user.inbox"`. ChannelId form: `user.inbox:{user_id}`.
+//! Only the owner may subscribe. The channel carries no state of its
+//! own — `inbox_changed` tells the bell to refetch its unread count,
+//! replacing the fixed-interval poll that kept mobile radios awake.
+//!
+//! Published from:
+//! - the `USER_INBOX_NOTIFICATION#` INSERT stream branch (new entry)
+//! - `mark_read_handler` / `mark_all_read_handler` (so other open
+//!   tabs and devices drop their badge too)
+
+use crate::common::realtime::channel::{ChannelContext, ChannelId, RoomChannel};
+use crate::common::realtime::hub::global_hub;
+use crate::common::realtime::RealtimeError;
+use crate::common::*;
+use async_trait::async_trait;
+
+pub const INBOX_CHANNEL_KIND: &str = "user.inbox";
+pub const INBOX_CHANGED_EVENT: &str = "inbox_changed";
+
+pub fn inbox_channel_id(user_id: &str) -> ChannelId {
+    ChannelId::from_parts(INBOX_CHANNEL_KIND, user_id)
+}
+
+/// Best-effort: a failed publish only delays the badge until the
+/// client's next resync, so errors are logged rather than returned.
+pub async fn publish_inbox_changed(user_pk: &Partition) {
+    let Partition::User(user_id) = user_pk else {
+        return;
+    };
+    if let Err(e) = global_hub()
+        .publish(
+            &inbox_channel_id(user_id),
+            INBOX_CHANGED_EVENT,
+            serde_json::Value::Null,
+        )
+        .await
+    {
+        crate::error!("user.inbox publish failed: {e}");
+    }
+}
+
+pub struct UserInboxChannel;
+
+#[async_trait]
+impl RoomChannel for UserInboxChannel {
+    fn kind(&self) -> &'static str {
+        INBOX_CHANNEL_KIND
+    }
+
+    async fn authorize(
+        &self,
+        ctx: &ChannelContext,
+        channel: &ChannelId,
+        _params: serde_json::Value,
+    ) -> crate::common::Result<serde_json::Value> {
+        if channel.inner().is_empty() || channel.inner() != ctx.user_id {
+            return Err(RealtimeError::ChannelForbidden.into());
+        }
+        Ok(serde_json::Value::Null)
+    }
+}
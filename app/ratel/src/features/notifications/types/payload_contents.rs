@@ -177,6 +177,36 @@ impl InboxPayload {
                 comment_preview.clone(),
                 Some(commenter_profile_url.clone()),
             ),
+            InboxPayload::BadgeAwarded {
+                badge_name,
+                criterion_text,
+                ..
+            } => (
+                tr.badge_awarded_title.replace("{badge}", badge_name),
+                criterion_text.clone(),
+                None,
+            ),
+            InboxPayload::MeetLifecycle {
+                space_title,
+                meet_title,
+                notice,
+                ..
+            } => {
+                use crate::features::spaces::pages::actions::actions::meet::MeetNotice;
+                let title = match notice {
+                    MeetNotice::Scheduled => tr.meet_scheduled_title,
+                    MeetNotice::StartingSoon => tr.meet_starting_soon_title,
+                    MeetNotice::LiveNow => tr.meet_live_title,
+                    MeetNotice::Cancelled => tr.meet_cancelled_title,
+                    MeetNotice::RecordingAvailable => tr.meet_recording_title,
+                };
+                (
+                    title.replace("{meet}", meet_title),
+                    tr.action_ongoing_subtitle
+                        .replace("{space_title}", space_title),
+                    None,
+                )
+            }
         }
     }
 }
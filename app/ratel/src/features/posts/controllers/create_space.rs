@@ -26,37 +26,64 @@ pub async fn create_space_handler(req: CreateSpaceRequest) -> Result<CreateSpace
     let conf = crate::features::posts::config::get();
     let cli = conf.dynamodb();
 
-    let post_pk: Partition = req.post_id.clone().into();
+    let space = create_space_on_post(cli, &user, req.post_id.clone(), |_| {}).await?;
+
+    let space_id = match space.pk.clone() {
+        Partition::Space(id) => SpacePartition(id),
+        _ => SpacePartition::default(),
+    };
+
+    Ok(CreateSpaceResponse { space_id })
+}
+
+/// Create a space on `post_id` on behalf of `user`, who must be able to
+/// edit the post. `configure` adjusts the space before it is written.
+#[cfg(feature = "server")]
+pub async fn create_space_on_post(
+    cli: &aws_sdk_dynamodb::Client,
+    user: &User,
+    post_id: FeedPartition,
+    configure: impl FnOnce(&mut SpaceCommon),
+) -> Result<SpaceCommon> {
+    let (space, items) = prepare_space_on_post(cli, user, post_id, configure).await?;
+    crate::transact_write_items!(cli, items)?;
+    Ok(space)
+}
+
+/// Permission check and rows of [`create_space_on_post`] without writing
+/// them: the space, its creator's participant row and the post's link to
+/// it. Callers that fill the space first (templates) write these last so
+/// a failure never leaves a half-built space on the post.
+#[cfg(feature = "server")]
+pub async fn prepare_space_on_post(
+    cli: &aws_sdk_dynamodb::Client,
+    user: &User,
+    post_id: FeedPartition,
+    configure: impl FnOnce(&mut SpaceCommon),
+) -> Result<(SpaceCommon, Vec<aws_sdk_dynamodb::types::TransactWriteItem>)> {
+    let post_pk: Partition = post_id.clone().into();
     let (post, has_perm) =
         Post::has_permission(cli, &post_pk, Some(&user.pk), TeamGroupPermission::PostEdit).await?;
     if !has_perm {
         return Err(PostError::NotAccessible.into());
     }
 
-    let space = SpaceCommon::new(
-        req.post_id.clone(),
+    let mut space = SpaceCommon::new(
+        post_id,
         post.user_pk.clone(),
         post.author_display_name.clone(),
         post.author_profile_url.clone(),
         post.author_username.clone(),
     );
+    configure(&mut space);
     let participant = SpaceParticipant::new_non_anonymous(space.pk.clone(), user.clone());
 
     let post_updater = Post::updater(&post.pk, &post.sk).with_space_pk(space.pk.clone());
 
-    crate::transact_write_items!(
-        cli,
-        vec![
-            space.create_transact_write_item(),
-            participant.create_transact_write_item(),
-            post_updater.transact_write_item(),
-        ]
-    )?;
-
-    let space_id = match space.pk.clone() {
-        Partition::Space(id) => SpacePartition(id),
-        _ => SpacePartition::default(),
-    };
-
-    Ok(CreateSpaceResponse { space_id })
+    let items = vec![
+        space.create_transact_write_item(),
+        participant.create_transact_write_item(),
+        post_updater.transact_write_item(),
+    ];
+    Ok((space, items))
 }
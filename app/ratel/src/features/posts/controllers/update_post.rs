@@ -5,7 +5,7 @@ use crate::features::posts::*;
 use std::collections::HashMap;
 
 use crate::features::cross_posting::models::PostSyndicationDirective;
-use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::cross_posting::types::{SchedulePlan, SocialPlatform};
 #[cfg(feature = "server")]
 #[allow(unused_imports)]
 use rmcp::schemars;
@@ -35,6 +35,10 @@ pub enum UpdatePostRequest {
         /// so v1.5 client/server are wire-compatible without a DTO change.
         #[serde(default)]
         platform_overrides: Option<HashMap<SocialPlatform, String>>,
+        /// Per-platform timing (cross-posting queue). Omitted platforms
+        /// go out at the connection's best time, or immediately.
+        #[serde(default)]
+        schedule: Option<HashMap<SocialPlatform, SchedulePlan>>,
     },
     PostType {
         r#type: PostType,
@@ -122,6 +126,7 @@ pub async fn update_post_handler(post_id: FeedPartition, req: UpdatePostRequest)
             categories,
             enabled_platforms,
             platform_overrides,
+            schedule,
         } => {
             validate_title(&title)?;
             validate_content(&content)?;
@@ -175,6 +180,7 @@ pub async fn update_post_handler(post_id: FeedPartition, req: UpdatePostRequest)
                     sk: EntityType::SyndicationDirective,
                     enabled_platforms: enabled_platforms.unwrap_or_default(),
                     platform_overrides: platform_overrides.unwrap_or_default(),
+                    schedule: schedule.unwrap_or_default(),
                     author_user_id: user.pk.clone(),
                     created_at: now,
                 };
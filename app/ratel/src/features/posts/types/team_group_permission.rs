@@ -21,6 +21,8 @@ pub enum TeamGroupPermission {
     TeamAdmin = 20,
     TeamEdit = 21,
     GroupEdit = 22,
+    /// Send posts through the team's social connections (cross-posting).
+    CrossPost = 23,
 
     ManagePromotions = 62,
     ManageNews = 63,
@@ -43,6 +45,7 @@ impl TeamGroupPermissions {
             TeamGroupPermission::TeamAdmin,
             TeamGroupPermission::TeamEdit,
             TeamGroupPermission::GroupEdit,
+            TeamGroupPermission::CrossPost,
         ])
     }
 
@@ -107,6 +110,7 @@ impl From<i64> for TeamGroupPermissions {
             TeamGroupPermission::TeamAdmin,
             TeamGroupPermission::TeamEdit,
             TeamGroupPermission::GroupEdit,
+            TeamGroupPermission::CrossPost,
             TeamGroupPermission::ManagePromotions,
             TeamGroupPermission::ManageNews,
         ];
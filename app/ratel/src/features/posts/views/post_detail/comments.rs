@@ -13,6 +13,7 @@
 
 use crate::common::components::mention_autocomplete::{MentionAutocomplete, MentionInsert};
 use crate::common::utils::mention::{parse_mention_segments, ContentSegment};
+use crate::features::cross_posting::components::ExternalComments;
 use crate::features::posts::controllers::dto::PostCommentResponse;
 use crate::features::posts::hooks::{use_post_detail, UsePostDetail};
 use crate::features::posts::*;
@@ -30,6 +31,7 @@ pub fn PostCommentsPanel() -> Element {
         is_submitting,
         members,
         mut submit_comment,
+        post_id,
         ..
     } = use_post_detail()?;
 
@@ -118,6 +120,9 @@ pub fn PostCommentsPanel() -> Element {
                         CommentItem { key: "{comment.sk}", comment: comment.clone() }
                     }
                 }
+                // Read-only replies imported from the syndicated copies
+                // (cross-posting reply import); renders nothing when none.
+                ExternalComments { post_id: post_id() }
             }
         }
     }
@@ -1,6 +1,7 @@
 use crate::common::components::SeoMeta;
 use crate::features::auth::hooks::use_user_context;
 use crate::features::cross_posting::components::SyndicationPanel;
+use crate::features::cross_posting::hooks::use_backlink_visit;
 use crate::features::posts::hooks::{use_post_detail, UsePostDetail};
 use crate::features::posts::*;
 
@@ -17,6 +18,8 @@ pub fn PostDetail(post_id: ReadSignal<FeedPartition>) -> Element {
     // via `use_context`. This keeps the controller's public shape free
     // of route-derived parameters.
     use_context_provider(|| post_id());
+    // Counts `?utm_source=` landings from cross-posted backlinks.
+    use_backlink_visit(post_id);
 
     let UsePostDetail {
         detail,
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 
 use super::i18n::PostEditTranslate;
 use super::posting_as::PostingAs;
+use super::space_template_picker::SpaceTemplatePicker;
 use crate::common::components::editor::Editor as RichEditor;
 use crate::common::contexts::use_team_context;
 use crate::common::types::{SpacePartition, TeamPartition, UserType};
@@ -10,7 +11,7 @@ use crate::features::auth::hooks::use_user_context;
 use crate::features::cross_posting::components::CrossPostSidebar;
 use crate::features::cross_posting::hooks::{use_cross_posting_provider, UseCrossPosting};
 use crate::features::cross_posting::models::ConnectionStatus;
-use crate::features::cross_posting::types::{ConnectionResponse, SocialPlatform};
+use crate::features::cross_posting::types::{ConnectionResponse, SchedulePlan, SocialPlatform};
 use crate::features::posts::controllers::get_post::get_post_handler;
 use crate::features::posts::controllers::update_post::{update_post_handler, UpdatePostRequest};
 use crate::features::posts::controllers::{
@@ -19,6 +20,7 @@ use crate::features::posts::controllers::{
 use crate::features::posts::models::Post;
 use crate::features::posts::types::{PostStatus, Visibility};
 use crate::features::posts::*;
+use crate::features::spaces::templates::create_space_from_template;
 
 const TITLE_MAX_LENGTH: usize = 80;
 const CONTENT_MIN_LENGTH: usize = 10;
@@ -130,6 +132,7 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
     let mut post_kind = use_signal(|| PostKind::Post);
     let mut visibility = use_signal(move || initial_visibility);
     let mut space_enabled = use_signal(move || has_existing_space);
+    let space_template = use_signal(|| None::<String>);
     let mut drawer_open = use_signal(|| false);
     let mut as_dropdown_open = use_signal(|| false);
 
@@ -227,28 +230,54 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
     let mut cp_ctx = use_cross_posting_provider()?;
     let UseCrossPosting {
         connections: cp_connections,
+        mut author_team,
         per_post_enabled,
+        per_post_schedule,
         ..
     } = cp_ctx;
 
+    // Team posts go out through the team's connections — keep the
+    // sidebar's list in step with the selected author.
+    use_effect(move || {
+        author_team.set(selected_team_pk().and_then(|pk| pk.parse::<TeamPartition>().ok()));
+    });
+
     // Cross-post connect button on a disconnected platform card:
-    //   * LinkedIn → kick off the OAuth flow with the current post-edit
-    //     URL baked into `return_to`, so the callback bounces the user
-    //     straight back here instead of dumping them on the settings page.
-    //   * Bluesky / Threads → no OAuth dance; send the user to Settings →
-    //     Connections where the Bluesky app-password modal lives.
+    //   * LinkedIn / Threads → kick off the OAuth flow with the current
+    //     post-edit URL baked into `return_to`, so the callback bounces the
+    //     user straight back here instead of dumping them on the settings
+    //     page.
+    //   * Bluesky / Mastodon → need input first (app password / server),
+    //     so send the user to Settings → Connections where those modals
+    //     live.
+    //   * Posting as a team → the team's settings page, where its admins
+    //     connect the shared accounts.
     let cp_username = user_handle.clone();
     let on_cp_connect = move |platform: SocialPlatform| {
         // Clone the captured String *before* the async block so the outer
         // closure stays FnMut — `async move` would otherwise drain
         // `cp_username` out of the closure env on the first invocation.
         let username = cp_username.clone();
+        let team_username = selected_team_pk().and_then(|pk| {
+            teams_signal()
+                .into_iter()
+                .find(|t| t.pk == pk)
+                .map(|t| t.username)
+        });
         async move {
+            if let Some(username) = team_username {
+                nav.push(crate::Route::SocialSetting { username });
+                return;
+            }
             match platform {
                 SocialPlatform::LinkedIn => {
                     let return_to = format!("/posts/{}/edit", post_id().0);
                     let _ = cp_ctx.connect_linkedin(Some(return_to)).await;
                 }
+                SocialPlatform::Threads => {
+                    let return_to = format!("/posts/{}/edit", post_id().0);
+                    let _ = cp_ctx.connect_threads(Some(return_to)).await;
+                }
                 _ => {
                     nav.push(crate::Route::UserSettingsConnectionsPage { username });
                 }
@@ -273,6 +302,8 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
         // future — keep all reactive reads on the synchronous path.
         let enabled_platforms_for_space =
             resolve_enabled_platforms(visibility(), &cp_connections(), &per_post_enabled());
+        let schedule_for_space =
+            resolve_schedule(enabled_platforms_for_space.as_deref(), &per_post_schedule());
         if space_enabled() {
             spawn(async move {
                 status.set(EditorStatus::Publishing);
@@ -287,6 +318,7 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
                         categories: Some(categories()),
                         enabled_platforms: enabled_platforms_for_space,
                         platform_overrides: None,
+                        schedule: schedule_for_space,
                     },
                 )
                 .await;
@@ -295,11 +327,19 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
                     status.set(EditorStatus::Unsaved);
                     return;
                 }
-                match create_space_handler(CreateSpaceRequest { post_id: post_id() }).await {
-                    Ok(resp) => {
-                        nav.push(crate::Route::SpaceIndexPage {
-                            space_id: resp.space_id,
-                        });
+                let created = match space_template() {
+                    Some(template_id) => {
+                        create_space_from_template(template_id.into(), post_id())
+                            .await
+                            .map(|resp| resp.space_id)
+                    }
+                    None => create_space_handler(CreateSpaceRequest { post_id: post_id() })
+                        .await
+                        .map(|resp| resp.space_id),
+                };
+                match created {
+                    Ok(space_id) => {
+                        nav.push(crate::Route::SpaceIndexPage { space_id });
                     }
                     Err(e) => {
                         crate::error!("create_space_handler failed: {e:?}");
@@ -313,6 +353,8 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
         let vis = visibility();
         let enabled_platforms_for_post =
             resolve_enabled_platforms(vis.clone(), &cp_connections(), &per_post_enabled());
+        let schedule_for_post =
+            resolve_schedule(enabled_platforms_for_post.as_deref(), &per_post_schedule());
         spawn(async move {
             status.set(EditorStatus::Publishing);
             match update_post_handler(
@@ -326,6 +368,7 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
                     categories: Some(categories()),
                     enabled_platforms: enabled_platforms_for_post,
                     platform_overrides: None,
+                    schedule: schedule_for_post,
                 },
             )
             .await
@@ -714,6 +757,13 @@ pub fn PostEdit(post_id: ReadSignal<FeedPartition>) -> Element {
                             }
                             "{tr.space_active_hint}"
                         }
+                        if space_enabled() && !has_existing_space {
+                            SpaceTemplatePicker {
+                                tr: tr.clone(),
+                                team_pk: selected_team_pk(),
+                                selected: space_template,
+                            }
+                        }
                     }
 
                     // Tags
@@ -1144,6 +1194,7 @@ fn resolve_enabled_platforms(
         SocialPlatform::Bluesky,
         SocialPlatform::LinkedIn,
         SocialPlatform::Threads,
+        SocialPlatform::Mastodon,
     ]
     .into_iter()
     .filter(|p| {
@@ -1158,3 +1209,19 @@ fn resolve_enabled_platforms(
     .collect();
     if enabled.is_empty() { None } else { Some(enabled) }
 }
+
+/// Per-platform timing for `UpdatePostRequest::Publish`, narrowed to the
+/// platforms actually being published to. Platforms the author never
+/// touched are left out so the server applies `SchedulePlan::Auto`.
+fn resolve_schedule(
+    enabled: Option<&[SocialPlatform]>,
+    schedule: &HashMap<SocialPlatform, SchedulePlan>,
+) -> Option<HashMap<SocialPlatform, SchedulePlan>> {
+    let enabled = enabled?;
+    let picked: HashMap<SocialPlatform, SchedulePlan> = schedule
+        .iter()
+        .filter(|(p, _)| enabled.contains(p))
+        .map(|(p, plan)| (*p, *plan))
+        .collect();
+    if picked.is_empty() { None } else { Some(picked) }
+}
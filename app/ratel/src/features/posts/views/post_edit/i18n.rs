@@ -118,6 +118,18 @@ translate! {
         en: "Publish will take you to the Space designer to configure quests, rewards, and members.",
         ko: "게시하면 퀘스트, 보상, 멤버를 설정하는 스페이스 디자이너로 이동합니다.",
     },
+    space_template: {
+        en: "Start from",
+        ko: "시작 템플릿",
+    },
+    space_template_blank: {
+        en: "Blank space",
+        ko: "빈 스페이스",
+    },
+    space_template_actions: {
+        en: "actions",
+        ko: "개 액션",
+    },
 
     tags: {
         en: "Tags",
@@ -1,6 +1,7 @@
 mod component;
 mod i18n;
 mod posting_as;
+mod space_template_picker;
 
 pub use component::*;
 use i18n::*;
@@ -0,0 +1,50 @@
+use crate::common::*;
+use crate::features::spaces::templates::{list_space_templates, SpaceTemplateSummary};
+
+use super::i18n::PostEditTranslate;
+
+/// Lets a new space start from a saved template instead of a blank one.
+/// Lists the user's own templates plus, when posting as a team, the
+/// templates shared with that team.
+#[component]
+pub fn SpaceTemplatePicker(
+    tr: PostEditTranslate,
+    team_pk: Option<String>,
+    selected: Signal<Option<String>>,
+) -> Element {
+    let mut selected = selected;
+    let templates = use_resource(use_reactive((&team_pk,), |(team_pk,)| async move {
+        let mut templates: Vec<SpaceTemplateSummary> =
+            list_space_templates(None).await.unwrap_or_default();
+        if let Some(team_pk) = team_pk.and_then(|pk| pk.parse::<TeamPartition>().ok()) {
+            templates.extend(list_space_templates(Some(team_pk)).await.unwrap_or_default());
+        }
+        templates
+    }));
+
+    let templates = templates.read().clone().unwrap_or_default();
+    if templates.is_empty() {
+        return rsx! {};
+    }
+
+    rsx! {
+        label { class: "space-toggle__template",
+            span { "{tr.space_template}" }
+            select {
+                value: selected().unwrap_or_default(),
+                onchange: move |e: FormEvent| {
+                    let value = e.value();
+                    selected.set(if value.is_empty() { None } else { Some(value) });
+                },
+                option { value: "", "{tr.space_template_blank}" }
+                for template in templates {
+                    option {
+                        key: "{template.template_id}",
+                        value: "{template.template_id}",
+                        "{template.name} · {template.action_count} {tr.space_template_actions}"
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use crate::common::Result;
+use crate::common::types::{SpacePartition, UserOrTeam};
+use crate::features::rag::qdrant::payloads::MeetTranscriptPayload;
+use crate::features::rag::qdrant::types::QdrantIndexType;
+use crate::features::spaces::pages::actions::actions::meet::{
+    SpaceMeetArchive, SpaceMeetTranscriptSegment, TranscriptSegment,
+};
+
+/// Soft cap on characters per embedded window. Cues are never split, so a
+/// window can run one cue over.
+const CHUNK_MAX_CHARS: usize = 2_000;
+
+/// A contiguous run of cues embedded as one Qdrant point.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptChunk {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speakers: Vec<String>,
+    pub content: String,
+}
+
+/// Group cues into windows of roughly `max_chars`, rendering each cue as
+/// `Speaker: text` so the speaker survives into the embedded text.
+pub fn chunk_transcript(
+    segments: &[TranscriptSegment],
+    max_chars: usize,
+) -> Vec<TranscriptChunk> {
+    let mut chunks: Vec<TranscriptChunk> = Vec::new();
+    let mut current: Option<TranscriptChunk> = None;
+
+    for seg in segments {
+        let line = match &seg.speaker {
+            Some(speaker) => format!("{speaker}: {}", seg.text),
+            None => seg.text.clone(),
+        };
+        let chunk = current.get_or_insert_with(|| TranscriptChunk {
+            start_ms: seg.start_ms,
+            end_ms: seg.end_ms,
+            speakers: Vec::new(),
+            content: String::new(),
+        });
+        if !chunk.content.is_empty() {
+            chunk.content.push('\n');
+        }
+        chunk.content.push_str(&line);
+        chunk.end_ms = chunk.end_ms.max(seg.end_ms);
+        if let Some(speaker) = &seg.speaker {
+            if !chunk.speakers.contains(speaker) {
+                chunk.speakers.push(speaker.clone());
+            }
+        }
+        if chunk.content.len() >= max_chars {
+            chunks.extend(current.take());
+        }
+    }
+    chunks.extend(current);
+    chunks
+}
+
+/// Qdrant point ids must be UUIDs. Derive one per window from the meet
+/// uuid so a redelivered stream event overwrites instead of duplicating.
+fn chunk_point_id(meet_id: &str, index: usize) -> String {
+    let base = uuid::Uuid::parse_str(meet_id)
+        .map(|u| u.as_u128())
+        .unwrap_or_default();
+    uuid::Uuid::from_u128(base ^ ((index as u128 + 1) << 64)).to_string()
+}
+
+/// Index a meet transcript into Qdrant, one point per window.
+pub async fn index_meet_transcript(archive: SpaceMeetArchive) -> Result<()> {
+    let config = crate::common::CommonConfig::default();
+    let cli = config.dynamodb();
+    let meet_id = archive.meet_id();
+    let segments: Vec<TranscriptSegment> =
+        SpaceMeetTranscriptSegment::list_for_meet(cli, &archive.pk, &meet_id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+    if segments.is_empty() {
+        return Ok(());
+    }
+
+    let tenant_id = super::tenant_id();
+    let space_id: SpacePartition = archive.pk.clone().into();
+    let user_id = UserOrTeam::from(archive.uploaded_by.clone());
+    let qdrant = config.qdrant();
+
+    for (index, chunk) in chunk_transcript(&segments, CHUNK_MAX_CHARS)
+        .into_iter()
+        .enumerate()
+    {
+        let payload = MeetTranscriptPayload {
+            r#type: QdrantIndexType::MeetTranscript,
+            tenant_id: tenant_id.clone(),
+            user_id: user_id.clone(),
+            space_id: space_id.clone(),
+            chunk_id: chunk_point_id(&meet_id.to_string(), index),
+            meet_id: meet_id.to_string(),
+            chunk_index: index as i64,
+            start_ms: chunk.start_ms,
+            end_ms: chunk.end_ms,
+            speakers: chunk.speakers,
+            content: chunk.content,
+        };
+        payload.upsert_points(qdrant).await?;
+    }
+    Ok(())
+}
@@ -1,8 +1,10 @@
 pub mod material_indexer;
+pub mod meet_transcript_indexer;
 pub mod post_indexer;
 pub mod reply_indexer;
 
 pub use material_indexer::*;
+pub use meet_transcript_indexer::*;
 pub use post_indexer::*;
 pub use reply_indexer::*;
 
@@ -0,0 +1,37 @@
+use by_macros::QdrantEntity;
+use serde::Serialize;
+
+use crate::common::types::{SpacePartition, UserOrTeam};
+use crate::features::rag::qdrant::types::QdrantIndexType;
+
+/// Payload for indexing one window of a meet transcript into Qdrant.
+/// A transcript is split into several points so each embedding covers a
+/// few minutes of conversation instead of the whole meeting.
+#[derive(Debug, Clone, Serialize, QdrantEntity)]
+#[qdrant(collection_name = "main")]
+pub struct MeetTranscriptPayload {
+    // Mandatory fields
+    pub r#type: QdrantIndexType,
+    pub tenant_id: String,
+    pub user_id: UserOrTeam,
+    pub space_id: SpacePartition,
+    // Transcript-specific fields
+    #[qdrant(id)]
+    pub chunk_id: String,
+    pub meet_id: String,
+    pub chunk_index: i64,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub speakers: Vec<String>,
+    pub content: String,
+}
+
+#[cfg(feature = "server")]
+#[async_trait::async_trait]
+impl crate::features::rag::qdrant::types::Embedding for MeetTranscriptPayload {
+    async fn embed(&self) -> crate::common::Result<Vec<f32>> {
+        let config = crate::common::CommonConfig::default();
+        let bedrock = config.bedrock_embeddings();
+        bedrock.embed(&self.content).await
+    }
+}
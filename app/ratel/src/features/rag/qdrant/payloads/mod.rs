@@ -1,7 +1,9 @@
 mod material_payload;
+mod meet_transcript_payload;
 mod post_payload;
 mod reply_payload;
 
 pub use material_payload::*;
+pub use meet_transcript_payload::*;
 pub use post_payload::*;
 pub use reply_payload::*;
@@ -7,6 +7,7 @@ pub enum QdrantIndexType {
     Reply,
     Material,
     Post,
+    MeetTranscript,
 }
 
 impl std::fmt::Display for QdrantIndexType {
@@ -15,6 +16,7 @@ impl std::fmt::Display for QdrantIndexType {
             QdrantIndexType::Reply => write!(f, "reply"),
             QdrantIndexType::Material => write!(f, "material"),
             QdrantIndexType::Post => write!(f, "post"),
+            QdrantIndexType::MeetTranscript => write!(f, "meet_transcript"),
         }
     }
 }
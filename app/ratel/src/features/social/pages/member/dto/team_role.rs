@@ -46,6 +46,7 @@ impl TeamRole {
                 TeamGroupPermission::SpaceDelete,
                 TeamGroupPermission::TeamEdit,
                 TeamGroupPermission::GroupEdit,
+                TeamGroupPermission::CrossPost,
             ]),
             TeamRole::Member => TeamGroupPermissions::read(),
         };
@@ -3,6 +3,7 @@ use super::super::controllers::TeamResponse;
 use super::super::controllers::{delete_team_handler, update_team_handler, UpdateTeamRequest};
 use super::super::*;
 use crate::common::*;
+use crate::features::cross_posting::components::TeamConnections;
 use crate::features::membership::controllers::{
     get_team_billing_info_handler, get_team_membership_handler, update_team_billing_card_handler,
     UpdateBillingCardRequest,
@@ -231,6 +232,12 @@ pub fn AdminPage(username: String, team: TeamResponse) -> Element {
                 }
             }
 
+            if team_state().role.is_admin_or_owner() {
+                Card { variant: CardVariant::Outlined, class: "p-6",
+                    TeamConnections { team_pk: team_state().id }
+                }
+            }
+
             if delete_team_permission {
                 Card { variant: CardVariant::Outlined, class: "p-6",
                     TeamSubscriptionCard { username: username.clone() }
@@ -81,6 +81,12 @@ translate! {
     mcp_copied: { en: "Copied to clipboard!", ko: "클립보드에 복사되었습니다!" },
     mcp_copy: { en: "Copy", ko: "복사" },
     mcp_not_generated: { en: "No secret generated yet. Click Generate to create one.", ko: "아직 시크릿이 생성되지 않았습니다. 생성 버튼을 클릭하세요." },
+    calendar_feed: { en: "Calendar Feed", ko: "캘린더 피드" },
+    calendar_feed_description: { en: "Subscribe in Google Calendar, Outlook or Apple Calendar to see meets, poll deadlines and space end dates from the spaces you joined.", ko: "Google 캘린더, Outlook 또는 Apple 캘린더에서 구독하면 참여한 스페이스의 회의, 투표 마감, 스페이스 종료일을 볼 수 있습니다." },
+    calendar_feed_url: { en: "Feed URL", ko: "피드 URL" },
+    calendar_feed_generate: { en: "Generate URL", ko: "URL 생성" },
+    calendar_feed_not_generated: { en: "No feed yet. Click Generate to create a private feed URL.", ko: "아직 피드가 없습니다. 생성 버튼을 클릭해 비공개 피드 URL을 만드세요." },
+    calendar_feed_exists: { en: "A feed URL has been generated. Regenerating revokes the old URL. The URL is only shown once after generation.", ko: "피드 URL이 생성되어 있습니다. 재생성하면 기존 URL은 더 이상 동작하지 않습니다. URL은 생성 직후에만 표시됩니다." },
     mcp_secret_exists: { en: "A secret has been generated. Click Regenerate to get a new URL. The token is only shown once after generation.", ko: "시크릿이 생성되어 있습니다. 재생성 버튼을 클릭하면 새 URL을 받을 수 있습니다. 토큰은 생성 직후에만 표시됩니다." },
 }
 
@@ -242,6 +248,9 @@ pub fn Home(username: String) -> Element {
 
             // Card 4: MCP Server
             Card { variant: CardVariant::Outlined, class: "p-6", McpServerCard {} }
+
+            // Card 5: Calendar Feed
+            Card { variant: CardVariant::Outlined, class: "p-6", CalendarFeedCard {} }
         }
     }
 }
@@ -769,6 +778,81 @@ fn McpServerCard() -> Element {
     }
 }
 
+#[component]
+fn CalendarFeedCard() -> Element {
+    use crate::features::calendar::{
+        get_calendar_feed_secret_handler, regenerate_calendar_feed_secret_handler,
+    };
+
+    let tr: UserSettingsTranslate = use_translate();
+    let mut feed_status =
+        use_loader(move || async move { get_calendar_feed_secret_handler().await })?;
+    let has_secret = feed_status().has_secret;
+
+    // Holds the raw token only right after generation (not persisted across reloads)
+    let mut raw_token = use_signal(|| Option::<String>::None);
+    let mut generating = use_signal(|| false);
+    let mut feed_error = use_signal(|| Option::<String>::None);
+    let origin = use_origin();
+
+    let on_generate = move |_: MouseEvent| {
+        spawn(async move {
+            generating.set(true);
+            feed_error.set(None);
+            match regenerate_calendar_feed_secret_handler().await {
+                Ok(resp) => {
+                    raw_token.set(resp.secret);
+                    feed_status.restart();
+                }
+                Err(e) => feed_error.set(Some(format!("{e}"))),
+            }
+            generating.set(false);
+        });
+    };
+
+    let feed_url = raw_token().map(|token| format!("{origin}/api/calendar/feed/{token}.ics"));
+
+    rsx! {
+        div { class: "flex flex-col gap-5 w-full",
+            h2 { class: "text-lg font-bold text-text-primary", {tr.calendar_feed} }
+            p { class: "text-sm text-foreground-muted", {tr.calendar_feed_description} }
+
+            if let Some(url) = feed_url {
+                SettingsRow { label: tr.calendar_feed_url.to_string(),
+                    code {
+                        class: "p-2 text-sm break-all rounded select-all bg-background text-text-primary",
+                        "data-testid": "calendar-feed-url",
+                        "{url}"
+                    }
+                }
+            } else if has_secret {
+                p { class: "text-sm italic text-foreground-muted", {tr.calendar_feed_exists} }
+            } else {
+                p { class: "text-sm italic text-foreground-muted", {tr.calendar_feed_not_generated} }
+            }
+
+            if let Some(msg) = feed_error() {
+                div { class: "text-sm text-destructive", "{msg}" }
+            }
+
+            div { class: "flex justify-end",
+                Button {
+                    style: if has_secret { ButtonStyle::Secondary } else { ButtonStyle::Primary },
+                    disabled: generating(),
+                    onclick: on_generate,
+                    if generating() {
+                        {tr.mcp_generating}
+                    } else if has_secret {
+                        {tr.mcp_regenerate}
+                    } else {
+                        {tr.calendar_feed_generate}
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn is_blocked_text(value: &str) -> bool {
     let lower = value.to_lowercase();
     lower.contains("test") || value.contains("테스트")
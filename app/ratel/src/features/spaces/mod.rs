@@ -7,6 +7,7 @@ mod layout;
 mod models;
 pub mod pages;
 pub(crate) mod space_common;
+pub mod templates;
 mod types;
 
 pub use layout::SpaceLayout;
@@ -5,6 +5,8 @@ mod types;
 mod views;
 
 pub mod controllers;
+#[cfg(feature = "server")]
+pub mod realtime;
 
 use context::*;
 use controllers::*;
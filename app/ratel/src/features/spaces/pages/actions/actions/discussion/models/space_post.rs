@@ -198,6 +198,7 @@ impl From<(SpacePost, SpaceUserRole)>
             comment_count: Some(post.comments),
             status: None,
             depends_on: Vec::new(),
+            dependency_conditions: Vec::new(),
             dependencies_met: true,
         }
     }
@@ -0,0 +1,83 @@
+//! `RoomChannel` for live discussion comments.
+//!
+//! Channel kind: `"space.comments"`. ChannelId form:
+//! `space.comments:{space_id}:{post_id}`. Viewers of the space may
+//! subscribe (see `space_common::realtime::authorize_space_viewer`).
+//!
+//! `comment_created` / `reply_created` carry the new comment as a
+//! `DiscussionCommentResponse` (with `liked: false` — the subscriber
+//! can't have liked a comment that didn't exist yet). Published from
+//! the `SPACE_POST_COMMENT#` / `SPACE_POST_COMMENT_REPLY#` INSERT
+//! stream branches.
+
+use crate::common::realtime::channel::{ChannelContext, ChannelId, RoomChannel};
+use crate::common::realtime::hub::global_hub;
+use crate::common::realtime::RealtimeError;
+use crate::features::spaces::pages::actions::actions::discussion::*;
+use crate::features::spaces::space_common::realtime::authorize_space_viewer;
+use async_trait::async_trait;
+
+pub const COMMENTS_CHANNEL_KIND: &str = "space.comments";
+
+pub fn comments_channel_id(space_id: &str, post_id: &str) -> ChannelId {
+    ChannelId::from_parts(COMMENTS_CHANNEL_KIND, &format!("{space_id}:{post_id}"))
+}
+
+pub async fn publish_comment_created(comment: SpacePostComment) {
+    let (Some(Partition::Space(space_id)), Partition::SpacePost(post_id)) =
+        (comment.space_pk.clone(), comment.pk.clone())
+    else {
+        return;
+    };
+    let name = if comment.parent_comment_sk.is_some() {
+        "reply_created"
+    } else {
+        "comment_created"
+    };
+    let payload = match serde_json::to_value(DiscussionCommentResponse::from(comment)) {
+        Ok(v) => v,
+        Err(e) => {
+            crate::error!("space.comments serialize failed: {e}");
+            return;
+        }
+    };
+    if let Err(e) = global_hub()
+        .publish(&comments_channel_id(&space_id, &post_id), name, payload)
+        .await
+    {
+        crate::error!("space.comments publish failed: {e}");
+    }
+}
+
+pub struct SpaceCommentsChannel {
+    cli: aws_sdk_dynamodb::Client,
+}
+
+impl SpaceCommentsChannel {
+    pub fn new(cli: aws_sdk_dynamodb::Client) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl RoomChannel for SpaceCommentsChannel {
+    fn kind(&self) -> &'static str {
+        COMMENTS_CHANNEL_KIND
+    }
+
+    async fn authorize(
+        &self,
+        ctx: &ChannelContext,
+        channel: &ChannelId,
+        _params: serde_json::Value,
+    ) -> crate::common::Result<serde_json::Value> {
+        let Some((space_id, post_id)) = channel.inner().split_once(':') else {
+            return Err(RealtimeError::ChannelPayloadInvalid.into());
+        };
+        if post_id.is_empty() {
+            return Err(RealtimeError::ChannelPayloadInvalid.into());
+        }
+        authorize_space_viewer(&self.cli, ctx, space_id).await?;
+        Ok(serde_json::Value::Null)
+    }
+}
@@ -19,6 +19,7 @@ pub fn ConfigCard() -> Element {
     let saved_credits = action.credits;
     let action_status = action.status.clone();
     let initial_depends_on = action.depends_on.clone();
+    let initial_dependency_conditions = action.dependency_conditions.clone();
     let initial_status = action.status.clone();
     let discussion_entity = use_memo(move || {
         crate::common::types::SpaceDiscussionEntityType(discussion_id().to_string())
@@ -50,6 +51,7 @@ pub fn ConfigCard() -> Element {
                         space_id,
                         action_id: action_id_signal,
                         initial_depends_on,
+                        initial_conditions: initial_dependency_conditions,
                     }
                 }
 
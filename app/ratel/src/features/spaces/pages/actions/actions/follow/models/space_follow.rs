@@ -52,6 +52,7 @@ impl From<SpaceFollowAction>
             comment_count: None,
             status: None,
             depends_on: Vec::new(),
+            dependency_conditions: Vec::new(),
             dependencies_met: true,
         }
     }
@@ -20,6 +20,7 @@ pub fn ConfigCard(
     let action_status = action.status.clone();
     let initial_prerequisite = action.prerequisite;
     let initial_depends_on = action.depends_on.clone();
+    let initial_dependency_conditions = action.dependency_conditions.clone();
     let initial_status = action.status.clone();
 
     let action_id_for_signal = action_id_str.clone();
@@ -48,6 +49,7 @@ pub fn ConfigCard(
                         space_id,
                         action_id: action_id_signal,
                         initial_depends_on,
+                        initial_conditions: initial_dependency_conditions,
                     }
                 }
 
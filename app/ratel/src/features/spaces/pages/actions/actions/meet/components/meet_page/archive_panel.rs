@@ -0,0 +1,144 @@
+use crate::features::spaces::pages::actions::actions::meet::components::meet_page::*;
+use crate::features::spaces::pages::actions::actions::meet::*;
+use crate::*;
+use dioxus::html::HasFileData;
+
+/// Post-meeting archive: recording link plus the searchable transcript.
+/// Hosts get the upload form while no archive exists.
+#[component]
+pub fn MeetArchivePanel(can_upload: bool) -> Element {
+    let tr: MeetActionTranslate = use_translate();
+    let UseMeet {
+        space_id, meet_id, ..
+    } = use_context::<UseMeet>();
+    let mut query = use_signal(String::new);
+    let mut recording = use_signal(|| Option::<UploadedFileMeta>::None);
+    let mut transcript_file = use_signal(|| Option::<(String, String)>::None);
+
+    // `ArchiveNotFound` is the normal state until the host uploads.
+    let mut archive = use_loader(move || async move {
+        let q = query();
+        Ok::<_, crate::common::Error>(
+            get_meet_transcript(space_id(), meet_id(), (!q.is_empty()).then_some(q))
+                .await
+                .ok(),
+        )
+    })?;
+
+    let mut upload = use_action(move || async move {
+        let Some((file_name, body)) = transcript_file() else {
+            return Ok::<(), crate::common::Error>(());
+        };
+        let rec = recording();
+        upload_meet_archive(
+            space_id(),
+            meet_id(),
+            UploadMeetArchiveRequest {
+                recording_url: rec.as_ref().map(|r| r.url.clone()),
+                recording_file_name: rec.map(|r| r.name),
+                transcript_file_name: file_name,
+                transcript_format: None,
+                transcript: body,
+                speaker_ids: Default::default(),
+            },
+        )
+        .await?;
+        archive.restart();
+        Ok(())
+    });
+
+    let current = archive();
+    let recording_label = recording()
+        .map(|meta| meta.name)
+        .unwrap_or_else(|| tr.archive_pick_recording.to_string());
+    let transcript_label = transcript_file()
+        .map(|(name, _)| name)
+        .unwrap_or_else(|| tr.archive_pick_transcript.to_string());
+
+    rsx! {
+        div { class: "meet-archive", "data-testid": "meet-archive",
+            match current {
+                Some(data) => rsx! {
+                    if let Some(url) = data.archive.recording_url.clone() {
+                        video {
+                            class: "meet-archive__recording",
+                            controls: true,
+                            src: "{url}",
+                        }
+                    }
+                    div { class: "meet-archive__meta",
+                        span { class: "meet-viewer__ended-label", "{tr.archive_transcript}" }
+                        span { class: "meet-archive__speakers", {data.archive.speakers.join(" · ")} }
+                    }
+                    input {
+                        class: "meet-archive__search",
+                        r#type: "search",
+                        placeholder: "{tr.archive_search_placeholder}",
+                        value: "{query}",
+                        oninput: move |evt| query.set(evt.value()),
+                    }
+                    if data.segments.is_empty() {
+                        p { class: "meet-viewer__desc", "{tr.archive_no_match}" }
+                    }
+                    ol { class: "meet-archive__segments",
+                        for (i, seg) in data.segments.iter().enumerate() {
+                            li { key: "{i}-{seg.start_ms}", class: "meet-archive__segment",
+                                span { class: "meet-archive__ts", {format_cue_time(seg.start_ms)} }
+                                if let Some(speaker) = &seg.speaker {
+                                    span { class: "meet-archive__speaker", "{speaker}" }
+                                }
+                                span { class: "meet-archive__text", "{seg.text}" }
+                            }
+                        }
+                    }
+                },
+                None if can_upload => rsx! {
+                    span { class: "meet-viewer__ended-label", "{tr.archive_upload_title}" }
+                    FileUploader {
+                        accept: "video/*,audio/*".to_string(),
+                        on_upload_meta: move |meta: UploadedFileMeta| recording.set(Some(meta)),
+                        on_upload_success: move |_url: String| {},
+                        span { class: "meet-archive__pick", "{recording_label}" }
+                    }
+                    label { class: "meet-archive__pick",
+                        "{transcript_label}"
+                        input {
+                            r#type: "file",
+                            accept: ".vtt,.srt",
+                            hidden: true,
+                            onchange: move |evt: FormEvent| async move {
+                                let Some(file) = evt.files().into_iter().next() else {
+                                    return;
+                                };
+                                let name = file.name();
+                                match file.read_string().await {
+                                    Ok(body) => transcript_file.set(Some((name, body))),
+                                    Err(e) => error!("transcript read failed: {e}"),
+                                }
+                            },
+                        }
+                    }
+                    Button {
+                        "data-testid": "meet-archive-upload",
+                        disabled: transcript_file().is_none() || upload.pending(),
+                        onclick: move |_| upload.call(),
+                        "{tr.archive_upload_submit}"
+                    }
+                },
+                None => rsx! {
+                    p { class: "meet-viewer__desc", "{tr.archive_pending}" }
+                },
+            }
+        }
+    }
+}
+
+fn format_cue_time(ms: i64) -> String {
+    let total = ms / 1000;
+    let (h, m, s) = (total / 3600, (total / 60) % 60, total % 60);
+    if h > 0 {
+        format!("{h}:{m:02}:{s:02}")
+    } else {
+        format!("{m:02}:{s:02}")
+    }
+}
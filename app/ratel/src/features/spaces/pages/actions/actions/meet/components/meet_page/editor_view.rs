@@ -1,9 +1,12 @@
 use crate::features::spaces::pages::actions::actions::meet::components::meet_page::*;
+use crate::features::spaces::pages::actions::actions::meet::*;
 use crate::*;
 
 #[component]
 pub fn MeetEditorView() -> Element {
     let tr: MeetActionTranslate = use_translate();
+    let UseMeet { meet, .. } = use_context::<UseMeet>();
+    let ended = meet().status == MeetStatus::Ended;
 
     rsx! {
 
@@ -15,6 +18,9 @@ pub fn MeetEditorView() -> Element {
             MeetWhenCard {}
             MeetConfigCard {}
             MeetSubmitBar {}
+            if ended {
+                MeetArchivePanel { can_upload: true }
+            }
         }
     }
 }
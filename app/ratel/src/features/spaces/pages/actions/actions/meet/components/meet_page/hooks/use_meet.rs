@@ -14,6 +14,7 @@ pub struct UseMeet {
     pub update_start_time: Action<(i64,), ()>,
     pub update_duration: Action<(i32,), ()>,
     pub publish: Action<(), ()>,
+    pub transition: Action<(MeetTransitionRequest,), ()>,
 }
 
 #[track_caller]
@@ -81,6 +82,12 @@ pub fn use_meet(
         Ok::<(), crate::common::Error>(())
     });
 
+    let transition = use_action(move |req: MeetTransitionRequest| async move {
+        transition_meet(space_id(), meet_id(), req).await?;
+        meet.restart();
+        Ok::<(), crate::common::Error>(())
+    });
+
     Ok(use_context_provider(|| UseMeet {
         space_id,
         meet_id,
@@ -89,5 +96,6 @@ pub fn use_meet(
         update_start_time,
         update_duration,
         publish,
+        transition,
     }))
 }
@@ -32,4 +32,20 @@ translate! {
     scheduled_starts_at: { en: "Starts at", ko: "시작 시간" },
     live_cta: { en: "Join meeting", ko: "입장" },
     ended_cta: { en: "View archive", ko: "아카이브 보기" },
+    cancelled_label: { en: "Meeting cancelled", ko: "취소됨" },
+    expired_label: { en: "Meeting expired", ko: "만료됨" },
+    expired_desc: { en: "This meeting was never started.", ko: "이 회의는 시작되지 않았습니다." },
+
+    host_start: { en: "Start meeting", ko: "회의 시작" },
+    host_end: { en: "End for all", ko: "모두 종료" },
+    host_cancel: { en: "Cancel meeting", ko: "회의 취소" },
+
+    archive_transcript: { en: "Transcript", ko: "회의 기록" },
+    archive_search_placeholder: { en: "Search transcript…", ko: "기록 검색…" },
+    archive_no_match: { en: "No lines match your search.", ko: "검색 결과가 없습니다." },
+    archive_pending: { en: "The host has not uploaded the recording yet.", ko: "호스트가 아직 녹화본을 업로드하지 않았습니다." },
+    archive_upload_title: { en: "Upload recording & transcript", ko: "녹화본 및 기록 업로드" },
+    archive_pick_recording: { en: "Choose recording (optional)", ko: "녹화본 선택 (선택)" },
+    archive_pick_transcript: { en: "Choose transcript (.vtt, .srt)", ko: "기록 파일 선택 (.vtt, .srt)" },
+    archive_upload_submit: { en: "Publish archive", ko: "아카이브 게시" },
 }
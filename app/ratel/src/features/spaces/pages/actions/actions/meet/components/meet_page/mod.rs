@@ -22,6 +22,9 @@ pub use config_card::*;
 mod submit_bar;
 pub use submit_bar::*;
 
+mod archive_panel;
+pub use archive_panel::*;
+
 mod i18n;
 pub use i18n::*;
 
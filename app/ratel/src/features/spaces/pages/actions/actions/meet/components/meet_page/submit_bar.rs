@@ -6,8 +6,12 @@ use crate::*;
 #[component]
 pub fn MeetSubmitBar() -> Element {
     let tr: MeetActionTranslate = use_translate();
+    let lang = use_language()();
     let UseMeet {
-        meet, mut publish, ..
+        meet,
+        mut publish,
+        mut transition,
+        ..
     } = use_context::<UseMeet>();
     let current = meet();
     let mode = current.mode.clone();
@@ -25,11 +29,40 @@ pub fn MeetSubmitBar() -> Element {
 
     rsx! {
         div { class: "create-bar",
-            Button {
-                "data-testid": "meet-submit-button",
-                disabled: is_published,
-                onclick: move |_| publish.call(),
-                "{label}"
+            if !is_published {
+                Button {
+                    "data-testid": "meet-submit-button",
+                    onclick: move |_| publish.call(),
+                    "{label}"
+                }
+            } else {
+                match current.status {
+                    MeetStatus::Scheduled => rsx! {
+                        Button {
+                            style: ButtonStyle::Outline,
+                            "data-testid": "meet-cancel-button",
+                            onclick: move |_| {
+                                transition.call(MeetTransitionRequest::Cancel { reason: None })
+                            },
+                            "{tr.host_cancel}"
+                        }
+                        Button {
+                            "data-testid": "meet-start-button",
+                            onclick: move |_| transition.call(MeetTransitionRequest::Start),
+                            "{tr.host_start}"
+                        }
+                    },
+                    MeetStatus::Live => rsx! {
+                        Button {
+                            "data-testid": "meet-end-button",
+                            onclick: move |_| transition.call(MeetTransitionRequest::End),
+                            "{tr.host_end}"
+                        }
+                    },
+                    other => rsx! {
+                        Button { style: ButtonStyle::Text, disabled: true, {other.translate(&lang)} }
+                    },
+                }
             }
         }
     }
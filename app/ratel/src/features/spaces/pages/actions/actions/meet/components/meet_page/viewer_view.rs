@@ -1,27 +1,23 @@
+use crate::features::calendar::AddToCalendarButton;
 use crate::features::spaces::pages::actions::SpaceActionStatus;
 use crate::features::spaces::pages::actions::actions::meet::components::meet_page::*;
+use crate::features::spaces::pages::actions::actions::meet::*;
 use crate::*;
 
 #[component]
 pub fn MeetViewerView() -> Element {
     let tr: MeetActionTranslate = use_translate();
-    let UseMeet { meet, .. } = use_context::<UseMeet>();
+    let UseMeet { space_id, meet, .. } = use_context::<UseMeet>();
     let current = meet();
     let title = current.space_action.title.clone();
     let description = current.space_action.description.clone();
-    let status = current
-        .space_action
-        .status
-        .clone()
-        .unwrap_or(SpaceActionStatus::Designing);
-    let now = crate::common::utils::time::get_now_timestamp_millis();
     let start_time = current.start_time;
-    let duration = current.duration_min as i64;
-    let is_live = matches!(status, SpaceActionStatus::Ongoing)
-        && start_time <= now
-        && now < start_time + duration * 60_000;
-    let is_scheduled = matches!(status, SpaceActionStatus::Ongoing) && now < start_time;
-    let is_ended = matches!(status, SpaceActionStatus::Finish);
+    let action_id = current.space_action.pk.1.clone();
+    let is_scheduled = current.status == MeetStatus::Scheduled
+        && matches!(
+            current.space_action.status,
+            Some(SpaceActionStatus::Ongoing)
+        );
 
     rsx! {
         SeoMeta { title: "{title}" }
@@ -33,18 +29,30 @@ pub fn MeetViewerView() -> Element {
                 div { class: "meet-viewer__scheduled",
                     span { class: "meet-viewer__scheduled-label", "{tr.scheduled_starts_at}" }
                     span { class: "meet-viewer__scheduled-ts", "{start_time}" }
+                    AddToCalendarButton { space_id, action_id }
                 }
-            } else if is_live {
+            } else if current.status == MeetStatus::Live {
                 div { class: "meet-viewer__live",
                     span { class: "meet-viewer__live-label", "{tr.live_label}" }
                     span { class: "meet-viewer__coming-soon", "{tr.coming_soon_badge}" }
                     Button { disabled: true, "data-testid": "meet-live-join", "{tr.live_cta}" }
                 }
-            } else if is_ended {
+            } else if current.status == MeetStatus::Ended {
                 div { class: "meet-viewer__ended",
                     span { class: "meet-viewer__ended-label", "{tr.ended_label}" }
-                    span { class: "meet-viewer__coming-soon", "{tr.coming_soon_badge}" }
-                    Button { disabled: true, "data-testid": "meet-ended-archive", "{tr.ended_cta}" }
+                }
+                MeetArchivePanel { can_upload: false }
+            } else if current.status == MeetStatus::Cancelled {
+                div { class: "meet-viewer__ended", "data-testid": "meet-cancelled",
+                    span { class: "meet-viewer__ended-label", "{tr.cancelled_label}" }
+                    if let Some(reason) = current.cancel_reason.clone() {
+                        span { class: "meet-viewer__desc", "{reason}" }
+                    }
+                }
+            } else if current.status == MeetStatus::Expired {
+                div { class: "meet-viewer__ended", "data-testid": "meet-expired",
+                    span { class: "meet-viewer__ended-label", "{tr.expired_label}" }
+                    span { class: "meet-viewer__desc", "{tr.expired_desc}" }
                 }
             }
         }
@@ -20,6 +20,27 @@ pub async fn get_meet(
     let meet = SpaceMeet::get(cli, &space_pk, Some(meet_sk_entity.clone()))
         .await?
         .ok_or(MeetActionError::NotFound)?;
+    // Lazy advance: don't show a stale status if the scheduler tick is late.
+    // Only the silent Expire/End transitions run here; reminders (and their
+    // notifications) stay with the scheduler so a read never fans out.
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let meet = match meet.due_tick(now) {
+        Some(MeetTick::Expire) | Some(MeetTick::End) => {
+            let next = if meet.status == MeetStatus::Live {
+                MeetStatus::Ended
+            } else {
+                MeetStatus::Expired
+            };
+            match apply_meet_transition(cli, meet, next, None).await? {
+                Some(meet) => meet,
+                // Someone else moved it first; show what they stored.
+                None => SpaceMeet::get(cli, &space_pk, Some(meet_sk_entity.clone()))
+                    .await?
+                    .ok_or(MeetActionError::NotFound)?,
+            }
+        }
+        _ => meet,
+    };
 
     let mut response: MeetResponse = meet.into();
 
@@ -0,0 +1,43 @@
+use crate::features::spaces::pages::actions::actions::meet::*;
+
+#[mcp_tool(
+    name = "get_meet_transcript",
+    description = "Fetch an ended meet's recording reference and speaker-attributed transcript. Pass q to keep only cues whose text or speaker contains it (case-insensitive)."
+)]
+#[get("/api/spaces/{space_pk}/meets/{meet_sk}/transcript?q", role: SpaceUserRole)]
+pub async fn get_meet_transcript(
+    #[mcp(description = "Space partition key")] space_pk: SpacePartition,
+    #[mcp(description = "Meet sort key (e.g. 'SpaceMeet#<uuid>')")] meet_sk: SpaceMeetEntityType,
+    #[mcp(description = "Optional search text")] q: Option<String>,
+) -> Result<MeetTranscriptResponse> {
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+    let space_pk: Partition = space_pk.into();
+
+    let (pk, sk) = SpaceMeetArchive::keys(&space_pk, &meet_sk);
+    let archive = SpaceMeetArchive::get(cli, &pk, Some(sk))
+        .await?
+        .ok_or(MeetActionError::ArchiveNotFound)?;
+
+    let needle = q.map(|q| q.trim().to_lowercase()).filter(|q| !q.is_empty());
+    let segments = SpaceMeetTranscriptSegment::list_for_meet(cli, &space_pk, &meet_sk)
+        .await?
+        .into_iter()
+        .map(TranscriptSegment::from)
+        .filter(|seg| match &needle {
+            Some(needle) => {
+                seg.text.to_lowercase().contains(needle)
+                    || seg
+                        .speaker
+                        .as_ref()
+                        .is_some_and(|s| s.to_lowercase().contains(needle))
+            }
+            None => true,
+        })
+        .collect();
+
+    Ok(MeetTranscriptResponse {
+        archive: archive.into(),
+        segments,
+    })
+}
@@ -4,8 +4,17 @@ pub use create_meet::*;
 mod get_meet;
 pub use get_meet::*;
 
+mod get_meet_transcript;
+pub use get_meet_transcript::*;
+
 mod update_meet;
 pub use update_meet::*;
 
+mod transition_meet;
+pub use transition_meet::*;
+
 mod delete_meet;
 pub use delete_meet::*;
+
+mod upload_meet_archive;
+pub use upload_meet_archive::*;
@@ -0,0 +1,71 @@
+use crate::features::spaces::pages::actions::actions::meet::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Host-driven lifecycle moves. `Expired` and the 24h auto-end are the
+/// scheduler's; everything a host can do is here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum MeetTransitionRequest {
+    Start,
+    End,
+    Cancel { reason: Option<String> },
+}
+
+#[mcp_tool(
+    name = "transition_meet",
+    description = "Start, end or cancel a meet. Start and Cancel require a Scheduled meet; End requires a Live one and is a no-op on an already-ended meet. Requires creator role."
+)]
+#[post("/api/spaces/{space_pk}/meets/{meet_sk}/status", role: SpaceUserRole)]
+pub async fn transition_meet(
+    #[mcp(description = "Space partition key")] space_pk: SpacePartition,
+    #[mcp(description = "Meet sort key (e.g. 'SpaceMeet#<uuid>')")] meet_sk: SpaceMeetEntityType,
+    #[mcp(description = "Transition as JSON: \"Start\", \"End\" or {\"Cancel\": {\"reason\": \"...\"}}")]
+    req: MeetTransitionRequest,
+) -> Result<MeetResponse> {
+    SpaceMeet::can_edit(&role)?;
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+    let space_pk: Partition = space_pk.into();
+    let meet_sk_entity: EntityType = meet_sk.into();
+
+    let meet = SpaceMeet::get(cli, &space_pk, Some(meet_sk_entity.clone()))
+        .await?
+        .ok_or(MeetActionError::NotFound)?;
+
+    let (next, reason, notice) = match req {
+        MeetTransitionRequest::Start => (MeetStatus::Live, None, Some(MeetNotice::LiveNow)),
+        // Two hosts racing "End for all": the second one is a no-op.
+        MeetTransitionRequest::End if meet.status == MeetStatus::Ended => {
+            return Ok(meet.into());
+        }
+        MeetTransitionRequest::End => (MeetStatus::Ended, None, None),
+        MeetTransitionRequest::Cancel { reason } => {
+            (MeetStatus::Cancelled, reason, Some(MeetNotice::Cancelled))
+        }
+    };
+
+    let meet = match apply_meet_transition(cli, meet, next, reason).await? {
+        Some(meet) => meet,
+        None => {
+            // The stored status moved between our read and write. A
+            // racing "End for all" that already landed is still a no-op.
+            let current = SpaceMeet::get(cli, &space_pk, Some(meet_sk_entity))
+                .await?
+                .ok_or(MeetActionError::NotFound)?;
+            if next == MeetStatus::Ended && current.status == MeetStatus::Ended {
+                return Ok(current.into());
+            }
+            return Err(MeetActionError::StatusChanged.into());
+        }
+    };
+
+    if let Some(notice) = notice {
+        if let Err(e) = notify_meet_participants(cli, &meet, notice).await {
+            crate::error!("meet {:?} notification failed: {e}", notice);
+        }
+    }
+
+    Ok(meet.into())
+}
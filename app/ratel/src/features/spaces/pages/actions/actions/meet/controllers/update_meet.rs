@@ -28,15 +28,28 @@ pub async fn update_meet(
     let space_pk: Partition = space_pk.into();
     let meet_sk_entity: EntityType = meet_sk.into();
 
+    let mut meet = SpaceMeet::get(cli, &space_pk, Some(meet_sk_entity.clone()))
+        .await?
+        .ok_or(MeetActionError::NotFound)?;
+    if meet.status != MeetStatus::Scheduled {
+        return Err(MeetActionError::NotEditable(meet.status).into());
+    }
+
     let now = crate::common::utils::time::get_now_timestamp_millis();
     let mut meet_updater = SpaceMeet::updater(&space_pk, &meet_sk_entity).with_updated_at(now);
 
     match req {
         UpdateMeetRequest::Mode { mode } => {
+            meet.mode = mode.clone();
             meet_updater = meet_updater.with_mode(mode);
         }
         UpdateMeetRequest::StartTime { start_time } => {
-            meet_updater = meet_updater.with_start_time(start_time);
+            // A moved meet is owed a fresh 10-minute reminder.
+            meet.start_time = start_time;
+            meet.reminder_sent = false;
+            meet_updater = meet_updater
+                .with_start_time(start_time)
+                .with_reminder_sent(false);
         }
         UpdateMeetRequest::DurationMin { duration_min } => {
             if !(15..=1440).contains(&duration_min) {
@@ -46,10 +59,20 @@ pub async fn update_meet(
         }
     }
 
-    meet_updater.execute(cli).await.map_err(|e| {
-        crate::error!("update meet failed: {e}");
-        MeetActionError::UpdateFailed
-    })?;
+    // Only a published meet sits in the deadline index; a draft enters it
+    // on publish (`handle_meet_published`).
+    let published = meet.schedule_bucket.is_some();
+    let meet = meet.with_schedule_keys();
+    if let (true, Some(bucket)) = (published, meet.schedule_bucket) {
+        meet_updater = meet_updater
+            .with_schedule_bucket(bucket)
+            .with_next_deadline_at(meet.next_deadline_at);
+    }
+
+    // A host may have started or cancelled the meet since the read.
+    if !execute_meet_update_if(cli, meet_updater, MeetGuard::status(MeetStatus::Scheduled)).await? {
+        return Err(MeetActionError::StatusChanged.into());
+    }
 
     Ok("success".to_string())
 }
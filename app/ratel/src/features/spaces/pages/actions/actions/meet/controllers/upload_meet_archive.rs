@@ -0,0 +1,108 @@
+use crate::features::spaces::pages::actions::actions::meet::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// The recording itself goes to storage through the presigned asset
+/// upload first; only its URL travels here. The transcript body is sent
+/// inline and parsed server-side.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct UploadMeetArchiveRequest {
+    #[serde(default)]
+    pub recording_url: Option<String>,
+    #[serde(default)]
+    pub recording_file_name: Option<String>,
+    pub transcript_file_name: String,
+    /// Detected from the file name / `WEBVTT` header when omitted.
+    #[serde(default)]
+    pub transcript_format: Option<TranscriptFormat>,
+    pub transcript: String,
+    /// Speaker label → the meeting tool's stable participant id (the
+    /// Ratel user id or username the attendee joined with). Mapped labels
+    /// credit exactly that participant; unmapped labels fall back to name
+    /// matching and are skipped when ambiguous.
+    #[serde(default)]
+    pub speaker_ids: std::collections::HashMap<String, String>,
+}
+
+#[mcp_tool(
+    name = "upload_meet_archive",
+    description = "Attach the recording and a WebVTT/SRT transcript to an ended meet. Cues are stored speaker-attributed, indexed for search, and speakers are credited with attendance, by the provider participant id in speaker_ids or else by an unambiguous name match. One archive per meet. Requires creator role."
+)]
+#[post("/api/spaces/{space_pk}/meets/{meet_sk}/archive", role: SpaceUserRole, user: crate::features::auth::User)]
+pub async fn upload_meet_archive(
+    #[mcp(description = "Space partition key")] space_pk: SpacePartition,
+    #[mcp(description = "Meet sort key (e.g. 'SpaceMeet#<uuid>')")] meet_sk: SpaceMeetEntityType,
+    #[mcp(description = "Archive as JSON: {\"recording_url\": \"...\", \"transcript_file_name\": \"meet.vtt\", \"transcript\": \"WEBVTT ...\", \"speaker_ids\": {\"Alice\": \"<user id>\"}}")]
+    req: UploadMeetArchiveRequest,
+) -> Result<MeetArchiveResponse> {
+    SpaceMeet::can_edit(&role)?;
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+    let space_id = space_pk;
+    let meet_id = meet_sk;
+    let space_pk: Partition = space_id.clone().into();
+    let meet_sk_entity: EntityType = meet_id.clone().into();
+
+    let meet = SpaceMeet::get(cli, &space_pk, Some(meet_sk_entity))
+        .await?
+        .ok_or(MeetActionError::NotFound)?;
+    if meet.status != MeetStatus::Ended {
+        return Err(MeetActionError::NotEnded(meet.status).into());
+    }
+
+    let (pk, sk) = SpaceMeetArchive::keys(&space_pk, &meet_id);
+    if SpaceMeetArchive::get(cli, &pk, Some(sk.clone())).await?.is_some() {
+        return Err(MeetActionError::ArchiveExists.into());
+    }
+
+    let format = req
+        .transcript_format
+        .unwrap_or_else(|| TranscriptFormat::detect(&req.transcript_file_name, &req.transcript));
+    let segments = parse_transcript(format, &req.transcript)?;
+    let speakers = transcript_speakers(&segments);
+
+    let archive = SpaceMeetArchive {
+        pk,
+        sk,
+        created_at: crate::common::utils::time::get_now_timestamp_millis(),
+        uploaded_by: user.pk.clone(),
+        recording_url: req.recording_url.filter(|u| !u.trim().is_empty()),
+        recording_file_name: req.recording_file_name,
+        transcript_file_name: req.transcript_file_name,
+        transcript_format: format,
+        segment_count: segments.len() as i64,
+        duration_ms: segments.iter().map(|s| s.end_ms).max().unwrap_or_default(),
+        word_count: segments
+            .iter()
+            .map(|s| s.text.split_whitespace().count() as i64)
+            .sum(),
+        speakers,
+    };
+    store_meet_archive(cli, &archive, segments).await?;
+
+    let attendee_count = match record_meet_attendance(
+        cli,
+        &space_id,
+        &meet_id,
+        &archive.speakers,
+        &req.speaker_ids,
+    )
+    .await
+    {
+        Ok(n) => n as i64,
+        Err(e) => {
+            crate::error!("meet attendance from transcript failed: {e}");
+            0
+        }
+    };
+
+    if let Err(e) = notify_meet_participants(cli, &meet, MeetNotice::RecordingAvailable).await {
+        crate::error!("meet recording notification failed: {e}");
+    }
+
+    let mut response: MeetArchiveResponse = archive.into();
+    response.attendee_count = attendee_count;
+    Ok(response)
+}
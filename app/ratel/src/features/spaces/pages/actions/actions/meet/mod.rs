@@ -1,11 +1,15 @@
 pub mod components;
 pub mod controllers;
 pub mod models;
+#[cfg(feature = "server")]
+pub mod services;
 pub mod types;
 
 pub use components::*;
 pub use controllers::*;
 pub use models::*;
+#[cfg(feature = "server")]
+pub use services::*;
 pub use types::*;
 
 use crate::features::spaces::pages::actions::*;
@@ -1,2 +1,11 @@
 mod space_meet;
 pub use space_meet::*;
+
+mod space_meet_archive;
+pub use space_meet_archive::*;
+
+mod space_meet_attendance;
+pub use space_meet_attendance::*;
+
+mod space_meet_transcript_segment;
+pub use space_meet_transcript_segment::*;
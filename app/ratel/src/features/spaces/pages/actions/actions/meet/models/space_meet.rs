@@ -1,6 +1,7 @@
 use crate::common::macros::DynamoEntity;
 use crate::common::utils::time::get_now_timestamp_millis;
 use crate::features::spaces::pages::actions::*;
+use crate::features::spaces::pages::actions::actions::meet::MeetActionError;
 #[cfg(feature = "server")]
 #[allow(unused_imports)]
 use rmcp::schemars;
@@ -15,6 +16,64 @@ pub enum MeetMode {
     Instant,
 }
 
+/// Lifecycle per `roadmap/meet-action.md` FR-1:
+/// `Scheduled → Live → Ended`, `Scheduled → Cancelled`, `Scheduled → Expired`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, Translate)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub enum MeetStatus {
+    #[default]
+    #[translate(ko = "예정", en = "Scheduled")]
+    Scheduled,
+    #[translate(ko = "진행 중", en = "Live")]
+    Live,
+    #[translate(ko = "종료", en = "Ended")]
+    Ended,
+    #[translate(ko = "취소됨", en = "Cancelled")]
+    Cancelled,
+    #[translate(ko = "만료됨", en = "Expired")]
+    Expired,
+}
+
+impl MeetStatus {
+    pub fn is_terminal(self) -> bool {
+        matches!(
+            self,
+            MeetStatus::Ended | MeetStatus::Cancelled | MeetStatus::Expired
+        )
+    }
+
+    pub fn can_transition_to(self, next: MeetStatus) -> bool {
+        matches!(
+            (self, next),
+            (MeetStatus::Scheduled, MeetStatus::Live)
+                | (MeetStatus::Scheduled, MeetStatus::Cancelled)
+                | (MeetStatus::Scheduled, MeetStatus::Expired)
+                | (MeetStatus::Live, MeetStatus::Ended)
+        )
+    }
+}
+
+/// The "starting soon" reminder goes out this long before `start_time`.
+pub const MEET_REMINDER_LEAD_MS: i64 = 10 * 60 * 1000;
+/// A `Scheduled` meet nobody started is expired this long after `start_time`.
+pub const MEET_EXPIRE_AFTER_MS: i64 = 24 * 60 * 60 * 1000;
+/// Live meets are ended by the scheduler at the hosting ceiling (24h).
+pub const MEET_MAX_LIVE_MS: i64 = 24 * 60 * 60 * 1000;
+/// Single partition of the sparse `find_due_meets` GSI. Low volume — one
+/// row per non-terminal meet — so no sharding.
+pub const MEET_SCHEDULE_BUCKET: &str = "MEET_SCHEDULE";
+
+/// Deadline work the scheduler owes a meet at a given instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeetTick {
+    /// Send the 10-minute reminder (`send = false` when the window was
+    /// missed, e.g. the meet was scheduled less than 10 minutes out —
+    /// only the flag is set).
+    Remind { send: bool },
+    Expire,
+    End,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
 #[dynamo(prefix = "SM")]
 pub struct SpaceMeet {
@@ -27,6 +86,82 @@ pub struct SpaceMeet {
     pub mode: MeetMode,
     pub start_time: i64,
     pub duration_min: i32,
+
+    #[serde(default)]
+    pub status: MeetStatus,
+    #[serde(default)]
+    pub live_at: Option<i64>,
+    #[serde(default)]
+    pub ended_at: Option<i64>,
+    #[serde(default)]
+    pub cancel_reason: Option<String>,
+    #[serde(default)]
+    pub reminder_sent: bool,
+
+    /// Sparse GSI partition for the deadline scheduler. `Some` while the
+    /// meet is published and non-terminal; unset on drafts and cleared on
+    /// Ended/Cancelled/Expired so the row drops out of the index.
+    #[serde(default)]
+    #[dynamo(index = "gsi1", name = "find_due_meets", pk)]
+    pub schedule_bucket: Option<String>,
+    /// Next instant the scheduler has work for this meet. See
+    /// [`SpaceMeet::next_deadline`].
+    #[serde(default)]
+    #[dynamo(index = "gsi1", sk)]
+    pub next_deadline_at: i64,
+}
+
+impl SpaceMeet {
+    /// Earliest instant `due_tick` can return something, `None` once
+    /// terminal.
+    pub fn next_deadline(&self) -> Option<i64> {
+        match self.status {
+            MeetStatus::Scheduled if !self.reminder_sent => {
+                Some(self.start_time - MEET_REMINDER_LEAD_MS)
+            }
+            MeetStatus::Scheduled => Some(self.start_time + MEET_EXPIRE_AFTER_MS),
+            MeetStatus::Live => Some(self.live_at.unwrap_or(self.start_time) + MEET_MAX_LIVE_MS),
+            _ => None,
+        }
+    }
+
+    /// What the scheduler should do at `now`. Pure.
+    pub fn due_tick(&self, now: i64) -> Option<MeetTick> {
+        match self.status {
+            MeetStatus::Scheduled if now >= self.start_time + MEET_EXPIRE_AFTER_MS => {
+                Some(MeetTick::Expire)
+            }
+            MeetStatus::Scheduled
+                if !self.reminder_sent && now >= self.start_time - MEET_REMINDER_LEAD_MS =>
+            {
+                Some(MeetTick::Remind {
+                    send: self.mode == MeetMode::Scheduled && now < self.start_time,
+                })
+            }
+            MeetStatus::Live
+                if now >= self.live_at.unwrap_or(self.start_time) + MEET_MAX_LIVE_MS =>
+            {
+                Some(MeetTick::End)
+            }
+            _ => None,
+        }
+    }
+
+    /// `(schedule_bucket, next_deadline_at)` matching the current state.
+    pub fn schedule_keys(&self) -> (Option<String>, i64) {
+        match self.next_deadline() {
+            Some(at) => (Some(MEET_SCHEDULE_BUCKET.to_string()), at),
+            None => (None, 0),
+        }
+    }
+
+    pub fn ensure_transition(&self, next: MeetStatus) -> Result<()> {
+        if self.status.can_transition_to(next) {
+            Ok(())
+        } else {
+            Err(MeetActionError::InvalidTransition(self.status, next).into())
+        }
+    }
 }
 
 #[cfg(feature = "server")]
@@ -43,9 +178,26 @@ impl SpaceMeet {
             mode: MeetMode::Scheduled,
             start_time: now,
             duration_min: 60,
+            status: MeetStatus::Scheduled,
+            live_at: None,
+            ended_at: None,
+            cancel_reason: None,
+            reminder_sent: false,
+            // Out of the deadline index until the action is published;
+            // `handle_meet_published` adds it.
+            schedule_bucket: None,
+            next_deadline_at: 0,
         })
     }
 
+    /// Copy of `self` with the scheduler GSI keys recomputed.
+    pub fn with_schedule_keys(mut self) -> Self {
+        let (bucket, at) = self.schedule_keys();
+        self.schedule_bucket = bucket;
+        self.next_deadline_at = at;
+        self
+    }
+
     pub fn can_edit(role: &SpaceUserRole) -> Result<()> {
         if role.is_admin() {
             Ok(())
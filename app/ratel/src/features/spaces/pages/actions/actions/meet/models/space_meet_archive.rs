@@ -0,0 +1,51 @@
+use crate::common::macros::DynamoEntity;
+use crate::features::spaces::pages::actions::*;
+use crate::features::spaces::pages::actions::actions::meet::TranscriptFormat;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Post-meeting archive: the recording reference plus transcript
+/// metadata. The cues themselves live in `SpaceMeetTranscriptSegment`
+/// rows under the same space partition. One per meet and immutable once
+/// written (roadmap: "Recordings are immutable once produced").
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct SpaceMeetArchive {
+    pub pk: Partition,  // SPACE#{space_id}
+    pub sk: EntityType, // SpaceMeetArchive#{meet_id}
+
+    pub created_at: i64,
+    pub uploaded_by: Partition,
+
+    #[serde(default)]
+    pub recording_url: Option<String>,
+    #[serde(default)]
+    pub recording_file_name: Option<String>,
+
+    pub transcript_file_name: String,
+    pub transcript_format: TranscriptFormat,
+    pub segment_count: i64,
+    /// End of the last cue — the transcript's running time.
+    pub duration_ms: i64,
+    pub word_count: i64,
+    /// Distinct speaker labels in order of first appearance.
+    #[serde(default)]
+    pub speakers: Vec<String>,
+}
+
+impl SpaceMeetArchive {
+    pub fn keys(space_pk: &Partition, meet_id: &SpaceMeetEntityType) -> (Partition, EntityType) {
+        (
+            space_pk.clone(),
+            EntityType::SpaceMeetArchive(meet_id.to_string()),
+        )
+    }
+
+    pub fn meet_id(&self) -> SpaceMeetEntityType {
+        match &self.sk {
+            EntityType::SpaceMeetArchive(id) => id.clone().into(),
+            _ => SpaceMeetEntityType::default(),
+        }
+    }
+}
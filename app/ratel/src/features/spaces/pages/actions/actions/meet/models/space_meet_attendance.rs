@@ -0,0 +1,64 @@
+use crate::common::macros::DynamoEntity;
+use crate::common::utils::time::get_now_timestamp_millis;
+use crate::features::spaces::pages::actions::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// A participant counted as present at a meet. Written once per (meet,
+/// user); the INSERT drives `MeetXpRecord`, which records the activity and
+/// pays the `AttendMeet` reward.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct SpaceMeetAttendance {
+    pub pk: Partition,  // SPACE#{space_id}
+    pub sk: EntityType, // SpaceMeetAttendance#{meet_id}#{user_pk}
+
+    pub created_at: i64,
+    pub space_id: String,
+    pub meet_id: String,
+    pub user_pk: Partition,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub profile_url: String,
+    /// Transcript speaker label the user was matched on.
+    #[serde(default)]
+    pub speaker_label: String,
+}
+
+impl SpaceMeetAttendance {
+    pub fn keys(
+        space_pk: &Partition,
+        meet_id: &SpaceMeetEntityType,
+        user_pk: &Partition,
+    ) -> (Partition, EntityType) {
+        (
+            space_pk.clone(),
+            EntityType::SpaceMeetAttendance(format!("{meet_id}#{user_pk}")),
+        )
+    }
+
+    pub fn new(
+        space_id: SpacePartition,
+        meet_id: &SpaceMeetEntityType,
+        user_pk: Partition,
+        display_name: String,
+        profile_url: String,
+        speaker_label: String,
+    ) -> Self {
+        let space_pk: Partition = space_id.clone().into();
+        let (pk, sk) = Self::keys(&space_pk, meet_id, &user_pk);
+        Self {
+            pk,
+            sk,
+            created_at: get_now_timestamp_millis(),
+            space_id: space_id.to_string(),
+            meet_id: meet_id.to_string(),
+            user_pk,
+            display_name,
+            profile_url,
+            speaker_label,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::common::macros::DynamoEntity;
+use crate::features::spaces::pages::actions::*;
+use crate::features::spaces::pages::actions::actions::meet::TranscriptSegment;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// One transcript cue. `seq` is zero-padded into the sort key so a
+/// begins-with query on the meet prefix returns cues in playback order.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct SpaceMeetTranscriptSegment {
+    pub pk: Partition,  // SPACE#{space_id}
+    pub sk: EntityType, // SpaceMeetTranscriptSegment#{meet_id}#{seq:06}
+
+    pub seq: i64,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    #[serde(default)]
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+impl SpaceMeetTranscriptSegment {
+    pub fn sk_prefix(meet_id: &SpaceMeetEntityType) -> String {
+        EntityType::SpaceMeetTranscriptSegment(format!("{meet_id}#")).to_string()
+    }
+
+    pub fn new(
+        space_pk: Partition,
+        meet_id: &SpaceMeetEntityType,
+        seq: usize,
+        cue: TranscriptSegment,
+    ) -> Self {
+        Self {
+            pk: space_pk,
+            sk: EntityType::SpaceMeetTranscriptSegment(format!("{meet_id}#{seq:06}")),
+            seq: seq as i64,
+            start_ms: cue.start_ms,
+            end_ms: cue.end_ms,
+            speaker: cue.speaker,
+            text: cue.text,
+        }
+    }
+}
+
+impl From<SpaceMeetTranscriptSegment> for TranscriptSegment {
+    fn from(row: SpaceMeetTranscriptSegment) -> Self {
+        Self {
+            start_ms: row.start_ms,
+            end_ms: row.end_ms,
+            speaker: row.speaker,
+            text: row.text,
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl SpaceMeetTranscriptSegment {
+    /// Every cue of a meet, in playback order.
+    pub async fn list_for_meet(
+        cli: &aws_sdk_dynamodb::Client,
+        space_pk: &Partition,
+        meet_id: &SpaceMeetEntityType,
+    ) -> Result<Vec<Self>> {
+        let mut out = Vec::new();
+        let mut bookmark: Option<String> = None;
+        loop {
+            let mut opt = Self::opt()
+                .sk(Self::sk_prefix(meet_id))
+                .scan_index_forward(true)
+                .limit(500);
+            if let Some(bk) = bookmark.take() {
+                opt = opt.bookmark(bk);
+            }
+            let (rows, next) = Self::query(cli, space_pk.clone(), opt).await?;
+            out.extend(rows);
+            match next {
+                Some(bk) => bookmark = Some(bk),
+                None => break,
+            }
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,143 @@
+use crate::common::models::space::SpaceParticipant;
+use crate::features::spaces::pages::actions::actions::meet::*;
+use std::collections::HashMap;
+
+/// Persist a parsed transcript: cue rows first, then the archive row. The
+/// archive INSERT is what the stream pipes key on (Qdrant + Essence), so
+/// the cues must already be readable when it lands.
+pub async fn store_meet_archive(
+    cli: &aws_sdk_dynamodb::Client,
+    archive: &SpaceMeetArchive,
+    segments: Vec<TranscriptSegment>,
+) -> Result<()> {
+    let meet_id = archive.meet_id();
+    let txs: Vec<_> = segments
+        .into_iter()
+        .enumerate()
+        .map(|(seq, cue)| {
+            SpaceMeetTranscriptSegment::new(archive.pk.clone(), &meet_id, seq, cue)
+                .upsert_transact_write_item()
+        })
+        .collect();
+    crate::transact_write_all_items!(cli, txs);
+
+    archive.create(cli).await.map_err(|e| {
+        crate::error!("meet archive create failed: {e}");
+        MeetActionError::ArchiveFailed
+    })?;
+    Ok(())
+}
+
+/// Credit every participant a transcript speaker resolves to (see
+/// [`match_meet_speakers`]) with attendance. Each new row drives
+/// `MeetXpRecord`. Unmatched or ambiguous labels (guests, "Speaker 1",
+/// shared display names) are ignored. Returns the number of participants
+/// matched.
+pub async fn record_meet_attendance(
+    cli: &aws_sdk_dynamodb::Client,
+    space_id: &SpacePartition,
+    meet_id: &SpaceMeetEntityType,
+    speakers: &[String],
+    speaker_ids: &HashMap<String, String>,
+) -> Result<usize> {
+    if speakers.is_empty() {
+        return Ok(0);
+    }
+
+    // Ambiguity is only knowable against the whole roster, so page it in
+    // before matching anything.
+    let space_pk: Partition = space_id.clone().into();
+    let mut participants = Vec::new();
+    let mut bookmark: Option<String> = None;
+    loop {
+        let opt = SpaceParticipant::opt_with_bookmark(bookmark.take()).limit(100);
+        let (rows, next) = SpaceParticipant::find_by_space(cli, &space_pk, opt).await?;
+        participants.extend(rows);
+        match next {
+            Some(bk) => bookmark = Some(bk),
+            None => break,
+        }
+    }
+
+    let mut matched = 0;
+    for (participant, label) in match_meet_speakers(&participants, speakers, speaker_ids) {
+        let row = SpaceMeetAttendance::new(
+            space_id.clone(),
+            meet_id,
+            participant.user_pk.clone(),
+            participant.display_name.clone(),
+            participant.profile_url.clone(),
+            label.clone(),
+        );
+        // Conditional create: a re-run never double-credits.
+        if let Err(e) = row.create(cli).await {
+            tracing::debug!(
+                user_pk = %participant.user_pk,
+                error = %e,
+                "record_meet_attendance: already recorded or write failed"
+            );
+            continue;
+        }
+        matched += 1;
+    }
+    Ok(matched)
+}
+
+/// Resolve transcript speaker labels to space participants.
+///
+/// A label the uploader mapped in `speaker_ids` to the meeting tool's
+/// stable participant id (the Ratel user id or username the attendee
+/// joined with) matches only that participant. Unmapped labels fall back
+/// to username / display name, and a label that fits more than one
+/// participant is skipped rather than guessed. Each participant is
+/// credited at most once, on the first label that resolves to them.
+pub fn match_meet_speakers<'a>(
+    participants: &'a [SpaceParticipant],
+    speakers: &'a [String],
+    speaker_ids: &HashMap<String, String>,
+) -> Vec<(&'a SpaceParticipant, &'a String)> {
+    let mut out: Vec<(&SpaceParticipant, &String)> = Vec::new();
+    for label in speakers {
+        let hits: Vec<&SpaceParticipant> = match speaker_ids.get(label) {
+            Some(id) => {
+                let id = normalize_speaker_label(id);
+                participants
+                    .iter()
+                    .filter(|p| {
+                        !id.is_empty()
+                            && (matches!(&p.user_pk, Partition::User(uid) if uid.to_lowercase() == id)
+                                || p.username.to_lowercase() == id)
+                    })
+                    .collect()
+            }
+            None => {
+                let key = normalize_speaker_label(label);
+                participants
+                    .iter()
+                    .filter(|p| {
+                        !key.is_empty()
+                            && (p.username.to_lowercase() == key
+                                || p.display_name.to_lowercase() == key)
+                    })
+                    .collect()
+            }
+        };
+        let participant = match hits.as_slice() {
+            [p] => *p,
+            [] => continue,
+            _ => {
+                tracing::debug!(
+                    speaker = %label,
+                    candidates = hits.len(),
+                    "match_meet_speakers: ambiguous label skipped"
+                );
+                continue;
+            }
+        };
+        if out.iter().any(|(p, _)| p.user_pk == participant.user_pk) {
+            continue;
+        }
+        out.push((participant, label));
+    }
+    out
+}
@@ -0,0 +1,195 @@
+use crate::features::spaces::pages::actions::actions::meet::*;
+
+/// Which stored state a guarded meet write expects to find.
+#[derive(Debug, Clone, Copy)]
+pub struct MeetGuard {
+    pub status: MeetStatus,
+    /// Also require `reminder_sent` to still be unset (the 10-minute
+    /// reminder is sent once, by whoever flips the flag).
+    pub reminder_pending: bool,
+}
+
+impl MeetGuard {
+    pub fn status(status: MeetStatus) -> Self {
+        Self {
+            status,
+            reminder_pending: false,
+        }
+    }
+}
+
+/// Run `updater` only while the row still matches `guard`. Returns
+/// `false` when another writer — a second scheduler tick, `get_meet`, a
+/// host — moved the meet first; callers then skip the side effects that
+/// belong to that write.
+pub async fn execute_meet_update_if(
+    cli: &aws_sdk_dynamodb::Client,
+    updater: SpaceMeetUpdater,
+    guard: MeetGuard,
+) -> Result<bool> {
+    use aws_sdk_dynamodb::types::AttributeValue;
+
+    let item = updater.transact_write_item();
+    let Some(update) = item.update() else {
+        return Err(MeetActionError::UpdateFailed.into());
+    };
+
+    let mut condition = format!(
+        "{} AND #guard_status = :guard_status",
+        update
+            .condition_expression()
+            .unwrap_or("attribute_exists(pk)")
+    );
+    let mut names = update
+        .expression_attribute_names()
+        .cloned()
+        .unwrap_or_default();
+    let mut values = update
+        .expression_attribute_values()
+        .cloned()
+        .unwrap_or_default();
+    names.insert("#guard_status".to_string(), "status".to_string());
+    values.insert(
+        ":guard_status".to_string(),
+        serde_dynamo::to_attribute_value(guard.status).map_err(|e| {
+            crate::error!("meet guard serialization failed: {e}");
+            MeetActionError::UpdateFailed
+        })?,
+    );
+    if guard.reminder_pending {
+        condition.push_str(
+            " AND (attribute_not_exists(#guard_reminder) OR #guard_reminder = :guard_false)",
+        );
+        names.insert("#guard_reminder".to_string(), "reminder_sent".to_string());
+        values.insert(":guard_false".to_string(), AttributeValue::Bool(false));
+    }
+
+    let resp = cli
+        .update_item()
+        .table_name(update.table_name())
+        .set_key(Some(update.key().clone()))
+        .update_expression(update.update_expression())
+        .condition_expression(condition)
+        .set_expression_attribute_names(Some(names))
+        .set_expression_attribute_values(Some(values))
+        .send()
+        .await;
+
+    match resp {
+        Ok(_) => Ok(true),
+        Err(e) => {
+            let svc = e.into_service_error();
+            if matches!(
+                svc,
+                aws_sdk_dynamodb::operation::update_item::UpdateItemError::ConditionalCheckFailedException(_)
+            ) {
+                return Ok(false);
+            }
+            crate::error!("guarded meet update failed: {svc}");
+            Err(MeetActionError::UpdateFailed.into())
+        }
+    }
+}
+
+/// Move `meet` to `next` and persist status, lifecycle timestamps and the
+/// scheduler GSI keys in one update, conditional on the row still having
+/// `meet.status`. Rejects transitions outside
+/// [`MeetStatus::can_transition_to`]. Returns `None` when the stored status
+/// had already moved on — the caller lost the race and must not repeat the
+/// transition's side effects.
+pub async fn apply_meet_transition(
+    cli: &aws_sdk_dynamodb::Client,
+    meet: SpaceMeet,
+    next: MeetStatus,
+    cancel_reason: Option<String>,
+) -> Result<Option<SpaceMeet>> {
+    meet.ensure_transition(next)?;
+
+    let expected = meet.status;
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let mut meet = meet;
+    meet.status = next;
+    meet.updated_at = now;
+    match next {
+        MeetStatus::Live => meet.live_at = Some(now),
+        MeetStatus::Ended | MeetStatus::Expired => meet.ended_at = Some(now),
+        MeetStatus::Cancelled => {
+            meet.ended_at = Some(now);
+            meet.cancel_reason = cancel_reason.filter(|r| !r.trim().is_empty());
+        }
+        MeetStatus::Scheduled => {}
+    }
+    let meet = meet.with_schedule_keys();
+
+    let mut updater = SpaceMeet::updater(&meet.pk, &meet.sk)
+        .with_status(meet.status)
+        .with_updated_at(now);
+    if let Some(at) = meet.live_at {
+        updater = updater.with_live_at(at);
+    }
+    if let Some(at) = meet.ended_at {
+        updater = updater.with_ended_at(at);
+    }
+    if let Some(reason) = meet.cancel_reason.clone() {
+        updater = updater.with_cancel_reason(reason);
+    }
+    updater = match meet.schedule_bucket.clone() {
+        Some(bucket) => updater
+            .with_schedule_bucket(bucket)
+            .with_next_deadline_at(meet.next_deadline_at),
+        None => updater.remove_schedule_bucket().with_next_deadline_at(0),
+    };
+    if !execute_meet_update_if(cli, updater, MeetGuard::status(expected)).await? {
+        tracing::info!(meet_sk = %meet.sk, status = ?next, "meet transition lost a race");
+        return Ok(None);
+    }
+
+    tracing::info!(meet_sk = %meet.sk, status = ?next, "meet transitioned");
+    Ok(Some(meet))
+}
+
+/// Called when a Meet's SpaceAction is published (Designing → Ongoing).
+/// Scheduled meets enter the deadline index and announce themselves;
+/// Instant meets go Live right away and send the single "live now" notice
+/// in place of "scheduled" (FR-5 #20).
+pub async fn handle_meet_published(
+    cli: &aws_sdk_dynamodb::Client,
+    action: &crate::features::spaces::pages::actions::models::SpaceAction,
+) -> Result<()> {
+    let space_pk: Partition = action.pk.0.clone().into();
+    let meet_id: SpaceMeetEntityType = action.pk.1.clone().into();
+    let meet_sk: EntityType = meet_id.into();
+    let Some(meet) = SpaceMeet::get(cli, &space_pk, Some(meet_sk)).await? else {
+        tracing::warn!(action_id = %action.pk.1, "meet published but SpaceMeet row missing");
+        return Ok(());
+    };
+    if meet.status != MeetStatus::Scheduled {
+        return Ok(());
+    }
+
+    match meet.mode {
+        MeetMode::Instant => {
+            match apply_meet_transition(cli, meet, MeetStatus::Live, None).await? {
+                Some(meet) => notify_meet_participants(cli, &meet, MeetNotice::LiveNow).await,
+                None => Ok(()),
+            }
+        }
+        MeetMode::Scheduled => {
+            // Drafts stay out of the deadline index (see `SpaceMeet::new`);
+            // publishing is what hands the meet to the scheduler.
+            let meet = meet.with_schedule_keys();
+            let mut updater = SpaceMeet::updater(&meet.pk, &meet.sk);
+            if let Some(bucket) = meet.schedule_bucket.clone() {
+                updater = updater
+                    .with_schedule_bucket(bucket)
+                    .with_next_deadline_at(meet.next_deadline_at);
+            }
+            if !execute_meet_update_if(cli, updater, MeetGuard::status(MeetStatus::Scheduled))
+                .await?
+            {
+                return Ok(());
+            }
+            notify_meet_participants(cli, &meet, MeetNotice::Scheduled).await
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use crate::common::models::notification::Notification;
+use crate::common::models::space::SpaceCommon;
+use crate::features::posts::models::Post;
+use crate::features::spaces::pages::actions::actions::meet::*;
+use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::space_common::services::space_status_change_notification::{
+    resolve_emails, resolve_space_participant_user_pks,
+};
+
+const EMAIL_CHUNK_SIZE: usize = 50;
+
+/// Fan one lifecycle notice out to every space participant: an inbox row
+/// (deduped per user, meet and notice) plus email via `Notification` rows.
+/// Mirrors `notify_action_ongoing`, including the guard that the parent
+/// space is Ongoing — there is no audience otherwise.
+pub async fn notify_meet_participants(
+    cli: &aws_sdk_dynamodb::Client,
+    meet: &SpaceMeet,
+    notice: MeetNotice,
+) -> Result<()> {
+    let space_id: SpacePartition = meet.pk.clone().into();
+    let space_pk = meet.pk.clone();
+    let meet_id = SpaceMeetEntityType::from(meet.sk.clone()).to_string();
+
+    let space = match SpaceCommon::get(cli, &space_pk, Some(&EntityType::SpaceCommon)).await? {
+        Some(s) => s,
+        None => return Ok(()),
+    };
+    if space.status != Some(SpaceStatus::Ongoing) {
+        tracing::info!(
+            space_pk = %space_pk,
+            notice = notice.as_str(),
+            "notify_meet_participants: parent space not Ongoing, skipping",
+        );
+        return Ok(());
+    }
+
+    let action = SpaceAction::get(
+        cli,
+        &CompositePartition(space_id.clone(), meet_id.clone()),
+        Some(EntityType::SpaceAction),
+    )
+    .await?
+    .ok_or(Error::SpaceActionNotFound)?;
+
+    let user_pks = resolve_space_participant_user_pks(cli, &space_pk).await?;
+    if user_pks.is_empty() {
+        return Ok(());
+    }
+
+    let post_pk = space_pk.clone().to_post_key()?;
+    let space_title = match Post::get(cli, &post_pk, Some(&EntityType::Post)).await? {
+        Some(p) => p.title,
+        None => {
+            tracing::error!("notify_meet_participants: post not found for {}", post_pk);
+            return Ok(());
+        }
+    };
+    let cta_url = action.get_cta_url();
+
+    tracing::info!(
+        space_pk = %space_pk,
+        meet_id = %meet_id,
+        notice = notice.as_str(),
+        recipient_count = user_pks.len(),
+        "notify_meet_participants: fanning out",
+    );
+
+    let dedup_source = format!("{}:{}:{}", space_pk, meet_id, notice.as_str());
+    for user_pk in &user_pks {
+        let payload = InboxPayload::MeetLifecycle {
+            space_id: space_id.clone(),
+            space_title: space_title.clone(),
+            meet_id: meet_id.clone(),
+            meet_title: action.title.clone(),
+            notice,
+            start_time: meet.start_time,
+            cta_url: cta_url.clone(),
+        };
+        if let Err(e) = crate::common::utils::inbox::create_inbox_row_once(
+            user_pk.clone(),
+            payload,
+            &dedup_source,
+        )
+        .await
+        {
+            crate::error!("notify_meet_participants inbox row failed: {e}");
+        }
+    }
+
+    let emails = resolve_emails(cli, user_pks).await?;
+    for chunk in emails.chunks(EMAIL_CHUNK_SIZE) {
+        let n = Notification::new(NotificationData::MeetLifecycle {
+            emails: chunk.to_vec(),
+            space_title: space_title.clone(),
+            meet_title: action.title.clone(),
+            notice,
+            start_time: meet.start_time,
+            cta_url: cta_url.clone(),
+        });
+        if let Err(e) = n.create(cli).await {
+            tracing::error!("notify_meet_participants: failed to create Notification row: {e}");
+        }
+    }
+
+    Ok(())
+}
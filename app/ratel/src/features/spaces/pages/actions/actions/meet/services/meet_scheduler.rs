@@ -0,0 +1,132 @@
+//! Deadline scheduler for meets. Every non-terminal meet sits in the
+//! sparse `find_due_meets` GSI keyed by its next deadline; a tick walks
+//! that index oldest-first and stops at the first deadline in the future.
+//!
+//! Triggered every minute — by an EventBridge schedule in deployed envs
+//! (`DetailType::MeetScheduleTick`) and by [`spawn_meet_scheduler`] under
+//! `local-dev`. `get_meet` also applies overdue Expire/End transitions as
+//! a safety net, so a delayed tick never shows a stale status; reminders
+//! and their notifications are only ever sent from here.
+//!
+//! Every write is conditional on the state the tick was computed from, so
+//! overlapping ticks (or a tick racing `get_meet` / a host) apply each
+//! deadline once.
+
+use crate::features::spaces::pages::actions::actions::meet::*;
+
+const SCHEDULER_PAGE_SIZE: i32 = 100;
+/// Upper bound on meets handled per tick; the rest wait for the next one.
+const MAX_MEETS_PER_TICK: usize = 1_000;
+
+/// Apply whatever deadline work `meet` owes at `now`. Returns `false`
+/// when nothing was due or another writer applied it first.
+pub async fn advance_meet_if_due(
+    cli: &aws_sdk_dynamodb::Client,
+    meet: SpaceMeet,
+    now: i64,
+) -> Result<bool> {
+    let Some(tick) = meet.due_tick(now) else {
+        return Ok(false);
+    };
+
+    match tick {
+        MeetTick::Remind { send } => {
+            let mut meet = meet;
+            meet.reminder_sent = true;
+            let meet = meet.with_schedule_keys();
+            let mut updater = SpaceMeet::updater(&meet.pk, &meet.sk).with_reminder_sent(true);
+            updater = match meet.schedule_bucket.clone() {
+                Some(bucket) => updater
+                    .with_schedule_bucket(bucket)
+                    .with_next_deadline_at(meet.next_deadline_at),
+                None => updater.remove_schedule_bucket().with_next_deadline_at(0),
+            };
+            let guard = MeetGuard {
+                status: MeetStatus::Scheduled,
+                reminder_pending: true,
+            };
+            if !execute_meet_update_if(cli, updater, guard).await? {
+                return Ok(false);
+            }
+
+            if send {
+                if let Err(e) = notify_meet_participants(cli, &meet, MeetNotice::StartingSoon).await
+                {
+                    crate::error!("meet reminder fan-out failed: {e}");
+                }
+            }
+            Ok(true)
+        }
+        MeetTick::Expire => Ok(apply_meet_transition(cli, meet, MeetStatus::Expired, None)
+            .await?
+            .is_some()),
+        MeetTick::End => Ok(apply_meet_transition(cli, meet, MeetStatus::Ended, None)
+            .await?
+            .is_some()),
+    }
+}
+
+/// One scheduler pass. Returns how many meets had deadline work applied.
+/// A failure on one meet is logged and skipped so it can't wedge the
+/// queue; its deadline stays due and the next tick retries it.
+pub async fn run_meet_scheduler(cli: &aws_sdk_dynamodb::Client, now: i64) -> Result<usize> {
+    let mut handled = 0;
+    let mut bookmark: Option<String> = None;
+
+    'pages: loop {
+        let opt = SpaceMeet::opt_with_bookmark(bookmark.take())
+            .scan_index_forward(true)
+            .limit(SCHEDULER_PAGE_SIZE);
+        let (meets, next) =
+            SpaceMeet::find_due_meets(cli, MEET_SCHEDULE_BUCKET, opt).await?;
+
+        for meet in meets {
+            if meet.next_deadline_at > now || handled >= MAX_MEETS_PER_TICK {
+                break 'pages;
+            }
+            let sk = meet.sk.clone();
+            match advance_meet_if_due(cli, meet, now).await {
+                Ok(true) => handled += 1,
+                Ok(false) => {}
+                Err(e) => crate::error!("meet scheduler: {sk} failed: {e}"),
+            }
+        }
+
+        match next {
+            Some(b) => bookmark = Some(b),
+            None => break,
+        }
+    }
+
+    if handled > 0 {
+        tracing::info!(handled, "meet scheduler tick");
+    }
+    Ok(handled)
+}
+
+/// Local-dev stand-in for the EventBridge schedule: a dedicated thread
+/// running [`run_meet_scheduler`] once a minute.
+#[cfg(feature = "local-dev")]
+pub fn spawn_meet_scheduler() {
+    std::thread::Builder::new()
+        .name("meet-scheduler".into())
+        .spawn(|| {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("meet-scheduler runtime");
+            rt.block_on(async {
+                let cfg = crate::common::CommonConfig::default();
+                let cli = cfg.dynamodb();
+                let mut interval = tokio::time::interval(std::time::Duration::from_secs(60));
+                loop {
+                    interval.tick().await;
+                    let now = crate::common::utils::time::get_now_timestamp_millis();
+                    if let Err(e) = run_meet_scheduler(cli, now).await {
+                        tracing::error!("meet scheduler tick failed: {e}");
+                    }
+                }
+            });
+        })
+        .expect("failed to spawn meet-scheduler thread");
+}
@@ -0,0 +1,9 @@
+mod meet_archive;
+mod meet_lifecycle;
+mod meet_notifications;
+mod meet_scheduler;
+
+pub use meet_archive::*;
+pub use meet_lifecycle::*;
+pub use meet_notifications::*;
+pub use meet_scheduler::*;
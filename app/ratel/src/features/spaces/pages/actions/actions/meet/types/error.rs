@@ -1,4 +1,5 @@
 use crate::common::*;
+use crate::features::spaces::pages::actions::actions::meet::MeetStatus;
 pub use thiserror::Error;
 
 #[derive(Debug, Error, Serialize, Deserialize, Translate, Clone, PartialEq)]
@@ -25,6 +26,60 @@ pub enum MeetActionError {
     #[error("delete meet failed")]
     #[translate(en = "Could not delete the meet", ko = "회의를 삭제할 수 없습니다")]
     DeleteFailed,
+
+    #[error("invalid meet transition {0:?} -> {1:?}")]
+    #[translate(
+        en = "This meet can no longer be changed that way",
+        ko = "현재 회의 상태에서는 변경할 수 없습니다"
+    )]
+    InvalidTransition(MeetStatus, MeetStatus),
+
+    #[error("meet is {0:?} and can no longer be edited")]
+    #[translate(
+        en = "Only scheduled meets can be edited",
+        ko = "예정된 회의만 수정할 수 있습니다"
+    )]
+    NotEditable(MeetStatus),
+
+    #[error("meet status changed concurrently")]
+    #[translate(
+        en = "This meet was just updated. Refresh and try again",
+        ko = "회의 상태가 방금 변경되었습니다. 새로고침 후 다시 시도하세요"
+    )]
+    StatusChanged,
+
+    #[error("meet is {0:?}; archives are only accepted once it has ended")]
+    #[translate(
+        en = "Recordings can be added after the meeting ends",
+        ko = "녹화는 회의가 종료된 후에 추가할 수 있습니다"
+    )]
+    NotEnded(MeetStatus),
+
+    #[error("meet archive already exists")]
+    #[translate(
+        en = "This meeting already has a recording",
+        ko = "이 회의에는 이미 녹화가 있습니다"
+    )]
+    ArchiveExists,
+
+    #[error("meet archive not found")]
+    #[translate(en = "No recording yet", ko = "아직 녹화가 없습니다")]
+    ArchiveNotFound,
+
+    #[error("invalid transcript: {0}")]
+    #[translate(
+        en = "The transcript is not a valid WebVTT or SRT file",
+        ko = "자막 파일이 올바른 WebVTT 또는 SRT 형식이 아닙니다"
+    )]
+    InvalidTranscript(String),
+
+    #[error("transcript too large")]
+    #[translate(en = "The transcript file is too large", ko = "자막 파일이 너무 큽니다")]
+    TranscriptTooLarge,
+
+    #[error("save meet archive failed")]
+    #[translate(en = "Could not save the recording", ko = "녹화를 저장할 수 없습니다")]
+    ArchiveFailed,
 }
 
 #[cfg(feature = "server")]
@@ -32,8 +87,17 @@ impl MeetActionError {
     pub fn status_code(&self) -> crate::axum::http::StatusCode {
         use crate::axum::http::StatusCode;
         match self {
-            MeetActionError::NotFound => StatusCode::NOT_FOUND,
-            MeetActionError::InvalidDuration(_) => StatusCode::BAD_REQUEST,
+            MeetActionError::NotFound | MeetActionError::ArchiveNotFound => {
+                StatusCode::NOT_FOUND
+            }
+            MeetActionError::InvalidDuration(_)
+            | MeetActionError::InvalidTranscript(_)
+            | MeetActionError::TranscriptTooLarge => StatusCode::BAD_REQUEST,
+            MeetActionError::InvalidTransition(..)
+            | MeetActionError::NotEditable(_)
+            | MeetActionError::StatusChanged
+            | MeetActionError::NotEnded(_)
+            | MeetActionError::ArchiveExists => StatusCode::CONFLICT,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
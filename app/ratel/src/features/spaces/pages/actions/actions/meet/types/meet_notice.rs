@@ -0,0 +1,65 @@
+use crate::common::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// The participant-facing lifecycle notifications (FR-5). Each goes out
+/// once per meet, in-app and by email, to every space participant.
+/// `RecordingAvailable` is sent by the archive upload, the rest by the
+/// lifecycle transitions and the scheduler.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum MeetNotice {
+    #[default]
+    Scheduled,
+    StartingSoon,
+    LiveNow,
+    Cancelled,
+    RecordingAvailable,
+}
+
+impl MeetNotice {
+    /// Dedup suffix — one inbox row per (user, meet, notice).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MeetNotice::Scheduled => "scheduled",
+            MeetNotice::StartingSoon => "starting_soon",
+            MeetNotice::LiveNow => "live_now",
+            MeetNotice::Cancelled => "cancelled",
+            MeetNotice::RecordingAvailable => "recording_available",
+        }
+    }
+
+    /// English email headline; SES templates are English-only.
+    pub fn email_headline(self, meet_title: &str) -> String {
+        match self {
+            MeetNotice::Scheduled => format!("Meet scheduled: {meet_title}"),
+            MeetNotice::StartingSoon => format!("Starting in 10 minutes: {meet_title}"),
+            MeetNotice::LiveNow => format!("Live now: {meet_title}"),
+            MeetNotice::Cancelled => format!("Meet cancelled: {meet_title}"),
+            MeetNotice::RecordingAvailable => format!("Recording available: {meet_title}"),
+        }
+    }
+
+    pub fn email_body(self, space_title: &str, start_time: i64) -> String {
+        let when = chrono::DateTime::from_timestamp_millis(start_time)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+            .unwrap_or_default();
+        match self {
+            MeetNotice::Scheduled => {
+                format!("A meet in {space_title} is scheduled for {when}.")
+            }
+            MeetNotice::StartingSoon => {
+                format!("A meet in {space_title} starts at {when}. Join from the meet page.")
+            }
+            MeetNotice::LiveNow => format!("A meet in {space_title} is live — join now."),
+            MeetNotice::Cancelled => {
+                format!("The meet in {space_title} planned for {when} was cancelled.")
+            }
+            MeetNotice::RecordingAvailable => format!(
+                "The recording and transcript of the meet in {space_title} are now available."
+            ),
+        }
+    }
+}
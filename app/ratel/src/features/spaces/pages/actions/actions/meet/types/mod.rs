@@ -1,5 +1,11 @@
 mod error;
 pub use error::*;
 
+mod meet_notice;
+pub use meet_notice::*;
+
 mod response;
 pub use response::*;
+
+mod transcript;
+pub use transcript::*;
@@ -8,6 +8,14 @@ pub struct MeetResponse {
     pub mode: MeetMode,
     pub start_time: i64,
     pub duration_min: i32,
+    #[serde(default)]
+    pub status: MeetStatus,
+    #[serde(default)]
+    pub live_at: Option<i64>,
+    #[serde(default)]
+    pub ended_at: Option<i64>,
+    #[serde(default)]
+    pub cancel_reason: Option<String>,
     pub created_at: i64,
     pub updated_at: i64,
 
@@ -26,9 +34,58 @@ impl From<SpaceMeet> for MeetResponse {
             mode: m.mode,
             start_time: m.start_time,
             duration_min: m.duration_min,
+            status: m.status,
+            live_at: m.live_at,
+            ended_at: m.ended_at,
+            cancel_reason: m.cancel_reason,
             created_at: m.created_at,
             updated_at: m.updated_at,
             space_action: SpaceAction::default(),
         }
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MeetArchiveResponse {
+    #[serde(default)]
+    pub recording_url: Option<String>,
+    #[serde(default)]
+    pub recording_file_name: Option<String>,
+    pub transcript_file_name: String,
+    pub transcript_format: TranscriptFormat,
+    pub segment_count: i64,
+    pub duration_ms: i64,
+    pub word_count: i64,
+    #[serde(default)]
+    pub speakers: Vec<String>,
+    /// Participants credited with attendance from the speaker labels.
+    /// Only filled on the upload response.
+    #[serde(default)]
+    pub attendee_count: i64,
+    pub created_at: i64,
+}
+
+impl From<SpaceMeetArchive> for MeetArchiveResponse {
+    fn from(a: SpaceMeetArchive) -> Self {
+        Self {
+            recording_url: a.recording_url,
+            recording_file_name: a.recording_file_name,
+            transcript_file_name: a.transcript_file_name,
+            transcript_format: a.transcript_format,
+            segment_count: a.segment_count,
+            duration_ms: a.duration_ms,
+            word_count: a.word_count,
+            speakers: a.speakers,
+            attendee_count: 0,
+            created_at: a.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MeetTranscriptResponse {
+    pub archive: MeetArchiveResponse,
+    /// Cues in playback order, filtered by the search query when one was
+    /// given.
+    pub segments: Vec<TranscriptSegment>,
+}
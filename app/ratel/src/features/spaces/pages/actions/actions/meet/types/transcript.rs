@@ -0,0 +1,231 @@
+use crate::common::*;
+use crate::features::spaces::pages::actions::actions::meet::MeetActionError;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Upper bound on cues per transcript. A 24h meet captioned every two
+/// seconds stays well under this; anything larger is not a transcript.
+pub const MAX_TRANSCRIPT_SEGMENTS: usize = 20_000;
+/// Upper bound on the raw transcript body, in bytes.
+pub const MAX_TRANSCRIPT_BYTES: usize = 5 * 1024 * 1024;
+/// Longest label accepted as a `Name: text` speaker prefix. Longer
+/// prefixes are treated as ordinary text that happens to contain a colon.
+const MAX_SPEAKER_LABEL_CHARS: usize = 48;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptFormat {
+    #[default]
+    WebVtt,
+    Srt,
+}
+
+impl TranscriptFormat {
+    /// Best guess from the file name, falling back to the `WEBVTT` header.
+    pub fn detect(file_name: &str, raw: &str) -> Self {
+        let lower = file_name.to_ascii_lowercase();
+        let has_header = raw.trim_start_matches('\u{feff}').starts_with("WEBVTT");
+        if lower.ends_with(".srt") {
+            TranscriptFormat::Srt
+        } else if lower.ends_with(".vtt") || has_header {
+            TranscriptFormat::WebVtt
+        } else {
+            TranscriptFormat::Srt
+        }
+    }
+}
+
+/// One caption cue, attributed to a speaker when the source names one —
+/// either a WebVTT voice span (`<v Alice>…`) or a `Alice: …` prefix.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct TranscriptSegment {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    #[serde(default)]
+    pub speaker: Option<String>,
+    pub text: String,
+}
+
+/// Parse a WebVTT or SRT body into segments in cue order. Cues with no
+/// text after tag stripping are dropped; a body with no usable cue is an
+/// error.
+pub fn parse_transcript(
+    format: TranscriptFormat,
+    raw: &str,
+) -> std::result::Result<Vec<TranscriptSegment>, MeetActionError> {
+    if raw.len() > MAX_TRANSCRIPT_BYTES {
+        return Err(MeetActionError::TranscriptTooLarge);
+    }
+    let body = raw
+        .trim_start_matches('\u{feff}')
+        .replace("\r\n", "\n")
+        .replace('\r', "\n");
+    let mut blocks = body.split("\n\n").map(str::trim).filter(|b| !b.is_empty());
+
+    if format == TranscriptFormat::WebVtt {
+        match blocks.next() {
+            Some(header) if header.starts_with("WEBVTT") => {}
+            _ => return Err(MeetActionError::InvalidTranscript("missing WEBVTT header".into())),
+        }
+    }
+
+    let mut segments = Vec::new();
+    for block in blocks {
+        let is_metadata = ["NOTE", "STYLE", "REGION"]
+            .iter()
+            .any(|kw| block.starts_with(kw));
+        if format == TranscriptFormat::WebVtt && is_metadata {
+            continue;
+        }
+
+        let mut lines = block.lines();
+        // Cue identifier (WebVTT, optional) or sequence number (SRT) sits
+        // before the timing line; skip until we find the arrow.
+        let timing = loop {
+            match lines.next() {
+                Some(line) if line.contains("-->") => break line,
+                Some(_) => continue,
+                None => {
+                    return Err(MeetActionError::InvalidTranscript(format!(
+                        "cue without timing: {}",
+                        block.lines().next().unwrap_or_default()
+                    )));
+                }
+            }
+        };
+        let (start_ms, end_ms) = parse_timing(timing)?;
+
+        let text = lines.collect::<Vec<_>>().join(" ");
+        let (speaker, text) = split_speaker(&text);
+        let text = strip_tags(&text);
+        if text.is_empty() {
+            continue;
+        }
+        segments.push(TranscriptSegment {
+            start_ms,
+            end_ms,
+            speaker,
+            text,
+        });
+        if segments.len() > MAX_TRANSCRIPT_SEGMENTS {
+            return Err(MeetActionError::TranscriptTooLarge);
+        }
+    }
+
+    if segments.is_empty() {
+        return Err(MeetActionError::InvalidTranscript("no cues".into()));
+    }
+    Ok(segments)
+}
+
+/// Distinct speaker labels in order of first appearance.
+pub fn transcript_speakers(segments: &[TranscriptSegment]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for speaker in segments.iter().filter_map(|s| s.speaker.as_ref()) {
+        if !out.contains(speaker) {
+            out.push(speaker.clone());
+        }
+    }
+    out
+}
+
+/// Key used to match a speaker label to a participant's username or
+/// display name: trimmed, `@` dropped, lowercased.
+pub fn normalize_speaker_label(label: &str) -> String {
+    label.trim().trim_start_matches('@').trim().to_lowercase()
+}
+
+fn parse_timing(line: &str) -> std::result::Result<(i64, i64), MeetActionError> {
+    let invalid = || MeetActionError::InvalidTranscript(format!("bad timing line: {line}"));
+    let (start, rest) = line.split_once("-->").ok_or_else(invalid)?;
+    // WebVTT allows cue settings (`align:start` …) after the end stamp.
+    let end = rest.split_whitespace().next().ok_or_else(invalid)?;
+    let start_ms = parse_timestamp(start.trim()).ok_or_else(invalid)?;
+    let end_ms = parse_timestamp(end).ok_or_else(invalid)?;
+    if end_ms < start_ms {
+        return Err(invalid());
+    }
+    Ok((start_ms, end_ms))
+}
+
+/// `hh:mm:ss.mmm`, `mm:ss.mmm` (WebVTT) or `hh:mm:ss,mmm` (SRT).
+fn parse_timestamp(raw: &str) -> Option<i64> {
+    let (clock, millis) = raw.split_once(['.', ',']).unwrap_or((raw, "0"));
+    if millis.is_empty() || millis.len() > 3 || !millis.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let millis: i64 = format!("{millis:0<3}").parse().ok()?;
+
+    let parts: Vec<i64> = clock
+        .split(':')
+        .map(|p| p.parse::<i64>().ok().filter(|_| !p.is_empty()))
+        .collect::<Option<_>>()?;
+    let (h, m, s) = match parts.as_slice() {
+        [h, m, s] => (*h, *m, *s),
+        [m, s] => (0, *m, *s),
+        _ => return None,
+    };
+    if m >= 60 || s >= 60 || h < 0 || m < 0 || s < 0 {
+        return None;
+    }
+    Some(((h * 60 + m) * 60 + s) * 1000 + millis)
+}
+
+fn split_speaker(text: &str) -> (Option<String>, String) {
+    let trimmed = text.trim();
+
+    // WebVTT voice span: `<v Alice>`, `<v.loud Alice>`.
+    if let Some(rest) = trimmed.strip_prefix("<v") {
+        if rest.starts_with([' ', '.']) {
+            if let Some((tag, body)) = rest.split_once('>') {
+                let name = tag
+                    .trim_start_matches('.')
+                    .split_once(' ')
+                    .map(|(_, name)| name)
+                    .unwrap_or_default()
+                    .trim();
+                if !name.is_empty() {
+                    return (Some(name.to_string()), body.to_string());
+                }
+            }
+        }
+    }
+
+    // `Alice: …` — the convention most meeting tools export to SRT/VTT.
+    if let Some((label, body)) = trimmed.split_once(':') {
+        let label = label.trim();
+        let is_label = !label.is_empty()
+            && label.chars().count() <= MAX_SPEAKER_LABEL_CHARS
+            && !label.contains('<')
+            && !body.starts_with("//")
+            && !label.chars().all(|c| c.is_ascii_digit());
+        if is_label {
+            return (Some(label.to_string()), body.to_string());
+        }
+    }
+
+    (None, trimmed.to_string())
+}
+
+fn strip_tags(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -139,6 +139,7 @@ impl From<(SpacePoll, bool)>
             comment_count: None,
             status: None,
             depends_on: Vec::new(),
+            dependency_conditions: Vec::new(),
             dependencies_met: true,
         }
     }
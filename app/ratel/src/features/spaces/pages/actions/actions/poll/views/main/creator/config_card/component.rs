@@ -27,6 +27,7 @@ pub fn ConfigCard() -> Element {
     let saved_credits = poll.space_action.credits;
     let action_status = poll.space_action.status.clone();
     let initial_depends_on = poll.space_action.depends_on.clone();
+    let initial_dependency_conditions = poll.space_action.dependency_conditions.clone();
     let initial_status = poll.space_action.status.clone();
 
     let action_id_for_signal = action_id_str.clone();
@@ -91,6 +92,7 @@ pub fn ConfigCard() -> Element {
                         space_id,
                         action_id: action_id_signal,
                         initial_depends_on,
+                        initial_conditions: initial_dependency_conditions,
                     }
                 }
 
@@ -70,6 +70,13 @@ pub async fn get_quiz(
         include_correct_answers = has_passed || retries_used_up || is_ended;
     }
 
+    // A pool's other questions are for other attempts and cohorts.
+    let is_pool = response.question_count < response.questions.len();
+    if is_pool && role != SpaceUserRole::Creator && !include_correct_answers {
+        response.questions.clear();
+        response.my_response = None;
+    }
+
     if include_correct_answers {
         let answer_sk = EntityType::SpaceQuizAnswer(quiz_id.to_string());
         if let Some(answer) =
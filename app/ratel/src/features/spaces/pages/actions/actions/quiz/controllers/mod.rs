@@ -3,6 +3,7 @@ pub mod get_quiz;
 pub mod get_quiz_answer;
 pub mod remove_quiz_file;
 pub mod respond_quiz;
+pub mod start_quiz_attempt;
 pub mod update_quiz;
 
 pub use create_quiz::*;
@@ -10,4 +11,5 @@ pub use get_quiz::*;
 pub use get_quiz_answer::*;
 pub use remove_quiz_file::*;
 pub use respond_quiz::*;
+pub use start_quiz_attempt::*;
 pub use update_quiz::*;
@@ -23,7 +23,7 @@ pub async fn respond_quiz(
     space_pk: SpacePartition,
     #[mcp(description = "Quiz sort key (e.g. 'SpaceQuiz#<uuid>')")]
     quiz_id: SpaceQuizEntityType,
-    #[mcp(description = "Quiz answers. Each answer: {\"answer_type\": \"single_choice\", \"answer\": <index>} or {\"answer_type\": \"multiple_choice\", \"answer\": [<indices>]}")]
+    #[mcp(description = "Quiz answers, in the question and option order returned by start_quiz_attempt. Each answer: {\"answer_type\": \"single_choice\", \"answer\": <index>} or {\"answer_type\": \"multiple_choice\", \"answer\": [<indices>]}")]
     req: RespondQuizRequest,
 ) -> Result<()> {
     let common_config = crate::common::CommonConfig::default();
@@ -31,8 +31,83 @@ pub async fn respond_quiz(
     let space_id = space_pk;
     let space_pk: Partition = space_id.clone().into();
     let quiz_sk: EntityType = quiz_id.clone().into();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
 
-    let quiz = SpaceQuiz::get(cli, &space_pk, Some(quiz_sk.clone()))
+    let (quiz, attempts) =
+        load_attemptable_quiz(cli, &space, &space_id, &quiz_id, role, &member.pk).await?;
+
+    let answer_sk = EntityType::SpaceQuizAnswer(quiz_id.to_string());
+    let correct = SpaceQuizAnswer::get(cli, &space_pk, Some(answer_sk))
+        .await?
+        .ok_or(Error::NotFound("Quiz answer not found".into()))?;
+
+    let (session_pk, session_sk) = SpaceQuizSession::keys(&member.pk, &quiz_id);
+    let session = SpaceQuizSession::get(cli, &session_pk, Some(session_sk.clone())).await?;
+    let drawn = match &session {
+        Some(session) if session.is_expired(now) => {
+            forfeit_session(cli, &space_id, &quiz_id, &quiz, &member, session).await?;
+            return Err(SpaceActionQuizError::AttemptExpired.into());
+        }
+        Some(session) => session.questions.clone(),
+        None if quiz.needs_session() => {
+            return Err(SpaceActionQuizError::AttemptNotStarted.into());
+        }
+        // Untimed quiz without draw or shuffle: every question, as stored.
+        None => draw_questions(&quiz.questions, 0, false, &mut rand::rng()),
+    };
+
+    let (answers, results) = grade_attempt(&quiz, &correct.answers, &drawn, &req.answers)?;
+    if session.is_some() {
+        // Conditional delete: of two racing submits, only one consumes
+        // the session.
+        SpaceQuizSession::delete(cli, session_pk, Some(session_sk))
+            .await
+            .map_err(|_| SpaceActionQuizError::AttemptNotStarted)?;
+    }
+    let attempt = SpaceQuizAttempt::new(
+        space_id.clone(),
+        quiz_id.clone(),
+        member.clone(),
+        answers,
+        results.clone(),
+        quiz.pass_score,
+    );
+    attempt.create(cli).await?;
+    SpaceQuizQuestionStat::record(cli, &space_pk, &quiz_id, &results).await;
+
+    if attempts.is_empty() {
+        SpaceQuiz::updater(&space_pk, &quiz_sk)
+            .increase_user_response_count(1)
+            .execute(cli)
+            .await?;
+    }
+
+    // Reward payout + XP recording run on EventBridge via SPACE_QUIZ_ATTEMPT#
+    // INSERT → handle_quiz_xp. See features/activity/services/handle_xp_event.rs.
+
+    crate::features::spaces::space_common::services::bump_participant_activity(
+        cli, &space_pk, &member.pk,
+    )
+    .await;
+
+    Ok(())
+}
+
+/// Load a quiz the member may attempt right now, with their previous
+/// attempts (newest first). Shared by `start_quiz_attempt`.
+#[cfg(feature = "server")]
+pub(super) async fn load_attemptable_quiz(
+    cli: &aws_sdk_dynamodb::Client,
+    space: &SpaceCommon,
+    space_id: &SpacePartition,
+    quiz_id: &SpaceQuizEntityType,
+    role: SpaceUserRole,
+    member_pk: &Partition,
+) -> Result<(SpaceQuiz, Vec<SpaceQuizAttempt>)> {
+    let space_pk: Partition = space_id.clone().into();
+    let quiz_sk: EntityType = quiz_id.clone().into();
+
+    let quiz = SpaceQuiz::get(cli, &space_pk, Some(quiz_sk))
         .await?
         .ok_or(Error::NotFound("Quiz not found".into()))?;
 
@@ -46,9 +121,9 @@ pub async fn respond_quiz(
 
     let deps_met = crate::features::spaces::pages::actions::services::dependency::dependencies_met(
         cli,
-        &space,
+        space,
         &space_action,
-        &member.pk,
+        member_pk,
     )
     .await?;
 
@@ -63,87 +138,97 @@ pub async fn respond_quiz(
         return Err(SpaceActionQuizError::NotAvailableInCurrentStatus.into());
     }
 
-    let answer_sk = EntityType::SpaceQuizAnswer(quiz_id.to_string());
-    let correct = SpaceQuizAnswer::get(cli, &space_pk, Some(answer_sk))
-        .await?
-        .ok_or(Error::NotFound("Quiz answer not found".into()))?;
-
-    if !crate::features::spaces::pages::actions::actions::poll::types::validate_answers(
-        quiz.questions.clone(),
-        req.answers.clone(),
-    ) {
-        return Err(SpaceActionQuizError::AnswersMismatch.into());
-    }
-
     let total_allowed = quiz.retry_count.saturating_add(1).min(MAX_TOTAL_ATTEMPTS);
     let limit: i32 = total_allowed as i32;
-    let attempts = SpaceQuizAttempt::list_by_quiz_user(cli, &quiz_id, &member.pk, limit).await?;
+    let attempts = SpaceQuizAttempt::list_by_quiz_user(cli, quiz_id, member_pk, limit).await?;
     if attempts.len() as i64 >= total_allowed {
         return Err(SpaceActionQuizError::NoRemainingAttempts.into());
     }
-    let score = calculate_score(&quiz.questions, &correct.answers, &req.answers)?;
-    let attempt = SpaceQuizAttempt::new(space_id.clone(), quiz_id.clone(), member.clone(), req.answers, score, quiz.pass_score);
-    attempt.create(cli).await?;
 
-    if attempts.is_empty() {
-        SpaceQuiz::updater(&space_pk, &quiz_sk)
-            .increase_user_response_count(1)
-            .execute(cli)
-            .await?;
-    }
-
-    // Reward payout + XP recording run on EventBridge via SPACE_QUIZ_ATTEMPT#
-    // INSERT → handle_quiz_xp. See features/activity/services/handle_xp_event.rs.
-    let _ = attempts; // kept above for aggregate side-effects only
-    let _ = score;
+    Ok((quiz, attempts))
+}
 
-    crate::features::spaces::space_common::services::bump_participant_activity(
-        cli, &space_pk, &member.pk,
-    )
-    .await;
+/// Close a timed-out session as a zero-credit attempt, so letting the
+/// clock run out cannot be used to preview questions for free.
+#[cfg(feature = "server")]
+pub(super) async fn forfeit_session(
+    cli: &aws_sdk_dynamodb::Client,
+    space_id: &SpacePartition,
+    quiz_id: &SpaceQuizEntityType,
+    quiz: &SpaceQuiz,
+    member: &SpaceUser,
+    session: &SpaceQuizSession,
+) -> Result<()> {
+    let answers = quiz.questions.iter().map(empty_answer).collect();
+    let results: Vec<QuizQuestionResult> = session
+        .questions
+        .iter()
+        .map(|drawn| QuizQuestionResult {
+            question_index: drawn.question_index,
+            credit: 0,
+        })
+        .collect();
+    let attempt = SpaceQuizAttempt::new(
+        space_id.clone(),
+        quiz_id.clone(),
+        member.clone(),
+        answers,
+        results.clone(),
+        quiz.pass_score,
+    );
+    attempt.create(cli).await?;
+    SpaceQuizSession::delete(cli, session.pk.clone(), Some(session.sk.clone())).await?;
 
+    let space_pk: Partition = space_id.clone().into();
+    SpaceQuizQuestionStat::record(cli, &space_pk, quiz_id, &results).await;
     Ok(())
 }
 
-fn calculate_score(
-    questions: &[Question],
+/// Grade answers given in presentation order. Returns the answers in
+/// bank order with original option indices, and the credit per drawn
+/// question.
+pub fn grade_attempt(
+    quiz: &SpaceQuiz,
     correct: &[QuizCorrectAnswer],
+    drawn: &[QuizDrawnQuestion],
     answers: &[Answer],
-) -> Result<i64> {
-    if questions.len() != correct.len() || questions.len() != answers.len() {
+) -> Result<(Vec<Answer>, Vec<QuizQuestionResult>)> {
+    if quiz.questions.len() != correct.len() || drawn.len() != answers.len() {
         return Err(SpaceActionQuizError::AnswersMismatch.into());
     }
 
-    let mut score = 0;
-    for ((question, correct), answer) in questions.iter().zip(correct).zip(answers) {
-        let is_correct = match (question, correct, answer) {
-            (
-                Question::SingleChoice(_),
-                QuizCorrectAnswer::Single { answer: expected },
-                Answer::SingleChoice { answer: actual, .. },
-            ) => expected.is_some() && expected == actual,
-            (
-                Question::MultipleChoice(_),
-                QuizCorrectAnswer::Multiple { answers: expected },
-                Answer::MultipleChoice { answer: actual, .. },
-            ) => {
-                let mut expected = expected.clone();
-                expected.sort_unstable();
-                expected.dedup();
-
-                let mut actual = actual.clone().unwrap_or_default();
-                actual.sort_unstable();
-                actual.dedup();
-
-                expected == actual && !expected.is_empty()
-            }
-            _ => false,
-        };
-
-        if is_correct {
-            score += 1;
-        }
+    let presented: Vec<Question> = drawn
+        .iter()
+        .filter_map(|d| {
+            let question = quiz.questions.get(d.question_index)?;
+            Some(present_question(question, &d.option_order))
+        })
+        .collect();
+    if presented.len() != drawn.len()
+        || !crate::features::spaces::pages::actions::actions::poll::types::validate_answers(
+            presented,
+            answers.to_vec(),
+        )
+    {
+        return Err(SpaceActionQuizError::AnswersMismatch.into());
+    }
+
+    let mut bank_answers: Vec<Answer> = quiz.questions.iter().map(empty_answer).collect();
+    let mut results = Vec::with_capacity(drawn.len());
+    for (d, answer) in drawn.iter().zip(answers) {
+        let restored = restore_answer(answer, &d.option_order);
+        let credit = grade_answer(
+            &quiz.questions[d.question_index],
+            &correct[d.question_index],
+            &restored,
+            quiz.partial_credit,
+        );
+        bank_answers[d.question_index] = restored;
+        results.push(QuizQuestionResult {
+            question_index: d.question_index,
+            credit,
+        });
     }
 
-    Ok(score)
+    Ok((bank_answers, results))
 }
@@ -0,0 +1,77 @@
+use crate::common::models::space::{SpaceCommon, SpaceUser};
+use crate::features::spaces::pages::actions::actions::quiz::*;
+#[cfg(feature = "server")]
+use super::respond_quiz::{forfeit_session, load_attemptable_quiz};
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct QuizAttemptResponse {
+    /// Questions for this attempt, options in presentation order. Answer
+    /// them in this order.
+    pub questions: Vec<Question>,
+    pub started_at: i64,
+    /// Submission deadline; `None` when the quiz is untimed.
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+}
+
+#[mcp_tool(
+    name = "start_quiz_attempt",
+    description = "Start (or resume) a quiz attempt. Returns the questions drawn for this attempt, with options in the order respond_quiz expects, and the deadline if the quiz is timed. Requires participant role."
+)]
+#[post(
+    "/api/spaces/{space_pk}/quizzes/{quiz_id}/start",
+    role: SpaceUserRole,
+    member: SpaceUser,
+    space: SpaceCommon
+)]
+pub async fn start_quiz_attempt(
+    #[mcp(description = "Space partition key")] space_pk: SpacePartition,
+    #[mcp(description = "Quiz sort key (e.g. 'SpaceQuiz#<uuid>')")] quiz_id: SpaceQuizEntityType,
+) -> Result<QuizAttemptResponse> {
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+    let space_id = space_pk;
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+
+    let (quiz, attempts) =
+        load_attemptable_quiz(cli, &space, &space_id, &quiz_id, role, &member.pk).await?;
+
+    let (session_pk, session_sk) = SpaceQuizSession::keys(&member.pk, &quiz_id);
+    let existing = SpaceQuizSession::get(cli, &session_pk, Some(session_sk)).await?;
+    let session = match existing {
+        // Resuming keeps the draw and the clock; restarting would let a
+        // participant re-roll questions or reset the timer.
+        Some(session) if !session.is_expired(now) => session,
+        expired => {
+            if let Some(expired) = expired {
+                forfeit_session(cli, &space_id, &quiz_id, &quiz, &member, &expired).await?;
+                let total_allowed = quiz.retry_count.saturating_add(1).min(MAX_TOTAL_ATTEMPTS);
+                if attempts.len() as i64 + 1 >= total_allowed {
+                    return Err(SpaceActionQuizError::NoRemainingAttempts.into());
+                }
+            }
+            let session = SpaceQuizSession::new(&member.pk, &quiz_id, &quiz);
+            session.upsert(cli).await?;
+            session
+        }
+    };
+
+    let questions = session
+        .questions
+        .iter()
+        .filter_map(|d| {
+            let question = quiz.questions.get(d.question_index)?;
+            Some(present_question(question, &d.option_order))
+        })
+        .collect();
+
+    Ok(QuizAttemptResponse {
+        questions,
+        started_at: session.started_at,
+        expires_at: session.expires_at,
+    })
+}
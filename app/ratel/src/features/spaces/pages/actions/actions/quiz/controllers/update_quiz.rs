@@ -20,18 +20,28 @@ pub struct UpdateQuizRequest {
     pub answers: Option<Vec<QuizCorrectAnswer>>,
     #[serde(default)]
     pub files: Option<Vec<File>>,
+    /// Questions drawn per attempt; 0 presents the whole bank.
+    #[serde(default)]
+    pub draw_count: Option<i64>,
+    #[serde(default)]
+    pub shuffle_options: Option<bool>,
+    /// 0 removes the time limit.
+    #[serde(default)]
+    pub time_limit_seconds: Option<i64>,
+    #[serde(default)]
+    pub partial_credit: Option<bool>,
 }
 
 #[mcp_tool(
     name = "update_quiz",
-    description = "Update a quiz (title, description, questions, answers, pass_score, retry_count, files). Requires creator role."
+    description = "Update a quiz (title, description, questions, answers, pass_score, retry_count, files, draw_count, shuffle_options, time_limit_seconds, partial_credit). Requires creator role."
 )]
 #[post("/api/spaces/{space_pk}/quizzes/{quiz_id}", role: SpaceUserRole)]
 pub async fn update_quiz(
     #[mcp(description = "Space partition key")] space_pk: SpacePartition,
     #[mcp(description = "Quiz sort key (e.g. 'SpaceQuiz#<uuid>')")] quiz_id: SpaceQuizEntityType,
     #[mcp(
-        description = "Quiz update data as JSON. Fields: title, description, retry_count, pass_score, questions, answers, files (all optional)"
+        description = "Quiz update data as JSON. Fields: title, description, retry_count, pass_score, questions, answers, files, draw_count (questions per attempt, 0 = all), shuffle_options, time_limit_seconds (0 = untimed), partial_credit (all optional)"
     )]
     req: UpdateQuizRequest,
 ) -> Result<String> {
@@ -99,6 +109,35 @@ pub async fn update_quiz(
         .as_ref()
         .unwrap_or(&existing.questions);
 
+    let question_count = questions_for_validation.len() as i64;
+    let mut draw_count = existing.draw_count;
+    if let Some(requested) = req.draw_count {
+        if !(0..=question_count).contains(&requested) {
+            return Err(SpaceActionQuizError::InvalidDrawCount.into());
+        }
+        draw_count = requested;
+        updater = updater.with_draw_count(draw_count);
+    } else if existing.draw_count > question_count {
+        // The bank shrank below the draw; keep presenting all of it.
+        draw_count = question_count;
+        updater = updater.with_draw_count(draw_count);
+    }
+
+    if let Some(time_limit_seconds) = req.time_limit_seconds {
+        if !(0..=MAX_QUIZ_TIME_LIMIT_SECONDS).contains(&time_limit_seconds) {
+            return Err(SpaceActionQuizError::InvalidTimeLimit.into());
+        }
+        updater = updater.with_time_limit_seconds(time_limit_seconds);
+    }
+
+    if let Some(shuffle_options) = req.shuffle_options {
+        updater = updater.with_shuffle_options(shuffle_options);
+    }
+
+    if let Some(partial_credit) = req.partial_credit {
+        updater = updater.with_partial_credit(partial_credit);
+    }
+
     if let Some(pass_score) = req.pass_score {
         if pass_score < 0 {
             return Err(SpaceActionQuizError::InvalidPassScore.into());
@@ -106,6 +145,21 @@ pub async fn update_quiz(
         updater = updater.with_pass_score(pass_score);
     }
 
+    // A score is at most one point per presented question, so a pass
+    // score above the draw can never be met. Re-checked whenever the
+    // score, the draw or the bank moves.
+    let presented_count = if draw_count > 0 {
+        draw_count.min(question_count)
+    } else {
+        question_count
+    };
+    let pass_score = req.pass_score.unwrap_or(existing.pass_score);
+    let reshapes_attempt =
+        req.pass_score.is_some() || req.draw_count.is_some() || questions_for_answers.is_some();
+    if reshapes_attempt && pass_score > presented_count {
+        return Err(SpaceActionQuizError::PassScoreExceedsQuestions.into());
+    }
+
     if let Some(mut files) = req.files {
         for file in &mut files {
             if file.id.is_empty() {
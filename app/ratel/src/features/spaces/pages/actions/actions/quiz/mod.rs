@@ -22,3 +22,9 @@ use crate::*;
 /// Maximum total attempts allowed (1 initial attempt + retries).
 /// Enforced server-side to prevent unbounded DynamoDB queries and abuse.
 pub const MAX_TOTAL_ATTEMPTS: i64 = 100;
+
+/// Longest time limit a creator may set on a quiz attempt (24 hours).
+pub const MAX_QUIZ_TIME_LIMIT_SECONDS: i64 = 24 * 60 * 60;
+
+/// Slack past a timed attempt's deadline for the submit request to land.
+pub const QUIZ_SUBMIT_GRACE_MS: i64 = 5_000;
@@ -1,7 +1,11 @@
 mod space_quiz;
 mod space_quiz_answer;
 mod space_quiz_attempt;
+mod space_quiz_question_stat;
+mod space_quiz_session;
 
 pub use space_quiz::*;
 pub use space_quiz_answer::*;
 pub use space_quiz_attempt::*;
+pub use space_quiz_question_stat::*;
+pub use space_quiz_session::*;
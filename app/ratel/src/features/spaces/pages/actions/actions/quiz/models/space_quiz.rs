@@ -22,10 +22,23 @@ pub struct SpaceQuiz {
     #[serde(default)]
     pub pass_score: i64,
 
+    /// Question bank. Each attempt presents `draw_count` of them.
     #[serde(default)]
     pub questions: Vec<Question>,
     #[serde(default)]
     pub files: Vec<File>,
+
+    /// Questions drawn per attempt; 0 presents the whole bank.
+    #[serde(default)]
+    pub draw_count: i64,
+    #[serde(default)]
+    pub shuffle_options: bool,
+    /// 0 means untimed.
+    #[serde(default)]
+    pub time_limit_seconds: i64,
+    /// Multiple-answer questions earn credit for a partly correct pick.
+    #[serde(default)]
+    pub partial_credit: bool,
 }
 
 #[cfg(feature = "server")]
@@ -47,9 +60,34 @@ impl SpaceQuiz {
             pass_score: 0,
             questions: vec![],
             files: vec![],
+            draw_count: 0,
+            shuffle_options: false,
+            time_limit_seconds: 0,
+            partial_credit: false,
         })
     }
 
+    /// Questions one attempt presents.
+    pub fn presented_count(&self) -> usize {
+        match usize::try_from(self.draw_count) {
+            Ok(draw) if draw > 0 => draw.min(self.questions.len()),
+            _ => self.questions.len(),
+        }
+    }
+
+    /// Whether attempts must be started through `start_quiz_attempt` —
+    /// i.e. the server has to remember a draw, an option order or a
+    /// deadline between start and submit.
+    pub fn needs_session(&self) -> bool {
+        self.presented_count() < self.questions.len()
+            || self.shuffle_options
+            || self.time_limit_seconds > 0
+    }
+
+    pub fn time_limit_ms(&self) -> Option<i64> {
+        (self.time_limit_seconds > 0).then(|| self.time_limit_seconds * 1000)
+    }
+
     pub fn can_edit(
         user_role: &SpaceUserRole,
     ) -> crate::features::spaces::pages::actions::actions::quiz::Result<()> {
@@ -17,8 +17,14 @@ pub struct SpaceQuizAttempt {
     #[dynamo(prefix = "QUIZ_USER", index = "gsi1", name = "find_by_quiz_user", pk)]
     pub quiz_user: String, // {user_pk}#{quiz_id}
 
+    /// In bank order with original option indices; questions not drawn
+    /// for this attempt hold an empty answer.
     pub answers: Vec<Answer>,
     pub score: i64,
+    /// Credit per presented question. Empty on attempts stored before
+    /// question pools existed.
+    #[serde(default)]
+    pub results: Vec<QuizQuestionResult>,
     #[serde(default)]
     pub user_pk: Option<Partition>,
     #[serde(default)]
@@ -40,7 +46,7 @@ impl SpaceQuizAttempt {
         quiz_id: SpaceQuizEntityType,
         author: crate::common::models::space::SpaceUser,
         answers: Vec<Answer>,
-        score: i64,
+        results: Vec<QuizQuestionResult>,
         pass_threshold: i64,
     ) -> Self {
         let created_at = get_now_timestamp_millis();
@@ -55,7 +61,8 @@ impl SpaceQuizAttempt {
             created_at,
             quiz_user,
             answers,
-            score,
+            score: score_from_results(&results),
+            results,
             user_pk: Some(user_pk),
             display_name: Some(author.display_name),
             profile_url: Some(author.profile_url),
@@ -65,6 +72,14 @@ impl SpaceQuizAttempt {
         }
     }
 
+    /// Every presented question earned full credit.
+    pub fn is_perfect(&self) -> bool {
+        if self.results.is_empty() {
+            return !self.answers.is_empty() && self.score >= self.answers.len() as i64;
+        }
+        self.results.iter().all(|r| r.credit >= FULL_CREDIT)
+    }
+
     pub fn quiz_user_key(quiz_id: &SpaceQuizEntityType, user_pk: &Partition) -> String {
         format!("{user_pk}#{quiz_id}")
     }
@@ -0,0 +1,87 @@
+use crate::features::spaces::pages::actions::actions::quiz::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Running totals for one bank question across every submitted attempt.
+/// Keyed by question index, so reordering the bank reassigns history.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct SpaceQuizQuestionStat {
+    pub pk: Partition,  // Partition::Space(space_id)
+    pub sk: EntityType, // SpaceQuizQuestionStat#{quiz_id}:{question_index}
+
+    pub question_index: i64,
+    /// Attempts the question was presented in.
+    #[serde(default)]
+    pub attempt_count: i64,
+    /// Attempts that earned full credit.
+    #[serde(default)]
+    pub correct_count: i64,
+    /// Sum of per-attempt credit, in percent.
+    #[serde(default)]
+    pub credit_total: i64,
+    pub updated_at: i64,
+}
+
+#[cfg(feature = "server")]
+impl SpaceQuizQuestionStat {
+    pub fn keys(
+        space_pk: &Partition,
+        quiz_id: &SpaceQuizEntityType,
+        question_index: usize,
+    ) -> (Partition, EntityType) {
+        (
+            space_pk.clone(),
+            EntityType::SpaceQuizQuestionStat(format!("{quiz_id}:{question_index}")),
+        )
+    }
+
+    pub async fn list_by_quiz(
+        cli: &aws_sdk_dynamodb::Client,
+        space_pk: &Partition,
+        quiz_id: &SpaceQuizEntityType,
+    ) -> crate::features::spaces::pages::actions::actions::quiz::Result<Vec<Self>> {
+        let prefix = EntityType::SpaceQuizQuestionStat(format!("{quiz_id}:")).to_string();
+        let mut stats = Vec::new();
+        let mut bookmark: Option<String> = None;
+        loop {
+            let opt = Self::opt_with_bookmark(bookmark.take())
+                .sk(prefix.clone())
+                .limit(100);
+            let (rows, next) = Self::query(cli, space_pk.clone(), opt).await?;
+            stats.extend(rows);
+            match next {
+                Some(bk) => bookmark = Some(bk),
+                None => break,
+            }
+        }
+        stats.sort_by_key(|s| s.question_index);
+        Ok(stats)
+    }
+
+    /// Fold one attempt's results into the running totals. Best-effort:
+    /// a lost increment only skews the stats.
+    pub async fn record(
+        cli: &aws_sdk_dynamodb::Client,
+        space_pk: &Partition,
+        quiz_id: &SpaceQuizEntityType,
+        results: &[QuizQuestionResult],
+    ) {
+        let now = crate::common::utils::time::get_now_timestamp_millis();
+        for result in results {
+            let (pk, sk) = Self::keys(space_pk, quiz_id, result.question_index);
+            let res = Self::updater(&pk, &sk)
+                .with_question_index(result.question_index as i64)
+                .with_updated_at(now)
+                .increase_attempt_count(1)
+                .increase_correct_count((result.credit >= FULL_CREDIT) as i64)
+                .increase_credit_total(result.credit as i64)
+                .execute(cli)
+                .await;
+            if let Err(e) = res {
+                tracing::warn!(quiz_id = %quiz_id, error = %e, "quiz question stat update failed");
+            }
+        }
+    }
+}
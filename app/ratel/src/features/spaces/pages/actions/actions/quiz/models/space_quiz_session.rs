@@ -0,0 +1,59 @@
+use crate::common::utils::time::get_now_timestamp_millis;
+
+use crate::features::spaces::pages::actions::actions::quiz::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// An attempt in progress: which questions were drawn, in what option
+/// order, and when it must be submitted. One per user and quiz; deleted
+/// when the attempt is submitted.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, DynamoEntity)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct SpaceQuizSession {
+    pub pk: Partition,  // Partition::SpaceQuizAttempt(user_pk)
+    pub sk: EntityType, // SpaceQuizSession#{quiz_id}
+
+    pub started_at: i64,
+    /// `None` when the quiz has no time limit.
+    #[serde(default)]
+    pub expires_at: Option<i64>,
+    #[serde(default)]
+    pub questions: Vec<QuizDrawnQuestion>,
+}
+
+#[cfg(feature = "server")]
+impl SpaceQuizSession {
+    pub fn new(user_pk: &Partition, quiz_id: &SpaceQuizEntityType, quiz: &SpaceQuiz) -> Self {
+        let started_at = get_now_timestamp_millis();
+        let (pk, sk) = Self::keys(user_pk, quiz_id);
+        let questions = draw_questions(
+            &quiz.questions,
+            quiz.draw_count,
+            quiz.shuffle_options,
+            &mut rand::rng(),
+        );
+
+        Self {
+            pk,
+            sk,
+            started_at,
+            expires_at: quiz.time_limit_ms().map(|limit| started_at + limit),
+            questions,
+        }
+    }
+
+    pub fn keys(user_pk: &Partition, quiz_id: &SpaceQuizEntityType) -> (Partition, EntityType) {
+        (
+            Partition::SpaceQuizAttempt(user_pk.to_string()),
+            EntityType::SpaceQuizSession(quiz_id.to_string()),
+        )
+    }
+
+    /// Whether a submission at `now` is too late. Allows
+    /// `QUIZ_SUBMIT_GRACE_MS` for the request to reach the server.
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| now > expires_at + QUIZ_SUBMIT_GRACE_MS)
+    }
+}
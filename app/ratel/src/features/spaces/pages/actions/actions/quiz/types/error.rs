@@ -77,6 +77,13 @@ pub enum SpaceActionQuizError {
     )]
     InvalidPassScore,
 
+    #[error("Pass score exceeds the questions per attempt")]
+    #[translate(
+        en = "Pass score cannot exceed the number of questions per attempt",
+        ko = "합격 점수는 시도당 문항 수를 넘을 수 없습니다."
+    )]
+    PassScoreExceedsQuestions,
+
     #[error("Answers length mismatch")]
     #[translate(
         en = "Number of answers does not match number of questions",
@@ -105,6 +112,34 @@ pub enum SpaceActionQuizError {
     )]
     AnswerTypeMismatch,
 
+    #[error("Draw count must be between 0 and the number of questions")]
+    #[translate(
+        en = "Questions per attempt must be between 0 and the number of questions",
+        ko = "시도당 문항 수는 0 이상, 전체 문항 수 이하여야 합니다."
+    )]
+    InvalidDrawCount,
+
+    #[error("Time limit out of range")]
+    #[translate(
+        en = "Time limit must be between 0 seconds and 24 hours",
+        ko = "제한 시간은 0초 이상 24시간 이하여야 합니다."
+    )]
+    InvalidTimeLimit,
+
+    #[error("Quiz attempt not started")]
+    #[translate(
+        en = "Start the quiz before submitting answers",
+        ko = "답변을 제출하기 전에 퀴즈를 시작해야 합니다."
+    )]
+    AttemptNotStarted,
+
+    #[error("Quiz attempt time limit exceeded")]
+    #[translate(
+        en = "The time limit for this attempt has passed",
+        ko = "이번 시도의 제한 시간이 지났습니다."
+    )]
+    AttemptExpired,
+
     #[error("Quiz not found")]
     #[translate(en = "Quiz not found", ko = "퀴즈를 찾을 수 없습니다.")]
     NotFound,
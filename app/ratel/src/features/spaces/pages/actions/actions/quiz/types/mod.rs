@@ -1,10 +1,12 @@
 mod correct_answer;
 mod error;
 mod quiz_answer_response;
+mod quiz_grading;
 mod quiz_response;
 mod quiz_status;
 pub use correct_answer::*;
 pub use error::*;
 pub use quiz_answer_response::*;
+pub use quiz_grading::*;
 pub use quiz_response::*;
 pub use quiz_status::*;
@@ -0,0 +1,183 @@
+//! Per-attempt question draw, option shuffling and grading. Attempts are
+//! stored in bank order with original option indices, so everything
+//! downstream (analyzes, badges, XP) reads them the same way whether or
+//! not the quiz draws or shuffles.
+
+use crate::features::spaces::pages::actions::actions::quiz::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Full credit for one question, in percent.
+pub const FULL_CREDIT: u32 = 100;
+
+/// One question as presented in an attempt.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct QuizDrawnQuestion {
+    /// Index into `SpaceQuiz::questions`.
+    pub question_index: usize,
+    /// `option_order[k]` is the original index of the option shown at `k`.
+    #[serde(default)]
+    pub option_order: Vec<i32>,
+}
+
+/// Credit one attempt earned on one bank question.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct QuizQuestionResult {
+    pub question_index: usize,
+    /// 0..=`FULL_CREDIT`.
+    pub credit: u32,
+}
+
+/// Pick the questions (and option orders) for one attempt: `draw_count`
+/// random questions from the bank, or the whole bank in order when it is
+/// zero or covers every question.
+pub fn draw_questions<R: rand::Rng + ?Sized>(
+    questions: &[Question],
+    draw_count: i64,
+    shuffle_options: bool,
+    rng: &mut R,
+) -> Vec<QuizDrawnQuestion> {
+    use rand::seq::SliceRandom;
+
+    let mut indices: Vec<usize> = (0..questions.len()).collect();
+    let draw = usize::try_from(draw_count).unwrap_or(0);
+    if draw > 0 && draw < questions.len() {
+        indices.shuffle(rng);
+        indices.truncate(draw);
+    }
+
+    indices
+        .into_iter()
+        .map(|question_index| {
+            let mut option_order: Vec<i32> =
+                (0..option_count(&questions[question_index]) as i32).collect();
+            if shuffle_options {
+                option_order.shuffle(rng);
+            }
+            QuizDrawnQuestion {
+                question_index,
+                option_order,
+            }
+        })
+        .collect()
+}
+
+fn option_count(question: &Question) -> usize {
+    match question {
+        Question::SingleChoice(q) | Question::MultipleChoice(q) => q.options.len(),
+        _ => 0,
+    }
+}
+
+/// `question` with its options in presentation order.
+pub fn present_question(question: &Question, option_order: &[i32]) -> Question {
+    let reorder = |q: &ChoiceQuestion| {
+        let mut q = q.clone();
+        if option_order.len() == q.options.len() {
+            q.options = option_order
+                .iter()
+                .filter_map(|i| q.options.get(*i as usize).cloned())
+                .collect();
+        }
+        q
+    };
+    match question {
+        Question::SingleChoice(q) => Question::SingleChoice(reorder(q)),
+        Question::MultipleChoice(q) => Question::MultipleChoice(reorder(q)),
+        other => other.clone(),
+    }
+}
+
+/// Map an answer given against presented options back to original
+/// option indices. Out-of-range picks are dropped.
+pub fn restore_answer(answer: &Answer, option_order: &[i32]) -> Answer {
+    let original = |i: &i32| option_order.get(usize::try_from(*i).ok()?).copied();
+    match answer {
+        Answer::SingleChoice { answer, other } => Answer::SingleChoice {
+            answer: answer.as_ref().and_then(original),
+            other: other.clone(),
+        },
+        Answer::MultipleChoice { answer, other } => Answer::MultipleChoice {
+            answer: answer
+                .as_ref()
+                .map(|picks| picks.iter().filter_map(original).collect()),
+            other: other.clone(),
+        },
+        other => other.clone(),
+    }
+}
+
+/// An unanswered response of the right shape for `question`.
+pub fn empty_answer(question: &Question) -> Answer {
+    match question {
+        Question::SingleChoice(_) => Answer::SingleChoice {
+            answer: None,
+            other: None,
+        },
+        Question::MultipleChoice(_) => Answer::MultipleChoice {
+            answer: Some(vec![]),
+            other: None,
+        },
+        Question::ShortAnswer(_) => Answer::ShortAnswer { answer: None },
+        Question::Subjective(_) => Answer::Subjective { answer: None },
+        Question::Checkbox(_) => Answer::Checkbox {
+            answer: Some(vec![]),
+        },
+        Question::Dropdown(_) => Answer::Dropdown { answer: None },
+        Question::LinearScale(_) => Answer::LinearScale { answer: None },
+    }
+}
+
+/// Credit for one answer in original option indices. Multiple-answer
+/// questions with `partial_credit` earn the share of correct options
+/// picked, less one share per wrong pick, floored at zero — so ticking
+/// every box never pays.
+pub fn grade_answer(
+    question: &Question,
+    correct: &QuizCorrectAnswer,
+    answer: &Answer,
+    partial_credit: bool,
+) -> u32 {
+    match (question, correct, answer) {
+        (
+            Question::SingleChoice(_),
+            QuizCorrectAnswer::Single { answer: expected },
+            Answer::SingleChoice { answer: actual, .. },
+        ) if expected.is_some() && expected == actual => FULL_CREDIT,
+        (
+            Question::MultipleChoice(_),
+            QuizCorrectAnswer::Multiple { answers: expected },
+            Answer::MultipleChoice { answer: actual, .. },
+        ) => {
+            let mut expected = expected.clone();
+            expected.sort_unstable();
+            expected.dedup();
+            let mut actual = actual.clone().unwrap_or_default();
+            actual.sort_unstable();
+            actual.dedup();
+
+            if expected.is_empty() {
+                0
+            } else if expected == actual {
+                FULL_CREDIT
+            } else if partial_credit {
+                let hits = actual.iter().filter(|i| expected.contains(i)).count();
+                let misses = actual.len() - hits;
+                let net = hits.saturating_sub(misses) as u32;
+                net * FULL_CREDIT / expected.len() as u32
+            } else {
+                0
+            }
+        }
+        _ => 0,
+    }
+}
+
+/// Attempt score from per-question credit: one point per fully correct
+/// question, with partial credit adding up (two halves make a point).
+pub fn score_from_results(results: &[QuizQuestionResult]) -> i64 {
+    results.iter().map(|r| r.credit as i64).sum::<i64>() / FULL_CREDIT as i64
+}
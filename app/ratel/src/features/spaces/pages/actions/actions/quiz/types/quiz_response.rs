@@ -10,9 +10,26 @@ pub struct QuizResponse {
     pub title: String,
     pub description: String,
     pub user_response_count: i64,
+    /// The whole bank. Empty for participants of a pooled quiz until they
+    /// are done with it — attempts get their questions from
+    /// `start_quiz_attempt`.
     pub questions: Vec<Question>,
     #[serde(default)]
     pub files: Vec<File>,
+    /// Questions one attempt presents.
+    #[serde(default)]
+    pub question_count: usize,
+    #[serde(default)]
+    pub draw_count: i64,
+    #[serde(default)]
+    pub shuffle_options: bool,
+    #[serde(default)]
+    pub time_limit_seconds: i64,
+    #[serde(default)]
+    pub partial_credit: bool,
+    /// Whether attempts go through `start_quiz_attempt`.
+    #[serde(default)]
+    pub needs_session: bool,
     #[serde(default)]
     pub my_response: Option<Vec<Answer>>,
     #[serde(default)]
@@ -45,6 +62,12 @@ impl From<SpaceQuiz> for QuizResponse {
             title: String::new(),
             description: String::new(),
             user_response_count: quiz.user_response_count,
+            question_count: quiz.presented_count(),
+            draw_count: quiz.draw_count,
+            shuffle_options: quiz.shuffle_options,
+            time_limit_seconds: quiz.time_limit_seconds,
+            partial_credit: quiz.partial_credit,
+            needs_session: quiz.needs_session(),
             questions: quiz.questions,
             files: quiz.files,
             my_response: None,
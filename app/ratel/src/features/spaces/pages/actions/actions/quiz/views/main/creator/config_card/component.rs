@@ -19,6 +19,7 @@ pub fn ConfigCard() -> Element {
     let saved_credits = quiz.space_action.credits;
     let action_status = quiz.space_action.status.clone();
     let initial_depends_on = quiz.space_action.depends_on.clone();
+    let initial_dependency_conditions = quiz.space_action.dependency_conditions.clone();
     let initial_status = quiz.space_action.status.clone();
 
     let action_id_for_signal = action_id_str.clone();
@@ -47,6 +48,7 @@ pub fn ConfigCard() -> Element {
                         space_id,
                         action_id: action_id_signal,
                         initial_depends_on,
+                        initial_conditions: initial_dependency_conditions,
                     }
                 }
 
@@ -48,6 +48,15 @@ pub fn ContentCard() -> Element {
     let mut description_status = use_signal(|| SaveStatus::Idle);
     let mut files = use_signal(|| initial_files);
 
+    let initial_draw_count = ctx.quiz.read().draw_count;
+    let initial_time_limit = ctx.quiz.read().time_limit_seconds / 60;
+    let initial_shuffle = ctx.quiz.read().shuffle_options;
+    let initial_partial = ctx.quiz.read().partial_credit;
+    let mut draw_count = use_signal(|| initial_draw_count);
+    let mut time_limit_minutes = use_signal(|| initial_time_limit);
+    let mut shuffle_options = use_signal(|| initial_shuffle);
+    let mut partial_credit = use_signal(|| initial_partial);
+
     let total_questions = questions.read().len();
 
     let save_files_after_upload = move |next_files: Vec<File>| {
@@ -81,6 +90,24 @@ pub fn ContentCard() -> Element {
         });
     };
 
+    let save_attempt_rules = move || {
+        spawn(async move {
+            let req = UpdateQuizRequest {
+                draw_count: Some(draw_count()),
+                time_limit_seconds: Some(time_limit_minutes() * 60),
+                shuffle_options: Some(shuffle_options()),
+                partial_credit: Some(partial_credit()),
+                ..Default::default()
+            };
+            if let Err(err) = update_quiz(space_id(), quiz_id(), req).await {
+                error!("Failed to save attempt rules: {:?}", err);
+                toast.error(err);
+            } else {
+                ctx.quiz.restart();
+            }
+        });
+    };
+
     let mut save_title = move || {
         let current = title();
         if current == last_saved_title() {
@@ -372,6 +399,7 @@ pub fn ContentCard() -> Element {
                 section { class: "section", "data-testid": "section-scoring",
                     div { class: "section__head",
                         span { class: "section__label", "{tr.section_scoring_label}" }
+                        span { class: "section__hint", "{tr.attempt_rules_hint}" }
                     }
                     div { class: "grid-2",
                         div { class: "field",
@@ -414,6 +442,69 @@ pub fn ContentCard() -> Element {
                                 span { class: "input-suffix", "{tr.retries_suffix}" }
                             }
                         }
+                        div { class: "field",
+                            label { class: "field__label", "{tr.draw_count_label}" }
+                            div { class: "input-group",
+                                input {
+                                    class: "input input--num",
+                                    r#type: "number",
+                                    min: "0",
+                                    max: "{total_questions}",
+                                    "data-testid": "quiz-draw-count",
+                                    value: "{draw_count()}",
+                                    oninput: move |e| {
+                                        if let Ok(v) = e.value().parse::<i64>() {
+                                            draw_count.set(v);
+                                        }
+                                    },
+                                    onblur: move |_| save_attempt_rules(),
+                                }
+                                span { class: "input-suffix",
+                                    "/ {total_questions} {tr.questions_suffix}"
+                                }
+                            }
+                        }
+                        div { class: "field",
+                            label { class: "field__label", "{tr.time_limit_label}" }
+                            div { class: "input-group",
+                                input {
+                                    class: "input input--num",
+                                    r#type: "number",
+                                    min: "0",
+                                    "data-testid": "quiz-time-limit",
+                                    value: "{time_limit_minutes()}",
+                                    oninput: move |e| {
+                                        if let Ok(v) = e.value().parse::<i64>() {
+                                            time_limit_minutes.set(v);
+                                        }
+                                    },
+                                    onblur: move |_| save_attempt_rules(),
+                                }
+                                span { class: "input-suffix", "{tr.minutes_suffix}" }
+                            }
+                        }
+                        div { class: "field",
+                            label { class: "field__label", "{tr.shuffle_options_label}" }
+                            crate::common::components::Switch {
+                                active: shuffle_options(),
+                                label: tr.shuffle_options_label.to_string(),
+                                on_toggle: move |_| {
+                                    shuffle_options.set(!shuffle_options());
+                                    save_attempt_rules();
+                                },
+                            }
+                        }
+                        div { class: "field",
+                            label { class: "field__label", "{tr.partial_credit_label}" }
+                            crate::common::components::Switch {
+                                active: partial_credit(),
+                                label: tr.partial_credit_label.to_string(),
+                                on_toggle: move |_| {
+                                    partial_credit.set(!partial_credit());
+                                    save_attempt_rules();
+                                },
+                            }
+                        }
                     }
                 }
             }
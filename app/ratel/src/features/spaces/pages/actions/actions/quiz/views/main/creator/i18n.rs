@@ -257,6 +257,30 @@ translate! {
         en: "Retry count",
         ko: "재시도 횟수",
     },
+    draw_count_label: {
+        en: "Questions per attempt",
+        ko: "응시당 문항 수",
+    },
+    time_limit_label: {
+        en: "Time limit",
+        ko: "제한 시간",
+    },
+    minutes_suffix: {
+        en: "min",
+        ko: "분",
+    },
+    shuffle_options_label: {
+        en: "Shuffle options",
+        ko: "보기 순서 섞기",
+    },
+    partial_credit_label: {
+        en: "Partial credit",
+        ko: "부분 점수",
+    },
+    attempt_rules_hint: {
+        en: "0 questions draws the whole bank; 0 minutes removes the time limit.",
+        ko: "문항 수 0은 전체 문항을, 제한 시간 0은 시간 제한 없음을 뜻합니다.",
+    },
     questions_suffix: {
         en: "questions",
         ko: "문항",
@@ -90,6 +90,19 @@ translate! {
         en: "You have no remaining attempts for this quiz.",
         ko: "남은 참여 횟수가 없습니다.",
     },
+    time_left: {
+        en: "Time left",
+        ko: "남은 시간",
+    },
+    time_up: {
+        en: "Time is up. This attempt can no longer be submitted.",
+        ko: "제한 시간이 지나 이 응시는 더 이상 제출할 수 없습니다.",
+    },
+}
+
+fn format_remaining(ms: i64) -> String {
+    let secs = (ms.max(0) + 999) / 1000;
+    format!("{:02}:{:02}", secs / 60, secs % 60)
 }
 
 #[component]
@@ -107,22 +120,48 @@ pub fn QuizReadPage(
     let mut question_index = use_signal(|| 0usize);
     let nav = navigator();
     let space = use_space().read().clone();
+    // Drawn, shuffled and timed quizzes are answered against the questions
+    // `start_quiz_attempt` hands out rather than the bank itself.
+    let mut attempt = use_signal(|| None::<QuizAttemptResponse>);
+    let mut now = use_signal(crate::common::utils::time::get_now_timestamp_millis);
+    use_future(move || async move {
+        loop {
+            crate::common::utils::time::sleep(std::time::Duration::from_secs(1)).await;
+            if attempt.peek().as_ref().and_then(|a| a.expires_at).is_some() {
+                now.set(crate::common::utils::time::get_now_timestamp_millis());
+            }
+        }
+    });
+
+    let questions = attempt
+        .read()
+        .as_ref()
+        .map(|a| a.questions.clone())
+        .unwrap_or_else(|| quiz.questions.clone());
+    let remaining_ms = attempt
+        .read()
+        .as_ref()
+        .and_then(|a| a.expires_at)
+        .map(|expires_at| expires_at - now());
+    let time_up = remaining_ms.is_some_and(|ms| ms <= 0);
 
     let initial_answers = quiz.my_response.clone().unwrap_or_else(|| {
         quiz.questions
             .iter()
-            .map(default_answer_for_question)
+            .map(empty_answer)
             .collect()
     });
     let mut answers = use_signal(|| initial_answers);
     let all_answered = use_memo({
-        let quiz = quiz.clone();
+        let bank = quiz.questions.clone();
         move || {
-            if quiz.questions.len() == 0 {
+            let attempt_read = attempt.read();
+            let questions = attempt_read.as_ref().map(|a| &a.questions).unwrap_or(&bank);
+            if questions.len() == 0 {
                 return false;
             }
             let answers_read = answers.read();
-            quiz.questions
+            questions
                 .iter()
                 .enumerate()
                 .all(|(idx, question)| has_answer_for_question(question, answers_read.get(idx)))
@@ -148,11 +187,12 @@ pub fn QuizReadPage(
         && can_execute_action
         && is_in_progress
         && !has_passed
-        && quiz.attempt_count < total_allowed;
+        && quiz.attempt_count < total_allowed
+        && !time_up;
     let remaining_submissions = total_allowed.saturating_sub(quiz.attempt_count);
-    let total_questions = quiz.questions.len();
+    let total_questions = questions.len();
     let current_idx = question_index().min(total_questions.saturating_sub(1));
-    let current_question = quiz.questions.get(current_idx).cloned();
+    let current_question = questions.get(current_idx).cloned();
     let current_answer = answers.read().get(current_idx).cloned();
     let has_current_answer = current_question
         .as_ref()
@@ -168,7 +208,7 @@ pub fn QuizReadPage(
         spawn(async move {
             match respond_quiz(space_id(), quiz_id(), req).await {
                 Ok(_) => {
-                        ctx.quiz.restart();
+                    ctx.quiz.restart();
                     ctx.answer.restart();
                     space_ctx.ranking.restart();
                     space_ctx.my_score.restart();
@@ -183,6 +223,30 @@ pub fn QuizReadPage(
         });
     };
 
+    let needs_session = quiz.needs_session && can_submit;
+    let on_start = move |_| {
+        question_index.set(0);
+        if !needs_session || attempt.read().is_some() {
+            step.set(QuizReadStep::Quiz);
+            return;
+        }
+        spawn(async move {
+            match start_quiz_attempt(space_id(), quiz_id()).await {
+                Ok(started) => {
+                    answers.set(started.questions.iter().map(empty_answer).collect());
+                    now.set(crate::common::utils::time::get_now_timestamp_millis());
+                    attempt.set(Some(started));
+                    step.set(QuizReadStep::Quiz);
+                }
+                Err(err) => {
+                    error!("Failed to start quiz attempt: {:?}", err);
+                    ctx.quiz.restart();
+                    toast.error(err);
+                }
+            }
+        });
+    };
+
     let on_cancel = move |_| {
         nav.push(format!("/spaces/{}/actions", space_id()));
     };
@@ -209,12 +273,9 @@ pub fn QuizReadPage(
                         style: ButtonStyle::Primary,
                         shape: ButtonShape::Square,
                         class: "min-w-[120px]",
-                        disabled: quiz.questions.is_empty(),
+                        disabled: quiz.question_count == 0,
                         "data-testid": "quiz-read-next",
-                        onclick: move |_| {
-                            question_index.set(0);
-                            step.set(QuizReadStep::Quiz);
-                        },
+                        onclick: on_start,
                         {i18n.btn_next}
                     }
                 },
@@ -255,6 +316,13 @@ pub fn QuizReadPage(
                     div { class: "text-sm text-foreground-muted",
                         "{i18n.remaining_submissions} {remaining_submissions}/{total_allowed}"
                     }
+                    if let Some(ms) = remaining_ms {
+                        div {
+                            class: "text-sm font-semibold text-text-primary",
+                            "data-testid": "quiz-read-timer",
+                            "{i18n.time_left} {format_remaining(ms)}"
+                        }
+                    }
                 },
                 bottom_right: rsx! {
                     if !is_first_question && total_questions > 0 {
@@ -338,14 +406,20 @@ pub fn QuizReadPage(
                         }
                     }
 
-                    if quiz.questions.is_empty() {
+                    if time_up {
+                        div { class: "p-3 text-sm rounded-lg bg-banner-bg text-banner-text",
+                            {i18n.time_up}
+                        }
+                    }
+
+                    if questions.is_empty() {
                         div { class: "flex justify-center items-center py-10 text-foreground-muted",
                             {i18n.no_questions}
                         }
                     } else {
                         {
                             let idx = question_index().min(total_questions.saturating_sub(1));
-                            let question = quiz.questions[idx].clone();
+                            let question = questions[idx].clone();
                             let answer = answers.read().get(idx).cloned();
                             let can_next = idx + 1 < total_questions;
                             rsx! {
@@ -383,23 +457,3 @@ pub fn QuizReadPage(
         }
     }
 }
-
-fn default_answer_for_question(question: &Question) -> Answer {
-    match question {
-        Question::SingleChoice(_) => Answer::SingleChoice {
-            answer: None,
-            other: None,
-        },
-        Question::MultipleChoice(_) => Answer::MultipleChoice {
-            answer: Some(vec![]),
-            other: None,
-        },
-        Question::ShortAnswer(_) => Answer::ShortAnswer { answer: None },
-        Question::Subjective(_) => Answer::Subjective { answer: None },
-        Question::Checkbox(_) => Answer::Checkbox {
-            answer: Some(vec![]),
-        },
-        Question::Dropdown(_) => Answer::Dropdown { answer: None },
-        Question::LinearScale(_) => Answer::LinearScale { answer: None },
-    }
-}
@@ -1,4 +1,5 @@
 use crate::common::{PopoverContent, PopoverRoot, PopoverTrigger};
+use crate::features::spaces::pages::actions::actions::poll::controllers::get_poll;
 use crate::features::spaces::pages::actions::controllers::{
     list_actions, update_space_action, UpdateSpaceActionRequest,
 };
@@ -9,11 +10,13 @@ pub fn ActionDependencySelector(
     space_id: ReadSignal<SpacePartition>,
     action_id: ReadSignal<String>,
     initial_depends_on: Vec<String>,
+    #[props(default)] initial_conditions: Vec<ActionDependency>,
     #[props(default)] on_changed: EventHandler<Vec<String>>,
 ) -> Element {
     let tr: ActionDependencySelectorTranslate = use_translate();
     let mut toast = use_toast();
     let mut depends_on = use_signal(|| initial_depends_on.clone());
+    let mut conditions = use_signal(|| initial_conditions.clone());
     let mut menu_open = use_signal(|| false);
     let mut saving = use_signal(|| false);
 
@@ -48,19 +51,31 @@ pub fn ActionDependencySelector(
         .cloned()
         .collect();
 
-    let save = use_callback(move |next: Vec<String>| {
+    let current = move || -> Vec<ActionDependency> {
+        depends_on()
+            .into_iter()
+            .map(|id| ActionDependency {
+                condition: unlock_condition_for(&conditions.read(), &id),
+                action_id: id,
+            })
+            .collect()
+    };
+
+    let save = use_callback(move |next: Vec<ActionDependency>| {
         if saving() {
             return;
         }
         saving.set(true);
         spawn(async move {
-            let req = UpdateSpaceActionRequest::Dependencies {
-                depends_on: next.clone(),
+            let req = UpdateSpaceActionRequest::DependencyRules {
+                dependencies: next.clone(),
             };
             match update_space_action(space_id(), action_id(), req).await {
                 Ok(_) => {
-                    depends_on.set(next.clone());
-                    on_changed.call(next);
+                    let (ids, rules) = split_dependencies(next);
+                    depends_on.set(ids.clone());
+                    conditions.set(rules);
+                    on_changed.call(ids);
                 }
                 Err(e) => {
                     toast.error(e);
@@ -76,6 +91,12 @@ pub fn ActionDependencySelector(
         .cloned()
         .collect();
 
+    let rule_targets: Vec<SpaceActionSummary> = selected_view
+        .iter()
+        .filter(|a| matches!(a.action_type, SpaceActionType::Quiz | SpaceActionType::Poll))
+        .cloned()
+        .collect();
+
     let has_available = !available.is_empty();
     let has_selected = !selected_view.is_empty();
 
@@ -96,12 +117,10 @@ pub fn ActionDependencySelector(
                             class: "inline-flex gap-1.5 items-center py-1 px-2.5 font-medium rounded-full bg-primary/10 text-[12px]/[16px] text-primary",
                             onclick: {
                                 let dep_id = dep.action_id.clone();
-                                let selected_list = selected.clone();
                                 move |_| {
-                                    let next: Vec<String> = selected_list
-                                        .iter()
-                                        .filter(|id| *id != &dep_id)
-                                        .cloned()
+                                    let next: Vec<ActionDependency> = current()
+                                        .into_iter()
+                                        .filter(|d| d.action_id != dep_id)
                                         .collect();
                                     save.call(next);
                                 }
@@ -133,10 +152,12 @@ pub fn ActionDependencySelector(
                                             class: "py-2 px-3 text-left rounded-[8px] text-[13px]/[18px] text-text-primary hover:bg-hover",
                                             onclick: {
                                                 let aid = action.action_id.clone();
-                                                let selected_list = selected.clone();
                                                 move |_| {
-                                                    let mut next = selected_list.clone();
-                                                    next.push(aid.clone());
+                                                    let mut next = current();
+                                                    next.push(ActionDependency {
+                                                        action_id: aid.clone(),
+                                                        condition: UnlockCondition::Completed,
+                                                    });
                                                     save.call(next);
                                                     menu_open.set(false);
                                                 }
@@ -150,6 +171,178 @@ pub fn ActionDependencySelector(
                     }
                 }
             }
+
+            if !rule_targets.is_empty() {
+                div { class: "flex flex-col gap-2 pt-1",
+                    span { class: "font-medium text-[12px]/[16px] text-foreground-muted",
+                        "{tr.unlock_rules_hint}"
+                    }
+                    for dep in rule_targets.iter() {
+                        DependencyRuleRow {
+                            key: "{dep.action_id}",
+                            space_id,
+                            dependency: dep.clone(),
+                            condition: unlock_condition_for(&conditions.read(), &dep.action_id),
+                            on_change: {
+                                let dep_id = dep.action_id.clone();
+                                move |condition: UnlockCondition| {
+                                    let next: Vec<ActionDependency> = current()
+                                        .into_iter()
+                                        .map(|mut d| {
+                                            if d.action_id == dep_id {
+                                                d.condition = condition.clone();
+                                            }
+                                            d
+                                        })
+                                        .collect();
+                                    save.call(next);
+                                }
+                            },
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Unlock rule picker for one quiz or poll dependency.
+#[component]
+fn DependencyRuleRow(
+    space_id: ReadSignal<SpacePartition>,
+    dependency: SpaceActionSummary,
+    condition: UnlockCondition,
+    on_change: EventHandler<UnlockCondition>,
+) -> Element {
+    rsx! {
+        div { class: "flex flex-wrap gap-2 items-center",
+            span { class: "font-medium truncate text-[13px]/[18px] text-text-primary max-w-[200px]",
+                "{dependency.title}"
+            }
+            if dependency.action_type == SpaceActionType::Quiz {
+                QuizRuleEditor { condition, on_change }
+            } else {
+                PollRuleEditor {
+                    space_id,
+                    poll_id: dependency.action_id.clone(),
+                    condition,
+                    on_change,
+                }
+            }
+        }
+    }
+}
+
+#[component]
+fn QuizRuleEditor(condition: UnlockCondition, on_change: EventHandler<UnlockCondition>) -> Element {
+    let tr: ActionDependencySelectorTranslate = use_translate();
+    let saved_score = match &condition {
+        UnlockCondition::QuizPassed {
+            min_score: Some(score),
+        } => *score,
+        _ => 0,
+    };
+    let mut min_score = use_signal(|| saved_score);
+    let mode = match &condition {
+        UnlockCondition::QuizPassed { min_score: None } => "passed",
+        UnlockCondition::QuizPassed { .. } => "score",
+        _ => "completed",
+    };
+
+    rsx! {
+        select {
+            class: "py-1 px-2 rounded-[8px] border border-separator bg-transparent text-[12px]/[16px] text-text-primary",
+            value: "{mode}",
+            onchange: move |e: FormEvent| {
+                let next = match e.value().as_str() {
+                    "passed" => UnlockCondition::QuizPassed {
+                        min_score: None,
+                    },
+                    "score" => UnlockCondition::QuizPassed {
+                        min_score: Some(min_score()),
+                    },
+                    _ => UnlockCondition::Completed,
+                };
+                on_change.call(next);
+            },
+            option { value: "completed", "{tr.rule_completed}" }
+            option { value: "passed", "{tr.rule_quiz_passed}" }
+            option { value: "score", "{tr.rule_quiz_min_score}" }
+        }
+        if mode == "score" {
+            input {
+                class: "py-1 px-2 w-16 rounded-[8px] border border-separator bg-transparent text-[12px]/[16px] text-text-primary",
+                r#type: "number",
+                min: "0",
+                value: "{min_score()}",
+                oninput: move |e| {
+                    if let Ok(v) = e.value().parse::<i64>() {
+                        min_score.set(v.max(0));
+                    }
+                },
+                onblur: move |_| {
+                    if min_score() != saved_score {
+                        on_change
+                            .call(UnlockCondition::QuizPassed {
+                                min_score: Some(min_score()),
+                            });
+                    }
+                },
+            }
+        }
+    }
+}
+
+#[component]
+fn PollRuleEditor(
+    space_id: ReadSignal<SpacePartition>,
+    poll_id: String,
+    condition: UnlockCondition,
+    on_change: EventHandler<UnlockCondition>,
+) -> Element {
+    let tr: ActionDependencySelectorTranslate = use_translate();
+    let poll_sk = poll_id.clone();
+    let poll = use_loader(move || get_poll(space_id(), poll_sk.clone().into()))?;
+    let questions = poll().questions;
+
+    let selected = match &condition {
+        UnlockCondition::PollOption {
+            question_index,
+            option_index,
+        } => format!("{question_index}:{option_index}"),
+        _ => "completed".to_string(),
+    };
+    let choices: Vec<(String, String)> = questions
+        .iter()
+        .enumerate()
+        .filter_map(|(qi, q)| choice_options(q).map(|options| (qi, options)))
+        .flat_map(|(qi, options)| {
+            options
+                .iter()
+                .enumerate()
+                .map(move |(oi, option)| (format!("{qi}:{oi}"), format!("Q{} · {option}", qi + 1)))
+        })
+        .collect();
+
+    rsx! {
+        select {
+            class: "py-1 px-2 rounded-[8px] border border-separator bg-transparent text-[12px]/[16px] text-text-primary max-w-[260px]",
+            value: "{selected}",
+            onchange: move |e: FormEvent| {
+                let value = e.value();
+                let next = value
+                    .split_once(':')
+                    .and_then(|(q, o)| Some(UnlockCondition::PollOption {
+                        question_index: q.parse().ok()?,
+                        option_index: o.parse().ok()?,
+                    }))
+                    .unwrap_or(UnlockCondition::Completed);
+                on_change.call(next);
+            },
+            option { value: "completed", "{tr.rule_completed}" }
+            for (value, label) in choices {
+                option { key: "{value}", value: "{value}", "{tr.rule_poll_option} {label}" }
+            }
         }
     }
 }
@@ -162,6 +355,14 @@ translate! {
         ko: "선택한 액션을 모두 완료해야 이 액션을 참여할 수 있습니다."
     },
     add_dependency: { en: "+ Add dependency", ko: "+ 선행 액션 추가" },
+    unlock_rules_hint: {
+        en: "Unlock rules: what counts as completing each dependency.",
+        ko: "해제 조건: 각 선행 액션을 완료한 것으로 인정하는 기준입니다."
+    },
+    rule_completed: { en: "Any participation", ko: "참여 완료" },
+    rule_quiz_passed: { en: "Passed", ko: "통과" },
+    rule_quiz_min_score: { en: "Score at least", ko: "최소 점수" },
+    rule_poll_option: { en: "Picked", ko: "선택:" },
     no_actions_available: {
         en: "No other actions exist in this space yet.",
         ko: "이 스페이스에 추가할 수 있는 다른 액션이 없습니다."
@@ -0,0 +1,37 @@
+use super::*;
+
+#[mcp_tool(
+    name = "get_action_graph",
+    description = "Get a space's action dependency graph as a DAG: actions in dependency order with their depth, and edges carrying each unlock rule (completed, quiz_passed, poll_option). Actions stuck behind a dependency cycle are flagged unreachable."
+)]
+#[get("/api/spaces/{space_pk}/actions/graph", role: SpaceUserRole)]
+pub async fn get_action_graph(
+    #[mcp(description = "Space partition key")] space_pk: SpacePartition,
+) -> Result<ActionGraph> {
+    let cli = crate::features::spaces::pages::actions::config::get()
+        .common
+        .dynamodb();
+    let space_pk: Partition = space_pk.into();
+
+    let (space_actions, _) =
+        SpaceAction::find_by_space(cli, &space_pk, SpaceAction::opt_all().oldest())
+            .await
+            .map_err(|e| {
+                crate::error!("failed to load actions: {e:?}");
+                SpaceActionError::ActionLoadFailed
+            })?;
+
+    let mut actions: Vec<SpaceActionSummary> = space_actions.into_iter().map(Into::into).collect();
+    // Participants see the same actions `list_actions` shows them.
+    if !matches!(role, SpaceUserRole::Creator) {
+        actions.retain(|a| {
+            a.prerequisite
+                || matches!(
+                    a.status,
+                    Some(SpaceActionStatus::Ongoing | SpaceActionStatus::Finish)
+                )
+        });
+    }
+
+    Ok(ActionGraph::build(&actions))
+}
@@ -10,10 +10,14 @@ use crate::features::spaces::pages::actions::actions::discussion::{SpacePost, Sp
 #[cfg(feature = "server")]
 use crate::features::spaces::pages::actions::actions::follow::SpaceFollowUser;
 #[cfg(feature = "server")]
+use crate::features::spaces::pages::actions::actions::meet::SpaceMeetAttendance;
+#[cfg(feature = "server")]
 use crate::features::spaces::pages::actions::actions::poll::SpacePollUserAnswer;
 #[cfg(feature = "server")]
 use crate::features::spaces::pages::actions::actions::quiz::{SpaceQuiz, SpaceQuizAttempt};
 #[cfg(feature = "server")]
+use crate::features::spaces::pages::actions::services::dependency;
+#[cfg(feature = "server")]
 use std::collections::{HashMap, HashSet};
 
 #[mcp_tool(
@@ -108,6 +112,12 @@ pub async fn list_actions(
                     action.user_participated =
                         has_completed_follow_action(cli, &space_pk, &user.pk).await?;
                 }
+                SpaceActionType::Meet => {
+                    let meet_id: SpaceMeetEntityType = action.action_id.clone().into();
+                    let (pk, sk) = SpaceMeetAttendance::keys(&space_pk, &meet_id, &user.pk);
+                    action.user_participated =
+                        SpaceMeetAttendance::get(cli, pk, Some(sk)).await?.is_some();
+                }
                 _ => {}
             }
         }
@@ -123,11 +133,30 @@ pub async fn list_actions(
             action.dependencies_met = true;
             continue;
         }
-        action.dependencies_met = current_user.is_some()
-            && action
-                .depends_on
-                .iter()
-                .all(|dep_id| participated_by_id.get(dep_id).copied().unwrap_or(false));
+        let Some(user) = current_user.as_ref() else {
+            action.dependencies_met = false;
+            continue;
+        };
+        let mut met = true;
+        for dep_id in &action.depends_on {
+            let participated = participated_by_id.get(dep_id).copied().unwrap_or(false);
+            // Every rule requires taking part, so a skipped dependency
+            // settles it without another lookup.
+            if !participated {
+                met = false;
+                break;
+            }
+            let condition = action.unlock_condition(dep_id);
+            let rule_met =
+                dependency::unlock_condition_met(cli, &space_pk, dep_id, &condition, &user.pk)
+                    .await?
+                    .unwrap_or(true);
+            if !rule_met {
+                met = false;
+                break;
+            }
+        }
+        action.dependencies_met = met;
     }
 
     if !matches!(role, SpaceUserRole::Creator) {
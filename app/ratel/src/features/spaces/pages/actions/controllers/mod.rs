@@ -1,8 +1,10 @@
 mod list_actions;
+mod get_action_graph;
 mod update_space_action;
 mod delete_space_action;
 
 pub use list_actions::*;
+pub use get_action_graph::*;
 pub use update_space_action::*;
 pub use delete_space_action::*;
 
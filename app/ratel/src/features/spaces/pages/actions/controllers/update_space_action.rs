@@ -14,8 +14,10 @@ use crate::features::membership::models::{
 #[serde(untagged)]
 pub enum UpdateSpaceActionRequest {
     Credits { credits: u64 },
+    RewardRules { reward_rules: RewardRules },
     Status { status: SpaceActionStatus },
     Dependencies { depends_on: Vec<String> },
+    DependencyRules { dependencies: Vec<ActionDependency> },
     Prerequisite { prerequisite: bool },
     Title { title: String },
     Description { description: String },
@@ -60,6 +62,15 @@ pub async fn update_space_action(
             )
             .await?;
         }
+        UpdateSpaceActionRequest::RewardRules { reward_rules } => {
+            SpaceReward::can_edit(&role)?;
+            let behavior = space_action.space_action_type.to_behavior();
+            let reward =
+                SpaceReward::get_by_action(cli, space_id.clone(), action_id.clone(), behavior)
+                    .await
+                    .map_err(|_| SpaceRewardError::NoCreditsConfigured)?;
+            set_reward_rules(cli, &user, &space, &reward, reward_rules, now).await?;
+        }
         UpdateSpaceActionRequest::Status { status } => {
             if !SpaceActionStatus::allows_transition(space_action.status.as_ref(), &status) {
                 return Err(SpaceActionError::InvalidStatusTransition.into());
@@ -76,19 +87,36 @@ pub async fn update_space_action(
                 })?;
         }
         UpdateSpaceActionRequest::Dependencies { depends_on } => {
-            if depends_on.iter().any(|id| id == &action_id) {
-                return Err(SpaceActionError::InvalidDependency.into());
-            }
-            space_action.depends_on = depends_on.clone();
-            SpaceAction::updater(&pk, &EntityType::SpaceAction)
-                .with_depends_on(depends_on)
-                .with_updated_at(now)
-                .execute(cli)
-                .await
-                .map_err(|e| {
-                    crate::error!("Failed to update action dependencies: {e:?}");
-                    SpaceActionError::ActionUpdateFailed
-                })?;
+            // A plain id list keeps the rules of the dependencies it retains.
+            let dependencies: Vec<ActionDependency> = depends_on
+                .into_iter()
+                .map(|id| ActionDependency {
+                    condition: space_action.unlock_condition(&id),
+                    action_id: id,
+                })
+                .collect();
+            set_dependencies(
+                cli,
+                &space,
+                &pk,
+                &action_id,
+                dependencies,
+                &mut space_action,
+                now,
+            )
+            .await?;
+        }
+        UpdateSpaceActionRequest::DependencyRules { dependencies } => {
+            set_dependencies(
+                cli,
+                &space,
+                &pk,
+                &action_id,
+                dependencies,
+                &mut space_action,
+                now,
+            )
+            .await?;
         }
         UpdateSpaceActionRequest::Prerequisite { prerequisite } => {
             space_action.prerequisite = prerequisite;
@@ -153,6 +181,40 @@ async fn reindex_essence_for_action(
     let _ = (space, action_id, space_pk, space_action);
 }
 
+#[cfg(feature = "server")]
+async fn set_dependencies(
+    cli: &aws_sdk_dynamodb::Client,
+    space: &SpaceCommon,
+    pk: &CompositePartition<SpacePartition, String>,
+    action_id: &str,
+    dependencies: Vec<ActionDependency>,
+    space_action: &mut SpaceAction,
+    now: i64,
+) -> Result<()> {
+    crate::features::spaces::pages::actions::services::dependency::validate_dependencies(
+        cli,
+        &space.pk,
+        action_id,
+        &dependencies,
+    )
+    .await?;
+
+    let (depends_on, conditions) = split_dependencies(dependencies);
+    space_action.depends_on = depends_on.clone();
+    space_action.dependency_conditions = conditions.clone();
+    SpaceAction::updater(pk, &EntityType::SpaceAction)
+        .with_depends_on(depends_on)
+        .with_dependency_conditions(conditions)
+        .with_updated_at(now)
+        .execute(cli)
+        .await
+        .map_err(|e| {
+            crate::error!("Failed to update action dependencies: {e:?}");
+            SpaceActionError::ActionUpdateFailed
+        })?;
+    Ok(())
+}
+
 #[cfg(feature = "server")]
 async fn update_credits(
     cli: &aws_sdk_dynamodb::Client,
@@ -199,7 +261,7 @@ async fn update_credits(
 
 /// Set or update reward credits: validate membership limits, deduct delta, upsert reward.
 #[cfg(feature = "server")]
-async fn set_credits(
+pub(crate) async fn set_credits(
     cli: &aws_sdk_dynamodb::Client,
     user: &crate::features::auth::User,
     space: &SpaceCommon,
@@ -211,14 +273,90 @@ async fn set_credits(
     behavior: &RewardUserBehavior,
     now: i64,
 ) -> Result<()> {
-    let old_credits = space_action.credits as i64;
-    let credit_delta = credits as i64 - old_credits;
-    let (point, period, condition) = get_or_create_reward(cli, behavior).await?;
+    let (point, period, condition, template_rules) = get_or_create_reward(cli, behavior).await?;
+    // Changing credits keeps rules the creator already tuned for this
+    // action; only a fresh reward starts from the template.
+    let existing = SpaceReward::get_by_action(
+        cli,
+        space_id.clone(),
+        action_id.to_string(),
+        behavior.clone(),
+    )
+    .await
+    .ok();
+    let old_billed = existing
+        .as_ref()
+        .map_or(space_action.credits as i64, SpaceReward::billed_credits);
+    let rules = existing.map_or(template_rules, |existing| existing.rules);
+    let billed = rules.billed_credits(point, credits as i64);
     let total_points = (credits as i64 * point) as u64;
     space_action.credits = credits;
     space_action.total_points = total_points;
 
-    let membership_item = if matches!(&space.user_pk, Partition::Team(_)) {
+    let membership_item =
+        charge_membership_item(cli, user, space, billed, billed - old_billed, now).await?;
+
+    let items = vec![
+        membership_item,
+        upsert_space_reward_item(
+            space_id, action_id, credits, behavior, point, period, condition, rules, now,
+        ),
+        update_action_credits_item(pk, credits, total_points, now),
+    ];
+    crate::transact_write_items!(cli, items).map_err(|e| {
+        crate::error!("Failed to execute transaction: {e:?}");
+        SpaceActionError::TransactionFailed
+    })?;
+
+    Ok(())
+}
+
+/// Replace a reward's rules, charging (or refunding) the difference in
+/// [`SpaceReward::billed_credits`] in the same transaction, so raising a
+/// multiplier costs what it can pay out.
+#[cfg(feature = "server")]
+pub(crate) async fn set_reward_rules(
+    cli: &aws_sdk_dynamodb::Client,
+    user: &crate::features::auth::User,
+    space: &SpaceCommon,
+    reward: &SpaceReward,
+    rules: RewardRules,
+    now: i64,
+) -> Result<()> {
+    rules.validate(reward.point)?;
+    let billed = rules.billed_credits(reward.point, reward.credits);
+    let delta = billed - reward.billed_credits();
+
+    let mut items = vec![];
+    if delta != 0 {
+        items.push(charge_membership_item(cli, user, space, billed, delta, now).await?);
+    }
+    items.push(
+        SpaceReward::updater(&reward.pk, &reward.sk)
+            .with_rules(rules)
+            .with_updated_at(now)
+            .transact_write_item(),
+    );
+    crate::transact_write_items!(cli, items).map_err(|e| {
+        crate::error!("Failed to update reward rules: {e:?}");
+        SpaceActionError::ActionUpdateFailed
+    })?;
+    Ok(())
+}
+
+/// Take `delta` credits from the membership paying for `space` (the team
+/// for team spaces, else `user`); a negative `delta` refunds. `billed`,
+/// the action's new total, is checked against the plan's per-space cap.
+#[cfg(feature = "server")]
+async fn charge_membership_item(
+    cli: &aws_sdk_dynamodb::Client,
+    user: &crate::features::auth::User,
+    space: &SpaceCommon,
+    billed: i64,
+    delta: i64,
+    now: i64,
+) -> Result<TransactWriteItem> {
+    let item = if matches!(&space.user_pk, Partition::Team(_)) {
         let mut team_membership =
             TeamMembership::get(cli, space.user_pk.clone(), Some(EntityType::TeamMembership))
                 .await
@@ -237,14 +375,14 @@ async fn set_credits(
                 SpaceActionError::MembershipCheckFailed
             })?;
         let max_per_space = membership.as_ref().map_or(0, |m| m.max_credits_per_space);
-        if max_per_space > 0 && credits as i64 > max_per_space {
+        if max_per_space > 0 && billed > max_per_space {
             return Err(SpaceRewardError::CreditsExceedMaxPerSpace.into());
         }
 
-        team_membership.use_credits(credit_delta)?;
+        team_membership.use_credits(delta)?;
 
         TeamMembership::updater(&team_membership.pk, &team_membership.sk)
-            .decrease_remaining_credits(credit_delta)
+            .decrease_remaining_credits(delta)
             .with_updated_at(now)
             .transact_write_item()
     } else {
@@ -266,31 +404,18 @@ async fn set_credits(
                 SpaceActionError::MembershipCheckFailed
             })?;
         let max_per_space = membership.as_ref().map_or(0, |m| m.max_credits_per_space);
-        if max_per_space > 0 && credits as i64 > max_per_space {
+        if max_per_space > 0 && billed > max_per_space {
             return Err(SpaceRewardError::CreditsExceedMaxPerSpace.into());
         }
 
-        user_membership.use_credits(credit_delta)?;
+        user_membership.use_credits(delta)?;
 
         UserMembership::updater(&user_membership.pk, &user_membership.sk)
-            .decrease_remaining_credits(credit_delta)
+            .decrease_remaining_credits(delta)
             .with_updated_at(now)
             .transact_write_item()
     };
-
-    let items = vec![
-        membership_item,
-        upsert_space_reward_item(
-            space_id, action_id, credits, behavior, point, period, condition, now,
-        ),
-        update_action_credits_item(pk, credits, total_points, now),
-    ];
-    crate::transact_write_items!(cli, items).map_err(|e| {
-        crate::error!("Failed to execute transaction: {e:?}");
-        SpaceActionError::TransactionFailed
-    })?;
-
-    Ok(())
+    Ok(item)
 }
 
 /// Remove reward and refund credits back to user membership.
@@ -336,7 +461,7 @@ async fn remove_credits(
                         ensure_team_membership_monthly_refill(cli, team_membership.clone()).await?;
                     items.push(
                         TeamMembership::updater(&team_membership.pk, &team_membership.sk)
-                            .increase_remaining_credits(reward.credits)
+                            .increase_remaining_credits(reward.billed_credits())
                             .with_updated_at(now)
                             .transact_write_item(),
                     );
@@ -352,7 +477,7 @@ async fn remove_credits(
                 let um = ensure_user_membership_monthly_refill(cli, um.clone()).await?;
                 items.push(
                     UserMembership::updater(&um.pk, &um.sk)
-                        .increase_remaining_credits(reward.credits)
+                        .increase_remaining_credits(reward.billed_credits())
                         .with_updated_at(now)
                         .transact_write_item(),
                 );
@@ -374,7 +499,7 @@ async fn remove_credits(
 async fn get_or_create_reward(
     cli: &aws_sdk_dynamodb::Client,
     behavior: &RewardUserBehavior,
-) -> Result<(i64, RewardPeriod, RewardCondition)> {
+) -> Result<(i64, RewardPeriod, RewardCondition, RewardRules)> {
     use crate::common::models::reward::Reward;
 
     let existing = Reward::get(
@@ -389,7 +514,7 @@ async fn get_or_create_reward(
     })?;
 
     match existing {
-        Some(r) => Ok((r.point, r.period, r.condition)),
+        Some(r) => Ok((r.point, r.period, r.condition, r.rules)),
         None => {
             let reward = Reward::new(
                 behavior.clone(),
@@ -401,7 +526,7 @@ async fn get_or_create_reward(
                 crate::error!("Failed to create reward template: {e:?}");
                 SpaceActionError::RewardTemplateFailed
             })?;
-            Ok((reward.point, reward.period, reward.condition))
+            Ok((reward.point, reward.period, reward.condition, reward.rules))
         }
     }
 }
@@ -415,9 +540,10 @@ fn upsert_space_reward_item(
     point: i64,
     period: RewardPeriod,
     condition: RewardCondition,
+    rules: RewardRules,
     now: i64,
 ) -> TransactWriteItem {
-    let space_reward = SpaceReward::new(
+    let mut space_reward = SpaceReward::new(
         space_id.clone(),
         action_id.to_string(),
         behavior.clone(),
@@ -427,6 +553,7 @@ fn upsert_space_reward_item(
         period,
         condition,
     );
+    space_reward.rules = rules;
     space_reward.upsert_transact_write_item()
 }
 
@@ -35,6 +35,10 @@ pub struct SpaceAction {
 
     #[serde(default)]
     pub depends_on: Vec<String>,
+    /// Unlock rules for entries of `depends_on` that need more than plain
+    /// completion. Dependencies without an entry use `Completed`.
+    #[serde(default)]
+    pub dependency_conditions: Vec<ActionDependency>,
 }
 
 #[cfg(feature = "server")]
@@ -63,11 +67,16 @@ impl SpaceAction {
             additional_score: 0,
             status: Some(SpaceActionStatus::Designing),
             depends_on: Vec::new(),
+            dependency_conditions: Vec::new(),
         }
     }
 }
 
 impl SpaceAction {
+    pub fn unlock_condition(&self, dependency_id: &str) -> UnlockCondition {
+        unlock_condition_for(&self.dependency_conditions, dependency_id)
+    }
+
     /// Build the absolute participant-facing deep link for this action. Used
     /// by inbox + email notifications. In-app callers (Dioxus `Link`) can
     /// keep using `SpaceActionSummary::get_url` which returns a `Route`
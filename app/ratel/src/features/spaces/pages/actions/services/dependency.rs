@@ -42,10 +42,151 @@ pub async fn dependencies_met(
         return Ok(false);
     }
 
-    let checks = deps
-        .iter()
-        .map(|dep| crate::common::has_completed_prerequisite_action(cli, space, dep, user_pk));
+    let checks = deps.iter().map(|dep| async move {
+        match action.unlock_condition(&dep.pk.1) {
+            UnlockCondition::Completed => {
+                crate::common::has_completed_prerequisite_action(cli, space, dep, user_pk).await
+            }
+            condition => unlock_condition_met(cli, &space.pk, &dep.pk.1, &condition, user_pk)
+                .await
+                .map(|met| met.unwrap_or(false)),
+        }
+    });
 
     let results = try_join_all(checks).await?;
     Ok(results.into_iter().all(|completed| completed))
 }
+
+/// Evaluate a rule richer than plain completion on dependency `dep_id`.
+/// Returns `None` for `Completed`, which callers resolve with whatever
+/// completion check they already have at hand.
+#[cfg(feature = "server")]
+pub async fn unlock_condition_met(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    dep_id: &str,
+    condition: &UnlockCondition,
+    user_pk: &Partition,
+) -> crate::common::Result<Option<bool>> {
+    use crate::features::spaces::pages::actions::actions::poll::SpacePollUserAnswer;
+    use crate::features::spaces::pages::actions::actions::quiz::{
+        SpaceQuiz, SpaceQuizAttempt, MAX_TOTAL_ATTEMPTS,
+    };
+
+    let met = match condition {
+        UnlockCondition::Completed => return Ok(None),
+        UnlockCondition::QuizPassed { min_score } => {
+            let quiz_id: SpaceQuizEntityType = dep_id.to_string().into();
+            let min_score = match min_score {
+                Some(score) => *score,
+                None => {
+                    let quiz_sk: EntityType = quiz_id.clone().into();
+                    match SpaceQuiz::get(cli, space_pk, Some(quiz_sk)).await? {
+                        Some(quiz) => quiz.pass_score,
+                        None => return Ok(Some(false)),
+                    }
+                }
+            };
+            SpaceQuizAttempt::list_by_quiz_user(cli, &quiz_id, user_pk, MAX_TOTAL_ATTEMPTS as i32)
+                .await
+                .map_err(|e| {
+                    crate::error!("failed to load quiz attempts for unlock rule: {e:?}");
+                    SpaceActionError::ActionLoadFailed
+                })?
+                .iter()
+                .any(|attempt| attempt.score >= min_score)
+        }
+        UnlockCondition::PollOption {
+            question_index,
+            option_index,
+        } => {
+            let poll_id: SpacePollEntityType = dep_id.to_string().into();
+            let poll_sk: EntityType = poll_id.into();
+            SpacePollUserAnswer::find_one(cli, space_pk, &poll_sk, user_pk)
+                .await
+                .map_err(|e| {
+                    crate::error!("failed to load poll answer for unlock rule: {e:?}");
+                    SpaceActionError::ActionLoadFailed
+                })?
+                .and_then(|response| response.answers.get(*question_index).cloned())
+                .is_some_and(|answer| answer_picks_option(&answer, *option_index))
+        }
+    };
+
+    Ok(Some(met))
+}
+
+/// Check a proposed dependency list for `action_id` before it is saved:
+/// every dependency must be another existing action in the space, each
+/// rule must fit its dependency, and the space's graph must stay acyclic.
+#[cfg(feature = "server")]
+pub async fn validate_dependencies(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    action_id: &str,
+    dependencies: &[ActionDependency],
+) -> crate::common::Result<()> {
+    use std::collections::BTreeMap;
+
+    use crate::features::spaces::pages::actions::actions::poll::SpacePoll;
+    use crate::features::spaces::pages::actions::models::SpaceAction;
+
+    if dependencies.is_empty() {
+        return Ok(());
+    }
+
+    let (actions, _) = SpaceAction::find_by_space(cli, space_pk, SpaceAction::opt_all())
+        .await
+        .map_err(|e| {
+            crate::error!("failed to load actions for dependency check: {e:?}");
+            SpaceActionError::ActionLoadFailed
+        })?;
+
+    for dependency in dependencies {
+        if dependency.action_id == action_id {
+            return Err(SpaceActionError::InvalidDependency.into());
+        }
+        let dep = actions
+            .iter()
+            .find(|a| a.pk.1 == dependency.action_id)
+            .ok_or(SpaceActionError::InvalidDependency)?;
+        if !dependency.condition.applies_to(&dep.space_action_type) {
+            return Err(SpaceActionError::InvalidUnlockCondition.into());
+        }
+
+        if let UnlockCondition::PollOption {
+            question_index,
+            option_index,
+        } = &dependency.condition
+        {
+            let poll_id: SpacePollEntityType = dependency.action_id.clone().into();
+            let poll_sk: EntityType = poll_id.into();
+            let poll = SpacePoll::get(cli, space_pk, Some(poll_sk))
+                .await?
+                .ok_or(SpaceActionError::InvalidDependency)?;
+            let option_count = poll
+                .questions
+                .get(*question_index)
+                .and_then(choice_options)
+                .map_or(0, |options| options.len());
+            if usize::try_from(*option_index).map_or(true, |i| i >= option_count) {
+                return Err(SpaceActionError::InvalidUnlockCondition.into());
+            }
+        }
+    }
+
+    let mut graph: BTreeMap<String, Vec<String>> = actions
+        .into_iter()
+        .map(|a| (a.pk.1, a.depends_on))
+        .collect();
+    graph.insert(
+        action_id.to_string(),
+        dependencies.iter().map(|d| d.action_id.clone()).collect(),
+    );
+    if let Some(cycle) = find_dependency_cycle(&graph) {
+        crate::warn!("rejected dependency cycle for action {action_id}: {cycle:?}");
+        return Err(SpaceActionError::DependencyCycle.into());
+    }
+
+    Ok(())
+}
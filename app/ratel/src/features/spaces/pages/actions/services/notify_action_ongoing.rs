@@ -3,6 +3,7 @@ use crate::common::models::notification::Notification;
 use crate::common::models::space::SpaceCommon;
 use crate::features::posts::models::Post;
 use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::pages::actions::types::SpaceActionType;
 use crate::features::spaces::space_common::services::space_status_change_notification::{
     resolve_emails, resolve_space_participant_user_pks,
 };
@@ -30,6 +31,17 @@ pub async fn notify_action_ongoing(action: SpaceAction) -> Result<()> {
     let space_pk: Partition = space_id.clone().into();
     let action_id = action.pk.1.clone();
 
+    // Meets send their own lifecycle notices (scheduled / live now) in
+    // place of the generic "new action" one. Dispatched ahead of the space
+    // guard because publishing also hands the meet to the deadline
+    // scheduler; `notify_meet_participants` re-checks the space itself.
+    if action.space_action_type == SpaceActionType::Meet {
+        return crate::features::spaces::pages::actions::actions::meet::handle_meet_published(
+            cli, &action,
+        )
+        .await;
+    }
+
     // Guard: parent space must be Ongoing — no audience otherwise.
     let space = match SpaceCommon::get(cli, &space_pk, Some(&EntityType::SpaceCommon)).await? {
         Some(s) => s,
@@ -0,0 +1,152 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use super::{SpaceActionStatus, SpaceActionSummary, SpaceActionType, UnlockCondition};
+use crate::features::spaces::pages::actions::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// A space's actions as a DAG, for drawing quest paths.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActionGraph {
+    /// In dependency order: every node comes after the nodes it depends on.
+    pub nodes: Vec<ActionGraphNode>,
+    pub edges: Vec<ActionGraphEdge>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActionGraphNode {
+    pub action_id: String,
+    pub action_type: SpaceActionType,
+    pub title: String,
+    #[serde(default)]
+    pub status: Option<SpaceActionStatus>,
+    pub prerequisite: bool,
+    /// Length of the longest dependency chain leading here; roots are 0.
+    pub depth: u32,
+    /// On or behind a dependency cycle, so nobody can ever unlock it.
+    #[serde(default)]
+    pub unreachable: bool,
+}
+
+/// `to` unlocks once `from` meets `condition`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActionGraphEdge {
+    pub from: String,
+    pub to: String,
+    pub condition: UnlockCondition,
+}
+
+impl ActionGraph {
+    /// Build the graph over `actions`. Dependencies on actions outside the
+    /// list (deleted, or hidden from the caller) are left out.
+    pub fn build(actions: &[SpaceActionSummary]) -> Self {
+        let index: HashMap<&str, usize> = actions
+            .iter()
+            .enumerate()
+            .map(|(i, a)| (a.action_id.as_str(), i))
+            .collect();
+
+        let mut edges = Vec::new();
+        let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); actions.len()];
+        let mut pending = vec![0usize; actions.len()];
+        for (to, action) in actions.iter().enumerate() {
+            for dep_id in &action.depends_on {
+                let Some(&from) = index.get(dep_id.as_str()) else {
+                    continue;
+                };
+                edges.push(ActionGraphEdge {
+                    from: dep_id.clone(),
+                    to: action.action_id.clone(),
+                    condition: action.unlock_condition(dep_id),
+                });
+                dependents[from].push(to);
+                pending[to] += 1;
+            }
+        }
+
+        // Kahn's algorithm; whatever never drains is on or behind a cycle.
+        let mut depth = vec![0u32; actions.len()];
+        let mut queue: VecDeque<usize> = (0..actions.len()).filter(|i| pending[*i] == 0).collect();
+        let mut order = Vec::with_capacity(actions.len());
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for &next in &dependents[i] {
+                depth[next] = depth[next].max(depth[i] + 1);
+                pending[next] -= 1;
+                if pending[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        let resolved = order.len();
+        order.extend((0..actions.len()).filter(|i| pending[*i] > 0));
+
+        let nodes = order
+            .into_iter()
+            .enumerate()
+            .map(|(position, i)| {
+                let action = &actions[i];
+                ActionGraphNode {
+                    action_id: action.action_id.clone(),
+                    action_type: action.action_type.clone(),
+                    title: action.title.clone(),
+                    status: action.status.clone(),
+                    prerequisite: action.prerequisite,
+                    depth: depth[i],
+                    unreachable: position >= resolved,
+                }
+            })
+            .collect();
+
+        Self { nodes, edges }
+    }
+}
+
+/// A dependency cycle in `depends_on` (action id → the ids it depends on),
+/// as the ids along it with the first repeated at the end, e.g.
+/// `[a, b, a]` when `a` depends on `b` and `b` on `a`.
+pub fn find_dependency_cycle(depends_on: &BTreeMap<String, Vec<String>>) -> Option<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Mark {
+        Visiting,
+        Done,
+    }
+
+    fn visit(
+        id: &str,
+        depends_on: &BTreeMap<String, Vec<String>>,
+        marks: &mut HashMap<String, Mark>,
+        path: &mut Vec<String>,
+    ) -> Option<Vec<String>> {
+        match marks.get(id) {
+            Some(Mark::Done) => return None,
+            Some(Mark::Visiting) => {
+                let start = path.iter().position(|p| p == id).unwrap_or(0);
+                let mut cycle = path[start..].to_vec();
+                cycle.push(id.to_string());
+                return Some(cycle);
+            }
+            None => {}
+        }
+
+        marks.insert(id.to_string(), Mark::Visiting);
+        path.push(id.to_string());
+        for dep in depends_on.get(id).into_iter().flatten() {
+            if let Some(cycle) = visit(dep, depends_on, marks, path) {
+                return Some(cycle);
+            }
+        }
+        path.pop();
+        marks.insert(id.to_string(), Mark::Done);
+        None
+    }
+
+    let mut marks = HashMap::new();
+    depends_on
+        .keys()
+        .find_map(|id| visit(id, depends_on, &mut marks, &mut Vec::new()))
+}
@@ -46,6 +46,20 @@ pub enum SpaceActionError {
         ko = "유효하지 않은 액션 의존성입니다."
     )]
     InvalidDependency,
+
+    #[error("dependency cycle")]
+    #[translate(
+        en = "These dependencies would form a cycle that no one could unlock",
+        ko = "선행 액션이 순환되어 아무도 참여할 수 없게 됩니다."
+    )]
+    DependencyCycle,
+
+    #[error("invalid unlock condition")]
+    #[translate(
+        en = "The unlock condition does not match the dependency action",
+        ko = "선행 액션에 맞지 않는 해제 조건입니다."
+    )]
+    InvalidUnlockCondition,
 }
 
 #[cfg(feature = "server")]
@@ -53,9 +67,10 @@ impl SpaceActionError {
     pub fn status_code(&self) -> crate::axum::http::StatusCode {
         use crate::axum::http::StatusCode;
         match self {
-            SpaceActionError::InvalidStatusTransition | SpaceActionError::InvalidDependency => {
-                StatusCode::BAD_REQUEST
-            }
+            SpaceActionError::InvalidStatusTransition
+            | SpaceActionError::InvalidDependency
+            | SpaceActionError::DependencyCycle
+            | SpaceActionError::InvalidUnlockCondition => StatusCode::BAD_REQUEST,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -8,3 +8,9 @@ pub use space_action_type::*;
 
 mod space_action_status;
 pub use space_action_status::*;
+
+mod unlock_condition;
+pub use unlock_condition::*;
+
+mod action_graph;
+pub use action_graph::*;
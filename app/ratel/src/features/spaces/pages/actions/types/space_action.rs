@@ -29,6 +29,8 @@ pub struct SpaceActionSummary {
     pub status: Option<SpaceActionStatus>,
     #[serde(default)]
     pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub dependency_conditions: Vec<ActionDependency>,
     #[serde(default = "default_deps_met")]
     pub dependencies_met: bool,
 }
@@ -58,12 +60,17 @@ impl From<crate::features::spaces::pages::actions::models::SpaceAction> for Spac
             comment_count: None,
             status: action.status,
             depends_on: action.depends_on,
+            dependency_conditions: action.dependency_conditions,
             dependencies_met: true,
         }
     }
 }
 
 impl SpaceActionSummary {
+    pub fn unlock_condition(&self, dependency_id: &str) -> UnlockCondition {
+        unlock_condition_for(&self.dependency_conditions, dependency_id)
+    }
+
     pub fn get_url(&self, space_id: &SpacePartition) -> Route {
         match self.action_type {
             SpaceActionType::Poll => Route::PollActionPage {
@@ -0,0 +1,109 @@
+use crate::features::spaces::pages::actions::actions::poll::{Answer, Question};
+use crate::features::spaces::pages::actions::*;
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// What a participant must have done on a dependency for the dependent
+/// action to unlock.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum UnlockCondition {
+    /// Took part at all: answered the poll or quiz, commented on the
+    /// discussion, followed, or attended the meet.
+    #[default]
+    Completed,
+    /// Some quiz attempt scored at least `min_score`; `None` means the
+    /// quiz's own pass score.
+    QuizPassed {
+        #[serde(default)]
+        min_score: Option<i64>,
+    },
+    /// The poll response picked `option_index` on `question_index`.
+    PollOption {
+        question_index: usize,
+        option_index: i32,
+    },
+}
+
+impl UnlockCondition {
+    pub fn is_completed(&self) -> bool {
+        matches!(self, UnlockCondition::Completed)
+    }
+
+    /// Whether this condition can be placed on a dependency of `action_type`.
+    pub fn applies_to(&self, action_type: &SpaceActionType) -> bool {
+        match self {
+            UnlockCondition::Completed => true,
+            UnlockCondition::QuizPassed { min_score } => {
+                *action_type == SpaceActionType::Quiz && min_score.is_none_or(|s| s >= 0)
+            }
+            UnlockCondition::PollOption { .. } => *action_type == SpaceActionType::Poll,
+        }
+    }
+}
+
+/// One edge of an action's dependency list with the rule that satisfies it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+pub struct ActionDependency {
+    pub action_id: String,
+    #[serde(default)]
+    pub condition: UnlockCondition,
+}
+
+/// The rule recorded for `dependency_id`, `Completed` when there is none.
+pub fn unlock_condition_for(
+    conditions: &[ActionDependency],
+    dependency_id: &str,
+) -> UnlockCondition {
+    conditions
+        .iter()
+        .find(|d| d.action_id == dependency_id)
+        .map(|d| d.condition.clone())
+        .unwrap_or_default()
+}
+
+/// Split a dependency list into `depends_on` ids and the non-default
+/// rules stored alongside them. Later duplicates of an id are dropped.
+pub fn split_dependencies(
+    dependencies: Vec<ActionDependency>,
+) -> (Vec<String>, Vec<ActionDependency>) {
+    let mut depends_on: Vec<String> = Vec::with_capacity(dependencies.len());
+    let mut conditions = Vec::new();
+    for dependency in dependencies {
+        if depends_on.contains(&dependency.action_id) {
+            continue;
+        }
+        depends_on.push(dependency.action_id.clone());
+        if !dependency.condition.is_completed() {
+            conditions.push(dependency);
+        }
+    }
+    (depends_on, conditions)
+}
+
+/// Pickable options of a choice-style poll question; `None` for free-text
+/// and scale questions, which a `PollOption` rule cannot target.
+pub fn choice_options(question: &Question) -> Option<&[String]> {
+    match question {
+        Question::SingleChoice(q) | Question::MultipleChoice(q) => Some(&q.options),
+        Question::Checkbox(q) => Some(&q.options),
+        Question::Dropdown(q) => Some(&q.options),
+        _ => None,
+    }
+}
+
+/// Whether `answer` picked `option_index`.
+pub fn answer_picks_option(answer: &Answer, option_index: i32) -> bool {
+    match answer {
+        Answer::SingleChoice { answer, .. } | Answer::Dropdown { answer } => {
+            *answer == Some(option_index)
+        }
+        Answer::MultipleChoice { answer, .. } | Answer::Checkbox { answer } => answer
+            .as_ref()
+            .is_some_and(|picks| picks.contains(&option_index)),
+        _ => false,
+    }
+}
@@ -0,0 +1,56 @@
+use crate::features::spaces::pages::actions::actions::quiz::{SpaceQuiz, SpaceQuizQuestionStat};
+use crate::features::spaces::pages::apps::apps::analyzes::*;
+use crate::features::spaces::pages::apps::models::SpaceApp;
+
+#[mcp_tool(
+    name = "list_quiz_question_stats",
+    description = "Per-question difficulty of a quiz across every submitted attempt: attempts, correct rate, average credit and an easy/medium/hard rating. Requires creator role."
+)]
+#[get("/api/spaces/{space_id}/apps/analyzes/quizzes/{quiz_id}/stats", role: SpaceUserRole)]
+pub async fn list_quiz_question_stats(
+    #[mcp(description = "Space partition key")] space_id: SpacePartition,
+    #[mcp(description = "Quiz sort key (e.g. 'SpaceQuiz#<uuid>')")] quiz_id: SpaceQuizEntityType,
+) -> Result<Vec<QuizQuestionStatItem>> {
+    SpaceApp::can_edit(role)?;
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+    let space_pk: Partition = space_id.into();
+
+    let quiz = SpaceQuiz::get(cli, &space_pk, Some(EntityType::from(quiz_id.clone())))
+        .await?
+        .ok_or(Error::NotFound("Quiz not found".into()))?;
+    let stats = SpaceQuizQuestionStat::list_by_quiz(cli, &space_pk, &quiz_id).await?;
+
+    let items = quiz
+        .questions
+        .iter()
+        .enumerate()
+        .map(|(question_idx, question)| {
+            let stat = stats
+                .iter()
+                .find(|s| s.question_index == question_idx as i64);
+            let attempt_count = stat.map(|s| s.attempt_count).unwrap_or(0).max(0) as u32;
+            let per_attempt = |total: i64| {
+                if attempt_count == 0 {
+                    0
+                } else {
+                    (total.max(0) as u64 / attempt_count as u64) as u32
+                }
+            };
+            let correct_rate = per_attempt(stat.map(|s| s.correct_count * 100).unwrap_or(0));
+            // credit_total is already in percent.
+            let average_credit = per_attempt(stat.map(|s| s.credit_total).unwrap_or(0));
+
+            QuizQuestionStatItem {
+                question_idx,
+                question_title: question.title().to_string(),
+                attempt_count,
+                correct_rate,
+                average_credit,
+                difficulty: QuizQuestionDifficulty::rate(attempt_count, average_credit),
+            }
+        })
+        .collect();
+
+    Ok(items)
+}
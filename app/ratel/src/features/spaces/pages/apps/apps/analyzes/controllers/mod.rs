@@ -31,5 +31,8 @@ pub use list_analyze_records::*;
 mod list_analyze_reports;
 pub use list_analyze_reports::*;
 
+mod list_quiz_question_stats;
+pub use list_quiz_question_stats::*;
+
 mod preview_analyze_report;
 pub use preview_analyze_report::*;
@@ -48,6 +48,10 @@ pub struct UseAnalyzeReportDetail {
     pub discussion_results:
         Loader<crate::common::ListResponse<SpaceAnalyzeDiscussionResult>>,
 
+    /// Difficulty of each question of the active quiz, across every
+    /// attempt (not just the report's matched users).
+    pub quiz_stats: Loader<Vec<QuizQuestionStatItem>>,
+
     /// Live form state for the 분석 설정 card.
     pub params: Signal<DiscussionAnalysisParams>,
 
@@ -121,6 +125,25 @@ pub fn use_analyze_report_detail(
         }
     })?;
 
+    // Same fallback as `discussion_results`: the panel shows the first
+    // quiz until the sidebar picks one.
+    let quiz_stats = use_loader(move || {
+        let sid = space_id();
+        let explicit = selected_quiz.read().clone();
+        let fallback = detail
+            .read()
+            .result
+            .as_ref()
+            .and_then(|r| r.quiz_aggregates.first().map(|q| q.quiz_id.clone()));
+        let qid = explicit.or(fallback);
+        async move {
+            match qid {
+                Some(q) if !q.is_empty() => list_quiz_question_stats(sid, q.into()).await,
+                _ => Ok(Vec::new()),
+            }
+        }
+    })?;
+
     let params = use_signal(|| DiscussionAnalysisParams {
         num_topics: 10,
         top_n_tfidf: 20,
@@ -277,6 +300,7 @@ pub fn use_analyze_report_detail(
         selected_discussion,
         active_panel,
         discussion_results,
+        quiz_stats,
         params,
         handle_run_discussion,
         handle_export_excel,
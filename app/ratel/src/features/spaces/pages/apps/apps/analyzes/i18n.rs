@@ -327,6 +327,26 @@ translate! {
         en: "Correct rate",
         ko: "정답률",
     },
+    detail_difficulty_prefix: {
+        en: "Difficulty",
+        ko: "난이도",
+    },
+    detail_difficulty_hint: {
+        en: "Based on every attempt of this quiz",
+        ko: "이 퀴즈의 전체 응시 기준",
+    },
+    detail_difficulty_easy: {
+        en: "Easy",
+        ko: "쉬움",
+    },
+    detail_difficulty_medium: {
+        en: "Medium",
+        ko: "보통",
+    },
+    detail_difficulty_hard: {
+        en: "Hard",
+        ko: "어려움",
+    },
     detail_correct_label: {
         en: "Correct",
         ko: "정답",
@@ -2,9 +2,11 @@
 //! the report DTOs returned by the server endpoints.
 
 mod create;
+mod quiz_stats;
 mod report;
 mod result;
 
 pub use create::*;
+pub use quiz_stats::*;
 pub use report::*;
 pub use result::*;
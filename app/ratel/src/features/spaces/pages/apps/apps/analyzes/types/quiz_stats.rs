@@ -0,0 +1,51 @@
+//! Per-question difficulty across every attempt of a quiz, independent
+//! of any report's filters.
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "server")]
+#[allow(unused_imports)]
+use rmcp::schemars;
+
+/// Attempts needed before a question is rated; fewer is noise.
+pub const MIN_DIFFICULTY_SAMPLES: u32 = 5;
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum QuizQuestionDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl QuizQuestionDifficulty {
+    /// Rate a question by the average credit (0..=100) it earned.
+    pub fn rate(attempt_count: u32, average_credit: u32) -> Option<Self> {
+        if attempt_count < MIN_DIFFICULTY_SAMPLES {
+            return None;
+        }
+        Some(match average_credit {
+            75.. => Self::Easy,
+            40..=74 => Self::Medium,
+            _ => Self::Hard,
+        })
+    }
+}
+
+#[cfg_attr(feature = "server", derive(rmcp::schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct QuizQuestionStatItem {
+    pub question_idx: usize,
+    pub question_title: String,
+    /// Attempts the question was presented in.
+    pub attempt_count: u32,
+    /// Share of those attempts with full credit, in percent.
+    pub correct_rate: u32,
+    /// Mean credit, in percent; differs from `correct_rate` only when
+    /// partial credit is on.
+    pub average_credit: u32,
+    /// `None` below `MIN_DIFFICULTY_SAMPLES` attempts.
+    #[serde(default)]
+    pub difficulty: Option<QuizQuestionDifficulty>,
+}
@@ -29,6 +29,7 @@ pub fn QuizPanel() -> Element {
             .collect(),
         None => Vec::new(),
     };
+    let stats = ctrl.quiz_stats.read().clone();
 
     rsx! {
         section { class: "panel", "data-panel": "quiz", "data-active": "false",
@@ -67,6 +68,7 @@ pub fn QuizPanel() -> Element {
                     QuizQuestionCard {
                         key: "quiz-q-{q_idx}-{q.quiz_id}",
                         question: q.clone(),
+                        stat: stats.iter().find(|s| s.question_idx == q.question_idx).cloned(),
                     }
                 }
             }
@@ -75,7 +77,10 @@ pub fn QuizPanel() -> Element {
 }
 
 #[component]
-fn QuizQuestionCard(question: QuizQuestionAggregate) -> Element {
+fn QuizQuestionCard(
+    question: QuizQuestionAggregate,
+    stat: Option<QuizQuestionStatItem>,
+) -> Element {
     let tr: SpaceAnalyzesAppTranslate = use_translate();
 
     let total = question.respondent_count.max(1) as f64;
@@ -87,6 +92,12 @@ fn QuizQuestionCard(question: QuizQuestionAggregate) -> Element {
         ((question.correct_count as f64 / question.respondent_count as f64) * 100.0).round() as u32
     };
 
+    let difficulty_label = stat.and_then(|s| s.difficulty).map(|d| match d {
+        QuizQuestionDifficulty::Easy => tr.detail_difficulty_easy.to_string(),
+        QuizQuestionDifficulty::Medium => tr.detail_difficulty_medium.to_string(),
+        QuizQuestionDifficulty::Hard => tr.detail_difficulty_hard.to_string(),
+    });
+
     let bars: Vec<BarItem> = question
         .options
         .iter()
@@ -124,6 +135,13 @@ fn QuizQuestionCard(question: QuizQuestionAggregate) -> Element {
                 span { class: "card__count",
                     "{question.respondent_count}명 {tr.detail_attempts_unit} · {tr.detail_correct_rate_prefix} {correct_pct}%"
                 }
+                if let Some(label) = difficulty_label {
+                    span {
+                        class: "card__count",
+                        title: "{tr.detail_difficulty_hint}",
+                        "{tr.detail_difficulty_prefix} {label}"
+                    }
+                }
             }
             if bars.is_empty() {
                 if question.text_answers.is_empty() {
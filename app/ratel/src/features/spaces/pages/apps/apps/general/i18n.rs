@@ -223,4 +223,32 @@ translate! {
         en: "Start time updated successfully.",
         ko: "시작 시간이 변경되었습니다.",
     },
+    save_as_template: {
+        en: "Save as Template",
+        ko: "템플릿으로 저장",
+    },
+    save_as_template_description: {
+        en: "Reuse this space's actions, apps and settings when creating a new space.",
+        ko: "새 스페이스를 만들 때 이 스페이스의 액션, 앱, 설정을 재사용합니다.",
+    },
+    template_name: {
+        en: "Template Name",
+        ko: "템플릿 이름",
+    },
+    template_description: {
+        en: "Description",
+        ko: "설명",
+    },
+    save_template: {
+        en: "Save",
+        ko: "저장",
+    },
+    saving_template: {
+        en: "Saving...",
+        ko: "저장 중...",
+    },
+    template_saved_successfully: {
+        en: "Template saved.",
+        ko: "템플릿이 저장되었습니다.",
+    },
 }
@@ -5,6 +5,7 @@ mod anonymous_setting;
 mod delete_space_popup;
 mod invite_participant;
 mod join_anytime_setting;
+mod save_template_setting;
 mod space_logo_setting;
 mod space_visibility_setting;
 mod start_time_setting;
@@ -14,6 +15,7 @@ use anonymous_setting::*;
 use delete_space_popup::*;
 use invite_participant::*;
 use join_anytime_setting::*;
+use save_template_setting::*;
 use space_logo_setting::*;
 use space_visibility_setting::*;
 use start_time_setting::*;
@@ -153,6 +155,7 @@ pub fn SpaceGeneralAppPage(space_id: ReadSignal<SpacePartition>) -> Element {
                 AnonymousSetting { space_id }
                 JoinAnytimeSetting { space_id }
                 Administrators { space_id }
+                SaveTemplateSetting { space_id }
 
                 // Danger zone
                 section { class: "sga-section sga-section--danger",
@@ -0,0 +1,71 @@
+use super::*;
+use crate::features::spaces::templates::save_space_template;
+
+#[component]
+pub fn SaveTemplateSetting(space_id: ReadSignal<SpacePartition>) -> Element {
+    let tr: GeneralTranslate = use_translate();
+    let mut toast = use_toast();
+
+    let mut name = use_signal(String::new);
+    let mut description = use_signal(String::new);
+
+    let mut save = use_action(move |name: String, description: String| async move {
+        let description = (!description.trim().is_empty()).then_some(description);
+        match save_space_template(space_id(), name, description, None).await {
+            Ok(_) => {
+                toast.info(tr.template_saved_successfully);
+            }
+            Err(err) => {
+                toast.error(err);
+            }
+        }
+        Ok::<(), crate::common::Error>(())
+    });
+    let pending = save.pending();
+
+    rsx! {
+        section { class: "sga-section", "data-testid": "section-save-template",
+            div { class: "sga-section__head",
+                span { class: "sga-section__label", "{tr.save_as_template}" }
+                span { class: "sga-section__hint", "{tr.save_as_template_description}" }
+            }
+
+            div { class: "sga-field",
+                span { class: "sga-field__label", "{tr.template_name}" }
+                input {
+                    class: "sga-input",
+                    r#type: "text",
+                    value: "{name()}",
+                    "data-testid": "template-name-input",
+                    oninput: move |e: FormEvent| name.set(e.value()),
+                }
+            }
+            div { class: "sga-field",
+                span { class: "sga-field__label", "{tr.template_description}" }
+                div { class: "sga-input-group",
+                    input {
+                        class: "sga-input",
+                        r#type: "text",
+                        value: "{description()}",
+                        "data-testid": "template-description-input",
+                        oninput: move |e: FormEvent| description.set(e.value()),
+                    }
+                    button {
+                        r#type: "button",
+                        class: "sga-btn sga-btn--accent",
+                        "data-testid": "template-save-btn",
+                        disabled: pending || name().trim().is_empty(),
+                        onclick: move |_| {
+                            save.call(name(), description());
+                        },
+                        if pending {
+                            {tr.saving_template}
+                        } else {
+                            {tr.save_template}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
 mod list_space_rewards;
+mod simulate_space_rewards;
 
 pub use list_space_rewards::*;
+pub use simulate_space_rewards::*;
@@ -0,0 +1,140 @@
+use crate::features::spaces::pages::apps::apps::rewards::*;
+use crate::features::membership::models::MembershipTier;
+
+pub const MAX_SIMULATED_PARTICIPANTS: i64 = 100_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SimulateSpaceRewardsRequest {
+    /// Participants expected to complete every action.
+    pub participants: i64,
+    /// Credits assumed for actions that have none configured yet.
+    #[serde(default)]
+    pub credits: Option<i64>,
+    /// Completion time to project, so launch-week windows can be
+    /// previewed ahead of time. Defaults to now.
+    #[serde(default)]
+    pub at: Option<i64>,
+    #[serde(default)]
+    pub membership_tier: Option<MembershipTier>,
+    /// Money Tree level assumed for every participant.
+    #[serde(default)]
+    pub skill_level: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RewardSimulationItem {
+    pub action_id: String,
+    pub title: String,
+    pub behavior: RewardUserBehavior,
+    /// `false` when the numbers come from the global template because
+    /// the action has no reward yet.
+    pub configured: bool,
+    pub credits: i64,
+    #[serde(flatten)]
+    pub projection: RewardProjection,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RewardSimulationResponse {
+    pub items: Vec<RewardSimulationItem>,
+    pub total_credits: i64,
+    pub total_points: i64,
+}
+
+/// Dry run of every action's reward for `participants` new claimants.
+/// Configured rewards continue from their current totals; the rest use
+/// the global template with `credits`. Nothing is written.
+#[post("/api/spaces/{space_id}/rewards/simulate", role: SpaceUserRole)]
+pub async fn simulate_space_rewards(
+    space_id: SpacePartition,
+    req: SimulateSpaceRewardsRequest,
+) -> Result<RewardSimulationResponse> {
+    use crate::common::models::reward::Reward;
+    use crate::features::character::skill_effects::{apply_effects, SkillPipeline};
+    use crate::features::character::types::SkillId;
+    use crate::features::spaces::pages::actions::models::SpaceAction;
+    use crate::features::spaces::space_common::models::SpaceReward;
+
+    SpaceReward::can_edit(&role)?;
+    let assumed_credits = req.credits.unwrap_or(0);
+    if !(1..=MAX_SIMULATED_PARTICIPANTS).contains(&req.participants) || assumed_credits < 0 {
+        return Err(SpaceRewardError::InvalidSimulation.into());
+    }
+
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+    let space_pk: Partition = space_id.clone().into();
+
+    let (actions, _) = SpaceAction::find_by_space(cli, space_pk, SpaceAction::opt_all()).await?;
+    let rewards = SpaceReward::list_by_action(cli, space_id, None).await?;
+    let at = req
+        .at
+        .unwrap_or_else(crate::common::utils::time::get_now_timestamp_millis);
+    let levels = [(SkillId::MoneyTree, req.skill_level)];
+    let boost = |v: i64| apply_effects(SkillPipeline::RewardPayout, &levels, v);
+
+    let mut items = Vec::with_capacity(actions.len());
+    for action in actions {
+        let action_id = action.pk.1.clone();
+        let behavior = action.space_action_type.to_behavior();
+        let existing = rewards
+            .iter()
+            .find(|r| r.sk.action_id.as_deref() == Some(&action_id) && r.behavior == behavior);
+
+        let (configured, credits, point, condition, rules, usage) = match existing {
+            Some(r) => (
+                true,
+                r.credits,
+                r.point,
+                r.condition.clone(),
+                r.rules.clone(),
+                RewardUsage {
+                    total_claims: r.total_claims,
+                    total_points: r.total_points,
+                    ..Default::default()
+                },
+            ),
+            None => {
+                // Same defaults `set_credits` would create the template with.
+                let template = Reward::get(cli, Partition::Reward, Some(behavior.clone())).await?;
+                let (point, condition, rules) = template
+                    .map(|t| (t.point, t.condition, t.rules))
+                    .unwrap_or((10000, RewardCondition::None, RewardRules::default()));
+                (
+                    false,
+                    assumed_credits,
+                    point,
+                    condition,
+                    rules,
+                    RewardUsage::default(),
+                )
+            }
+        };
+
+        let ctx = RewardClaimContext {
+            at,
+            usage,
+            membership_tier: req.membership_tier.clone(),
+        };
+        let projection = if credits > 0 {
+            rules.project(point, credits, &condition, req.participants, ctx, boost)
+        } else {
+            RewardProjection::default()
+        };
+
+        items.push(RewardSimulationItem {
+            action_id,
+            title: action.title,
+            behavior,
+            configured,
+            credits,
+            projection,
+        });
+    }
+
+    Ok(RewardSimulationResponse {
+        total_credits: items.iter().map(|i| i.credits).sum(),
+        total_points: items.iter().map(|i| i.projection.total_points).sum(),
+        items,
+    })
+}
@@ -80,7 +80,7 @@ pub fn DashboardCard(
 
     match data {
         DashboardComponentData::RankingTable(data) => rsx! {
-            RankingTable { data }
+            RankingTable { data, is_creator, space_id }
         },
         DashboardComponentData::StatSummary(data) => {
             rsx! {
@@ -1,9 +1,18 @@
+use crate::features::activity::controllers::export_leaderboard_handler;
+use crate::features::activity::interop::download_leaderboard_csv;
+use crate::features::activity::types::LeaderboardCategory;
 use crate::features::spaces::pages::dashboard::i18n::DashboardTranslate;
 use crate::features::spaces::pages::dashboard::*;
 
 #[component]
-pub fn RankingTable(data: RankingTableData) -> Element {
+pub fn RankingTable(
+    data: RankingTableData,
+    #[props(default = false)] is_creator: bool,
+    space_id: SpacePartition,
+) -> Element {
     let tr: DashboardTranslate = use_translate();
+    let mut toast = use_toast();
+    let mut exporting = use_signal(|| false);
     let current_page = use_signal(|| 0usize);
     let page_size = data.page_size;
     let total_pages = (data.entries.len() + page_size - 1) / page_size;
@@ -70,6 +79,15 @@ pub fn RankingTable(data: RankingTableData) -> Element {
                                 span { class: "flex-1 basis-0 text-center text-text-primary text-sm leading-[22px] max-mobile:text-[13px] max-mobile:leading-5 font-normal font-raleway",
                                     "{entry.rank}"
                                 }
+                                if entry.change > 0 {
+                                    span { class: "shrink-0 text-[11px] leading-4 font-semibold font-inter text-green-500",
+                                        "▲{entry.change}"
+                                    }
+                                } else if entry.change < 0 {
+                                    span { class: "shrink-0 text-[11px] leading-4 font-semibold font-inter text-red-500",
+                                        "▼{entry.change.abs()}"
+                                    }
+                                }
                             }
 
                             // Participant
@@ -119,8 +137,37 @@ pub fn RankingTable(data: RankingTableData) -> Element {
             }
 
             // Pagination
-            div { class: "px-[30px] max-tablet:px-5 max-mobile:px-4 py-4 max-mobile:py-3",
-                Pagination { current_page, total_pages }
+            div { class: "flex items-center gap-3 px-[30px] max-tablet:px-5 max-mobile:px-4 py-4 max-mobile:py-3",
+                div { class: "min-w-0 flex-1",
+                    Pagination { current_page, total_pages }
+                }
+                if is_creator {
+                    button {
+                        class: "shrink-0 rounded-[10px] border border-separator px-3 py-1.5 text-xs font-semibold font-raleway text-text-primary transition-colors hover:bg-web-graph-bg disabled:opacity-50",
+                        disabled: exporting(),
+                        onclick: move |_| {
+                            let space_id = space_id.clone();
+                            exporting.set(true);
+                            spawn(async move {
+                                let result = match export_leaderboard_handler(
+                                        space_id,
+                                        Some(LeaderboardCategory::Total),
+                                        None,
+                                    )
+                                    .await
+                                {
+                                    Ok(file) => download_leaderboard_csv(&file).await,
+                                    Err(err) => Err(err),
+                                };
+                                if let Err(err) = result {
+                                    toast.error(err);
+                                }
+                                exporting.set(false);
+                            });
+                        },
+                        "{tr.export_csv}"
+                    }
+                }
             }
         }
     }
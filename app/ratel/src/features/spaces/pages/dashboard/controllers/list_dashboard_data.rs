@@ -95,24 +95,21 @@ pub async fn list_dashboard_data_handler(
     // }
 
     {
-        use crate::features::activity::models::SpaceScore;
+        use crate::features::activity::services::load_ranked_leaderboard;
+        use crate::features::activity::types::{LeaderboardCategory, LeaderboardPeriod};
         use crate::features::spaces::space_common::types::dashboard::*;
 
-        let score_opts = SpaceScore::opt().limit(50).scan_index_forward(true);
-
-        if let Ok((scores, _)) = SpaceScore::find_by_space_rank(cli, &space_pk, score_opts).await {
-            if !scores.is_empty() {
-                let entries: Vec<RankingEntry> = scores
-                    .iter()
-                    .enumerate()
-                    .map(|(i, score)| RankingEntry {
-                        rank: (i as u32) + 1,
-                        name: score.user_name.clone(),
-                        avatar: score.user_avatar.clone(),
-                        score: score.total_score as f64,
-                        change: 0,
-                    })
-                    .collect();
+        if let Ok((ranked, _)) = load_ranked_leaderboard(
+            cli,
+            &space_pk,
+            LeaderboardCategory::Total,
+            LeaderboardPeriod::Day,
+        )
+        .await
+        {
+            if !ranked.is_empty() {
+                let entries: Vec<RankingEntry> =
+                    ranked.into_iter().take(50).map(RankingEntry::from).collect();
 
                 components.push(DashboardComponentData::RankingTable(RankingTableData {
                     entries,
@@ -9,29 +9,29 @@ pub async fn list_ranking_handler(
 {
     use crate::features::spaces::space_common::types::dashboard::*;
 
-    use crate::features::activity::models::SpaceScore;
+    use crate::features::activity::services::load_ranked_leaderboard;
+    use crate::features::activity::types::{LeaderboardCategory, LeaderboardPeriod};
 
     let cfg = crate::common::CommonConfig::default();
     let cli = cfg.dynamodb();
     let space_pk: crate::common::Partition = space_id.into();
 
-    let mut opts = SpaceScore::opt().limit(50).scan_index_forward(true);
-    if let Some(bm) = bookmark {
-        opts = opts.bookmark(bm);
-    }
+    // Ranks and deltas need the full standings, so `bookmark` pages the
+    // ranked list rather than the score index.
+    let offset: usize = bookmark.and_then(|bm| bm.parse().ok()).unwrap_or(0);
+    let (ranked, _) = load_ranked_leaderboard(
+        cli,
+        &space_pk,
+        LeaderboardCategory::Total,
+        LeaderboardPeriod::Day,
+    )
+    .await?;
 
-    let (scores, _) = SpaceScore::find_by_space_rank(cli, &space_pk, opts).await?;
-
-    let entries: Vec<RankingEntry> = scores
-        .iter()
-        .enumerate()
-        .map(|(i, score)| RankingEntry {
-            rank: (i as u32) + 1,
-            name: score.user_name.clone(),
-            avatar: score.user_avatar.clone(),
-            score: score.total_score as f64,
-            change: 0,
-        })
+    let entries: Vec<RankingEntry> = ranked
+        .into_iter()
+        .skip(offset)
+        .take(50)
+        .map(RankingEntry::from)
         .collect();
 
     Ok(RankingTableData {
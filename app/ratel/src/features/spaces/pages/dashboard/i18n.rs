@@ -38,6 +38,11 @@ translate! {
         ko: "랭킹",
     },
 
+    export_csv: {
+        en: "Export CSV",
+        ko: "CSV 내보내기",
+    },
+
     rank: {
         en: "Rank",
         ko: "순위",
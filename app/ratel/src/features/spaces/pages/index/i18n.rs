@@ -325,6 +325,11 @@ translate! {
         ko: "팔로우",
     },
 
+    meet_label: {
+        en: "Meet",
+        ko: "회의",
+    },
+
     top_participants: {
         en: "Top Participants",
         ko: "상위 참가자",
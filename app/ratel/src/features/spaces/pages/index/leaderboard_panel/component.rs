@@ -107,6 +107,10 @@ fn LeaderboardContent(space_id: ReadSignal<SpacePartition>) -> Element {
                     span { class: "leaderboard-breakdown__value", "{my_score.follow_score}" }
                     span { class: "leaderboard-breakdown__label", "{tr.follow_label}" }
                 }
+                div { class: "leaderboard-breakdown__item leaderboard-breakdown__item--meet",
+                    span { class: "leaderboard-breakdown__value", "{my_score.meet_score}" }
+                    span { class: "leaderboard-breakdown__label", "{tr.meet_label}" }
+                }
             }
         }
 
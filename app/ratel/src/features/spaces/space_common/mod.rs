@@ -4,6 +4,8 @@ pub mod config;
 pub mod controllers;
 pub mod hooks;
 pub mod models;
+#[cfg(feature = "server")]
+pub mod realtime;
 pub mod services;
 pub mod types;
 
@@ -27,6 +27,9 @@ pub struct SpaceReward {
 
     pub period: RewardPeriod,
     pub condition: RewardCondition,
+
+    #[serde(default)]
+    pub rules: RewardRules,
 }
 
 #[cfg(feature = "server")]
@@ -57,6 +60,7 @@ impl SpaceReward {
             condition,
             total_points: 0,
             total_claims: 0,
+            rules: RewardRules::default(),
         }
     }
 
@@ -64,6 +68,19 @@ impl SpaceReward {
         self.point * self.credits
     }
 
+    /// Payout of the next claim under `rules`, before character skills.
+    /// Equals [`get_amount`](Self::get_amount) when no rule applies and
+    /// never exceeds [`RewardRules::max_amount`] of the committed credits.
+    pub fn amount_for(&self, ctx: &RewardClaimContext) -> i64 {
+        self.rules.amount(self.point, self.credits, ctx)
+    }
+
+    /// Membership credits this reward is charged for; see
+    /// [`RewardRules::billed_credits`].
+    pub fn billed_credits(&self) -> i64 {
+        self.rules.billed_credits(self.point, self.credits)
+    }
+
     #[cfg(feature = "server")]
     pub async fn get_by_action(
         cli: &aws_sdk_dynamodb::Client,
@@ -188,53 +205,50 @@ impl SpaceReward {
             .map(|reward| (reward.total_claims, reward.total_points))
             .unwrap_or((0, 0));
 
-        match &space_reward.condition {
-            RewardCondition::None => {}
-            RewardCondition::MaxClaims(max) => {
-                if space_reward.total_claims >= *max {
-                    return Err(SpaceRewardError::MaxClaimsReached.into());
-                }
-            }
-            RewardCondition::MaxPoints(max) => {
-                if space_reward.total_points >= *max {
-                    return Err(SpaceRewardError::MaxPointsReached.into());
-                }
-            }
-            RewardCondition::MaxUserClaims(max) => {
-                if current_user_claims >= *max {
-                    return Err(SpaceRewardError::MaxUserClaimsReached.into());
-                }
-            }
-            RewardCondition::MaxUserPoints(max) => {
-                if current_user_points >= *max {
-                    return Err(SpaceRewardError::MaxUserPointsReached.into());
-                }
-            }
-        }
+        let usage = RewardUsage {
+            total_claims: space_reward.total_claims,
+            total_points: space_reward.total_points,
+            user_claims: current_user_claims,
+            user_points: current_user_points,
+        };
+        space_reward.rules.check(&space_reward.condition, &usage)?;
 
-        // Apply Money Tree multiplier to the participant payout. Per spec
-        // FR14, this MUST NOT affect the creator owner-bonus (which is
-        // computed separately from the raw amount inside the Biyard service).
-        let raw_amount = space_reward.get_amount();
-        let mt_permille = {
-            use crate::features::character::leveling::multiplier_permille;
-            use crate::features::character::models::CharacterSkill;
-            use crate::features::character::types::SkillId;
-            CharacterSkill::level_or_zero(cli, &target_pk, SkillId::MoneyTree)
-                .await
-                .map(multiplier_permille)
-                .unwrap_or(1000)
+        let membership_tier = if space_reward.rules.needs_membership_tier() {
+            claimant_membership_tier(cli, &target_pk).await
+        } else {
+            None
+        };
+        let ctx = RewardClaimContext {
+            at: now,
+            usage,
+            membership_tier,
+        };
+
+        // Skill registry: Money Tree boosts the participant payout; the
+        // owner's Sweeper level sets their cut. Per spec FR14 the owner
+        // bonus is taken from the raw amount, never the boosted one.
+        use crate::features::character::services::apply_skill_effects;
+        use crate::features::character::skill_effects::SkillPipeline;
+        let raw_amount = space_reward.amount_for(&ctx);
+        let amount = if space_reward.rules.skill_multipliers {
+            apply_skill_effects(cli, &target_pk, SkillPipeline::RewardPayout, raw_amount).await
+        } else {
+            raw_amount
         };
-        let amount = crate::features::character::leveling::apply_permille(raw_amount, mt_permille);
         if amount > raw_amount {
             tracing::info!(
                 target_pk = %target_pk,
                 raw_amount,
-                mt_permille,
                 bonus = amount - raw_amount,
                 "money tree bonus applied"
             );
         }
+        let owner_amount = match owner_pk.as_ref() {
+            Some(owner) if *owner != target_pk => {
+                apply_skill_effects(cli, owner, SkillPipeline::OwnerBonus, raw_amount).await
+            }
+            _ => 0,
+        };
 
         let user_reward = if let Some(mut user_reward) = user_reward {
             txs.push(
@@ -257,7 +271,7 @@ impl SpaceReward {
         };
 
         // Scope-A balance: credit local `points` to the actor (and the
-        // owner's 10% bonus) in the same atomic transaction, routing to the
+        // owner bonus) in the same atomic transaction, routing to the
         // User row or the Team row by partition kind.
         let mut credit_points = |pk: &Partition, amt: i64| match pk {
             Partition::User(_) => txs.push(
@@ -283,7 +297,7 @@ impl SpaceReward {
         credit_points(&target_pk, amount);
         if let Some(ref owner) = owner_pk {
             if *owner != target_pk {
-                credit_points(owner, amount * 10 / 100);
+                credit_points(owner, owner_amount);
             }
         }
 
@@ -291,7 +305,7 @@ impl SpaceReward {
         txs.push(
             SpaceReward::updater(&space_pk, &space_reward.sk)
                 .increase_total_claims(1)
-                .increase_total_points(space_reward.get_amount())
+                .increase_total_points(raw_amount)
                 .with_updated_at(now)
                 .transact_write_item(),
         );
@@ -312,7 +326,7 @@ impl SpaceReward {
             target_pk.clone(),
             space_reward.sk.clone(),
             &space_reward.period,
-            space_reward.get_amount(),
+            raw_amount,
         );
         if !description.is_empty() {
             history.description = Some(description.clone());
@@ -322,7 +336,7 @@ impl SpaceReward {
         }
         txs.push(history.create_transact_write_item());
 
-        // Create UserRewardHistory for the OWNER bonus (10%) when the
+        // Create UserRewardHistory for the OWNER bonus when the
         // space is owned by someone other than the actor.
         //
         // Why this branch needs its own constructor: the actor row's
@@ -347,7 +361,6 @@ impl SpaceReward {
                     _ => String::new(),
                 };
                 if !actor_uid.is_empty() {
-                    let owner_amount = space_reward.get_amount() * 10 / 100;
                     let period_key = space_reward.period.to_time_key(now);
                     let mut owner_history = UserRewardHistory::from_params_with_time_key(
                         owner.clone(),
@@ -390,3 +403,37 @@ impl SpaceReward {
         Ok(user_reward)
     }
 }
+
+/// Membership tier of a user or team claimant; `None` without an active
+/// membership or on lookup failure, which leaves the payout unscaled.
+#[cfg(feature = "server")]
+async fn claimant_membership_tier(
+    cli: &aws_sdk_dynamodb::Client,
+    target_pk: &Partition,
+) -> Option<crate::features::membership::models::MembershipTier> {
+    use crate::features::membership::models::{
+        Membership, MembershipStatus, TeamMembership, UserMembership,
+    };
+
+    let membership_pk = match target_pk {
+        Partition::User(_) => UserMembership::get(cli, target_pk, Some(EntityType::UserMembership))
+            .await
+            .ok()
+            .flatten()
+            .filter(|m| m.status == MembershipStatus::Active)
+            .map(|m| m.membership_pk),
+        Partition::Team(_) => TeamMembership::get(cli, target_pk, Some(EntityType::TeamMembership))
+            .await
+            .ok()
+            .flatten()
+            .filter(|m| m.status == MembershipStatus::Active)
+            .map(|m| m.membership_pk),
+        _ => None,
+    }?;
+    let membership_pk: Partition = membership_pk.into();
+    Membership::get(cli, membership_pk, Some(EntityType::Membership))
+        .await
+        .ok()
+        .flatten()
+        .map(|m| m.tier)
+}
@@ -0,0 +1,107 @@
+//! `RoomChannel` for space lifecycle changes.
+//!
+//! Channel kind: `"space.status"`. ChannelId form:
+//! `space.status:{space_id}`. Anyone who can view the space may
+//! subscribe — the same rule `SpaceUserRole` applies to page loads.
+//!
+//! `status_changed` is published from the `SPACE_STATUS_CHANGE_EVENT#`
+//! stream branch with `{ old_status, new_status }`; open space pages
+//! refetch instead of waiting for a reload to notice a space went
+//! live or finished.
+
+use crate::common::models::auth::User;
+use crate::common::models::space::{SpaceCommon, SpaceStatusChangeEvent};
+use crate::common::realtime::channel::{ChannelContext, ChannelId, RoomChannel};
+use crate::common::realtime::hub::global_hub;
+use crate::common::realtime::RealtimeError;
+use crate::common::*;
+use async_trait::async_trait;
+
+pub const SPACE_STATUS_CHANNEL_KIND: &str = "space.status";
+
+pub fn space_status_channel_id(space_id: &str) -> ChannelId {
+    ChannelId::from_parts(SPACE_STATUS_CHANNEL_KIND, space_id)
+}
+
+/// Shared authorize rule for space-scoped channels: resolve the
+/// caller's `SpaceUserRole` and reject if the space is hidden from
+/// them.
+pub async fn authorize_space_viewer(
+    cli: &aws_sdk_dynamodb::Client,
+    ctx: &ChannelContext,
+    space_id: &str,
+) -> crate::common::Result<SpaceUserRole> {
+    if space_id.is_empty() {
+        return Err(RealtimeError::ChannelPayloadInvalid.into());
+    }
+
+    let space = SpaceCommon::get(
+        cli,
+        &Partition::Space(space_id.to_string()),
+        Some(&EntityType::SpaceCommon),
+    )
+    .await
+    .map_err(|e| {
+        crate::error!("space channel authorize: space lookup failed: {e}");
+        RealtimeError::StorageFailure
+    })?
+    .ok_or(RealtimeError::ChannelForbidden)?;
+
+    let user = User::get(
+        cli,
+        Partition::User(ctx.user_id.clone()),
+        Some(&EntityType::User),
+    )
+    .await
+    .map_err(|e| {
+        crate::error!("space channel authorize: user lookup failed: {e}");
+        RealtimeError::StorageFailure
+    })?;
+
+    SpaceUserRole::resolve(&space, user.as_ref())
+        .await
+        .map_err(|_| RealtimeError::ChannelForbidden.into())
+}
+
+pub async fn publish_space_status_changed(event: &SpaceStatusChangeEvent) {
+    let Partition::Space(space_id) = &event.space_pk else {
+        return;
+    };
+    let payload = serde_json::json!({
+        "old_status": event.old_status,
+        "new_status": event.new_status,
+    });
+    if let Err(e) = global_hub()
+        .publish(&space_status_channel_id(space_id), "status_changed", payload)
+        .await
+    {
+        crate::error!("space.status publish failed: {e}");
+    }
+}
+
+pub struct SpaceStatusChannel {
+    cli: aws_sdk_dynamodb::Client,
+}
+
+impl SpaceStatusChannel {
+    pub fn new(cli: aws_sdk_dynamodb::Client) -> Self {
+        Self { cli }
+    }
+}
+
+#[async_trait]
+impl RoomChannel for SpaceStatusChannel {
+    fn kind(&self) -> &'static str {
+        SPACE_STATUS_CHANNEL_KIND
+    }
+
+    async fn authorize(
+        &self,
+        ctx: &ChannelContext,
+        channel: &ChannelId,
+        _params: serde_json::Value,
+    ) -> crate::common::Result<serde_json::Value> {
+        authorize_space_viewer(&self.cli, ctx, channel.inner()).await?;
+        Ok(serde_json::Value::Null)
+    }
+}
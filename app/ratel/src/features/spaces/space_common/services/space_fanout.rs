@@ -7,7 +7,8 @@
 //!
 //! Eligibility for the Hot tab:
 //! - Space must be Public+Published.
-//! - Space must have at least `MIN_PARTICIPANTS_FOR_HOT` participants.
+//! - Space must have at least `MIN_PARTICIPANTS_FOR_HOT` participants,
+//!   lowered by the owner's Influencer skill.
 //!
 //! Anything else has its row deleted so it disappears from
 //! `list_hot_spaces` results. Every viewer sees the same `HSR#ALL` stream;
@@ -38,12 +39,13 @@ const ACTION_MULTIPLIER: u64 = 2;
 
 /// Minimum participants required for a space to surface in the Hot tab.
 /// Filters out empty/test spaces so the global stream stays signal-heavy.
+/// The owner's Influencer skill lowers it for their own spaces.
 pub const MIN_PARTICIPANTS_FOR_HOT: i64 = 10;
 
 /// Re-snapshot a space's hot ranking. Idempotent; safe to call repeatedly.
 ///
-/// - Public+Published spaces with `>= MIN_PARTICIPANTS_FOR_HOT` participants:
-///   upsert the HotSpace row.
+/// - Public+Published spaces with `>= MIN_PARTICIPANTS_FOR_HOT` participants
+///   (after the owner's skill adjustment): upsert the HotSpace row.
 /// - Everything else (private, draft, low participation, missing): delete the
 ///   row. Best-effort; missing-row deletes are ignored.
 pub async fn upsert_hot_space(cli: &aws_sdk_dynamodb::Client, space_pk: &Partition) {
@@ -65,7 +67,14 @@ pub async fn upsert_hot_space(cli: &aws_sdk_dynamodb::Client, space_pk: &Partiti
         }
     };
 
-    if !space.is_public() || space.participants < MIN_PARTICIPANTS_FOR_HOT {
+    let min_participants = crate::features::character::services::apply_skill_effects(
+        cli,
+        &space.user_pk,
+        crate::features::character::skill_effects::SkillPipeline::HotSpaceThreshold,
+        MIN_PARTICIPANTS_FOR_HOT,
+    )
+    .await;
+    if !space.is_public() || space.participants < min_participants {
         if let Err(e) = HotSpace::delete(cli, space_pk.clone(), Some(EntityType::HotSpace)).await {
             crate::warn!(
                 space_pk = %space_pk,
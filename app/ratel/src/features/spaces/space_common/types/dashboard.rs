@@ -145,7 +145,19 @@ pub struct RankingEntry {
     #[serde(default)]
     pub avatar: String,
     pub score: f64,
+    /// Places moved since the previous day; positive is up.
     #[serde(default)]
     pub change: i32,
 }
 
+impl From<crate::features::activity::controllers::LeaderboardEntryResponse> for RankingEntry {
+    fn from(entry: crate::features::activity::controllers::LeaderboardEntryResponse) -> Self {
+        Self {
+            rank: entry.rank,
+            name: entry.name,
+            avatar: entry.avatar,
+            score: entry.score as f64,
+            change: entry.rank_change.unwrap_or_default(),
+        }
+    }
+}
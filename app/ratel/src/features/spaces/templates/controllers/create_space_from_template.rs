@@ -0,0 +1,72 @@
+use crate::features::spaces::templates::*;
+use crate::*;
+
+#[mcp_tool(
+    name = "create_space_from_template",
+    description = "Create a space on an existing post from a space template. Copies the template's settings, actions (with dependencies remapped to the new action ids), apps, panel quotas, AI moderator and reward settings. Rewards are funded from your credits again; actions that cannot be funded are listed in unfunded_actions."
+)]
+#[post(
+    "/api/space-templates/{template_id}/spaces",
+    user: crate::features::auth::User
+)]
+pub async fn create_space_from_template(
+    #[mcp(description = "Template id from list_space_templates")]
+    template_id: SpaceTemplateEntityType,
+    #[mcp(description = "Post partition key to create the space on")] post_id: FeedPartition,
+) -> Result<CreateSpaceFromTemplateResponse> {
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+
+    let (pk, sk) = SpaceTemplate::keys(&template_id);
+    let template = SpaceTemplate::get(cli, &pk, Some(sk))
+        .await?
+        .ok_or(SpaceTemplateError::NotFound)?;
+    if !template.can_use(cli, &user.pk).await? {
+        return Err(SpaceTemplateError::NotAccessible.into());
+    }
+
+    let content = template.load_content(cli).await?;
+    let settings = &content.settings;
+    let (space, space_items) =
+        crate::features::posts::controllers::prepare_space_on_post(cli, &user, post_id, |space| {
+            space.content = settings.content.clone();
+            space.logo = settings.logo.clone();
+            space.anonymous_participation = settings.anonymous_participation;
+            space.join_anytime = settings.join_anytime;
+            space.custom_booster = settings.custom_booster;
+            space.rewards = settings.rewards;
+            if settings.quota > 0 {
+                space.quota = settings.quota;
+                space.remains = settings.quota;
+            }
+        })
+        .await?;
+
+    // Content first, space last: until the space row and post link land,
+    // a failed instantiation leaves nothing reachable on the post.
+    let pending = instantiate_template(cli, &user, &space, &content)
+        .await
+        .map_err(|e| {
+            crate::error!("failed to create space from template: {e:?}");
+            SpaceTemplateError::InstantiateFailed
+        })?;
+    crate::transact_write_items!(cli, space_items)?;
+
+    let created = finish_template(cli, &user, &space, pending)
+        .await
+        .map_err(|e| {
+            crate::error!("failed to finish space from template: {e:?}");
+            SpaceTemplateError::InstantiateFailed
+        })?;
+
+    let space_id = match space.pk {
+        Partition::Space(id) => SpacePartition(id),
+        _ => SpacePartition::default(),
+    };
+
+    Ok(CreateSpaceFromTemplateResponse {
+        space_id,
+        action_ids: created.action_ids,
+        unfunded_actions: created.unfunded_actions,
+    })
+}
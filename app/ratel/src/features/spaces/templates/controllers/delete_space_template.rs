@@ -0,0 +1,19 @@
+use crate::features::spaces::templates::*;
+use crate::*;
+
+#[delete("/api/space-templates/{template_id}", user: crate::features::auth::User)]
+pub async fn delete_space_template(template_id: SpaceTemplateEntityType) -> Result<()> {
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+
+    let (pk, sk) = SpaceTemplate::keys(&template_id);
+    let template = SpaceTemplate::get(cli, &pk, Some(sk))
+        .await?
+        .ok_or(SpaceTemplateError::NotFound)?;
+    if !template.can_manage(cli, &user.pk).await? {
+        return Err(SpaceTemplateError::NotAccessible.into());
+    }
+
+    template.delete_with_content(cli).await?;
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use crate::features::posts::types::TeamGroupPermission;
+use crate::features::spaces::templates::*;
+use crate::*;
+
+#[mcp_tool(
+    name = "list_space_templates",
+    description = "List space templates, newest first. Without team_id lists the user's own templates; with team_id lists the templates shared with that team."
+)]
+#[get("/api/space-templates?team_id", user: crate::features::auth::User)]
+pub async fn list_space_templates(
+    #[mcp(
+        description = "Team ID (e.g. 'TEAM#<uuid>') whose templates to list. Omit for your own."
+    )]
+    team_id: Option<TeamPartition>,
+) -> Result<Vec<SpaceTemplateSummary>> {
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+
+    let owner_pk: Partition = match team_id {
+        Some(team_id) => team_id.into(),
+        None => user.pk.clone(),
+    };
+    if !SpaceTemplate::can_access(cli, &owner_pk, &user.pk, TeamGroupPermission::SpaceRead).await? {
+        return Err(SpaceTemplateError::NotAccessible.into());
+    }
+
+    let (templates, _) = SpaceTemplate::find_by_owner(
+        cli,
+        &owner_pk,
+        SpaceTemplate::opt_all().scan_index_forward(false),
+    )
+    .await?;
+
+    Ok(templates.into_iter().map(Into::into).collect())
+}
@@ -0,0 +1,9 @@
+mod create_space_from_template;
+mod delete_space_template;
+mod list_space_templates;
+mod save_space_template;
+
+pub use create_space_from_template::*;
+pub use delete_space_template::*;
+pub use list_space_templates::*;
+pub use save_space_template::*;
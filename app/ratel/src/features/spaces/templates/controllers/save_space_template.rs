@@ -0,0 +1,57 @@
+use crate::common::models::space::SpaceCommon;
+use crate::features::posts::types::TeamGroupPermission;
+use crate::features::spaces::templates::*;
+use crate::*;
+
+#[mcp_tool(
+    name = "save_space_template",
+    description = "Save a space as a reusable template: its settings, every action with its poll/quiz/discussion/meet content and dependencies, installed apps, panel quotas, AI moderator and reward settings. Pass team_id to share the template with a team. Requires creator role."
+)]
+#[post(
+    "/api/spaces/{space_pk}/templates",
+    role: SpaceUserRole,
+    user: crate::features::auth::User,
+    space: SpaceCommon
+)]
+pub async fn save_space_template(
+    #[mcp(description = "Space partition key")] space_pk: SpacePartition,
+    #[mcp(description = "Template name")] name: String,
+    #[mcp(description = "What the template is for")] description: Option<String>,
+    #[mcp(
+        description = "Team ID (e.g. 'TEAM#<uuid>') to share the template with. Omit to keep it personal."
+    )]
+    team_id: Option<TeamPartition>,
+) -> Result<SpaceTemplateSummary> {
+    SpaceTemplate::can_save(&role)?;
+    let common_config = crate::common::CommonConfig::default();
+    let cli = common_config.dynamodb();
+
+    let name = name.trim().to_string();
+    if name.is_empty() {
+        return Err(SpaceTemplateError::NameRequired.into());
+    }
+
+    let owner_pk: Partition = match team_id {
+        Some(team_id) => team_id.into(),
+        None => user.pk.clone(),
+    };
+    if !SpaceTemplate::can_access(cli, &owner_pk, &user.pk, TeamGroupPermission::SpaceEdit).await? {
+        return Err(SpaceTemplateError::NotAccessible.into());
+    }
+
+    let content = snapshot_space(cli, &space).await?;
+    let template = SpaceTemplate::new(
+        owner_pk,
+        user.pk.clone(),
+        name,
+        description.unwrap_or_default(),
+        space_pk.into(),
+        &content,
+    );
+    template.save(cli, content).await.map_err(|e| {
+        crate::error!("failed to save space template: {e:?}");
+        SpaceTemplateError::SaveFailed
+    })?;
+
+    Ok(template.into())
+}
@@ -0,0 +1,11 @@
+pub mod controllers;
+pub mod models;
+#[cfg(feature = "server")]
+pub mod services;
+pub mod types;
+
+pub use controllers::*;
+pub use models::*;
+#[cfg(feature = "server")]
+pub use services::*;
+pub use types::*;
@@ -0,0 +1,7 @@
+mod space_template;
+mod space_template_action;
+mod space_template_body;
+
+pub use space_template::*;
+pub use space_template_action::*;
+pub use space_template_body::*;
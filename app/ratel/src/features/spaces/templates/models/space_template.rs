@@ -0,0 +1,225 @@
+use crate::features::posts::types::TeamGroupPermission;
+use crate::features::spaces::pages::actions::types::SpaceActionType;
+use crate::features::spaces::pages::apps::types::SpaceAppType;
+use crate::features::spaces::templates::*;
+use crate::features::spaces::*;
+
+/// A reusable snapshot of a space: settings, actions with their content,
+/// installed apps and panel quotas. This row only carries what a listing
+/// shows; the content lives in child rows under the same pk
+/// (`SpaceTemplateBody`, one `SpaceTemplateAction` per action).
+///
+/// - pk: SPACE_TEMPLATE#<uuid>
+/// - sk: SPACE_TEMPLATE#<uuid>
+/// - gsi1 pk: STPL#<owner_pk> (user or team the template belongs to)
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+pub struct SpaceTemplate {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    #[dynamo(index = "gsi1", sk)]
+    pub created_at: i64,
+    pub updated_at: i64,
+
+    #[dynamo(prefix = "STPL", name = "find_by_owner", index = "gsi1", pk)]
+    pub owner_pk: Partition,
+    pub created_by: Partition,
+
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub source_space_pk: Partition,
+
+    #[serde(default)]
+    pub action_types: Vec<SpaceActionType>,
+    #[serde(default)]
+    pub apps: Vec<SpaceAppType>,
+}
+
+#[cfg(feature = "server")]
+impl SpaceTemplate {
+    pub fn new(
+        owner_pk: Partition,
+        created_by: Partition,
+        name: String,
+        description: String,
+        source_space_pk: Partition,
+        content: &SpaceTemplateContent,
+    ) -> Self {
+        let now = crate::common::utils::time::get_now_timestamp_millis();
+        let id = uuid::Uuid::now_v7().to_string();
+        Self {
+            pk: Partition::SpaceTemplate(id.clone()),
+            sk: EntityType::SpaceTemplate(id),
+            created_at: now,
+            updated_at: now,
+            owner_pk,
+            created_by,
+            name,
+            description,
+            source_space_pk,
+            action_types: content
+                .actions
+                .iter()
+                .map(|action| action.action_type.clone())
+                .collect(),
+            apps: content.apps.clone(),
+        }
+    }
+
+    pub fn keys(template_id: &SpaceTemplateEntityType) -> (Partition, EntityType) {
+        (
+            Partition::SpaceTemplate(template_id.to_string()),
+            EntityType::SpaceTemplate(template_id.to_string()),
+        )
+    }
+
+    /// Write the template with `content`. Child rows go first and the
+    /// parent last, so a save that fails midway is never listed.
+    pub async fn save(
+        &self,
+        cli: &aws_sdk_dynamodb::Client,
+        content: SpaceTemplateContent,
+    ) -> crate::common::Result<()> {
+        let body = SpaceTemplateBody::new(self.pk.clone(), content.settings, content.panel_quotas);
+        let mut items = vec![body.create_transact_write_item()];
+        items.extend(
+            content
+                .actions
+                .into_iter()
+                .enumerate()
+                .map(|(position, action)| {
+                    SpaceTemplateAction::new(self.pk.clone(), position, action)
+                        .create_transact_write_item()
+                }),
+        );
+        crate::transact_write_all_items!(cli, items);
+
+        self.create(cli).await?;
+        Ok(())
+    }
+
+    /// Reassemble the content from the child rows, actions in saved order.
+    pub async fn load_content(
+        &self,
+        cli: &aws_sdk_dynamodb::Client,
+    ) -> crate::common::Result<SpaceTemplateContent> {
+        let body = SpaceTemplateBody::get(cli, &self.pk, Some(EntityType::SpaceTemplateBody))
+            .await?
+            .unwrap_or_default();
+        let (actions, _) = SpaceTemplateAction::query(
+            cli,
+            &self.pk,
+            SpaceTemplateAction::opt_all()
+                .sk("SPACE_TEMPLATE_ACTION#".to_string())
+                .scan_index_forward(true),
+        )
+        .await?;
+
+        Ok(SpaceTemplateContent {
+            settings: body.settings,
+            actions: actions.into_iter().map(|row| row.action).collect(),
+            apps: self.apps.clone(),
+            panel_quotas: body.panel_quotas,
+        })
+    }
+
+    /// Delete the parent first so the template disappears from listings at
+    /// once, then its child rows.
+    pub async fn delete_with_content(
+        &self,
+        cli: &aws_sdk_dynamodb::Client,
+    ) -> crate::common::Result<()> {
+        Self::delete(cli, &self.pk, Some(self.sk.clone())).await?;
+
+        let (actions, _) = SpaceTemplateAction::query(
+            cli,
+            &self.pk,
+            SpaceTemplateAction::opt_all().sk("SPACE_TEMPLATE_ACTION#".to_string()),
+        )
+        .await?;
+        let mut items = vec![SpaceTemplateBody::delete_transact_write_item(
+            &self.pk,
+            EntityType::SpaceTemplateBody,
+        )];
+        items.extend(
+            actions
+                .into_iter()
+                .map(|row| SpaceTemplateAction::delete_transact_write_item(row.pk, row.sk)),
+        );
+        crate::transact_write_all_items!(cli, items);
+        Ok(())
+    }
+
+    /// Whether `user_pk` may use the template: its owner, or any member of
+    /// the owning team with space-write access.
+    pub async fn can_use(
+        &self,
+        cli: &aws_sdk_dynamodb::Client,
+        user_pk: &Partition,
+    ) -> crate::common::Result<bool> {
+        Self::can_access(
+            cli,
+            &self.owner_pk,
+            user_pk,
+            TeamGroupPermission::SpaceWrite,
+        )
+        .await
+    }
+
+    /// Whether `user_pk` may edit or delete the template.
+    pub async fn can_manage(
+        &self,
+        cli: &aws_sdk_dynamodb::Client,
+        user_pk: &Partition,
+    ) -> crate::common::Result<bool> {
+        Self::can_access(cli, &self.owner_pk, user_pk, TeamGroupPermission::SpaceEdit).await
+    }
+
+    /// Whether `user_pk` may act for `owner_pk` with `perm`.
+    pub async fn can_access(
+        cli: &aws_sdk_dynamodb::Client,
+        owner_pk: &Partition,
+        user_pk: &Partition,
+        perm: TeamGroupPermission,
+    ) -> crate::common::Result<bool> {
+        match owner_pk {
+            Partition::Team(_) => {
+                crate::features::posts::models::Team::has_permission(cli, owner_pk, user_pk, perm)
+                    .await
+            }
+            _ => Ok(owner_pk == user_pk),
+        }
+    }
+}
+
+impl SpaceTemplate {
+    pub fn can_save(role: &SpaceUserRole) -> crate::common::Result<()> {
+        match role {
+            SpaceUserRole::Creator => Ok(()),
+            _ => Err(Error::NoPermission),
+        }
+    }
+}
+
+impl From<SpaceTemplate> for SpaceTemplateSummary {
+    fn from(template: SpaceTemplate) -> Self {
+        let template_id = match &template.sk {
+            EntityType::SpaceTemplate(id) => id.clone(),
+            _ => String::new(),
+        };
+        Self {
+            template_id,
+            name: template.name,
+            description: template.description,
+            owner_pk: template.owner_pk,
+            created_by: template.created_by,
+            action_count: template.action_types.len(),
+            action_types: template.action_types,
+            apps: template.apps,
+            created_at: template.created_at,
+            updated_at: template.updated_at,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::features::spaces::templates::*;
+use crate::features::spaces::*;
+
+/// One captured action of a `SpaceTemplate`. A row per action keeps each
+/// item well under DynamoDB's size limit however many actions the source
+/// space had.
+///
+/// - pk: SPACE_TEMPLATE#<uuid>
+/// - sk: SPACE_TEMPLATE_ACTION#<position>#<source action id>
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+pub struct SpaceTemplateAction {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    pub action: TemplateAction,
+}
+
+#[cfg(feature = "server")]
+impl SpaceTemplateAction {
+    /// `position` is zero-padded into the sk so a query returns actions in
+    /// the order they were captured.
+    pub fn new(template_pk: Partition, position: usize, action: TemplateAction) -> Self {
+        Self {
+            pk: template_pk,
+            sk: EntityType::SpaceTemplateAction(format!("{position:04}#{}", action.action_id)),
+            action,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::features::spaces::templates::*;
+use crate::features::spaces::*;
+
+/// Settings and panel quotas of a `SpaceTemplate`, kept off the parent so
+/// listings stay small.
+///
+/// - pk: SPACE_TEMPLATE#<uuid>
+/// - sk: SPACE_TEMPLATE_BODY
+#[derive(Debug, Default, Clone, Serialize, Deserialize, DynamoEntity, PartialEq)]
+pub struct SpaceTemplateBody {
+    pub pk: Partition,
+    pub sk: EntityType,
+
+    #[serde(default)]
+    pub settings: SpaceTemplateSettings,
+    #[serde(default)]
+    pub panel_quotas: Vec<TemplatePanelQuota>,
+}
+
+#[cfg(feature = "server")]
+impl SpaceTemplateBody {
+    pub fn new(
+        template_pk: Partition,
+        settings: SpaceTemplateSettings,
+        panel_quotas: Vec<TemplatePanelQuota>,
+    ) -> Self {
+        Self {
+            pk: template_pk,
+            sk: EntityType::SpaceTemplateBody,
+            settings,
+            panel_quotas,
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::TransactWriteItem;
+
+use crate::common::models::space::{SpaceCommon, SpaceUser};
+use crate::features::ai_moderator::AiModeratorConfig;
+use crate::features::auth::User;
+use crate::features::spaces::models::SpacePanelQuota;
+use crate::features::spaces::pages::actions::actions::discussion::{
+    SpacePost, SpacePostSubscription,
+};
+use crate::features::spaces::pages::actions::actions::follow::SpaceFollowAction;
+use crate::features::spaces::pages::actions::actions::meet::SpaceMeet;
+use crate::features::spaces::pages::actions::actions::poll::SpacePoll;
+use crate::features::spaces::pages::actions::actions::quiz::{SpaceQuiz, SpaceQuizAnswer};
+use crate::features::spaces::pages::actions::controllers::{set_credits, set_reward_rules};
+use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::pages::apps::models::SpaceApp;
+use crate::features::spaces::space_common::models::aggregate::DashboardAggregate;
+use crate::features::spaces::space_common::models::SpaceReward;
+use crate::features::spaces::templates::*;
+use crate::features::spaces::*;
+
+/// What `instantiate_template` created.
+pub struct InstantiatedTemplate {
+    /// Source action id → new action id.
+    pub action_ids: HashMap<String, String>,
+    /// New action ids whose reward could not be funded.
+    pub unfunded_actions: Vec<String>,
+}
+
+/// Content rows of a template already written under a new space id,
+/// waiting for the space itself; see [`finish_template`].
+pub struct PendingTemplate<'a> {
+    action_ids: HashMap<String, String>,
+    actions: Vec<(SpaceAction, &'a TemplateAction)>,
+    polls: i64,
+    posts: i64,
+    meets: i64,
+}
+
+/// Fill the not-yet-written `space` with `content`: actions and their
+/// content under new ids, apps and panel quotas. Everything is built and
+/// validated before the first write, and nothing here needs the space row,
+/// so callers write the space (and its post link) only once this succeeds.
+pub async fn instantiate_template<'a>(
+    cli: &aws_sdk_dynamodb::Client,
+    user: &User,
+    space: &SpaceCommon,
+    content: &'a SpaceTemplateContent,
+) -> crate::common::Result<PendingTemplate<'a>> {
+    let space_id = match &space.pk {
+        Partition::Space(id) => SpacePartition(id.clone()),
+        _ => return Err(SpaceTemplateError::InstantiateFailed.into()),
+    };
+    let member = SpaceUser::from(user.clone());
+
+    let mut items: Vec<TransactWriteItem> = Vec::new();
+    let mut action_ids = HashMap::new();
+    let mut actions = Vec::with_capacity(content.actions.len());
+    let (mut polls, mut posts, mut meets) = (0, 0, 0);
+
+    for template_action in &content.actions {
+        let action_id = match &template_action.payload {
+            TemplateActionPayload::Poll {
+                title,
+                description,
+                response_editable,
+                questions,
+                canister_upload_enabled,
+            } => {
+                let mut poll = SpacePoll::new(space_id.clone())?;
+                poll.title = title.clone();
+                poll.description = description.clone();
+                poll.response_editable = *response_editable;
+                poll.questions = questions.clone();
+                poll.canister_upload_enabled = *canister_upload_enabled;
+                items.push(poll.create_transact_write_item());
+                polls += 1;
+                SpacePollEntityType::from(poll.sk).to_string()
+            }
+            TemplateActionPayload::Quiz {
+                retry_count,
+                pass_score,
+                questions,
+                answers,
+                files,
+                draw_count,
+                shuffle_options,
+                time_limit_seconds,
+                partial_credit,
+            } => {
+                let mut quiz = SpaceQuiz::new(space_id.clone())?;
+                quiz.retry_count = *retry_count;
+                quiz.pass_score = *pass_score;
+                quiz.questions = questions.clone();
+                quiz.files = files.clone();
+                quiz.draw_count = *draw_count;
+                quiz.shuffle_options = *shuffle_options;
+                quiz.time_limit_seconds = *time_limit_seconds;
+                quiz.partial_credit = *partial_credit;
+                let quiz_id = SpaceQuizEntityType::from(quiz.sk.clone());
+                let answer =
+                    SpaceQuizAnswer::new(space_id.clone(), quiz_id.clone(), answers.clone());
+                items.push(quiz.create_transact_write_item());
+                items.push(answer.create_transact_write_item());
+                quiz_id.to_string()
+            }
+            TemplateActionPayload::Discussion {
+                title,
+                html_contents,
+                category_name,
+                files,
+                ai_moderator,
+            } => {
+                let mut post = SpacePost::new(
+                    space_id.clone(),
+                    title.clone(),
+                    html_contents.clone(),
+                    category_name.clone(),
+                    &member,
+                    None,
+                    None,
+                );
+                post.files = files.clone();
+                let discussion_id = SpacePostEntityType::from(post.sk.clone()).to_string();
+                // Same author subscription `create_discussion` sets up.
+                let subscription = SpacePostSubscription::new(
+                    SpacePostPartition(discussion_id.clone()),
+                    space_id.clone(),
+                    &member.pk,
+                );
+                items.push(post.create_transact_write_item());
+                items.push(subscription.create_transact_write_item());
+                if let Some(moderator) = ai_moderator {
+                    let mut config =
+                        AiModeratorConfig::new(space_id.clone(), discussion_id.clone());
+                    config.enabled = moderator.enabled;
+                    config.reply_interval = moderator.reply_interval;
+                    config.guidelines = moderator.guidelines.clone();
+                    items.push(config.create_transact_write_item());
+                }
+                posts += 1;
+                discussion_id
+            }
+            TemplateActionPayload::Meet { mode, duration_min } => {
+                let mut meet = SpaceMeet::new(space_id.clone())?;
+                meet.mode = mode.clone();
+                meet.duration_min = *duration_min;
+                items.push(meet.create_transact_write_item());
+                meets += 1;
+                SpaceMeetEntityType::from(meet.sk).to_string()
+            }
+            TemplateActionPayload::Follow => {
+                let follow = SpaceFollowAction::new(space_id.clone());
+                items.push(follow.create_transact_write_item());
+                SpaceActionFollowEntityType::from(follow.sk).to_string()
+            }
+        };
+
+        let mut action = SpaceAction::new(
+            space_id.clone(),
+            action_id.clone(),
+            template_action.action_type.clone(),
+        );
+        action.title = match template_action.payload {
+            // A follow action is titled after the space's author.
+            TemplateActionPayload::Follow if space.author_display_name.is_empty() => {
+                space.author_username.clone()
+            }
+            TemplateActionPayload::Follow => space.author_display_name.clone(),
+            _ => template_action.title.clone(),
+        };
+        action.description = template_action.description.clone();
+        action.prerequisite = template_action.prerequisite;
+        action_ids.insert(template_action.action_id.clone(), action_id);
+        actions.push((action, template_action));
+    }
+
+    for (action, template_action) in actions.iter_mut() {
+        let (depends_on, conditions) = template_action.remap_dependencies(&action_ids);
+        action.depends_on = depends_on;
+        action.dependency_conditions = conditions;
+        items.push(action.create_transact_write_item());
+    }
+
+    for app_type in &content.apps {
+        let app = SpaceApp::new(space.pk.clone(), app_type.clone());
+        items.push(app.create_transact_write_item());
+    }
+    for panel in &content.panel_quotas {
+        let quota = SpacePanelQuota::new_with_attributes_vec(
+            space.pk.clone(),
+            panel.quotas,
+            panel.attributes_vec.clone(),
+        );
+        items.push(quota.create_transact_write_item());
+    }
+
+    crate::transact_write_all_items!(cli, items);
+
+    Ok(PendingTemplate {
+        action_ids,
+        actions,
+        polls,
+        posts,
+        meets,
+    })
+}
+
+/// Second half of [`instantiate_template`], once the space exists: the
+/// dashboard counters and rewards. Rewards are funded from `user`'s (or
+/// the owning team's) credits like a manual credit update; an action whose
+/// reward cannot be funded is kept without one.
+pub async fn finish_template(
+    cli: &aws_sdk_dynamodb::Client,
+    user: &User,
+    space: &SpaceCommon,
+    pending: PendingTemplate<'_>,
+) -> crate::common::Result<InstantiatedTemplate> {
+    let PendingTemplate {
+        action_ids,
+        actions,
+        polls,
+        posts,
+        meets,
+    } = pending;
+    let space_id = match &space.pk {
+        Partition::Space(id) => SpacePartition(id.clone()),
+        _ => return Err(SpaceTemplateError::InstantiateFailed.into()),
+    };
+
+    // All counters live on one aggregate row, which a transaction may
+    // touch only once, so each bump is written on its own.
+    let _ = DashboardAggregate::get_or_create(cli, &space.pk).await?;
+    for item in [
+        (polls > 0).then(|| DashboardAggregate::inc_polls(&space.pk, polls)),
+        (posts > 0).then(|| DashboardAggregate::inc_posts(&space.pk, posts)),
+        (meets > 0).then(|| DashboardAggregate::inc_meets(&space.pk, meets)),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        crate::transact_write_items!(cli, vec![item])?;
+    }
+
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let mut unfunded_actions = Vec::new();
+    for (mut action, template_action) in actions {
+        if template_action.credits == 0 {
+            continue;
+        }
+        let action_id = action.pk.1.clone();
+        let pk = action.pk.clone();
+        let behavior = action.space_action_type.to_behavior();
+        if let Err(e) = set_credits(
+            cli,
+            user,
+            space,
+            &space_id,
+            &action_id,
+            &pk,
+            template_action.credits,
+            &mut action,
+            &behavior,
+            now,
+        )
+        .await
+        {
+            crate::warn!("template reward for action {action_id} not funded: {e:?}");
+            unfunded_actions.push(action_id);
+            continue;
+        }
+
+        if let Some(rules) = &template_action.reward_rules {
+            // Rules are charged for like credits; a space that can't
+            // cover them keeps the flat reward.
+            let reward =
+                SpaceReward::get_by_action(cli, space_id.clone(), action_id.clone(), behavior)
+                    .await?;
+            if let Err(e) = set_reward_rules(cli, user, space, &reward, rules.clone(), now).await {
+                crate::warn!("template reward rules for action {action_id} not funded: {e:?}");
+                unfunded_actions.push(action_id);
+            }
+        }
+    }
+
+    Ok(InstantiatedTemplate {
+        action_ids,
+        unfunded_actions,
+    })
+}
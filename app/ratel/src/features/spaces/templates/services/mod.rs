@@ -0,0 +1,5 @@
+mod instantiate;
+mod snapshot;
+
+pub use instantiate::*;
+pub use snapshot::*;
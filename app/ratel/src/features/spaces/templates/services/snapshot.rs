@@ -0,0 +1,181 @@
+use crate::common::models::space::SpaceCommon;
+use crate::features::ai_moderator::AiModeratorConfig;
+use crate::features::spaces::models::SpacePanelQuota;
+use crate::features::spaces::pages::actions::actions::discussion::SpacePost;
+use crate::features::spaces::pages::actions::actions::meet::SpaceMeet;
+use crate::features::spaces::pages::actions::actions::poll::SpacePoll;
+use crate::features::spaces::pages::actions::actions::quiz::{SpaceQuiz, SpaceQuizAnswer};
+use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::pages::actions::types::SpaceActionType;
+use crate::features::spaces::pages::apps::models::SpaceApp;
+use crate::features::spaces::space_common::models::SpaceReward;
+use crate::features::spaces::templates::*;
+use crate::features::spaces::*;
+
+/// Capture everything a template needs from `space`. Actions whose
+/// content row is missing (half-deleted) are skipped.
+pub async fn snapshot_space(
+    cli: &aws_sdk_dynamodb::Client,
+    space: &SpaceCommon,
+) -> crate::common::Result<SpaceTemplateContent> {
+    let space_id = match &space.pk {
+        Partition::Space(id) => SpacePartition(id.clone()),
+        _ => return Err(SpaceTemplateError::NotFound.into()),
+    };
+
+    let (actions, _) =
+        SpaceAction::find_by_space(cli, &space.pk, SpaceAction::opt_all().oldest()).await?;
+    let rewards = SpaceReward::list_by_action(cli, space_id.clone(), None).await?;
+
+    let mut template_actions = Vec::with_capacity(actions.len());
+    for action in actions {
+        let action_id = action.pk.1.clone();
+        let Some(payload) = snapshot_payload(cli, &space.pk, &space_id, &action).await? else {
+            crate::warn!("skipping action {action_id} without content in template snapshot");
+            continue;
+        };
+        let reward_rules = rewards
+            .iter()
+            .find(|reward| reward.sk.action_id.as_deref() == Some(action_id.as_str()))
+            .map(|reward| reward.rules.clone());
+
+        template_actions.push(TemplateAction {
+            action_id,
+            action_type: action.space_action_type,
+            title: action.title,
+            description: action.description,
+            prerequisite: action.prerequisite,
+            depends_on: action.depends_on,
+            dependency_conditions: action.dependency_conditions,
+            credits: action.credits,
+            reward_rules,
+            payload,
+        });
+    }
+
+    let (apps, _) = SpaceApp::query(
+        cli,
+        &space.pk,
+        SpaceApp::opt_all()
+            .sk(SpaceApp::sk_prefix())
+            .scan_index_forward(true),
+    )
+    .await?;
+
+    let (panel_quotas, _) = SpacePanelQuota::query(
+        cli,
+        CompositePartition(space.pk.clone(), Partition::PanelAttribute),
+        SpacePanelQuota::opt_all()
+            .sk("SPACE_PANEL_ATTRIBUTE#".to_string())
+            .scan_index_forward(true),
+    )
+    .await?;
+
+    Ok(SpaceTemplateContent {
+        settings: SpaceTemplateSettings {
+            content: space.content.clone(),
+            logo: space.logo.clone(),
+            anonymous_participation: space.anonymous_participation,
+            join_anytime: space.join_anytime,
+            quota: space.quota,
+            custom_booster: space.custom_booster,
+            rewards: space.rewards,
+        },
+        actions: template_actions,
+        apps: apps.into_iter().map(|app| app.app_type).collect(),
+        panel_quotas: panel_quotas
+            .into_iter()
+            .map(|panel| TemplatePanelQuota {
+                quotas: panel.quotas,
+                // Rows written before `attributes_vec` only carry `attributes`.
+                attributes_vec: if panel.attributes_vec.is_empty()
+                    && !matches!(panel.attributes, PanelAttribute::None)
+                {
+                    vec![panel.attributes]
+                } else {
+                    panel.attributes_vec
+                },
+            })
+            .collect(),
+    })
+}
+
+async fn snapshot_payload(
+    cli: &aws_sdk_dynamodb::Client,
+    space_pk: &Partition,
+    space_id: &SpacePartition,
+    action: &SpaceAction,
+) -> crate::common::Result<Option<TemplateActionPayload>> {
+    let action_id = action.pk.1.clone();
+
+    let payload = match action.space_action_type {
+        SpaceActionType::Poll => {
+            let sk = EntityType::SpacePoll(action_id);
+            SpacePoll::get(cli, space_pk, Some(sk))
+                .await?
+                .map(|poll| TemplateActionPayload::Poll {
+                    title: poll.title,
+                    description: poll.description,
+                    response_editable: poll.response_editable,
+                    questions: poll.questions,
+                    canister_upload_enabled: poll.canister_upload_enabled,
+                })
+        }
+        SpaceActionType::Quiz => {
+            let quiz_sk = EntityType::SpaceQuiz(action_id.clone());
+            let Some(quiz) = SpaceQuiz::get(cli, space_pk, Some(quiz_sk)).await? else {
+                return Ok(None);
+            };
+            let answer_sk = EntityType::SpaceQuizAnswer(action_id);
+            let answers = SpaceQuizAnswer::get(cli, space_pk, Some(answer_sk))
+                .await?
+                .map(|answer| answer.answers)
+                .unwrap_or_default();
+            Some(TemplateActionPayload::Quiz {
+                retry_count: quiz.retry_count,
+                pass_score: quiz.pass_score,
+                questions: quiz.questions,
+                answers,
+                files: quiz.files,
+                draw_count: quiz.draw_count,
+                shuffle_options: quiz.shuffle_options,
+                time_limit_seconds: quiz.time_limit_seconds,
+                partial_credit: quiz.partial_credit,
+            })
+        }
+        SpaceActionType::TopicDiscussion => {
+            let post_sk = EntityType::SpacePost(action_id.clone());
+            let Some(post) = SpacePost::get(cli, space_pk, Some(post_sk)).await? else {
+                return Ok(None);
+            };
+            let moderator_pk = CompositePartition(space_id.clone(), action_id);
+            let ai_moderator =
+                AiModeratorConfig::get(cli, &moderator_pk, Some(EntityType::AiModeratorConfig))
+                    .await?
+                    .map(|config| TemplateAiModerator {
+                        enabled: config.enabled,
+                        reply_interval: config.reply_interval,
+                        guidelines: config.guidelines,
+                    });
+            Some(TemplateActionPayload::Discussion {
+                title: post.title,
+                html_contents: post.html_contents,
+                category_name: post.category_name,
+                files: post.files,
+                ai_moderator,
+            })
+        }
+        SpaceActionType::Meet => {
+            let sk = EntityType::SpaceMeet(action_id);
+            SpaceMeet::get(cli, space_pk, Some(sk))
+                .await?
+                .map(|meet| TemplateActionPayload::Meet {
+                    mode: meet.mode,
+                    duration_min: meet.duration_min,
+                })
+        }
+        SpaceActionType::Follow => Some(TemplateActionPayload::Follow),
+    };
+
+    Ok(payload)
+}
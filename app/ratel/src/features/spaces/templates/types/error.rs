@@ -0,0 +1,64 @@
+use crate::common::*;
+pub use thiserror::Error;
+
+#[derive(Debug, Error, Serialize, Deserialize, Translate, Clone)]
+pub enum SpaceTemplateError {
+    #[error("space template not found")]
+    #[translate(en = "Template not found", ko = "템플릿을 찾을 수 없습니다")]
+    NotFound,
+
+    #[error("template name is empty")]
+    #[translate(en = "Give the template a name", ko = "템플릿 이름을 입력해 주세요")]
+    NameRequired,
+
+    #[error("not allowed to use this template")]
+    #[translate(
+        en = "You don't have access to this template",
+        ko = "이 템플릿에 접근할 권한이 없습니다"
+    )]
+    NotAccessible,
+
+    #[error("failed to save space template")]
+    #[translate(
+        en = "Failed to save the template",
+        ko = "템플릿을 저장하지 못했습니다"
+    )]
+    SaveFailed,
+
+    #[error("failed to create space from template")]
+    #[translate(
+        en = "Failed to create a space from the template",
+        ko = "템플릿으로 스페이스를 만들지 못했습니다"
+    )]
+    InstantiateFailed,
+}
+
+#[cfg(feature = "server")]
+impl SpaceTemplateError {
+    pub fn status_code(&self) -> crate::axum::http::StatusCode {
+        use crate::axum::http::StatusCode;
+        match self {
+            SpaceTemplateError::NotFound => StatusCode::NOT_FOUND,
+            SpaceTemplateError::NameRequired => StatusCode::BAD_REQUEST,
+            SpaceTemplateError::NotAccessible => StatusCode::FORBIDDEN,
+            SpaceTemplateError::SaveFailed | SpaceTemplateError::InstantiateFailed => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::axum::response::IntoResponse for SpaceTemplateError {
+    fn into_response(self) -> crate::axum::response::Response {
+        use crate::axum::response::IntoResponse;
+        (self.status_code(), self.to_string()).into_response()
+    }
+}
+
+#[cfg(feature = "server")]
+impl dioxus::fullstack::AsStatusCode for SpaceTemplateError {
+    fn as_status_code(&self) -> crate::axum::http::StatusCode {
+        self.status_code()
+    }
+}
@@ -0,0 +1,5 @@
+mod error;
+mod template;
+
+pub use error::*;
+pub use template::*;
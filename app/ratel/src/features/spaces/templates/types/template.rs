@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::features::spaces::models::PanelAttribute;
+use crate::features::spaces::pages::actions::actions::meet::MeetMode;
+use crate::features::spaces::pages::actions::actions::poll::Question;
+use crate::features::spaces::pages::actions::actions::quiz::QuizCorrectAnswer;
+use crate::features::spaces::pages::actions::types::{ActionDependency, SpaceActionType};
+use crate::features::spaces::pages::apps::types::SpaceAppType;
+use crate::features::spaces::*;
+
+/// `SpaceCommon` settings a new space inherits. Status, visibility and
+/// publish state are left out: every cloned space starts as a draft.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct SpaceTemplateSettings {
+    #[serde(default)]
+    pub content: String,
+    #[serde(default)]
+    pub logo: String,
+    #[serde(default)]
+    pub anonymous_participation: bool,
+    #[serde(default)]
+    pub join_anytime: bool,
+    #[serde(default)]
+    pub quota: i64,
+    #[serde(default)]
+    pub custom_booster: Option<i64>,
+    #[serde(default)]
+    pub rewards: Option<i64>,
+}
+
+/// One action of the source space. `action_id` is its id there and only
+/// serves to remap `depends_on` onto the ids the new space hands out.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct TemplateAction {
+    pub action_id: String,
+    pub action_type: SpaceActionType,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub prerequisite: bool,
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub dependency_conditions: Vec<ActionDependency>,
+    /// Reward credits; charged to the new space owner's membership again.
+    #[serde(default)]
+    pub credits: u64,
+    #[serde(default)]
+    pub reward_rules: Option<RewardRules>,
+    pub payload: TemplateActionPayload,
+}
+
+/// Type-specific content of a `TemplateAction`. Responses, counters and
+/// schedule state are never copied.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TemplateActionPayload {
+    Poll {
+        title: String,
+        description: String,
+        response_editable: bool,
+        #[serde(default)]
+        questions: Vec<Question>,
+        #[serde(default)]
+        canister_upload_enabled: bool,
+    },
+    Quiz {
+        retry_count: i64,
+        pass_score: i64,
+        #[serde(default)]
+        questions: Vec<Question>,
+        #[serde(default)]
+        answers: Vec<QuizCorrectAnswer>,
+        #[serde(default)]
+        files: Vec<File>,
+        #[serde(default)]
+        draw_count: i64,
+        #[serde(default)]
+        shuffle_options: bool,
+        #[serde(default)]
+        time_limit_seconds: i64,
+        #[serde(default)]
+        partial_credit: bool,
+    },
+    Discussion {
+        title: String,
+        html_contents: String,
+        #[serde(default)]
+        category_name: String,
+        #[serde(default)]
+        files: Vec<File>,
+        #[serde(default)]
+        ai_moderator: Option<TemplateAiModerator>,
+    },
+    Meet {
+        mode: MeetMode,
+        duration_min: i32,
+    },
+    #[default]
+    Follow,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct TemplateAiModerator {
+    pub enabled: bool,
+    pub reply_interval: i64,
+    pub guidelines: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct TemplatePanelQuota {
+    pub quotas: i64,
+    #[serde(default)]
+    pub attributes_vec: Vec<PanelAttribute>,
+}
+
+/// Everything a template carries besides its own metadata.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct SpaceTemplateContent {
+    #[serde(default)]
+    pub settings: SpaceTemplateSettings,
+    #[serde(default)]
+    pub actions: Vec<TemplateAction>,
+    #[serde(default)]
+    pub apps: Vec<SpaceAppType>,
+    #[serde(default)]
+    pub panel_quotas: Vec<TemplatePanelQuota>,
+}
+
+impl TemplateAction {
+    /// `depends_on` and its rules rewritten through `ids` (old action id →
+    /// new one). Dependencies on actions the template did not capture are
+    /// dropped along with their rules.
+    pub fn remap_dependencies(
+        &self,
+        ids: &HashMap<String, String>,
+    ) -> (Vec<String>, Vec<ActionDependency>) {
+        let depends_on = self
+            .depends_on
+            .iter()
+            .filter_map(|id| ids.get(id).cloned())
+            .collect();
+        let conditions = self
+            .dependency_conditions
+            .iter()
+            .filter_map(|dependency| {
+                ids.get(&dependency.action_id).map(|id| ActionDependency {
+                    action_id: id.clone(),
+                    condition: dependency.condition.clone(),
+                })
+            })
+            .collect();
+        (depends_on, conditions)
+    }
+}
+
+/// Listing entry for a template; the content itself stays server-side.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct SpaceTemplateSummary {
+    pub template_id: String,
+    pub name: String,
+    pub description: String,
+    /// The user or team the template belongs to.
+    pub owner_pk: Partition,
+    pub created_by: Partition,
+    pub action_count: usize,
+    pub action_types: Vec<SpaceActionType>,
+    pub apps: Vec<SpaceAppType>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct CreateSpaceFromTemplateResponse {
+    pub space_id: SpacePartition,
+    /// New action id for each source action id of the template.
+    pub action_ids: HashMap<String, String>,
+    /// New action ids whose reward could not be funded (e.g. not enough
+    /// credits); they were created without a reward.
+    #[serde(default)]
+    pub unfunded_actions: Vec<String>,
+}
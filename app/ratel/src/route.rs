@@ -1,5 +1,6 @@
 use crate::*;
 
+use crate::features::badge::pages::BadgeVaultPage;
 use crate::features::character::pages::CharacterPage;
 use crate::features::essence::EssenceSourcesPage;
 use crate::features::me::pages::MyAiPage;
@@ -46,8 +47,8 @@ use crate::features::admin::{AdminLayout, AdminMainPage};
 // `ArcadeLayout` (top-bar + chip wallet) under `/arcade`.
 use crate::features::arcade::games::fact_or_fold::pages::{
     FactFoldAdminSubjectsPage, FactFoldAdminLayout, FactFoldAdminNewSubjectPage,
-    FactFoldAdminReportsPage, FactFoldAdminSchedulePage, FactFoldAdminSettingsPage,
-    FactFoldAdminStatsPage, FactFoldGameRoomPage, FactFoldMatchingPage,
+    FactFoldAdminReportsPage, FactFoldAdminReviewsPage, FactFoldAdminSchedulePage,
+    FactFoldAdminSettingsPage, FactFoldAdminStatsPage, FactFoldGameRoomPage, FactFoldMatchingPage,
 };
 use crate::features::arcade::layout::ArcadeLayout;
 use crate::features::arcade::pages::{ArcadeHomePage, ArcadeLeaderboardPage};
@@ -151,6 +152,9 @@ pub enum Route {
         #[route("/me/character")]
         CharacterPage { },
 
+        #[route("/badges")]
+        BadgeVaultPage { },
+
         #[nest("/arcade")]
             #[layout(ArcadeLayout)]
                 #[route("/home")]
@@ -181,6 +185,8 @@ pub enum Route {
                         FactFoldAdminStatsPage {},
                         #[route("/reports")]
                         FactFoldAdminReportsPage {},
+                        #[route("/reviews")]
+                        FactFoldAdminReviewsPage {},
                         #[route("/settings")]
                         FactFoldAdminSettingsPage {},
                     #[end_layout]
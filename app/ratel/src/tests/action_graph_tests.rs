@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use super::*;
+use crate::common::models::space::SpaceCommon;
+use crate::common::types::{
+    CompositePartition, EntityType, Partition, SpacePartition, SpacePublishState, SpaceStatus,
+    SpaceVisibility,
+};
+use crate::features::spaces::pages::actions::actions::poll::Answer;
+use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::pages::actions::types::{
+    answer_picks_option, find_dependency_cycle, split_dependencies, ActionDependency, ActionGraph,
+    SpaceActionStatus, SpaceActionSummary, SpaceActionType, UnlockCondition,
+};
+
+fn summary(id: &str, depends_on: &[&str]) -> SpaceActionSummary {
+    SpaceActionSummary {
+        action_id: id.to_string(),
+        title: id.to_uppercase(),
+        depends_on: depends_on.iter().map(|d| d.to_string()).collect(),
+        ..Default::default()
+    }
+}
+
+fn graph(edges: &[(&str, &[&str])]) -> BTreeMap<String, Vec<String>> {
+    edges
+        .iter()
+        .map(|(id, deps)| (id.to_string(), deps.iter().map(|d| d.to_string()).collect()))
+        .collect()
+}
+
+#[test]
+fn test_find_dependency_cycle() {
+    assert_eq!(
+        find_dependency_cycle(&graph(&[("a", &["b", "c"]), ("b", &["c"]), ("c", &[])])),
+        None
+    );
+    assert_eq!(
+        find_dependency_cycle(&graph(&[("a", &["b"]), ("b", &["c"]), ("c", &["a"])])),
+        Some(vec![
+            "a".to_string(),
+            "b".to_string(),
+            "c".to_string(),
+            "a".to_string()
+        ])
+    );
+    // A dangling id (deleted action) is not a cycle.
+    assert_eq!(find_dependency_cycle(&graph(&[("a", &["gone"])])), None);
+}
+
+#[test]
+fn test_action_graph_depth_and_unreachable() {
+    let mut quiz_gate = summary("d", &["b", "c"]);
+    quiz_gate.dependency_conditions = vec![ActionDependency {
+        action_id: "c".to_string(),
+        condition: UnlockCondition::QuizPassed { min_score: Some(3) },
+    }];
+    let actions = vec![
+        quiz_gate,
+        summary("b", &["a"]),
+        summary("a", &[]),
+        summary("c", &["a", "deleted"]),
+        summary("x", &["y"]),
+        summary("y", &["x"]),
+        summary("z", &["y"]),
+    ];
+
+    let graph = ActionGraph::build(&actions);
+    let order: Vec<(&str, u32, bool)> = graph
+        .nodes
+        .iter()
+        .map(|n| (n.action_id.as_str(), n.depth, n.unreachable))
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            ("a", 0, false),
+            ("b", 1, false),
+            ("c", 1, false),
+            ("d", 2, false),
+            ("x", 0, true),
+            ("y", 0, true),
+            ("z", 0, true),
+        ]
+    );
+
+    assert_eq!(
+        graph.edges.len(),
+        7,
+        "the edge to a deleted action is dropped"
+    );
+    let gate = graph
+        .edges
+        .iter()
+        .find(|e| e.from == "c" && e.to == "d")
+        .unwrap();
+    assert_eq!(
+        gate.condition,
+        UnlockCondition::QuizPassed { min_score: Some(3) }
+    );
+    assert!(graph
+        .edges
+        .iter()
+        .filter(|e| !(e.from == "c" && e.to == "d"))
+        .all(|e| e.condition == UnlockCondition::Completed));
+}
+
+#[test]
+fn test_unlock_condition_helpers() {
+    let (ids, rules) = split_dependencies(vec![
+        ActionDependency {
+            action_id: "a".to_string(),
+            condition: UnlockCondition::Completed,
+        },
+        ActionDependency {
+            action_id: "b".to_string(),
+            condition: UnlockCondition::PollOption {
+                question_index: 0,
+                option_index: 2,
+            },
+        },
+        ActionDependency {
+            action_id: "a".to_string(),
+            condition: UnlockCondition::QuizPassed { min_score: None },
+        },
+    ]);
+    assert_eq!(ids, vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(rules.len(), 1, "only non-default rules are stored");
+    assert_eq!(rules[0].action_id, "b");
+
+    assert!(UnlockCondition::QuizPassed { min_score: None }.applies_to(&SpaceActionType::Quiz));
+    assert!(!UnlockCondition::QuizPassed {
+        min_score: Some(-1)
+    }
+    .applies_to(&SpaceActionType::Quiz));
+    assert!(!UnlockCondition::QuizPassed { min_score: None }.applies_to(&SpaceActionType::Poll));
+    assert!(UnlockCondition::Completed.applies_to(&SpaceActionType::Meet));
+
+    let multi = Answer::MultipleChoice {
+        answer: Some(vec![0, 2]),
+        other: None,
+    };
+    assert!(answer_picks_option(&multi, 2));
+    assert!(!answer_picks_option(&multi, 1));
+    assert!(answer_picks_option(
+        &Answer::Dropdown { answer: Some(1) },
+        1
+    ));
+    assert!(!answer_picks_option(
+        &Answer::ShortAnswer { answer: None },
+        0
+    ));
+}
+
+#[tokio::test]
+async fn test_dependency_rules_reject_cycles_and_build_graph() {
+    let ctx = TestContext::setup().await;
+    let space_id = uuid::Uuid::new_v4().to_string();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+
+    let mut space = SpaceCommon::default();
+    space.pk = Partition::Space(space_id.clone());
+    space.sk = EntityType::SpaceCommon;
+    space.created_at = now;
+    space.updated_at = now;
+    space.status = Some(SpaceStatus::Ongoing);
+    space.publish_state = SpacePublishState::Published;
+    space.visibility = SpaceVisibility::Public;
+    space.post_pk = Partition::Feed(space_id.clone());
+    space.user_pk = ctx.test_user.0.pk.clone();
+    space.create(&ctx.ddb).await.expect("create space");
+
+    let mut ids = Vec::new();
+    for action_type in [
+        SpaceActionType::Poll,
+        SpaceActionType::Quiz,
+        SpaceActionType::TopicDiscussion,
+    ] {
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut action =
+            SpaceAction::new(SpacePartition(space_id.clone()), id.clone(), action_type);
+        action.status = Some(SpaceActionStatus::Ongoing);
+        action.create(&ctx.ddb).await.expect("create action");
+        ids.push(id);
+    }
+    let (poll, quiz, discussion) = (&ids[0], &ids[1], &ids[2]);
+    let action_path = |id: &str| format!("/api/spaces/{}/actions/{}", space_id, id);
+
+    // discussion <- quiz (score >= 2) <- poll
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &action_path(discussion),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "dependencies": [
+            { "action_id": quiz, "condition": { "type": "quiz_passed", "min_score": 2 } }
+        ] } }
+    };
+    assert_eq!(status, 200, "set rules: {:?}", body);
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &action_path(quiz),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "depends_on": [poll] } }
+    };
+    assert_eq!(status, 200, "set deps: {:?}", body);
+
+    // poll -> discussion would close the loop.
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &action_path(poll),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "depends_on": [discussion] } }
+    };
+    assert_eq!(status, 400, "cycle must be rejected");
+
+    // A quiz rule cannot sit on a poll dependency.
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &action_path(discussion),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "dependencies": [
+            { "action_id": poll, "condition": { "type": "quiz_passed" } }
+        ] } }
+    };
+    assert_eq!(status, 400, "mismatched rule must be rejected");
+
+    let stored = SpaceAction::get(
+        &ctx.ddb,
+        &CompositePartition(SpacePartition(space_id.clone()), discussion.clone()),
+        Some(EntityType::SpaceAction),
+    )
+    .await
+    .unwrap()
+    .unwrap();
+    assert_eq!(stored.depends_on, vec![quiz.clone()]);
+    assert_eq!(
+        stored.unlock_condition(quiz),
+        UnlockCondition::QuizPassed { min_score: Some(2) }
+    );
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/actions/graph", space_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "graph: {:?}", body);
+    let nodes = body["nodes"].as_array().unwrap();
+    let depth_of = |id: &str| {
+        nodes
+            .iter()
+            .find(|n| n["action_id"] == id)
+            .map(|n| n["depth"].as_u64().unwrap())
+    };
+    assert_eq!(depth_of(poll), Some(0));
+    assert_eq!(depth_of(quiz), Some(1));
+    assert_eq!(depth_of(discussion), Some(2));
+    let edges = body["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), 2);
+    assert!(edges.iter().any(|e| e["from"] == quiz.as_str()
+        && e["condition"]["type"] == "quiz_passed"
+        && e["condition"]["min_score"] == 2));
+}
@@ -0,0 +1,233 @@
+//! Activity streaks + participation calendar.
+//!
+//! Covers:
+//!   - StreakState::advance — next day, grace spend, reset, grace refill
+//!   - local_day offset bucketing
+//!   - StreakMultiplier ladder parsing / validation / lookup
+//!   - record_activity writes the daily ledger row and streak
+//!   - GET /api/me/activity/calendar — auth + dense grid shape
+//!   - PUT /api/me/activity/timezone — range validation
+
+use super::*;
+
+use crate::common::types::StreakMultiplier;
+use crate::features::activity::controllers::{
+    ActivityCalendarResponse, UpdateActivityTimezoneRequest,
+};
+use crate::features::activity::models::{UserActivityStreak, UserDailyActivity};
+use crate::features::activity::types::*;
+
+// ── Pure streak arithmetic ────────────────────────────────────────
+
+#[test]
+fn test_streak_advances_on_consecutive_days() {
+    let s = StreakState::default().advance(100).advance(101).advance(102);
+    assert_eq!(s.current_streak_days, 3);
+    assert_eq!(s.longest_streak_days, 3);
+    assert_eq!(s.last_active_day, 102);
+
+    // Same-day and late events don't move anything.
+    assert_eq!(s.advance(102), s);
+    assert_eq!(s.advance(90), s);
+}
+
+#[test]
+fn test_streak_refills_and_spends_grace() {
+    let mut s = StreakState::default();
+    for day in 1..=GRACE_REFILL_STREAK_DAYS {
+        s = s.advance(day);
+    }
+    assert_eq!(s.grace_days_available, 1);
+
+    // One missed day is covered by the banked grace day.
+    let s = s.advance(GRACE_REFILL_STREAK_DAYS + 2);
+    assert_eq!(s.current_streak_days, GRACE_REFILL_STREAK_DAYS + 1);
+    assert_eq!(s.grace_days_available, 0);
+
+    // The next gap is not.
+    let s = s.advance(GRACE_REFILL_STREAK_DAYS + 4);
+    assert_eq!(s.current_streak_days, 1);
+    assert_eq!(s.longest_streak_days, GRACE_REFILL_STREAK_DAYS + 1);
+}
+
+#[test]
+fn test_grace_bank_is_capped() {
+    let mut s = StreakState::default();
+    for day in 1..=GRACE_REFILL_STREAK_DAYS * (MAX_GRACE_DAYS + 2) {
+        s = s.advance(day);
+    }
+    assert_eq!(s.grace_days_available, MAX_GRACE_DAYS);
+}
+
+#[test]
+fn test_current_as_of_breaks_after_uncovered_gap() {
+    let s = StreakState::default().advance(10).advance(11);
+    assert_eq!(s.current_as_of(11), 2);
+    // Today can still be saved.
+    assert_eq!(s.current_as_of(12), 2);
+    assert_eq!(s.current_as_of(13), 0);
+    assert_eq!(StreakState::default().current_as_of(13), 0);
+}
+
+#[test]
+fn test_local_day_respects_offset() {
+    // 2026-03-01T15:30:00Z is already 2026-03-02 in Seoul (UTC+9).
+    let at = chrono::DateTime::parse_from_rfc3339("2026-03-01T15:30:00Z")
+        .unwrap()
+        .timestamp_millis();
+    assert_eq!(day_to_date(local_day(at, 0)), "2026-03-01");
+    assert_eq!(day_to_date(local_day(at, 9 * 60)), "2026-03-02");
+    assert_eq!(day_to_date(local_day(at, -8 * 60)), "2026-03-01");
+}
+
+#[test]
+fn test_calendar_levels() {
+    assert_eq!(calendar_level(0), 0);
+    assert_eq!(calendar_level(1), 1);
+    assert_eq!(calendar_level(3), 2);
+    assert_eq!(calendar_level(5), 3);
+    assert_eq!(calendar_level(50), 4);
+}
+
+// ── Streak multipliers ────────────────────────────────────────────
+
+#[test]
+fn test_streak_multiplier_ladder() {
+    let tiers = StreakMultiplier::parse_list("30:25, 7:10").expect("parse");
+    assert!(StreakMultiplier::validate(&tiers).is_ok());
+    assert_eq!(StreakMultiplier::bonus_percent_for(&tiers, 3), 0);
+    assert_eq!(StreakMultiplier::bonus_percent_for(&tiers, 7), 10);
+    assert_eq!(StreakMultiplier::bonus_percent_for(&tiers, 45), 25);
+    assert_eq!(StreakMultiplier::parse_list(""), Some(vec![]));
+    assert_eq!(StreakMultiplier::parse_list("7-10"), None);
+}
+
+#[test]
+fn test_streak_multiplier_validation_rejects_bad_tiers() {
+    let over = vec![StreakMultiplier {
+        min_streak_days: 7,
+        bonus_percent: StreakMultiplier::MAX_BONUS_PERCENT + 1,
+    }];
+    assert!(StreakMultiplier::validate(&over).is_err());
+
+    let dup = vec![
+        StreakMultiplier { min_streak_days: 7, bonus_percent: 10 },
+        StreakMultiplier { min_streak_days: 7, bonus_percent: 20 },
+    ];
+    assert!(StreakMultiplier::validate(&dup).is_err());
+}
+
+// ── Ledger writes ─────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_record_activity_writes_daily_ledger_and_streak() {
+    let ctx = TestContext::setup().await;
+    let user = create_test_user(&ctx.ddb).await;
+
+    let space_id = crate::common::types::SpacePartition(uuid::Uuid::new_v4().to_string());
+    let author = AuthorPartition::from(user.pk.clone());
+    for _ in 0..2 {
+        let action_id = uuid::Uuid::new_v4().to_string();
+        crate::features::activity::controllers::record_activity(
+            &ctx.ddb,
+            space_id.clone(),
+            author.clone(),
+            action_id.clone(),
+            crate::features::spaces::pages::actions::types::SpaceActionType::Follow,
+            SpaceActivityData::Follow { follow_id: action_id },
+            "testuser".to_string(),
+            "".to_string(),
+        )
+        .await
+        .expect("record_activity");
+    }
+
+    let today = local_day(crate::common::utils::time::get_now_timestamp_millis(), 0);
+    let (pk, sk) = UserDailyActivity::keys(&user.pk, today);
+    let row = UserDailyActivity::get(&ctx.ddb, &pk, Some(sk))
+        .await
+        .expect("get daily")
+        .expect("daily row written");
+    assert_eq!(row.activity_count, 2);
+    assert_eq!(row.day, today);
+
+    let streak = UserActivityStreak::get_or_default(&ctx.ddb, &user.pk)
+        .await
+        .expect("get streak");
+    assert_eq!(streak.current_streak_days, 1);
+    assert_eq!(streak.last_active_day, today);
+}
+
+// ── Endpoints ─────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_activity_calendar_requires_auth() {
+    let ctx = TestContext::setup().await;
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app,
+        path: "/api/me/activity/calendar",
+    };
+    assert_ne!(status, 200);
+}
+
+#[tokio::test]
+async fn test_activity_calendar_returns_dense_grid() {
+    let ctx = TestContext::setup().await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: "/api/me/activity/calendar?days=28",
+        headers: ctx.test_user.1.clone(),
+        response_type: ActivityCalendarResponse,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(body.days.len(), 28);
+    assert_eq!(body.days.first().map(|d| d.date.clone()), Some(body.start_date.clone()));
+    assert_eq!(body.days.last().map(|d| d.date.clone()), Some(body.end_date.clone()));
+    assert_eq!(
+        body.total_activities,
+        body.days.iter().map(|d| d.activity_count).sum::<i64>()
+    );
+}
+
+#[tokio::test]
+async fn test_activity_timezone_rejects_out_of_range_offset() {
+    let ctx = TestContext::setup().await;
+    let (status, _, _) = crate::test_put! {
+        app: ctx.app,
+        path: "/api/me/activity/timezone",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "utc_offset_minutes": 15 * 60 } }
+    };
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_activity_timezone_persists_offset() {
+    let ctx = TestContext::setup().await;
+    let (status, _, body) = crate::test_put! {
+        app: ctx.app.clone(),
+        path: "/api/me/activity/timezone",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "utc_offset_minutes": 540 } },
+        response_type: UpdateActivityTimezoneRequest,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(body.utc_offset_minutes, 540);
+
+    let (_, _, calendar) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/me/activity/calendar?days=7",
+        headers: ctx.test_user.1.clone(),
+        response_type: ActivityCalendarResponse,
+    };
+    assert_eq!(calendar.utc_offset_minutes, Some(540));
+
+    // Other viewers never learn the owner's offset.
+    let (status, _, calendar) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/users/{}/activity/calendar?days=7", ctx.test_user.0.username),
+        response_type: ActivityCalendarResponse,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(calendar.utc_offset_minutes, None);
+}
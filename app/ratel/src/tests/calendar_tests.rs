@@ -0,0 +1,191 @@
+use super::*;
+use crate::common::models::space::SpaceCommon;
+use crate::common::types::{
+    CompositePartition, EntityType, Partition, SpacePartition, SpacePublishState, SpaceStatus,
+    SpaceVisibility,
+};
+use crate::features::calendar::*;
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// Seed a published space owned by the test user that ends in a week.
+async fn seed_space_with_end(ctx: &TestContext) -> (String, i64) {
+    let space_id = uuid::Uuid::new_v4().to_string();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let ended_at = now + 7 * DAY_MS;
+    let post_pk = Partition::Feed(space_id.clone());
+
+    let mut space = SpaceCommon::default();
+    space.pk = Partition::Space(space_id.clone());
+    space.sk = EntityType::SpaceCommon;
+    space.created_at = now;
+    space.updated_at = now;
+    space.status = Some(SpaceStatus::Ongoing);
+    space.publish_state = SpacePublishState::Published;
+    space.visibility = SpaceVisibility::Public;
+    space.post_pk = post_pk.clone();
+    space.user_pk = ctx.test_user.0.pk.clone();
+    space.author_display_name = ctx.test_user.0.display_name.clone();
+    space.author_username = ctx.test_user.0.username.clone();
+    space.ended_at = Some(ended_at);
+    space.create(&ctx.ddb).await.expect("create space");
+
+    let post = crate::features::posts::models::Post {
+        pk: post_pk,
+        sk: EntityType::Post,
+        title: "Budget Assembly".to_string(),
+        ..Default::default()
+    };
+    post.create(&ctx.ddb).await.expect("create post");
+
+    (space_id, ended_at)
+}
+
+async fn create_published_meet(ctx: &TestContext, space_id: &str) -> String {
+    use crate::features::spaces::pages::actions::models::SpaceAction;
+    use crate::features::spaces::pages::actions::types::SpaceActionStatus;
+
+    let (_, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets", space_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    let meet_sk = body["sk"].as_str().unwrap().to_string();
+
+    let mut action = SpaceAction::get(
+        &ctx.ddb,
+        &CompositePartition(SpacePartition(space_id.to_string()), meet_sk.clone()),
+        Some(EntityType::SpaceAction),
+    )
+    .await
+    .unwrap()
+    .expect("space action");
+    action.title = "Kickoff, part 1".to_string();
+    action.status = Some(SpaceActionStatus::Ongoing);
+    action.upsert(&ctx.ddb).await.expect("publish action");
+    meet_sk
+}
+
+async fn get_raw(app: axum::Router, path: &str) -> (axum::http::StatusCode, String, String) {
+    let req = axum::http::Request::builder()
+        .uri(format!("http://localhost:8080{}", path))
+        .method("GET")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let res = tower::ServiceExt::oneshot(app, req).await.unwrap();
+    let (parts, body) = res.into_parts();
+    let content_type = parts
+        .headers
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = axum::body::to_bytes(body, 10 * 1024 * 1024).await.unwrap();
+    (parts.status, content_type, String::from_utf8(bytes.to_vec()).unwrap())
+}
+
+#[test]
+fn test_render_calendar_escapes_and_folds() {
+    let event = IcsEvent {
+        uid: "meet-1@ratel.foundation".into(),
+        sequence: 3,
+        status: IcsStatus::Cancelled,
+        starts_at: 1_767_225_600_000,
+        ends_at: Some(1_767_229_200_000),
+        updated_at: 1_767_225_600_000,
+        summary: "Q&A; budget, round 2".into(),
+        description: "회의 ".repeat(40),
+        url: String::new(),
+    };
+    let ics = render_calendar("Ratel", &[event]);
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert!(ics.contains("SUMMARY:Q&A\\; budget\\, round 2\r\n"));
+    assert!(ics.contains("DTSTART:20260101T000000Z\r\n"));
+    assert!(ics.contains("DTEND:20260101T010000Z\r\n"));
+    assert!(ics.contains("SEQUENCE:3\r\n"));
+    assert!(ics.contains("STATUS:CANCELLED\r\n"));
+    assert!(!ics.contains("URL:"));
+    for line in ics.split("\r\n") {
+        assert!(line.len() <= 75, "unfolded line: {line}");
+    }
+}
+
+#[test]
+fn test_ics_sequence_follows_updates() {
+    assert_eq!(ics_sequence(1_000, 1_000), 0);
+    assert_eq!(ics_sequence(1_000, 61_000), 60);
+    assert_eq!(ics_sequence(5_000, 1_000), 0);
+}
+
+#[tokio::test]
+async fn test_action_calendar_for_meet_and_cancellation() {
+    let ctx = TestContext::setup().await;
+    let (space_id, _) = seed_space_with_end(&ctx).await;
+    let meet_sk = create_published_meet(&ctx, &space_id).await;
+    let path = format!("/api/spaces/{}/actions/{}/calendar", space_id, meet_sk);
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &path,
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "calendar: {:?}", body);
+    assert_eq!(body["file_name"], "kickoff-part-1.ics");
+    let content = body["content"].as_str().unwrap();
+    assert!(content.contains("SUMMARY:Kickoff\\, part 1"));
+    assert!(content.contains("STATUS:CONFIRMED"));
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets/{}/status", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "Cancel": { "reason": "Moved" } } }
+    };
+    assert_eq!(status, 200);
+
+    let (_, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &path,
+        headers: ctx.test_user.1.clone(),
+    };
+    let content = body["content"].as_str().unwrap();
+    assert!(content.contains("STATUS:CANCELLED"), "{content}");
+}
+
+#[tokio::test]
+async fn test_calendar_feed_requires_token() {
+    let ctx = TestContext::setup().await;
+    let (space_id, ended_at) = seed_space_with_end(&ctx).await;
+    create_published_meet(&ctx, &space_id).await;
+
+    let (status, _, _) = get_raw(ctx.app.clone(), "/api/calendar/feed/not-a-token.ics").await;
+    assert_eq!(status, 404);
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/me/calendar-feed/regenerate",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200);
+    let token = body["secret"].as_str().unwrap().to_string();
+
+    let (status, content_type, ics) =
+        get_raw(ctx.app.clone(), &format!("/api/calendar/feed/{}.ics", token)).await;
+    assert_eq!(status, 200);
+    assert!(content_type.starts_with("text/calendar"));
+    assert!(ics.contains("SUMMARY:Kickoff\\, part 1"), "{ics}");
+    assert!(ics.contains("SUMMARY:Budget Assembly ends"));
+    assert!(ics.contains(&format!("DTSTART:{}", format_utc(ended_at))));
+
+    // Rotating revokes the old URL.
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/me/calendar-feed/regenerate",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200);
+    let (status, _, _) = get_raw(ctx.app, &format!("/api/calendar/feed/{}", token)).await;
+    assert_eq!(status, 404);
+}
@@ -2,14 +2,28 @@
 //!
 //! Coverage matrix per `roadmap/cross-posting.md` § Phase 1A:
 //!
-//! | Endpoint                             | Unauth | Owner happy-path | Cross-user / not-allowed |
-//! |--------------------------------------|--------|------------------|--------------------------|
-//! | POST  `/connections/bluesky/connect` |   ✅   |     skipped*     |             —            |
-//! | GET   `/connections`                 |   ✅   |        ✅        |             —            |
-//! | PATCH `/connections/{platform}`      |   ✅   |        ✅        |             —            |
-//! | DELETE `/connections/{platform}`     |   ✅   |        ✅        |             —            |
-//! | GET   `/posts/{id}/syndication`      |   ✅   |        ✅        |             ✅           |
-//! | POST  `/posts/{id}/jobs/{p}/retry`   |   ✅   |        ✅        |             ✅           |
+//! | Endpoint                              | Unauth | Owner happy-path | Cross-user / not-allowed |
+//! |---------------------------------------|--------|------------------|--------------------------|
+//! | POST  `/connections/bluesky/connect`  |   ✅   |     skipped*     |             —            |
+//! | GET   `/connections`                  |   ✅   |        ✅        |             —            |
+//! | PATCH `/connections/{platform}`       |   ✅   |        ✅        |             —            |
+//! | DELETE `/connections/{platform}`      |   ✅   |        ✅        |             —            |
+//! | GET   `/posts/{id}/syndication`       |   ✅   |        ✅        |             ✅           |
+//! | POST  `/posts/{id}/jobs/{p}/retry`    |   ✅   |        ✅        |             ✅           |
+//! | PATCH `/connections/{p}/reply-import` |   ✅   |        ✅        |             ✅           |
+//! | PATCH `/connections/{p}/thread-mode`  |   ✅   |        ✅        |             ✅           |
+//! | GET   `/posts/{id}/external-comments` |   —    |        ✅        |             ✅           |
+//! | GET   `/queue`                        |   ✅   |        ✅        |             —            |
+//! | POST  `/posts/{id}/jobs/{p}/cancel`   |   —    |        ✅        |             ✅           |
+//! | PATCH `/connections/{p}/best-time`    |   —    |        ✅        |             ✅           |
+//! | PATCH `/connections/{p}/space-cards`  |   ✅   |        ✅        |             —            |
+//! | GET   `/teams/{t}/.../connections`    |   ✅   |        ✅        |             ✅           |
+//! | PATCH `/teams/{t}/.../{p}/approval`   |   —    |        ✅        |             ✅           |
+//! | GET   `/teams/{t}/.../approvals`      |   —    |        ✅        |             —            |
+//! | POST  `/posts/{id}/jobs/{p}/approve`  |   ✅   |        ✅        |             ✅           |
+//! | POST  `/posts/{id}/jobs/{p}/reject`   |   —    |        ✅        |             —            |
+//! | GET   `/analytics`                    |   ✅   |        ✅        |             —            |
+//! | POST  `/posts/{id}/visits/{p}`        |   —    |        ✅        |             ✅           |
 //!
 //! \* The `connect_bluesky` happy path is skipped because the handler calls
 //!    `BlueskyAdapter::create_session` against the real Bluesky API. Stubbing
@@ -20,9 +34,10 @@
 use super::*;
 use crate::common::types::*;
 use crate::features::cross_posting::models::{
-    ConnectionStatus, JobState, SocialConnection, SyndicationJob,
+    ConnectionStatus, ExternalComment, JobSource, JobState, SocialConnection, SyndicationJob,
 };
 use crate::features::cross_posting::types::SocialPlatform;
+use crate::features::posts::models::{Post, Team};
 
 // ─────────────────────────────────────────────────────────────────────────────
 // connect_bluesky — POST /api/cross-posting/connections/bluesky/connect
@@ -62,6 +77,61 @@ async fn test_connect_bluesky_empty_input_rejected() {
     );
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// connect_threads_init — POST /api/cross-posting/connections/threads/init
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_connect_threads_init_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_post! {
+        app: app,
+        path: "/api/cross-posting/connections/threads/init",
+        body: { "req": { "return_to": null } }
+    };
+    assert_ne!(
+        status, 200,
+        "unauthenticated connect_threads_init must not succeed"
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// connect_mastodon_init — POST /api/cross-posting/connections/mastodon/init
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_connect_mastodon_init_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_post! {
+        app: app,
+        path: "/api/cross-posting/connections/mastodon/init",
+        body: { "req": { "instance": "mastodon.social", "return_to": null } }
+    };
+    assert_ne!(
+        status, 200,
+        "unauthenticated connect_mastodon_init must not succeed"
+    );
+}
+
+#[tokio::test]
+async fn test_connect_mastodon_init_rejects_private_instance() {
+    let TestContext { app, test_user, .. } = TestContext::setup().await;
+
+    // Normalization rejects loopback / bare IPs before any outbound call,
+    // so app registration is never attempted against them.
+    for instance in ["localhost", "127.0.0.1", "mastodon.social/@alice"] {
+        let (status, _, body) = crate::test_post! {
+            app: app.clone(),
+            path: "/api/cross-posting/connections/mastodon/init",
+            headers: test_user.1.clone(),
+            body: { "req": { "instance": instance, "return_to": null } }
+        };
+        assert_eq!(status, 400, "{instance} must be rejected: {:?}", body);
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // list_connections — GET /api/cross-posting/connections
 // ─────────────────────────────────────────────────────────────────────────────
@@ -263,6 +333,33 @@ async fn test_get_syndication_panel_returns_seeded_jobs() {
     assert_eq!(jobs[0]["state"], "published");
 }
 
+#[tokio::test]
+async fn test_get_syndication_panel_reports_thread_length() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_syndication_job(&ctx, &post_id, SocialPlatform::Bluesky, JobState::Published).await;
+    SyndicationJob::updater(
+        Partition::Feed(post_id.clone()),
+        EntityType::SyndicationJob(SocialPlatform::Bluesky.to_string()),
+    )
+    .with_external_post_id("at://did:plc:alice/app.bsky.feed.post/1".to_string())
+    .with_thread_post_ids(vec![
+        "at://did:plc:alice/app.bsky.feed.post/2".to_string(),
+        "at://did:plc:alice/app.bsky.feed.post/3".to_string(),
+    ])
+    .execute(&ctx.ddb)
+    .await
+    .expect("record thread");
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/syndication", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "panel: {:?}", body);
+    assert_eq!(body["jobs"][0]["thread_len"], 3, "root + two replies: {:?}", body);
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // retry_job — POST /api/cross-posting/posts/{id}/jobs/{platform}/retry
 // ─────────────────────────────────────────────────────────────────────────────
@@ -343,71 +440,917 @@ async fn test_retry_job_failed_flips_to_pending() {
 // Helpers
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Seed a `SocialConnection` row directly in DynamoDB. The real connect
-/// flow goes through Bluesky's `createSession` which we don't mock yet,
-/// so the listing / toggle / disconnect tests bypass the API and write
-/// the row themselves to exercise the read-side logic.
-async fn seed_bluesky_connection(
-    ctx: &TestContext,
-    status: ConnectionStatus,
-    auto_post: bool,
-) {
-    let user_pk = ctx.test_user.0.pk.clone();
-    let now = crate::common::utils::time::now();
-    let conn = SocialConnection {
-        pk: user_pk,
-        sk: EntityType::SocialConnection(SocialPlatform::Bluesky.to_string()),
-        platform_status: format!("bluesky#{}", status),
-        platform: SocialPlatform::Bluesky,
-        status,
-        external_handle: "alice.bsky.social".to_string(),
-        external_user_id: "did:plc:alice".to_string(),
-        // Sentinel ciphertext — these tests never decrypt; if you add a
-        // decrypt path, replace with `aead::seal(...)` against
-        // `CROSS_POSTING_DATA_KEY`.
-        credential_ciphertext: vec![1, 2, 3, 4],
-        token_expires_at: None,
-        auto_post_enabled: auto_post,
-        posts_syndicated_count: 0,
-        last_synced_at: None,
-        created_at: now,
-        updated_at: now,
+// ─────────────────────────────────────────────────────────────────────────────
+// toggle_reply_import — PATCH /api/cross-posting/connections/{platform}/reply-import
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_toggle_reply_import_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_patch! {
+        app: app,
+        path: "/api/cross-posting/connections/bluesky/reply-import",
+        body: { "req": { "import_replies_enabled": true } }
     };
-    conn.create(&ctx.ddb).await.expect("seed connection");
+    assert_ne!(status, 200, "unauthenticated toggle must not succeed");
 }
 
-/// Seed a `SyndicationJob` row directly in DynamoDB for panel / retry
-/// tests. The fan-out Lambda would normally write these in Stage 1.
-async fn seed_syndication_job(
-    ctx: &TestContext,
-    post_id: &str,
-    platform: SocialPlatform,
-    state: JobState,
-) {
-    let now = crate::common::utils::time::now();
-    let job = SyndicationJob {
-        pk: Partition::Feed(post_id.to_string()),
-        sk: EntityType::SyndicationJob(platform.to_string()),
-        dispatch_shard: None,
-        engagement_shard: None,
-        next_attempt_at: 0,
-        engagement_next_at: 0,
-        author_user_id: ctx.test_user.0.pk.clone(),
-        platform,
-        state,
-        attempts: 0,
-        last_error_category: None,
-        last_error_message: None,
-        external_post_id: None,
-        external_post_url: None,
-        body_snapshot_len: 0,
-        backlink_url: format!("https://ratel.foundation/posts/{}?utm_source={}", post_id, platform),
-        dispatch_lock_id: None,
-        lock_acquired_at: None,
-        created_at: now,
-        updated_at: now,
+#[tokio::test]
+async fn test_toggle_reply_import_flips_value() {
+    let ctx = TestContext::setup().await;
+    seed_bluesky_connection(&ctx, ConnectionStatus::Connected, true).await;
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/bluesky/reply-import",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "import_replies_enabled": true } }
     };
-    job.create(&ctx.ddb).await.expect("seed job");
+    assert_eq!(status, 200, "toggle on: {:?}", body);
+    assert_eq!(body["import_replies_enabled"], true, "{:?}", body);
+
+    let (_, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections",
+        headers: ctx.test_user.1.clone(),
+    };
+    let arr = body.as_array().unwrap();
+    assert_eq!(arr[0]["import_replies_enabled"], true, "persisted: {:?}", body);
+    assert_eq!(arr[0]["auto_post_enabled"], true, "auto-post untouched: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_toggle_reply_import_linkedin_rejected() {
+    let ctx = TestContext::setup().await;
+
+    // LinkedIn's API gives no read access to replies, so enabling is
+    // refused before the connection is even looked up.
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/linkedin/reply-import",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "import_replies_enabled": true } }
+    };
+    assert_eq!(status, 400, "linkedin reply import must be rejected: {:?}", body);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// toggle_thread_mode — PATCH /api/cross-posting/connections/{platform}/thread-mode
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_toggle_thread_mode_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_patch! {
+        app: app,
+        path: "/api/cross-posting/connections/bluesky/thread-mode",
+        body: { "req": { "thread_mode_enabled": true } }
+    };
+    assert_ne!(status, 200, "unauthenticated toggle must not succeed");
+}
+
+#[tokio::test]
+async fn test_toggle_thread_mode_flips_value() {
+    let ctx = TestContext::setup().await;
+    seed_bluesky_connection(&ctx, ConnectionStatus::Connected, true).await;
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/bluesky/thread-mode",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "thread_mode_enabled": true } }
+    };
+    assert_eq!(status, 200, "toggle on: {:?}", body);
+    assert_eq!(body["thread_mode_enabled"], true, "{:?}", body);
+
+    let (_, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections",
+        headers: ctx.test_user.1.clone(),
+    };
+    let arr = body.as_array().unwrap();
+    assert_eq!(arr[0]["thread_mode_enabled"], true, "persisted: {:?}", body);
+    assert_eq!(arr[0]["import_replies_enabled"], false, "reply import untouched: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_toggle_thread_mode_linkedin_rejected() {
+    let ctx = TestContext::setup().await;
+
+    // LinkedIn posts can't be replied to by their author through the API,
+    // so enabling is refused before the connection is even looked up.
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/linkedin/thread-mode",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "thread_mode_enabled": true } }
+    };
+    assert_eq!(status, 400, "linkedin thread mode must be rejected: {:?}", body);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// toggle_space_cards — PATCH /api/cross-posting/connections/{platform}/space-cards
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_toggle_space_cards_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_patch! {
+        app: app,
+        path: "/api/cross-posting/connections/bluesky/space-cards",
+        body: { "req": { "space_cards_enabled": true } }
+    };
+    assert_ne!(status, 200, "unauthenticated toggle must not succeed");
+}
+
+#[tokio::test]
+async fn test_toggle_space_cards_flips_value() {
+    let ctx = TestContext::setup().await;
+    seed_bluesky_connection(&ctx, ConnectionStatus::Connected, true).await;
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/bluesky/space-cards",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "space_cards_enabled": true } }
+    };
+    assert_eq!(status, 200, "toggle on: {:?}", body);
+    assert_eq!(body["space_cards_enabled"], true, "{:?}", body);
+
+    let (_, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections",
+        headers: ctx.test_user.1.clone(),
+    };
+    let arr = body.as_array().unwrap();
+    assert_eq!(arr[0]["space_cards_enabled"], true, "persisted: {:?}", body);
+    assert_eq!(arr[0]["auto_post_enabled"], true, "auto-post untouched: {:?}", body);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// list_external_comments — GET /api/cross-posting/posts/{id}/external-comments
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_external_comments_empty_for_new_post() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/external-comments", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "list: {:?}", body);
+    assert!(body.as_array().expect("array").is_empty(), "{:?}", body);
+}
+
+#[tokio::test]
+async fn test_list_external_comments_returns_seeded_oldest_first() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_external_comment(&ctx, &post_id, SocialPlatform::Mastodon, "109", 2_000).await;
+    seed_external_comment(&ctx, &post_id, SocialPlatform::Bluesky, "at://did:plc:bob/x", 1_000)
+        .await;
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/external-comments", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "list: {:?}", body);
+    let arr = body.as_array().expect("array");
+    assert_eq!(arr.len(), 2, "two seeded replies: {:?}", body);
+    assert_eq!(arr[0]["platform"], "bluesky", "oldest first: {:?}", body);
+    assert_eq!(arr[1]["platform"], "mastodon");
+}
+
+#[tokio::test]
+async fn test_list_external_comments_unreadable_post_rejected() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_external_comment(&ctx, &post_id, SocialPlatform::Bluesky, "at://did:plc:bob/x", 1_000)
+        .await;
+
+    // Drafts are author-only, so their imported replies are too.
+    let (_, headers2) = ctx.create_another_user().await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/external-comments", post_id),
+        headers: headers2,
+    };
+    assert_ne!(status, 200, "non-reader must not list replies: {:?}", body);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Scheduled cross-posts — GET /queue, POST /posts/{id}/jobs/{p}/cancel,
+// PATCH /connections/{p}/best-time
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_queue_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_get! {
+        app: app,
+        path: "/api/cross-posting/queue",
+    };
+    assert_ne!(status, 200, "unauthenticated queue must not succeed");
+}
+
+#[tokio::test]
+async fn test_list_queue_returns_scheduled_jobs_only() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_scheduled_job(&ctx, &post_id, SocialPlatform::Bluesky).await;
+    seed_syndication_job(&ctx, &post_id, SocialPlatform::LinkedIn, JobState::Pending).await;
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/queue",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "queue: {:?}", body);
+    let arr = body.as_array().expect("array");
+    assert_eq!(arr.len(), 1, "only the scheduled job is queued: {:?}", body);
+    assert_eq!(arr[0]["platform"], "bluesky");
+    assert_eq!(arr[0]["post_id"], post_id.as_str());
+}
+
+#[tokio::test]
+async fn test_cancel_job_scheduled_flips_to_cancelled() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_scheduled_job(&ctx, &post_id, SocialPlatform::Bluesky).await;
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/cancel", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "cancel: {:?}", body);
+
+    let (_, _, panel) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/syndication", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    let jobs = panel["jobs"].as_array().expect("jobs array");
+    assert_eq!(jobs[0]["state"], "cancelled", "state after cancel: {:?}", panel);
+
+    // Cancelled jobs drop out of the queue index.
+    let (_, _, queue) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/queue",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert!(queue.as_array().expect("array").is_empty(), "{:?}", queue);
+}
+
+#[tokio::test]
+async fn test_cancel_job_not_scheduled_rejected() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_syndication_job(&ctx, &post_id, SocialPlatform::Bluesky, JobState::Pending).await;
+
+    // Once released to Pending the dispatcher may already be publishing.
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/cancel", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 400, "cancel on Pending must be rejected: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_cancel_job_other_user_rejected() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_scheduled_job(&ctx, &post_id, SocialPlatform::Bluesky).await;
+
+    let (_, headers2) = ctx.create_another_user().await;
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/cancel", post_id),
+        headers: headers2,
+    };
+    assert_ne!(status, 200, "non-author must not cancel: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_retry_job_scheduled_rejected() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_scheduled_job(&ctx, &post_id, SocialPlatform::Bluesky).await;
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/retry", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 400, "retry on Scheduled must be rejected: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_set_best_time_persists() {
+    let ctx = TestContext::setup().await;
+    seed_bluesky_connection(&ctx, ConnectionStatus::Connected, true).await;
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/bluesky/best-time",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "best_time": { "minute_of_day": 540, "utc_offset_minutes": 540 } } }
+    };
+    assert_eq!(status, 200, "set best time: {:?}", body);
+    assert_eq!(body["best_time"]["minute_of_day"], 540, "{:?}", body);
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/bluesky/best-time",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "best_time": null } }
+    };
+    assert_eq!(status, 200, "clear best time: {:?}", body);
+    assert!(body["best_time"].is_null(), "{:?}", body);
+}
+
+#[tokio::test]
+async fn test_set_best_time_invalid_rejected() {
+    let ctx = TestContext::setup().await;
+    seed_bluesky_connection(&ctx, ConnectionStatus::Connected, true).await;
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/connections/bluesky/best-time",
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "best_time": { "minute_of_day": 1440, "utc_offset_minutes": 0 } } }
+    };
+    assert_eq!(status, 400, "minute 1440 is out of range: {:?}", body);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Team connections & approvals — /api/teams/{t}/cross-posting/...,
+// POST /posts/{id}/jobs/{p}/approve|reject
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_list_team_connections_unauthenticated() {
+    let ctx = TestContext::setup().await;
+    let team_pk = create_team(&ctx).await;
+
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/teams/{}/cross-posting/connections", team_id(&team_pk)),
+    };
+    assert_ne!(
+        status, 200,
+        "unauthenticated team connections must not succeed"
+    );
+}
+
+#[tokio::test]
+async fn test_list_team_connections_returns_team_rows() {
+    let ctx = TestContext::setup().await;
+    let team_pk = create_team(&ctx).await;
+    seed_team_connection(&ctx, &team_pk, true).await;
+    // The owner's personal connection must not leak into the team list.
+    seed_bluesky_connection(&ctx, ConnectionStatus::Connected, true).await;
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/teams/{}/cross-posting/connections", team_id(&team_pk)),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "team connections: {:?}", body);
+    let arr = body.as_array().expect("array");
+    assert_eq!(arr.len(), 1, "{:?}", body);
+    assert_eq!(arr[0]["external_handle"], "ratel.team");
+    assert_eq!(arr[0]["approval_required"], true);
+}
+
+#[tokio::test]
+async fn test_list_team_connections_non_member_rejected() {
+    let ctx = TestContext::setup().await;
+    let team_pk = create_team(&ctx).await;
+    seed_team_connection(&ctx, &team_pk, false).await;
+
+    let (_, headers2) = ctx.create_another_user().await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/teams/{}/cross-posting/connections", team_id(&team_pk)),
+        headers: headers2,
+    };
+    assert_ne!(status, 200, "non-member must not list: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_set_team_approval_persists() {
+    let ctx = TestContext::setup().await;
+    let team_pk = create_team(&ctx).await;
+    seed_team_connection(&ctx, &team_pk, false).await;
+
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: &format!(
+            "/api/teams/{}/cross-posting/connections/bluesky/approval",
+            team_id(&team_pk)
+        ),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "approval_required": true } }
+    };
+    assert_eq!(status, 200, "set approval: {:?}", body);
+    assert_eq!(body["approval_required"], true, "{:?}", body);
+}
+
+#[tokio::test]
+async fn test_set_team_approval_member_rejected() {
+    let ctx = TestContext::setup().await;
+    let team_pk = create_team(&ctx).await;
+    seed_team_connection(&ctx, &team_pk, false).await;
+
+    let (member, headers2) = ctx.create_another_user().await;
+    add_team_member(&ctx, &team_pk, &member).await;
+    let (status, _, body) = crate::test_patch! {
+        app: ctx.app.clone(),
+        path: &format!(
+            "/api/teams/{}/cross-posting/connections/bluesky/approval",
+            team_id(&team_pk)
+        ),
+        headers: headers2,
+        body: { "req": { "approval_required": false } }
+    };
+    assert_ne!(
+        status, 200,
+        "plain members must not toggle approval: {:?}",
+        body
+    );
+}
+
+#[tokio::test]
+async fn test_approve_job_unauthenticated() {
+    let ctx = TestContext::setup().await;
+    let (_, post_id) = create_team_post(&ctx).await;
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/approve", post_id),
+    };
+    assert_ne!(status, 200, "unauthenticated approve must not succeed");
+}
+
+#[tokio::test]
+async fn test_approve_job_releases_to_pending_with_audit() {
+    let ctx = TestContext::setup().await;
+    let (team_pk, post_id) = create_team_post(&ctx).await;
+    seed_awaiting_approval_job(&ctx, &post_id, &team_pk).await;
+
+    let (status, _, queue) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/teams/{}/cross-posting/approvals", team_id(&team_pk)),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "approvals: {:?}", queue);
+    let arr = queue.as_array().expect("array");
+    assert_eq!(arr.len(), 1, "{:?}", queue);
+    assert_eq!(arr[0]["post_id"], post_id.as_str());
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/approve", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "approve: {:?}", body);
+
+    let (_, _, panel) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/syndication", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(panel["jobs"][0]["state"], "pending", "{:?}", panel);
+    let audit = panel["audit"].as_array().expect("audit array");
+    assert!(
+        audit.iter().any(|e| e["action"] == "approved"),
+        "approval must be on the audit trail: {:?}",
+        panel
+    );
+
+    // Settled jobs leave the approval queue, and can't be approved twice.
+    let (_, _, queue) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/teams/{}/cross-posting/approvals", team_id(&team_pk)),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert!(queue.as_array().expect("array").is_empty(), "{:?}", queue);
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/approve", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 400, "second approve must be rejected: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_reject_job_is_terminal() {
+    let ctx = TestContext::setup().await;
+    let (team_pk, post_id) = create_team_post(&ctx).await;
+    seed_awaiting_approval_job(&ctx, &post_id, &team_pk).await;
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/reject", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "reject: {:?}", body);
+
+    let (_, _, panel) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/syndication", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(panel["jobs"][0]["state"], "rejected", "{:?}", panel);
+
+    // Retry must not sneak a rejected post out.
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/retry", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(
+        status, 400,
+        "retry on Rejected must be rejected: {:?}",
+        body
+    );
+}
+
+#[tokio::test]
+async fn test_approve_job_member_rejected() {
+    let ctx = TestContext::setup().await;
+    let (team_pk, post_id) = create_team_post(&ctx).await;
+    seed_awaiting_approval_job(&ctx, &post_id, &team_pk).await;
+
+    let (member, headers2) = ctx.create_another_user().await;
+    add_team_member(&ctx, &team_pk, &member).await;
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/approve", post_id),
+        headers: headers2,
+    };
+    assert_ne!(status, 200, "plain members must not approve: {:?}", body);
+}
+
+#[tokio::test]
+async fn test_approve_job_personal_post_rejected() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_syndication_job(
+        &ctx,
+        &post_id,
+        SocialPlatform::Bluesky,
+        JobState::AwaitingApproval,
+    )
+    .await;
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/jobs/bluesky/approve", post_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_ne!(status, 200, "approval is a team-post workflow: {:?}", body);
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Analytics — GET /api/cross-posting/analytics,
+//             POST /api/cross-posting/posts/{id}/visits/{platform}
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn test_get_analytics_unauthenticated() {
+    let TestContext { app, .. } = TestContext::setup().await;
+
+    let (status, _, _) = crate::test_get! {
+        app: app,
+        path: "/api/cross-posting/analytics",
+    };
+    assert_ne!(status, 200, "unauthenticated request must fail");
+}
+
+#[tokio::test]
+async fn test_backlink_visit_counts_toward_analytics() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_syndication_job(&ctx, &post_id, SocialPlatform::Bluesky, JobState::Published).await;
+
+    // Readers land signed out, so the beacon carries no session.
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/visits/bluesky", post_id),
+    };
+    assert_eq!(status, 200, "record visit: {:?}", body);
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/analytics",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "analytics: {:?}", body);
+    assert_eq!(body["platforms"][0]["platform"], "bluesky", "{:?}", body);
+    assert_eq!(body["platforms"][0]["counts"]["visits"], 1, "{:?}", body);
+    assert_eq!(body["posts"][0]["post_id"], post_id.as_str(), "{:?}", body);
+    assert_eq!(body["daily"][0]["counts"]["visits"], 1, "{:?}", body);
+}
+
+#[tokio::test]
+async fn test_backlink_visit_counted_once_per_visitor_per_day() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_syndication_job(&ctx, &post_id, SocialPlatform::Bluesky, JobState::Published).await;
+
+    // The client writes the first hop; the load balancer appends the last.
+    let reader = |claimed: &str, peer: &str, ua: &str| {
+        let mut headers = axum::http::HeaderMap::new();
+        headers.insert(
+            "x-forwarded-for",
+            format!("{claimed}, {peer}").parse().unwrap(),
+        );
+        headers.insert("user-agent", ua.parse().unwrap());
+        headers
+    };
+    // A reload replays the beacon, and rotating the claimed hop doesn't
+    // make it a new reader; a second reader on another network is a new
+    // visit.
+    for headers in [
+        reader("10.0.0.1", "203.0.113.7", "Mozilla/5.0 (alice)"),
+        reader("10.0.0.2", "203.0.113.7", "Mozilla/5.0 (alice)"),
+        reader("10.0.0.3", "198.51.100.4", "Mozilla/5.0 (bob)"),
+    ] {
+        let (status, _, body) = crate::test_post! {
+            app: ctx.app.clone(),
+            path: &format!("/api/cross-posting/posts/{}/visits/bluesky", post_id),
+            headers: headers,
+        };
+        assert_eq!(status, 200, "record visit: {:?}", body);
+    }
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/analytics",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "analytics: {:?}", body);
+    assert_eq!(body["platforms"][0]["counts"]["visits"], 2, "{:?}", body);
+    assert_eq!(body["daily"][0]["counts"]["visits"], 2, "{:?}", body);
+}
+
+#[tokio::test]
+async fn test_backlink_visit_unpublished_job_ignored() {
+    let ctx = TestContext::setup().await;
+    let post_id = create_draft_post(&ctx, &ctx.test_user.1).await;
+    seed_syndication_job(&ctx, &post_id, SocialPlatform::Bluesky, JobState::Pending).await;
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/cross-posting/posts/{}/visits/bluesky", post_id),
+    };
+    assert_eq!(status, 200, "ignored visits still succeed: {:?}", body);
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/cross-posting/analytics",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "analytics: {:?}", body);
+    let platforms = body["platforms"].as_array().expect("platforms array");
+    assert!(platforms.is_empty(), "nothing went out yet: {:?}", body);
+}
+
+/// Seed a `SocialConnection` row directly in DynamoDB. The real connect
+/// flow goes through Bluesky's `createSession` which we don't mock yet,
+/// so the listing / toggle / disconnect tests bypass the API and write
+/// the row themselves to exercise the read-side logic.
+async fn seed_bluesky_connection(
+    ctx: &TestContext,
+    status: ConnectionStatus,
+    auto_post: bool,
+) {
+    let user_pk = ctx.test_user.0.pk.clone();
+    let now = crate::common::utils::time::now();
+    let conn = SocialConnection {
+        pk: user_pk,
+        sk: EntityType::SocialConnection(SocialPlatform::Bluesky.to_string()),
+        platform_status: format!("bluesky#{}", status),
+        platform: SocialPlatform::Bluesky,
+        status,
+        external_handle: "alice.bsky.social".to_string(),
+        external_user_id: "did:plc:alice".to_string(),
+        // Sentinel ciphertext — these tests never decrypt; if you add a
+        // decrypt path, replace with `aead::seal(...)` against
+        // `CROSS_POSTING_DATA_KEY`.
+        credential_ciphertext: vec![1, 2, 3, 4],
+        token_expires_at: None,
+        auto_post_enabled: auto_post,
+        import_replies_enabled: false,
+        thread_mode_enabled: false,
+        space_cards_enabled: false,
+        approval_required: false,
+        best_time: None,
+        posts_syndicated_count: 0,
+        last_synced_at: None,
+        created_at: now,
+        updated_at: now,
+    };
+    conn.create(&ctx.ddb).await.expect("seed connection");
+}
+
+/// Seed a `SyndicationJob` row directly in DynamoDB for panel / retry
+/// tests. The fan-out Lambda would normally write these in Stage 1.
+async fn seed_syndication_job(
+    ctx: &TestContext,
+    post_id: &str,
+    platform: SocialPlatform,
+    state: JobState,
+) {
+    let now = crate::common::utils::time::now();
+    let job = SyndicationJob {
+        pk: Partition::Feed(post_id.to_string()),
+        sk: EntityType::SyndicationJob(platform.to_string()),
+        dispatch_shard: None,
+        engagement_shard: None,
+        next_attempt_at: 0,
+        engagement_next_at: 0,
+        author_user_id: ctx.test_user.0.pk.clone(),
+        queue_owner: None,
+        scheduled_at: None,
+        connection_pk: None,
+        approval_team: None,
+        reviewed_by: None,
+        reviewed_at: None,
+        platform,
+        source: JobSource::Post,
+        state,
+        attempts: 0,
+        last_error_category: None,
+        last_error_message: None,
+        external_post_id: None,
+        external_post_url: None,
+        thread_post_ids: Vec::new(),
+        body_snapshot_len: 0,
+        backlink_url: format!("https://ratel.foundation/posts/{}?utm_source={}", post_id, platform),
+        dispatch_lock_id: None,
+        lock_acquired_at: None,
+        created_at: now,
+        updated_at: now,
+    };
+    job.create(&ctx.ddb).await.expect("seed job");
+}
+
+/// Seed a `Scheduled` job an hour out, as Stage 1 writes it for a post
+/// whose timing resolves to the future.
+async fn seed_scheduled_job(ctx: &TestContext, post_id: &str, platform: SocialPlatform) {
+    seed_syndication_job(ctx, post_id, platform, JobState::Scheduled).await;
+    let at = crate::common::utils::time::now() + 60 * 60 * 1000;
+    SyndicationJob::updater(
+        Partition::Feed(post_id.to_string()),
+        EntityType::SyndicationJob(platform.to_string()),
+    )
+    .with_queue_owner(ctx.test_user.0.pk.clone())
+    .with_scheduled_at(at)
+    .with_dispatch_shard("SDS#0".to_string())
+    .with_next_attempt_at(at / 1000)
+    .execute(&ctx.ddb)
+    .await
+    .expect("schedule job");
+}
+
+/// Team owned by `ctx.test_user`; returns its pk.
+async fn create_team(ctx: &TestContext) -> Partition {
+    let (team_pk, _) = Team::create_new_team(
+        &ctx.test_user.0,
+        &ctx.ddb,
+        format!("team{}", uuid::Uuid::new_v4().simple()),
+        String::new(),
+        format!("t-{}", uuid::Uuid::new_v4().simple()),
+        "desc".to_string(),
+    )
+    .await
+    .expect("create team");
+    team_pk
+}
+
+/// Team id without the `TEAM#` prefix, as the `/api/teams/:team_pk` routes
+/// take it.
+fn team_id(pk: &Partition) -> String {
+    match pk {
+        Partition::Team(id) => id.clone(),
+        _ => panic!("expected Team pk, got {:?}", pk),
+    }
+}
+
+async fn add_team_member(
+    ctx: &TestContext,
+    team_pk: &Partition,
+    user: &crate::common::models::auth::User,
+) {
+    crate::features::auth::UserTeam::new(
+        user.pk.clone(),
+        team_pk.clone(),
+        "team".to_string(),
+        String::new(),
+        "t".to_string(),
+        None,
+        crate::features::social::pages::member::dto::TeamRole::Member,
+    )
+    .create(&ctx.ddb)
+    .await
+    .expect("add team member");
+}
+
+/// Draft post by `ctx.test_user`, re-authored as a fresh team the way
+/// the composer's "Posting as" switch does. Returns (team_pk, post_id).
+async fn create_team_post(ctx: &TestContext) -> (Partition, String) {
+    let team_pk = create_team(ctx).await;
+    let post_id = create_draft_post(ctx, &ctx.test_user.1).await;
+    Post::updater(Partition::Feed(post_id.clone()), EntityType::Post)
+        .with_user_pk(team_pk.clone())
+        .with_author_type(UserType::Team)
+        .execute(&ctx.ddb)
+        .await
+        .expect("re-author post as team");
+    (team_pk, post_id)
+}
+
+/// Team-owned Bluesky connection, as the team connect flow stores it.
+async fn seed_team_connection(ctx: &TestContext, team_pk: &Partition, approval_required: bool) {
+    let now = crate::common::utils::time::now();
+    let conn = SocialConnection {
+        pk: team_pk.clone(),
+        sk: EntityType::SocialConnection(SocialPlatform::Bluesky.to_string()),
+        platform_status: format!("bluesky#{}", ConnectionStatus::Connected),
+        platform: SocialPlatform::Bluesky,
+        status: ConnectionStatus::Connected,
+        external_handle: "ratel.team".to_string(),
+        external_user_id: "did:plc:team".to_string(),
+        credential_ciphertext: vec![1, 2, 3, 4],
+        auto_post_enabled: true,
+        approval_required,
+        created_at: now,
+        updated_at: now,
+        ..Default::default()
+    };
+    conn.create(&ctx.ddb).await.expect("seed team connection");
+}
+
+/// Bluesky job parked in the team's approval queue, as Stage 1 writes it
+/// for a connection with `approval_required`.
+async fn seed_awaiting_approval_job(ctx: &TestContext, post_id: &str, team_pk: &Partition) {
+    seed_syndication_job(
+        ctx,
+        post_id,
+        SocialPlatform::Bluesky,
+        JobState::AwaitingApproval,
+    )
+    .await;
+    SyndicationJob::updater(
+        Partition::Feed(post_id.to_string()),
+        EntityType::SyndicationJob(SocialPlatform::Bluesky.to_string()),
+    )
+    .with_connection_pk(team_pk.clone())
+    .with_approval_team(team_pk.clone())
+    .execute(&ctx.ddb)
+    .await
+    .expect("queue job for approval");
+}
+
+/// Seed an imported reply directly in DynamoDB — the Stage 4 refresh
+/// would normally write these from the platform's reply thread.
+async fn seed_external_comment(
+    ctx: &TestContext,
+    post_id: &str,
+    platform: SocialPlatform,
+    external_id: &str,
+    posted_at: i64,
+) {
+    let now = crate::common::utils::time::now();
+    let (pk, sk) =
+        ExternalComment::keys(&Partition::Feed(post_id.to_string()), platform, external_id);
+    let comment = ExternalComment {
+        pk,
+        sk,
+        platform,
+        external_id: external_id.to_string(),
+        author_handle: "bob@example.social".to_string(),
+        author_display_name: "Bob".to_string(),
+        author_avatar_url: None,
+        content: "Nice post".to_string(),
+        url: format!("https://example.social/replies/{external_id}"),
+        posted_at,
+        imported_at: now,
+        updated_at: now,
+    };
+    comment.create(&ctx.ddb).await.expect("seed external comment");
 }
 
 /// POST `/api/posts` (creates an empty draft owned by `headers`'s user)
@@ -21,10 +21,13 @@ use crate::features::arcade::games::fact_or_fold::models::FactFoldRound;
 use crate::features::arcade::models::ArcadeWalletBalance;
 use crate::features::arcade::games::fact_or_fold::controllers::settlement::SettleRoundResponse;
 use crate::features::arcade::games::fact_or_fold::types::{
-    BetResponse, BetSide, FactOrFoldSettingsResponse, SubjectResponse, SubjectStatus,
-    InsiderStatementResponse, ListBetsResponse, ListParticipantsResponse, ListRationalesResponse,
-    LobbyResponse, ParticipantResponse, QueueAlarmResponse, RationaleResponse,
-    RoundSubjectResponse, RoundResponse, RoundStatus, Verdict,
+    BetResponse, BetSide, CollusionReviewResponse, CollusionReviewStatus, CollusionSignalKind,
+    FactOrFoldSettingsResponse, SubjectResponse, SubjectStatus, InsiderStatementResponse,
+    ListBetsResponse, ListParticipantsResponse,
+    ListRationalesResponse, LobbyResponse, ParticipantResponse, QueueAlarmResponse,
+    RationaleResponse,
+    ReplayEventKind, RoundReplayResponse, RoundSubjectResponse, RoundResponse, RoundStatus,
+    SpectatorSnapshotResponse, Verdict,
 };
 
 // ── Helpers ───────────────────────────────────────────────────────
@@ -1703,6 +1706,186 @@ async fn test_settle_round_idempotent() {
     assert_eq!(status, 200, "second settle on a Settled round must be a no-op");
 }
 
+// ── Collusion review ────────────────────────────────────────────────
+
+/// Give the caller a 30/30 lifetime record so the abnormal win-rate
+/// rule trips on their next settlement.
+async fn seed_perfect_record(ctx: &TestContext) {
+    use crate::features::arcade::games::fact_or_fold::models::FactFoldUserStats;
+
+    let user_id = UserPartition::from(ctx.test_user.0.pk.clone()).0;
+    let mut stats = FactFoldUserStats::get_or_default(&ctx.ddb, &user_id)
+        .await
+        .expect("stats read");
+    stats.total_rounds = 30;
+    stats.correct_count = 30;
+    stats.win_rate_cleared_bps = 0;
+    stats.upsert(&ctx.ddb).await.expect("stats upsert");
+}
+
+#[tokio::test]
+async fn test_collusion_signal_holds_settlement_until_approved() {
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, headers) = fill_round_to_capacity(&ctx, &admin).await;
+    seed_bets_for_all(&ctx, &round_id, "REAL").await;
+    force_round_to_debate(&ctx, &round_id, 5_000).await;
+    seed_perfect_record(&ctx).await;
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/rounds/{}/settle", round_id),
+        headers: admin.clone(),
+    };
+    assert_eq!(status, 409, "detector hit must hold the payout");
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/rounds/{}", round_id),
+        headers: headers.clone(),
+        response_type: RoundResponse,
+    };
+    assert_eq!(status, 200);
+    assert!(matches!(body.status, RoundStatus::Settlement));
+    assert!(body.held_for_review);
+
+    // Players read the hold as a normal settlement state, not an error.
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/rounds/{}/settlement", round_id),
+        headers: headers.clone(),
+        response_type: SettleRoundResponse,
+    };
+    assert_eq!(status, 200);
+    assert!(body.held_for_review);
+    assert!(body.outcomes.is_empty());
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/fact-or-fold/admin/reviews?status=Pending",
+        headers: admin.clone(),
+        response_type: crate::common::types::ListResponse<CollusionReviewResponse>,
+    };
+    assert_eq!(status, 200);
+    let review = body
+        .items
+        .iter()
+        .find(|r| r.round_id.0 == round_id)
+        .expect("held round must be in the pending queue");
+    assert!(
+        review
+            .signals
+            .iter()
+            .any(|s| s.kind == CollusionSignalKind::AbnormalWinRate)
+    );
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/reviews/{}/approve", round_id),
+        headers: admin.clone(),
+        body: { "req": { "note": "known streak" } },
+        response_type: CollusionReviewResponse,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(body.status, CollusionReviewStatus::Approved);
+    assert_eq!(body.note, "known streak");
+
+    // The approved row moves from the pending partition to the
+    // approved one.
+    for (filter, listed) in [("Pending", false), ("Approved", true)] {
+        let (status, _, body) = crate::test_get! {
+            app: ctx.app.clone(),
+            path: &format!("/api/fact-or-fold/admin/reviews?status={filter}"),
+            headers: admin.clone(),
+            response_type: crate::common::types::ListResponse<CollusionReviewResponse>,
+        };
+        assert_eq!(status, 200);
+        assert_eq!(
+            body.items.iter().any(|r| r.round_id.0 == round_id),
+            listed,
+            "{filter}"
+        );
+    }
+
+    // Approving clears the win-rate flag at the accuracy it fired on.
+    {
+        use crate::features::arcade::games::fact_or_fold::models::FactFoldUserStats;
+
+        let user_id = UserPartition::from(ctx.test_user.0.pk.clone()).0;
+        let stats = FactFoldUserStats::get_or_default(&ctx.ddb, &user_id)
+            .await
+            .expect("stats read");
+        assert_eq!(stats.win_rate_cleared_bps, 10_000);
+    }
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/rounds/{}", round_id),
+        headers: headers,
+        response_type: RoundResponse,
+    };
+    assert_eq!(status, 200);
+    assert!(matches!(body.status, RoundStatus::Settled));
+    assert!(!body.held_for_review);
+
+    // A resolved review cannot be flipped again.
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app,
+        path: &format!("/api/fact-or-fold/admin/reviews/{}/void", round_id),
+        headers: admin,
+        body: { "req": { "note": "" } },
+    };
+    assert_eq!(status, 409);
+}
+
+#[tokio::test]
+async fn test_void_review_settles_round_without_stats() {
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, headers) = fill_round_to_capacity(&ctx, &admin).await;
+    seed_bets_for_all(&ctx, &round_id, "REAL").await;
+    force_round_to_debate(&ctx, &round_id, 5_000).await;
+    seed_perfect_record(&ctx).await;
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/rounds/{}/settle", round_id),
+        headers: admin.clone(),
+    };
+    assert_eq!(status, 409);
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/reviews/{}/void", round_id),
+        headers: admin,
+        body: { "req": { "note": "pair confirmed" } },
+        response_type: CollusionReviewResponse,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(body.status, CollusionReviewStatus::Voided);
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/rounds/{}", round_id),
+        headers: headers.clone(),
+        response_type: RoundResponse,
+    };
+    assert_eq!(status, 200);
+    assert!(matches!(body.status, RoundStatus::Settled));
+
+    // Voided rounds do not count toward the lifetime record.
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: "/api/fact-or-fold/me/stats",
+        headers: headers,
+        response_type: crate::features::arcade::games::fact_or_fold::types::UserStatsResponse,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(body.total_rounds, 30);
+}
+
 #[tokio::test]
 async fn test_chat_post_succeeds_during_debate() {
     let ctx = TestContext::setup().await;
@@ -2619,6 +2802,184 @@ async fn test_user_can_play_different_subjects_in_series() {
     assert_eq!(body.subject_id.0, subject_b);
 }
 
+// ── Spectator + replay (PR8) ────────────────────────────────────────
+
+#[tokio::test]
+async fn test_spectate_open_to_non_participant_and_redacts_bets() {
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, _) = fill_round_to_capacity(&ctx, &admin).await;
+    seed_bets_for_all(&ctx, &round_id, "REAL").await;
+
+    let (_, outsider) = ctx.create_another_user().await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/rounds/{}/spectate", round_id),
+        headers: outsider.clone(),
+        response_type: SpectatorSnapshotResponse,
+    };
+    assert_eq!(status, 200, "outsiders may spectate an in-progress round");
+    assert!(matches!(body.round.status, RoundStatus::NewsReveal));
+    assert_eq!(body.participants.len(), 4);
+    assert!(
+        body.participants.iter().all(|p| !p.is_insider),
+        "spectators must never learn the insider"
+    );
+    assert!(body.bets.is_empty(), "bets stay hidden before Reveal");
+    assert!(body.subject.verdict.is_none());
+
+    force_round_to_debate(&ctx, &round_id, 30_000).await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/fact-or-fold/rounds/{}/spectate", round_id),
+        headers: outsider,
+        response_type: SpectatorSnapshotResponse,
+    };
+    assert_eq!(status, 200);
+    assert_eq!(body.bets.len(), 4, "bets unlock for spectators at Reveal+");
+}
+
+#[tokio::test]
+async fn test_spectate_rejects_settled_round() {
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, headers) = fill_round_to_capacity(&ctx, &admin).await;
+    seed_bets_for_all(&ctx, &round_id, "REAL").await;
+    force_round_to_debate(&ctx, &round_id, 5_000).await;
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/rounds/{}/settle", round_id),
+        headers: admin,
+    };
+    assert_eq!(status, 200);
+
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/fact-or-fold/rounds/{}/spectate", round_id),
+        headers: headers,
+    };
+    assert_ne!(status, 200, "settled rounds go through /replay instead");
+}
+
+#[tokio::test]
+async fn test_replay_rejects_unsettled_round() {
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, headers) = fill_round_to_capacity(&ctx, &admin).await;
+
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/fact-or-fold/rounds/{}/replay", round_id),
+        headers: headers,
+    };
+    assert_ne!(status, 200, "replay is only available once settled");
+}
+
+#[tokio::test]
+async fn test_replay_reconstructs_timeline_after_settle() {
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, headers) = fill_round_to_capacity(&ctx, &admin).await;
+    seed_bets_for_all(&ctx, &round_id, "REAL").await;
+    force_round_to_debate(&ctx, &round_id, 30_000).await;
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/arcade/games/fact-or-fold/rounds/{}/chat", round_id),
+        headers: headers,
+        body: { "req": { "text": "replay me" } }
+    };
+    assert_eq!(status, 200);
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/rounds/{}/settle", round_id),
+        headers: admin,
+    };
+    assert_eq!(status, 200);
+
+    let (_, outsider) = ctx.create_another_user().await;
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/fact-or-fold/rounds/{}/replay", round_id),
+        headers: outsider,
+        response_type: RoundReplayResponse,
+    };
+    assert_eq!(status, 200, "replay is open to any signed-in user");
+    assert_eq!(body.round_id.0, round_id);
+    assert!(matches!(body.subject.verdict, Some(Verdict::Real)));
+    assert_eq!(body.participants.iter().filter(|p| p.is_insider).count(), 1);
+
+    let events = &body.events;
+    assert!(
+        events.windows(2).all(|w| w[0].at <= w[1].at),
+        "timeline must be chronological"
+    );
+    let bets = events
+        .iter()
+        .filter(|e| e.kind == ReplayEventKind::BetPlaced)
+        .count();
+    assert_eq!(bets, 4);
+    assert!(events
+        .iter()
+        .any(|e| e.kind == ReplayEventKind::ChatMessage && e.text.as_deref() == Some("replay me")));
+    assert_eq!(
+        events
+            .iter()
+            .filter(|e| e.kind == ReplayEventKind::Settled)
+            .count(),
+        4,
+        "one settlement event per participant",
+    );
+    assert!(events.iter().all(|e| e.offset_ms >= 0));
+}
+
+#[tokio::test]
+async fn test_replay_includes_chat_beyond_one_page() {
+    use crate::features::arcade::games::fact_or_fold::models::FactFoldChatMessage;
+    use crate::features::arcade::games::fact_or_fold::types::CHAT_PAGE_LIMIT;
+
+    let ctx = TestContext::setup().await;
+    reset_fact_fold_state(&ctx).await;
+    let (_, admin) = ctx.create_admin_user().await;
+    let (round_id, headers) = fill_round_to_capacity(&ctx, &admin).await;
+    seed_bets_for_all(&ctx, &round_id, "REAL").await;
+    force_round_to_debate(&ctx, &round_id, 30_000).await;
+
+    let total = CHAT_PAGE_LIMIT + 5;
+    for i in 0..total {
+        FactFoldChatMessage::new(&round_id, ctx.test_user.0.pk.clone(), format!("msg {i}"))
+            .create(&ctx.ddb)
+            .await
+            .expect("chat seed");
+    }
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/fact-or-fold/admin/rounds/{}/settle", round_id),
+        headers: admin,
+    };
+    assert_eq!(status, 200);
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/fact-or-fold/rounds/{}/replay", round_id),
+        headers: headers,
+        response_type: RoundReplayResponse,
+    };
+    assert_eq!(status, 200);
+    let chat = body
+        .events
+        .iter()
+        .filter(|e| e.kind == ReplayEventKind::ChatMessage)
+        .count();
+    assert_eq!(chat, total, "replay must page through the whole chat");
+}
+
 #[allow(dead_code)]
 fn _force_dto_imports_used() {
     // Pulls in BetResponse / RationaleResponse / BetSide so they
@@ -0,0 +1,435 @@
+use std::sync::{Arc, Mutex};
+
+use super::*;
+use crate::common::models::auth::UserFollow;
+use crate::common::types::{EntityType, Partition};
+use crate::features::federation::models::{FederatedObject, RemoteActor};
+use crate::features::federation::services::actors::{self, ActorKind};
+use crate::features::federation::services::{http_signature, objects};
+use crate::features::posts::models::{Post, PostComment};
+use crate::features::posts::types::{PostStatus, Visibility};
+use rsa::RsaPrivateKey;
+use rsa::pkcs8::{EncodePublicKey, LineEnding};
+
+/// Actor documents and signed deliveries seal the signing key with the
+/// data key, so these tests are meaningless without one. `make test` bakes
+/// in a dev key; fail loudly rather than skip when it is missing.
+fn require_data_key() {
+    assert!(
+        option_env!("CROSS_POSTING_DATA_KEY").is_some_and(|v| !v.is_empty()),
+        "CROSS_POSTING_DATA_KEY must be set at build time to run federation tests (use `make test`)"
+    );
+}
+
+fn user_id(pk: &Partition) -> String {
+    match pk {
+        Partition::User(id) => id.clone(),
+        _ => panic!("not a user pk: {pk}"),
+    }
+}
+
+async fn get_raw(
+    app: axum::Router,
+    path: &str,
+) -> (axum::http::StatusCode, String, serde_json::Value) {
+    let req = axum::http::Request::builder()
+        .uri(format!("http://localhost:8080{}", path))
+        .method("GET")
+        .body(axum::body::Body::empty())
+        .unwrap();
+    let res = tower::ServiceExt::oneshot(app, req).await.unwrap();
+    let (parts, body) = res.into_parts();
+    let content_type = parts
+        .headers
+        .get(axum::http::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = axum::body::to_bytes(body, 10 * 1024 * 1024).await.unwrap();
+    let json = serde_json::from_slice(&bytes).unwrap_or_default();
+    (parts.status, content_type, json)
+}
+
+async fn seed_post(ctx: &TestContext, visibility: Visibility) -> Post {
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    let user = &ctx.test_user.0;
+    let post = Post {
+        pk: Partition::Feed(uuid::Uuid::new_v4().to_string()),
+        sk: EntityType::Post,
+        created_at: now,
+        updated_at: now,
+        title: String::new(),
+        body: "<p>Hello fediverse</p>".into(),
+        status: PostStatus::Published,
+        visibility: Some(visibility),
+        user_pk: user.pk.clone(),
+        author_display_name: user.display_name.clone(),
+        author_username: user.username.clone(),
+        ..Default::default()
+    };
+    post.create(&ctx.ddb).await.expect("create post");
+    post
+}
+
+fn post_id(post: &Post) -> String {
+    match &post.pk {
+        Partition::Feed(id) => id.clone(),
+        _ => unreachable!(),
+    }
+}
+
+/// A minimal remote fediverse server: one actor whose document publishes
+/// `key`, and an inbox recording what Ratel delivers to it.
+struct FakeInstance {
+    actor_id: String,
+    key: RsaPrivateKey,
+    delivered: Arc<Mutex<Vec<serde_json::Value>>>,
+}
+
+impl FakeInstance {
+    async fn spawn() -> Self {
+        use axum::Json;
+        use axum::routing::{get, post};
+
+        let key = RsaPrivateKey::new(&mut rsa::rand_core::OsRng, 1024).unwrap();
+        let pem = key
+            .to_public_key()
+            .to_public_key_pem(LineEnding::LF)
+            .unwrap();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let actor_id = format!("{base}/users/alice");
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+
+        let doc = serde_json::json!({
+            "id": actor_id,
+            "type": "Person",
+            "preferredUsername": "alice",
+            "name": "Alice Remote",
+            "inbox": format!("{actor_id}/inbox"),
+            "publicKey": {
+                "id": format!("{actor_id}#main-key"),
+                "owner": actor_id,
+                "publicKeyPem": pem,
+            },
+        });
+        let inbox_log = delivered.clone();
+        let app = axum::Router::new()
+            .route("/users/alice", get(move || async move { Json(doc) }))
+            .route(
+                "/users/alice/inbox",
+                post(move |Json(body): Json<serde_json::Value>| async move {
+                    inbox_log.lock().unwrap().push(body);
+                    axum::http::StatusCode::ACCEPTED
+                }),
+            );
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        Self {
+            actor_id,
+            key,
+            delivered,
+        }
+    }
+
+    fn remote_pk(&self) -> Partition {
+        RemoteActor::keys(&self.actor_id).0
+    }
+
+    /// Signed inbox POST, as the remote server would send it.
+    async fn send(
+        &self,
+        app: &axum::Router,
+        inbox_path: &str,
+        activity: serde_json::Value,
+    ) -> axum::http::StatusCode {
+        let body = serde_json::to_vec(&activity).unwrap();
+        let url = url::Url::parse(&format!("http://localhost:8080{inbox_path}")).unwrap();
+        let mut headers = axum::http::HeaderMap::new();
+        for (name, value) in http_signature::signed_request_headers(
+            "POST",
+            &url,
+            Some(&body),
+            &format!("{}#main-key", self.actor_id),
+            &self.key,
+            chrono::Utc::now(),
+        ) {
+            headers.insert(name, value.parse().unwrap());
+        }
+        let (status, _, _) = crate::test_call! {
+            app: app,
+            path: inbox_path,
+            method: "POST",
+            body: axum::body::Body::from(body),
+            headers: headers,
+            response_type: serde_json::Value
+        };
+        status
+    }
+}
+
+#[tokio::test]
+async fn test_webfinger_resolves_local_user() {
+    let ctx = TestContext::setup().await;
+    let user = &ctx.test_user.0;
+    let host = actors::site_host();
+
+    let (status, content_type, body) = get_raw(
+        ctx.app.clone(),
+        &format!(
+            "/.well-known/webfinger?resource=acct:{}@{}",
+            user.username, host
+        ),
+    )
+    .await;
+    assert_eq!(status, 200, "{body}");
+    assert!(content_type.starts_with("application/jrd+json"));
+    assert_eq!(body["subject"], format!("acct:{}@{}", user.username, host));
+    assert_eq!(
+        body["links"][0]["href"],
+        actors::actor_url(ActorKind::User, &user_id(&user.pk))
+    );
+
+    let (status, _, _) = get_raw(
+        ctx.app.clone(),
+        &format!(
+            "/.well-known/webfinger?resource=acct:no-such-user-{}@{}",
+            uuid::Uuid::new_v4(),
+            host
+        ),
+    )
+    .await;
+    assert_eq!(status, 404);
+    let (status, _, _) = get_raw(
+        ctx.app.clone(),
+        &format!(
+            "/.well-known/webfinger?resource=acct:{}@mastodon.social",
+            user.username
+        ),
+    )
+    .await;
+    assert_eq!(status, 404);
+    let (status, _, _) = get_raw(ctx.app, "/.well-known/webfinger?resource=nobody").await;
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_outbox_serves_public_posts() {
+    let ctx = TestContext::setup().await;
+    let user_id = user_id(&ctx.test_user.0.pk);
+    let public = seed_post(&ctx, Visibility::Public).await;
+    let private = seed_post(&ctx, Visibility::Private).await;
+
+    let (status, content_type, page) = get_raw(
+        ctx.app.clone(),
+        &format!("/ap/users/{user_id}/outbox?page=true"),
+    )
+    .await;
+    assert_eq!(status, 200, "{page}");
+    assert!(content_type.starts_with("application/activity+json"));
+    let ids: Vec<&str> = page["orderedItems"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|item| item["object"]["id"].as_str())
+        .collect();
+    assert!(
+        ids.contains(&objects::post_object_url(&post_id(&public)).as_str()),
+        "{page}"
+    );
+    assert!(!ids.contains(&objects::post_object_url(&post_id(&private)).as_str()));
+
+    let (status, _, note) =
+        get_raw(ctx.app.clone(), &format!("/ap/posts/{}", post_id(&public))).await;
+    assert_eq!(status, 200);
+    assert_eq!(note["type"], "Note");
+    assert_eq!(note["content"], "<p>Hello fediverse</p>");
+    assert_eq!(
+        note["attributedTo"],
+        actors::actor_url(ActorKind::User, &user_id)
+    );
+    let (status, _, _) =
+        get_raw(ctx.app.clone(), &format!("/ap/posts/{}", post_id(&private))).await;
+    assert_eq!(status, 404);
+
+    // Actor documents embed the signing key, which needs the data key to seal.
+    require_data_key();
+    let (status, _, actor) = get_raw(ctx.app, &format!("/ap/users/{user_id}")).await;
+    assert_eq!(status, 200, "{actor}");
+    assert_eq!(actor["type"], "Person");
+    assert_eq!(actor["preferredUsername"], ctx.test_user.0.username);
+    let pem = actor["publicKey"]["publicKeyPem"].as_str().unwrap();
+    assert!(http_signature::parse_public_key_pem(pem).is_some());
+}
+
+#[tokio::test]
+async fn test_inbox_rejects_unsigned_and_forged_activities() {
+    let ctx = TestContext::setup().await;
+    let user_id = user_id(&ctx.test_user.0.pk);
+    let inbox_path = format!("/ap/users/{user_id}/inbox");
+    let remote = FakeInstance::spawn().await;
+    let follow = serde_json::json!({
+        "id": format!("{}#follows/1", remote.actor_id),
+        "type": "Follow",
+        "actor": remote.actor_id,
+        "object": actors::actor_url(ActorKind::User, &user_id),
+    });
+
+    let (status, _, _) = crate::test_call! {
+        app: ctx.app,
+        path: &inbox_path,
+        method: "POST",
+        body: axum::body::Body::from(follow.to_string()),
+        headers: axum::http::HeaderMap::new(),
+        response_type: serde_json::Value
+    };
+    assert_eq!(status, 401);
+
+    // Validly signed by alice, but claiming to come from someone else.
+    let mut forged = follow.clone();
+    forged["actor"] = "https://elsewhere.example/users/bob".into();
+    assert_eq!(remote.send(&ctx.app, &inbox_path, forged).await, 401);
+
+    let (pk, sk) = UserFollow::follower_keys(&ctx.test_user.0.pk, &remote.remote_pk());
+    assert!(
+        UserFollow::get(&ctx.ddb, pk, Some(sk))
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    // Signed by alice, but claiming a note id on another server.
+    let post = seed_post(&ctx, Visibility::Public).await;
+    let spoofed_id = "https://elsewhere.example/statuses/1";
+    let create = serde_json::json!({
+        "id": format!("{}/statuses/1/activity", remote.actor_id),
+        "type": "Create",
+        "actor": remote.actor_id,
+        "object": {
+            "id": spoofed_id,
+            "type": "Note",
+            "attributedTo": remote.actor_id,
+            "inReplyTo": objects::post_object_url(&post_id(&post)),
+            "content": "<p>Not mine</p>",
+            "to": ["https://www.w3.org/ns/activitystreams#Public"],
+        },
+    });
+    assert_eq!(remote.send(&ctx.app, &inbox_path, create).await, 401);
+    let (pk, sk) = FederatedObject::keys(spoofed_id);
+    assert!(
+        FederatedObject::get(&ctx.ddb, pk, Some(sk))
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_inbox_applies_remote_follow_like_and_reply() {
+    let ctx = TestContext::setup().await;
+    let user = ctx.test_user.0.clone();
+    let user_id = user_id(&user.pk);
+    let inbox_path = format!("/ap/users/{user_id}/inbox");
+    let post = seed_post(&ctx, Visibility::Public).await;
+    let post_url = objects::post_object_url(&post_id(&post));
+    let remote = FakeInstance::spawn().await;
+
+    // Follow → follower row + counter; redelivery is a no-op.
+    let follow = serde_json::json!({
+        "id": format!("{}#follows/1", remote.actor_id),
+        "type": "Follow",
+        "actor": remote.actor_id,
+        "object": actors::actor_url(ActorKind::User, &user_id),
+    });
+    assert_eq!(
+        remote.send(&ctx.app, &inbox_path, follow.clone()).await,
+        202
+    );
+    assert_eq!(remote.send(&ctx.app, &inbox_path, follow).await, 202);
+    let (pk, sk) = UserFollow::follower_keys(&user.pk, &remote.remote_pk());
+    let row = UserFollow::get(&ctx.ddb, pk, Some(sk))
+        .await
+        .unwrap()
+        .expect("follower row");
+    assert_eq!(row.display_name.as_deref(), Some("Alice Remote"));
+    let stored =
+        crate::features::auth::User::get(&ctx.ddb, user.pk.clone(), Some(EntityType::User))
+            .await
+            .unwrap()
+            .unwrap();
+    assert_eq!(stored.followers_count, user.followers_count + 1);
+    require_data_key();
+    let delivered = remote.delivered.lock().unwrap().clone();
+    assert_eq!(
+        delivered.iter().filter(|a| a["type"] == "Accept").count(),
+        1,
+        "an accepted Follow replayed is not answered again: {delivered:?}"
+    );
+
+    // Like, twice.
+    let like = serde_json::json!({
+        "id": format!("{}#likes/1", remote.actor_id),
+        "type": "Like",
+        "actor": remote.actor_id,
+        "object": post_url,
+    });
+    assert_eq!(remote.send(&ctx.app, &inbox_path, like.clone()).await, 202);
+    assert_eq!(remote.send(&ctx.app, &inbox_path, like).await, 202);
+
+    // Public reply to the post, delivered twice.
+    let note_id = format!("{}/statuses/1", remote.actor_id);
+    let create = serde_json::json!({
+        "id": format!("{note_id}/activity"),
+        "type": "Create",
+        "actor": remote.actor_id,
+        "object": {
+            "id": note_id,
+            "type": "Note",
+            "attributedTo": remote.actor_id,
+            "inReplyTo": post_url,
+            "content": "<p>Nice <b>post</b></p>",
+            "to": ["https://www.w3.org/ns/activitystreams#Public"],
+        },
+    });
+    assert_eq!(
+        remote.send(&ctx.app, &inbox_path, create.clone()).await,
+        202
+    );
+    assert_eq!(remote.send(&ctx.app, &inbox_path, create).await, 202);
+
+    let stored = Post::get(&ctx.ddb, post.pk.clone(), Some(EntityType::Post))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.likes, 1);
+    assert_eq!(stored.comments, 1);
+
+    let (pk, sk) = FederatedObject::keys(&note_id);
+    let marker = FederatedObject::get(&ctx.ddb, pk, Some(sk))
+        .await
+        .unwrap()
+        .expect("marker");
+    let comment = PostComment::get(&ctx.ddb, marker.comment_pk.unwrap(), marker.comment_sk)
+        .await
+        .unwrap()
+        .expect("comment");
+    assert_eq!(comment.content, "Nice post");
+    assert_eq!(comment.author_pk, remote.remote_pk());
+    assert!(comment.author_username.starts_with("alice@127.0.0.1:"));
+
+    // Deleting the note removes the comment again.
+    let delete = serde_json::json!({
+        "id": format!("{note_id}#delete"),
+        "type": "Delete",
+        "actor": remote.actor_id,
+        "object": { "id": note_id, "type": "Tombstone" },
+    });
+    assert_eq!(remote.send(&ctx.app, &inbox_path, delete).await, 202);
+    let stored = Post::get(&ctx.ddb, post.pk, Some(EntityType::Post))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.comments, 0);
+}
@@ -0,0 +1,242 @@
+use super::*;
+use crate::common::models::space::SpaceCommon;
+use crate::common::types::{
+    EntityType, Partition, SpacePartition, SpacePublishState, SpaceStatus, SpaceVisibility,
+};
+use crate::features::activity::models::{SpaceLeaderboardSnapshot, SpaceScore};
+use crate::features::activity::types::{
+    rank_change, rank_entries, render_leaderboard_csv, AuthorPartition, LeaderboardCategory,
+    LeaderboardPeriod, LeaderboardSnapshotEntry,
+};
+
+fn at(y: i32, m: u32, d: u32, h: u32) -> i64 {
+    chrono::NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, 0, 0)
+        .unwrap()
+        .and_utc()
+        .timestamp_millis()
+}
+
+fn entry(user: &str, total: i64, poll: i64) -> LeaderboardSnapshotEntry {
+    LeaderboardSnapshotEntry {
+        user_pk: format!("USER#{user}"),
+        name: user.to_string(),
+        total_score: total,
+        poll_score: poll,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_period_keys() {
+    // 2026-10-21 is the Wednesday of ISO week 43.
+    let wed = at(2026, 10, 21, 15);
+    assert_eq!(LeaderboardPeriod::Day.key_at(wed), "day-2026-10-21");
+    assert_eq!(LeaderboardPeriod::Week.key_at(wed), "week-2026-W43");
+    assert_eq!(LeaderboardPeriod::Day.previous_key(wed), "day-2026-10-20");
+    assert_eq!(LeaderboardPeriod::Week.previous_key(wed), "week-2026-W42");
+
+    assert_eq!(LeaderboardPeriod::Day.start_of(wed), at(2026, 10, 21, 0));
+    assert_eq!(LeaderboardPeriod::Week.start_of(wed), at(2026, 10, 19, 0));
+    // The first millisecond of a Monday already belongs to the new week.
+    let monday = at(2026, 10, 19, 0);
+    assert_eq!(LeaderboardPeriod::Week.start_of(monday), monday);
+    assert_eq!(
+        LeaderboardPeriod::Week.previous_key(monday),
+        "week-2026-W42"
+    );
+
+    // ISO week-year differs from the calendar year around New Year.
+    assert_eq!(
+        LeaderboardPeriod::Week.key_at(at(2027, 1, 1, 0)),
+        "week-2026-W53"
+    );
+}
+
+#[test]
+fn test_closed_keys_since_backfills_quiet_periods() {
+    let wed = at(2026, 10, 21, 15);
+
+    // First snapshot ever: only the period that just closed.
+    assert_eq!(
+        LeaderboardPeriod::Day.closed_keys_since(None, wed, 31),
+        vec!["day-2026-10-20"]
+    );
+    // Up to date: nothing to write.
+    assert!(LeaderboardPeriod::Day
+        .closed_keys_since(Some("day-2026-10-20"), wed, 31)
+        .is_empty());
+    // A quiet stretch: every skipped day, oldest first.
+    assert_eq!(
+        LeaderboardPeriod::Day.closed_keys_since(Some("day-2026-10-17"), wed, 31),
+        vec!["day-2026-10-18", "day-2026-10-19", "day-2026-10-20"]
+    );
+    // Across the ISO year boundary, capped to the newest periods.
+    assert_eq!(
+        LeaderboardPeriod::Week.closed_keys_since(Some("week-2026-W50"), at(2027, 1, 13, 0), 3),
+        vec!["week-2026-W52", "week-2026-W53", "week-2027-W01"]
+    );
+}
+
+#[test]
+fn test_rank_entries_ties_and_changes() {
+    let entries = vec![
+        entry("dan", 40, 0),
+        entry("amy", 70, 10),
+        entry("cat", 50, 10),
+        entry("bob", 50, 30),
+        entry("eve", 0, 0),
+    ];
+
+    let ranked: Vec<(u32, String)> = rank_entries(&entries, LeaderboardCategory::Total)
+        .into_iter()
+        .map(|(rank, e)| (rank, e.name))
+        .collect();
+    assert_eq!(
+        ranked,
+        vec![
+            (1, "amy".to_string()),
+            (2, "bob".to_string()),
+            (2, "cat".to_string()),
+            (4, "dan".to_string()),
+        ],
+        "ties share a rank and zero scores are skipped"
+    );
+
+    let poll = rank_entries(&entries, LeaderboardCategory::Poll);
+    assert_eq!(poll.len(), 3);
+    assert_eq!(poll[0].1.name, "bob");
+
+    let previous = rank_entries(
+        &[entry("amy", 10, 0), entry("dan", 20, 0)],
+        LeaderboardCategory::Total,
+    );
+    assert_eq!(rank_change(&previous, "USER#amy", 1), Some(1));
+    assert_eq!(rank_change(&previous, "USER#dan", 4), Some(-3));
+    assert_eq!(rank_change(&previous, "USER#bob", 2), None);
+}
+
+#[test]
+fn test_render_leaderboard_csv_escapes() {
+    let mut risky = entry("x", 5, 5);
+    risky.name = "=HYPERLINK(\"a\",\"b\")".to_string();
+    let ranked = vec![(1, risky), (2, entry("plain", 3, 0))];
+
+    let csv = render_leaderboard_csv(&ranked);
+    let lines: Vec<&str> = csv.split("\r\n").collect();
+    assert_eq!(
+        lines[0],
+        "rank,name,user,total,poll,quiz,discussion,follow,meet"
+    );
+    assert_eq!(
+        lines[1],
+        "1,\"'=HYPERLINK(\"\"a\"\",\"\"b\"\")\",USER#x,5,5,0,0,0,0"
+    );
+    assert_eq!(lines[2], "2,plain,USER#plain,3,0,0,0,0,0");
+    assert_eq!(lines[3], "");
+}
+
+#[tokio::test]
+async fn test_leaderboard_rank_changes_and_export() {
+    let ctx = TestContext::setup().await;
+    let space_id = uuid::Uuid::new_v4().to_string();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+
+    let mut space = SpaceCommon::default();
+    space.pk = Partition::Space(space_id.clone());
+    space.sk = EntityType::SpaceCommon;
+    space.created_at = now;
+    space.updated_at = now;
+    space.status = Some(SpaceStatus::Ongoing);
+    space.publish_state = SpacePublishState::Published;
+    space.visibility = SpaceVisibility::Public;
+    space.post_pk = Partition::Feed(space_id.clone());
+    space.user_pk = ctx.test_user.0.pk.clone();
+    space.create(&ctx.ddb).await.expect("create space");
+
+    for (user, poll) in [("amy", 30), ("bob", 20), ("cat", 50), ("dan", 0)] {
+        let mut score = SpaceScore::new(
+            SpacePartition(space_id.clone()),
+            AuthorPartition::User(user.to_string()),
+            user.to_string(),
+            String::new(),
+        );
+        score.poll_score = poll;
+        score.quiz_score = 5;
+        score.total_score = poll + 5;
+        score.create(&ctx.ddb).await.expect("create score");
+    }
+
+    // Yesterday cat trailed; today cat leads the poll board.
+    let space_pk = Partition::Space(space_id.clone());
+    let period_key = LeaderboardPeriod::Day.previous_key(now);
+    let (pk, sk) = SpaceLeaderboardSnapshot::keys(&space_pk, &period_key);
+    SpaceLeaderboardSnapshot {
+        pk,
+        sk,
+        period: LeaderboardPeriod::Day,
+        period_key: period_key.clone(),
+        created_at: now,
+        entries: vec![
+            entry("amy", 35, 30),
+            entry("bob", 25, 20),
+            entry("cat", 15, 10),
+        ],
+    }
+    .create(&ctx.ddb)
+    .await
+    .expect("create snapshot");
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/leaderboard?category=poll", space_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "leaderboard: {:?}", body);
+    assert_eq!(body["compared_to"], period_key.as_str());
+    let entries = body["entries"].as_array().unwrap();
+    assert_eq!(entries.len(), 3, "zero poll score is not ranked");
+    let row = |i: usize| {
+        (
+            entries[i]["name"].as_str().unwrap(),
+            entries[i]["rank_change"].as_i64(),
+        )
+    };
+    assert_eq!(row(0), ("cat", Some(2)));
+    assert_eq!(row(1), ("amy", Some(-1)));
+    assert_eq!(row(2), ("bob", Some(-1)));
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/leaderboard/history", space_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "history: {:?}", body);
+    assert_eq!(body["items"][0]["period_key"], period_key.as_str());
+
+    let export_path = format!(
+        "/api/spaces/{}/leaderboard/export?category=poll&period_key={}",
+        space_id, period_key
+    );
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &export_path,
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "export: {:?}", body);
+    assert_eq!(
+        body["file_name"],
+        format!("leaderboard-{}-poll.csv", period_key).as_str()
+    );
+    let content = body["content"].as_str().unwrap();
+    assert!(content.contains("1,amy,USER#amy,35,30"), "{content}");
+
+    let (_other_user, other_headers) = ctx.create_another_user().await;
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &export_path,
+        headers: other_headers,
+    };
+    assert_ne!(status, 200, "only the creator may export");
+}
@@ -1,7 +1,8 @@
 use super::*;
 use crate::common::models::space::SpaceCommon;
 use crate::common::types::{
-    EntityType, Partition, SpacePublishState, SpaceStatus, SpaceVisibility,
+    CompositePartition, EntityType, Partition, SpacePartition, SpacePublishState, SpaceStatus,
+    SpaceVisibility,
 };
 
 /// Helper: seed a public individual-owned space so the `SpaceCommon`
@@ -164,3 +165,481 @@ async fn test_delete_meet_removes_row() {
     };
     assert_ne!(status, 200, "get after delete should fail");
 }
+
+// ── Lifecycle ─────────────────────────────────────────────────────
+
+async fn create_meet_sk(ctx: &TestContext, space_id: &str) -> String {
+    let (_, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets", space_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    body["sk"].as_str().unwrap().to_string()
+}
+
+/// Run the publish hook the action-status stream would run.
+async fn publish_meet(ctx: &TestContext, space_id: &str, meet_sk: &str) {
+    use crate::features::spaces::pages::actions::actions::meet::handle_meet_published;
+    use crate::features::spaces::pages::actions::models::SpaceAction;
+
+    let action = SpaceAction::get(
+        &ctx.ddb,
+        &CompositePartition(SpacePartition(space_id.to_string()), meet_sk.to_string()),
+        Some(EntityType::SpaceAction),
+    )
+    .await
+    .unwrap()
+    .expect("space action");
+    handle_meet_published(&ctx.ddb, &action)
+        .await
+        .expect("handle_meet_published");
+}
+
+async fn load_meet(
+    ctx: &TestContext,
+    space_id: &str,
+    meet_sk: &str,
+) -> crate::features::spaces::pages::actions::actions::meet::SpaceMeet {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let meet_id: SpaceMeetEntityType = meet_sk.to_string().into();
+    SpaceMeet::get(
+        &ctx.ddb,
+        &Partition::Space(space_id.to_string()),
+        Some(EntityType::from(meet_id)),
+    )
+    .await
+    .unwrap()
+    .expect("meet row")
+}
+
+async fn set_meet_start(ctx: &TestContext, space_id: &str, meet_sk: &str, start_time: i64) {
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets/{}", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "StartTime": { "start_time": start_time } } }
+    };
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn test_meet_due_tick_deadlines() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let start = 1_000_000_000_000;
+    let mut meet = SpaceMeet {
+        start_time: start,
+        ..Default::default()
+    };
+
+    assert_eq!(meet.due_tick(start - MEET_REMINDER_LEAD_MS - 1), None);
+    assert_eq!(
+        meet.due_tick(start - MEET_REMINDER_LEAD_MS),
+        Some(MeetTick::Remind { send: true })
+    );
+    // Reminder window missed entirely: only the flag is set.
+    assert_eq!(meet.due_tick(start + 1), Some(MeetTick::Remind { send: false }));
+
+    meet.reminder_sent = true;
+    assert_eq!(meet.next_deadline(), Some(start + MEET_EXPIRE_AFTER_MS));
+    assert_eq!(meet.due_tick(start + MEET_EXPIRE_AFTER_MS), Some(MeetTick::Expire));
+
+    meet.status = MeetStatus::Live;
+    meet.live_at = Some(start);
+    assert_eq!(meet.due_tick(start + MEET_MAX_LIVE_MS), Some(MeetTick::End));
+
+    meet.status = MeetStatus::Ended;
+    assert_eq!(meet.next_deadline(), None);
+    assert_eq!(meet.schedule_keys(), (None, 0));
+}
+
+#[test]
+fn test_meet_status_transitions() {
+    use crate::features::spaces::pages::actions::actions::meet::MeetStatus::*;
+
+    assert!(Scheduled.can_transition_to(Live));
+    assert!(Scheduled.can_transition_to(Cancelled));
+    assert!(Scheduled.can_transition_to(Expired));
+    assert!(Live.can_transition_to(Ended));
+    assert!(!Live.can_transition_to(Cancelled));
+    assert!(!Ended.can_transition_to(Live));
+    assert!(!Cancelled.can_transition_to(Live));
+}
+
+#[tokio::test]
+async fn test_meet_start_then_end_is_idempotent() {
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+    let path = format!("/api/spaces/{}/meets/{}/status", space_id, meet_sk);
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": "Start" }
+    };
+    assert_eq!(status, 200, "start: {:?}", body);
+    assert_eq!(body["status"], "Live");
+    assert!(body["live_at"].as_i64().is_some());
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": "Start" }
+    };
+    assert_eq!(status, 409, "a live meet cannot be started again");
+
+    for _ in 0..2 {
+        let (status, _, body) = crate::test_post! {
+            app: ctx.app.clone(),
+            path: &path,
+            headers: ctx.test_user.1.clone(),
+            body: { "req": "End" }
+        };
+        assert_eq!(status, 200, "end: {:?}", body);
+        assert_eq!(body["status"], "Ended");
+    }
+}
+
+#[tokio::test]
+async fn test_meet_cancel_blocks_edits() {
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets/{}/status", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "Cancel": { "reason": "Speaker unavailable" } } }
+    };
+    assert_eq!(status, 200, "cancel: {:?}", body);
+    assert_eq!(body["status"], "Cancelled");
+    assert_eq!(body["cancel_reason"], "Speaker unavailable");
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app,
+        path: &format!("/api/spaces/{}/meets/{}", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "DurationMin": { "duration_min": 30 } } }
+    };
+    assert_eq!(status, 409, "cancelled meets are read-only");
+}
+
+#[tokio::test]
+async fn test_meet_expires_when_never_started() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+
+    let long_ago = crate::common::utils::time::get_now_timestamp_millis()
+        - MEET_EXPIRE_AFTER_MS
+        - 60_000;
+    set_meet_start(&ctx, &space_id, &meet_sk, long_ago).await;
+    publish_meet(&ctx, &space_id, &meet_sk).await;
+
+    // The scheduler expires it...
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    run_meet_scheduler(&ctx.ddb, now).await.expect("scheduler tick");
+
+    let (_, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/spaces/{}/meets/{}", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(body["status"], "Expired");
+
+    // ...and drops it from the due index.
+    let meet = load_meet(&ctx, &space_id, &meet_sk).await;
+    assert_eq!(meet.schedule_bucket, None);
+}
+
+#[tokio::test]
+async fn test_meet_enters_schedule_only_when_published() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+
+    // A draft is never picked up by the scheduler, even once overdue.
+    let long_ago =
+        crate::common::utils::time::get_now_timestamp_millis() - MEET_EXPIRE_AFTER_MS - 60_000;
+    set_meet_start(&ctx, &space_id, &meet_sk, long_ago).await;
+    let draft = load_meet(&ctx, &space_id, &meet_sk).await;
+    assert_eq!(draft.schedule_bucket, None);
+
+    publish_meet(&ctx, &space_id, &meet_sk).await;
+    let meet = load_meet(&ctx, &space_id, &meet_sk).await;
+    assert_eq!(meet.schedule_bucket.as_deref(), Some(MEET_SCHEDULE_BUCKET));
+    assert_eq!(meet.next_deadline_at, long_ago - MEET_REMINDER_LEAD_MS);
+}
+
+#[tokio::test]
+async fn test_meet_reminder_is_applied_once() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+    set_meet_start(&ctx, &space_id, &meet_sk, now + 5 * 60 * 1000).await;
+    publish_meet(&ctx, &space_id, &meet_sk).await;
+
+    // A read inside the reminder window leaves the reminder to the
+    // scheduler.
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets/{}", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200);
+    let snapshot = load_meet(&ctx, &space_id, &meet_sk).await;
+    assert!(!snapshot.reminder_sent, "get_meet must not send reminders");
+
+    // Two overlapping ticks working from the same read: one wins.
+    let first = advance_meet_if_due(&ctx.ddb, snapshot.clone(), now).await;
+    let second = advance_meet_if_due(&ctx.ddb, snapshot, now).await;
+    assert!(first.unwrap());
+    assert!(!second.unwrap());
+    assert!(load_meet(&ctx, &space_id, &meet_sk).await.reminder_sent);
+}
+
+#[tokio::test]
+async fn test_meet_stale_transition_is_skipped() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+    publish_meet(&ctx, &space_id, &meet_sk).await;
+
+    let snapshot = load_meet(&ctx, &space_id, &meet_sk).await;
+    let cancelled = apply_meet_transition(&ctx.ddb, snapshot.clone(), MeetStatus::Cancelled, None)
+        .await
+        .unwrap();
+    assert!(cancelled.is_some());
+
+    // The scheduler read the row before the cancel landed.
+    let expired = apply_meet_transition(&ctx.ddb, snapshot, MeetStatus::Expired, None)
+        .await
+        .unwrap();
+    assert!(expired.is_none());
+    assert_eq!(
+        load_meet(&ctx, &space_id, &meet_sk).await.status,
+        MeetStatus::Cancelled
+    );
+}
+
+#[tokio::test]
+async fn test_instant_meet_goes_live_on_publish() {
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets/{}", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "Mode": { "mode": "Instant" } } }
+    };
+    assert_eq!(status, 200);
+
+    publish_meet(&ctx, &space_id, &meet_sk).await;
+
+    let (_, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/spaces/{}/meets/{}", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(body["status"], "Live");
+}
+
+// ── Archive ───────────────────────────────────────────────────────
+
+const SAMPLE_VTT: &str = "WEBVTT
+
+NOTE exported by the meeting tool
+
+1
+00:00:01.000 --> 00:00:04.500 align:start
+<v Alice>Welcome &amp; thanks for joining</v>
+
+00:04.500 --> 00:09.000
+<v.loud Bob>Let's review the <b>budget</b>
+";
+
+#[test]
+fn test_parse_transcript_webvtt_voice_spans() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let segments = parse_transcript(TranscriptFormat::WebVtt, SAMPLE_VTT).unwrap();
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].start_ms, 1_000);
+    assert_eq!(segments[0].end_ms, 4_500);
+    assert_eq!(segments[0].speaker.as_deref(), Some("Alice"));
+    assert_eq!(segments[0].text, "Welcome & thanks for joining");
+    assert_eq!(segments[1].speaker.as_deref(), Some("Bob"));
+    assert_eq!(segments[1].text, "Let's review the budget");
+    assert_eq!(transcript_speakers(&segments), vec!["Alice", "Bob"]);
+}
+
+#[test]
+fn test_parse_transcript_srt_speaker_prefix() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let raw = "1\r\n00:00:00,000 --> 00:00:02,000\r\nAlice: Hello\r\n\r\n\
+               2\r\n00:00:02,000 --> 00:00:05,250\r\nSee https://ratel.foundation\r\n";
+    assert_eq!(TranscriptFormat::detect("call.srt", raw), TranscriptFormat::Srt);
+
+    let segments = parse_transcript(TranscriptFormat::Srt, raw).unwrap();
+    assert_eq!(segments[0].speaker.as_deref(), Some("Alice"));
+    assert_eq!(segments[0].text, "Hello");
+    assert_eq!(segments[1].speaker, None, "URL colons are not speaker labels");
+    assert_eq!(segments[1].end_ms, 5_250);
+    assert_eq!(normalize_speaker_label(" @Alice "), "alice");
+}
+
+#[test]
+fn test_parse_transcript_rejects_malformed() {
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    assert!(matches!(
+        parse_transcript(TranscriptFormat::WebVtt, "00:01.000 --> 00:02.000\nhi"),
+        Err(MeetActionError::InvalidTranscript(_))
+    ));
+    assert!(matches!(
+        parse_transcript(TranscriptFormat::Srt, "1\n00:00:05,000 --> 00:00:01,000\nbackwards"),
+        Err(MeetActionError::InvalidTranscript(_))
+    ));
+    assert!(matches!(
+        parse_transcript(TranscriptFormat::WebVtt, "WEBVTT\n\nNOTE only metadata"),
+        Err(MeetActionError::InvalidTranscript(_))
+    ));
+}
+
+#[test]
+fn test_chunk_transcript_windows() {
+    use crate::features::rag::qdrant::indexers::chunk_transcript;
+    use crate::features::spaces::pages::actions::actions::meet::*;
+
+    let segments = parse_transcript(TranscriptFormat::WebVtt, SAMPLE_VTT).unwrap();
+
+    let one = chunk_transcript(&segments, 10_000);
+    assert_eq!(one.len(), 1);
+    assert_eq!((one[0].start_ms, one[0].end_ms), (1_000, 9_000));
+    assert_eq!(one[0].speakers, vec!["Alice", "Bob"]);
+    assert!(one[0].content.starts_with("Alice: Welcome"));
+
+    let split = chunk_transcript(&segments, 10);
+    assert_eq!(split.len(), 2);
+    assert_eq!(split[1].speakers, vec!["Bob"]);
+}
+
+#[test]
+fn test_match_meet_speakers_prefers_ids_and_skips_ambiguous_labels() {
+    use crate::common::models::space::SpaceParticipant;
+    use crate::features::spaces::pages::actions::actions::meet::*;
+    use std::collections::HashMap;
+
+    let participant = |id: &str, username: &str, display_name: &str| SpaceParticipant {
+        user_pk: Partition::User(id.to_string()),
+        username: username.to_string(),
+        display_name: display_name.to_string(),
+        ..Default::default()
+    };
+    let participants = vec![
+        participant("u1", "alice-kim", "Alice"),
+        participant("u2", "alice-lee", "Alice"),
+        participant("u3", "bob", "Bob Park"),
+    ];
+    let speakers = vec!["Alice".to_string(), "@bob".to_string()];
+
+    // Two participants share the display name: the bare label is skipped.
+    let matched = match_meet_speakers(&participants, &speakers, &HashMap::new());
+    let ids: Vec<_> = matched.iter().map(|(p, _)| p.user_pk.clone()).collect();
+    assert_eq!(ids, vec![Partition::User("u3".to_string())]);
+
+    // The provider id pins the label to exactly one participant.
+    let speaker_ids = HashMap::from([("Alice".to_string(), "u2".to_string())]);
+    let matched = match_meet_speakers(&participants, &speakers, &speaker_ids);
+    let ids: Vec<_> = matched.iter().map(|(p, _)| p.user_pk.clone()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            Partition::User("u2".to_string()),
+            Partition::User("u3".to_string())
+        ]
+    );
+
+    // A mapped id that matches nobody never falls back to the name.
+    let speaker_ids = HashMap::from([("@bob".to_string(), "guest-7".to_string())]);
+    let matched = match_meet_speakers(&participants, &speakers, &speaker_ids);
+    assert!(matched.is_empty());
+}
+
+#[tokio::test]
+async fn test_meet_archive_upload_and_search() {
+    let ctx = TestContext::setup().await;
+    let space_id = seed_creator_space(&ctx).await;
+    let meet_sk = create_meet_sk(&ctx, &space_id).await;
+    let archive_path = format!("/api/spaces/{}/meets/{}/archive", space_id, meet_sk);
+    let archive_req = serde_json::json!({
+        "recording_url": "https://cdn.example/meet.mp4",
+        "transcript_file_name": "meet.vtt",
+        "transcript": SAMPLE_VTT,
+    });
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &archive_path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": archive_req.clone() }
+    };
+    assert_eq!(status, 409, "archives require an ended meet");
+
+    let status_path = format!("/api/spaces/{}/meets/{}/status", space_id, meet_sk);
+    for req in ["Start", "End"] {
+        let (status, _, _) = crate::test_post! {
+            app: ctx.app.clone(),
+            path: &status_path,
+            headers: ctx.test_user.1.clone(),
+            body: { "req": req }
+        };
+        assert_eq!(status, 200);
+    }
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &archive_path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": archive_req.clone() }
+    };
+    assert_eq!(status, 200, "upload: {:?}", body);
+    assert_eq!(body["segment_count"], 2);
+    assert_eq!(body["duration_ms"], 9_000);
+    assert_eq!(body["speakers"], serde_json::json!(["Alice", "Bob"]));
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &archive_path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": archive_req }
+    };
+    assert_eq!(status, 409, "one archive per meet");
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/spaces/{}/meets/{}/transcript?q=BUDGET", space_id, meet_sk),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "transcript: {:?}", body);
+    let segments = body["segments"].as_array().unwrap();
+    assert_eq!(segments.len(), 1);
+    assert_eq!(segments[0]["speaker"], "Bob");
+}
@@ -5,25 +5,34 @@ pub mod setup;
 // after `use super::*;` without each declaring its own `use crate::axum;`.
 pub use crate::axum;
 
+mod action_graph_tests;
+mod activity_streak_tests;
 mod arcade_tests;
 mod auth_tests;
+mod calendar_tests;
 mod cors_tests;
 mod cross_posting_tests;
 mod discussion_subscription_tests;
 mod discussion_tests;
 mod fact_or_fold_tests;
+mod federation_tests;
 mod get_space_admin_tests;
 mod home_tests;
 mod inbox_helper_tests;
+mod leaderboard_tests;
 mod launchpad_partner_tests;
 mod mcp_tests;
 mod meet_action_tests;
 mod notifications_tests;
 mod post_tests;
+mod quiz_engine_tests;
+mod realtime_tests;
 mod report_tests;
+mod reward_rules_tests;
 mod space_action_notification_tests;
 mod space_member_tests;
 mod space_status_change_tests;
+mod space_template_tests;
 mod sub_team_tests;
 mod xp_dedup_tests;
 
@@ -0,0 +1,228 @@
+use crate::features::spaces::pages::actions::actions::poll::{Answer, ChoiceQuestion, Question};
+use crate::features::spaces::pages::actions::actions::quiz::controllers::grade_attempt;
+use crate::features::spaces::pages::actions::actions::quiz::{
+    draw_questions, grade_answer, present_question, restore_answer, score_from_results,
+    QuizCorrectAnswer, QuizDrawnQuestion, QuizQuestionResult, SpaceQuiz, FULL_CREDIT,
+};
+use crate::features::spaces::pages::apps::apps::analyzes::QuizQuestionDifficulty;
+
+fn single(title: &str, options: &[&str]) -> Question {
+    Question::SingleChoice(ChoiceQuestion {
+        title: title.to_string(),
+        options: options.iter().map(|o| o.to_string()).collect(),
+        ..Default::default()
+    })
+}
+
+fn multiple(title: &str, options: &[&str]) -> Question {
+    Question::MultipleChoice(ChoiceQuestion {
+        title: title.to_string(),
+        options: options.iter().map(|o| o.to_string()).collect(),
+        ..Default::default()
+    })
+}
+
+fn picks(answer: &[i32]) -> Answer {
+    Answer::MultipleChoice {
+        answer: Some(answer.to_vec()),
+        other: None,
+    }
+}
+
+#[test]
+fn test_draw_questions_pool_and_shuffle() {
+    let bank: Vec<Question> = (0..10)
+        .map(|i| single(&format!("q{i}"), &["a", "b", "c", "d"]))
+        .collect();
+    let mut rng = rand::rng();
+
+    let all = draw_questions(&bank, 0, false, &mut rng);
+    assert_eq!(
+        all.iter().map(|d| d.question_index).collect::<Vec<_>>(),
+        (0..10).collect::<Vec<_>>(),
+        "no pool presents the whole bank in order"
+    );
+    assert!(all.iter().all(|d| d.option_order == vec![0, 1, 2, 3]));
+
+    for _ in 0..20 {
+        let drawn = draw_questions(&bank, 4, true, &mut rng);
+        assert_eq!(drawn.len(), 4);
+        let mut indices: Vec<usize> = drawn.iter().map(|d| d.question_index).collect();
+        indices.sort_unstable();
+        indices.dedup();
+        assert_eq!(indices.len(), 4, "a question is drawn at most once");
+        assert!(indices.iter().all(|i| *i < bank.len()));
+        for d in &drawn {
+            let mut order = d.option_order.clone();
+            order.sort_unstable();
+            assert_eq!(order, vec![0, 1, 2, 3], "option order is a permutation");
+        }
+    }
+
+    assert_eq!(draw_questions(&bank, 25, false, &mut rng).len(), 10);
+}
+
+#[test]
+fn test_present_and_restore_round_trip() {
+    let question = multiple("pick", &["a", "b", "c", "d"]);
+    let order = vec![2, 0, 3, 1];
+
+    match present_question(&question, &order) {
+        Question::MultipleChoice(q) => assert_eq!(q.options, vec!["c", "a", "d", "b"]),
+        other => panic!("unexpected question: {other:?}"),
+    }
+
+    // Shown "c" and "b" sit at positions 0 and 3; 9 is out of range.
+    assert_eq!(restore_answer(&picks(&[0, 3, 9]), &order), picks(&[2, 1]));
+    assert_eq!(
+        restore_answer(
+            &Answer::SingleChoice {
+                answer: Some(1),
+                other: None,
+            },
+            &order,
+        ),
+        Answer::SingleChoice {
+            answer: Some(0),
+            other: None,
+        }
+    );
+}
+
+#[test]
+fn test_grade_answer_partial_credit() {
+    let question = multiple("pick", &["a", "b", "c", "d", "e"]);
+    let correct = QuizCorrectAnswer::Multiple {
+        answers: vec![0, 1, 2],
+    };
+    let grade =
+        |answer: &[i32], partial: bool| grade_answer(&question, &correct, &picks(answer), partial);
+
+    assert_eq!(grade(&[2, 1, 0], false), FULL_CREDIT);
+    assert_eq!(grade(&[0, 1], false), 0);
+    assert_eq!(grade(&[0, 1], true), 66);
+    assert_eq!(grade(&[0, 1, 3], true), 33);
+    assert_eq!(
+        grade(&[0, 1, 2, 3, 4], true),
+        0,
+        "ticking every box never pays"
+    );
+    assert_eq!(grade(&[3], true), 0);
+
+    let single_q = single("one", &["a", "b"]);
+    let single_correct = QuizCorrectAnswer::Single { answer: Some(1) };
+    let answer = |i: Option<i32>| Answer::SingleChoice {
+        answer: i,
+        other: None,
+    };
+    assert_eq!(
+        grade_answer(&single_q, &single_correct, &answer(Some(1)), true),
+        FULL_CREDIT
+    );
+    assert_eq!(
+        grade_answer(&single_q, &single_correct, &answer(None), true),
+        0
+    );
+
+    let results = [100, 50, 50, 33]
+        .into_iter()
+        .enumerate()
+        .map(|(question_index, credit)| QuizQuestionResult {
+            question_index,
+            credit,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(score_from_results(&results), 2);
+}
+
+#[test]
+fn test_grade_attempt_maps_back_to_bank_order() {
+    let quiz = SpaceQuiz {
+        questions: vec![
+            single("q0", &["a", "b"]),
+            multiple("q1", &["a", "b", "c"]),
+            single("q2", &["a", "b", "c"]),
+        ],
+        partial_credit: true,
+        ..Default::default()
+    };
+    let correct = vec![
+        QuizCorrectAnswer::Single { answer: Some(0) },
+        QuizCorrectAnswer::Multiple {
+            answers: vec![0, 2],
+        },
+        QuizCorrectAnswer::Single { answer: Some(2) },
+    ];
+    // Only q2 (options reversed) and q1 (as is) were drawn.
+    let drawn = vec![
+        QuizDrawnQuestion {
+            question_index: 2,
+            option_order: vec![2, 1, 0],
+        },
+        QuizDrawnQuestion {
+            question_index: 1,
+            option_order: vec![0, 1, 2],
+        },
+    ];
+    let answers = vec![
+        Answer::SingleChoice {
+            answer: Some(0),
+            other: None,
+        },
+        picks(&[0]),
+    ];
+
+    let (bank_answers, results) =
+        grade_attempt(&quiz, &correct, &drawn, &answers).expect("grade attempt");
+    assert_eq!(bank_answers.len(), 3);
+    assert_eq!(
+        bank_answers[2],
+        Answer::SingleChoice {
+            answer: Some(2),
+            other: None,
+        }
+    );
+    assert_eq!(
+        bank_answers[0],
+        Answer::SingleChoice {
+            answer: None,
+            other: None,
+        },
+        "undrawn questions stay unanswered"
+    );
+    assert_eq!(
+        results,
+        vec![
+            QuizQuestionResult {
+                question_index: 2,
+                credit: FULL_CREDIT,
+            },
+            QuizQuestionResult {
+                question_index: 1,
+                credit: 50,
+            },
+        ]
+    );
+
+    assert!(
+        grade_attempt(&quiz, &correct, &drawn, &answers[..1]).is_err(),
+        "one answer per drawn question"
+    );
+}
+
+#[test]
+fn test_question_difficulty_rating() {
+    assert_eq!(QuizQuestionDifficulty::rate(4, 100), None);
+    assert_eq!(
+        QuizQuestionDifficulty::rate(5, 75),
+        Some(QuizQuestionDifficulty::Easy)
+    );
+    assert_eq!(
+        QuizQuestionDifficulty::rate(50, 40),
+        Some(QuizQuestionDifficulty::Medium)
+    );
+    assert_eq!(
+        QuizQuestionDifficulty::rate(50, 39),
+        Some(QuizQuestionDifficulty::Hard)
+    );
+}
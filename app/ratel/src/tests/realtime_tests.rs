@@ -0,0 +1,197 @@
+use super::*;
+use crate::common::realtime::RealtimePollResponse;
+use crate::common::types::InboxPayload;
+use crate::common::utils::inbox::create_inbox_row;
+use crate::features::notifications::types::MarkAllReadResponse;
+
+fn reply_payload(content: &str) -> InboxPayload {
+    InboxPayload::ReplyOnComment {
+        space_id: None,
+        post_id: None,
+        comment_preview: content.into(),
+        replier_name: "bob".into(),
+        replier_profile_url: String::new(),
+        cta_url: "/posts/xyz".into(),
+    }
+}
+
+#[tokio::test]
+async fn test_realtime_events_rejects_unknown_channel() {
+    let ctx = TestContext::setup().await;
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app,
+        path: "/api/realtime/events?channel=unknown_kind:abc",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, axum::http::StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_realtime_inbox_rejects_other_user() {
+    let ctx = TestContext::setup().await;
+    let (other, _) = ctx.create_another_user().await;
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/realtime/events?channel=user.inbox:{}", other.id()),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(
+        status,
+        axum::http::StatusCode::FORBIDDEN,
+        "only the owner may subscribe to an inbox channel"
+    );
+}
+
+#[tokio::test]
+async fn test_realtime_poll_delivers_inbox_changed() {
+    let ctx = TestContext::setup().await;
+    let user_pk = ctx.test_user.0.pk.clone();
+    let channel = format!("user.inbox:{}", ctx.test_user.0.id());
+
+    let (status, _, first) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/realtime/poll?channel={channel}"),
+        headers: ctx.test_user.1.clone(),
+        response_type: RealtimePollResponse,
+    };
+    assert_eq!(status, 200, "first poll: {:?}", first);
+    assert!(first.initial_state.is_some());
+    assert!(first.events.is_empty());
+
+    create_inbox_row(user_pk, reply_payload("hi"))
+        .await
+        .unwrap();
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/inbox/read-all",
+        headers: ctx.test_user.1.clone(),
+        response_type: MarkAllReadResponse,
+    };
+    assert_eq!(status, 200);
+
+    let (status, _, next) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: &format!("/api/realtime/poll?channel={channel}&since={}", first.last_id),
+        headers: ctx.test_user.1.clone(),
+        response_type: RealtimePollResponse,
+    };
+    assert_eq!(status, 200, "second poll: {:?}", next);
+    assert!(next.initial_state.is_none());
+    assert!(
+        next.events.iter().any(|e| e.name == "inbox_changed"),
+        "mark-all-read must publish inbox_changed: {:?}",
+        next.events
+    );
+    assert!(next.last_id > first.last_id);
+}
+
+#[tokio::test]
+async fn test_realtime_poll_rejects_other_user_inbox() {
+    let ctx = TestContext::setup().await;
+    let (other, _) = ctx.create_another_user().await;
+    let (status, _, _) = crate::test_get! {
+        app: ctx.app,
+        path: &format!("/api/realtime/poll?channel=user.inbox:{}", other.id()),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, axum::http::StatusCode::FORBIDDEN);
+}
+
+// ── DynamoStreamBackplane ───────────────────────────────────────────
+
+fn dynamo_test_channel() -> crate::common::realtime::channel::ChannelId {
+    crate::common::realtime::channel::ChannelId(format!("echo:{}", uuid::Uuid::new_v4().simple()))
+}
+
+#[tokio::test]
+async fn test_dynamo_backplane_ids_are_monotonic_across_instances() {
+    use crate::common::realtime::backplane::{Backplane, DynamoStreamBackplane};
+
+    let ctx = TestContext::setup().await;
+    // Two backplanes on one table stand in for two app instances.
+    let a = DynamoStreamBackplane::new(ctx.ddb.clone());
+    let b = DynamoStreamBackplane::new(ctx.ddb.clone());
+    let ch = dynamo_test_channel();
+    let other = dynamo_test_channel();
+
+    let first = a.append(&ch, "tick", serde_json::json!(1)).await.unwrap();
+    let second = b.append(&ch, "tick", serde_json::json!(2)).await.unwrap();
+    let third = a.append(&ch, "tick", serde_json::json!(3)).await.unwrap();
+    assert_eq!((first.id, second.id, third.id), (1, 2, 3));
+
+    let separate = b
+        .append(&other, "tick", serde_json::json!(4))
+        .await
+        .unwrap();
+    assert_eq!(separate.id, 1, "ids are per channel");
+}
+
+#[tokio::test]
+async fn test_dynamo_backplane_since_returns_only_missed_events() {
+    use crate::common::realtime::backplane::{Backplane, DynamoStreamBackplane};
+
+    let ctx = TestContext::setup().await;
+    let writer = DynamoStreamBackplane::new(ctx.ddb.clone());
+    let reader = DynamoStreamBackplane::new(ctx.ddb.clone());
+    let ch = dynamo_test_channel();
+    for n in 0..5 {
+        writer
+            .append(&ch, "tick", serde_json::json!({ "n": n }))
+            .await
+            .unwrap();
+    }
+
+    let missed = reader.since(&ch, 3).await.unwrap();
+    let ids: Vec<u64> = missed.iter().map(|e| e.id).collect();
+    assert_eq!(ids, vec![4, 5], "replay is oldest first on any instance");
+    assert_eq!(missed[0].name, "tick");
+    assert_eq!(missed[0].channel, ch);
+    assert_eq!(missed[0].payload, serde_json::json!({ "n": 3 }));
+
+    assert!(reader.since(&ch, 5).await.unwrap().is_empty());
+    assert!(reader
+        .since(&dynamo_test_channel(), 0)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_dynamo_backplane_tail_reaches_other_hub_once() {
+    use crate::common::realtime::backplane::{DynamoStreamBackplane, TAIL_INTERVAL};
+    use crate::common::realtime::channel::InProcessChannel;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let ctx = TestContext::setup().await;
+    let publisher =
+        InProcessChannel::with_backplane(Arc::new(DynamoStreamBackplane::new(ctx.ddb.clone())), 8);
+    let remote =
+        InProcessChannel::with_backplane(Arc::new(DynamoStreamBackplane::new(ctx.ddb.clone())), 8);
+    let ch = dynamo_test_channel();
+    let mut local_rx = publisher.subscribe_stream(&ch).await;
+    let mut remote_rx = remote.subscribe_stream(&ch).await;
+
+    let id = publisher
+        .publish(&ch, "chat", serde_json::json!({ "text": "hi" }))
+        .await
+        .unwrap();
+    assert_eq!(local_rx.recv().await.unwrap().id, id);
+
+    // The remote hub reads the row on its next tail of the log.
+    let got = tokio::time::timeout(TAIL_INTERVAL * 5, remote_rx.recv())
+        .await
+        .expect("remote hub tails the log")
+        .unwrap();
+    assert_eq!(got.id, id);
+    assert_eq!(got.name, "chat");
+    assert_eq!(got.payload, serde_json::json!({ "text": "hi" }));
+
+    // Another tick: both tails have now seen the row.
+    tokio::time::sleep(TAIL_INTERVAL * 2 + Duration::from_millis(200)).await;
+    assert!(remote_rx.try_recv().is_err(), "redelivery is dropped");
+    assert!(
+        local_rx.try_recv().is_err(),
+        "publisher ignores its own row"
+    );
+}
@@ -0,0 +1,291 @@
+use super::*;
+use crate::common::models::space::SpaceCommon;
+use crate::common::types::{
+    EntityType, MembershipMultiplier, Partition, RewardClaimContext, RewardCondition,
+    RewardPeriod, RewardPointTier, RewardRules, RewardTimeWindow, RewardUsage,
+    RewardUserBehavior, SpacePartition, SpacePublishState, SpaceStatus, SpaceVisibility,
+};
+use crate::features::membership::models::MembershipTier;
+use crate::features::spaces::space_common::models::space_reward::SpaceReward;
+
+fn launch_rules() -> RewardRules {
+    RewardRules {
+        conditions: vec![RewardCondition::MaxClaims(3)],
+        time_windows: vec![RewardTimeWindow {
+            starts_at: 1_000,
+            ends_at: 2_000,
+            multiplier_percent: 200,
+        }],
+        point_tiers: vec![RewardPointTier {
+            max_claims: 1,
+            point: 30,
+        }],
+        membership_multipliers: vec![MembershipMultiplier {
+            tier: MembershipTier::Enterprise("acme".into()),
+            multiplier_percent: 150,
+        }],
+        skill_multipliers: false,
+    }
+}
+
+#[test]
+fn test_reward_rules_compose() {
+    let rules = launch_rules();
+    let mut ctx = RewardClaimContext {
+        at: 500,
+        ..Default::default()
+    };
+
+    // First claim sits in the tier; later claims fall back to `point`.
+    assert_eq!(rules.amount(10, 2, &ctx), 60);
+    ctx.usage.total_claims = 1;
+    assert_eq!(rules.amount(10, 2, &ctx), 20);
+
+    // Launch window doubles, and any Enterprise plan adds 50% on top.
+    ctx.at = 1_500;
+    assert_eq!(rules.amount(10, 2, &ctx), 40);
+    ctx.membership_tier = Some(MembershipTier::Enterprise("other".into()));
+    assert_eq!(rules.amount(10, 2, &ctx), 60);
+    ctx.at = 2_000;
+    assert_eq!(rules.amount(10, 2, &ctx), 30);
+
+    // Conditions are AND-ed with the reward's own.
+    let usage = RewardUsage {
+        total_claims: 3,
+        ..Default::default()
+    };
+    assert!(rules.check(&RewardCondition::None, &usage).is_err());
+    let usage = RewardUsage {
+        total_claims: 1,
+        user_points: 5,
+        ..Default::default()
+    };
+    assert!(rules.check(&RewardCondition::None, &usage).is_ok());
+    assert!(rules
+        .check(&RewardCondition::MaxUserPoints(5), &usage)
+        .is_err());
+}
+
+#[test]
+fn test_reward_rules_validate() {
+    assert!(RewardRules::default().validate(10).is_ok());
+    assert!(launch_rules().validate(10).is_ok());
+
+    let mut rules = launch_rules();
+    rules.time_windows[0].ends_at = rules.time_windows[0].starts_at;
+    assert!(rules.validate(10).is_err());
+
+    let mut rules = launch_rules();
+    rules.point_tiers.push(RewardPointTier {
+        max_claims: 1,
+        point: 15,
+    });
+    assert!(rules.validate(10).is_err());
+
+    let mut rules = launch_rules();
+    rules.conditions.push(RewardCondition::None);
+    assert!(rules.validate(10).is_err());
+
+    let mut rules = launch_rules();
+    rules.membership_multipliers[0].multiplier_percent = RewardRules::MAX_MULTIPLIER_PERCENT + 1;
+    assert!(rules.validate(10).is_err());
+
+    // A tier may pay at most MAX_MULTIPLIER_PERCENT of the base point.
+    let mut rules = launch_rules();
+    rules.point_tiers[0].point = 101;
+    assert!(rules.validate(10).is_err());
+    assert!(rules.validate(11).is_ok());
+}
+
+#[test]
+fn test_reward_rules_payout_is_capped() {
+    let max = RewardRules::MAX_MULTIPLIER_PERCENT;
+    let rules = RewardRules {
+        time_windows: vec![RewardTimeWindow {
+            starts_at: 0,
+            ends_at: 1_000,
+            multiplier_percent: max,
+        }],
+        point_tiers: vec![RewardPointTier {
+            max_claims: 1,
+            point: 1_000_000,
+        }],
+        membership_multipliers: vec![MembershipMultiplier {
+            tier: MembershipTier::Enterprise("acme".into()),
+            multiplier_percent: max,
+        }],
+        ..Default::default()
+    };
+    let mut ctx = RewardClaimContext {
+        at: 500,
+        membership_tier: Some(MembershipTier::Enterprise("acme".into())),
+        ..Default::default()
+    };
+
+    // Window and membership multipliers don't stack past the ceiling.
+    assert_eq!(
+        rules.multiplier_percent(ctx.at, ctx.membership_tier.as_ref()),
+        max
+    );
+
+    // Neither does a stored tier that predates the point cap.
+    assert_eq!(rules.point_for(10, 0), 10 * max / 100);
+    assert_eq!(rules.amount(10, 2, &ctx), RewardRules::max_amount(10, 2));
+    ctx.usage.total_claims = 1;
+    assert_eq!(rules.amount(10, 2, &ctx), RewardRules::max_amount(10, 2));
+}
+
+#[test]
+fn test_reward_rules_bill_the_worst_case() {
+    // No rules: the flat credits.
+    assert_eq!(RewardRules::default().billed_credits(10, 2), 2);
+
+    // Tier 3× × window 2× × membership 1.5× = 9×; the best claim pays
+    // exactly what was billed.
+    let rules = launch_rules();
+    assert_eq!(rules.worst_case_percent(10), 900);
+    assert_eq!(rules.billed_credits(10, 2), 18);
+    let ctx = RewardClaimContext {
+        at: 1_500,
+        membership_tier: Some(MembershipTier::Enterprise("acme".into())),
+        ..Default::default()
+    };
+    assert_eq!(rules.amount(10, 2, &ctx), 10 * 18);
+
+    // Multipliers below 100% never bill less than the flat credits, and
+    // the ceiling caps the bill like the payout.
+    let mut rules = launch_rules();
+    rules.point_tiers.clear();
+    rules.time_windows[0].multiplier_percent = 50;
+    rules.membership_multipliers[0].multiplier_percent = 50;
+    assert_eq!(rules.billed_credits(10, 2), 2);
+    rules.time_windows[0].multiplier_percent = RewardRules::MAX_MULTIPLIER_PERCENT;
+    rules.membership_multipliers[0].multiplier_percent = RewardRules::MAX_MULTIPLIER_PERCENT;
+    assert_eq!(rules.billed_credits(10, 2), 20);
+}
+
+#[test]
+fn test_reward_rules_project_stops_at_limit() {
+    let rules = launch_rules();
+    let ctx = RewardClaimContext {
+        at: 500,
+        ..Default::default()
+    };
+    let projection = rules.project(10, 1, &RewardCondition::None, 10, ctx, |v| v * 2);
+
+    // MaxClaims(3) caps the run; skills are off, so `boost` is ignored.
+    assert_eq!(projection.paid_claims, 3);
+    assert_eq!(projection.first_payout, 30);
+    assert_eq!(projection.last_payout, 10);
+    assert_eq!(projection.total_points, 50);
+}
+
+#[tokio::test]
+async fn test_award_applies_rules() {
+    let ctx = TestContext::setup().await;
+    let nonce = uuid::Uuid::new_v4().to_string();
+    let mut reward = SpaceReward::new(
+        SpacePartition(format!("space-rules-{nonce}")),
+        format!("action-rules-{nonce}"),
+        RewardUserBehavior::RespondPoll,
+        "rules".into(),
+        1,
+        100,
+        RewardPeriod::Once,
+        RewardCondition::None,
+    );
+    reward.rules = RewardRules {
+        conditions: vec![RewardCondition::MaxClaims(2)],
+        point_tiers: vec![RewardPointTier {
+            max_claims: 1,
+            point: 500,
+        }],
+        skill_multipliers: false,
+        ..Default::default()
+    };
+    reward.create(&ctx.ddb).await.unwrap();
+
+    let first = SpaceReward::award(&ctx.ddb, &reward, Partition::User(format!("a-{nonce}")), None)
+        .await
+        .unwrap();
+    assert_eq!(first.total_points, 500, "early responder tier");
+
+    let stored = SpaceReward::get(&ctx.ddb, &reward.pk, Some(reward.sk.clone()))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.total_points, 500);
+    let second = SpaceReward::award(&ctx.ddb, &stored, Partition::User(format!("b-{nonce}")), None)
+        .await
+        .unwrap();
+    assert_eq!(second.total_points, 100);
+
+    let stored = SpaceReward::get(&ctx.ddb, &reward.pk, Some(reward.sk.clone()))
+        .await
+        .unwrap()
+        .unwrap();
+    let third = SpaceReward::award(&ctx.ddb, &stored, Partition::User(format!("c-{nonce}")), None)
+        .await;
+    assert!(third.is_err(), "rule condition MaxClaims(2) must reject");
+}
+
+#[tokio::test]
+async fn test_simulate_space_rewards() {
+    let ctx = TestContext::setup().await;
+    let space_id = uuid::Uuid::new_v4().to_string();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+
+    let mut space = SpaceCommon::default();
+    space.pk = Partition::Space(space_id.clone());
+    space.sk = EntityType::SpaceCommon;
+    space.created_at = now;
+    space.updated_at = now;
+    space.status = Some(SpaceStatus::Ongoing);
+    space.publish_state = SpacePublishState::Published;
+    space.visibility = SpaceVisibility::Public;
+    space.post_pk = Partition::Feed(space_id.clone());
+    space.user_pk = ctx.test_user.0.pk.clone();
+    space.create(&ctx.ddb).await.expect("create space");
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/meets", space_id),
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "create meet: {:?}", body);
+    let meet_sk = body["sk"].as_str().unwrap().to_string();
+
+    let path = format!("/api/spaces/{}/rewards/simulate", space_id);
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "participants": 0 } }
+    };
+    assert_eq!(status, 400);
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &path,
+        headers: ctx.test_user.1.clone(),
+        body: { "req": { "participants": 3, "credits": 2 } }
+    };
+    assert_eq!(status, 200, "simulate: {:?}", body);
+    let item = body["items"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|i| i["action_id"] == meet_sk.as_str())
+        .expect("meet row");
+    assert_eq!(item["configured"], false);
+    assert_eq!(item["credits"], 2);
+    assert_eq!(item["paid_claims"], 3);
+    let first = item["first_payout"].as_i64().unwrap();
+    assert!(first > 0);
+    assert_eq!(item["total_points"].as_i64().unwrap(), first * 3);
+
+    // Dry run: nothing was written.
+    let rewards =
+        SpaceReward::list_by_action(&ctx.ddb, SpacePartition(space_id.clone()), None).await;
+    assert!(rewards.unwrap().is_empty());
+}
@@ -33,15 +33,18 @@ impl TestContext {
         let arcade_router = crate::features::arcade::server::router();
         let dioxus_router = dioxus::server::router(App)
             .merge(mcp_router)
+            .merge(crate::common::realtime::sse::router())
             .merge(arcade_router)
-            .merge(crate::features::launchpad_partner::server::router());
+            .merge(crate::features::launchpad_partner::server::router())
+            .merge(crate::features::calendar::server::router())
+            .merge(crate::features::federation::server::router());
         let app = dioxus_router.layer(session_layer);
         crate::common::mcp::set_app_router(app.clone());
 
-        // Match the production startup sequence: register arcade
-        // realtime channels with the per-process global hub so the
-        // SSE endpoint can resolve handlers in tests too.
-        crate::features::arcade::games::fact_or_fold::realtime::register_channels().await;
+        // Match the production startup sequence: register realtime
+        // channels with the per-process global hub so the SSE endpoint
+        // can resolve handlers in tests too.
+        crate::common::realtime::register_channels().await;
 
         let ddb = cli.clone();
         let test_user = create_user_session(app.clone(), &ddb).await;
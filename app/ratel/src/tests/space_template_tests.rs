@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use super::*;
+use crate::common::models::space::SpaceCommon;
+use crate::common::types::{
+    CompositePartition, EntityType, Partition, SpacePartition, SpacePublishState, SpaceStatus,
+    SpaceVisibility,
+};
+use crate::features::spaces::pages::actions::actions::poll::SpacePoll;
+use crate::features::spaces::pages::actions::models::SpaceAction;
+use crate::features::spaces::pages::actions::types::{
+    ActionDependency, SpaceActionType, UnlockCondition,
+};
+use crate::features::spaces::templates::{SpaceTemplateAction, TemplateAction};
+
+#[test]
+fn test_remap_dependencies_drops_uncaptured_actions() {
+    let action = TemplateAction {
+        action_id: "c".to_string(),
+        action_type: SpaceActionType::Quiz,
+        depends_on: vec!["a".to_string(), "gone".to_string()],
+        dependency_conditions: vec![
+            ActionDependency {
+                action_id: "a".to_string(),
+                condition: UnlockCondition::QuizPassed { min_score: Some(2) },
+            },
+            ActionDependency {
+                action_id: "gone".to_string(),
+                condition: UnlockCondition::Completed,
+            },
+        ],
+        ..Default::default()
+    };
+    let ids = HashMap::from([
+        ("a".to_string(), "new-a".to_string()),
+        ("c".to_string(), "new-c".to_string()),
+    ]);
+
+    let (depends_on, conditions) = action.remap_dependencies(&ids);
+    assert_eq!(depends_on, vec!["new-a".to_string()]);
+    assert_eq!(conditions.len(), 1);
+    assert_eq!(conditions[0].action_id, "new-a");
+    assert_eq!(
+        conditions[0].condition,
+        UnlockCondition::QuizPassed { min_score: Some(2) }
+    );
+}
+
+#[tokio::test]
+async fn test_save_template_and_create_space_from_it() {
+    let ctx = TestContext::setup().await;
+    let space_id = uuid::Uuid::new_v4().to_string();
+    let now = crate::common::utils::time::get_now_timestamp_millis();
+
+    let mut space = SpaceCommon::default();
+    space.pk = Partition::Space(space_id.clone());
+    space.sk = EntityType::SpaceCommon;
+    space.created_at = now;
+    space.updated_at = now;
+    space.status = Some(SpaceStatus::Ongoing);
+    space.publish_state = SpacePublishState::Published;
+    space.visibility = SpaceVisibility::Public;
+    space.post_pk = Partition::Feed(space_id.clone());
+    space.user_pk = ctx.test_user.0.pk.clone();
+    space.join_anytime = true;
+    space.create(&ctx.ddb).await.expect("create space");
+
+    // poll <- follow
+    let mut poll = SpacePoll::new(SpacePartition(space_id.clone())).unwrap();
+    poll.title = "Warm-up".to_string();
+    poll.create(&ctx.ddb).await.expect("create poll");
+    let poll_id = match &poll.sk {
+        EntityType::SpacePoll(id) => id.clone(),
+        _ => unreachable!(),
+    };
+    let mut poll_action = SpaceAction::new(
+        SpacePartition(space_id.clone()),
+        poll_id.clone(),
+        SpaceActionType::Poll,
+    );
+    poll_action.title = "Warm-up".to_string();
+    poll_action
+        .create(&ctx.ddb)
+        .await
+        .expect("create poll action");
+    let follow_id = uuid::Uuid::new_v4().to_string();
+    let mut follow_action = SpaceAction::new(
+        SpacePartition(space_id.clone()),
+        follow_id.clone(),
+        SpaceActionType::Follow,
+    );
+    follow_action.depends_on = vec![poll_id.clone()];
+    follow_action
+        .create(&ctx.ddb)
+        .await
+        .expect("create follow action");
+
+    let (status, _, _) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/templates", space_id),
+        headers: ctx.test_user.1.clone(),
+        body: { "name": "  ", "description": null, "team_id": null }
+    };
+    assert_eq!(status, 400, "blank name must be rejected");
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/spaces/{}/templates", space_id),
+        headers: ctx.test_user.1.clone(),
+        body: { "name": "Onboarding", "description": "Poll then follow", "team_id": null }
+    };
+    assert_eq!(status, 200, "save template: {:?}", body);
+    assert_eq!(body["action_count"], 2);
+    let template_id = body["template_id"].as_str().unwrap().to_string();
+
+    // Actions are stored as child rows, in capture order.
+    let (rows, _) = SpaceTemplateAction::query(
+        &ctx.ddb,
+        Partition::SpaceTemplate(template_id.clone()),
+        SpaceTemplateAction::opt_all().sk("SPACE_TEMPLATE_ACTION#".to_string()),
+    )
+    .await
+    .unwrap();
+    let captured: Vec<&str> = rows.iter().map(|r| r.action.action_id.as_str()).collect();
+    assert_eq!(captured, vec![poll_id.as_str(), follow_id.as_str()]);
+
+    let (status, _, body) = crate::test_get! {
+        app: ctx.app.clone(),
+        path: "/api/space-templates",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "list templates: {:?}", body);
+    assert!(body
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|t| t["template_id"] == template_id.as_str()));
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: "/api/posts",
+        headers: ctx.test_user.1.clone(),
+    };
+    assert_eq!(status, 200, "create post: {:?}", body);
+    let post_pk = body["post_pk"].as_str().unwrap();
+    let post_id = post_pk.strip_prefix("FEED#").unwrap_or(post_pk).to_string();
+
+    let (status, _, body) = crate::test_post! {
+        app: ctx.app.clone(),
+        path: &format!("/api/space-templates/{}/spaces", template_id),
+        headers: ctx.test_user.1.clone(),
+        body: { "post_id": post_id }
+    };
+    assert_eq!(status, 200, "create from template: {:?}", body);
+    let new_space_id = body["space_id"].as_str().unwrap().to_string();
+    let new_poll_id = body["action_ids"][&poll_id].as_str().unwrap().to_string();
+    let new_follow_id = body["action_ids"][&follow_id].as_str().unwrap().to_string();
+    assert_ne!(new_poll_id, poll_id);
+
+    let new_space = SpaceCommon::get(
+        &ctx.ddb,
+        &Partition::Space(new_space_id.clone()),
+        Some(EntityType::SpaceCommon),
+    )
+    .await
+    .unwrap()
+    .expect("new space");
+    assert!(new_space.join_anytime, "settings are carried over");
+    assert_eq!(new_space.publish_state, SpacePublishState::Draft);
+
+    let new_poll = SpacePoll::get(
+        &ctx.ddb,
+        &Partition::Space(new_space_id.clone()),
+        Some(EntityType::SpacePoll(new_poll_id.clone())),
+    )
+    .await
+    .unwrap()
+    .expect("cloned poll");
+    assert_eq!(new_poll.title, "Warm-up");
+
+    let new_follow = SpaceAction::get(
+        &ctx.ddb,
+        &CompositePartition(SpacePartition(new_space_id), new_follow_id),
+        Some(EntityType::SpaceAction),
+    )
+    .await
+    .unwrap()
+    .expect("cloned follow action");
+    assert_eq!(new_follow.depends_on, vec![new_poll_id]);
+}
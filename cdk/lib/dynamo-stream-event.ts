@@ -1852,6 +1852,94 @@ export class DynamoStreamEventStack extends Stack {
       targets: [new eventsTargets.LambdaFunction(props.lambdaFunction)],
     });
 
+    // ── Pipe: SpaceParticipant Insert → BadgeSpaceJoin ──────────────
+    // One participant row per (space, user), so each INSERT is one join
+    // toward the participant's badge progress. SpaceParticipant is a unit
+    // EntityType variant — sk is the bare string "SPACE_PARTICIPANT".
+    new pipes.CfnPipe(this, "BadgeSpaceJoinPipe", {
+      name: `ratel-${stage}-badge-space-join-pipe`,
+      roleArn: pipeRole.roleArn,
+      source: mainTableStreamArn,
+      sourceParameters: {
+        dynamoDbStreamParameters: {
+          startingPosition: "LATEST",
+          batchSize: 10,
+        },
+        filterCriteria: {
+          filters: [
+            {
+              pattern: JSON.stringify({
+                eventName: ["INSERT"],
+                dynamodb: {
+                  NewImage: {
+                    sk: { S: ["SPACE_PARTICIPANT"] },
+                  },
+                },
+              }),
+            },
+          ],
+        },
+      },
+      target: eventBus.eventBusArn,
+      targetParameters: {
+        eventBridgeEventBusParameters: {
+          source: "ratel.dynamodb.stream",
+          detailType: "BadgeSpaceJoin",
+        },
+        inputTemplate: '{"newImage": <$.dynamodb.NewImage>}',
+      },
+    });
+
+    // ── Pipe: User Insert → BadgeAccountCreated ─────────────────────
+    // Sign-up feeds windowed badges (Founding Voice). The remaining badge
+    // sources (poll, quiz, discussion, follow, space status) ride on the
+    // existing XP / status detail types.
+    new pipes.CfnPipe(this, "BadgeAccountCreatedPipe", {
+      name: `ratel-${stage}-badge-account-created-pipe`,
+      roleArn: pipeRole.roleArn,
+      source: mainTableStreamArn,
+      sourceParameters: {
+        dynamoDbStreamParameters: {
+          startingPosition: "LATEST",
+          batchSize: 10,
+        },
+        filterCriteria: {
+          filters: [
+            {
+              pattern: JSON.stringify({
+                eventName: ["INSERT"],
+                dynamodb: {
+                  NewImage: {
+                    sk: { S: ["USER"] },
+                  },
+                },
+              }),
+            },
+          ],
+        },
+      },
+      target: eventBus.eventBusArn,
+      targetParameters: {
+        eventBridgeEventBusParameters: {
+          source: "ratel.dynamodb.stream",
+          detailType: "BadgeAccountCreated",
+        },
+        inputTemplate: '{"newImage": <$.dynamodb.NewImage>}',
+      },
+    });
+
+    // ── Rule: Route badge events to app-shell Lambda ────────────────
+    new events.Rule(this, "BadgeProgressRule", {
+      eventBus,
+      description:
+        "Route space-join and sign-up events to app-shell for badge progress",
+      eventPattern: {
+        source: ["ratel.dynamodb.stream"],
+        detailType: ["BadgeSpaceJoin", "BadgeAccountCreated"],
+      },
+      targets: [new eventsTargets.LambdaFunction(props.lambdaFunction)],
+    });
+
     // ── Pipe: Cross-posting Stage 1 — Post Draft→Published transition ──
     // Fires on Post MODIFY only when status transitions to PUBLISHED (the
     // OldImage anything-but guard prevents re-firing on title/body edits
@@ -1961,5 +2049,199 @@ export class DynamoStreamEventStack extends Stack {
       targets: [new eventsTargets.LambdaFunction(crossPostingLambdaFunction)],
     });
 
+    // ── Pipe: SpaceMeetArchive insert → MeetTranscriptVectorIndex ──
+    new pipes.CfnPipe(this, "MeetTranscriptVectorIndexPipe", {
+      name: `ratel-${stage}-meet-transcript-vector-index-pipe`,
+      roleArn: pipeRole.roleArn,
+      source: mainTableStreamArn,
+      sourceParameters: {
+        dynamoDbStreamParameters: {
+          startingPosition: "LATEST",
+          batchSize: 10,
+        },
+        filterCriteria: {
+          filters: [
+            {
+              pattern: JSON.stringify({
+                eventName: ["INSERT"],
+                dynamodb: {
+                  NewImage: {
+                    sk: { S: [{ prefix: "SPACE_MEET_ARCHIVE#" }] },
+                  },
+                },
+              }),
+            },
+          ],
+        },
+      },
+      target: eventBus.eventBusArn,
+      targetParameters: {
+        eventBridgeEventBusParameters: {
+          source: "ratel.dynamodb.stream",
+          detailType: "MeetTranscriptVectorIndex",
+        },
+        inputTemplate: '{"newImage": <$.dynamodb.NewImage>}',
+      },
+    });
+
+    new events.Rule(this, "MeetTranscriptVectorIndexRule", {
+      eventBus,
+      description:
+        "Route meet archive inserts to app-shell for transcript vector indexing",
+      eventPattern: {
+        source: ["ratel.dynamodb.stream"],
+        detailType: ["MeetTranscriptVectorIndex"],
+      },
+      targets: [new eventsTargets.LambdaFunction(props.lambdaFunction)],
+    });
+
+    // ── Pipe: SpaceMeetArchive insert → EssenceIndexMeetTranscript ──
+    new pipes.CfnPipe(this, "EssenceIndexMeetTranscriptPipe", {
+      name: `ratel-${stage}-essence-index-meet-transcript-pipe`,
+      roleArn: pipeRole.roleArn,
+      source: mainTableStreamArn,
+      sourceParameters: {
+        dynamoDbStreamParameters: {
+          startingPosition: "LATEST",
+          batchSize: 10,
+        },
+        filterCriteria: {
+          filters: [
+            {
+              pattern: JSON.stringify({
+                eventName: ["INSERT"],
+                dynamodb: {
+                  NewImage: {
+                    sk: { S: [{ prefix: "SPACE_MEET_ARCHIVE#" }] },
+                  },
+                },
+              }),
+            },
+          ],
+        },
+      },
+      target: eventBus.eventBusArn,
+      targetParameters: {
+        eventBridgeEventBusParameters: {
+          source: "ratel.dynamodb.stream",
+          detailType: "EssenceIndexMeetTranscript",
+        },
+        inputTemplate: '{"newImage": <$.dynamodb.NewImage>}',
+      },
+    });
+
+    new events.Rule(this, "EssenceIndexMeetTranscriptRule", {
+      eventBus,
+      description:
+        "Route meet archive inserts to app-shell for essence indexing",
+      eventPattern: {
+        source: ["ratel.dynamodb.stream"],
+        detailType: ["EssenceIndexMeetTranscript"],
+      },
+      targets: [new eventsTargets.LambdaFunction(props.lambdaFunction)],
+    });
+
+    // ── Pipe: SpaceMeetAttendance insert → MeetXpRecord ──
+    new pipes.CfnPipe(this, "MeetXpPipe", {
+      name: `ratel-${stage}-meet-xp-pipe`,
+      roleArn: pipeRole.roleArn,
+      source: mainTableStreamArn,
+      sourceParameters: {
+        dynamoDbStreamParameters: {
+          startingPosition: "LATEST",
+          batchSize: 10,
+        },
+        filterCriteria: {
+          filters: [
+            {
+              pattern: JSON.stringify({
+                eventName: ["INSERT"],
+                dynamodb: {
+                  NewImage: {
+                    sk: { S: [{ prefix: "SPACE_MEET_ATTENDANCE#" }] },
+                  },
+                },
+              }),
+            },
+          ],
+        },
+      },
+      target: eventBus.eventBusArn,
+      targetParameters: {
+        eventBridgeEventBusParameters: {
+          source: "ratel.dynamodb.stream",
+          detailType: "MeetXpRecord",
+        },
+        inputTemplate: '{"newImage": <$.dynamodb.NewImage>}',
+      },
+    });
+
+    new events.Rule(this, "MeetXpRecordRule", {
+      eventBus,
+      description:
+        "Route meet attendance inserts to app-shell for XP and AttendMeet reward",
+      eventPattern: {
+        source: ["ratel.dynamodb.stream"],
+        detailType: ["MeetXpRecord"],
+      },
+      targets: [new eventsTargets.LambdaFunction(props.lambdaFunction)],
+    });
+
+    // ── Schedule: Meet deadline scheduler ──
+    // Not stream-driven: meet deadlines (10-minute reminder, 24h expiry,
+    // Live ceiling) pass without any write to the row. Runs every minute
+    // on the default bus and calls `run_meet_scheduler`, which only touches
+    // meets whose `next_deadline_at` has passed.
+    new events.Rule(this, "MeetScheduleTickRule", {
+      description: "Run the meet deadline scheduler every minute",
+      schedule: events.Schedule.rate(cdk.Duration.minutes(1)),
+      targets: [
+        new eventsTargets.LambdaFunction(props.lambdaFunction, {
+          event: events.RuleTargetInput.fromObject({
+            source: "ratel.scheduler",
+            "detail-type": "MeetScheduleTick",
+            detail: {},
+          }),
+        }),
+      ],
+    });
+
+    // ── Schedule: cross-posting engagement refresh ──
+    // Stage 4 of cross-posting. Published syndication jobs sit in a sparse
+    // GSI keyed by their next refresh time; every 5 minutes
+    // `run_engagement_refresh` pulls counts (and opted-in replies) for the
+    // ones that are due and reschedules them on the adaptive cadence.
+    new events.Rule(this, "EngagementRefreshTickRule", {
+      description: "Run the cross-posting engagement refresh every 5 minutes",
+      schedule: events.Schedule.rate(cdk.Duration.minutes(5)),
+      targets: [
+        new eventsTargets.LambdaFunction(props.lambdaFunction, {
+          event: events.RuleTargetInput.fromObject({
+            source: "ratel.scheduler",
+            "detail-type": "EngagementRefreshTick",
+            detail: {},
+          }),
+        }),
+      ],
+    });
+
+    // ── Schedule: cross-posting syndication scheduler ──
+    // Jobs queued for a later time (per-post "at" or the connection's best
+    // time) wait as `scheduled` in the dispatch-shard GSI; every minute
+    // `run_syndication_scheduler` flips the due ones to `pending`, which the
+    // Stage 2 pipe then dispatches.
+    new events.Rule(this, "SyndicationScheduleTickRule", {
+      description: "Release due scheduled cross-posts every minute",
+      schedule: events.Schedule.rate(cdk.Duration.minutes(1)),
+      targets: [
+        new eventsTargets.LambdaFunction(props.lambdaFunction, {
+          event: events.RuleTargetInput.fromObject({
+            source: "ratel.scheduler",
+            "detail-type": "SyndicationScheduleTick",
+            detail: {},
+          }),
+        }),
+      ],
+    });
   }
 }